@@ -1,8 +1,9 @@
 #![allow(unused_imports)]
 
 use criterion::{Criterion, black_box, criterion_group, criterion_main};
-use grac::Syllables;
-use grac::{is_greek_word, syllabify_with_merge, to_monotonic};
+use grac::{CachedSyllabifier, Syllables};
+use grac::{greek_fraction, greek_fraction_sampled};
+use grac::{is_greek_word, syllabify, syllabify_with_merge, syllabify_words, to_monotonic};
 use std::fs::File;
 use std::io::Read;
 use std::path::Path;
@@ -43,7 +44,7 @@ const PATHS: [&str; 4] = [
     "tests/fixtures/dump.txt",
 ];
 
-fn syllabify_with_merge_never(s: &str) -> Syllables {
+fn syllabify_with_merge_never(s: &str) -> Syllables<'_> {
     syllabify_with_merge(s, grac::Merge::Never)
 }
 
@@ -62,8 +63,50 @@ fn benchmark_syllabify(c: &mut Criterion) {
         let (content, stem) = read_file(file_path);
         let words: Vec<_> = content.split_whitespace().collect();
 
-        bench_words!(group, words, stem, syllabify_with_merge_never);
+        bench_words!(group, words, stem, syllabify_with_merge_never, syllabify);
+
+        group.bench_with_input(format!("syllabify_words@{stem}"), &content, |b, i| {
+            b.iter(|| {
+                let result: Vec<_> = syllabify_words(i).collect();
+                black_box(result);
+            });
+        });
     }
+
+    // Most tokens are short, and take the fast path that does not allocate.
+    let (content, stem) = read_file("tests/fixtures/dump.txt");
+    let words: Vec<_> = content
+        .split_whitespace()
+        .filter(|word| word.chars().count() <= 6)
+        .collect();
+    let stem = format!("short@{stem}");
+    bench_words!(group, words, stem, syllabify_with_merge_never, syllabify);
+}
+
+fn benchmark_cached_syllabify(c: &mut Criterion) {
+    let mut group = c.benchmark_group("cached_syllabify");
+    group
+        .measurement_time(std::time::Duration::new(3, 0))
+        .warm_up_time(std::time::Duration::new(2, 0));
+
+    let (content, stem) = read_file("tests/fixtures/dump.txt");
+    let words: Vec<_> = content.split_whitespace().collect();
+    // A fresh cache per iteration: the speedup comes from repeated words only.
+    group.bench_with_input(format!("cold@{stem}"), &words, |b, i| {
+        b.iter(|| {
+            let syllabifier = CachedSyllabifier::new();
+            let result: Vec<_> = i.iter().map(|word| syllabifier.syllabify(word)).collect();
+            black_box(result);
+        });
+    });
+
+    let syllabifier = CachedSyllabifier::new();
+    group.bench_with_input(format!("warm@{stem}"), &words, |b, i| {
+        b.iter(|| {
+            let result: Vec<_> = i.iter().map(|word| syllabifier.syllabify(word)).collect();
+            black_box(result);
+        });
+    });
 }
 
 fn benchmark_to_monotonic(c: &mut Criterion) {
@@ -93,10 +136,21 @@ fn benchmark_char(c: &mut Criterion) {
 
     for file_path in PATHS {
         let (content, stem) = read_file(file_path);
+        // Enough to tell English from Greek documents.
+        group.bench_with_input(format!("greek_fraction@{stem}"), &content, |b, i| {
+            b.iter(|| black_box(greek_fraction(i)));
+        });
+        group.bench_with_input(
+            format!("greek_fraction_sampled@{stem}"),
+            &content,
+            |b, i| {
+                b.iter(|| black_box(greek_fraction_sampled(i, 1000)));
+            },
+        );
         let words: Vec<_> = content.split_whitespace().collect();
         group.bench_with_input(stem, &words, |b, i| {
             b.iter(|| {
-                let result: Vec<_> = i.iter().map(|word| is_greek_word(word)).collect();
+                let result: Vec<_> = i.iter().map(is_greek_word).collect();
                 black_box(result);
             });
         });
@@ -106,6 +160,7 @@ fn benchmark_char(c: &mut Criterion) {
 criterion_group!(
     benches,
     benchmark_syllabify,
+    benchmark_cached_syllabify,
     benchmark_to_monotonic,
     benchmark_char
 );
@@ -0,0 +1,186 @@
+//! Minimal language server for Greek text.
+//!
+//! Provides:
+//! * monotonic lint diagnostics (cf. `grac::lint_monotonic`)
+//! * hyphenation on hover
+//!
+//! Talks LSP over stdio: point your editor's generic LSP client to
+//! `cargo run --release --example lsp`.
+
+use std::collections::HashMap;
+use std::error::Error;
+
+use grac::{
+    OffsetEncoder, SpanEncoding, TokenKind, encode_spans, lint_monotonic, syllabify, tokenize,
+};
+use lsp_server::{Connection, ErrorCode, Message, Notification, Request, Response};
+use lsp_types::notification::{
+    DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument,
+    Notification as NotificationTrait, PublishDiagnostics,
+};
+use lsp_types::request::{HoverRequest, Request as RequestTrait};
+use lsp_types::{
+    Diagnostic, DiagnosticSeverity, Hover, HoverContents, HoverParams, HoverProviderCapability,
+    MarkedString, Position, PublishDiagnosticsParams, Range, ServerCapabilities,
+    TextDocumentSyncCapability, TextDocumentSyncKind, Uri,
+};
+
+type Result<T> = std::result::Result<T, Box<dyn Error + Sync + Send>>;
+
+/// LSP position (line, UTF-16 column) of a byte offset.
+fn position_at(text: &str, byte: usize) -> Position {
+    let line_start = text[..byte].rfind('\n').map_or(0, |i| i + 1);
+    let line = text[..line_start].matches('\n').count();
+    let character =
+        OffsetEncoder::new(&text[line_start..], SpanEncoding::Utf16).encode(byte - line_start);
+    Position::new(line as u32, character as u32)
+}
+
+fn diagnostics(text: &str) -> Vec<Diagnostic> {
+    lint_monotonic(text)
+        .into_iter()
+        .map(|lint| Diagnostic {
+            range: Range::new(
+                position_at(text, lint.change.span.start),
+                position_at(text, lint.change.span.end),
+            ),
+            severity: Some(DiagnosticSeverity::WARNING),
+            source: Some("grac".to_string()),
+            message: format!("{}: expected `{}`", lint.kind.message(), lint.change.new),
+            ..Diagnostic::default()
+        })
+        .collect()
+}
+
+fn hover(text: &str, position: Position) -> Option<Hover> {
+    let line = text.split_inclusive('\n').nth(position.line as usize)?;
+    let words: Vec<_> = tokenize(line)
+        .filter(|token| token.kind == TokenKind::Word)
+        .collect();
+    let columns = encode_spans(
+        line,
+        words.iter().map(|token| token.span.clone()),
+        SpanEncoding::Utf16,
+    );
+    let (token, columns) = words
+        .iter()
+        .zip(columns)
+        .find(|(_, columns)| columns.contains(&(position.character as usize)))?;
+    Some(Hover {
+        contents: HoverContents::Scalar(MarkedString::String(syllabify(token.text).join("-"))),
+        range: Some(Range::new(
+            Position::new(position.line, columns.start as u32),
+            Position::new(position.line, columns.end as u32),
+        )),
+    })
+}
+
+struct Server {
+    connection: Connection,
+    documents: HashMap<Uri, String>,
+}
+
+impl Server {
+    fn publish_diagnostics(&self, uri: Uri) -> Result<()> {
+        let diagnostics = self
+            .documents
+            .get(&uri)
+            .map_or_else(Vec::new, |text| diagnostics(text));
+        let params = PublishDiagnosticsParams::new(uri, diagnostics, None);
+        let notification = Notification::new(PublishDiagnostics::METHOD.to_string(), params);
+        self.connection
+            .sender
+            .send(Message::Notification(notification))?;
+        Ok(())
+    }
+
+    fn handle_request(&self, request: Request) -> Result<()> {
+        if request.method != HoverRequest::METHOD {
+            let response = Response::new_err(
+                request.id,
+                ErrorCode::MethodNotFound as i32,
+                format!("unsupported method: {}", request.method),
+            );
+            self.connection.sender.send(Message::Response(response))?;
+            return Ok(());
+        }
+        let (id, params) = request.extract::<HoverParams>(HoverRequest::METHOD)?;
+        let document = params.text_document_position_params;
+        let result = self
+            .documents
+            .get(&document.text_document.uri)
+            .and_then(|text| hover(text, document.position));
+        let response = Response::new_ok(id, result);
+        self.connection.sender.send(Message::Response(response))?;
+        Ok(())
+    }
+
+    fn handle_notification(&mut self, notification: Notification) -> Result<()> {
+        match notification.method.as_str() {
+            DidOpenTextDocument::METHOD => {
+                let params = notification
+                    .extract::<<DidOpenTextDocument as NotificationTrait>::Params>(
+                        DidOpenTextDocument::METHOD,
+                    )?;
+                let document = params.text_document;
+                self.documents.insert(document.uri.clone(), document.text);
+                self.publish_diagnostics(document.uri)?;
+            }
+            DidChangeTextDocument::METHOD => {
+                let params = notification
+                    .extract::<<DidChangeTextDocument as NotificationTrait>::Params>(
+                        DidChangeTextDocument::METHOD,
+                    )?;
+                // We only advertise full document sync.
+                if let Some(change) = params.content_changes.into_iter().last() {
+                    let uri = params.text_document.uri;
+                    self.documents.insert(uri.clone(), change.text);
+                    self.publish_diagnostics(uri)?;
+                }
+            }
+            DidCloseTextDocument::METHOD => {
+                let params = notification
+                    .extract::<<DidCloseTextDocument as NotificationTrait>::Params>(
+                        DidCloseTextDocument::METHOD,
+                    )?;
+                self.documents.remove(&params.text_document.uri);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn run(mut self) -> Result<()> {
+        while let Ok(message) = self.connection.receiver.recv() {
+            match message {
+                Message::Request(request) => {
+                    if self.connection.handle_shutdown(&request)? {
+                        return Ok(());
+                    }
+                    self.handle_request(request)?;
+                }
+                Message::Notification(notification) => self.handle_notification(notification)?,
+                Message::Response(_) => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+fn main() -> Result<()> {
+    let (connection, io_threads) = Connection::stdio();
+    let capabilities = ServerCapabilities {
+        text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+        hover_provider: Some(HoverProviderCapability::Simple(true)),
+        ..ServerCapabilities::default()
+    };
+    connection.initialize(serde_json::to_value(capabilities)?)?;
+
+    Server {
+        connection,
+        documents: HashMap::new(),
+    }
+    .run()?;
+    io_threads.join()?;
+    Ok(())
+}
@@ -0,0 +1,141 @@
+//! Every public function that takes text must return without panicking.
+//!
+//! Run with `cargo +nightly fuzz run public_api` from the root of the repository.
+
+#![no_main]
+
+use std::sync::LazyLock;
+
+use grac::*;
+use libfuzzer_sys::fuzz_target;
+
+static TABLE_BYTES: LazyLock<Vec<u8>> = LazyLock::new(|| {
+    ExceptionTable::new()
+        .with_synizesis("ποιος", &["πο", "ιος"])
+        .with_special_case("ποιὸς", "ποιός")
+        .to_bytes()
+});
+
+fuzz_target!(|text: &str| {
+    // Positions and widths, derived from the input so that they are reproducible,
+    // and that may be past the end of the text or close to usize::MAX.
+    let mut first_bytes = [0; 8];
+    for (byte, &input) in first_bytes.iter_mut().zip(text.as_bytes()) {
+        *byte = input;
+    }
+    let n = u64::from_le_bytes(first_bytes) as usize;
+
+    let _ = syllabify(text);
+    let _ = syllabify_with_merge(text, Merge::Every);
+    let _ = syllabify_with_merge(text, Merge::from_indices(&[1, n]));
+    let _ = syllabify_with_options(
+        text,
+        &SyllabifyOptions::new().with_digamma_as_consonant(true),
+    );
+    let _ = syllabify_with_options(text, &SyllabifyOptions::new().with_morphological(true));
+    let _ = lookup_syllables(text);
+    let _ = syllabify_chars(&text.chars().collect::<Vec<_>>(), Merge::Every);
+    let _ = syllable_spans_utf16(text);
+    let _ = explain::explain(text);
+
+    let _ = to_monotonic(text);
+    let _ = to_monotonic_cow(text);
+    let _ = to_monotonic_stream(text.as_bytes(), std::io::sink(), &MonotonicOptions::new());
+    let _ = monotonic_changes_utf16(text);
+    let changes = monotonic_changes(text);
+    let _ = (apply_changes(text, &changes), invert_changes(&changes));
+    let _ = unified_diff(text, &changes, "fuzz.txt");
+    let _ = split_punctuation(text);
+    let mut buf = GreekWordBuf::new();
+    buf.set(text);
+    let _ = (buf.split_punctuation(), buf.is_all_caps());
+    let _ = buf.to_monotonic(text);
+    let _ = normalize_diaereses(text, &DiaeresisPolicy::default());
+    let _ = normalize_diaereses_cow(text, &DiaeresisPolicy::Keep);
+    let report = to_monotonic_with_report(text, &MonotonicOptions::new());
+    let _ = sample_for_review(&report, n, text.len() as u64);
+    let _ = lint_monotonic(text);
+    let _ = validate_polytonic(text);
+    let _ = apply_barytonesis(text);
+    let _ = lift_graves(text);
+    let _ = apply_nu_ephelkystikon(text);
+    let _ = (has_coronis(text), expand_crasis(text), expand_elision(text));
+    let mut converter = IncrementalConverter::new(text);
+    let _ = converter.try_update(n..text.len(), text);
+
+    let _ = remove_diacritic_at(text, n, Diacritic::ACUTE);
+    let _ = add_acute_at(text, n);
+    let _ = acute_to_circumflex_at(text, n);
+    let _ = add_diacritic_at_char(text, n, Diacritic::ROUGH);
+    let _ = remove_diacritic_at_char(text, n, Diacritic::ACUTE);
+    let _ = diacritic_pos(text, Diacritic::ACUTE);
+    let _ = diacritic_char_pos(text, Diacritic::ACUTE);
+    let _ = diacritic_map(text);
+    let _ = has_diacritic_at(text, n, Diacritic::ACUTE);
+    let _ = suggest_accented_forms(text);
+    let _ = fix_required_diaeresis(text);
+
+    let _ = titlecase_greek(text);
+    let _ = fix_sentence_capitalization(text);
+    let _ = to_allcaps(text, AccentStyle::KeepInitial);
+    let (folded, map) = fold_case_with_map(text);
+    let _ = map.span_to_original(0..folded.len());
+    let _ = to_upper_str(text);
+    let _ = base_lower_str(text);
+    let _ = graphemes_el(text).count();
+    let _ = (greek_fraction(text), greek_fraction_sampled(text, n));
+    let _ = text.greek_words().map(GreekStrExt::syllables).count();
+    let _ = (text.base_lower_chars().count(), text.without_diacritics());
+    let _ = remove_all_diacritics_cow(text);
+    let _ = ends_with_diphthong(text);
+
+    let _ = hyphenate(text, "-");
+    let _ = html::soft_hyphenate_html(text);
+    let _ = wrap_greek(text, n);
+    let _ = break_opportunities(text);
+    let _ = tokenize_utf16(text);
+    let _ = for_each_word_with_context(text, n, |prev, word, _| {
+        prev.first().map(|_| word.text.to_uppercase())
+    });
+    let _ = tts_tokens(text);
+    let _ = syllabify_words_outside(text, &ProtectedRegions::latex()).count();
+    let result = Pipeline::new()
+        .then(|text| normalize_quotes(text, QuoteStyle::Greek))
+        .then_changes(|text| monotonic_changes(text))
+        .run(text);
+    let _ = result.span_to_input(n.min(result.output.len())..result.output.len());
+
+    let _ = dehyphenate(text);
+    let _ = repair_ocr_diacritics(text);
+    let _ = normalize_dashes_cow(text, &DashStyle::default());
+    let _ = normalize_quotes_cow(text, QuoteStyle::Greek);
+    let _ = normalize_greek_punctuation_cow(text, GreekPunctuationForm::Dedicated);
+    let _ = normalize_whitespace_cow(text, &WhitespaceOptions::new().with_plain_spaces(n > 3));
+
+    let _ = invalid_clusters(text);
+    let _ = segment_prefix(text);
+    let _ = split_compound(text);
+    let _ = anagram_key(text);
+    let _ = matches_pattern("καλημέρα", text);
+    let _ = greek_edit_distance(text, "καλημέρα");
+    let _ = stem(text);
+    let _ = romanize(text);
+
+    let _ = EmbeddedTable::parse_bytes(text.as_bytes());
+    let mut table = ExceptionTable::parse(text).unwrap_or_default();
+    // Entries that the fields accept, but that do not spell their word
+    table
+        .synizesis
+        .insert(text.to_string(), vec![text.chars().rev().collect()]);
+    table.special_cases.insert(text.to_string(), String::new());
+    let embedded = EmbeddedTable::parse_bytes(&TABLE_BYTES).expect("valid table");
+    let ctx = GracContext::new()
+        .with_exception_table(&table)
+        .with_embedded_table(embedded);
+    let _ = (ctx.syllabify(text), ctx.syllable_spans(text));
+    let _ = (ctx.to_monotonic(text), ctx.lint_monotonic(text));
+    let _ = (ctx.hyphenate(text, "-"), ctx.syllabify_words(text).count());
+    if let Ok(ctx) = GracContext::new().try_with_exception(text, &[text]) {
+        let _ = ctx.syllabify(text);
+    }
+});
@@ -1,6 +1,9 @@
+import os
 from enum import Enum
+from pathlib import Path
 
 from .grac import *
+from .grac import __version__
 
 __doc__ = grac.__doc__
 if hasattr(grac, "__all__"):
@@ -16,3 +19,21 @@ class Diacritic(Enum):
     SMOOTH = '\u0313'         # ψιλή (psili)
     ROUGH = '\u0314'          # δασεία (dasia)
 # fmt: on
+
+
+def convert_dir(src, dst, profile="monotonic", pattern="*.txt"):
+    """Convert every file of `src` matching `pattern`, recursively, into the
+    same path under `dst` (cf. `convert_file`).
+
+    Returns the paths of the written files.
+    """
+    src, dst = Path(src), Path(dst)
+    written = []
+    for path in sorted(src.rglob(pattern)):
+        if not path.is_file():
+            continue
+        target = dst / path.relative_to(src)
+        os.makedirs(target.parent, exist_ok=True)
+        convert_file(path, target, profile)
+        written.append(target)
+    return written
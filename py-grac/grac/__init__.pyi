@@ -1,12 +1,112 @@
 # https://pyo3.rs/v0.23.3/python-typing-hints.html#my_projectpyi-content
 from enum import Enum
+from os import PathLike
+from pathlib import Path
+from types import TracebackType
+from typing import Iterable, Iterator, Literal, Self, overload
+
+__version__: str
+
+def features() -> dict[str, bool]: ...
+def build_info() -> dict[str, str]: ...
 
 class Diacritic(Enum): ...
 
+# Errors
+class GracError(ValueError): ...
+
+class InvalidWordError(GracError):
+    word: str
+    position: int
+
+class PolytonicError(GracError):
+    word: str
+    position: int
+    kind: str
+    errors: list[tuple[str, int]]
+
+class MonotonicError(GracError):
+    word: str
+    position: int
+    end: int
+    kind: str
+    suggestion: str
+
+class Syllables:
+    """The syllables of a word, as a sequence of strings."""
+
+    @property
+    def word(self) -> str: ...
+    @property
+    def spans(self) -> list[tuple[int, int]]:
+        """(start, end) of every syllable, as indices of the word."""
+    @property
+    def utf16_spans(self) -> list[tuple[int, int]]: ...
+    @property
+    def byte_spans(self) -> list[tuple[int, int]]: ...
+    def join(self, sep: str = "-") -> str: ...
+    def __len__(self) -> int: ...
+    @overload
+    def __getitem__(self, index: int) -> str: ...
+    @overload
+    def __getitem__(self, index: slice) -> list[str]: ...
+    def __iter__(self) -> Iterator[str]: ...
+    def __eq__(self, other: object) -> bool: ...
+
+# Options, also usable as context managers for the module functions
+class Syllabifier:
+    def __init__(
+        self,
+        *,
+        merge: Literal["every", "never"] | list[int] | None = None,
+        synizesis: bool = True,
+        digamma_as_consonant: bool = False,
+        morphological: bool = False,
+    ) -> None: ...
+    def syllabify(self, s: str) -> Syllables: ...
+    def __enter__(self) -> Self: ...
+    def __exit__(
+        self,
+        exc_type: type[BaseException] | None,
+        exc: BaseException | None,
+        tb: TracebackType | None,
+    ) -> bool: ...
+
+class Hyphenator:
+    """Hyphenation, with the interface of `pyphen.Pyphen`."""
+
+    def __init__(self, *, lang: str = "el", left: int = 2, right: int = 2) -> None: ...
+    def positions(self, word: str) -> list[int]: ...
+    def iterate(self, word: str) -> list[tuple[str, str]]: ...
+    def wrap(self, word: str, width: int, hyphen: str = "-") -> tuple[str, str] | None: ...
+    def inserted(self, word: str, hyphen: str = "-") -> str: ...
+
+class MonotonicOptions:
+    def __init__(
+        self,
+        *,
+        keep_pronoun_accents: bool = False,
+        keep_diaereses: bool = False,
+        strict_greek: bool = False,
+        conservative_names: bool = False,
+        protected: Literal["xml", "markdown", "latex"] | None = None,
+        protected_words: Iterable[str] = ...,
+        special_cases: dict[str, str] = ...,
+    ) -> None: ...
+    def to_monotonic(self, s: str) -> str: ...
+    def __enter__(self) -> Self: ...
+    def __exit__(
+        self,
+        exc_type: type[BaseException] | None,
+        exc: BaseException | None,
+        tb: TracebackType | None,
+    ) -> bool: ...
+
 # Syllabification
-def syllabify(s: str) -> list[str]: ...
-def syllabify_with_merge(s: str, merge: bool) -> list[str]: ...
-def syllabify_with_merge_at(s: str, idxs: list[int]) -> list[str]: ...
+def syllabify(s: str) -> Syllables: ...
+def try_syllabify(s: str) -> Syllables: ...
+def syllabify_with_merge(s: str, merge: bool) -> Syllables: ...
+def syllabify_with_merge_at(s: str, idxs: list[int]) -> Syllables: ...
 
 # Accent utilities
 def remove_all_diacritics(s: str) -> str: ...
@@ -16,3 +116,25 @@ def has_diacritic(s: str, diacritic: str) -> bool: ...
 
 # Monotonic
 def to_monotonic(s: str) -> str: ...
+
+# Tokenization (cf. grac.tokenizers for spaCy and NLTK)
+def tokenize(
+    s: str,
+) -> list[tuple[Literal["word", "punctuation", "whitespace"], str, int, int]]: ...
+
+# Files
+def convert_file(
+    src: str | PathLike[str],
+    dst: str | PathLike[str],
+    profile: Literal["monotonic", "monotonic-xml"] = "monotonic",
+) -> None: ...
+def convert_dir(
+    src: str | PathLike[str],
+    dst: str | PathLike[str],
+    profile: Literal["monotonic", "monotonic-xml"] = "monotonic",
+    pattern: str = "*.txt",
+) -> list[Path]: ...
+
+# Validation, raising the errors above
+def validate_polytonic(s: str) -> None: ...
+def validate_monotonic(s: str) -> None: ...
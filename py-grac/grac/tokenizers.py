@@ -0,0 +1,67 @@
+"""Adapters of the grac tokenizer for spaCy and NLTK.
+
+Neither library is required to import this module: spaCy is only imported
+when a `SpacyTokenizer` is called.
+
+    import spacy
+    from grac.tokenizers import SpacyTokenizer
+
+    nlp = spacy.blank("el")
+    nlp.tokenizer = SpacyTokenizer(nlp.vocab)
+"""
+
+from .grac import tokenize
+
+
+def token_table(text):
+    """Tokens of `text` as (text, whitespace, idx) rows, as in a spaCy `Doc`.
+
+    `whitespace` is the single space that follows the token, if any. Other
+    whitespace becomes tokens of its own, as spaCy does.
+    """
+    rows = []
+    for kind, token, start, _ in tokenize(text):
+        if kind != "whitespace":
+            rows.append((token, "", start))
+            continue
+        if rows and rows[-1][1] == "" and token.startswith(" "):
+            prev, _, idx = rows[-1]
+            rows[-1] = (prev, " ", idx)
+            token, start = token[1:], start + 1
+        if token:
+            rows.append((token, "", start))
+    return rows
+
+
+class SpacyTokenizer:
+    """A spaCy tokenizer: `nlp.tokenizer = SpacyTokenizer(nlp.vocab)`."""
+
+    def __init__(self, vocab):
+        self.vocab = vocab
+
+    def __call__(self, text):
+        from spacy.tokens import Doc
+
+        rows = token_table(text)
+        words = [token for token, _, _ in rows]
+        spaces = [bool(whitespace) for _, whitespace, _ in rows]
+        return Doc(self.vocab, words=words, spaces=spaces)
+
+
+class NLTKTokenizer:
+    """A tokenizer with the interface of `nltk.tokenize.api.TokenizerI`."""
+
+    def tokenize(self, text):
+        return [token for kind, token, _, _ in tokenize(text) if kind != "whitespace"]
+
+    def span_tokenize(self, text):
+        for kind, _, start, end in tokenize(text):
+            if kind != "whitespace":
+                yield start, end
+
+    def tokenize_sents(self, texts):
+        return [self.tokenize(text) for text in texts]
+
+    def span_tokenize_sents(self, texts):
+        for text in texts:
+            yield list(self.span_tokenize(text))
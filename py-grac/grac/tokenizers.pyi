@@ -0,0 +1,15 @@
+from collections.abc import Iterable, Iterator
+from typing import Any
+
+def token_table(text: str) -> list[tuple[str, str, int]]: ...
+
+class SpacyTokenizer:
+    vocab: Any
+    def __init__(self, vocab: Any) -> None: ...
+    def __call__(self, text: str) -> Any: ...
+
+class NLTKTokenizer:
+    def tokenize(self, text: str) -> list[str]: ...
+    def span_tokenize(self, text: str) -> Iterator[tuple[int, int]]: ...
+    def tokenize_sents(self, texts: Iterable[str]) -> list[list[str]]: ...
+    def span_tokenize_sents(self, texts: Iterable[str]) -> Iterator[list[tuple[int, int]]]: ...
@@ -1,27 +1,600 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::PathBuf;
+
 use ::grac as _grac;
+use pyo3::create_exception;
+use pyo3::exceptions::{PyIndexError, PyValueError};
 use pyo3::prelude::*;
+use pyo3::sync::GILOnceCell;
+use pyo3::types::{IntoPyDict, PyIterator, PyList, PySlice, PyTuple};
+
+create_exception!(grac, GracError, PyValueError, "Invalid input to grac.");
+create_exception!(
+    grac,
+    InvalidWordError,
+    GracError,
+    "A word with characters that can not be syllabified."
+);
+create_exception!(
+    grac,
+    PolytonicError,
+    GracError,
+    "A misplaced diacritic in a polytonic word."
+);
+create_exception!(
+    grac,
+    MonotonicError,
+    GracError,
+    "A word of a monotonic text that is not in monotonic form."
+);
+
+/// An exception of type `T`, with `position` and the other given attributes.
+///
+/// Positions are indices of the input in Python.
+fn error_at<T: pyo3::PyTypeInfo>(
+    py: Python<'_>,
+    message: String,
+    position: usize,
+    attrs: &[(&str, &str)],
+) -> PyResult<PyErr> {
+    let err = PyErr::from_type(py.get_type::<T>(), message);
+    let value = err.value(py);
+    value.setattr("position", position)?;
+    for (name, attr) in attrs {
+        value.setattr(*name, *attr)?;
+    }
+    Ok(err)
+}
+
+/// Index in Python of the byte `idx` of `s`.
+fn char_idx(s: &str, idx: usize) -> usize {
+    s[..idx].chars().count()
+}
+
+/// Options set by `with` blocks, as a `contextvars.ContextVar` holding a tuple
+/// of the options of the enclosing blocks, innermost last.
+///
+/// Unlike a thread local, every asyncio task and thread sees its own blocks.
+struct OptionsStack {
+    name: &'static str,
+    var: GILOnceCell<PyObject>,
+}
+
+static SYLLABIFIERS: OptionsStack = OptionsStack::new("grac_syllabifiers");
+static MONOTONIC_OPTIONS: OptionsStack = OptionsStack::new("grac_monotonic_options");
+
+impl OptionsStack {
+    const fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            var: GILOnceCell::new(),
+        }
+    }
+
+    fn var<'py>(&self, py: Python<'py>) -> PyResult<&Bound<'py, PyAny>> {
+        let var = self.var.get_or_try_init(py, || {
+            let default = [("default", PyTuple::empty(py))].into_py_dict(py)?;
+            let var = py
+                .import("contextvars")?
+                .getattr("ContextVar")?
+                .call((self.name,), Some(&default))?;
+            Ok::<_, PyErr>(var.unbind())
+        })?;
+        Ok(var.bind(py))
+    }
+
+    fn get<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        Ok(self.var(py)?.call_method0("get")?.downcast_into()?)
+    }
+
+    fn push(&self, options: &Bound<'_, PyAny>) -> PyResult<()> {
+        let py = options.py();
+        let mut stack: Vec<Bound<'_, PyAny>> = self.get(py)?.iter().collect();
+        stack.push(options.clone());
+        self.var(py)?
+            .call_method1("set", (PyTuple::new(py, stack)?,))?;
+        Ok(())
+    }
+
+    /// Remove the innermost entry of `options`, that may not be the last one
+    /// if blocks were exited out of order.
+    fn remove(&self, options: &Bound<'_, PyAny>) -> PyResult<()> {
+        let py = options.py();
+        let mut stack: Vec<Bound<'_, PyAny>> = self.get(py)?.iter().collect();
+        if let Some(idx) = stack.iter().rposition(|entry| entry.is(options)) {
+            stack.remove(idx);
+        }
+        self.var(py)?
+            .call_method1("set", (PyTuple::new(py, stack)?,))?;
+        Ok(())
+    }
+
+    /// The options of the innermost block, if any.
+    fn innermost<'py, T: pyo3::PyClass>(&self, py: Python<'py>) -> PyResult<Option<Bound<'py, T>>> {
+        let stack = self.get(py)?;
+        match stack.len() {
+            0 => Ok(None),
+            len => Ok(Some(stack.get_item(len - 1)?.downcast_into()?)),
+        }
+    }
+}
+
+/// A merge, as given from Python: "every", "never" or a list of indices.
+#[derive(FromPyObject)]
+enum MergeArg {
+    Name(String),
+    Indices(Vec<usize>),
+}
+
+impl TryFrom<MergeArg> for _grac::Merge {
+    type Error = PyErr;
+
+    fn try_from(merge: MergeArg) -> PyResult<Self> {
+        match merge {
+            MergeArg::Name(name) => match name.as_str() {
+                "every" => Ok(Self::Every),
+                "never" => Ok(Self::Never),
+                _ => Err(PyValueError::new_err(format!(
+                    "invalid merge {name:?}: expected \"every\", \"never\" or a list of indices"
+                ))),
+            },
+            MergeArg::Indices(indices) => Ok(Self::Indices(indices)),
+        }
+    }
+}
+
+/// The syllables of a word, as a sequence of strings.
+#[pyclass(module = "grac", sequence)]
+struct Syllables {
+    #[pyo3(get)]
+    word: String,
+    syllables: Vec<String>,
+}
+
+/// An index of `Syllables.__getitem__`.
+#[derive(FromPyObject)]
+enum SequenceIndex<'py> {
+    Int(isize),
+    Slice(Bound<'py, PySlice>),
+}
+
+#[derive(IntoPyObject)]
+enum SequenceItem {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl Syllables {
+    fn new(word: &str, syllables: &[&str]) -> Self {
+        Self {
+            word: word.to_string(),
+            syllables: syllables.iter().map(|s| (*s).to_string()).collect(),
+        }
+    }
+
+    fn encoded_spans(&self, encoding: _grac::SpanEncoding) -> Vec<(usize, usize)> {
+        let mut start = 0;
+        let spans = self.syllables.iter().map(|syllable| {
+            start += syllable.len();
+            start - syllable.len()..start
+        });
+        _grac::encode_spans(&self.word, spans, encoding)
+            .into_iter()
+            .map(|span| (span.start, span.end))
+            .collect()
+    }
+}
+
+#[pymethods]
+impl Syllables {
+    fn __len__(&self) -> usize {
+        self.syllables.len()
+    }
+
+    fn __getitem__(&self, index: SequenceIndex<'_>) -> PyResult<SequenceItem> {
+        let len = self.syllables.len();
+        match index {
+            SequenceIndex::Int(idx) => {
+                let idx = if idx < 0 { idx + len as isize } else { idx };
+                usize::try_from(idx)
+                    .ok()
+                    .and_then(|idx| self.syllables.get(idx))
+                    .map(|syllable| SequenceItem::One(syllable.clone()))
+                    .ok_or_else(|| PyIndexError::new_err("syllable index out of range"))
+            }
+            SequenceIndex::Slice(slice) => {
+                let indices = slice.indices(len as isize)?;
+                let items = (0..indices.slicelength)
+                    .map(|n| {
+                        self.syllables[(indices.start + n as isize * indices.step) as usize].clone()
+                    })
+                    .collect();
+                Ok(SequenceItem::Many(items))
+            }
+        }
+    }
+
+    fn __iter__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyIterator>> {
+        PyList::new(py, &self.syllables)?.try_iter()
+    }
+
+    fn __eq__(&self, other: &Bound<'_, PyAny>) -> bool {
+        if let Ok(other) = other.downcast::<Self>() {
+            return other.borrow().syllables == self.syllables;
+        }
+        other
+            .extract::<Vec<String>>()
+            .is_ok_and(|other| other == self.syllables)
+    }
+
+    fn __repr__(&self, py: Python<'_>) -> PyResult<String> {
+        let list = PyList::new(py, &self.syllables)?;
+        Ok(format!("Syllables({})", list.repr()?))
+    }
+
+    #[pyo3(signature = (sep="-"))]
+    fn join(&self, sep: &str) -> String {
+        self.syllables.join(sep)
+    }
+
+    /// (start, end) of every syllable, as indices of the word in Python.
+    #[getter]
+    fn spans(&self) -> Vec<(usize, usize)> {
+        self.encoded_spans(_grac::SpanEncoding::Chars)
+    }
+
+    /// Same as `spans`, in UTF-16 code units (Qt, JavaScript).
+    #[getter]
+    fn utf16_spans(&self) -> Vec<(usize, usize)> {
+        self.encoded_spans(_grac::SpanEncoding::Utf16)
+    }
+
+    /// Same as `spans`, in bytes of the word encoded as UTF-8.
+    #[getter]
+    fn byte_spans(&self) -> Vec<(usize, usize)> {
+        self.encoded_spans(_grac::SpanEncoding::Utf8)
+    }
+}
+
+/// Syllabification options.
+///
+/// Also a context manager, to make `syllabify` use them in a `with` block.
+#[pyclass(module = "grac")]
+#[derive(Clone)]
+struct Syllabifier {
+    options: _grac::SyllabifyOptions,
+    /// Look up words in the synizesis table first, as `syllabify` does.
+    synizesis: bool,
+}
+
+#[pymethods]
+impl Syllabifier {
+    #[new]
+    #[pyo3(signature = (*, merge=None, synizesis=true, digamma_as_consonant=false, morphological=false))]
+    fn new(
+        merge: Option<MergeArg>,
+        synizesis: bool,
+        digamma_as_consonant: bool,
+        morphological: bool,
+    ) -> PyResult<Self> {
+        let mut options = _grac::SyllabifyOptions::new()
+            .with_digamma_as_consonant(digamma_as_consonant)
+            .with_morphological(morphological);
+        // An explicit merge replaces the lookup table.
+        let synizesis = synizesis && merge.is_none();
+        if let Some(merge) = merge {
+            options = options.with_merge(merge.try_into()?);
+        }
+        Ok(Self { options, synizesis })
+    }
+
+    fn syllabify(&self, word: &str) -> Syllables {
+        Syllables::new(word, &self.syllabify_word(word))
+    }
+
+    fn __enter__(slf: Bound<'_, Self>) -> PyResult<Bound<'_, Self>> {
+        SYLLABIFIERS.push(&slf)?;
+        Ok(slf)
+    }
+
+    #[pyo3(signature = (*_args))]
+    fn __exit__(slf: &Bound<'_, Self>, _args: &Bound<'_, PyTuple>) -> PyResult<bool> {
+        SYLLABIFIERS.remove(slf)?;
+        Ok(false)
+    }
+}
+
+impl Syllabifier {
+    fn syllabify_word<'a>(&self, word: &'a str) -> _grac::Syllables<'a> {
+        if self.synizesis
+            && let Some(syllables) = _grac::lookup_syllables(word)
+        {
+            return syllables;
+        }
+        _grac::syllabify_with_options(word, &self.options)
+    }
+}
+
+/// Hyphenation, with the interface of `pyphen.Pyphen`.
+///
+/// `left` and `right` are the minimum number of letters before and after a
+/// hyphen. Positions are indices of the word in Python.
+#[pyclass(module = "grac")]
+struct Hyphenator {
+    options: _grac::HyphenationOptions,
+}
+
+#[pymethods]
+impl Hyphenator {
+    #[new]
+    #[pyo3(signature = (*, lang="el", left=2, right=2))]
+    fn new(lang: &str, left: usize, right: usize) -> PyResult<Self> {
+        if !(lang == "el" || lang.starts_with("el_") || lang.starts_with("el-")) {
+            return Err(PyValueError::new_err(format!(
+                "unsupported language {lang:?}: only Greek (\"el\") is supported"
+            )));
+        }
+        let options = _grac::HyphenationOptions::new()
+            .with_min_prefix(left)
+            .with_min_suffix(right);
+        Ok(Self { options })
+    }
+
+    fn positions(&self, word: &str) -> Vec<usize> {
+        self.points(word)
+            .into_iter()
+            .map(|point| char_idx(word, point))
+            .collect()
+    }
+
+    /// Every (head, tail) split of the word, with the longest head first.
+    fn iterate(&self, word: &str) -> Vec<(String, String)> {
+        self.points(word)
+            .into_iter()
+            .rev()
+            .map(|point| (word[..point].to_string(), word[point..].to_string()))
+            .collect()
+    }
+
+    /// The split with the longest head that fits in `width` with the hyphen.
+    #[pyo3(signature = (word, width, hyphen="-"))]
+    fn wrap(&self, word: &str, width: usize, hyphen: &str) -> Option<(String, String)> {
+        let width = width.checked_sub(hyphen.chars().count())?;
+        self.iterate(word)
+            .into_iter()
+            .find(|(head, _)| head.chars().count() <= width)
+            .map(|(head, tail)| (head + hyphen, tail))
+    }
+
+    #[pyo3(signature = (word, hyphen="-"))]
+    fn inserted(&self, word: &str, hyphen: &str) -> String {
+        let mut out = String::with_capacity(word.len());
+        let mut start = 0;
+        for point in self.points(word) {
+            out.push_str(&word[start..point]);
+            out.push_str(hyphen);
+            start = point;
+        }
+        out.push_str(&word[start..]);
+        out
+    }
+}
+
+impl Hyphenator {
+    fn points(&self, word: &str) -> Vec<usize> {
+        _grac::hyphenation_points_with_options(word, &self.options)
+    }
+}
+
+/// Options of `to_monotonic`.
+///
+/// Also a context manager, to make `to_monotonic` use them in a `with` block.
+#[pyclass(module = "grac")]
+#[derive(Clone)]
+struct MonotonicOptions {
+    options: _grac::MonotonicOptions,
+}
+
+#[pymethods]
+impl MonotonicOptions {
+    #[new]
+    #[pyo3(signature = (
+        *,
+        keep_pronoun_accents=false,
+        keep_diaereses=false,
+        strict_greek=false,
+        conservative_names=false,
+        protected=None,
+        protected_words=None,
+        special_cases=BTreeMap::new(),
+    ))]
+    fn new(
+        keep_pronoun_accents: bool,
+        keep_diaereses: bool,
+        strict_greek: bool,
+        conservative_names: bool,
+        protected: Option<&str>,
+        protected_words: Option<&Bound<'_, PyAny>>,
+        special_cases: BTreeMap<String, String>,
+    ) -> PyResult<Self> {
+        let protected = match protected {
+            None => _grac::ProtectedRegions::new(),
+            Some("xml") => _grac::ProtectedRegions::xml(),
+            Some("markdown") => _grac::ProtectedRegions::markdown(),
+            Some("latex") => _grac::ProtectedRegions::latex(),
+            Some(other) => {
+                return Err(PyValueError::new_err(format!(
+                    "invalid protected {other:?}: expected \"xml\", \"markdown\" or \"latex\""
+                )));
+            }
+        };
+        // Any iterable of strings
+        let protected_words = match protected_words {
+            Some(words) => words
+                .try_iter()?
+                .map(|word| word?.extract::<String>())
+                .collect::<PyResult<BTreeSet<String>>>()?,
+            None => BTreeSet::new(),
+        };
+        let diaeresis = if keep_diaereses {
+            _grac::DiaeresisPolicy::Keep
+        } else {
+            _grac::DiaeresisPolicy::RemoveSuperfluous
+        };
+        let mut options = _grac::MonotonicOptions::new()
+            .with_keep_pronoun_accents(keep_pronoun_accents)
+            .with_protected(protected)
+            .with_diaeresis(diaeresis)
+            .with_strict_greek(strict_greek)
+            .with_conservative_names(conservative_names);
+        options.special_cases = special_cases;
+        options.protected_words = protected_words;
+        Ok(Self { options })
+    }
+
+    fn to_monotonic(&self, text: &str) -> String {
+        _grac::to_monotonic_with_options(text, &self.options)
+    }
+
+    fn __enter__(slf: Bound<'_, Self>) -> PyResult<Bound<'_, Self>> {
+        MONOTONIC_OPTIONS.push(&slf)?;
+        Ok(slf)
+    }
+
+    #[pyo3(signature = (*_args))]
+    fn __exit__(slf: &Bound<'_, Self>, _args: &Bound<'_, PyTuple>) -> PyResult<bool> {
+        MONOTONIC_OPTIONS.remove(slf)?;
+        Ok(false)
+    }
+}
+
+/// Syllabify with the options of the innermost `with Syllabifier(...)` block, if any.
+#[pyfunction]
+fn syllabify(py: Python<'_>, word: &str) -> PyResult<Syllables> {
+    let syllables = match SYLLABIFIERS.innermost::<Syllabifier>(py)? {
+        Some(syllabifier) => syllabifier.borrow().syllabify_word(word),
+        None => _grac::syllabify(word),
+    };
+    Ok(Syllables::new(word, &syllables))
+}
+
+/// Same as `syllabify`, raising `InvalidWordError` for words that are empty or
+/// have non Greek letters.
+#[pyfunction]
+fn try_syllabify(py: Python<'_>, word: &str) -> PyResult<Syllables> {
+    if word.is_empty() {
+        return Err(error_at::<InvalidWordError>(
+            py,
+            "empty word".to_string(),
+            0,
+            &[("word", word)],
+        )?);
+    }
+    let mut buf = [0; 4];
+    let invalid = word
+        .char_indices()
+        .find(|(_, ch)| !_grac::is_greek_word(&*ch.encode_utf8(&mut buf)));
+    if let Some((idx, ch)) = invalid {
+        let position = char_idx(word, idx);
+        return Err(error_at::<InvalidWordError>(
+            py,
+            format!("{word}: position {position}: {ch:?} is not Greek"),
+            position,
+            &[("word", word)],
+        )?);
+    }
+    syllabify(py, word)
+}
+
+/// Raise `PolytonicError` for the first misplaced diacritic of `word`.
+///
+/// The error has the `word`, the `position` of the character, its `kind`
+/// (ex. "MisplacedBreathing") and the list of every error as `errors`.
+#[pyfunction]
+fn validate_polytonic(py: Python<'_>, word: &str) -> PyResult<()> {
+    let errors = _grac::validate_polytonic(word);
+    let Some(first) = errors.first() else {
+        return Ok(());
+    };
+    // Error indices count user-perceived characters.
+    let positions: Vec<usize> = errors
+        .iter()
+        .map(|error| {
+            _grac::graphemes_el(word)
+                .take(error.idx)
+                .map(|cluster| cluster.chars().count())
+                .sum()
+        })
+        .collect();
+    let kind = format!("{:?}", first.kind);
+    let err = error_at::<PolytonicError>(
+        py,
+        format!(
+            "{word}: position {}: {}",
+            positions[0],
+            first.kind.message()
+        ),
+        positions[0],
+        &[("word", word), ("kind", &kind)],
+    )?;
+    let all: Vec<(String, usize)> = errors
+        .iter()
+        .zip(positions)
+        .map(|(error, position)| (format!("{:?}", error.kind), position))
+        .collect();
+    err.value(py).setattr("errors", all)?;
+    Err(err)
+}
 
+/// Raise `MonotonicError` for the first word of `text` that is not in
+/// monotonic form.
+///
+/// The error has the `word`, its `position` and `end` in `text`, its `kind`
+/// (ex. "DoubleAccent") and the `suggestion` to replace it with.
 #[pyfunction]
-fn syllabify(word: &str) -> PyResult<Vec<&str>> {
-    Ok(_grac::syllabify(word).to_vec())
+fn validate_monotonic(py: Python<'_>, text: &str) -> PyResult<()> {
+    let Some(lint) = _grac::lint_monotonic(text).into_iter().next() else {
+        return Ok(());
+    };
+    let span = lint.change.span;
+    let (position, end) = (char_idx(text, span.start), char_idx(text, span.end));
+    let kind = format!("{:?}", lint.kind);
+    let err = error_at::<MonotonicError>(
+        py,
+        format!(
+            "{}: position {position}: {}",
+            lint.change.old,
+            lint.kind.message()
+        ),
+        position,
+        &[
+            ("word", &lint.change.old),
+            ("kind", &kind),
+            ("suggestion", &lint.change.new),
+        ],
+    )?;
+    err.value(py).setattr("end", end)?;
+    Err(err)
 }
 
 #[pyfunction]
-fn syllabify_with_merge(word: &str, merge: bool) -> PyResult<Vec<&str>> {
+fn syllabify_with_merge(word: &str, merge: bool) -> PyResult<Syllables> {
     let merge = if merge {
         _grac::Merge::Every
     } else {
         _grac::Merge::Never
     };
     let syllables = _grac::syllabify_with_merge(word, merge);
-    Ok(syllables.to_vec())
+    Ok(Syllables::new(word, &syllables))
 }
 
 #[pyfunction]
-fn syllabify_with_merge_at(word: &str, indices: Vec<usize>) -> PyResult<Vec<&str>> {
+fn syllabify_with_merge_at(word: &str, indices: Vec<usize>) -> PyResult<Syllables> {
     let merge = _grac::Merge::Indices(indices);
     let syllables = _grac::syllabify_with_merge(word, merge);
-    Ok(syllables.to_vec())
+    Ok(Syllables::new(word, &syllables))
 }
 
 #[pyfunction]
@@ -44,14 +617,114 @@ fn add_acute_at(word: &str, pos: usize) -> PyResult<String> {
     Ok(_grac::add_acute_at(word, pos))
 }
 
+/// Convert with the options of the innermost `with MonotonicOptions(...)` block, if any.
+#[pyfunction]
+fn to_monotonic(py: Python<'_>, word: &str) -> PyResult<String> {
+    let converted = match MONOTONIC_OPTIONS.innermost::<MonotonicOptions>(py)? {
+        Some(options) => options.borrow().to_monotonic(word),
+        None => _grac::to_monotonic(word),
+    };
+    Ok(converted)
+}
+
+/// Tokens of `text`, as (kind, text, start, end) where kind is "word",
+/// "punctuation" or "whitespace", and start and end are indices in Python.
+#[pyfunction]
+fn tokenize(text: &str) -> Vec<(&'static str, &str, usize, usize)> {
+    let tokens: Vec<_> = _grac::tokenize(text).collect();
+    let spans = _grac::encode_spans(
+        text,
+        tokens.iter().map(|token| token.span.clone()),
+        _grac::SpanEncoding::Chars,
+    );
+    tokens
+        .into_iter()
+        .zip(spans)
+        .map(|(token, span)| {
+            let kind = match token.kind {
+                _grac::TokenKind::Word => "word",
+                _grac::TokenKind::Punctuation => "punctuation",
+                _grac::TokenKind::Whitespace => "whitespace",
+            };
+            (kind, token.text, span.start, span.end)
+        })
+        .collect()
+}
+
+/// Convert the file `src` into `dst`, one line or XML event at a time,
+/// without holding the GIL.
+///
+/// Profiles are "monotonic", for plain text, and "monotonic-xml", for the
+/// text nodes of XML documents (TEI, for instance). Plain text uses the
+/// options of the innermost `with MonotonicOptions(...)` block, if any.
+#[pyfunction]
+#[pyo3(signature = (src, dst, profile="monotonic"))]
+fn convert_file(py: Python<'_>, src: PathBuf, dst: PathBuf, profile: &str) -> PyResult<()> {
+    let xml = match profile {
+        "monotonic" => false,
+        "monotonic-xml" => true,
+        _ => {
+            return Err(PyValueError::new_err(format!(
+                "invalid profile {profile:?}: expected \"monotonic\" or \"monotonic-xml\""
+            )));
+        }
+    };
+    let options = MONOTONIC_OPTIONS
+        .innermost::<MonotonicOptions>(py)?
+        .map(|o| o.borrow().options.clone())
+        .unwrap_or_default();
+    let result = py.allow_threads(|| {
+        let input = BufReader::new(File::open(src)?);
+        let output = BufWriter::new(File::create(dst)?);
+        if xml {
+            _grac::formats::xml_to_monotonic(input, output)
+        } else {
+            _grac::to_monotonic_stream(input, output, &options).map_err(Into::into)
+        }
+    });
+    result.map_err(|err| match err {
+        _grac::formats::FormatError::Io(err) => err.into(),
+        err => GracError::new_err(err.to_string()),
+    })
+}
+
+/// Optional features of grac, and whether this build has them.
+#[pyfunction]
+fn features() -> BTreeMap<&'static str, bool> {
+    BTreeMap::from([
+        ("tei", true),
+        ("sync", cfg!(feature = "sync")),
+        ("corpus-tools", cfg!(feature = "corpus-tools")),
+        ("html", cfg!(feature = "html")),
+        ("wiktionary", cfg!(feature = "wiktionary")),
+    ])
+}
+
+/// How this build was made.
 #[pyfunction]
-fn to_monotonic(word: &str) -> PyResult<String> {
-    Ok(_grac::to_monotonic(word))
+fn build_info() -> BTreeMap<&'static str, &'static str> {
+    BTreeMap::from([
+        ("version", env!("CARGO_PKG_VERSION")),
+        (
+            "profile",
+            if cfg!(debug_assertions) {
+                "debug"
+            } else {
+                "release"
+            },
+        ),
+        ("os", std::env::consts::OS),
+        ("arch", std::env::consts::ARCH),
+    ])
 }
 
 #[pymodule]
 fn grac(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add("__version__", env!("CARGO_PKG_VERSION"))?;
+    m.add_function(wrap_pyfunction!(features, m)?)?;
+    m.add_function(wrap_pyfunction!(build_info, m)?)?;
     m.add_function(wrap_pyfunction!(syllabify, m)?)?;
+    m.add_function(wrap_pyfunction!(try_syllabify, m)?)?;
     m.add_function(wrap_pyfunction!(syllabify_with_merge, m)?)?;
     m.add_function(wrap_pyfunction!(syllabify_with_merge_at, m)?)?;
     m.add_function(wrap_pyfunction!(has_diacritic, m)?)?;
@@ -59,5 +732,17 @@ fn grac(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(remove_diacritic_at, m)?)?;
     m.add_function(wrap_pyfunction!(add_acute_at, m)?)?;
     m.add_function(wrap_pyfunction!(to_monotonic, m)?)?;
+    m.add_function(wrap_pyfunction!(tokenize, m)?)?;
+    m.add_function(wrap_pyfunction!(convert_file, m)?)?;
+    m.add_function(wrap_pyfunction!(validate_polytonic, m)?)?;
+    m.add_function(wrap_pyfunction!(validate_monotonic, m)?)?;
+    m.add("GracError", m.py().get_type::<GracError>())?;
+    m.add("InvalidWordError", m.py().get_type::<InvalidWordError>())?;
+    m.add("PolytonicError", m.py().get_type::<PolytonicError>())?;
+    m.add("MonotonicError", m.py().get_type::<MonotonicError>())?;
+    m.add_class::<Syllables>()?;
+    m.add_class::<Syllabifier>()?;
+    m.add_class::<Hyphenator>()?;
+    m.add_class::<MonotonicOptions>()?;
     Ok(())
 }
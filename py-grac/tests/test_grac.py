@@ -0,0 +1,211 @@
+import asyncio
+import threading
+from pathlib import Path
+
+import pytest
+
+import grac
+
+
+def test_syllables_sequence():
+    syllables = grac.syllabify("καλημέρα")
+    assert len(syllables) == 4
+    assert syllables == ["κα", "λη", "μέ", "ρα"]
+    assert syllables[1] == "λη"
+    assert syllables[-1] == "ρα"
+    assert syllables[1:3] == ["λη", "μέ"]
+    assert syllables[::-2] == ["ρα", "λη"]
+    assert syllables[10:] == []
+    assert list(syllables) == ["κα", "λη", "μέ", "ρα"]
+    assert syllables.join() == "κα-λη-μέ-ρα"
+    with pytest.raises(IndexError):
+        syllables[4]
+
+
+def test_syllables_spans():
+    syllables = grac.syllabify("άνθρωπος")
+    assert syllables.spans == [(0, 2), (2, 5), (5, 8)]
+    assert syllables.utf16_spans == [(0, 2), (2, 5), (5, 8)]
+    assert syllables.byte_spans == [(0, 4), (4, 10), (10, 16)]
+    word = syllables.word
+    assert [word[start:end] for start, end in syllables.spans] == list(syllables)
+
+
+def test_syllabifier_context():
+    assert grac.syllabify("μια") == ["μια"]
+    with grac.Syllabifier(merge="never"):
+        assert grac.syllabify("μια") == ["μι", "α"]
+        with grac.Syllabifier(merge="every"):
+            assert grac.syllabify("μια") == ["μια"]
+        assert grac.syllabify("μια") == ["μι", "α"]
+    assert grac.syllabify("μια") == ["μια"]
+
+
+def test_exit_removes_its_own_options():
+    never = grac.Syllabifier(merge="never")
+    every = grac.Syllabifier(merge="every")
+    never.__enter__()
+    every.__enter__()
+    # Exited out of order: the options of `every` stay.
+    never.__exit__(None, None, None)
+    assert grac.syllabify("μια") == ["μια"]
+    every.__exit__(None, None, None)
+    assert grac.syllabify("μια") == ["μια"]
+
+
+def test_monotonic_options_context():
+    assert grac.to_monotonic("μοῦ") == "μου"
+    with grac.MonotonicOptions(keep_pronoun_accents=True):
+        assert grac.to_monotonic("μοῦ") == "μού"
+    assert grac.to_monotonic("μοῦ") == "μου"
+
+
+def test_context_is_not_shared_between_tasks():
+    keep = grac.MonotonicOptions(keep_pronoun_accents=True)
+
+    async def convert(options):
+        if options is None:
+            await asyncio.sleep(0.01)
+            return grac.to_monotonic("μοῦ")
+        with options:
+            await asyncio.sleep(0.02)
+            return grac.to_monotonic("μοῦ")
+
+    async def main():
+        return await asyncio.gather(convert(keep), convert(None), convert(keep))
+
+    assert asyncio.run(main()) == ["μού", "μου", "μού"]
+    assert grac.to_monotonic("μοῦ") == "μου"
+
+
+def test_context_is_not_shared_between_threads():
+    results = []
+    with grac.MonotonicOptions(keep_pronoun_accents=True):
+        thread = threading.Thread(target=lambda: results.append(grac.to_monotonic("μοῦ")))
+        thread.start()
+        thread.join()
+        assert grac.to_monotonic("μοῦ") == "μού"
+    assert results == ["μου"]
+
+
+def test_invalid_word_error():
+    with pytest.raises(grac.InvalidWordError) as info:
+        grac.try_syllabify("καλάx")
+    assert isinstance(info.value, grac.GracError)
+    assert info.value.word == "καλάx"
+    assert info.value.position == 4
+
+    with pytest.raises(grac.InvalidWordError) as info:
+        grac.try_syllabify("")
+    assert info.value.position == 0
+
+
+def test_polytonic_error():
+    grac.validate_polytonic("ἄνθρωπος")
+    with pytest.raises(grac.PolytonicError) as info:
+        grac.validate_polytonic("λὁγιͅ")
+    assert info.value.word == "λὁγιͅ"
+    assert info.value.kind == "MisplacedBreathing"
+    assert info.value.position == 1
+    assert info.value.errors == [("MisplacedBreathing", 1), ("MisplacedIotaSubscript", 3)]
+
+
+def test_monotonic_error():
+    grac.validate_monotonic("και το σπίτι")
+    with pytest.raises(grac.MonotonicError) as info:
+        grac.validate_monotonic("και τὸ σπίτι")
+    assert info.value.word == "τὸ"
+    assert (info.value.position, info.value.end) == (4, 6)
+    assert info.value.suggestion == "το"
+    assert isinstance(info.value.kind, str)
+
+
+def test_hyphenator():
+    hyphenator = grac.Hyphenator()
+    assert hyphenator.positions("καλημέρα") == [2, 4, 6]
+    assert hyphenator.inserted("καλημέρα") == "κα-λη-μέ-ρα"
+    assert hyphenator.inserted("καλημέρα", hyphen="=") == "κα=λη=μέ=ρα"
+    assert grac.Hyphenator(left=3, right=3).positions("καλημέρα") == [4]
+
+
+def test_hyphenator_wrap():
+    hyphenator = grac.Hyphenator()
+    assert hyphenator.wrap("καλημέρα", 5) == ("καλη-", "μέρα")
+    assert hyphenator.wrap("καλημέρα", 3) == ("κα-", "λημέρα")
+    assert hyphenator.wrap("καλημέρα", 2) is None
+
+
+def test_hyphenator_iterate():
+    hyphenator = grac.Hyphenator()
+    assert list(hyphenator.iterate("καλημέρα")) == [
+        ("καλημέ", "ρα"),
+        ("καλη", "μέρα"),
+        ("κα", "λημέρα"),
+    ]
+    assert list(hyphenator.iterate("και")) == []
+
+
+def test_hyphenator_unsupported_language():
+    with pytest.raises(ValueError):
+        grac.Hyphenator(lang="en")
+
+
+def test_convert_file(tmp_path):
+    src, dst = tmp_path / "in.txt", tmp_path / "out.txt"
+    src.write_text("Ἐν ἀρχῇ ἦν ὁ λόγος\n", encoding="utf-8")
+    grac.convert_file(src, dst)
+    assert dst.read_text(encoding="utf-8") == "Εν αρχή ην ο λόγος\n"
+
+
+def test_convert_file_xml(tmp_path):
+    src, dst = tmp_path / "in.xml", tmp_path / "out.xml"
+    src.write_text("<p>Ἐν ἀρχῇ</p>", encoding="utf-8")
+    grac.convert_file(src, dst, profile="monotonic-xml")
+    assert dst.read_text(encoding="utf-8") == "<p>Εν αρχή</p>"
+
+
+def test_convert_file_errors(tmp_path):
+    with pytest.raises(FileNotFoundError):
+        grac.convert_file(tmp_path / "missing.txt", tmp_path / "out.txt")
+    assert not (tmp_path / "out.txt").exists()
+    (tmp_path / "in.txt").write_text("ἦν", encoding="utf-8")
+    with pytest.raises(ValueError):
+        grac.convert_file(tmp_path / "in.txt", tmp_path / "out.txt", profile="polytonic")
+
+
+def test_convert_dir(tmp_path):
+    src, dst = tmp_path / "src", tmp_path / "dst"
+    (src / "sub").mkdir(parents=True)
+    (src / "a.txt").write_text("Ἐν ἀρχῇ\n", encoding="utf-8")
+    (src / "sub" / "b.txt").write_text("καὶ ὁ λόγος\n", encoding="utf-8")
+    (src / "c.md").write_text("ἦν\n", encoding="utf-8")
+    written = grac.convert_dir(src, dst)
+    assert written == [dst / "a.txt", dst / "sub" / "b.txt"]
+    assert (dst / "a.txt").read_text(encoding="utf-8") == "Εν αρχή\n"
+    assert (dst / "sub" / "b.txt").read_text(encoding="utf-8") == "και ο λόγος\n"
+    assert not (dst / "c.md").exists()
+
+
+def test_version():
+    tomllib = pytest.importorskip("tomllib")
+    with open(Path(__file__).parents[1] / "Cargo.toml", "rb") as f:
+        manifest = tomllib.load(f)
+    assert isinstance(grac.__version__, str)
+    assert grac.__version__ == manifest["package"]["version"]
+
+
+def test_features():
+    features = grac.features()
+    assert isinstance(features, dict)
+    assert {"tei", "sync", "corpus-tools", "html", "wiktionary"} <= features.keys()
+    assert all(isinstance(enabled, bool) for enabled in features.values())
+    assert features["tei"]
+
+
+def test_build_info():
+    info = grac.build_info()
+    assert isinstance(info, dict)
+    assert {"version", "profile", "os", "arch"} <= info.keys()
+    assert all(isinstance(value, str) for value in info.values())
+    assert info["version"] == grac.__version__
+    assert info["profile"] in ("debug", "release")
@@ -0,0 +1,51 @@
+import pytest
+
+from grac.tokenizers import NLTKTokenizer, SpacyTokenizer, token_table
+
+
+def test_token_table():
+    assert token_table("Καλημέρα, κόσμε!  Τι;") == [
+        ("Καλημέρα", "", 0),
+        (",", " ", 8),
+        ("κόσμε", "", 10),
+        ("!", " ", 15),
+        (" ", "", 17),
+        ("Τι", "", 18),
+        (";", "", 20),
+    ]
+    assert token_table("") == []
+
+
+def test_token_table_round_trip():
+    text = "  Ο Γιάννης\tείπε:\n«Έλα!» "
+    rows = token_table(text)
+    assert "".join(token + whitespace for token, whitespace, _ in rows) == text
+    for token, _, idx in rows:
+        assert text[idx : idx + len(token)] == token
+
+
+def test_spacy_tokenizer():
+    spacy = pytest.importorskip("spacy")
+    nlp = spacy.blank("el")
+    nlp.tokenizer = SpacyTokenizer(nlp.vocab)
+    text = "Καλημέρα, κόσμε!  Τι;"
+    doc = nlp(text)
+    assert doc.text == text
+    assert [(token.text, token.whitespace_, token.idx) for token in doc] == token_table(text)
+
+
+def test_nltk_tokenizer():
+    tokenizer = NLTKTokenizer()
+    text = "Καλημέρα, κόσμε!"
+    assert tokenizer.tokenize(text) == ["Καλημέρα", ",", "κόσμε", "!"]
+    assert list(tokenizer.span_tokenize(text)) == [(0, 8), (8, 9), (10, 15), (15, 16)]
+    assert tokenizer.tokenize_sents(["α β", "γ"]) == [["α", "β"], ["γ"]]
+    assert list(tokenizer.span_tokenize_sents(["α β", "γ"])) == [[(0, 1), (2, 3)], [(0, 1)]]
+
+
+def test_nltk_tokenizer_spans_align():
+    util = pytest.importorskip("nltk.tokenize.util")
+    tokenizer = NLTKTokenizer()
+    text = "Ο Γιάννης είπε: «Έλα!»"
+    tokens = tokenizer.tokenize(text)
+    assert util.align_tokens(tokens, text) == list(tokenizer.span_tokenize(text))
@@ -306,6 +306,7 @@ def generate_lookup_synizesis(f: TextIO) -> None:
         "static LOOKUP: phf::Map<&'static str, &'static [&'static str]> = phf_map! {\n"
     )
 
+    # Only lowercase words: capitalized ones are folded by the lookup.
     mapping = {}
 
     for word in SYNIZESIS:
@@ -316,20 +317,12 @@ def generate_lookup_synizesis(f: TextIO) -> None:
         syllables = str(_syls).replace("'", '"')
         mapping[word] = syllables
 
-        _syls = [_syls[0].capitalize()] + _syls[1:]
-        syllables_cap = str(_syls).replace("'", '"')
-        mapping[word.capitalize()] = syllables_cap
-
     for words, accent_at in MERGE_AT:
         for word in words:
             _syls = syllabify_with_merge_at(word, accent_at)
             syllables = str(_syls).replace("'", '"')
             mapping[word] = syllables
 
-            _syls = [_syls[0].capitalize()] + _syls[1:]
-            syllables_cap = str(_syls).replace("'", '"')
-            mapping[word.capitalize()] = syllables_cap
-
     for fr, to in sorted(mapping.items(), key=lambda pair: sort_key(pair[0])):
         f.write(f'    "{fr}" => &{to},\n')
 
@@ -1,5 +1,9 @@
-use crate::syllabify::{is_vowel, syllabify};
-use unicode_normalization::UnicodeNormalization;
+use std::borrow::Cow;
+
+use crate::chars::graphemes_el;
+use crate::constants::is_monosyllable_accented;
+use crate::syllabify::{Merge, is_diphthong, is_vowel, syllabify, syllabify_with_merge};
+use unicode_normalization::{IsNormalized, UnicodeNormalization, is_nfc_quick};
 
 pub struct Diacritic;
 
@@ -65,6 +69,62 @@ where
     has_diacritic(word, Diacritic::ACUTE)
 }
 
+/// Check if the word has a circumflex (ῶ).
+///
+/// # Example
+///
+/// ```
+/// use grac::has_circumflex;
+///
+/// assert!(has_circumflex("δῶρον"));
+/// assert!(!has_circumflex("δώρον"));
+/// ```
+pub fn has_circumflex<I>(word: impl UnicodeNormalization<I>) -> bool
+where
+    I: Iterator<Item = char>,
+{
+    has_diacritic(word, Diacritic::CIRCUMFLEX)
+}
+
+/// Same as [`has_acute`] for a single char of the Greek and Coptic block (the
+/// tonos of monotonic), or a combining acute.
+pub(crate) const fn has_acute_char(ch: char) -> bool {
+    matches!(
+        ch,
+        '\u{0301}'
+            | '\u{0386}'
+            | '\u{0388}'..='\u{038A}'
+            | '\u{038C}'
+            | '\u{038E}'..='\u{0390}'
+            | '\u{03AC}'..='\u{03AF}'
+            | '\u{03B0}'
+            | '\u{03CC}'..='\u{03CE}'
+            | '\u{03D3}'
+    )
+}
+
+/// Same as [`has_diaeresis`] for a single char, usable in const contexts.
+pub(crate) const fn has_diaeresis_char(ch: char) -> bool {
+    matches!(
+        ch,
+        '\u{0308}'
+            | '\u{0344}'
+            | '\u{0390}'
+            | '\u{03AA}'
+            | '\u{03AB}'
+            | '\u{03B0}'
+            | '\u{03CA}'
+            | '\u{03CB}'
+            | '\u{03D4}'
+            | '\u{1FD2}'
+            | '\u{1FD3}'
+            | '\u{1FD7}'
+            | '\u{1FE2}'
+            | '\u{1FE3}'
+            | '\u{1FE7}'
+    )
+}
+
 /// Check if the word has any given diacritics.
 ///
 /// A word can be anything that implements [`unicode_normalization::UnicodeNormalization`]
@@ -92,6 +152,8 @@ where
 /// Return syllable positions where the given diacritic is found.
 ///
 /// The syllable position starts at one and is counted from the end of the word.
+/// Positions are sorted in increasing order, and appear once even if their
+/// syllable has the diacritic more than once.
 ///
 /// # Examples
 ///
@@ -102,8 +164,12 @@ where
 /// assert_eq!(diacritic_pos("άνθρωπός", Diacritic::ACUTE), [1, 3]);
 /// assert_eq!(diacritic_pos("τίποτα", Diacritic::GRAVE), []);
 /// ```
-pub fn diacritic_pos(s: &str, diacritic: char) -> Vec<usize> {
-    syllabify(s)
+pub fn diacritic_pos(s: impl AsRef<str>, diacritic: char) -> Vec<usize> {
+    diacritic_pos_syllables(&syllabify(s.as_ref()), diacritic)
+}
+
+pub(crate) fn diacritic_pos_syllables(syllables: &[&str], diacritic: char) -> Vec<usize> {
+    syllables
         .iter()
         .rev()
         .enumerate()
@@ -117,6 +183,90 @@ pub fn diacritic_pos(s: &str, diacritic: char) -> Vec<usize> {
         .collect()
 }
 
+/// Same as [`diacritic_pos`] for the circumflex.
+///
+/// # Example
+///
+/// ```
+/// use grac::circumflex_pos;
+///
+/// assert_eq!(circumflex_pos("σῶμα"), [2]);
+/// assert_eq!(circumflex_pos("σώμα"), []);
+/// ```
+pub fn circumflex_pos(s: impl AsRef<str>) -> Vec<usize> {
+    diacritic_pos(s, Diacritic::CIRCUMFLEX)
+}
+
+/// Same as [`diacritic_pos`], with the index of the character that has the
+/// diacritic inside its syllable.
+///
+/// The index counts user-perceived characters (cf. [`graphemes_el`]) and
+/// starts at zero. A syllable appears once per character with the diacritic.
+///
+/// # Examples
+///
+/// ```
+/// use grac::{diacritic_char_pos, Diacritic};
+///
+/// assert_eq!(diacritic_char_pos("άνθρωπος", Diacritic::ACUTE), [(3, 0)]);
+/// assert_eq!(diacritic_char_pos("ευλογία", Diacritic::ACUTE), [(2, 1)]);
+/// ```
+pub fn diacritic_char_pos(s: impl AsRef<str>, diacritic: char) -> Vec<(usize, usize)> {
+    syllabify(s.as_ref())
+        .iter()
+        .rev()
+        .enumerate()
+        .flat_map(|(index, syllable)| {
+            graphemes_el(syllable)
+                .enumerate()
+                .filter(move |(_, cluster)| has_diacritic(*cluster, diacritic))
+                .map(move |(idx, _)| (index + 1, idx))
+        })
+        .collect()
+}
+
+/// Diacritics of every syllable that has some, by syllable position.
+///
+/// The syllable position starts at one and is counted from the end of the word,
+/// and positions are sorted in increasing order. The diacritics of a syllable
+/// appear once each, in the order of the syllable in NFD.
+///
+/// This syllabifies and decomposes the word once, instead of once per call to
+/// [`diacritic_pos`].
+///
+/// # Examples
+///
+/// ```
+/// use grac::{diacritic_map, Diacritic};
+///
+/// assert_eq!(
+///     diacritic_map("ἄνθρωπος"),
+///     [(3, vec![Diacritic::SMOOTH, Diacritic::ACUTE])]
+/// );
+/// assert_eq!(
+///     diacritic_map("ᾄδεις"),
+///     [(2, vec![Diacritic::SMOOTH, Diacritic::ACUTE, Diacritic::IOTA_SUBSCRIPT])]
+/// );
+/// assert_eq!(diacritic_map("πάντοτε"), [(3, vec![Diacritic::ACUTE])]);
+/// assert!(diacritic_map("παντα").is_empty());
+/// ```
+pub fn diacritic_map(s: impl AsRef<str>) -> Vec<(usize, Vec<char>)> {
+    syllabify(s.as_ref())
+        .iter()
+        .rev()
+        .enumerate()
+        .filter_map(|(index, syllable)| {
+            let mut diacritics = Vec::new();
+            for ch in syllable.nfd() {
+                if ALL_DIACRITICS.contains(&ch) && !diacritics.contains(&ch) {
+                    diacritics.push(ch);
+                }
+            }
+            (!diacritics.is_empty()).then_some((index + 1, diacritics))
+        })
+        .collect()
+}
+
 /// Remove given diacritics.
 ///
 /// # Examples
@@ -129,12 +279,30 @@ pub fn diacritic_pos(s: &str, diacritic: char) -> Vec<usize> {
 /// let res  = "άνθρωπος εστι";
 /// assert_eq!(remove_diacritics(text, &diacritics), res);
 /// ```
-pub fn remove_diacritics(s: &str, diacritics: &[char]) -> String {
-    s.nfd()
+pub fn remove_diacritics(s: impl AsRef<str>, diacritics: &[char]) -> String {
+    remove_diacritics_impl(s.as_ref().chars(), diacritics)
+}
+
+/// Same as [`remove_diacritics`], for callers that already hold chars.
+///
+/// # Examples
+///
+/// ```
+/// use grac::{remove_diacritics_chars, Diacritic};
+///
+/// let chars = ['ἄ', 'ν', 'θ', 'ρ', 'ω', 'π', 'ο', 'ς'];
+/// assert_eq!(remove_diacritics_chars(&chars, &[Diacritic::SMOOTH]), "άνθρωπος");
+/// ```
+pub fn remove_diacritics_chars(chars: &[char], diacritics: &[char]) -> String {
+    remove_diacritics_impl(chars.iter().copied(), diacritics)
+}
+
+fn remove_diacritics_impl(chars: impl Iterator<Item = char>, diacritics: &[char]) -> String {
+    chars
+        .nfd()
         .filter(|ch| !diacritics.contains(ch))
-        .collect::<String>()
         .nfc()
-        .to_string()
+        .collect()
 }
 
 /// Remove all diacritics.
@@ -150,14 +318,57 @@ pub fn remove_diacritics(s: &str, diacritics: &[char]) -> String {
 ///             "την δ᾽ εγω ου λυσω: πριν μιν και γηρας επεισιν\n
 ///              ημετερω ενι οικω εν Αργει τηλοθι πατρης");
 /// ```
-pub fn remove_all_diacritics(s: &str) -> String {
+pub fn remove_all_diacritics(s: impl AsRef<str>) -> String {
     remove_diacritics(s, &ALL_DIACRITICS)
 }
 
-pub fn remove_acute(s: &str) -> String {
+/// Same as [`remove_all_diacritics`], borrowing the text when it has none.
+///
+/// # Examples
+///
+/// ```
+/// use std::borrow::Cow;
+///
+/// use grac::remove_all_diacritics_cow;
+///
+/// assert!(matches!(remove_all_diacritics_cow("καλημερα"), Cow::Borrowed(_)));
+/// assert_eq!(remove_all_diacritics_cow("καλημέρα"), "καλημερα");
+/// ```
+pub fn remove_all_diacritics_cow(s: &str) -> Cow<'_, str> {
+    // Not in NFC, the result would differ even without diacritics.
+    if is_nfc_quick(s.chars()) == IsNormalized::Yes && !has_any_diacritic(s) {
+        Cow::Borrowed(s)
+    } else {
+        Cow::Owned(remove_all_diacritics(s))
+    }
+}
+
+pub fn remove_acute(s: impl AsRef<str>) -> String {
     remove_diacritics(s, &[Diacritic::ACUTE])
 }
 
+/// Check if the syllable at the given position has the diacritic.
+///
+/// The syllable position starts at one and is counted from the end of the word,
+/// as in [`remove_diacritic_at`]. Out of bounds positions have no diacritics.
+///
+/// # Examples
+///
+/// ```
+/// use grac::{has_diacritic_at, Diacritic};
+///
+/// assert!(has_diacritic_at("άνθρωπος", 3, Diacritic::ACUTE));
+/// assert!(!has_diacritic_at("άνθρωπος", 1, Diacritic::ACUTE));
+/// assert!(!has_diacritic_at("άνθρωπος", 0, Diacritic::ACUTE));
+/// assert!(!has_diacritic_at("άνθρωπος", 4, Diacritic::ACUTE));
+/// ```
+pub fn has_diacritic_at(s: impl AsRef<str>, pos: usize, diacritic: char) -> bool {
+    let syllables = syllabify(s.as_ref());
+    pos.checked_sub(1)
+        .and_then(|pos| syllables.iter().rev().nth(pos))
+        .is_some_and(|syllable| has_diacritic(*syllable, diacritic))
+}
+
 /// Remove diacritic at the given syllable position.
 ///
 /// The syllable position starts at one and is counted from the end of the word.
@@ -171,11 +382,17 @@ pub fn remove_acute(s: &str) -> String {
 /// assert_eq!(remove_diacritic_at("άνθρωπέ", 2, Diacritic::ACUTE), "άνθρωπέ");
 /// assert_eq!(remove_diacritic_at("άνθρωπέ", 3, Diacritic::ACUTE), "ανθρωπέ");
 /// ```
-pub fn remove_diacritic_at(s: &str, pos: usize, diacritic: char) -> String {
-    let syllables = syllabify(s);
+pub fn remove_diacritic_at(s: impl AsRef<str>, pos: usize, diacritic: char) -> String {
+    remove_diacritic_at_syllables(&syllabify(s.as_ref()), pos, diacritic)
+}
 
+pub(crate) fn remove_diacritic_at_syllables(
+    syllables: &[&str],
+    pos: usize,
+    diacritic: char,
+) -> String {
     if pos == 0 || pos > syllables.len() {
-        s.to_string()
+        syllables.concat()
     } else {
         let idx = syllables.len() - pos;
         syllables
@@ -212,15 +429,21 @@ pub fn remove_diacritic_at(s: &str, pos: usize, diacritic: char) -> String {
 /// // May not yield the expected result based on syllabification
 /// assert_eq!(add_acute_at("σοι", 1), "σοί");
 /// ```
-pub fn add_acute_at(s: &str, pos: usize) -> String {
-    add_diacritic_at(s, pos, Diacritic::ACUTE)
+pub fn add_acute_at(s: impl AsRef<str>, pos: usize) -> String {
+    add_diacritic_at(s.as_ref(), pos, Diacritic::ACUTE)
 }
 
 fn add_diacritic_at(s: &str, pos: usize, diacritic: char) -> String {
-    let syllables = syllabify(s);
+    add_diacritic_at_syllables(&syllabify(s), pos, diacritic)
+}
 
+pub(crate) fn add_diacritic_at_syllables(
+    syllables: &[&str],
+    pos: usize,
+    diacritic: char,
+) -> String {
     if pos == 0 || pos > syllables.len() {
-        s.to_string()
+        syllables.concat()
     } else {
         let idx = syllables.len() - pos;
         syllables
@@ -237,6 +460,226 @@ fn add_diacritic_at(s: &str, pos: usize, diacritic: char) -> String {
     }
 }
 
+/// Turn the acute at the given syllable position into a circumflex.
+///
+/// The syllable position starts at one and is counted from the end of the word.
+///
+/// Does nothing if the syllable has no acute, or if its vowel can not take a
+/// circumflex (ε, ο). The changed character is returned in NFC.
+///
+/// # Examples
+///
+/// ```
+/// use grac::acute_to_circumflex_at;
+///
+/// assert_eq!(acute_to_circumflex_at("σώμα", 2), "σῶμα");
+/// assert_eq!(acute_to_circumflex_at("ἄνθρωποι", 3), "ἆνθρωποι");
+///
+/// // Only on the given syllable, and only if it can take a circumflex.
+/// assert_eq!(acute_to_circumflex_at("σώμα", 1), "σώμα");
+/// assert_eq!(acute_to_circumflex_at("λόγος", 2), "λόγος");
+/// ```
+pub fn acute_to_circumflex_at(s: impl AsRef<str>, pos: usize) -> String {
+    let syllables = syllabify(s.as_ref());
+    if pos == 0 || pos > syllables.len() {
+        return syllables.concat();
+    }
+    let idx = syllables.len() - pos;
+    let mut out = String::new();
+    for (i, syllable) in syllables.iter().enumerate() {
+        if i != idx {
+            out.push_str(syllable);
+            continue;
+        }
+        replace_diacritic_into(syllable, Diacritic::ACUTE, Diacritic::CIRCUMFLEX, &mut out);
+    }
+    out
+}
+
+/// Push `s` to `out`, replacing the diacritic `from` by `to`.
+///
+/// Characters whose replacement has no precomposed form are kept as they are,
+/// and the others are pushed in NFC.
+pub(crate) fn replace_diacritic_into(s: &str, from: char, to: char, out: &mut String) {
+    for cluster in graphemes_el(s) {
+        if !has_diacritic(cluster, from) {
+            out.push_str(cluster);
+            continue;
+        }
+        let replaced: String = cluster
+            .nfd()
+            .map(|ch| if ch == from { to } else { ch })
+            .nfc()
+            .collect();
+        if replaced.chars().count() == 1 {
+            out.push_str(&replaced);
+        } else {
+            out.push_str(cluster);
+        }
+    }
+}
+
+/// Remove diacritic from the character at the given index.
+///
+/// The index counts user-perceived characters (cf. [`graphemes_el`]) and
+/// starts at zero, so it is the same for NFC and NFD input.
+///
+/// # Examples
+///
+/// ```
+/// use grac::{remove_diacritic_at_char, Diacritic};
+///
+/// assert_eq!(remove_diacritic_at_char("ἄνθρωπος", 0, Diacritic::SMOOTH), "άνθρωπος");
+/// assert_eq!(remove_diacritic_at_char("ἄνθρωπος", 1, Diacritic::SMOOTH), "ἄνθρωπος");
+/// ```
+pub fn remove_diacritic_at_char(s: impl AsRef<str>, idx: usize, diacritic: char) -> String {
+    map_char_at(s.as_ref(), idx, |cluster| {
+        remove_diacritics(cluster, &[diacritic])
+    })
+}
+
+/// Add diacritic to the character at the given index.
+///
+/// The index counts user-perceived characters (cf. [`graphemes_el`]) and
+/// starts at zero, so it is the same for NFC and NFD input.
+///
+/// Does nothing if the character already has the diacritic, or if the result
+/// has no precomposed form.
+///
+/// # Examples
+///
+/// ```
+/// use grac::{add_diacritic_at_char, Diacritic};
+///
+/// assert_eq!(add_diacritic_at_char("ανθρωπος", 0, Diacritic::ROUGH), "ἁνθρωπος");
+/// assert_eq!(add_diacritic_at_char("ἁνθρωπος", 0, Diacritic::ACUTE), "ἅνθρωπος");
+///
+/// // Consonants do not take accents, and out of bounds indices are ignored.
+/// assert_eq!(add_diacritic_at_char("ανθρωπος", 1, Diacritic::ACUTE), "ανθρωπος");
+/// assert_eq!(add_diacritic_at_char("ανθρωπος", 8, Diacritic::ACUTE), "ανθρωπος");
+/// ```
+pub fn add_diacritic_at_char(s: impl AsRef<str>, idx: usize, diacritic: char) -> String {
+    map_char_at(s.as_ref(), idx, |cluster| {
+        if cluster.nfd().any(|ch| ch == diacritic) {
+            return cluster.to_string();
+        }
+        let composed: String = cluster.chars().chain([diacritic]).nfc().collect();
+        if composed.chars().count() == 1 {
+            composed
+        } else {
+            cluster.to_string()
+        }
+    })
+}
+
+/// Replace the `idx`-th user-perceived character of `s` by `f` of it.
+fn map_char_at(s: &str, idx: usize, f: impl FnOnce(&str) -> String) -> String {
+    let mut start = 0;
+    for (i, cluster) in graphemes_el(s).enumerate() {
+        if i == idx {
+            let end = start + cluster.len();
+            return format!("{}{}{}", &s[..start], f(cluster), &s[end..]);
+        }
+        start += cluster.len();
+    }
+    s.to_string()
+}
+
+/// Candidate accent placements for an unaccented word, most plausible first.
+///
+/// Following the three-syllable law, the accent goes on one of the last three
+/// syllables. Candidates are ranked by:
+/// 1. Placements that need no synizesis, before those that do (καρδιά).
+/// 2. Then from the antepenultimate to the final syllable.
+///
+/// Monosyllables are only accented if they are in
+/// [`MONOSYLLABLE_ACCENTED`](crate::constants::MONOSYLLABLE_ACCENTED), after
+/// the unaccented word.
+///
+/// Existing acute accents are ignored. To rank by the frequencies of a word
+/// list instead, see [`suggest_accented_forms_with_lexicon`](crate::lexicon::suggest_accented_forms_with_lexicon).
+///
+/// # Example
+///
+/// ```
+/// use grac::suggest_accented_forms;
+///
+/// assert_eq!(
+///     suggest_accented_forms("ανθρωπος"),
+///     ["άνθρωπος", "ανθρώπος", "ανθρωπός"]
+/// );
+/// assert_eq!(suggest_accented_forms("πως"), ["πως", "πώς"]);
+/// assert_eq!(suggest_accented_forms("για"), ["για"]);
+/// ```
+pub fn suggest_accented_forms(word: impl AsRef<str>) -> Vec<String> {
+    let word = remove_acute(word);
+    // Candidates with their rank: (needs synizesis, position from the antepenultimate)
+    let mut candidates: Vec<(String, (bool, usize))> = Vec::new();
+    let readings = [syllabify(&word), syllabify_with_merge(&word, Merge::Every)];
+    for (synizesis, syllables) in [false, true].into_iter().zip(readings) {
+        let mut push = |candidate: String, rank| {
+            if !candidates.iter().any(|(other, _)| *other == candidate) {
+                candidates.push((candidate, rank));
+            }
+        };
+        if syllables.len() == 1 {
+            push(word.clone(), (synizesis, 0));
+            let accented = add_diacritic_at_syllables(&syllables, 1, Diacritic::ACUTE);
+            if is_monosyllable_accented(&accented) {
+                push(accented, (synizesis, 1));
+            }
+            continue;
+        }
+        let n_positions = syllables.len().min(3);
+        for pos in (1..=n_positions).rev() {
+            let candidate = add_diacritic_at_syllables(&syllables, pos, Diacritic::ACUTE);
+            if candidate != word {
+                push(candidate, (synizesis, n_positions - pos));
+            }
+        }
+    }
+    if candidates.is_empty() {
+        return vec![word];
+    }
+    candidates.sort_by_key(|(_, rank)| *rank);
+    candidates
+        .into_iter()
+        .map(|(candidate, _)| candidate)
+        .collect()
+}
+
+/// Add the diaereses that the accent made superfluous, before removing it.
+///
+/// An accent on the first vowel of a diphthong (γάιδαρος, άυλος) tells that the
+/// vowels are pronounced apart. Without it, they would be read as a diphthong
+/// (γαιδαρος), so a diaeresis is needed: call this before removing accents or
+/// uppercasing. The word is returned in NFC.
+///
+/// # Example
+///
+/// ```
+/// use grac::{Diacritic, fix_required_diaeresis, remove_diacritics};
+///
+/// assert_eq!(fix_required_diaeresis("γάιδαρος"), "γάϊδαρος");
+/// assert_eq!(fix_required_diaeresis("παιδί"), "παιδί");
+///
+/// let fixed = fix_required_diaeresis("άυλος");
+/// let upper = remove_diacritics(&fixed, &[Diacritic::ACUTE]).to_uppercase();
+/// assert_eq!(upper, "ΑΫΛΟΣ");
+/// ```
+pub fn fix_required_diaeresis(word: impl AsRef<str>) -> String {
+    const ACCENTS: [char; 3] = [Diacritic::ACUTE, Diacritic::GRAVE, Diacritic::CIRCUMFLEX];
+    let mut chars: Vec<char> = word.as_ref().nfc().collect();
+    for idx in 1..chars.len() {
+        let (prev, ch) = (chars[idx - 1], chars[idx]);
+        if is_diphthong(prev, ch) && has_diacritics(prev, &ACCENTS) && !has_diacritics(ch, &ACCENTS)
+        {
+            chars[idx] = add_diacritic_to_char(ch, Diacritic::DIAERESIS);
+        }
+    }
+    chars.into_iter().collect()
+}
+
 /// Add diacritic to the first vowel from the end.
 ///
 /// This is not ideal and could not yield the expected result.
@@ -256,6 +699,77 @@ fn add_diacritic_to_char(ch: char, diacritic: char) -> char {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_has_diaeresis_char() {
+        for ch in ('\u{0300}'..='\u{03FF}').chain('\u{1F00}'..='\u{1FFF}') {
+            assert_eq!(has_diaeresis_char(ch), has_diaeresis(ch), "{ch}");
+        }
+    }
+
+    #[test]
+    fn test_fix_required_diaeresis() {
+        let cases = [
+            ("κακόυπνος", "κακόϋπνος"),
+            ("Λούις", "Λούϊς"),
+            ("ΆΥΛΟΣ", "ΆΫΛΟΣ"),
+            ("τρόλεϊ", "τρόλεϊ"),
+            ("φαΐ", "φαΐ"),
+            ("ευχαριστώ", "ευχαριστώ"),
+            ("σόι", "σόϊ"),
+            ("", ""),
+        ];
+        for (word, expected) in cases {
+            assert_eq!(fix_required_diaeresis(word), expected, "{word}");
+            let decomposed: String = word.nfd().collect();
+            assert_eq!(fix_required_diaeresis(decomposed), expected, "{word}");
+        }
+    }
+
+    #[test]
+    fn test_diacritic_at_char_decomposed() {
+        let word = "ἀνθρώπῳ";
+        let decomposed: String = word.nfd().collect();
+        for idx in 0..word.chars().count() {
+            for diacritic in [
+                Diacritic::ACUTE,
+                Diacritic::SMOOTH,
+                Diacritic::IOTA_SUBSCRIPT,
+            ] {
+                let removed: String = remove_diacritic_at_char(&decomposed, idx, diacritic);
+                assert_eq!(
+                    removed.nfc().collect::<String>(),
+                    remove_diacritic_at_char(word, idx, diacritic)
+                );
+                let added: String = add_diacritic_at_char(&decomposed, idx, diacritic);
+                assert_eq!(
+                    added.nfc().collect::<String>(),
+                    add_diacritic_at_char(word, idx, diacritic)
+                );
+            }
+        }
+        assert_eq!(
+            add_diacritic_at_char("τω", 1, Diacritic::IOTA_SUBSCRIPT),
+            "τῳ"
+        );
+    }
+
+    #[test]
+    fn test_suggest_accented_forms() {
+        assert_eq!(
+            suggest_accented_forms("καρδια"),
+            ["κάρδια", "καρδία", "καρδιά"]
+        );
+        // Monosyllables by synizesis
+        assert_eq!(suggest_accented_forms("ποιος"), ["ποιος"]);
+        assert_eq!(suggest_accented_forms("καλημερα")[1], "καλημέρα");
+        assert_eq!(
+            suggest_accented_forms("παιδια"),
+            ["παίδια", "παιδία", "παιδιά"]
+        );
+        assert_eq!(suggest_accented_forms("ν"), ["ν"]);
+        assert_eq!(suggest_accented_forms(""), [""]);
+    }
+
     #[test]
     fn test_add_acute() {
         assert_eq!(add_diacritic_to_char('α', Diacritic::ACUTE), 'ά');
@@ -274,6 +788,78 @@ mod tests {
     fn test_diacritics_pos() {
         assert_eq!(diacritic_pos("παϊδάκι", Diacritic::ACUTE), [2]);
         assert_eq!(diacritic_pos("παϊδάκι", Diacritic::DIAERESIS), [3]);
+        // Sorted and without duplicates
+        assert_eq!(diacritic_pos("ά\u{301}νθρωπός", Diacritic::ACUTE), [1, 3]);
+    }
+
+    #[test]
+    fn test_diacritic_map() {
+        let word = "παϊδάκι";
+        let map = diacritic_map(word);
+        assert_eq!(
+            map,
+            [(2, vec![Diacritic::ACUTE]), (3, vec![Diacritic::DIAERESIS])]
+        );
+        // Agrees with diacritic_pos, and with NFD input.
+        for diacritic in ALL_DIACRITICS {
+            let positions: Vec<usize> = map
+                .iter()
+                .filter(|(_, diacritics)| diacritics.contains(&diacritic))
+                .map(|(pos, _)| *pos)
+                .collect();
+            assert_eq!(positions, diacritic_pos(word, diacritic));
+        }
+        let decomposed: String = "ἄνθρωπός".nfd().collect();
+        assert_eq!(diacritic_map(&decomposed), diacritic_map("ἄνθρωπός"));
+        assert_eq!(diacritic_map("ά\u{301}"), [(1, vec![Diacritic::ACUTE])]);
+    }
+
+    #[test]
+    fn test_diacritic_char_pos() {
+        let decomposed: String = "ἄνθρωπός".nfd().collect();
+        assert_eq!(
+            diacritic_char_pos(&decomposed, Diacritic::ACUTE),
+            [(1, 1), (3, 0)]
+        );
+        assert_eq!(diacritic_char_pos("παϊδάκι", Diacritic::GRAVE), []);
+    }
+
+    #[test]
+    fn test_has_diacritic_at() {
+        let decomposed: String = "ἄνθρωπός".nfd().collect();
+        for word in ["ἄνθρωπός", &decomposed] {
+            for pos in 0..5 {
+                for diacritic in ALL_DIACRITICS {
+                    assert_eq!(
+                        has_diacritic_at(word, pos, diacritic),
+                        diacritic_pos(word, diacritic).contains(&pos),
+                        "{word} {pos} {diacritic}"
+                    );
+                }
+            }
+        }
+        // Removing it is then a no-op.
+        assert!(!has_diacritic_at("άνθρωπος", 2, Diacritic::ACUTE));
+        assert_eq!(
+            remove_diacritic_at("άνθρωπος", 2, Diacritic::ACUTE),
+            "άνθρωπος"
+        );
+    }
+
+    #[test]
+    fn test_acute_to_circumflex_at() {
+        assert_eq!(acute_to_circumflex_at("οἴκοι", 2), "οἶκοι");
+        assert_eq!(acute_to_circumflex_at("ᾄδω", 2), "ᾆδω");
+        assert_eq!(acute_to_circumflex_at("σώμα", 0), "σώμα");
+        assert_eq!(acute_to_circumflex_at("σώμα", 3), "σώμα");
+        // Decomposed input
+        let decomposed: String = "σώμα".nfd().collect();
+        assert_eq!(acute_to_circumflex_at(&decomposed, 2), "σῶμα");
+        // Toggling back
+        let word = acute_to_circumflex_at("σώμα", 2);
+        assert_eq!(circumflex_pos(&word), [2]);
+        let word = remove_diacritic_at(&word, 2, Diacritic::CIRCUMFLEX);
+        assert_eq!(add_acute_at(word, 2), "σώμα");
     }
 
     #[test]
@@ -0,0 +1,236 @@
+//! Memoized syllabification.
+//!
+//! Real corpora are extremely Zipfian: the top few thousand word forms cover
+//! most tokens, so caching the syllable boundaries of every seen word pays off
+//! once the cache is warm. Over `tests/fixtures/dump.txt` (`cargo bench cached`):
+//! * warm cache: ~1.3x faster than [`syllabify`](crate::syllabify)
+//! * cold cache: ~1.3x slower, since a third of the tokens are unique forms
+//!
+//! That is, reuse the same syllabifier across documents.
+//!
+//! The cache is thread-safe (`RwLock`) when the `sync` feature is enabled, and
+//! a cheaper `RefCell` otherwise.
+
+use std::collections::HashMap;
+use std::hash::{BuildHasherDefault, Hasher};
+
+use crate::syllabify::{Merge, Syllables, syllabify, syllabify_with_merge};
+
+#[cfg(feature = "sync")]
+type Inner<T> = std::sync::RwLock<T>;
+#[cfg(not(feature = "sync"))]
+type Inner<T> = std::cell::RefCell<T>;
+
+// Byte offsets of the syllable ends.
+type Boundaries = Box<[usize]>;
+type Map = HashMap<String, Boundaries, BuildHasherDefault<FxHasher>>;
+
+/// The rustc hasher: words are short and not attacker controlled, so the
+/// default `SipHash` costs more than the syllabification we want to skip.
+#[derive(Default)]
+struct FxHasher {
+    hash: u64,
+}
+
+impl FxHasher {
+    const SEED: u64 = 0x51_7c_c1_b7_27_22_0a_95;
+
+    #[inline]
+    const fn add(&mut self, word: u64) {
+        self.hash = (self.hash.rotate_left(5) ^ word).wrapping_mul(Self::SEED);
+    }
+}
+
+impl Hasher for FxHasher {
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        let mut chunks = bytes.chunks_exact(8);
+        for chunk in &mut chunks {
+            self.add(u64::from_le_bytes(chunk.try_into().unwrap()));
+        }
+        for &byte in chunks.remainder() {
+            self.add(u64::from(byte));
+        }
+    }
+
+    #[inline]
+    fn write_u8(&mut self, i: u8) {
+        self.add(u64::from(i));
+    }
+
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.hash
+    }
+}
+
+/// Syllabifier that remembers the words it has seen.
+///
+/// # Example
+///
+/// ```
+/// use grac::CachedSyllabifier;
+///
+/// let syllabifier = CachedSyllabifier::new();
+/// for word in ["άνθρωπος", "και", "άνθρωπος"] {
+///     let _ = syllabifier.syllabify(word);
+/// }
+/// assert_eq!(syllabifier.syllabify("αρρώστια").join("-"), "αρ-ρώ-στια");
+/// assert_eq!(syllabifier.len(), 3);
+/// ```
+pub struct CachedSyllabifier {
+    merge: Option<Merge>,
+    max_entries: usize,
+    cache: Inner<Map>,
+}
+
+impl Default for CachedSyllabifier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CachedSyllabifier {
+    /// Cache the results of [`syllabify`].
+    pub fn new() -> Self {
+        Self {
+            merge: None,
+            max_entries: usize::MAX,
+            cache: Inner::default(),
+        }
+    }
+
+    /// Cache the results of [`syllabify_with_merge`].
+    pub fn with_merge(merge: Merge) -> Self {
+        Self {
+            merge: Some(merge),
+            ..Self::new()
+        }
+    }
+
+    /// Stop caching new words after `max_entries` words.
+    ///
+    /// The most frequent forms are usually seen first, so a capped cache keeps
+    /// most of its hits: this bounds memory usage without the bookkeeping of
+    /// an LRU.
+    #[must_use]
+    pub const fn max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = max_entries;
+        self
+    }
+
+    /// Number of cached words.
+    pub fn len(&self) -> usize {
+        self.read(HashMap::len)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn clear(&self) {
+        self.write(HashMap::clear);
+    }
+
+    pub fn syllabify<'a>(&self, word: &'a str) -> Syllables<'a> {
+        if let Some(syllables) = self.read(|cache| {
+            cache
+                .get(word)
+                .map(|boundaries| split_at_boundaries(word, boundaries))
+        }) {
+            return syllables;
+        }
+
+        let syllables = match &self.merge {
+            None => syllabify(word),
+            Some(merge) => syllabify_with_merge(word, merge.clone()),
+        };
+        self.write(|cache| {
+            if cache.len() < self.max_entries {
+                cache.insert(word.to_string(), syllable_ends(&syllables));
+            }
+        });
+        syllables
+    }
+
+    #[cfg(feature = "sync")]
+    fn read<T>(&self, f: impl FnOnce(&Map) -> T) -> T {
+        f(&self
+            .cache
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner))
+    }
+
+    #[cfg(feature = "sync")]
+    fn write<T>(&self, f: impl FnOnce(&mut Map) -> T) -> T {
+        f(&mut self
+            .cache
+            .write()
+            .unwrap_or_else(std::sync::PoisonError::into_inner))
+    }
+
+    #[cfg(not(feature = "sync"))]
+    fn read<T>(&self, f: impl FnOnce(&Map) -> T) -> T {
+        f(&self.cache.borrow())
+    }
+
+    #[cfg(not(feature = "sync"))]
+    fn write<T>(&self, f: impl FnOnce(&mut Map) -> T) -> T {
+        f(&mut self.cache.borrow_mut())
+    }
+}
+
+pub(crate) fn syllable_ends(syllables: &[&str]) -> Boundaries {
+    let mut end = 0;
+    syllables
+        .iter()
+        .map(|syllable| {
+            end += syllable.len();
+            end
+        })
+        .collect()
+}
+
+pub(crate) fn split_at_boundaries<'a>(word: &'a str, boundaries: &[usize]) -> Syllables<'a> {
+    let mut start = 0;
+    boundaries
+        .iter()
+        .map(|&end| {
+            let syllable = &word[start..end];
+            start = end;
+            syllable
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cached_agrees_with_syllabify() {
+        let syllabifier = CachedSyllabifier::new();
+        let words = ["άνθρωπος", "Αγάλια", "στρες", "", "αστειάκια", "άνθρωπος"];
+        for _ in 0..2 {
+            for word in words {
+                assert_eq!(syllabifier.syllabify(word), syllabify(word));
+            }
+        }
+        assert_eq!(syllabifier.len(), 5);
+    }
+
+    #[test]
+    fn test_cached_with_merge() {
+        let syllabifier = CachedSyllabifier::with_merge(Merge::Every);
+        assert_eq!(syllabifier.syllabify("αστειάκια").join("-"), "α-στειά-κια");
+    }
+
+    #[test]
+    fn test_cached_max_entries() {
+        let syllabifier = CachedSyllabifier::new().max_entries(1);
+        let _ = syllabifier.syllabify("ένα");
+        let _ = syllabifier.syllabify("δύο");
+        assert_eq!(syllabifier.len(), 1);
+        assert_eq!(syllabifier.syllabify("δύο").join("-"), "δύ-ο");
+    }
+}
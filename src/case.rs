@@ -0,0 +1,560 @@
+//! Case conversions that follow Greek orthography.
+
+use std::collections::HashSet;
+use std::ops::Range;
+
+use unicode_normalization::UnicodeNormalization;
+use unicode_normalization::char::decompose_canonical;
+
+use crate::accents::{
+    Diacritic, add_diacritic_at_char, fix_required_diaeresis, has_diacritics, remove_diacritics,
+};
+use crate::chars::{base_lower, is_combining_mark, is_greek_char, to_upper_char};
+use crate::constants::is_stopword;
+use crate::tokenize::{TokenKind, tokenize};
+
+/// Punctuation after which a new sentence starts.
+pub(crate) const SENTENCE_END: [char; 7] = ['.', '!', ';', ':', '?', '\u{037E}', '…'];
+
+/// Diacritics dropped by ALL-CAPS: only the diaeresis is kept.
+const ALLCAPS_DROPPED: [char; 6] = [
+    Diacritic::ACUTE,
+    Diacritic::GRAVE,
+    Diacritic::CIRCUMFLEX,
+    Diacritic::SMOOTH,
+    Diacritic::ROUGH,
+    Diacritic::IOTA_SUBSCRIPT,
+];
+
+/// Accents of [`to_allcaps`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AccentStyle {
+    /// No accents: ΑΓΙΟΣ ΟΡΟΣ.
+    #[default]
+    Unaccented,
+    /// Keep the accent of a first letter, as a tonos: ΆΓΙΟΣ ΌΡΟΣ.
+    KeepInitial,
+}
+
+/// Options for [`titlecase_greek_with_options`].
+#[derive(Debug, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct TitlecaseOptions {
+    /// Write [stopwords](crate::constants::STOPWORDS) in lowercase, except at the
+    /// start of a sentence: "Ο Δρόμος για την Ιθάκη".
+    pub lowercase_stopwords: bool,
+}
+
+impl TitlecaseOptions {
+    pub const fn new() -> Self {
+        Self {
+            lowercase_stopwords: false,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_lowercase_stopwords(mut self, lowercase_stopwords: bool) -> Self {
+        self.lowercase_stopwords = lowercase_stopwords;
+        self
+    }
+}
+
+/// Capitalize the first letter of every word, as in headlines.
+///
+/// Only capitals of ALL-CAPS text drop their accent: the first letter keeps it
+/// (όταν > Όταν). Words starting with a digraph or a diphthong only capitalize
+/// their first letter, and the accent stays on the second one (μπαίνω > Μπαίνω,
+/// αύριο > Αύριο, εὐχή > Εὐχή). The other letters are left as they are.
+///
+/// # Example
+///
+/// ```
+/// use grac::titlecase_greek;
+///
+/// assert_eq!(titlecase_greek("όταν έρθει το αύριο"), "Όταν Έρθει Το Αύριο");
+/// assert_eq!(titlecase_greek("«ᾄδω» τὸν ἥλιο"), "«ᾌδω» Τὸν Ἥλιο");
+/// assert_eq!(titlecase_greek("η ομάδα του NATO"), "Η Ομάδα Του NATO");
+/// ```
+pub fn titlecase_greek(text: &str) -> String {
+    titlecase_greek_with_options(text, &TitlecaseOptions::new())
+}
+
+/// Same as [`titlecase_greek`], with [`TitlecaseOptions`].
+///
+/// # Example
+///
+/// ```
+/// use grac::{TitlecaseOptions, titlecase_greek_with_options};
+///
+/// let options = TitlecaseOptions::new().with_lowercase_stopwords(true);
+/// let title = titlecase_greek_with_options("ο δρόμος για την ιθάκη. και μετά η θάλασσα", &options);
+/// assert_eq!(title, "Ο Δρόμος για την Ιθάκη. Και μετά η Θάλασσα");
+/// ```
+pub fn titlecase_greek_with_options(text: &str, options: &TitlecaseOptions) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut sentence_start = true;
+    for token in tokenize(text) {
+        match token.kind {
+            TokenKind::Word => {
+                let lower = token.text.to_lowercase();
+                let nfc: String = lower.nfc().collect();
+                if options.lowercase_stopwords && !sentence_start && is_stopword(&nfc) {
+                    out.push_str(&lower);
+                } else {
+                    capitalize_first_into(token.text, &mut out);
+                }
+                sentence_start = false;
+            }
+            TokenKind::Punctuation => {
+                if token.text.ends_with(SENTENCE_END) {
+                    sentence_start = true;
+                }
+                out.push_str(token.text);
+            }
+            TokenKind::Whitespace => out.push_str(token.text),
+        }
+    }
+    out
+}
+
+/// Options for [`fix_sentence_capitalization_with_options`].
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub struct SentenceCaseOptions {
+    /// Capitalize the first word of every sentence.
+    pub capitalize_sentence_starts: bool,
+    /// Lowercase the capitalized words that start a line in the middle of a
+    /// sentence, as OCR often gives.
+    ///
+    /// Words that are also capitalized in the middle of a line and of a
+    /// sentence elsewhere in the text, like names, are kept.
+    pub lowercase_line_starts: bool,
+}
+
+impl SentenceCaseOptions {
+    pub const fn new() -> Self {
+        Self {
+            capitalize_sentence_starts: true,
+            lowercase_line_starts: true,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_capitalize_sentence_starts(mut self, capitalize: bool) -> Self {
+        self.capitalize_sentence_starts = capitalize;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_lowercase_line_starts(mut self, lowercase: bool) -> Self {
+        self.lowercase_line_starts = lowercase;
+        self
+    }
+}
+
+impl Default for SentenceCaseOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Check if a word followed by a period is an abbreviation: κ., π.χ.
+fn is_abbreviation(word: &str) -> bool {
+    word.contains('.') || word.chars().filter(|ch| ch.is_alphabetic()).count() == 1
+}
+
+/// Capitalize the start of every sentence, and lowercase the words that start
+/// a line in the middle of one.
+///
+/// Only words starting with a Greek letter are changed. Sentences end at
+/// `.`, `!`, `;` (Greek question mark), `?` and `…`, but not at the period of
+/// an abbreviation (π.χ., κ.). Words in capitals (ΝΑΤΟ) are kept.
+///
+/// # Example
+///
+/// ```
+/// use grac::fix_sentence_capitalization;
+///
+/// let text = "ήρθε το πρωί. Ο Γιάννης\nΕίπε ότι θα μείνει, π.χ. Αύριο;\nναι.";
+/// assert_eq!(
+///     fix_sentence_capitalization(text),
+///     "Ήρθε το πρωί. Ο Γιάννης\nείπε ότι θα μείνει, π.χ. Αύριο;\nΝαι."
+/// );
+/// ```
+pub fn fix_sentence_capitalization(text: &str) -> String {
+    fix_sentence_capitalization_with_options(text, &SentenceCaseOptions::new())
+}
+
+/// Same as [`fix_sentence_capitalization`], with [`SentenceCaseOptions`].
+///
+/// # Example
+///
+/// ```
+/// use grac::{SentenceCaseOptions, fix_sentence_capitalization_with_options};
+///
+/// let options = SentenceCaseOptions::new().with_lowercase_line_starts(false);
+/// let text = "ήρθε ο\nΓιάννης.";
+/// assert_eq!(fix_sentence_capitalization_with_options(text, &options), "Ήρθε ο\nΓιάννης.");
+/// ```
+pub fn fix_sentence_capitalization_with_options(
+    text: &str,
+    options: &SentenceCaseOptions,
+) -> String {
+    // Capitalized in the middle of a line and of a sentence, so probably names.
+    let names: HashSet<&str> = if options.lowercase_line_starts {
+        words_with_position(text)
+            .filter(|(word, sentence_start, line_start)| {
+                !sentence_start && !line_start && word.starts_with(char::is_uppercase)
+            })
+            .map(|(word, _, _)| word)
+            .collect()
+    } else {
+        HashSet::new()
+    };
+
+    let mut out = String::with_capacity(text.len());
+    let mut words = words_with_position(text);
+    for token in tokenize(text) {
+        if token.kind != TokenKind::Word {
+            out.push_str(token.text);
+            continue;
+        }
+        let (_, sentence_start, line_start) = words.next().unwrap_or_default();
+        let mut chars = token.text.chars();
+        let first = chars.next().filter(|ch| is_greek_char(*ch));
+        let rest = chars.as_str();
+        match first {
+            Some(_) if sentence_start && options.capitalize_sentence_starts => {
+                capitalize_first_into(token.text, &mut out);
+            }
+            Some(ch)
+                if !sentence_start
+                    && line_start
+                    && options.lowercase_line_starts
+                    && ch.is_uppercase()
+                    && !rest.contains(char::is_uppercase)
+                    && !names.contains(token.text) =>
+            {
+                out.extend(ch.to_lowercase());
+                out.push_str(rest);
+            }
+            _ => out.push_str(token.text),
+        }
+    }
+    out
+}
+
+/// Every word of the text, with whether it starts a sentence, and a line.
+fn words_with_position(text: &str) -> impl Iterator<Item = (&str, bool, bool)> {
+    let mut sentence_start = true;
+    let mut line_start = false;
+    let mut last_word = "";
+    tokenize(text).filter_map(move |token| match token.kind {
+        TokenKind::Word => {
+            let position = (token.text, sentence_start, line_start);
+            sentence_start = false;
+            line_start = false;
+            last_word = token.text;
+            Some(position)
+        }
+        TokenKind::Punctuation => {
+            let is_end = token.text.ends_with(SENTENCE_END) && !token.text.ends_with(':');
+            if is_end && !(token.text.starts_with('.') && is_abbreviation(last_word)) {
+                sentence_start = true;
+            }
+            None
+        }
+        TokenKind::Whitespace => {
+            line_start |= token.text.contains('\n');
+            None
+        }
+    })
+}
+
+/// Convert text to ALL-CAPS, where capitals drop their diacritics.
+///
+/// The diaeresis that an accent made superfluous is added back, since without
+/// the accent the vowels would be read as a diphthong (ρολόι > ΡΟΛΟΪ, cf.
+/// [`fix_required_diaeresis`]). Breathings and iota subscripts are dropped
+/// (ᾠδή > ΩΔΗ). The disjunctive ή always keeps its accent, to tell it apart
+/// from the article η. The text is returned in NFC.
+///
+/// # Example
+///
+/// ```
+/// use grac::{AccentStyle, to_allcaps};
+///
+/// assert_eq!(to_allcaps("Άγιος ρολόι", AccentStyle::Unaccented), "ΑΓΙΟΣ ΡΟΛΟΪ");
+/// assert_eq!(to_allcaps("Άγιος ρολόι", AccentStyle::KeepInitial), "ΆΓΙΟΣ ΡΟΛΟΪ");
+/// assert_eq!(to_allcaps("ἡ ᾠδή ἢ ἡ προσευχή", AccentStyle::Unaccented), "Η ΩΔΗ Ή Η ΠΡΟΣΕΥΧΗ");
+/// ```
+pub fn to_allcaps(text: &str, style: AccentStyle) -> String {
+    let mut out = String::with_capacity(text.len());
+    for token in tokenize(text) {
+        if token.kind == TokenKind::Word {
+            allcaps_word_into(token.text, style, &mut out);
+        } else {
+            out.extend(token.text.nfc());
+        }
+    }
+    out
+}
+
+fn allcaps_word_into(word: &str, style: AccentStyle, out: &mut String) {
+    const ACCENTS: [char; 3] = [Diacritic::ACUTE, Diacritic::GRAVE, Diacritic::CIRCUMFLEX];
+    let fixed = fix_required_diaeresis(word);
+    let is_disjunctive = matches!(fixed.as_str(), "ή" | "ὴ" | "ἤ" | "ἢ" | "Ή" | "Ἤ" | "Ἢ");
+    let keep_initial = is_disjunctive || style == AccentStyle::KeepInitial;
+    let mut caps = remove_diacritics(&fixed, &ALLCAPS_DROPPED);
+    if keep_initial
+        && fixed
+            .chars()
+            .next()
+            .is_some_and(|ch| has_diacritics(ch, &ACCENTS))
+    {
+        caps = add_diacritic_at_char(&caps, 0, Diacritic::ACUTE);
+    }
+    for ch in caps.chars() {
+        if is_greek_char(ch) {
+            out.push(to_upper_char(ch));
+        } else {
+            out.extend(ch.to_uppercase());
+        }
+    }
+}
+
+/// Maps byte offsets of text folded by [`fold_case_with_map`] back to the original.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CaseMap {
+    /// For every char that produced output: (folded offset, original offset).
+    starts: Vec<(usize, usize)>,
+    folded_len: usize,
+    original_len: usize,
+}
+
+impl CaseMap {
+    /// Byte offset in the original text of a byte offset in the folded text.
+    ///
+    /// Offsets inside a folded char are rounded down to its start, and offsets
+    /// past the end map to the end of the original text.
+    pub fn to_original(&self, offset: usize) -> usize {
+        if offset >= self.folded_len {
+            return self.original_len;
+        }
+        let idx = self.starts.partition_point(|(folded, _)| *folded <= offset);
+        self.starts[idx - 1].1
+    }
+
+    /// Span of the original text that was folded into a span of the folded text.
+    ///
+    /// The span includes the combining marks that were dropped after its last char.
+    pub fn span_to_original(&self, span: Range<usize>) -> Range<usize> {
+        self.to_original(span.start)..self.to_original(span.end)
+    }
+}
+
+/// Fold case and diacritics, for searching, and map the offsets back.
+///
+/// Greek letters lose their diacritics and final sigma becomes σ, so that ΆΝΘΡΩΠΟΣ,
+/// ἄνθρωπος and ανθρωπος fold to the same string. Since folding changes byte
+/// lengths, the [`CaseMap`] gives the span of the original text for a match in
+/// the folded text. Queries must be folded as well.
+///
+/// # Example
+///
+/// ```
+/// use grac::fold_case_with_map;
+///
+/// let text = "Ὁ ΆΝΘΡΩΠΟΣ";
+/// let (folded, map) = fold_case_with_map(text);
+/// assert_eq!(folded, "ο ανθρωποσ");
+///
+/// let (query, _) = fold_case_with_map("άνθρωπος");
+/// let start = folded.find(&query).unwrap();
+/// let span = map.span_to_original(start..start + query.len());
+/// assert_eq!(&text[span], "ΆΝΘΡΩΠΟΣ");
+/// ```
+pub fn fold_case_with_map(text: &str) -> (String, CaseMap) {
+    let mut folded = String::with_capacity(text.len());
+    let mut starts = Vec::new();
+    for (offset, ch) in text.char_indices() {
+        if is_combining_mark(ch) {
+            continue;
+        }
+        starts.push((folded.len(), offset));
+        // base_lower keeps some letters with diaeresis (ϊ).
+        let mut base = None;
+        decompose_canonical(base_lower(ch), |c| {
+            base.get_or_insert(c);
+        });
+        let base = base.unwrap_or(ch);
+        match base {
+            'ς' => folded.push('σ'),
+            _ => folded.extend(base.to_lowercase()),
+        }
+    }
+    let map = CaseMap {
+        starts,
+        folded_len: folded.len(),
+        original_len: text.len(),
+    };
+    (folded, map)
+}
+
+/// Capitalize the first char of a word, if it is a letter.
+fn capitalize_first_into(word: &str, out: &mut String) {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(ch) if is_greek_char(ch) => out.push(to_upper_char(ch)),
+        Some(ch) => out.extend(ch.to_uppercase()),
+        None => return,
+    }
+    out.push_str(chars.as_str());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_titlecase_greek() {
+        let cases = [
+            ("", ""),
+            ("όταν", "Όταν"),
+            ("ΑΥΡΙΟ", "ΑΥΡΙΟ"),
+            ("μπαίνω ντροπαλά", "Μπαίνω Ντροπαλά"),
+            ("ούτε αύριο", "Ούτε Αύριο"),
+            ("ᾀδή", "ᾈδή"),
+            ("ό,τι θέλεις", "Ό,τι Θέλεις"),
+            ("  ...ρόδα\n(ζάχαρη)", "  ...Ρόδα\n(Ζάχαρη)"),
+            ("élan vital", "Élan Vital"),
+        ];
+        for (text, expected) in cases {
+            assert_eq!(titlecase_greek(text), expected, "{text}");
+        }
+    }
+
+    #[test]
+    fn test_to_allcaps() {
+        let cases = [
+            ("", "", ""),
+            ("γάιδαρος", "ΓΑΪΔΑΡΟΣ", "ΓΑΪΔΑΡΟΣ"),
+            ("άυλος", "ΑΫΛΟΣ", "ΆΫΛΟΣ"),
+            ("παιδάκι", "ΠΑΙΔΑΚΙ", "ΠΑΙΔΑΚΙ"),
+            ("αύριο", "ΑΥΡΙΟ", "ΑΥΡΙΟ"),
+            ("Ήταν ή δεν ήταν;", "ΗΤΑΝ Ή ΔΕΝ ΗΤΑΝ;", "ΉΤΑΝ Ή ΔΕΝ ΉΤΑΝ;"),
+            ("ἄνθρωπος ἐστὶ", "ΑΝΘΡΩΠΟΣ ΕΣΤΙ", "ΆΝΘΡΩΠΟΣ ΕΣΤΙ"),
+            ("πρωτεΐνη", "ΠΡΩΤΕΪΝΗ", "ΠΡΩΤΕΪΝΗ"),
+            ("«σος» και straße", "«ΣΟΣ» ΚΑΙ STRASSE", "«ΣΟΣ» ΚΑΙ STRASSE"),
+        ];
+        for (text, unaccented, keep_initial) in cases {
+            assert_eq!(
+                to_allcaps(text, AccentStyle::Unaccented),
+                unaccented,
+                "{text}"
+            );
+            assert_eq!(
+                to_allcaps(text, AccentStyle::KeepInitial),
+                keep_initial,
+                "{text}"
+            );
+            let decomposed: String = text.nfd().collect();
+            assert_eq!(
+                to_allcaps(&decomposed, AccentStyle::Unaccented),
+                unaccented,
+                "{text}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_fold_case_with_map() {
+        let cases = [
+            ("", ""),
+            ("Ἄνθρωπος", "ανθρωποσ"),
+            ("ΐ Ϊ ῷ", "ι ι ω"),
+            ("Élan, ΣΟΦΌΣ!", "elan, σοφοσ!"),
+        ];
+        for (text, expected) in cases {
+            let (folded, _) = fold_case_with_map(text);
+            assert_eq!(folded, expected, "{text}");
+            let decomposed: String = text.nfd().collect();
+            let (folded, _) = fold_case_with_map(&decomposed);
+            assert_eq!(folded, expected, "{text}");
+        }
+    }
+
+    #[test]
+    fn test_case_map() {
+        let text: String = "ἄλφα Βῆτα".nfd().collect();
+        let (folded, map) = fold_case_with_map(&text);
+        assert_eq!(folded, "αλφα βητα");
+        let start = folded.find("βητα").unwrap();
+        assert_eq!(
+            &text[map.span_to_original(start..folded.len())],
+            "Βη\u{342}τα"
+        );
+        // Marks of the last char of the span are included.
+        assert_eq!(&text[map.span_to_original(0..2)], "α\u{313}\u{301}");
+        // Inside a char, and past the end.
+        assert_eq!(map.to_original(1), 0);
+        assert_eq!(map.to_original(100), text.len());
+    }
+
+    #[test]
+    fn test_fix_sentence_capitalization() {
+        let cases = [
+            ("", ""),
+            ("ναι. όχι! ίσως;", "Ναι. Όχι! Ίσως;"),
+            ("«ναι», είπε. «όχι»", "«Ναι», είπε. «Όχι»"),
+            (
+                "τα λεμόνια\nΚαι τα\n\nΠορτοκάλια.",
+                "Τα λεμόνια\nκαι τα\n\nπορτοκάλια.",
+            ),
+            ("το ΝΑΤΟ\nΝΑΤΟ και\nΟ", "Το ΝΑΤΟ\nΝΑΤΟ και\nο"),
+            ("είπε: ναι", "Είπε: ναι"),
+            ("κ. Παπαδόπουλος\nΤου είπε", "Κ. Παπαδόπουλος\nτου είπε"),
+            ("hello. élan", "hello. élan"),
+            // Names, capitalized elsewhere in the text
+            (
+                "είδα τον Γιάννη και τον\nΓιάννη. Ο Γιάννης\nΠου",
+                "Είδα τον Γιάννη και τον\nΓιάννη. Ο Γιάννης\nπου",
+            ),
+            ("ο\nΓιάννης. Γιάννης", "Ο\nγιάννης. Γιάννης"),
+            ("ᾄδω. ᾀδή", "ᾌδω. ᾈδή"),
+        ];
+        for (text, expected) in cases {
+            assert_eq!(fix_sentence_capitalization(text), expected, "{text}");
+        }
+        let options = SentenceCaseOptions::new().with_capitalize_sentence_starts(false);
+        assert_eq!(
+            fix_sentence_capitalization_with_options("ναι.\nόχι και\nΝαι", &options),
+            "ναι.\nόχι και\nναι"
+        );
+    }
+
+    #[test]
+    fn test_titlecase_greek_decomposed() {
+        let text: String = "ἥλιος ὅπου".nfd().collect();
+        let expected: String = "Ἥλιος Ὅπου".nfd().collect();
+        assert_eq!(titlecase_greek(&text), expected);
+    }
+
+    #[test]
+    fn test_titlecase_greek_stopwords() {
+        let options = TitlecaseOptions::new().with_lowercase_stopwords(true);
+        let cases = [
+            ("και ψωμί και αλάτι", "Και Ψωμί και Αλάτι"),
+            ("Ο Δρόμος Και Η Ζωή", "Ο Δρόμος και η Ζωή"),
+            ("ψωμί; στο φούρνο", "Ψωμί; Στο Φούρνο"),
+        ];
+        for (text, expected) in cases {
+            assert_eq!(
+                titlecase_greek_with_options(text, &options),
+                expected,
+                "{text}"
+            );
+        }
+    }
+}
@@ -7,7 +7,8 @@
 // Match statements usually try to benefit from some fast path.
 #![allow(clippy::match_same_arms)]
 
-use unicode_normalization::char::decompose_canonical;
+use unicode_normalization::UnicodeNormalization;
+use unicode_normalization::char::{compose, decompose_canonical};
 
 /// Check if a character is in the _Greek and Coptic_ range.
 ///
@@ -55,6 +56,22 @@ pub const fn is_greek_char(ch: char) -> bool {
     is_greek_and_coptic_char(ch) || is_greek_extended_char(ch)
 }
 
+/// Same as [`is_greek_char`], excluding the Coptic letters of the _Greek and
+/// Coptic_ range (Ϣ to ϯ).
+///
+/// # Example
+///
+/// ```
+/// use grac::{is_greek_char, is_greek_char_strict};
+///
+/// assert!(is_greek_char_strict('α'));
+/// assert!(is_greek_char('ϣ'));
+/// assert!(!is_greek_char_strict('ϣ'));
+/// ```
+pub const fn is_greek_char_strict(ch: char) -> bool {
+    is_greek_char(ch) && !matches!(ch, '\u{03E2}'..='\u{03EF}')
+}
+
 /// Check if a character is in the _Greek and Coptic_ or the _Greek Extended_ range.
 ///
 /// Excludes non alphabetic characters.
@@ -70,6 +87,34 @@ pub const fn is_greek_letter(ch: char) -> bool {
     }
 }
 
+/// Check if a character is an archaic letter of the _Greek and Coptic_ range.
+///
+/// These are heta (Ͱ), sampi (Ͳ, Ϡ), pamphylian digamma (Ͷ), yot (Ϳ), stigma (Ϛ),
+/// digamma (Ϝ), koppa (Ϙ, Ϟ) and san (Ϻ), in both cases.
+///
+/// # Example
+///
+/// ```
+/// use grac::is_archaic_letter;
+///
+/// assert!(is_archaic_letter('ϝ'));
+/// assert!(is_archaic_letter('Ϙ'));
+/// assert!(!is_archaic_letter('β'));
+/// ```
+pub const fn is_archaic_letter(ch: char) -> bool {
+    matches!(
+        ch,
+        '\u{0370}'..='\u{0373}'
+            | '\u{0376}'
+            | '\u{0377}'
+            | '\u{037F}'
+            | '\u{03F3}'
+            | '\u{03D8}'..='\u{03E1}'
+            | '\u{03FA}'
+            | '\u{03FB}'
+    )
+}
+
 /// Check if a word is Greek.
 ///
 /// Note that from the three common characters that represent apostrophe:
@@ -79,10 +124,178 @@ pub const fn is_greek_letter(ch: char) -> bool {
 ///
 /// the last one is the only considered alphabetic, and since it can appear
 /// as a possible (probably wrong) variant, it makes sense to include it here.
-pub fn is_greek_word(s: &str) -> bool {
+pub fn is_greek_word(s: impl AsRef<str>) -> bool {
+    s.as_ref().chars().all(is_greek_word_char)
+}
+
+/// Same as [`is_greek_word`], for callers that already hold chars.
+///
+/// # Examples
+///
+/// ```
+/// use grac::is_greek_word_chars;
+///
+/// assert!(is_greek_word_chars(&['λ', 'ό', 'γ', 'ο', 'ς']));
+/// assert!(!is_greek_word_chars(&['l', 'o', 'g', 'o', 's']));
+/// ```
+pub fn is_greek_word_chars(chars: &[char]) -> bool {
+    chars.iter().copied().all(is_greek_word_char)
+}
+
+/// Check if a char can be part of a Greek word.
+fn is_greek_word_char(ch: char) -> bool {
     // The order is important: is_greek_char is cheaper.
-    s.chars()
-        .all(|ch| is_greek_char(ch) || ch == '\u{02BC}' || !ch.is_alphabetic())
+    is_greek_char(ch) || ch == '\u{02BC}' || is_combining_mark(ch) || !ch.is_alphabetic()
+}
+
+/// Same as [`is_greek_word`], with [`is_greek_char_strict`]: words with Coptic
+/// letters are not Greek.
+///
+/// # Example
+///
+/// ```
+/// use grac::{is_greek_word, is_greek_word_strict};
+///
+/// assert!(is_greek_word_strict("καλημέρα"));
+/// assert!(is_greek_word("ϣαϫε"));
+/// assert!(!is_greek_word_strict("ϣαϫε"));
+/// ```
+pub fn is_greek_word_strict(s: impl AsRef<str>) -> bool {
+    s.as_ref().chars().all(|ch| {
+        is_greek_char_strict(ch) || ch == '\u{02BC}' || is_combining_mark(ch) || !ch.is_alphabetic()
+    })
+}
+
+/// Fraction of the letters of a text that are Greek (cf. [`is_greek_char`]),
+/// or zero if it has no letters.
+///
+/// # Example
+///
+/// ```
+/// use grac::greek_fraction;
+///
+/// assert_eq!(greek_fraction("Καλημέρα, 1821!"), 1.0);
+/// assert_eq!(greek_fraction("Ἔρως, love"), 0.5);
+/// assert_eq!(greek_fraction("..."), 0.0);
+/// ```
+pub fn greek_fraction(text: impl AsRef<str>) -> f32 {
+    let mut count = LetterCount::default();
+    count.add(text.as_ref().chars());
+    count.fraction()
+}
+
+/// Windows of the text read by [`greek_fraction_sampled`].
+const SAMPLE_WINDOWS: usize = 8;
+
+/// Same as [`greek_fraction`], reading at most `max_letters` letters, so that
+/// its cost does not depend on the length of the text.
+///
+/// The text is cut into eight parts, and up to an eighth of `max_letters` are
+/// read at the start of each, so that a Greek book with an English preface is
+/// still taken for Greek.
+///
+/// # Example
+///
+/// ```
+/// use grac::greek_fraction_sampled;
+///
+/// let text = "Preface.\n".repeat(10) + &"Κεφάλαιο πρώτο.\n".repeat(1000);
+/// assert!(greek_fraction_sampled(&text, 800) > 0.8);
+/// ```
+pub fn greek_fraction_sampled(text: impl AsRef<str>, max_letters: usize) -> f32 {
+    let text = text.as_ref();
+    let per_window = max_letters.div_ceil(SAMPLE_WINDOWS);
+    let mut bounds = [0; SAMPLE_WINDOWS + 1];
+    for (idx, bound) in bounds.iter_mut().enumerate() {
+        let mut offset = text.len() / SAMPLE_WINDOWS * idx;
+        if idx == SAMPLE_WINDOWS {
+            offset = text.len();
+        }
+        while !text.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        *bound = offset;
+    }
+    let mut count = LetterCount::default();
+    for window in bounds.windows(2) {
+        let letters = text[window[0]..window[1]]
+            .chars()
+            .filter(|ch| ch.is_alphabetic())
+            .take(per_window);
+        count.add(letters);
+    }
+    count.fraction()
+}
+
+#[derive(Default)]
+struct LetterCount {
+    greek: usize,
+    total: usize,
+}
+
+impl LetterCount {
+    fn add(&mut self, chars: impl Iterator<Item = char>) {
+        for ch in chars.filter(|ch| ch.is_alphabetic()) {
+            self.greek += usize::from(is_greek_char(ch));
+            self.total += 1;
+        }
+    }
+
+    fn fraction(&self) -> f32 {
+        if self.total == 0 {
+            return 0.0;
+        }
+        self.greek as f32 / self.total as f32
+    }
+}
+
+/// Check if a character is a combining diacritical mark (U+0300 to U+036F).
+pub(crate) const fn is_combining_mark(ch: char) -> bool {
+    matches!(ch, '\u{0300}'..='\u{036F}')
+}
+
+/// Check if a string has combining marks, that is, if it may not be in NFC.
+pub(crate) fn has_combining_marks(s: &str) -> bool {
+    // Combining marks start with these bytes in UTF-8
+    s.bytes().any(|byte| matches!(byte, 0xCC | 0xCD)) && s.chars().any(is_combining_mark)
+}
+
+/// Split a string into user-perceived characters: a base char followed by its combining marks.
+///
+/// Precomposed chars are clusters of their own, so that NFC and NFD input
+/// yield the same number of clusters. Combining marks at the start of the
+/// string form a cluster without a base.
+///
+/// # Example
+///
+/// ```
+/// use grac::graphemes_el;
+///
+/// let decomposed = "α\u{313}\u{301}νθρωπος";
+/// assert_eq!(graphemes_el(decomposed).next(), Some("α\u{313}\u{301}"));
+/// assert_eq!(graphemes_el(decomposed).count(), graphemes_el("ἄνθρωπος").count());
+/// ```
+pub fn graphemes_el(s: &str) -> impl Iterator<Item = &str> {
+    let mut rest = s;
+    std::iter::from_fn(move || {
+        let mut chars = rest.char_indices();
+        chars.next()?;
+        let end = chars
+            .find(|(_, ch)| !is_combining_mark(*ch))
+            .map_or(rest.len(), |(idx, _)| idx);
+        let (cluster, tail) = rest.split_at(end);
+        rest = tail;
+        Some(cluster)
+    })
+}
+
+/// Compose a base character with the combining marks that follow it.
+///
+/// Marks that can not be composed are ignored.
+pub(crate) fn compose_marks(base: char, marks: impl IntoIterator<Item = char>) -> char {
+    marks
+        .into_iter()
+        .fold(base, |acc, mark| compose(acc, mark).unwrap_or(acc))
 }
 
 /// Check if the word ends with a diphthong.
@@ -98,24 +311,66 @@ pub fn is_greek_word(s: &str) -> bool {
 /// assert_eq!(ends_with_diphthong("πλάι"), true);
 /// assert_eq!(ends_with_diphthong("γάιδαρος"), false);
 /// ```
-pub fn ends_with_diphthong(s: &str) -> bool {
-    const DIPHTHONGS: [&str; 12] = [
-        "όι", "Όι", "έι", "Έι", "άι", "Άι", "όυ", "Όυ", "έυ", "Έυ", "άυ", "Άυ",
+pub fn ends_with_diphthong(s: impl AsRef<str>) -> bool {
+    let s = s.as_ref();
+    if has_combining_marks(s) {
+        let composed: Vec<char> = s.nfc().collect();
+        return ends_with_diphthong_impl(composed.into_iter());
+    }
+    ends_with_diphthong_impl(s.chars())
+}
+
+/// Same as [`ends_with_diphthong`], for callers that already hold chars.
+///
+/// # Examples
+///
+/// ```
+/// use grac::ends_with_diphthong_chars;
+///
+/// assert_eq!(ends_with_diphthong_chars(&['π', 'λ', 'ά', 'ι']), true);
+/// ```
+pub fn ends_with_diphthong_chars(chars: &[char]) -> bool {
+    if chars.iter().copied().any(is_combining_mark) {
+        let composed: Vec<char> = chars.iter().copied().nfc().collect();
+        return ends_with_diphthong_impl(composed.into_iter());
+    }
+    ends_with_diphthong_impl(chars.iter().copied())
+}
+
+fn ends_with_diphthong_impl(chars: impl DoubleEndedIterator<Item = char>) -> bool {
+    const DIPHTHONGS: [(char, char); 12] = [
+        ('ό', 'ι'),
+        ('Ό', 'ι'),
+        ('έ', 'ι'),
+        ('Έ', 'ι'),
+        ('ά', 'ι'),
+        ('Ά', 'ι'),
+        ('ό', 'υ'),
+        ('Ό', 'υ'),
+        ('έ', 'υ'),
+        ('Έ', 'υ'),
+        ('ά', 'υ'),
+        ('Ά', 'υ'),
     ];
 
-    let vowels = extract_vowels(s);
-    DIPHTHONGS.iter().any(|&e| vowels.ends_with(e))
+    let mut vowels = extract_vowels(chars).rev();
+    match (vowels.next(), vowels.next()) {
+        (Some(last), Some(prev)) => DIPHTHONGS.contains(&(prev, last)),
+        _ => false,
+    }
 }
 
 /// Extract vowels from an assumed well formed lowercase syllable.
-fn extract_vowels(s: &str) -> String {
+fn extract_vowels(
+    chars: impl DoubleEndedIterator<Item = char>,
+) -> impl DoubleEndedIterator<Item = char> {
     const CONSONANTS: [char; 35] = [
         // Lowercase
         'β', 'γ', 'δ', 'ζ', 'θ', 'κ', 'λ', 'μ', 'ν', 'ξ', 'π', 'ρ', 'σ', 'ς', 'τ', 'φ', 'χ', 'ψ',
         // Uppercase
         'Β', 'Γ', 'Δ', 'Ζ', 'Θ', 'Κ', 'Λ', 'Μ', 'Ν', 'Ξ', 'Π', 'Ρ', 'Σ', 'Τ', 'Φ', 'Χ', 'Ψ',
     ];
-    s.chars().filter(|ch| !CONSONANTS.contains(ch)).collect()
+    chars.filter(|ch| !CONSONANTS.contains(ch))
 }
 
 /// Return the normalized character.
@@ -149,6 +404,154 @@ pub const fn base_lower(ch: char) -> char {
     }
 }
 
+/// Apply [`base_lower`] to every character of a string.
+///
+/// # Examples
+///
+/// ```
+/// use grac::base_lower_str;
+///
+/// assert_eq!(base_lower_str("Ἄνθρωπος, ΆΝΘΡΩΠΟΣ!"), "ανθρωπος, ανθρωποσ!");
+/// ```
+pub fn base_lower_str(s: impl AsRef<str>) -> String {
+    let s = s.as_ref();
+    let mut out = String::with_capacity(s.len());
+    base_lower_into(s, &mut out);
+    out
+}
+
+/// Same as [`base_lower_str`], but appends to `out` to reuse its allocation.
+///
+/// Since [`base_lower`] maps each char to exactly one char, that is never
+/// longer in UTF-8, reserving `s.len()` bytes is always enough.
+///
+/// # Examples
+///
+/// ```
+/// use grac::base_lower_into;
+///
+/// let mut buf = String::new();
+/// for word in ["Ἑλλάς", "κόσμος"] {
+///     buf.clear();
+///     base_lower_into(word, &mut buf);
+///     assert!(!buf.contains(['ά', 'ό']));
+/// }
+/// ```
+pub fn base_lower_into(s: impl AsRef<str>, out: &mut String) {
+    let s = s.as_ref();
+    out.reserve(s.len());
+    out.extend(s.chars().map(base_lower));
+}
+
+/// Capitalize a Greek (or ASCII) character, returning it unchanged if there is no mapping.
+///
+/// Covers monotonic and polytonic Greek (ἀ > Ἀ). Letters with iota subscript
+/// map to their titlecase form (ᾳ > ᾼ), instead of the two chars of
+/// [`char::to_uppercase`] (ΑΙ). Letters without a precomposed capital, like ᾶ
+/// or ΐ, are returned unchanged: use [`to_upper_str`] for those.
+///
+/// The result is never longer in UTF-8, so this is usable in const contexts
+/// (cf. [`with_capitalized`](crate::with_capitalized)).
+///
+/// # Example
+///
+/// ```
+/// use grac::to_upper_char;
+///
+/// assert_eq!(to_upper_char('ά'), 'Ά');
+/// assert_eq!(to_upper_char('ἀ'), 'Ἀ');
+/// assert_eq!(to_upper_char('ᾳ'), 'ᾼ');
+/// assert_eq!(to_upper_char('ῶ'), 'ῶ');
+/// ```
+pub const fn to_upper_char(ch: char) -> char {
+    const fn shift(ch: char, delta: i32) -> char {
+        match char::from_u32((ch as u32).wrapping_add_signed(delta)) {
+            Some(upper) => upper,
+            None => ch,
+        }
+    }
+
+    match ch {
+        'a'..='z' => ch.to_ascii_uppercase(),
+        'ͱ' | 'ͳ' | 'ͷ' | 'ϙ' | 'ϛ' | 'ϝ' | 'ϟ' | 'ϡ' | 'ϣ' | 'ϥ' | 'ϧ' | 'ϩ' | 'ϫ' | 'ϭ' | 'ϯ'
+        | 'ϸ' | 'ϻ' => shift(ch, -1),
+        'ͻ' | 'ͼ' | 'ͽ' => shift(ch, 130),
+        'ά' => shift(ch, -38),
+        'έ' | 'ή' | 'ί' => shift(ch, -37),
+        'α' | 'β' | 'γ' | 'δ' | 'ε' | 'ζ' | 'η' | 'θ' | 'ι' | 'κ' | 'λ' | 'μ' | 'ν' | 'ξ' | 'ο'
+        | 'π' | 'ρ' | 'σ' | 'τ' | 'υ' | 'φ' | 'χ' | 'ψ' | 'ω' | 'ϊ' | 'ϋ' => {
+            shift(ch, -32)
+        }
+        'ς' => shift(ch, -31),
+        'ό' => shift(ch, -64),
+        'ύ' | 'ώ' => shift(ch, -63),
+        'ϐ' => shift(ch, -62),
+        'ϑ' => shift(ch, -57),
+        'ϕ' => shift(ch, -47),
+        'ϖ' => shift(ch, -54),
+        'ϗ' => shift(ch, -8),
+        'ϰ' => shift(ch, -86),
+        'ϱ' => shift(ch, -80),
+        'ϲ' | 'ῥ' => shift(ch, 7),
+        'ϳ' => shift(ch, -116),
+        'ϵ' => shift(ch, -96),
+        'ἀ' | 'ἁ' | 'ἂ' | 'ἃ' | 'ἄ' | 'ἅ' | 'ἆ' | 'ἇ' | 'ἐ' | 'ἑ' | 'ἒ' | 'ἓ' | 'ἔ' | 'ἕ' | 'ἠ'
+        | 'ἡ' | 'ἢ' | 'ἣ' | 'ἤ' | 'ἥ' | 'ἦ' | 'ἧ' | 'ἰ' | 'ἱ' | 'ἲ' | 'ἳ' | 'ἴ' | 'ἵ' | 'ἶ'
+        | 'ἷ' | 'ὀ' | 'ὁ' | 'ὂ' | 'ὃ' | 'ὄ' | 'ὅ' | 'ὑ' | 'ὓ' | 'ὕ' | 'ὗ' | 'ὠ' | 'ὡ' | 'ὢ'
+        | 'ὣ' | 'ὤ' | 'ὥ' | 'ὦ' | 'ὧ' | 'ᾀ' | 'ᾁ' | 'ᾂ' | 'ᾃ' | 'ᾄ' | 'ᾅ' | 'ᾆ' | 'ᾇ' | 'ᾐ'
+        | 'ᾑ' | 'ᾒ' | 'ᾓ' | 'ᾔ' | 'ᾕ' | 'ᾖ' | 'ᾗ' | 'ᾠ' | 'ᾡ' | 'ᾢ' | 'ᾣ' | 'ᾤ' | 'ᾥ' | 'ᾦ'
+        | 'ᾧ' | 'ᾰ' | 'ᾱ' | 'ῐ' | 'ῑ' | 'ῠ' | 'ῡ' => shift(ch, 8),
+        'ὰ' | 'ά' => shift(ch, 74),
+        'ὲ' | 'έ' | 'ὴ' | 'ή' => shift(ch, 86),
+        'ὶ' | 'ί' => shift(ch, 100),
+        'ὸ' | 'ό' => shift(ch, 128),
+        'ὺ' | 'ύ' => shift(ch, 112),
+        'ὼ' | 'ώ' => shift(ch, 126),
+        'ᾳ' | 'ῃ' | 'ῳ' => shift(ch, 9),
+        // Prosgegrammeni
+        '\u{1FBE}' => 'Ι',
+        _ => ch,
+    }
+}
+
+/// Convert a string to uppercase, keeping its diacritics.
+///
+/// Greek letters are capitalized as by [`to_upper_char`], and letters without a
+/// precomposed capital keep their diacritics as combining marks (ῶ > Ω͂).
+/// Other chars are converted with [`char::to_uppercase`]. Use
+/// [`to_allcaps`](crate::to_allcaps) to drop the accents instead.
+///
+/// # Example
+///
+/// ```
+/// use grac::to_upper_str;
+///
+/// assert_eq!(to_upper_str("ἀλλὰ καί ᾠδή"), "ἈΛΛᾺ ΚΑΊ ᾨΔΉ");
+/// assert_eq!(to_upper_str("τῶν"), "ΤΩ\u{342}Ν");
+/// assert_eq!(to_upper_str("straße"), "STRASSE");
+/// ```
+pub fn to_upper_str(s: impl AsRef<str>) -> String {
+    let s = s.as_ref();
+    let mut out = String::with_capacity(s.len());
+    for ch in s.chars() {
+        if is_greek_char(ch) {
+            let upper = to_upper_char(ch);
+            if upper == ch && ch.is_lowercase() {
+                // No precomposed capital: capitalize the base char.
+                decompose_canonical(ch, |c| out.push(to_upper_char(c)));
+            } else {
+                out.push(upper);
+            }
+        } else if is_combining_mark(ch) {
+            // Not to_uppercase, that maps the iota subscript to Ι.
+            out.push(ch);
+        } else {
+            out.extend(ch.to_uppercase());
+        }
+    }
+    out.nfc().collect()
+}
+
 /// Normalize and cast to lowercase the _Greek and Coptic_ range.
 ///
 /// Does NOT normalize:  ';' | '·' | 'Ϊ' | 'Ϋ' | 'ϓ' | 'ϔ'
@@ -202,6 +605,18 @@ const fn base_lower_gc(ch: char) -> char {
         'Ύ' => 'υ',
         'Ώ' => 'ω',
 
+        // Archaic letters
+        'Ͱ' => 'ͱ',
+        'Ͳ' => 'ͳ',
+        'Ͷ' => 'ͷ',
+        'Ϳ' => 'ϳ',
+        'Ϙ' => 'ϙ',
+        'Ϛ' => 'ϛ',
+        'Ϝ' => 'ϝ',
+        'Ϟ' => 'ϟ',
+        'Ϡ' => 'ϡ',
+        'Ϻ' => 'ϻ',
+
         // Diereses && punctuation
         'ϊ' | 'ΐ' => 'ι',
         'ϋ' | 'ΰ' => 'υ',
@@ -252,6 +667,36 @@ const fn base_lower_ge(ch: char) -> char {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_greek_fraction() {
+        let english = include_str!("../tests/fixtures/english.txt");
+        let polytonic = include_str!("../tests/fixtures/polytonic.txt");
+        assert!(greek_fraction(english) < 0.05);
+        assert!(greek_fraction(polytonic) > 0.95);
+        for max_letters in [1, 100, 1000] {
+            assert!(greek_fraction_sampled(english, max_letters) < 0.05);
+            assert!(greek_fraction_sampled(polytonic, max_letters) > 0.9);
+        }
+        // Short texts are read entirely.
+        assert!((greek_fraction_sampled("Ἔρως, love", 100) - 0.5).abs() < f32::EPSILON);
+        assert!(greek_fraction_sampled("", 100).abs() < f32::EPSILON);
+        assert!(greek_fraction_sampled("αβγ", 0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_graphemes_el() {
+        let clusters: Vec<&str> = graphemes_el("\u{301}ᾳ\u{313}ι").collect();
+        assert_eq!(clusters, ["\u{301}", "ᾳ\u{313}", "ι"]);
+        assert_eq!(graphemes_el("").count(), 0);
+
+        let word = "ᾯ τῇ ψυχῇ";
+        let decomposed: String = word.nfd().collect();
+        let composed: Vec<String> = graphemes_el(&decomposed)
+            .map(|g| g.nfc().collect())
+            .collect();
+        assert_eq!(composed, graphemes_el(word).collect::<Vec<_>>());
+    }
+
     fn dbg_test(ch: char, received: char, expected: char) {
         if received != expected {
             println!(
@@ -266,13 +711,64 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_to_upper_char() {
+        for ch in ('\u{0370}'..='\u{03FF}').chain('\u{1F00}'..='\u{1FFF}') {
+            let mut upper = ch.to_uppercase();
+            if let (Some(expected), None) = (upper.next(), upper.next()) {
+                assert_eq!(to_upper_char(ch), expected, "{ch}");
+            }
+            assert!(to_upper_char(ch).len_utf8() <= ch.len_utf8());
+        }
+        assert_eq!(to_upper_char('ᾳ'), 'ᾼ');
+        assert_eq!(to_upper_char('ᾀ'), 'ᾈ');
+        assert_eq!(to_upper_char('ΐ'), 'ΐ');
+    }
+
+    #[test]
+    fn test_to_upper_str() {
+        let cases = [
+            ("", ""),
+            ("ᾶ ᾷ ὐ", "Α\u{342} ᾼ\u{342} Υ\u{313}"),
+            ("ΐ", "Ϊ\u{301}"),
+            ("σοφός;", "ΣΟΦΌΣ;"),
+            ("ΑΒΓ 123", "ΑΒΓ 123"),
+        ];
+        for (text, expected) in cases {
+            let expected: String = expected.nfc().collect();
+            assert_eq!(to_upper_str(text), expected, "{text}");
+            let decomposed: String = text.nfd().collect();
+            assert_eq!(to_upper_str(&decomposed), expected, "{text}");
+        }
+    }
+
+    #[test]
+    fn test_base_lower_str_never_grows() {
+        let s: String = ('\u{0370}'..='\u{03FF}')
+            .chain('\u{1F00}'..='\u{1FFF}')
+            .collect();
+        let lowered = base_lower_str(&s);
+        assert!(lowered.len() <= s.len());
+        assert_eq!(lowered.chars().count(), s.chars().count());
+    }
+
+    #[test]
+    fn test_chars_variants_agree() {
+        for word in ["Κάιν", "πλάι", "γάιδαρος", "σόι", "Άυλος", "α", "", "hello"]
+        {
+            let chars: Vec<char> = word.chars().collect();
+            assert_eq!(ends_with_diphthong(word), ends_with_diphthong_chars(&chars));
+            assert_eq!(is_greek_word(word), is_greek_word_chars(&chars));
+        }
+        assert!(is_greek_word(String::from("λόγος")));
+    }
+
     #[test]
     fn test_base_lower_greek_coptic() {
         for ch in '\u{0370}'..='\u{03FF}' {
             match ch {
-                ';' | '·' | 'Ϊ' | 'Ϋ' | 'ϓ' | 'ϔ' | 'Ͱ' | 'Ͳ' | 'ʹ' | 'Ͷ' | 'Ϳ' | 'Ϗ' | 'Ϙ'
-                | 'Ϝ' | 'Ϛ' | 'Ϟ' | 'Ϡ' | 'Ϣ' | 'Ϥ' | 'Ϧ' | 'Ϩ' | 'Ϫ' | 'Ϭ' | 'Ϯ' | 'ϴ' | 'Ϸ'
-                | 'Ϲ' | 'Ϻ' | 'Ͻ' | 'Ͼ' | 'Ͽ' => continue,
+                ';' | '·' | 'Ϊ' | 'Ϋ' | 'ϓ' | 'ϔ' | 'ʹ' | 'Ϗ' | 'Ϣ' | 'Ϥ' | 'Ϧ' | 'Ϩ' | 'Ϫ'
+                | 'Ϭ' | 'Ϯ' | 'ϴ' | 'Ϸ' | 'Ϲ' | 'Ͻ' | 'Ͼ' | 'Ͽ' => continue,
                 _ => (),
             }
             let expected = __base_lower(ch);
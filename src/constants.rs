@@ -30,6 +30,43 @@ pub const MONOSYLLABLE_ACCENTED_WITH_PRONOUNS: [&str; 28] = conc!(
     with_capitalized!(["μού", "μάς", "τού", "τής", "τούς", "τών", "σού", "σάς"])
 );
 
+/// Monosyllables from which we want to remove the accent.
+///
+/// These have an accent in polytonic that conflicts with our syllabify logic.
+/// We need to store them separatedly to treat them as monosyllables with no accent.
+/// Ex. πιὸ ταπεινά > πιό ταπεινά // Expected: πιο ταπεινά
+#[rustfmt::skip]
+pub const MONOSYL_REMOVE_ACCENT: [&str; 32] = with_capitalized!([
+    "πιό", "πιά", "μιά", "μιάς", "γιά", "γειά",
+    // πιώ
+    "πιώ",
+    "πίεις", "πίη", "πιή", "πίει", "πιεί", "πίης", "πιής",
+    "πιούν", "πιές",
+]);
+
+/// Function words: articles, weak pronouns, particles, conjunctions and prepositions.
+///
+/// In monotonic, and capitalized (Και) as well as lowercase.
+#[rustfmt::skip]
+pub const STOPWORDS: [&str; 160] = with_capitalized!([
+    // Articles
+    "ο", "η", "το", "οι", "τα", "του", "της", "των", "τον", "την", "τους", "τις", "ένας",
+    "μια", "μία", "ένα", "ενός", "μιας", "έναν",
+    // Weak pronouns (the ones that are not also articles)
+    "μου", "σου", "μας", "σας", "με", "σε",
+    // Personal and demonstrative pronouns
+    "εγώ", "εσύ", "αυτός", "αυτή", "αυτό", "αυτοί", "αυτές", "αυτά", "αυτού", "αυτής",
+    "αυτών", "αυτόν", "αυτήν", "αυτούς",
+    // Particles
+    "να", "θα", "ας", "δεν", "δε", "μην", "μη",
+    // Conjunctions
+    "και", "κι", "ή", "ούτε", "αλλά", "όμως", "ενώ", "αν", "εάν", "ότι", "πως", "που",
+    "γιατί", "επειδή", "όταν", "ώστε",
+    // Prepositions and their contractions
+    "στο", "στη", "στην", "στον", "στα", "στους", "στις", "στου", "στης", "από", "για",
+    "προς", "χωρίς", "κατά", "μετά", "παρά", "αντί", "ως",
+]);
+
 /// Words with multiple accepted pronunciations.
 ///
 /// Only contains words with accent not on the last syllable,
@@ -78,3 +115,86 @@ pub const MULTIPLE_PRONUNCIATION: [&str; 76] = [
     "φυλάκια", "Φυλάκια", 
     "ουράνια", "Ουράνια", 
 ];
+
+// Sorted copies for binary search: these are queried for every word by to_monotonic.
+const MONOSYLLABLE_ACCENTED_SORTED: &[&str] = &dedup_sorted!(MONOSYLLABLE_ACCENTED);
+const MONOSYLLABLE_ACCENTED_WITH_PRONOUNS_SORTED: &[&str] =
+    &dedup_sorted!(MONOSYLLABLE_ACCENTED_WITH_PRONOUNS);
+const MONOSYL_REMOVE_ACCENT_SORTED: &[&str] = &dedup_sorted!(MONOSYL_REMOVE_ACCENT);
+const MULTIPLE_PRONUNCIATION_SORTED: &[&str] = &dedup_sorted!(MULTIPLE_PRONUNCIATION);
+const STOPWORDS_SORTED: &[&str] = &dedup_sorted!(STOPWORDS);
+
+/// Check if the word is in [`MONOSYLLABLE_ACCENTED`].
+pub fn is_monosyllable_accented(word: &str) -> bool {
+    MONOSYLLABLE_ACCENTED_SORTED.binary_search(&word).is_ok()
+}
+
+/// Check if the word is in [`MONOSYLLABLE_ACCENTED_WITH_PRONOUNS`].
+pub fn is_monosyllable_accented_with_pronouns(word: &str) -> bool {
+    MONOSYLLABLE_ACCENTED_WITH_PRONOUNS_SORTED
+        .binary_search(&word)
+        .is_ok()
+}
+
+/// Check if the word is in [`MONOSYL_REMOVE_ACCENT`].
+pub fn is_monosyllable_remove_accent(word: &str) -> bool {
+    MONOSYL_REMOVE_ACCENT_SORTED.binary_search(&word).is_ok()
+}
+
+/// Check if the word is in [`MULTIPLE_PRONUNCIATION`].
+///
+/// # Example
+///
+/// ```
+/// use grac::constants::has_multiple_pronunciations;
+///
+/// assert!(has_multiple_pronunciations("Άγιος"));
+/// assert!(!has_multiple_pronunciations("άνθρωπος"));
+/// ```
+pub fn has_multiple_pronunciations(word: &str) -> bool {
+    MULTIPLE_PRONUNCIATION_SORTED.binary_search(&word).is_ok()
+}
+
+/// Check if the word is in [`STOPWORDS`].
+///
+/// # Example
+///
+/// ```
+/// use grac::constants::is_stopword;
+///
+/// assert!(is_stopword("Και"));
+/// assert!(is_stopword("στην"));
+/// assert!(!is_stopword("σπίτι"));
+/// ```
+pub fn is_stopword(word: &str) -> bool {
+    STOPWORDS_SORTED.binary_search(&word).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Predicate = fn(&str) -> bool;
+
+    #[test]
+    fn test_predicates_agree_with_lists() {
+        let cases: [(&[&str], Predicate); 5] = [
+            (&MONOSYLLABLE_ACCENTED, is_monosyllable_accented),
+            (
+                &MONOSYLLABLE_ACCENTED_WITH_PRONOUNS,
+                is_monosyllable_accented_with_pronouns,
+            ),
+            (&MONOSYL_REMOVE_ACCENT, is_monosyllable_remove_accent),
+            (&MULTIPLE_PRONUNCIATION, has_multiple_pronunciations),
+            (&STOPWORDS, is_stopword),
+        ];
+        for (list, predicate) in cases {
+            for word in list {
+                assert!(predicate(word), "{word}");
+            }
+            for word in ["", "και", "ΠΟΥ", "πως"] {
+                assert_eq!(predicate(word), list.contains(&word), "{word}");
+            }
+        }
+    }
+}
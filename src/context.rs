@@ -0,0 +1,424 @@
+//! Shared configuration.
+
+use std::collections::HashMap;
+use std::ops::Range;
+use std::sync::Arc;
+
+use crate::accents::{
+    Diacritic, add_diacritic_at_syllables, diacritic_pos_syllables, remove_diacritic_at_syllables,
+};
+use crate::cache::{split_at_boundaries, syllable_ends};
+use crate::exceptions::{EmbeddedTable, ExceptionTable, ExceptionTableError, check_synizesis};
+use crate::hyphenate::{HyphenationOptions, insert_hyphens, scored_hyphenation_points_by};
+use crate::lint::{Lint, lint_changes};
+use crate::monotonic::{Converter, MonotonicOptions};
+use crate::span::Change;
+use crate::syllabify::{
+    Merge, Syllables, folded_key, map_folded_syllables, syllabify, syllabify_with_merge,
+};
+use crate::tokenize::{Token, tokenize, words};
+
+#[derive(Clone, Default)]
+struct Config {
+    /// `None` stands for the default synizesis detection.
+    merge: Option<Merge>,
+    /// Byte offsets of the syllable ends of user words.
+    exceptions: HashMap<String, Box<[usize]>>,
+    /// Searched after `exceptions`, the last one first.
+    embedded: Vec<EmbeddedTable<'static>>,
+    monotonic: MonotonicOptions,
+}
+
+/// Configuration and exception tables, shared by every function.
+///
+/// Cloning is cheap (the configuration is behind an [`Arc`]) and the context is
+/// `Send + Sync`, so one instance can be built at startup and handed to every
+/// thread, instead of threading options through each call.
+///
+/// # Example
+///
+/// ```
+/// use grac::GracContext;
+///
+/// let ctx = GracContext::new().with_exception("ποιος", &["πο", "ιος"]);
+/// assert_eq!(ctx.syllabify("ποιος").join("-"), "πο-ιος");
+/// assert_eq!(ctx.syllabify("μια").join("-"), "μια");
+///
+/// let worker = ctx.clone();
+/// std::thread::spawn(move || assert_eq!(worker.to_monotonic("καὶ"), "και"))
+///     .join()
+///     .unwrap();
+/// ```
+#[derive(Clone, Default)]
+pub struct GracContext {
+    inner: Arc<Config>,
+}
+
+impl GracContext {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Use [`syllabify_with_merge`] instead of the default synizesis detection.
+    #[must_use]
+    pub fn with_merge(mut self, merge: Merge) -> Self {
+        Arc::make_mut(&mut self.inner).merge = Some(merge);
+        self
+    }
+
+    /// Use these options in [`to_monotonic`](Self::to_monotonic) and friends.
+    #[must_use]
+    pub fn with_monotonic_options(mut self, options: MonotonicOptions) -> Self {
+        Arc::make_mut(&mut self.inner).monotonic = options;
+        self
+    }
+
+    /// Always split `word` into the given syllables.
+    ///
+    /// Exceptions are matched against the whole word, without punctuation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the syllables do not concatenate to `word`.
+    #[must_use]
+    pub fn with_exception(self, word: &str, syllables: &[&str]) -> Self {
+        self.try_with_exception(word, syllables)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Same as [`with_exception`](Self::with_exception), with an error for
+    /// syllables that do not concatenate to `word`, or that are empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExceptionTableError::InvalidEntry`](crate::ExceptionTableError::InvalidEntry)
+    /// if the syllables are not a valid entry.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use grac::GracContext;
+    ///
+    /// assert!(GracContext::new().try_with_exception("μια", &["μι"]).is_err());
+    /// ```
+    pub fn try_with_exception(
+        mut self,
+        word: &str,
+        syllables: &[&str],
+    ) -> Result<Self, ExceptionTableError> {
+        check_synizesis(word, syllables)?;
+        Arc::make_mut(&mut self.inner)
+            .exceptions
+            .insert(word.to_string(), syllable_ends(syllables));
+        Ok(self)
+    }
+
+    /// Add the syllabifications and monotonic conversions of a table.
+    ///
+    /// The fields of a table are public, so that syllabifications that do not
+    /// concatenate to their word, or with an empty syllable, are skipped (cf.
+    /// [`ExceptionTable::try_with_synizesis`]).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use grac::{ExceptionTable, GracContext};
+    ///
+    /// let table = ExceptionTable::parse("grac-exceptions 1\n[special]\nποιὸς\tποιος\n").unwrap();
+    /// let ctx = GracContext::new().with_exception_table(&table);
+    /// assert_eq!(ctx.to_monotonic("«ποιὸς"), "«ποιος");
+    /// ```
+    #[must_use]
+    pub fn with_exception_table(mut self, table: &ExceptionTable) -> Self {
+        let config = Arc::make_mut(&mut self.inner);
+        for (word, syllables) in &table.synizesis {
+            let syllables: Vec<&str> = syllables.iter().map(String::as_str).collect();
+            if check_synizesis(word, &syllables).is_err() {
+                continue;
+            }
+            config
+                .exceptions
+                .insert(word.clone(), syllable_ends(&syllables));
+        }
+        config.monotonic.special_cases.extend(
+            table
+                .special_cases
+                .iter()
+                .map(|(word, monotonic)| (word.clone(), monotonic.clone())),
+        );
+        self
+    }
+
+    /// Add the syllabifications and monotonic conversions of an embedded table.
+    ///
+    /// Syllabifications are looked up in the table itself, that is never parsed.
+    /// Monotonic conversions are copied, since tables have few of them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use grac::{EmbeddedTable, ExceptionTable, GracContext};
+    ///
+    /// // Usually include_bytes!("exceptions.bin")
+    /// let bytes = ExceptionTable::new().with_synizesis("ποιος", &["πο", "ιος"]).to_bytes();
+    /// let table = EmbeddedTable::parse_bytes(bytes.leak()).unwrap();
+    /// let ctx = GracContext::new().with_embedded_table(table);
+    /// assert_eq!(ctx.syllabify("ποιος").join("-"), "πο-ιος");
+    /// ```
+    #[must_use]
+    pub fn with_embedded_table(mut self, table: EmbeddedTable<'static>) -> Self {
+        let config = Arc::make_mut(&mut self.inner);
+        config.monotonic.special_cases.extend(
+            table
+                .special_cases()
+                .map(|(word, monotonic)| (word.to_string(), monotonic.to_string())),
+        );
+        config.embedded.push(table);
+        self
+    }
+
+    /// Same as [`syllabify`], with the context's merge policy and exceptions.
+    ///
+    /// As in [`lookup_syllables`](crate::lookup_syllables), exceptions are
+    /// looked up ignoring case and combining marks, if the word itself is not
+    /// one.
+    pub fn syllabify<'a>(&self, s: &'a str) -> Syllables<'a> {
+        let folded = || {
+            let key = folded_key(s)?;
+            map_folded_syllables(s, &self.lookup_exception(&key)?)
+        };
+        if let Some(syllables) = self.lookup_exception(s).or_else(folded) {
+            return syllables;
+        }
+        match &self.inner.merge {
+            None => syllabify(s),
+            Some(merge) => syllabify_with_merge(s, merge.clone()),
+        }
+    }
+
+    fn lookup_exception<'a>(&self, s: &'a str) -> Option<Syllables<'a>> {
+        if let Some(boundaries) = self.inner.exceptions.get(s) {
+            return Some(split_at_boundaries(s, boundaries));
+        }
+        self.inner
+            .embedded
+            .iter()
+            .rev()
+            .find_map(|table| table.synizesis(s))
+            .map(|syllables| split_at_boundaries(s, &syllable_ends(&syllables)))
+    }
+
+    /// Same as [`syllabify_words`](crate::syllabify_words).
+    pub fn syllabify_words<'a>(
+        &self,
+        s: &'a str,
+    ) -> impl Iterator<Item = (Range<usize>, Syllables<'a>)> {
+        words(s).map(|(span, word)| (span, self.syllabify(word)))
+    }
+
+    /// Same as [`tokenize`](crate::tokenize), that no configuration affects.
+    pub fn tokenize<'a>(&self, s: &'a str) -> impl Iterator<Item = Token<'a>> + use<'a> {
+        tokenize(s)
+    }
+
+    /// Same as [`hyphenation_points`](crate::hyphenation_points).
+    pub fn hyphenation_points(&self, word: &str) -> Vec<usize> {
+        let syllabify = higher_ranked(|s| self.syllabify(s));
+        scored_hyphenation_points_by(word, &HyphenationOptions::new(), &syllabify)
+            .into_iter()
+            .map(|(offset, _)| offset)
+            .collect()
+    }
+
+    /// Same as [`hyphenate`](crate::hyphenate).
+    pub fn hyphenate(&self, word: &str, hyphen: &str) -> String {
+        insert_hyphens(word, hyphen, self.hyphenation_points(word))
+    }
+
+    /// Same as [`syllable_spans`](crate::syllable_spans).
+    pub fn syllable_spans(&self, s: &str) -> Vec<Range<usize>> {
+        let mut start = 0;
+        self.syllabify(s)
+            .iter()
+            .map(|syllable| {
+                start += syllable.len();
+                start - syllable.len()..start
+            })
+            .collect()
+    }
+
+    /// Same as [`to_monotonic`](crate::to_monotonic).
+    pub fn to_monotonic(&self, s: &str) -> String {
+        self.with_converter(|converter| converter.to_monotonic(s))
+    }
+
+    /// Same as [`monotonic_changes`](crate::monotonic_changes).
+    pub fn monotonic_changes(&self, s: &str) -> Vec<Change> {
+        self.with_converter(|converter| converter.changes(s))
+    }
+
+    /// Same as [`lint_monotonic`](crate::lint_monotonic).
+    pub fn lint_monotonic(&self, s: &str) -> Vec<Lint> {
+        lint_changes(self.monotonic_changes(s))
+    }
+
+    /// Same as [`diacritic_pos`](crate::diacritic_pos).
+    pub fn diacritic_pos(&self, s: &str, diacritic: char) -> Vec<usize> {
+        diacritic_pos_syllables(&self.syllabify(s), diacritic)
+    }
+
+    /// Same as [`remove_diacritic_at`](crate::remove_diacritic_at).
+    pub fn remove_diacritic_at(&self, s: &str, pos: usize, diacritic: char) -> String {
+        remove_diacritic_at_syllables(&self.syllabify(s), pos, diacritic)
+    }
+
+    /// Same as [`add_acute_at`](crate::add_acute_at).
+    pub fn add_acute_at(&self, s: &str, pos: usize) -> String {
+        add_diacritic_at_syllables(&self.syllabify(s), pos, Diacritic::ACUTE)
+    }
+
+    fn with_converter<T>(&self, f: impl FnOnce(&Converter) -> T) -> T {
+        let syllabify = higher_ranked(|s| self.syllabify(s));
+        f(&Converter {
+            syllabify: &syllabify,
+            options: &self.inner.monotonic,
+        })
+    }
+}
+
+// Helps closure inference with the lifetimes of `SyllabifyFn`.
+const fn higher_ranked<F: for<'a> Fn(&'a str) -> Syllables<'a>>(f: F) -> F {
+    f
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_context_is_send_sync() {
+        const fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<GracContext>();
+    }
+
+    #[test]
+    fn test_default_context_agrees() {
+        let ctx = GracContext::new();
+        let text = "Ὁ ἄνθρωπος πρᾶγμά τι εἶναι, γάϊδαρος.";
+        assert_eq!(ctx.to_monotonic(text), crate::to_monotonic(text));
+        assert_eq!(ctx.syllabify("αστειάκια"), syllabify("αστειάκια"));
+        assert_eq!(ctx.lint_monotonic(text), crate::lint_monotonic(text));
+    }
+
+    #[test]
+    fn test_context_exceptions() {
+        let ctx = GracContext::new().with_exception("ποιος", &["πο", "ιος"]);
+        assert_eq!(ctx.diacritic_pos("ποιος", Diacritic::ACUTE), []);
+        assert_eq!(ctx.add_acute_at("ποιος", 2), "πόιος");
+
+        // No longer a monosyllable
+        let ctx = ctx.with_exception("ζεί", &["ζε", "ί"]);
+        assert_eq!(ctx.to_monotonic("ζεί"), "ζεί");
+        assert_eq!(crate::to_monotonic("ζεί"), "ζει");
+    }
+
+    #[test]
+    fn test_context_exceptions_fold_case() {
+        let ctx = GracContext::new().with_exception("ποιος", &["πο", "ιος"]);
+        assert_eq!(ctx.syllabify("Ποιος").join("-"), "Πο-ιος");
+        assert_eq!(ctx.syllabify("ΠΟΙΟΣ").join("-"), "ΠΟ-ΙΟΣ");
+        assert_eq!(ctx.syllabify("ποιος").join("-"), "πο-ιος");
+        // An exception of the capitalized word wins.
+        let ctx = ctx.with_exception("Ποιος", &["Ποιος"]);
+        assert_eq!(ctx.syllabify("Ποιος").join("-"), "Ποιος");
+    }
+
+    #[test]
+    fn test_context_words() {
+        let ctx = GracContext::new().with_exception("ποιος", &["πο", "ιος"]);
+        assert_eq!(ctx.hyphenate("Ποιος", "-"), "Πο-ιος");
+        assert_eq!(crate::hyphenate("Ποιος", "-"), "Ποιος");
+        assert_eq!(ctx.hyphenation_points("«ποιος»"), [6]);
+
+        let text = "«Ποιος», είπε.";
+        let words: Vec<_> = ctx
+            .syllabify_words(text)
+            .map(|(span, syllables)| (span, syllables.join("-")))
+            .collect();
+        assert_eq!(
+            words,
+            [(2..12, "Πο-ιος".to_string()), (16..24, "εί-πε".to_string())]
+        );
+        assert!(ctx.tokenize(text).eq(crate::tokenize(text)));
+    }
+
+    #[test]
+    fn test_context_exception_table() {
+        let table = ExceptionTable::new()
+            .with_synizesis("ποιος", &["πο", "ιος"])
+            .with_special_case("πὼς", "πώς");
+        let ctx = GracContext::new().with_exception_table(&table);
+        assert_eq!(ctx.syllabify("ποιος").join("-"), "πο-ιος");
+        assert_eq!(ctx.to_monotonic("πὼς καὶ ποὺ"), "πώς και που");
+        assert_eq!(crate::to_monotonic("πὼς"), "πως");
+    }
+
+    #[test]
+    fn test_context_invalid_exception_table() {
+        let mut table = ExceptionTable::new().with_synizesis("ποιος", &["πο", "ιος"]);
+        table
+            .synizesis
+            .insert("αβ".to_string(), vec!["αβγ".to_string()]);
+        table
+            .synizesis
+            .insert("μια".to_string(), vec!["μια".to_string(), String::new()]);
+        let ctx = GracContext::new().with_exception_table(&table);
+        assert_eq!(ctx.syllabify("αβ").join("-"), "αβ");
+        assert_eq!(ctx.syllabify("μια").join("-"), "μια");
+        assert_eq!(ctx.syllabify("ποιος").join("-"), "πο-ιος");
+    }
+
+    #[test]
+    fn test_context_embedded_table() {
+        static BYTES: std::sync::LazyLock<Vec<u8>> = std::sync::LazyLock::new(|| {
+            ExceptionTable::new()
+                .with_synizesis("ποιος", &["πο", "ιος"])
+                .with_special_case("πὼς", "πώς")
+                .to_bytes()
+        });
+        let table = EmbeddedTable::parse_bytes(&BYTES).unwrap();
+        let ctx = GracContext::new()
+            .with_embedded_table(table)
+            .with_exception("μια", &["μι", "α"]);
+        assert_eq!(ctx.syllabify("ποιος").join("-"), "πο-ιος");
+        assert_eq!(ctx.syllabify("μια").join("-"), "μι-α");
+        assert_eq!(ctx.to_monotonic("πὼς"), "πώς");
+    }
+
+    #[test]
+    fn test_context_merge() {
+        let ctx = GracContext::new().with_merge(Merge::Never);
+        assert_eq!(ctx.syllabify("αστειάκια").join("-"), "α-στει-ά-κι-α");
+        // Cloning shares the configuration, and reconfiguring does not affect clones.
+        let other = ctx.clone().with_merge(Merge::Every);
+        assert_eq!(ctx.syllable_spans("μια").len(), 2);
+        assert_eq!(other.syllable_spans("μια").len(), 1);
+    }
+
+    #[test]
+    fn test_context_monotonic_options() {
+        let options = MonotonicOptions {
+            keep_pronoun_accents: true,
+            ..Default::default()
+        };
+        let ctx = GracContext::new().with_monotonic_options(options);
+        assert_eq!(ctx.to_monotonic("μοῦ"), "μού");
+        assert!(ctx.lint_monotonic("μού").is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "concatenate")]
+    fn test_context_bad_exception() {
+        let _ = GracContext::new().with_exception("μια", &["μι"]);
+    }
+}
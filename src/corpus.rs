@@ -0,0 +1,152 @@
+//! Tools to grow the synizesis lookup table from a corpus.
+//!
+//! Requires the `corpus-tools` feature.
+
+use std::collections::HashMap;
+use std::io::{self, BufRead};
+
+use crate::chars::{base_lower_str, is_greek_letter};
+use crate::syllabify::{Merge, lookup_syllables, syllabify_with_merge};
+
+/// Syllables of a word, as stored in the synizesis lookup table.
+pub type Syllabification = Vec<String>;
+
+/// Endings where the ι may be pronounced together with the next vowel.
+const ENDINGS: [&str; 9] = ["ια", "ιας", "ιες", "ιο", "ιου", "ιος", "ιοι", "ιους", "ιε"];
+
+/// Minimum number of genitive plurals of a stem to take a decision.
+const MIN_VOTES: usize = 2;
+
+/// Find words of a corpus with synizesis at the last syllable that are not
+/// yet in the lookup table.
+///
+/// Words like χρόνια can be read χρό-νι-α or χρό-νια. The accent of the
+/// genitive plural settles it: it moves to the last syllable with synizesis
+/// (χρονιών), and stays on the ι otherwise (ακρωτηρίων). A stem is considered
+/// to have synizesis when the corpus has more genitive plurals in -ιών than in
+/// -ίων, and at least two of them.
+///
+/// Words are returned sorted, and can be added to `scripts/synizesis/data/registry.txt`
+/// or to the generated table directly (cf. [`lookup_entry`]).
+///
+/// # Errors
+///
+/// Returns an error if the corpus can not be read, or is not UTF-8.
+///
+/// # Example
+///
+/// ```
+/// use grac::corpus::learn_synizesis;
+///
+/// let corpus = "Τα μπαγλαμάδια παίζουν.\nΟ ήχος των μπαγλαμαδιών, των μπαγλαμαδιών!\n";
+/// let learned = learn_synizesis(corpus.as_bytes()).unwrap();
+/// assert_eq!(learned.len(), 1);
+/// assert_eq!(learned[0].0, "μπαγλαμάδια");
+/// assert_eq!(learned[0].1.join("-"), "μπα-γλα-μά-δια");
+/// ```
+pub fn learn_synizesis(corpus: impl BufRead) -> io::Result<Vec<(String, Syllabification)>> {
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for line in corpus.lines() {
+        for word in line?.split(|ch: char| !is_greek_letter(ch)) {
+            if !word.is_empty() {
+                *counts.entry(word.to_string()).or_default() += 1;
+            }
+        }
+    }
+
+    // Votes for synizesis and hiatus by stem (lowercase, without diacritics).
+    let mut votes: HashMap<String, (usize, usize)> = HashMap::new();
+    for (word, count) in &counts {
+        if let Some(stem) = word.strip_suffix("ιών") {
+            votes.entry(base_lower_str(stem)).or_default().0 += count;
+        } else if let Some(stem) = word.strip_suffix("ίων") {
+            votes.entry(base_lower_str(stem)).or_default().1 += count;
+        }
+    }
+
+    let mut learned: Vec<(String, Syllabification)> = counts
+        .keys()
+        .filter_map(|word| {
+            let ending = ENDINGS.iter().find(|ending| word.ends_with(*ending))?;
+            let stem = base_lower_str(&word[..word.len() - ending.len()]);
+            let (synizesis, hiatus) = votes.get(&stem)?;
+            if synizesis <= hiatus || synizesis + hiatus < MIN_VOTES {
+                return None;
+            }
+            if lookup_syllables(word).is_some() {
+                return None;
+            }
+            let syllables = syllabify_with_merge(word, Merge::from_indices(&[1]));
+            // Monosyllables (μια, πιο) are listed by hand.
+            if syllables.len() < 2 {
+                return None;
+            }
+            let syllables = syllables.iter().map(ToString::to_string).collect();
+            Some((word.clone(), syllables))
+        })
+        .collect();
+    learned.sort();
+    Ok(learned)
+}
+
+/// An entry of the generated synizesis lookup table.
+///
+/// # Example
+///
+/// ```
+/// use grac::corpus::lookup_entry;
+///
+/// let syllables = ["κου", "τά", "βια"].map(String::from);
+/// assert_eq!(
+///     lookup_entry("κουτάβια", &syllables),
+///     r#""κουτάβια" => &["κου", "τά", "βια"],"#
+/// );
+/// ```
+pub fn lookup_entry(word: &str, syllables: &[String]) -> String {
+    let syllables: Vec<String> = syllables
+        .iter()
+        .map(|syllable| format!("\"{syllable}\""))
+        .collect();
+    format!("\"{word}\" => &[{}],", syllables.join(", "))
+}
+
+/// An entry of a table of exceptions loaded at runtime: the word, a tab, and
+/// its syllables separated by hyphens.
+///
+/// # Example
+///
+/// ```
+/// use grac::corpus::table_entry;
+///
+/// let syllables = ["κου", "τά", "βια"].map(String::from);
+/// assert_eq!(table_entry("κουτάβια", &syllables), "κουτάβια\tκου-τά-βια");
+/// ```
+pub fn table_entry(word: &str, syllables: &[String]) -> String {
+    format!("{word}\t{}", syllables.join("-"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_learn_synizesis() {
+        let corpus = "\
+ακρωτήρια ακρωτηρίων ακρωτηρίων
+μπαγλαμάδια μπαγλαμαδιών μπαγλαμαδιών
+φασκόμηλια φασκόμηλιας φασκομηλιών φασκομηλιών
+λεβέντικια λεβεντικιών
+";
+        let learned = learn_synizesis(corpus.as_bytes()).unwrap();
+        let words: Vec<&str> = learned.iter().map(|(word, _)| word.as_str()).collect();
+        // Hiatus, and too few votes
+        assert_eq!(words, ["μπαγλαμάδια", "φασκόμηλια", "φασκόμηλιας"]);
+        assert_eq!(learned[2].1.join("-"), "φα-σκό-μη-λιας");
+    }
+
+    #[test]
+    fn test_learn_synizesis_skips_known_words() {
+        let corpus = "παιδιά παιδιών παιδιών χρόνια χρονιών χρονιών";
+        assert!(learn_synizesis(corpus.as_bytes()).unwrap().is_empty());
+    }
+}
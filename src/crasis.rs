@@ -0,0 +1,217 @@
+//! Crasis and elision.
+//!
+//! In polytonic text, a crasis merges two words and marks the merge with a
+//! coronis (κἀγώ = καὶ ἐγώ), and an elision drops the final vowel of a word
+//! before an apostrophe (ἀλλ᾽ = ἀλλά). Only the common forms that can be
+//! expanded in a single way are known.
+
+use unicode_normalization::UnicodeNormalization;
+
+use crate::accents::{Diacritic, has_diacritic};
+use crate::chars::graphemes_el;
+use crate::constants::APOSTROPHES;
+use crate::monotonic::split_punctuation;
+use crate::polytonic::{base_of, breathing_idx, lift_graves};
+use crate::syllabify::is_vowel;
+
+/// Crasis forms, with the words they merge.
+#[rustfmt::skip]
+const CRASIS: [(&str, [&str; 2]); 36] = [
+    // καί
+    ("κἀγώ", ["καί", "ἐγώ"]), ("κἀμοί", ["καί", "ἐμοί"]), ("κἀμέ", ["καί", "ἐμέ"]),
+    ("κἀκεῖνος", ["καί", "ἐκεῖνος"]), ("κἀκείνη", ["καί", "ἐκείνη"]), ("κἀκεῖνο", ["καί", "ἐκεῖνο"]),
+    ("κἀκεῖ", ["καί", "ἐκεῖ"]), ("κἀκεῖθεν", ["καί", "ἐκεῖθεν"]), ("κἀν", ["καί", "ἐν"]),
+    ("κᾆτα", ["καί", "εἶτα"]), ("κἄπειτα", ["καί", "ἔπειτα"]),
+    ("κοὐ", ["καί", "οὐ"]), ("κοὐκ", ["καί", "οὐκ"]), ("κοὐχ", ["καί", "οὐχ"]),
+    ("κοὐδέ", ["καί", "οὐδέ"]), ("κοὐδέν", ["καί", "οὐδέν"]),
+    ("χὠ", ["καί", "ὁ"]), ("χἠ", ["καί", "ἡ"]), ("χοἰ", ["καί", "οἱ"]), ("χαἰ", ["καί", "αἱ"]),
+    // Article
+    ("τἀγαθά", ["τά", "ἀγαθά"]), ("τἆλλα", ["τά", "ἄλλα"]), ("τἀληθῆ", ["τά", "ἀληθῆ"]),
+    ("τἀμά", ["τά", "ἐμά"]), ("ταὐτά", ["τά", "αὐτά"]),
+    ("τοὔνομα", ["τό", "ὄνομα"]), ("τοὔργον", ["τό", "ἔργον"]), ("ταὐτό", ["τό", "αὐτό"]),
+    ("τἀληθές", ["τό", "ἀληθές"]), ("τοὐναντίον", ["τό", "ἐναντίον"]), ("τοὐμόν", ["τό", "ἐμόν"]),
+    ("θἄτερον", ["τό", "ἕτερον"]), ("θἀτέρου", ["τοῦ", "ἑτέρου"]), ("θἀτέρῳ", ["τῷ", "ἑτέρῳ"]),
+    // ἐγώ
+    ("ἐγᾦδα", ["ἐγώ", "οἶδα"]), ("ἐγᾦμαι", ["ἐγώ", "οἶμαι"]),
+];
+
+/// Elided forms without their apostrophe, with the word they come from.
+///
+/// Forms that can come from several words (σ᾽ for σε or σοι) are left out.
+#[rustfmt::skip]
+const ELISIONS: [(&str, &str); 27] = [
+    ("ἀλλ", "ἀλλά"), ("δ", "δέ"), ("τ", "τε"), ("θ", "τε"), ("γ", "γε"),
+    ("οὐδ", "οὐδέ"), ("μηδ", "μηδέ"),
+    ("ἀπ", "ἀπό"), ("ἀφ", "ἀπό"), ("ἐπ", "ἐπί"), ("ἐφ", "ἐπί"), ("ὑπ", "ὑπό"), ("ὑφ", "ὑπό"),
+    ("κατ", "κατά"), ("καθ", "κατά"), ("μετ", "μετά"), ("μεθ", "μετά"), ("παρ", "παρά"),
+    ("δι", "διά"), ("ἀντ", "ἀντί"), ("ἀνθ", "ἀντί"), ("ἀμφ", "ἀμφί"),
+    ("ταῦτ", "ταῦτα"), ("τοῦτ", "τοῦτο"), ("ἐστ", "ἐστί"), ("ποτ", "ποτέ"), ("ὅτ", "ὅτε"),
+];
+
+/// Index of the character that may take the coronis of a crasis.
+///
+/// The coronis goes where the breathing of the second word would be, after
+/// the first consonant: κἀγώ, τοὔνομα, ἐγᾦδα.
+pub(crate) fn coronis_idx(word: &str) -> Option<usize> {
+    let mut end = 0;
+    for (idx, cluster) in graphemes_el(word).enumerate() {
+        end += cluster.len();
+        if !is_vowel(base_of(cluster)) {
+            return Some(idx + 1 + breathing_idx(&word[end..]));
+        }
+    }
+    None
+}
+
+/// Check if a word has a coronis, and is thus a crasis.
+///
+/// This does not need the word to be known to [`expand_crasis`].
+///
+/// # Example
+///
+/// ```
+/// use grac::has_coronis;
+///
+/// assert!(has_coronis("κἀγώ"));
+/// assert!(has_coronis("τοὔνομα"));
+/// assert!(has_coronis("τἀνδρός"));
+/// assert!(!has_coronis("καί"));
+/// assert!(!has_coronis("ἀγώ"));
+/// ```
+pub fn has_coronis(word: impl AsRef<str>) -> bool {
+    let (_, core, _) = split_punctuation(word.as_ref());
+    coronis_idx(core)
+        .and_then(|idx| graphemes_el(core).nth(idx))
+        .is_some_and(|cluster| {
+            is_vowel(base_of(cluster)) && has_diacritic(cluster, Diacritic::SMOOTH)
+        })
+}
+
+/// Check if a word ends with an apostrophe (cf. [`APOSTROPHES`]).
+///
+/// # Example
+///
+/// ```
+/// use grac::is_elided;
+///
+/// assert!(is_elided("ἀλλ᾽"));
+/// assert!(is_elided("δ'"));
+/// assert!(!is_elided("ἀλλά"));
+/// ```
+pub fn is_elided(word: impl AsRef<str>) -> bool {
+    word.as_ref().ends_with(APOSTROPHES)
+}
+
+/// Lowercase the word, in NFC and with acutes instead of graves.
+fn lookup_key(word: &str) -> String {
+    lift_graves(word).nfc().collect::<String>().to_lowercase()
+}
+
+/// Uppercase the first char of `word` if `like` starts with an uppercase one.
+fn with_case_of(word: &str, like: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) if like.starts_with(char::is_uppercase) => {
+            first.to_uppercase().chain(chars).collect()
+        }
+        _ => word.to_string(),
+    }
+}
+
+/// The words merged by a crasis, or an empty vector if the crasis is unknown.
+///
+/// Graves count as acutes, and a capital is kept on the first word.
+///
+/// # Example
+///
+/// ```
+/// use grac::expand_crasis;
+///
+/// assert_eq!(expand_crasis("κἀγώ"), ["καί", "ἐγώ"]);
+/// assert_eq!(expand_crasis("Τοὔνομα"), ["Τό", "ὄνομα"]);
+/// assert!(expand_crasis("καί").is_empty());
+/// ```
+pub fn expand_crasis(word: impl AsRef<str>) -> Vec<String> {
+    let word = word.as_ref();
+    let key = lookup_key(word);
+    CRASIS
+        .iter()
+        .find(|(crasis, _)| *crasis == key)
+        .map(|(_, [first, second])| vec![with_case_of(first, word), (*second).to_string()])
+        .unwrap_or_default()
+}
+
+/// The word that an elided form comes from, or `None` if it is unknown or
+/// not elided.
+///
+/// Any of the [`APOSTROPHES`] is accepted. Graves count as acutes, and a
+/// capital is kept.
+///
+/// # Example
+///
+/// ```
+/// use grac::expand_elision;
+///
+/// assert_eq!(expand_elision("ἀλλ᾽").as_deref(), Some("ἀλλά"));
+/// assert_eq!(expand_elision("Δ'").as_deref(), Some("Δέ"));
+/// // σ᾽ may be σε or σοι
+/// assert_eq!(expand_elision("σ᾽"), None);
+/// assert_eq!(expand_elision("ἀλλά"), None);
+/// ```
+pub fn expand_elision(word: impl AsRef<str>) -> Option<String> {
+    let word = word.as_ref();
+    let stem = word.strip_suffix(APOSTROPHES)?;
+    let key = lookup_key(stem);
+    ELISIONS
+        .iter()
+        .find(|(elided, _)| *elided == key)
+        .map(|(_, full)| with_case_of(full, word))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tables() {
+        for (crasis, words) in CRASIS {
+            assert!(has_coronis(crasis), "{crasis}");
+            assert_eq!(crasis.nfc().collect::<String>(), crasis, "{crasis}");
+            for word in words {
+                assert!(!has_coronis(word), "{word}");
+            }
+        }
+        for (elided, full) in ELISIONS {
+            assert!(
+                full.chars().next() == elided.chars().next() || elided == "θ",
+                "{elided}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_expand_crasis() {
+        assert_eq!(expand_crasis("κἀγὼ"), ["καί", "ἐγώ"]);
+        let decomposed: String = "θἄτερον".nfd().collect();
+        assert_eq!(expand_crasis(decomposed), ["τό", "ἕτερον"]);
+        assert_eq!(expand_crasis("Ἐγᾦδα"), ["Ἐγώ", "οἶδα"]);
+        assert!(expand_crasis("").is_empty());
+    }
+
+    #[test]
+    fn test_expand_elision() {
+        assert_eq!(expand_elision("ἀπ\u{2019}").as_deref(), Some("ἀπό"));
+        assert_eq!(expand_elision("ταῦτ᾽").as_deref(), Some("ταῦτα"));
+        assert_eq!(expand_elision("καθ᾽").as_deref(), Some("κατά"));
+        assert_eq!(expand_elision("᾽"), None);
+        assert_eq!(expand_elision(""), None);
+    }
+
+    #[test]
+    fn test_has_coronis() {
+        assert!(has_coronis("«κἀγώ»"));
+        assert!(!has_coronis("κἁγώ"));
+        assert!(!has_coronis("λόγος"));
+        assert!(!has_coronis(""));
+    }
+}
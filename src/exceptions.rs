@@ -0,0 +1,785 @@
+//! User exception tables, shared between programs as files.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+
+/// First line of an exception table file, followed by the version.
+const MAGIC: &str = "grac-exceptions";
+
+/// The version of the format written by [`ExceptionTable::save`].
+pub const EXCEPTION_TABLE_VERSION: u32 = 1;
+
+/// Errors when loading an exception table.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ExceptionTableError {
+    Io(std::io::Error),
+    /// A table written by a newer version of grac.
+    UnsupportedVersion(String),
+    /// Malformed line (line numbers start at one).
+    Parse {
+        line: usize,
+        message: String,
+    },
+    /// Malformed binary table (cf. [`EmbeddedTable`]).
+    InvalidBytes(String),
+    /// An entry that can not be written to a table.
+    InvalidEntry {
+        word: String,
+        message: String,
+    },
+}
+
+impl fmt::Display for ExceptionTableError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "{err}"),
+            Self::UnsupportedVersion(version) => {
+                write!(
+                    f,
+                    "unsupported version {version}, expected at most {EXCEPTION_TABLE_VERSION}"
+                )
+            }
+            Self::Parse { line, message } => write!(f, "line {line}: {message}"),
+            Self::InvalidBytes(message) => write!(f, "invalid exception table: {message}"),
+            Self::InvalidEntry { word, message } => write!(f, "invalid entry {word}: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for ExceptionTableError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for ExceptionTableError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Section {
+    Synizesis,
+    SpecialCases,
+}
+
+impl Section {
+    const fn header(self) -> &'static str {
+        match self {
+            Self::Synizesis => "[synizesis]",
+            Self::SpecialCases => "[special]",
+        }
+    }
+
+    fn from_header(s: &str) -> Option<Self> {
+        [Self::Synizesis, Self::SpecialCases]
+            .into_iter()
+            .find(|section| section.header() == s)
+    }
+}
+
+/// Curated syllabifications and monotonic conversions.
+///
+/// Tables are saved as UTF-8 text, so that they can be reviewed in a diff: a
+/// `grac-exceptions 1` version line, then a `[synizesis]` section with a word,
+/// a tab and its syllables separated by hyphens per line, and a `[special]`
+/// section with a polytonic word, a tab and its monotonic conversion per line.
+/// Empty lines and lines starting with `#` are ignored.
+///
+/// Tables are used through [`GracContext::with_exception_table`](crate::GracContext::with_exception_table).
+///
+/// # Example
+///
+/// ```
+/// use grac::ExceptionTable;
+///
+/// let table = ExceptionTable::new()
+///     .with_synizesis("ποιος", &["πο", "ιος"])
+///     .with_special_case("ποιὸς", "ποιός");
+/// let saved = table.to_string();
+/// assert_eq!(
+///     saved,
+///     "grac-exceptions 1\n[synizesis]\nποιος\tπο-ιος\n[special]\nποιὸς\tποιός\n"
+/// );
+/// assert_eq!(ExceptionTable::parse(&saved).unwrap(), table);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExceptionTable {
+    /// Syllables of words, by word.
+    pub synizesis: BTreeMap<String, Vec<String>>,
+    /// Monotonic conversions of polytonic words, by polytonic word.
+    pub special_cases: BTreeMap<String, String>,
+}
+
+impl ExceptionTable {
+    pub const fn new() -> Self {
+        Self {
+            synizesis: BTreeMap::new(),
+            special_cases: BTreeMap::new(),
+        }
+    }
+
+    /// Always split `word` into the given syllables.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the syllables do not concatenate to `word`.
+    #[must_use]
+    pub fn with_synizesis(self, word: &str, syllables: &[&str]) -> Self {
+        self.try_with_synizesis(word, syllables)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Same as [`with_synizesis`](Self::with_synizesis), with an error for
+    /// syllables that do not concatenate to `word`, or that the file format
+    /// can not represent (empty, or with a `-`).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExceptionTableError::InvalidEntry`] if the syllables are not
+    /// a valid entry.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use grac::ExceptionTable;
+    ///
+    /// assert!(ExceptionTable::new().try_with_synizesis("ποιος", &["πο", "ιος"]).is_ok());
+    /// assert!(ExceptionTable::new().try_with_synizesis("ποιος", &["πο"]).is_err());
+    /// assert!(ExceptionTable::new().try_with_synizesis("α-β", &["α-β"]).is_err());
+    /// ```
+    pub fn try_with_synizesis(
+        mut self,
+        word: &str,
+        syllables: &[&str],
+    ) -> Result<Self, ExceptionTableError> {
+        check_synizesis(word, syllables)?;
+        if word.contains(['-', '\t', '\n']) {
+            return Err(ExceptionTableError::InvalidEntry {
+                word: word.to_string(),
+                message: "the word contains a '-', a tab or a newline".to_string(),
+            });
+        }
+        self.synizesis.insert(
+            word.to_string(),
+            syllables.iter().map(ToString::to_string).collect(),
+        );
+        Ok(self)
+    }
+
+    /// Always convert the polytonic `word` to `monotonic`.
+    #[must_use]
+    pub fn with_special_case(mut self, word: &str, monotonic: &str) -> Self {
+        self.special_cases
+            .insert(word.to_string(), monotonic.to_string());
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.synizesis.len() + self.special_cases.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Add the entries of `other`, that win over those of `self`.
+    pub fn merge(&mut self, other: Self) {
+        self.synizesis.extend(other.synizesis);
+        self.special_cases.extend(other.special_cases);
+    }
+
+    /// Read a table from a file (cf. [`parse`](Self::parse)).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExceptionTableError::Io`] if the file can not be read, and
+    /// the errors of [`parse`](Self::parse) otherwise.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ExceptionTableError> {
+        Self::parse(&std::fs::read_to_string(path)?)
+    }
+
+    /// Write the table to a file, in the format read by [`load`](Self::load).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can not be written.
+    pub fn save(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        std::fs::write(path, self.to_string())
+    }
+
+    /// Parse a table in the format written by [`save`](Self::save).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExceptionTableError::UnsupportedVersion`] for a table of a
+    /// newer version of grac, and [`ExceptionTableError::Parse`] for a
+    /// malformed line.
+    pub fn parse(s: &str) -> Result<Self, ExceptionTableError> {
+        let mut lines = s.lines().enumerate().map(|(idx, line)| (idx + 1, line));
+        let version = lines
+            .next()
+            .and_then(|(_, line)| line.trim().strip_prefix(MAGIC))
+            .map(str::trim)
+            .ok_or_else(|| parse_error(1, format!("expected a '{MAGIC} <version>' line")))?;
+        match version.parse::<u32>() {
+            Ok(version) if version <= EXCEPTION_TABLE_VERSION => (),
+            _ => return Err(ExceptionTableError::UnsupportedVersion(version.to_string())),
+        }
+
+        let mut table = Self::new();
+        let mut section = None;
+        for (line_number, line) in lines {
+            let line = line.trim_end_matches('\r');
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if line.starts_with('[') {
+                let header = Section::from_header(line.trim())
+                    .ok_or_else(|| parse_error(line_number, format!("unknown section {line}")))?;
+                section = Some(header);
+                continue;
+            }
+            let Some((word, value)) = line.split_once('\t') else {
+                return Err(parse_error(line_number, "expected a tab".to_string()));
+            };
+            match section {
+                None => {
+                    return Err(parse_error(
+                        line_number,
+                        "entry before any section".to_string(),
+                    ));
+                }
+                Some(Section::Synizesis) => {
+                    let syllables: Vec<&str> = value.split('-').collect();
+                    if let Some(message) = invalid_synizesis(word, &syllables) {
+                        return Err(parse_error(line_number, format!("{message}: {value}")));
+                    }
+                    table.synizesis.insert(
+                        word.to_string(),
+                        syllables.into_iter().map(str::to_string).collect(),
+                    );
+                }
+                Some(Section::SpecialCases) => {
+                    table
+                        .special_cases
+                        .insert(word.to_string(), value.to_string());
+                }
+            }
+        }
+        Ok(table)
+    }
+}
+
+fn parse_error(line: usize, message: String) -> ExceptionTableError {
+    ExceptionTableError::Parse { line, message }
+}
+
+/// Check that `syllables` are not empty, and spell `word`.
+pub(crate) fn check_synizesis(word: &str, syllables: &[&str]) -> Result<(), ExceptionTableError> {
+    match invalid_synizesis(word, syllables) {
+        Some(message) => Err(ExceptionTableError::InvalidEntry {
+            word: word.to_string(),
+            message: message.to_string(),
+        }),
+        None => Ok(()),
+    }
+}
+
+fn invalid_synizesis(word: &str, syllables: &[&str]) -> Option<&'static str> {
+    if syllables.concat() != word {
+        Some("the syllables do not concatenate to the word")
+    } else if syllables.iter().any(|syllable| syllable.is_empty()) {
+        Some("empty syllable")
+    } else {
+        None
+    }
+}
+
+/// First bytes of the binary format of [`ExceptionTable::to_bytes`], followed by
+/// the version byte.
+const BINARY_MAGIC: &[u8; 7] = b"GRACEXC";
+
+/// Size of the binary header: magic, version, and the two section lengths.
+const BINARY_HEADER_LEN: usize = 16;
+
+impl ExceptionTable {
+    /// The table in the binary format of [`EmbeddedTable`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the table does not fit in 4GB.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let entries: Vec<String> = self
+            .synizesis
+            .iter()
+            .map(|(word, syllables)| format!("{word}\t{}", syllables.join("-")))
+            .chain(
+                self.special_cases
+                    .iter()
+                    .map(|(word, monotonic)| format!("{word}\t{monotonic}")),
+            )
+            .collect();
+        let to_u32 = |n: usize| u32::try_from(n).expect("exception table too large");
+
+        let mut bytes = Vec::with_capacity(
+            BINARY_HEADER_LEN
+                + 4 * (entries.len() + 1)
+                + entries.iter().map(String::len).sum::<usize>(),
+        );
+        bytes.extend_from_slice(BINARY_MAGIC);
+        // The version is small.
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.push(EXCEPTION_TABLE_VERSION as u8);
+        bytes.extend_from_slice(&to_u32(self.synizesis.len()).to_le_bytes());
+        bytes.extend_from_slice(&to_u32(self.special_cases.len()).to_le_bytes());
+        let mut offset = 0;
+        bytes.extend_from_slice(&0_u32.to_le_bytes());
+        for entry in &entries {
+            offset += entry.len();
+            bytes.extend_from_slice(&to_u32(offset).to_le_bytes());
+        }
+        for entry in &entries {
+            bytes.extend_from_slice(entry.as_bytes());
+        }
+        bytes
+    }
+}
+
+/// An exception table read in place from the bytes of [`ExceptionTable::to_bytes`].
+///
+/// Entries are found by binary search in the bytes, without parsing the table
+/// into maps first. Bytes are validated when the table is built, so that with
+/// [`include_bytes!`] and a `static` large tables are checked at compile time,
+/// and cost nothing at startup:
+///
+/// ```ignore
+/// static TABLE: EmbeddedTable = EmbeddedTable::from_bytes(include_bytes!("exceptions.bin"));
+/// ```
+///
+/// # Example
+///
+/// ```
+/// use grac::{EmbeddedTable, ExceptionTable};
+///
+/// let bytes = ExceptionTable::new()
+///     .with_synizesis("ποιος", &["πο", "ιος"])
+///     .with_special_case("ποιὸς", "ποιός")
+///     .to_bytes();
+/// let table = EmbeddedTable::parse_bytes(&bytes).unwrap();
+/// assert_eq!(table.synizesis("ποιος"), Some(vec!["πο", "ιος"]));
+/// assert_eq!(table.special_case("ποιὸς"), Some("ποιός"));
+/// assert_eq!(table.synizesis("μια"), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EmbeddedTable<'a> {
+    bytes: &'a [u8],
+    n_synizesis: usize,
+    n_special_cases: usize,
+}
+
+impl<'a> EmbeddedTable<'a> {
+    /// Read a table in const contexts.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the bytes are not a valid table.
+    pub const fn from_bytes(bytes: &'a [u8]) -> Self {
+        match Self::validate(bytes) {
+            Ok(table) => table,
+            Err(_) => panic!("invalid exception table bytes"),
+        }
+    }
+
+    /// Same as [`from_bytes`](Self::from_bytes), with an error for bytes that
+    /// are not a valid table.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExceptionTableError::UnsupportedVersion`] for a table of a
+    /// newer version of grac, and [`ExceptionTableError::InvalidBytes`]
+    /// otherwise.
+    pub fn parse_bytes(bytes: &'a [u8]) -> Result<Self, ExceptionTableError> {
+        Self::validate(bytes).map_err(|message| match message {
+            UNSUPPORTED_VERSION => {
+                ExceptionTableError::UnsupportedVersion(bytes[BINARY_MAGIC.len()].to_string())
+            }
+            message => ExceptionTableError::InvalidBytes(message.to_string()),
+        })
+    }
+
+    pub const fn len(&self) -> usize {
+        self.n_synizesis + self.n_special_cases
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The syllables of a word of the `[synizesis]` section.
+    pub fn synizesis(&self, word: &str) -> Option<Vec<&'a str>> {
+        self.find(0, self.n_synizesis, word)
+            .map(|syllables| syllables.split('-').collect())
+    }
+
+    /// The monotonic conversion of a word of the `[special]` section.
+    pub fn special_case(&self, word: &str) -> Option<&'a str> {
+        self.find(self.n_synizesis, self.len(), word)
+    }
+
+    /// Every entry of the `[special]` section.
+    pub fn special_cases(&self) -> impl Iterator<Item = (&'a str, &'a str)> + '_ {
+        (self.n_synizesis..self.len()).map(|idx| split_entry(self.entry(idx)))
+    }
+
+    /// Parse the whole table.
+    pub fn to_table(&self) -> ExceptionTable {
+        let mut table = ExceptionTable::new();
+        for idx in 0..self.n_synizesis {
+            let (word, syllables) = split_entry(self.entry(idx));
+            table.synizesis.insert(
+                word.to_string(),
+                syllables.split('-').map(str::to_string).collect(),
+            );
+        }
+        for (word, monotonic) in self.special_cases() {
+            table
+                .special_cases
+                .insert(word.to_string(), monotonic.to_string());
+        }
+        table
+    }
+
+    /// Binary search of the value of `word` among the entries `lo..hi`.
+    fn find(&self, mut lo: usize, mut hi: usize, word: &str) -> Option<&'a str> {
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            let (key, value) = split_entry(self.entry(mid));
+            match key.cmp(word) {
+                std::cmp::Ordering::Less => lo = mid + 1,
+                std::cmp::Ordering::Greater => hi = mid,
+                std::cmp::Ordering::Equal => return Some(value),
+            }
+        }
+        None
+    }
+
+    fn entry(&self, idx: usize) -> &'a str {
+        let (start, end) = entry_range(self.bytes, self.len(), idx);
+        // Entries are valid UTF-8, as checked by `validate`.
+        std::str::from_utf8(&self.bytes[start..end]).unwrap_or_default()
+    }
+
+    const fn validate(bytes: &'a [u8]) -> Result<Self, &'static str> {
+        if bytes.len() < BINARY_HEADER_LEN {
+            return Err("truncated header");
+        }
+        let mut i = 0;
+        while i < BINARY_MAGIC.len() {
+            if bytes[i] != BINARY_MAGIC[i] {
+                return Err("not an exception table");
+            }
+            i += 1;
+        }
+        let version = bytes[BINARY_MAGIC.len()];
+        if version == 0 || version as u32 > EXCEPTION_TABLE_VERSION {
+            return Err(UNSUPPORTED_VERSION);
+        }
+        let n_synizesis = read_u32(bytes, 8) as usize;
+        let n_special_cases = read_u32(bytes, 12) as usize;
+        let n = n_synizesis + n_special_cases;
+        let data_start = BINARY_HEADER_LEN + 4 * (n + 1);
+        if bytes.len() < data_start || read_u32(bytes, BINARY_HEADER_LEN) != 0 {
+            return Err("truncated offsets");
+        }
+        if data_start + read_u32(bytes, data_start - 4) as usize != bytes.len() {
+            return Err("wrong length");
+        }
+
+        let mut idx = 0;
+        while idx < n {
+            let (start, end) = entry_range(bytes, n, idx);
+            if start > end || end > bytes.len() {
+                return Err("unsorted offsets");
+            }
+            let entry = bytes.split_at(end).0.split_at(start).1;
+            if std::str::from_utf8(entry).is_err() {
+                return Err("invalid UTF-8");
+            }
+            let tab = find_tab(entry);
+            if tab == entry.len() {
+                return Err("entry without a tab");
+            }
+            // Keys are sorted within a section, for the binary search.
+            if idx > 0 && idx != n_synizesis {
+                let previous = bytes
+                    .split_at(start)
+                    .0
+                    .split_at(entry_range(bytes, n, idx - 1).0)
+                    .1;
+                if !bytes_lt(
+                    previous.split_at(find_tab(previous)).0,
+                    entry.split_at(tab).0,
+                ) {
+                    return Err("unsorted entries");
+                }
+            }
+            if idx < n_synizesis && !spells_word(entry.split_at(tab).0, entry.split_at(tab + 1).1) {
+                return Err("syllables that do not concatenate to their word");
+            }
+            idx += 1;
+        }
+        Ok(Self {
+            bytes,
+            n_synizesis,
+            n_special_cases,
+        })
+    }
+}
+
+const UNSUPPORTED_VERSION: &str = "unsupported version";
+
+const fn read_u32(bytes: &[u8], at: usize) -> u32 {
+    u32::from_le_bytes([bytes[at], bytes[at + 1], bytes[at + 2], bytes[at + 3]])
+}
+
+/// Byte range of an entry in a validated table of `n` entries.
+const fn entry_range(bytes: &[u8], n: usize, idx: usize) -> (usize, usize) {
+    let data_start = BINARY_HEADER_LEN + 4 * (n + 1);
+    (
+        data_start + read_u32(bytes, BINARY_HEADER_LEN + 4 * idx) as usize,
+        data_start + read_u32(bytes, BINARY_HEADER_LEN + 4 * (idx + 1)) as usize,
+    )
+}
+
+const fn find_tab(entry: &[u8]) -> usize {
+    let mut i = 0;
+    while i < entry.len() && entry[i] != b'\t' {
+        i += 1;
+    }
+    i
+}
+
+/// Whether the `-` separated `syllables` are not empty, and concatenate to `word`.
+const fn spells_word(word: &[u8], syllables: &[u8]) -> bool {
+    if syllables.is_empty() || syllables[0] == b'-' || syllables[syllables.len() - 1] == b'-' {
+        return false;
+    }
+    let (mut i, mut j) = (0, 0);
+    while j < syllables.len() {
+        if syllables[j] == b'-' {
+            if syllables[j - 1] == b'-' {
+                return false;
+            }
+        } else if i < word.len() && word[i] == syllables[j] {
+            i += 1;
+        } else {
+            return false;
+        }
+        j += 1;
+    }
+    i == word.len()
+}
+
+// Lexicographic byte order, which is the order of str::cmp
+const fn bytes_lt(a: &[u8], b: &[u8]) -> bool {
+    let mut i = 0;
+    while i < a.len() && i < b.len() {
+        if a[i] != b[i] {
+            return a[i] < b[i];
+        }
+        i += 1;
+    }
+    a.len() < b.len()
+}
+
+fn split_entry(entry: &str) -> (&str, &str) {
+    entry.split_once('\t').unwrap_or((entry, ""))
+}
+
+impl fmt::Display for ExceptionTable {
+    /// The table in the format of [`save`](Self::save).
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{MAGIC} {EXCEPTION_TABLE_VERSION}")?;
+        if !self.synizesis.is_empty() {
+            writeln!(f, "{}", Section::Synizesis.header())?;
+            for (word, syllables) in &self.synizesis {
+                writeln!(f, "{word}\t{}", syllables.join("-"))?;
+            }
+        }
+        if !self.special_cases.is_empty() {
+            writeln!(f, "{}", Section::SpecialCases.header())?;
+            for (word, monotonic) in &self.special_cases {
+                writeln!(f, "{word}\t{monotonic}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let s = "grac-exceptions 1\r\n# Curated\n\n[special]\nπὼς\tπως\n[synizesis]\nμια\tμια\nπιο\tπι-ο\n";
+        let table = ExceptionTable::parse(s).unwrap();
+        assert_eq!(table.len(), 3);
+        assert_eq!(table.synizesis["πιο"], ["πι", "ο"]);
+        assert_eq!(table.special_cases["πὼς"], "πως");
+        assert!(
+            ExceptionTable::parse("grac-exceptions 1")
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let cases = [
+            ("", 1),
+            ("grac-exceptions 1\nμια\tμια", 2),
+            ("grac-exceptions 1\n[synizesis]\nμια μια", 3),
+            ("grac-exceptions 1\n[synizesis]\nμια\tμι-α-", 3),
+            ("grac-exceptions 1\n[synizesis]\nμια\tμι-ο", 3),
+            ("grac-exceptions 1\n[syllables]", 2),
+        ];
+        for (s, expected) in cases {
+            let err = ExceptionTable::parse(s).unwrap_err();
+            assert!(
+                matches!(err, ExceptionTableError::Parse { line, .. } if line == expected),
+                "{s}: {err}"
+            );
+        }
+        let err = ExceptionTable::parse("grac-exceptions 2\n").unwrap_err();
+        assert!(matches!(err, ExceptionTableError::UnsupportedVersion(_)));
+    }
+
+    #[test]
+    fn test_try_with_synizesis() {
+        for (word, syllables) in [
+            ("μια", &["μι"][..]),
+            ("μια", &["μια", ""]),
+            ("α-β", &["α-", "β"]),
+            ("α\tβ", &["α\tβ"]),
+        ] {
+            let err = ExceptionTable::new()
+                .try_with_synizesis(word, syllables)
+                .unwrap_err();
+            assert!(
+                matches!(&err, ExceptionTableError::InvalidEntry { word: w, .. } if w == word),
+                "{err}"
+            );
+        }
+        let table = ExceptionTable::new()
+            .try_with_synizesis("μια", &["μι", "α"])
+            .unwrap();
+        assert_eq!(ExceptionTable::parse(&table.to_string()).unwrap(), table);
+    }
+
+    #[test]
+    fn test_load_save() {
+        let table = ExceptionTable::new()
+            .with_synizesis("ποιος", &["πο", "ιος"])
+            .with_synizesis("χρόνια", &["χρό", "νι", "α"]);
+        let path = std::env::temp_dir().join(format!("grac-exceptions-{}.txt", std::process::id()));
+        table.save(&path).unwrap();
+        let loaded = ExceptionTable::load(&path);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(loaded.unwrap(), table);
+        assert!(matches!(
+            ExceptionTable::load(&path),
+            Err(ExceptionTableError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn test_embedded_table() {
+        static BYTES: [u8; 48] = *b"GRACEXC\x01\x02\0\0\0\x01\0\0\0\
+\0\0\0\0\x06\0\0\0\x0c\0\0\0\x10\0\0\0\
+ab\ta-bbc\tb-cpo\tp";
+        static TABLE: EmbeddedTable = EmbeddedTable::from_bytes(&BYTES);
+        let table = ExceptionTable::new()
+            .with_synizesis("bc", &["b", "c"])
+            .with_synizesis("ab", &["a", "b"])
+            .with_special_case("po", "p");
+        assert_eq!(table.to_bytes(), BYTES);
+        assert_eq!(TABLE.len(), 3);
+        assert_eq!(TABLE.synizesis("bc"), Some(vec!["b", "c"]));
+        assert_eq!(TABLE.synizesis("po"), None);
+        assert_eq!(TABLE.special_case("po"), Some("p"));
+        assert_eq!(TABLE.to_table(), table);
+    }
+
+    #[test]
+    fn test_embedded_table_roundtrip() {
+        let words = ["χρόνια", "αλήθεια", "μια", "ποιος", "Αγόρια", "άδεια", "ζ"];
+        let mut table = ExceptionTable::new().with_special_case("ποὺ", "που");
+        for word in words {
+            table = table.with_synizesis(word, &[word]);
+        }
+        let bytes = table.to_bytes();
+        let embedded = EmbeddedTable::parse_bytes(&bytes).unwrap();
+        for word in words {
+            assert_eq!(embedded.synizesis(word), Some(vec![word]));
+            assert_eq!(embedded.special_case(word), None);
+        }
+        assert_eq!(embedded.special_case("ποὺ"), Some("που"));
+        assert_eq!(embedded.to_table(), table);
+        let empty = ExceptionTable::new().to_bytes();
+        assert!(EmbeddedTable::parse_bytes(&empty).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_embedded_table_errors() {
+        let bytes = ExceptionTable::new()
+            .with_synizesis("μια", &["μια"])
+            .with_synizesis("πιο", &["πιο"])
+            .to_bytes();
+        assert!(EmbeddedTable::parse_bytes(&bytes[..bytes.len() - 1]).is_err());
+        assert!(EmbeddedTable::parse_bytes(&bytes[..10]).is_err());
+        assert!(EmbeddedTable::parse_bytes(b"grac-exceptions 1\n[synizesis]").is_err());
+
+        let mut unsorted = bytes.clone();
+        let data = unsorted.len() - "μια\tμιαπιο\tπιο".len();
+        unsorted[data..].copy_from_slice("πιο\tπιομια\tμια".as_bytes());
+        assert!(EmbeddedTable::parse_bytes(&unsorted).is_err());
+
+        let mut mismatch = bytes.clone();
+        mismatch[data..].copy_from_slice("μια\tμιοπιο\tπιο".as_bytes());
+        assert!(matches!(
+            EmbeddedTable::parse_bytes(&mismatch),
+            Err(ExceptionTableError::InvalidBytes(_))
+        ));
+        let mut empty_syllable = ExceptionTable::new();
+        empty_syllable
+            .synizesis
+            .insert("μια".to_string(), vec!["μια".to_string(), String::new()]);
+        assert!(EmbeddedTable::parse_bytes(&empty_syllable.to_bytes()).is_err());
+
+        let mut out_of_bounds = bytes.clone();
+        out_of_bounds[20] = 0xff;
+        assert!(EmbeddedTable::parse_bytes(&out_of_bounds).is_err());
+
+        let mut newer = bytes;
+        newer[7] = 2;
+        assert!(matches!(
+            EmbeddedTable::parse_bytes(&newer),
+            Err(ExceptionTableError::UnsupportedVersion(_))
+        ));
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut table = ExceptionTable::new().with_special_case("ποὺ", "πού");
+        table.merge(ExceptionTable::new().with_special_case("ποὺ", "που"));
+        assert_eq!(table.special_cases["ποὺ"], "που");
+    }
+}
@@ -0,0 +1,108 @@
+//! Rule tracing for syllabification.
+//!
+//! Mainly intended for development: this is what `grac explain <word>` prints.
+
+use crate::syllabify::{Merge, Syllables, lookup_syllables, syllabify_impl_traced};
+
+pub use crate::syllabify::State;
+
+/// A single transition of the syllabification automaton.
+///
+/// Since the automaton walks the word backwards, steps are yielded from the
+/// last char to the first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    /// Byte index of the char being read.
+    pub byte: usize,
+    pub ch: char,
+    pub is_vowel: bool,
+    /// The char being read, followed by the (at most) two chars after it.
+    pub buffer: [(usize, char); 3],
+    pub from: State,
+    pub to: State,
+    /// Syllable position (counting from the end) before reading the char.
+    pub syllable_idx: usize,
+    /// Whether merging was active for that syllable.
+    pub merge: bool,
+    /// Byte index of a syllable boundary emitted at this step, if any.
+    pub boundary: Option<usize>,
+}
+
+/// Trace of a syllabification.
+#[derive(Debug)]
+pub struct Explanation<'a> {
+    pub word: &'a str,
+    /// True if the word was found in the synizesis table, in which case
+    /// there are no steps.
+    pub from_lookup: bool,
+    pub steps: Vec<Step>,
+    pub syllables: Syllables<'a>,
+}
+
+/// Explain how [`syllabify`](crate::syllabify) processes a word.
+///
+/// # Example
+///
+/// ```
+/// use grac::explain::explain;
+///
+/// let explanation = explain("άνθρωπος");
+/// assert!(!explanation.from_lookup);
+/// assert_eq!(explanation.steps.len(), "άνθρωπος".chars().count());
+/// assert_eq!(explanation.syllables.join("-"), "άν-θρω-πος");
+///
+/// assert!(explain("αρρώστια").from_lookup);
+/// ```
+pub fn explain(s: &str) -> Explanation<'_> {
+    match lookup_syllables(s) {
+        Some(syllables) => Explanation {
+            word: s,
+            from_lookup: true,
+            steps: Vec::new(),
+            syllables,
+        },
+        None => explain_with_merge(s, Merge::Never),
+    }
+}
+
+/// Explain how [`syllabify_with_merge`](crate::syllabify_with_merge) processes a word.
+pub fn explain_with_merge(s: &str, merge: Merge) -> Explanation<'_> {
+    let mut steps = Vec::new();
+    let syllables = syllabify_impl_traced(s, &merge, &mut steps);
+    Explanation {
+        word: s,
+        from_lookup: false,
+        steps,
+        syllables,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::syllabify_with_merge;
+
+    #[test]
+    fn test_explain_agrees_with_syllabify() {
+        for word in ["άνθρωπος", "αστειάκια", "ρολοϊού", "στρες", ""] {
+            for merge in [Merge::Every, Merge::Never, Merge::from_indices(&[2])] {
+                let expected = syllabify_with_merge(word, merge.clone());
+                assert_eq!(explain_with_merge(word, merge).syllables, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_explain_boundaries() {
+        let explanation = explain_with_merge("πάντα", Merge::Never);
+        let boundaries: Vec<_> = explanation
+            .steps
+            .iter()
+            .filter_map(|step| step.boundary)
+            .collect();
+        // Yielded backwards: πά|ντα
+        assert_eq!(boundaries, ["πά".len()]);
+        assert_eq!(explanation.steps[0].from, State::Start);
+        assert_eq!(explanation.steps[0].to, State::FoundVowel);
+    }
+}
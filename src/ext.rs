@@ -0,0 +1,85 @@
+//! Methods on `str` for the free functions that are chained most often.
+
+use crate::accents::remove_all_diacritics;
+use crate::chars::{base_lower, is_greek_word};
+use crate::syllabify::{Syllables, syllabify};
+use crate::tokenize::{TokenKind, tokenize};
+
+/// Methods on `str` for pipelines of grac functions.
+///
+/// Iterators borrow from the string and do not allocate.
+///
+/// # Example
+///
+/// ```
+/// use grac::GreekStrExt;
+///
+/// let text = "«Ἄνθρωπος», hello 1821 κόσμος.";
+/// let words: Vec<_> = text.greek_words().collect();
+/// assert_eq!(words, ["Ἄνθρωπος", "κόσμος"]);
+///
+/// let lengths: Vec<_> = text.greek_words().map(|word| word.syllables().len()).collect();
+/// assert_eq!(lengths, [3, 2]);
+///
+/// assert!(words[0].base_lower_chars().eq("ανθρωπος".chars()));
+/// assert_eq!(words[0].without_diacritics(), "Ανθρωπος");
+/// ```
+pub trait GreekStrExt {
+    /// Same as [`base_lower_str`](crate::base_lower_str), lazily.
+    fn base_lower_chars(&self) -> impl Iterator<Item = char> + '_;
+
+    /// The Greek words of the text, without the punctuation around them
+    /// (cf. [`tokenize`] and [`is_greek_word`]).
+    fn greek_words(&self) -> impl Iterator<Item = &str> + '_;
+
+    /// Same as [`syllabify`].
+    fn syllables(&self) -> Syllables<'_>;
+
+    /// Same as [`remove_all_diacritics`].
+    fn without_diacritics(&self) -> String;
+}
+
+impl GreekStrExt for str {
+    fn base_lower_chars(&self) -> impl Iterator<Item = char> + '_ {
+        self.chars().map(base_lower)
+    }
+
+    fn greek_words(&self) -> impl Iterator<Item = &str> + '_ {
+        tokenize(self)
+            .filter(|token| token.kind == TokenKind::Word && is_greek_word(token.text))
+            .map(|token| token.text)
+    }
+
+    fn syllables(&self) -> Syllables<'_> {
+        syllabify(self)
+    }
+
+    fn without_diacritics(&self) -> String {
+        remove_all_diacritics(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_greek_words() {
+        let text = "ό,τι «καλό»· hello-κόσμε Ἀθῆναι\n";
+        let words: Vec<_> = text.greek_words().collect();
+        assert_eq!(words, ["ό,τι", "καλό", "Ἀθῆναι"]);
+        assert_eq!("".greek_words().count(), 0);
+        assert_eq!("1821 ...".greek_words().count(), 0);
+    }
+
+    #[test]
+    fn test_methods_match_functions() {
+        let word = String::from("Ἀθῆναι");
+        assert_eq!(word.syllables(), syllabify(&word));
+        assert_eq!(
+            word.base_lower_chars().collect::<String>(),
+            crate::base_lower_str(&word)
+        );
+        assert_eq!(word.without_diacritics(), "Αθηναι");
+    }
+}
@@ -0,0 +1,164 @@
+//! Document formats: conversion of TEI and other XML documents.
+//!
+//! Requires the `tei` feature.
+
+use std::fmt;
+use std::io::{self, BufRead, Write};
+
+use quick_xml::events::{BytesText, Event};
+use quick_xml::{Reader, Writer};
+
+use crate::monotonic::to_monotonic;
+use crate::protect::ProtectedRegions;
+
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum FormatError {
+    Io(io::Error),
+    /// Malformed XML, at the given byte position of the input.
+    Xml {
+        position: u64,
+        message: String,
+    },
+}
+
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "{err}"),
+            Self::Xml { position, message } => write!(f, "byte {position}: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for FormatError {}
+
+impl From<io::Error> for FormatError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+/// Convert the text nodes of an XML document (TEI, for instance) to monotonic.
+///
+/// Markup is copied as it is: tags and their attributes, comments, CDATA
+/// sections, processing instructions and the doctype. Entity and character
+/// references (`&amp;`, `&#x3B1;`) are left untouched too, so a word with a
+/// character reference is only converted around it.
+///
+/// The document is read and written one event at a time, so that large files
+/// do not need to fit in memory. Text nodes are converted independently of
+/// each other: a word split by a tag is converted in parts.
+///
+/// # Errors
+///
+/// Returns [`FormatError::Io`] if the input can not be read or the output
+/// written, and [`FormatError::Xml`] if the input is not well-formed XML.
+///
+/// # Example
+///
+/// ```
+/// use grac::formats::xml_to_monotonic;
+///
+/// let tei = r#"<l n="1">Μῆνιν ἄειδε θεὰ &amp; <persName>Πηληϊάδεω</persName></l>"#;
+/// let mut out = Vec::new();
+/// xml_to_monotonic(tei.as_bytes(), &mut out).unwrap();
+/// assert_eq!(
+///     String::from_utf8(out).unwrap(),
+///     r#"<l n="1">Μήνιν άειδε θεά &amp; <persName>Πηληϊάδεω</persName></l>"#
+/// );
+/// ```
+pub fn xml_to_monotonic(input: impl BufRead, output: impl Write) -> Result<(), FormatError> {
+    let mut reader = Reader::from_reader(input);
+    let mut writer = Writer::new(output);
+    let references = ProtectedRegions::new().with_entity_references();
+    let mut buf = Vec::new();
+    loop {
+        let event = reader
+            .read_event_into(&mut buf)
+            .map_err(|err| xml_error(&reader, &err))?;
+        match event {
+            Event::Eof => break,
+            Event::Text(text) => {
+                let raw = text.into_inner();
+                let raw = std::str::from_utf8(&raw).map_err(|err| FormatError::Xml {
+                    position: reader.buffer_position(),
+                    message: err.to_string(),
+                })?;
+                let converted = references.apply(raw, |s| to_monotonic(s));
+                writer.write_event(Event::Text(BytesText::from_escaped(converted)))?;
+            }
+            event => writer.write_event(event)?,
+        }
+        buf.clear();
+    }
+    Ok(())
+}
+
+/// Same as [`xml_to_monotonic`], over a string.
+///
+/// # Errors
+///
+/// Returns [`FormatError::Xml`] if the input is not well-formed XML.
+///
+/// # Example
+///
+/// ```
+/// use grac::formats::xml_str_to_monotonic;
+///
+/// let xml = "<p>Ἡ <!-- ἡ --> Ἑλλάς</p>";
+/// assert_eq!(xml_str_to_monotonic(xml).unwrap(), "<p>Η <!-- ἡ --> Ελλάς</p>");
+/// assert!(xml_str_to_monotonic("<p>Ἡ</q>").is_err());
+/// ```
+pub fn xml_str_to_monotonic(s: &str) -> Result<String, FormatError> {
+    let mut out = Vec::with_capacity(s.len());
+    xml_to_monotonic(s.as_bytes(), &mut out)?;
+    String::from_utf8(out).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err).into())
+}
+
+fn xml_error<R>(reader: &Reader<R>, err: &quick_xml::Error) -> FormatError {
+    match err {
+        quick_xml::Error::Io(err) => FormatError::Io(io::Error::new(err.kind(), err.to_string())),
+        err => FormatError::Xml {
+            position: reader.error_position(),
+            message: err.to_string(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_markup_is_preserved() {
+        let xml = "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+<!DOCTYPE TEI>\n\
+<TEI xmlns=\"http://www.tei-c.org/ns/1.0\">\n\
+  <teiHeader><title  type='main'>Ἰλιάς</title></teiHeader>\n\
+  <text><body><l>τὸν &#x1F00;&gt; <![CDATA[ὁ <b>]]> ἄνδρα<lb/>καὶ</l></body></text>\n\
+</TEI>\n";
+        let expected = xml
+            .replace(">Ἰλιάς<", ">Ιλιάς<")
+            .replace("τὸν", "τον")
+            .replace("ἄνδρα", "άνδρα")
+            .replace("καὶ", "και");
+        assert_eq!(xml_str_to_monotonic(xml).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_bare_ampersand() {
+        let xml = "<p>Ἁ & Β; καὶ &amp;τὸ</p>";
+        assert_eq!(
+            xml_str_to_monotonic(xml).unwrap(),
+            "<p>Α & Β; και &amp;το</p>"
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let err = xml_str_to_monotonic("<a>καὶ</b>").unwrap_err();
+        assert!(matches!(err, FormatError::Xml { .. }), "{err:?}");
+        assert!(xml_str_to_monotonic("").unwrap().is_empty());
+    }
+}
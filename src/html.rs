@@ -0,0 +1,221 @@
+//! Soft hyphenation of HTML and XHTML documents (EPUB).
+//!
+//! Requires the `html` feature.
+
+use crate::hyphenate::hyphenation_points;
+use crate::protect::ProtectedRegions;
+use crate::tokenize::{TokenKind, tokenize};
+
+/// The soft hyphen entity of HTML.
+///
+/// XHTML (EPUB) does not define `&shy;`: use `"&#173;"` instead.
+pub const SOFT_HYPHEN: &str = "&shy;";
+
+/// Elements whose text is never hyphenated.
+const SKIPPED_ELEMENTS: [&str; 9] = [
+    "code", "kbd", "pre", "samp", "script", "style", "textarea", "math", "svg",
+];
+
+/// Elements whose content is not markup, and ends at their closing tag.
+const RAW_TEXT_ELEMENTS: [&str; 2] = ["script", "style"];
+
+/// Insert soft hyphens at the hyphenation points of the Greek words of an HTML document.
+///
+/// Only text is hyphenated: tags and their attributes, comments and entity
+/// references are copied as they are, as well as the content of `<code>`,
+/// `<pre>`, `<script>`, `<style>` and a few other elements. Hyphenation points
+/// are those of [`hyphenation_points`](crate::hyphenation_points).
+///
+/// This is not a validating parser: malformed markup is copied as text.
+///
+/// # Example
+///
+/// ```
+/// use grac::html::soft_hyphenate_html;
+///
+/// let html = r#"<p title="καλημέρα">καλημέρα <code>καλημέρα</code></p>"#;
+/// assert_eq!(
+///     soft_hyphenate_html(html),
+///     r#"<p title="καλημέρα">κα&shy;λη&shy;μέ&shy;ρα <code>καλημέρα</code></p>"#
+/// );
+/// ```
+pub fn soft_hyphenate_html(html: &str) -> String {
+    soft_hyphenate_html_with(html, SOFT_HYPHEN)
+}
+
+/// Same as [`soft_hyphenate_html`], with a custom hyphen.
+///
+/// # Example
+///
+/// ```
+/// use grac::html::soft_hyphenate_html_with;
+///
+/// let xhtml = "<p>Ελλάδα</p>";
+/// assert_eq!(soft_hyphenate_html_with(xhtml, "&#173;"), "<p>Ελ&#173;λά&#173;δα</p>");
+/// ```
+pub fn soft_hyphenate_html_with(html: &str, hyphen: &str) -> String {
+    let references = ProtectedRegions::new().with_entity_references();
+    let mut out = String::with_capacity(html.len() + html.len() / 4);
+    // Depth of nested skipped elements.
+    let mut skipped = 0_usize;
+    let mut rest = html;
+    while !rest.is_empty() {
+        let markup_len = markup_len(rest);
+        if markup_len > 0 {
+            let markup = &rest[..markup_len];
+            out.push_str(markup);
+            rest = &rest[markup_len..];
+
+            let Some((name, is_closing, is_self_closing)) = parse_tag(markup) else {
+                continue;
+            };
+            if !SKIPPED_ELEMENTS.contains(&name.as_str()) || is_self_closing {
+                continue;
+            }
+            if is_closing {
+                skipped = skipped.saturating_sub(1);
+            } else if RAW_TEXT_ELEMENTS.contains(&name.as_str()) {
+                // Copy up to the closing tag, that is handled in the next iteration.
+                let end =
+                    find_ascii_case_insensitive(rest, &format!("</{name}")).unwrap_or(rest.len());
+                out.push_str(&rest[..end]);
+                rest = &rest[end..];
+                skipped += 1;
+            } else {
+                skipped += 1;
+            }
+        } else {
+            // Up to the next tag (the text may start with a `<` that is not markup)
+            let text_len = rest
+                .char_indices()
+                .skip(1)
+                .find(|(_, ch)| *ch == '<')
+                .map_or(rest.len(), |(idx, _)| idx);
+            let text = &rest[..text_len];
+            if skipped > 0 {
+                out.push_str(text);
+            } else {
+                out.push_str(&references.apply(text, |s| hyphenate_text(s, hyphen)));
+            }
+            rest = &rest[text_len..];
+        }
+    }
+    out
+}
+
+fn hyphenate_text(text: &str, hyphen: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for token in tokenize(text) {
+        if token.kind != TokenKind::Word {
+            out.push_str(token.text);
+            continue;
+        }
+        let mut start = 0;
+        for point in hyphenation_points(token.text) {
+            out.push_str(&token.text[start..point]);
+            out.push_str(hyphen);
+            start = point;
+        }
+        out.push_str(&token.text[start..]);
+    }
+    out
+}
+
+/// Length of the tag, comment or declaration at the start of `s`, or zero.
+fn markup_len(s: &str) -> usize {
+    if !s.starts_with('<') {
+        return 0;
+    }
+    let (close, from) = if s.starts_with("<!--") {
+        ("-->", 4)
+    } else if s.starts_with("<![CDATA[") {
+        ("]]>", 9)
+    } else {
+        let is_tag = s[1..].starts_with(|ch: char| ch.is_ascii_alphabetic() || "/!?".contains(ch));
+        if !is_tag {
+            return 0;
+        }
+        return tag_len(s);
+    };
+    s[from..]
+        .find(close)
+        .map_or(s.len(), |idx| from + idx + close.len())
+}
+
+/// Length of a tag, skipping `>` inside quoted attribute values.
+fn tag_len(s: &str) -> usize {
+    let mut quote = None;
+    for (idx, ch) in s.char_indices().skip(1) {
+        match (quote, ch) {
+            (None, '"' | '\'') => quote = Some(ch),
+            (Some(q), _) if q == ch => quote = None,
+            (None, '>') => return idx + 1,
+            _ => (),
+        }
+    }
+    s.len()
+}
+
+/// Lowercase name of a tag, and whether it is closing or self-closing.
+fn parse_tag(markup: &str) -> Option<(String, bool, bool)> {
+    let inner = markup.strip_prefix('<')?.strip_suffix('>')?;
+    let (is_closing, inner) = match inner.strip_prefix('/') {
+        Some(inner) => (true, inner),
+        None => (false, inner),
+    };
+    let name: String = inner
+        .chars()
+        .take_while(|ch| ch.is_ascii_alphanumeric())
+        .map(|ch| ch.to_ascii_lowercase())
+        .collect();
+    if name.is_empty() {
+        return None;
+    }
+    Some((name, is_closing, inner.ends_with('/')))
+}
+
+fn find_ascii_case_insensitive(haystack: &str, needle: &str) -> Option<usize> {
+    haystack
+        .as_bytes()
+        .windows(needle.len())
+        .position(|window| window.eq_ignore_ascii_case(needle.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_skipped_elements() {
+        let html = "<PRE>καλημέρα <b>καλημέρα</b></PRE><script>if (a<b) { s = 'καλημέρα'; }</script>καλημέρα";
+        assert_eq!(
+            soft_hyphenate_html(html),
+            "<PRE>καλημέρα <b>καλημέρα</b></PRE><script>if (a<b) { s = 'καλημέρα'; }</script>κα&shy;λη&shy;μέ&shy;ρα"
+        );
+    }
+
+    #[test]
+    fn test_markup_is_preserved() {
+        let html = "<!DOCTYPE html>\n<!-- καλημέρα --><img alt='a > καλημέρα'/><br>\nκαλημέρα&nbsp;Ελλάδα &lt; 3";
+        assert_eq!(
+            soft_hyphenate_html(html),
+            "<!DOCTYPE html>\n<!-- καλημέρα --><img alt='a > καλημέρα'/><br>\nκα&shy;λη&shy;μέ&shy;ρα&nbsp;Ελ&shy;λά&shy;δα &lt; 3"
+        );
+    }
+
+    #[test]
+    fn test_malformed_markup() {
+        assert_eq!(
+            soft_hyphenate_html("1 < 2 <b>Ελλάδα</b>"),
+            "1 < 2 <b>Ελ&shy;λά&shy;δα</b>"
+        );
+        assert_eq!(
+            soft_hyphenate_html("Α & Β; καλημέρα"),
+            "Α & Β; κα&shy;λη&shy;μέ&shy;ρα"
+        );
+        for html in ["<", "a < b", "<p", "<!--", "</>", "<script>καλημέρα"] {
+            let result = soft_hyphenate_html(html);
+            assert_eq!(result.replace(SOFT_HYPHEN, ""), html);
+        }
+    }
+}
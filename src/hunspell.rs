@@ -0,0 +1,611 @@
+//! Loader for Hunspell dictionaries (.aff and .dic files).
+//!
+//! Only what is needed to recognize words is supported: prefixes and suffixes
+//! (with cross products), `NEEDAFFIX` and `FORBIDDENWORD`. Compounding and
+//! twofold affixes are ignored.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+
+use crate::lexicon::Lexicon;
+
+type Flag = u64;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+enum FlagMode {
+    /// One char per flag.
+    #[default]
+    Char,
+    /// Two chars per flag.
+    Long,
+    /// Comma separated numbers.
+    Num,
+}
+
+impl FlagMode {
+    fn parse(self, flags: &str) -> Result<Vec<Flag>, String> {
+        match self {
+            Self::Char => Ok(flags.chars().map(Flag::from).collect()),
+            Self::Long => {
+                let chars: Vec<char> = flags.chars().collect();
+                if !chars.len().is_multiple_of(2) {
+                    return Err(format!("odd number of chars in long flags: {flags}"));
+                }
+                Ok(chars
+                    .chunks(2)
+                    .map(|pair| (Flag::from(pair[0]) << 32) | Flag::from(pair[1]))
+                    .collect())
+            }
+            Self::Num => flags
+                .split(',')
+                .map(|flag| flag.parse().map_err(|_| format!("invalid flag: {flag}")))
+                .collect(),
+        }
+    }
+
+    fn parse_one(self, flag: &str) -> Result<Flag, String> {
+        match self.parse(flag)?.as_slice() {
+            [flag] => Ok(*flag),
+            _ => Err(format!("expected a single flag: {flag}")),
+        }
+    }
+}
+
+/// Errors when loading a Hunspell dictionary.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum HunspellError {
+    Io(std::io::Error),
+    /// Only UTF-8 and ISO-8859-7 dictionaries are supported.
+    UnsupportedEncoding(String),
+    /// Malformed line in the .aff or .dic file (line numbers start at one).
+    Parse {
+        line: usize,
+        message: String,
+    },
+}
+
+impl fmt::Display for HunspellError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "{err}"),
+            Self::UnsupportedEncoding(encoding) => {
+                write!(
+                    f,
+                    "unsupported encoding {encoding}, only UTF-8 and ISO-8859-7 are supported"
+                )
+            }
+            Self::Parse { line, message } => write!(f, "line {line}: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for HunspellError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for HunspellError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+/// Encoding of a dictionary, as given by the `SET` line of its .aff file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Encoding {
+    Utf8,
+    /// Greek, used by older el_GR dictionaries.
+    Iso8859_7,
+}
+
+/// ISO-8859-7 characters from 0xA0 on, that are not in Latin-1.
+///
+/// Unassigned bytes are decoded to the replacement character.
+const ISO_8859_7_HIGH: [char; 32] = [
+    '\u{00A0}', '\u{2018}', '\u{2019}', '\u{00A3}', '\u{20AC}', '\u{20AF}', '\u{00A6}', '\u{00A7}',
+    '\u{00A8}', '\u{00A9}', '\u{037A}', '\u{00AB}', '\u{00AC}', '\u{00AD}', '\u{FFFD}', '\u{2015}',
+    '\u{00B0}', '\u{00B1}', '\u{00B2}', '\u{00B3}', '\u{0384}', '\u{0385}', '\u{0386}', '\u{00B7}',
+    '\u{0388}', '\u{0389}', '\u{038A}', '\u{00BB}', '\u{038C}', '\u{00BD}', '\u{038E}', '\u{038F}',
+];
+
+impl Encoding {
+    fn from_name(name: &str) -> Result<Self, HunspellError> {
+        match name.to_ascii_uppercase().as_str() {
+            "UTF-8" => Ok(Self::Utf8),
+            "ISO8859-7" | "ISO-8859-7" => Ok(Self::Iso8859_7),
+            _ => Err(HunspellError::UnsupportedEncoding(name.to_string())),
+        }
+    }
+
+    /// The encoding of the `SET` line of an .aff file, UTF-8 by default.
+    fn of_aff(aff: &[u8]) -> Result<Self, HunspellError> {
+        for line in aff.split(|&byte| byte == b'\n') {
+            // The line is ASCII, whatever the encoding.
+            let line = String::from_utf8_lossy(line);
+            let mut fields = line.trim_start_matches('\u{FEFF}').split_whitespace();
+            if let (Some("SET"), Some(name)) = (fields.next(), fields.next()) {
+                return Self::from_name(name);
+            }
+        }
+        Ok(Self::Utf8)
+    }
+
+    fn decode(self, bytes: Vec<u8>) -> Result<String, HunspellError> {
+        match self {
+            Self::Utf8 => String::from_utf8(bytes)
+                .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err).into()),
+            Self::Iso8859_7 => Ok(bytes
+                .into_iter()
+                .map(|byte| match byte {
+                    0x00..=0x9F => char::from(byte),
+                    0xA0..=0xBF => ISO_8859_7_HIGH[usize::from(byte - 0xA0)],
+                    0xD2 | 0xFF => '\u{FFFD}',
+                    // Greek letters, in Unicode order
+                    _ => char::from_u32(0x0390 + u32::from(byte - 0xC0)).unwrap_or('\u{FFFD}'),
+                })
+                .collect()),
+        }
+    }
+}
+
+/// A condition on the start (prefixes) or the end (suffixes) of a stem.
+#[derive(Debug, Clone)]
+enum CondPart {
+    Any,
+    Char(char),
+    Set { chars: Vec<char>, negated: bool },
+}
+
+impl CondPart {
+    fn matches(&self, ch: char) -> bool {
+        match self {
+            Self::Any => true,
+            Self::Char(expected) => ch == *expected,
+            Self::Set { chars, negated } => chars.contains(&ch) != *negated,
+        }
+    }
+}
+
+fn parse_condition(condition: &str) -> Result<Vec<CondPart>, String> {
+    if condition == "." {
+        return Ok(Vec::new());
+    }
+    let mut parts = Vec::new();
+    let mut chars = condition.chars();
+    while let Some(ch) = chars.next() {
+        let part = match ch {
+            '.' => CondPart::Any,
+            '[' => {
+                let mut set: Vec<char> = Vec::new();
+                loop {
+                    match chars.next() {
+                        Some(']') => break,
+                        Some(ch) => set.push(ch),
+                        None => return Err(format!("unclosed bracket in condition: {condition}")),
+                    }
+                }
+                let negated = set.first() == Some(&'^');
+                if negated {
+                    set.remove(0);
+                }
+                CondPart::Set {
+                    chars: set,
+                    negated,
+                }
+            }
+            ch => CondPart::Char(ch),
+        };
+        parts.push(part);
+    }
+    Ok(parts)
+}
+
+#[derive(Debug, Clone)]
+struct Affix {
+    flag: Flag,
+    cross_product: bool,
+    strip: String,
+    condition: Vec<CondPart>,
+}
+
+impl Affix {
+    fn matches_prefix_of(&self, stem: &str) -> bool {
+        let mut chars = stem.chars();
+        self.condition
+            .iter()
+            .all(|part| chars.next().is_some_and(|ch| part.matches(ch)))
+    }
+
+    fn matches_suffix_of(&self, stem: &str) -> bool {
+        let mut chars = stem.chars().rev();
+        self.condition
+            .iter()
+            .rev()
+            .all(|part| chars.next().is_some_and(|ch| part.matches(ch)))
+    }
+}
+
+/// A Hunspell dictionary, such as the el_GR one shipped by LibreOffice.
+///
+/// # Example
+///
+/// ```
+/// use grac::lexicon::{HunspellDictionary, Lexicon};
+///
+/// let aff = "SET UTF-8\nSFX A Y 2\nSFX A ος οι ος\nSFX A ος ου ος\n";
+/// let dic = "1\nάνθρωπος/A\n";
+/// let dict = HunspellDictionary::parse(aff, dic).unwrap();
+/// assert!(dict.is_known_word("άνθρωποι"));
+/// assert!(!dict.is_known_word("άνθρωπα"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct HunspellDictionary {
+    /// Stems with their flags.
+    words: HashMap<String, Vec<Flag>>,
+    /// Affixes by the string they add.
+    prefixes: HashMap<String, Vec<Affix>>,
+    suffixes: HashMap<String, Vec<Affix>>,
+    need_affix: Option<Flag>,
+    forbidden: Option<Flag>,
+}
+
+impl HunspellDictionary {
+    /// Load a dictionary from its .aff and .dic files.
+    ///
+    /// Both files are decoded with the encoding of the `SET` line of the .aff
+    /// file, either UTF-8 (the default) or ISO-8859-7.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HunspellError::Io`] if a file can not be read, and the errors
+    /// of [`parse_bytes`](Self::parse_bytes) otherwise.
+    pub fn from_files(aff: impl AsRef<Path>, dic: impl AsRef<Path>) -> Result<Self, HunspellError> {
+        Self::parse_bytes(std::fs::read(aff)?, std::fs::read(dic)?)
+    }
+
+    /// Parse a dictionary from the raw contents of its .aff and .dic files.
+    ///
+    /// They are decoded as in [`from_files`](Self::from_files).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HunspellError::UnsupportedEncoding`] for an encoding other
+    /// than UTF-8 or ISO-8859-7, [`HunspellError::Io`] for invalid UTF-8, and
+    /// [`HunspellError::Parse`] for a malformed line.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use grac::lexicon::{HunspellDictionary, Lexicon};
+    ///
+    /// // "λόγος" in ISO-8859-7
+    /// let aff = b"SET ISO8859-7\n".to_vec();
+    /// let dic = b"1\n\xeb\xfc\xe3\xef\xf2\n".to_vec();
+    /// let dict = HunspellDictionary::parse_bytes(aff, dic).unwrap();
+    /// assert!(dict.is_known_word("λόγος"));
+    /// ```
+    pub fn parse_bytes(aff: Vec<u8>, dic: Vec<u8>) -> Result<Self, HunspellError> {
+        let encoding = Encoding::of_aff(&aff)?;
+        Self::parse(&encoding.decode(aff)?, &encoding.decode(dic)?)
+    }
+
+    /// Parse a dictionary from the contents of its .aff and .dic files.
+    ///
+    /// They are already decoded, so the `SET` line only needs to name a
+    /// supported encoding.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HunspellError::UnsupportedEncoding`] if the `SET` line names
+    /// another encoding, and [`HunspellError::Parse`] for a malformed line.
+    pub fn parse(aff: &str, dic: &str) -> Result<Self, HunspellError> {
+        let mut dict = Self::default();
+        let mode = dict.parse_aff(aff)?;
+        dict.parse_dic(dic, mode)?;
+        Ok(dict)
+    }
+
+    fn parse_aff(&mut self, aff: &str) -> Result<FlagMode, HunspellError> {
+        let mut mode = FlagMode::default();
+        // Affix classes whose header has been read, with their cross product option.
+        let mut headers: HashMap<(bool, Flag), bool> = HashMap::new();
+
+        for (idx, line) in aff.trim_start_matches('\u{FEFF}').lines().enumerate() {
+            let err = |message: String| HunspellError::Parse {
+                line: idx + 1,
+                message,
+            };
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            match fields.as_slice() {
+                ["SET", encoding, ..] => {
+                    Encoding::from_name(encoding)?;
+                }
+                ["FLAG", flag_mode, ..] => {
+                    mode = match *flag_mode {
+                        "long" => FlagMode::Long,
+                        "num" => FlagMode::Num,
+                        "UTF-8" => FlagMode::Char,
+                        other => return Err(err(format!("unknown flag type: {other}"))),
+                    };
+                }
+                ["NEEDAFFIX", flag, ..] => {
+                    self.need_affix = Some(mode.parse_one(flag).map_err(err)?)
+                }
+                ["FORBIDDENWORD", flag, ..] => {
+                    self.forbidden = Some(mode.parse_one(flag).map_err(err)?);
+                }
+                [kind @ ("PFX" | "SFX"), flag, rest @ ..] => {
+                    let is_prefix = *kind == "PFX";
+                    let flag = mode.parse_one(flag).map_err(err)?;
+                    let Some(&cross_product) = headers.get(&(is_prefix, flag)) else {
+                        let [cross_product, ..] = rest else {
+                            return Err(err(format!("missing cross product option: {line}")));
+                        };
+                        headers.insert((is_prefix, flag), *cross_product == "Y");
+                        continue;
+                    };
+                    let [strip, add, condition @ ..] = rest else {
+                        return Err(err(format!("malformed affix rule: {line}")));
+                    };
+                    // Twofold affixes are not supported: drop the continuation flags.
+                    let add = add.split('/').next().unwrap_or_default();
+                    let affix = Affix {
+                        flag,
+                        cross_product,
+                        strip: zero_to_empty(strip).to_string(),
+                        condition: parse_condition(condition.first().unwrap_or(&"."))
+                            .map_err(err)?,
+                    };
+                    let affixes = if is_prefix {
+                        &mut self.prefixes
+                    } else {
+                        &mut self.suffixes
+                    };
+                    affixes
+                        .entry(zero_to_empty(add).to_string())
+                        .or_default()
+                        .push(affix);
+                }
+                _ => {}
+            }
+        }
+        Ok(mode)
+    }
+
+    fn parse_dic(&mut self, dic: &str, mode: FlagMode) -> Result<(), HunspellError> {
+        // The first line is the (approximate) number of words.
+        for (idx, line) in dic
+            .trim_start_matches('\u{FEFF}')
+            .lines()
+            .enumerate()
+            .skip(1)
+        {
+            // Morphological fields follow a tab or a space.
+            let Some(entry) = line.split(['\t', ' ']).next().filter(|e| !e.is_empty()) else {
+                continue;
+            };
+            let (word, flags) = match entry.split_once('/') {
+                Some((word, flags)) => (word, mode.parse(flags)),
+                None => (entry, Ok(Vec::new())),
+            };
+            let flags = flags.map_err(|message| HunspellError::Parse {
+                line: idx + 1,
+                message,
+            })?;
+            self.words
+                .entry(word.to_string())
+                .or_default()
+                .extend(flags);
+        }
+        Ok(())
+    }
+
+    /// Number of stems.
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    fn stem_has(&self, stem: &str, flag: Flag) -> bool {
+        self.words.get(stem).is_some_and(|flags| {
+            flags.contains(&flag) && !self.forbidden.is_some_and(|f| flags.contains(&f))
+        })
+    }
+
+    fn check_stem(&self, word: &str) -> bool {
+        self.words.get(word).is_some_and(|flags| {
+            !flags
+                .iter()
+                .any(|flag| Some(*flag) == self.need_affix || Some(*flag) == self.forbidden)
+        })
+    }
+
+    /// Stems of `word` with one of its suffixes removed, that have the suffix flag.
+    fn check_suffixed(&self, word: &str, prefix: Option<&Affix>) -> bool {
+        word.char_indices()
+            .map(|(idx, _)| idx)
+            .chain([word.len()])
+            .any(|idx| {
+                let (base, add) = word.split_at(idx);
+                self.suffixes.get(add).is_some_and(|affixes| {
+                    affixes.iter().any(|affix| {
+                        if prefix.is_some() && !affix.cross_product {
+                            return false;
+                        }
+                        let stem = format!("{base}{}", affix.strip);
+                        affix.matches_suffix_of(&stem)
+                            && self.stem_has(&stem, affix.flag)
+                            && prefix.is_none_or(|prefix| self.stem_has(&stem, prefix.flag))
+                    })
+                })
+            })
+    }
+
+    fn check_prefixed(&self, word: &str) -> bool {
+        word.char_indices()
+            .map(|(idx, _)| idx)
+            .chain([word.len()])
+            .any(|idx| {
+                let (add, rest) = word.split_at(idx);
+                self.prefixes.get(add).is_some_and(|affixes| {
+                    affixes.iter().any(|affix| {
+                        let stem = format!("{}{rest}", affix.strip);
+                        affix.matches_prefix_of(&stem)
+                            && (self.stem_has(&stem, affix.flag)
+                                || affix.cross_product && self.check_suffixed(&stem, Some(affix)))
+                    })
+                })
+            })
+    }
+
+    fn check(&self, word: &str) -> bool {
+        self.check_stem(word) || self.check_suffixed(word, None) || self.check_prefixed(word)
+    }
+}
+
+impl Lexicon for HunspellDictionary {
+    /// Check the word, then its lowercase forms if it is capitalized.
+    fn is_known_word(&self, word: &str) -> bool {
+        if word.is_empty() {
+            return false;
+        }
+        if self.check(word) {
+            return true;
+        }
+        let mut chars = word.chars();
+        let Some(first) = chars.next().filter(|ch| ch.is_uppercase()) else {
+            return false;
+        };
+        let uncapitalized: String = first.to_lowercase().chain(chars).collect();
+        self.check(&uncapitalized) || self.check(&word.to_lowercase())
+    }
+}
+
+fn zero_to_empty(s: &str) -> &str {
+    if s == "0" { "" } else { s }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AFF: &str = "\
+SET UTF-8
+FLAG long
+NEEDAFFIX Zz
+FORBIDDENWORD Xx
+
+# Nouns in -ος
+SFX Aa Y 3
+SFX Aa ος ου [^ι]ος
+SFX Aa ος ο ος
+SFX Aa ος οι/Bb ος
+
+PFX Pp Y 1
+PFX Pp 0 ξανα .
+
+PFX Qq N 1
+PFX Qq 0 προ .
+
+SFX Vv Y 1
+SFX Vv 0 ω [^ω]
+";
+
+    const DIC: &str = "\
+5
+δρόμος/AaQq
+φέρν/VvPpZz
+φίλος/Aa po:noun
+ράδιος/Aa
+κακοςος/AaXx
+";
+
+    fn dict() -> HunspellDictionary {
+        HunspellDictionary::parse(AFF, DIC).unwrap()
+    }
+
+    #[test]
+    fn test_hunspell_affixes() {
+        let dict = dict();
+        let known = [
+            "δρόμος",
+            "δρόμου",
+            "δρόμοι",
+            "Δρόμος",
+            "προδρόμος",
+            "φέρνω",
+            "ξαναφέρνω",
+            "φίλοι",
+            "ράδιο",
+        ];
+        for word in known {
+            assert!(dict.is_known_word(word), "{word}");
+        }
+        // Needs an affix, bad condition, not cross product, forbidden
+        let unknown = [
+            "φέρν",
+            "ράδιου",
+            "προδρόμου",
+            "κακοςος",
+            "κακοςου",
+            "",
+            "δρόμ",
+        ];
+        for word in unknown {
+            assert!(!dict.is_known_word(word), "{word}");
+        }
+    }
+
+    #[test]
+    fn test_hunspell_errors() {
+        let err = HunspellDictionary::parse("SET ISO8859-1\n", "0\n").unwrap_err();
+        assert!(matches!(err, HunspellError::UnsupportedEncoding(_)));
+
+        let err = HunspellDictionary::parse("FLAG long\nSFX A Y 1\n", "0\n").unwrap_err();
+        assert!(matches!(err, HunspellError::Parse { line: 2, .. }), "{err}");
+
+        let err = HunspellDictionary::parse("FLAG num\n", "1\nλόγος/x\n").unwrap_err();
+        assert_eq!(err.to_string(), "line 2: invalid flag: x");
+
+        let err = HunspellDictionary::parse_bytes(b"SET UTF-8\n".to_vec(), b"1\n\xeb\n".to_vec())
+            .unwrap_err();
+        assert!(matches!(err, HunspellError::Io(_)), "{err}");
+    }
+
+    #[test]
+    fn test_hunspell_iso_8859_7() {
+        let fixtures = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures");
+        let dict = HunspellDictionary::from_files(
+            format!("{fixtures}/el_GR.aff"),
+            format!("{fixtures}/el_GR.dic"),
+        )
+        .unwrap();
+        for word in ["δρόμος", "δρόμου", "δρόμοι", "Ελλάδα", "ΐδιος", "αΰπνος"]
+        {
+            assert!(dict.is_known_word(word), "{word}");
+        }
+        assert!(!dict.is_known_word("δρόμα"));
+
+        let err = HunspellDictionary::from_files(format!("{fixtures}/missing.aff"), "missing.dic")
+            .unwrap_err();
+        assert!(matches!(err, HunspellError::Io(_)), "{err}");
+    }
+
+    #[test]
+    fn test_iso_8859_7_table() {
+        let bytes: Vec<u8> = (0xA0..=0xFF).collect();
+        let decoded = Encoding::Iso8859_7.decode(bytes).unwrap();
+        let expected = "\u{A0}‘’£€₯¦§¨©ͺ«¬\u{AD}\u{FFFD}―°±²³΄΅Ά·ΈΉΊ»Ό½ΎΏ\
+            ΐΑΒΓΔΕΖΗΘΙΚΛΜΝΞΟΠΡ\u{FFFD}ΣΤΥΦΧΨΩΪΫάέήίΰαβγδεζηθικλμνξοπρςστυφχψωϊϋόύώ\u{FFFD}";
+        assert_eq!(decoded, expected);
+    }
+}
@@ -0,0 +1,233 @@
+//! Hyphenation points, for typesetting.
+
+use crate::chars::{graphemes_el, is_combining_mark, is_greek_letter, is_greek_word};
+use crate::monotonic::{SyllabifyFn, split_punctuation};
+use crate::syllabify::syllabify;
+
+/// A hyphenation point, as given to [`HyphenationOptions::penalty_fn`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HyphenationPoint<'a> {
+    /// The word, with its punctuation.
+    pub word: &'a str,
+    /// Byte offset of the point in the word.
+    pub offset: usize,
+    pub syllables_before: usize,
+    pub syllables_after: usize,
+}
+
+/// Options for [`hyphenation_points_with_options`] and
+/// [`break_opportunities_with_options`](crate::break_opportunities_with_options).
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub struct HyphenationOptions {
+    /// Minimum number of chars before a hyphenation point.
+    pub min_prefix: usize,
+    /// Minimum number of chars after a hyphenation point.
+    pub min_suffix: usize,
+    /// Badness of breaking a line at a hyphenation point.
+    ///
+    /// Avoiding consecutive hyphenated lines is left to the line breaking algorithm.
+    pub penalty_fn: fn(&HyphenationPoint) -> i32,
+}
+
+impl HyphenationOptions {
+    pub const fn new() -> Self {
+        Self {
+            min_prefix: 2,
+            min_suffix: 2,
+            penalty_fn: default_penalty,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_min_prefix(mut self, min_prefix: usize) -> Self {
+        self.min_prefix = min_prefix;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_min_suffix(mut self, min_suffix: usize) -> Self {
+        self.min_suffix = min_suffix;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_penalty_fn(mut self, penalty_fn: fn(&HyphenationPoint) -> i32) -> Self {
+        self.penalty_fn = penalty_fn;
+        self
+    }
+}
+
+impl Default for HyphenationOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Penalty of breaks at hyphens, as TeX's `\hyphenpenalty` and `\exhyphenpenalty`.
+pub(crate) const HYPHEN_PENALTY: i32 = 50;
+
+const fn default_penalty(_point: &HyphenationPoint) -> i32 {
+    HYPHEN_PENALTY
+}
+
+/// Byte offsets in the word where it can be hyphenated.
+///
+/// Hyphenation points are the syllable boundaries of [`syllabify`], apart from
+/// those that would leave less than two letters on either side, or that are
+/// next to punctuation (ό,τι). Words that are not Greek can not be hyphenated.
+///
+/// # Example
+///
+/// ```
+/// use grac::hyphenation_points;
+///
+/// let word = "«καλημέρα»,";
+/// let points = hyphenation_points(word);
+/// assert_eq!(points, [6, 10, 14]);
+/// assert_eq!(&word[..points[0]], "«κα");
+///
+/// // Not α-πό
+/// assert_eq!(hyphenation_points("από"), []);
+/// assert_eq!(hyphenation_points("hello"), []);
+/// ```
+pub fn hyphenation_points(word: &str) -> Vec<usize> {
+    hyphenation_points_with_options(word, &HyphenationOptions::new())
+}
+
+/// Same as [`hyphenation_points`], with the minimum number of chars of
+/// [`HyphenationOptions`] on each side.
+///
+/// # Example
+///
+/// ```
+/// use grac::{HyphenationOptions, hyphenation_points_with_options};
+///
+/// let options = HyphenationOptions::new().with_min_prefix(1).with_min_suffix(3);
+/// assert_eq!(hyphenation_points_with_options("από", &options), []);
+/// assert_eq!(hyphenation_points_with_options("απόψε", &options), [2]);
+/// ```
+pub fn hyphenation_points_with_options(word: &str, options: &HyphenationOptions) -> Vec<usize> {
+    scored_hyphenation_points(word, options)
+        .into_iter()
+        .map(|(offset, _)| offset)
+        .collect()
+}
+
+/// Hyphenation points with their penalties.
+pub(crate) fn scored_hyphenation_points(
+    word: &str,
+    options: &HyphenationOptions,
+) -> Vec<(usize, i32)> {
+    scored_hyphenation_points_by(word, options, &syllabify)
+}
+
+/// Same as [`scored_hyphenation_points`], with another syllabification.
+pub(crate) fn scored_hyphenation_points_by(
+    word: &str,
+    options: &HyphenationOptions,
+    syllabify: SyllabifyFn<'_>,
+) -> Vec<(usize, i32)> {
+    let (left, core, _) = split_punctuation(word);
+    if core.is_empty() || !is_greek_word(core) {
+        return Vec::new();
+    }
+    let syllables = syllabify(core);
+    let n_chars = graphemes_el(core).count();
+    let mut points = Vec::new();
+    let mut end = 0;
+    let mut chars_before = 0;
+    for (idx, syllable) in syllables.iter().enumerate() {
+        if end > 0
+            && chars_before >= options.min_prefix.max(1)
+            && n_chars - chars_before >= options.min_suffix.max(1)
+            && is_letter_boundary(core, end)
+        {
+            let point = HyphenationPoint {
+                word,
+                offset: left.len() + end,
+                syllables_before: idx,
+                syllables_after: syllables.len() - idx,
+            };
+            points.push((point.offset, (options.penalty_fn)(&point)));
+        }
+        end += syllable.len();
+        chars_before += graphemes_el(syllable).count();
+    }
+    points
+}
+
+/// Check that the chars around a byte offset are letters.
+fn is_letter_boundary(s: &str, idx: usize) -> bool {
+    let before = s[..idx].chars().rev().find(|ch| !is_combining_mark(*ch));
+    let after = s[idx..].chars().next();
+    before.is_some_and(is_greek_letter) && after.is_some_and(is_greek_letter)
+}
+
+/// Insert `hyphen` at every hyphenation point of the word (cf. [`hyphenation_points`]).
+///
+/// # Example
+///
+/// ```
+/// use grac::hyphenate;
+///
+/// assert_eq!(hyphenate("αγαπημένος", "\u{AD}"), "αγα\u{AD}πη\u{AD}μέ\u{AD}νος");
+/// assert_eq!(hyphenate("άνθρωπος", "-"), "άν-θρω-πος");
+/// ```
+pub fn hyphenate(word: &str, hyphen: &str) -> String {
+    insert_hyphens(word, hyphen, hyphenation_points(word))
+}
+
+/// Insert `hyphen` at the given sorted byte offsets of the word.
+pub(crate) fn insert_hyphens(word: &str, hyphen: &str, points: Vec<usize>) -> String {
+    let mut out = String::with_capacity(word.len() + points.len() * hyphen.len());
+    let mut start = 0;
+    for point in points {
+        out.push_str(&word[start..point]);
+        out.push_str(hyphen);
+        start = point;
+    }
+    out.push_str(&word[start..]);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hyphenation_points() {
+        let cases = [
+            ("αγαπημένος", "αγα-πη-μέ-νος"),
+            ("ώρα", "ώρα"),
+            ("Ελλάδα", "Ελ-λά-δα"),
+            ("ό,τι", "ό,τι"),
+            ("σπίτι", "σπί-τι"),
+            ("ευχαριστώ", "ευ-χα-ρι-στώ"),
+            ("αι", "αι"),
+            ("", ""),
+            ("...", "..."),
+        ];
+        for (word, expected) in cases {
+            assert_eq!(hyphenate(word, "-"), expected, "{word}");
+        }
+    }
+
+    #[test]
+    fn test_penalty_fn() {
+        let options = HyphenationOptions {
+            penalty_fn: |point| if point.syllables_before == 1 { 500 } else { 10 },
+            ..Default::default()
+        };
+        let points = scored_hyphenation_points("«θάλασσα»", &options);
+        assert_eq!(points, [(6, 500), (12, 10)]);
+        let default = scored_hyphenation_points("θάλασσα", &HyphenationOptions::new());
+        assert_eq!(default, [(4, HYPHEN_PENALTY), (10, HYPHEN_PENALTY)]);
+    }
+
+    #[test]
+    fn test_hyphenation_points_decomposed() {
+        let word = "α\u{314}\u{301}νθρωπος";
+        assert_eq!(hyphenate(word, "-"), "α\u{314}\u{301}ν-θρω-πος");
+    }
+}
@@ -0,0 +1,180 @@
+//! Incremental monotonic conversion for edited buffers.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::ops::Range;
+
+use crate::monotonic::{Converter, DiaeresisFixer, DiaeresisPolicy, word_changes};
+use crate::span::Change;
+use crate::word::GreekWordBuf;
+
+/// Default of [`IncrementalConverter::max_entries`].
+const DEFAULT_MAX_ENTRIES: usize = 1 << 16;
+
+/// Keeps a document and reports the monotonic [`Change`]s of edited regions.
+///
+/// Only the lines touched by an edit are re-processed, and converted words are
+/// cached, so that editors can call [`update`](Self::update) on every keystroke.
+///
+/// The cache stops growing after [`max_entries`](Self::max_entries) words
+/// (65536 by default), and can be emptied with [`clear_cache`](Self::clear_cache).
+///
+/// # Example
+///
+/// ```
+/// use grac::IncrementalConverter;
+///
+/// let mut converter = IncrementalConverter::new("Καλημέρα.\nΤί κάνεις;");
+/// assert_eq!(converter.changes().len(), 1);
+///
+/// // Replace "Τί" by "Πῶς"
+/// let changes = converter.update(18..22, "Πῶς");
+/// assert_eq!(converter.text(), "Καλημέρα.\nΠῶς κάνεις;");
+/// assert_eq!(changes.len(), 1);
+/// assert_eq!(changes[0].span, 18..25);
+/// assert_eq!(changes[0].new, "Πώς");
+/// ```
+pub struct IncrementalConverter {
+    text: String,
+    cache: HashMap<String, String>,
+    max_entries: usize,
+    diaereses: Cow<'static, DiaeresisFixer>,
+}
+
+impl IncrementalConverter {
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            cache: HashMap::new(),
+            max_entries: DEFAULT_MAX_ENTRIES,
+            diaereses: DiaeresisFixer::shared(&DiaeresisPolicy::default()),
+        }
+    }
+
+    /// Stop caching new words after `max_entries` words, as
+    /// [`CachedSyllabifier::max_entries`](crate::CachedSyllabifier::max_entries)
+    /// does.
+    #[must_use]
+    pub const fn max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = max_entries;
+        self
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Number of cached words.
+    pub fn cache_len(&self) -> usize {
+        self.cache.len()
+    }
+
+    /// Forget the cached words, and free their memory.
+    pub fn clear_cache(&mut self) {
+        self.cache = HashMap::new();
+    }
+
+    /// Changes over the whole document.
+    pub fn changes(&mut self) -> Vec<Change> {
+        self.changes_in(0..self.text.len())
+    }
+
+    /// Replace the bytes at `range` by `new_text`.
+    ///
+    /// Return the changes of the re-processed region, that is, every line
+    /// touched by the edit. Spans refer to the updated text.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds or not on char boundaries
+    /// (cf. [`try_update`](Self::try_update)).
+    pub fn update(&mut self, range: Range<usize>, new_text: &str) -> Vec<Change> {
+        let start = range.start;
+        self.text.replace_range(range, new_text);
+        let end = start + new_text.len();
+
+        let region_start = self.text[..start].rfind('\n').map_or(0, |i| i + 1);
+        let region_end = self.text[end..]
+            .find('\n')
+            .map_or(self.text.len(), |i| end + i + 1);
+        self.changes_in(region_start..region_end)
+    }
+
+    /// Same as [`update`](Self::update), but returns `None` and leaves the text
+    /// untouched if the range is out of bounds or not on char boundaries.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use grac::IncrementalConverter;
+    ///
+    /// let mut converter = IncrementalConverter::new("καὶ");
+    /// assert!(converter.try_update(1..1, "x").is_none());
+    /// assert!(converter.try_update(0..10, "").is_none());
+    /// assert!(converter.try_update(0..0, "Ὁ ").is_some());
+    /// ```
+    pub fn try_update(&mut self, range: Range<usize>, new_text: &str) -> Option<Vec<Change>> {
+        self.text.get(range.clone())?;
+        Some(self.update(range, new_text))
+    }
+
+    fn changes_in(&mut self, region: Range<usize>) -> Vec<Change> {
+        let Self {
+            text,
+            cache,
+            max_entries,
+            diaereses,
+        } = self;
+        let offset = region.start;
+        let mut buf = GreekWordBuf::new();
+        let mut changes = word_changes(&text[region], |word| {
+            if let Some(converted) = cache.get(word) {
+                return converted.clone();
+            }
+            let converted = Converter::default().convert_word(diaereses, &mut buf, word);
+            if cache.len() < *max_entries {
+                cache.insert(word.to_string(), converted.clone());
+            }
+            converted
+        });
+        for change in &mut changes {
+            change.span = change.span.start + offset..change.span.end + offset;
+        }
+        changes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::monotonic_changes;
+
+    #[test]
+    fn test_incremental_agrees_with_full_conversion() {
+        let mut converter = IncrementalConverter::new("ἄνθρωπος\nκαὶ\nγάϊδαρος");
+        let edits = [(0..0, "Ὁ "), (13..13, "-πρωὶ "), (3..3, "\n\n")];
+        for (range, new_text) in edits {
+            let changes = converter.update(range, new_text);
+            let expected = monotonic_changes(converter.text());
+            for change in &changes {
+                assert!(expected.contains(change), "{change:?}");
+            }
+        }
+        assert_eq!(converter.changes(), monotonic_changes(converter.text()));
+    }
+
+    #[test]
+    fn test_incremental_cache() {
+        let mut converter = IncrementalConverter::new("καὶ καὶ καὶ");
+        assert_eq!(converter.changes().len(), 3);
+        assert_eq!(converter.cache_len(), 1);
+        converter.clear_cache();
+        assert_eq!(converter.cache_len(), 0);
+
+        let mut converter = IncrementalConverter::new("καὶ τὸ καὶ").max_entries(1);
+        assert_eq!(converter.changes().len(), 3);
+        assert_eq!(converter.cache_len(), 1);
+        assert_eq!(converter.update(0..0, "ὁ ").len(), 4);
+        assert_eq!(converter.cache_len(), 1);
+    }
+}
@@ -0,0 +1,170 @@
+//! Dead keys for Greek input methods.
+//!
+//! Follows the usual Greek layouts: a dead key is typed before the letter it
+//! applies to, and several dead keys can be combined for polytonic letters.
+//!
+//! | Key | Diacritic       |
+//! |-----|-----------------|
+//! | `;` | acute (tonos)   |
+//! | `:` | diaeresis       |
+//! | `[` | grave           |
+//! | `]` | circumflex      |
+//! | `'` | smooth          |
+//! | `"` | rough           |
+//! | `{` | iota subscript  |
+
+use unicode_normalization::UnicodeNormalization;
+
+use crate::accents::Diacritic;
+
+/// Dead keys and the diacritic they add.
+pub const DEAD_KEYS: [(char, char); 7] = [
+    (';', Diacritic::ACUTE),
+    (':', Diacritic::DIAERESIS),
+    ('[', Diacritic::GRAVE),
+    (']', Diacritic::CIRCUMFLEX),
+    ('\'', Diacritic::SMOOTH),
+    ('"', Diacritic::ROUGH),
+    ('{', Diacritic::IOTA_SUBSCRIPT),
+];
+
+/// The diacritic added by a dead key, if `key` is one.
+pub fn dead_key_diacritic(key: char) -> Option<char> {
+    DEAD_KEYS
+        .iter()
+        .find_map(|&(dead_key, diacritic)| (dead_key == key).then_some(diacritic))
+}
+
+/// Dead keys typed so far.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ComposeState {
+    keys: String,
+}
+
+impl ComposeState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// The dead keys typed so far, to be displayed as preedit text.
+    pub fn keys(&self) -> &str {
+        &self.keys
+    }
+}
+
+/// Outcome of a keystroke.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ComposeResult {
+    /// Waiting for more keys.
+    Pending(ComposeState),
+    /// Text to insert. The state is reset.
+    Commit(String),
+}
+
+/// Feed a key to the dead key state machine.
+///
+/// Typing a dead key twice commits it, which is how to type a literal `;`.
+/// If the letter can not take the pending diacritics, the typed keys are
+/// committed as they are.
+///
+/// # Example
+///
+/// ```
+/// use grac::keyboard::{ComposeResult, ComposeState, compose_keystroke};
+///
+/// let ComposeResult::Pending(state) = compose_keystroke(ComposeState::new(), ';') else {
+///     unreachable!()
+/// };
+/// assert_eq!(compose_keystroke(state, 'α'), ComposeResult::Commit("ά".to_string()));
+///
+/// // Polytonic: rough breathing, circumflex and iota subscript.
+/// let mut state = ComposeState::new();
+/// for key in ['"', ']', '{'] {
+///     let ComposeResult::Pending(next) = compose_keystroke(state, key) else {
+///         unreachable!()
+///     };
+///     state = next;
+/// }
+/// assert_eq!(compose_keystroke(state, 'ω'), ComposeResult::Commit("ᾧ".to_string()));
+///
+/// // No accented κ
+/// let ComposeResult::Pending(state) = compose_keystroke(ComposeState::new(), ';') else {
+///     unreachable!()
+/// };
+/// assert_eq!(compose_keystroke(state, 'κ'), ComposeResult::Commit(";κ".to_string()));
+/// ```
+pub fn compose_keystroke(mut state: ComposeState, key: char) -> ComposeResult {
+    if dead_key_diacritic(key).is_some() {
+        if state.keys.contains(key) {
+            return ComposeResult::Commit(state.keys);
+        }
+        state.keys.push(key);
+        return ComposeResult::Pending(state);
+    }
+    if state.is_empty() {
+        return ComposeResult::Commit(key.to_string());
+    }
+
+    // Dead keys can be typed in any order, but acute and diaeresis only
+    // compose in the order of the Unicode decompositions: breathing or
+    // diaeresis, then accent, then iota subscript.
+    let mut marks: Vec<char> = state.keys.chars().filter_map(dead_key_diacritic).collect();
+    marks.sort_by_key(|&mark| match mark {
+        Diacritic::SMOOTH | Diacritic::ROUGH | Diacritic::DIAERESIS => 0,
+        Diacritic::IOTA_SUBSCRIPT => 2,
+        _ => 1,
+    });
+    let composed: String = std::iter::once(key).chain(marks).nfc().collect();
+    if composed.chars().count() == 1 {
+        ComposeResult::Commit(composed)
+    } else {
+        state.keys.push(key);
+        ComposeResult::Commit(state.keys)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn type_keys(keys: &str) -> String {
+        let mut out = String::new();
+        let mut state = ComposeState::new();
+        for key in keys.chars() {
+            match compose_keystroke(state, key) {
+                ComposeResult::Pending(next) => state = next,
+                ComposeResult::Commit(text) => {
+                    out.push_str(&text);
+                    state = ComposeState::new();
+                }
+            }
+        }
+        out.push_str(state.keys());
+        out
+    }
+
+    #[test]
+    fn test_compose_keystroke() {
+        let cases = [
+            ("καλημ;ερα", "καλημέρα"),
+            ("προ:υπ;οθεση", "προϋπόθεση"),
+            (":;ι", "ΐ"),
+            (";:ι", "ΐ"),
+            ("'[Ε", "Ἒ"),
+            ("τ]{η", "τῇ"),
+            // Typed twice
+            (";;", ";"),
+            ("ναι;;", "ναι;"),
+            // Not composable
+            (":α", ":α"),
+            ("ψ;", "ψ;"),
+        ];
+        for (keys, expected) in cases {
+            assert_eq!(type_keys(keys), expected, "{keys}");
+        }
+    }
+}
@@ -0,0 +1,314 @@
+//! Word lists, to check the output of heuristics against a real lexicon.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::accents::suggest_accented_forms;
+use crate::constants::has_multiple_pronunciations;
+use crate::prefix::split_compound_by;
+use crate::syllabify::{Merge, Syllables, syllabify, syllabify_with_merge};
+
+pub use crate::hunspell::{HunspellDictionary, HunspellError};
+
+/// A set of known words, optionally with their frequencies.
+pub trait Lexicon {
+    fn is_known_word(&self, word: &str) -> bool;
+
+    /// Relative frequency of the word, if known. Higher is more frequent.
+    fn frequency(&self, _word: &str) -> Option<f32> {
+        None
+    }
+
+    /// Relative frequency of a reading of the word, given as its syllables
+    /// joined with hyphens (φυ-λά-κια), if known. Higher is more frequent.
+    fn reading_frequency(&self, _word: &str, _reading: &str) -> Option<f32> {
+        None
+    }
+}
+
+impl Lexicon for HashMap<String, f32> {
+    fn is_known_word(&self, word: &str) -> bool {
+        self.contains_key(word)
+    }
+
+    fn frequency(&self, word: &str) -> Option<f32> {
+        self.get(word).copied()
+    }
+}
+
+/// Frequencies of the readings of every word: φυλάκια → {φυ-λά-κι-α → 0.2, φυ-λά-κια → 0.8}.
+impl Lexicon for HashMap<String, HashMap<String, f32>> {
+    fn is_known_word(&self, word: &str) -> bool {
+        self.contains_key(word)
+    }
+
+    /// The sum of the frequencies of the readings of the word.
+    fn frequency(&self, word: &str) -> Option<f32> {
+        self.get(word).map(|readings| readings.values().sum())
+    }
+
+    fn reading_frequency(&self, word: &str, reading: &str) -> Option<f32> {
+        self.get(word)?.get(reading).copied()
+    }
+}
+
+impl Lexicon for HashSet<String> {
+    fn is_known_word(&self, word: &str) -> bool {
+        self.contains(word)
+    }
+}
+
+impl Lexicon for HashSet<&str> {
+    fn is_known_word(&self, word: &str) -> bool {
+        self.contains(word)
+    }
+}
+
+impl<L: Lexicon + ?Sized> Lexicon for &L {
+    fn is_known_word(&self, word: &str) -> bool {
+        (**self).is_known_word(word)
+    }
+
+    fn frequency(&self, word: &str) -> Option<f32> {
+        (**self).frequency(word)
+    }
+
+    fn reading_frequency(&self, word: &str, reading: &str) -> Option<f32> {
+        (**self).reading_frequency(word, reading)
+    }
+}
+
+/// A reading of an ambiguous word, for [`syllabify_with_lexicon`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Candidate<'a> {
+    pub syllables: Syllables<'a>,
+    /// Frequency of the reading, from [`Lexicon::reading_frequency`], if known.
+    pub frequency: Option<f32>,
+}
+
+/// Why a reading was chosen by [`syllabify_with_lexicon`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Decision {
+    /// The word has a single reading.
+    Unambiguous,
+    /// The reading with the highest frequency.
+    Frequency,
+    /// No frequency settles the choice: the reading of [`syllabify`].
+    Default,
+}
+
+/// Result of [`syllabify_with_lexicon`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Analysis<'a> {
+    /// The chosen reading.
+    pub syllables: Syllables<'a>,
+    pub decision: Decision,
+    /// Every reading, starting with the one of [`syllabify`].
+    pub candidates: Vec<Candidate<'a>>,
+}
+
+/// Same as [`syllabify`], using frequencies to choose between the readings of
+/// words with multiple pronunciations (cf. [`has_multiple_pronunciations`]).
+///
+/// These are words where synizesis at the last syllable is optional: φυλάκια
+/// can be φυ-λά-κι-α (guard posts) or φυ-λά-κια (small prisons). Frequencies
+/// are looked up with [`Lexicon::reading_frequency`], by the word and the
+/// syllables of the reading joined with hyphens, and ties are left to
+/// [`syllabify`].
+///
+/// # Example
+///
+/// ```
+/// use std::collections::HashMap;
+/// use grac::lexicon::{Decision, syllabify_with_lexicon};
+///
+/// let readings = HashMap::from([
+///     ("φυ-λά-κι-α".to_string(), 0.2),
+///     ("φυ-λά-κια".to_string(), 0.8),
+/// ]);
+/// let frequencies = HashMap::from([("φυλάκια".to_string(), readings)]);
+/// let analysis = syllabify_with_lexicon("φυλάκια", &frequencies);
+/// assert_eq!(analysis.syllables.join("-"), "φυ-λά-κια");
+/// assert_eq!(analysis.decision, Decision::Frequency);
+/// assert_eq!(analysis.candidates.len(), 2);
+///
+/// let analysis = syllabify_with_lexicon("άνθρωπος", &frequencies);
+/// assert_eq!(analysis.decision, Decision::Unambiguous);
+/// ```
+pub fn syllabify_with_lexicon<'a>(s: &'a str, lexicon: &impl Lexicon) -> Analysis<'a> {
+    let mut readings = vec![syllabify(s)];
+    if has_multiple_pronunciations(s) {
+        let merged = syllabify_with_merge(s, Merge::from_indices(&[1]));
+        if merged != readings[0] {
+            readings.push(merged);
+        }
+    }
+
+    let candidates: Vec<Candidate> = readings
+        .into_iter()
+        .map(|syllables| {
+            let frequency = lexicon.reading_frequency(s, &syllables.join("-"));
+            Candidate {
+                syllables,
+                frequency,
+            }
+        })
+        .collect();
+
+    let (syllables, decision) = if candidates.len() == 1 {
+        (candidates[0].syllables.clone(), Decision::Unambiguous)
+    } else {
+        let best = candidates
+            .iter()
+            .filter_map(|candidate| Some((candidate, candidate.frequency?)))
+            .max_by(|(_, a), (_, b)| a.total_cmp(b));
+        let tied = best.is_some_and(|(_, max)| {
+            candidates
+                .iter()
+                .filter(|candidate| candidate.frequency == Some(max))
+                .count()
+                > 1
+        });
+        match best {
+            Some((candidate, _)) if !tied => (candidate.syllables.clone(), Decision::Frequency),
+            _ => (candidates[0].syllables.clone(), Decision::Default),
+        }
+    };
+
+    Analysis {
+        syllables,
+        decision,
+        candidates,
+    }
+}
+
+/// Same as [`split_compound`](crate::split_compound), keeping the splits
+/// where the rest of the word is known to the lexicon instead of those before
+/// a hiatus.
+///
+/// # Example
+///
+/// ```
+/// use std::collections::HashSet;
+/// use grac::lexicon::split_compound_with_lexicon;
+///
+/// let lexicon = HashSet::from(["σταθμός", "καρδιογράφημα", "γράφημα"]);
+/// assert_eq!(split_compound_with_lexicon("ραδιοσταθμός", &lexicon), ["ραδιο", "σταθμός"]);
+/// assert_eq!(
+///     split_compound_with_lexicon("ηλεκτροκαρδιογράφημα", &lexicon),
+///     ["ηλεκτρο", "καρδιο", "γράφημα"]
+/// );
+/// assert_eq!(split_compound_with_lexicon("αεροελεγκτής", &lexicon), ["αεροελεγκτής"]);
+/// ```
+pub fn split_compound_with_lexicon<'a>(word: &'a str, lexicon: &impl Lexicon) -> Vec<&'a str> {
+    split_compound_by(word, |right| lexicon.is_known_word(right))
+}
+
+/// Same as [`suggest_accented_forms`], ranking the candidates by their
+/// frequency in the lexicon.
+///
+/// Candidates with a higher [`Lexicon::frequency`] come first, then known
+/// words without a frequency, then the rest. Ties keep the order of
+/// [`suggest_accented_forms`].
+///
+/// # Example
+///
+/// ```
+/// use std::collections::{HashMap, HashSet};
+/// use grac::lexicon::suggest_accented_forms_with_lexicon;
+///
+/// let frequencies = HashMap::from([
+///     ("καρδιά".to_string(), 0.9),
+///     ("καρδία".to_string(), 0.1),
+/// ]);
+/// assert_eq!(
+///     suggest_accented_forms_with_lexicon("καρδια", &frequencies),
+///     ["καρδιά", "καρδία", "κάρδια"]
+/// );
+///
+/// let lexicon = HashSet::from(["ανθρωπός"]);
+/// assert_eq!(
+///     suggest_accented_forms_with_lexicon("ανθρωπος", &lexicon),
+///     ["ανθρωπός", "άνθρωπος", "ανθρώπος"]
+/// );
+/// ```
+pub fn suggest_accented_forms_with_lexicon(
+    word: impl AsRef<str>,
+    lexicon: &impl Lexicon,
+) -> Vec<String> {
+    let mut candidates: Vec<_> = suggest_accented_forms(word)
+        .into_iter()
+        .map(|candidate| {
+            let frequency = lexicon.frequency(&candidate);
+            let known = frequency.is_some() || lexicon.is_known_word(&candidate);
+            (candidate, known, frequency.unwrap_or(f32::NEG_INFINITY))
+        })
+        .collect();
+    candidates.sort_by(|(_, known_a, freq_a), (_, known_b, freq_b)| {
+        known_b.cmp(known_a).then(freq_b.total_cmp(freq_a))
+    });
+    candidates
+        .into_iter()
+        .map(|(candidate, _, _)| candidate)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_syllabify_with_lexicon_ties() {
+        let readings = |readings: &[(&str, f32)]| {
+            readings
+                .iter()
+                .map(|&(reading, frequency)| (reading.to_string(), frequency))
+                .collect::<HashMap<_, _>>()
+        };
+        let frequencies = HashMap::from([
+            (
+                "άγια".to_string(),
+                readings(&[("ά-γι-α", 1.0), ("ά-για", 1.0)]),
+            ),
+            ("ίδιο".to_string(), readings(&[("ί-διο", 1.0)])),
+        ]);
+        // Same frequency
+        let analysis = syllabify_with_lexicon("άγια", &frequencies);
+        assert_eq!(analysis.decision, Decision::Default);
+        assert_eq!(analysis.syllables, syllabify("άγια"));
+        // A single known frequency
+        let analysis = syllabify_with_lexicon("ίδιο", &frequencies);
+        assert_eq!(analysis.decision, Decision::Frequency);
+        assert_eq!(analysis.syllables.join("-"), "ί-διο");
+        assert_eq!(frequencies.frequency("άγια"), Some(2.0));
+        // No frequencies
+        for analysis in [
+            syllabify_with_lexicon("ήλιο", &frequencies),
+            syllabify_with_lexicon("ήλιο", &HashSet::<String>::new()),
+        ] {
+            assert_eq!(analysis.decision, Decision::Default);
+            assert!(analysis.candidates.iter().all(|c| c.frequency.is_none()));
+        }
+    }
+
+    #[test]
+    fn test_suggest_accented_forms_with_lexicon() {
+        let frequencies = HashMap::from([
+            ("πώς".to_string(), 2.0),
+            ("πως".to_string(), 1.0),
+            ("καλημέρα".to_string(), 1.0),
+        ]);
+        assert_eq!(
+            suggest_accented_forms_with_lexicon("πως", &frequencies),
+            ["πώς", "πως"]
+        );
+        assert_eq!(
+            suggest_accented_forms_with_lexicon("καλημερα", &frequencies)[0],
+            "καλημέρα"
+        );
+        // No known candidate: the order of suggest_accented_forms
+        assert_eq!(
+            suggest_accented_forms_with_lexicon("ανθρωπος", &HashSet::<String>::new()),
+            suggest_accented_forms("ανθρωπος")
+        );
+    }
+}
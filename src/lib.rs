@@ -2,34 +2,253 @@
 pub mod macros;
 
 mod accents;
+mod cache;
+mod case;
 mod chars;
+mod context;
+mod crasis;
+mod exceptions;
+mod ext;
+mod hunspell;
+mod hyphenate;
+mod incremental;
+mod lint;
 mod monotonic;
+mod ocr;
+mod patch;
+mod phonotactics;
+mod pipeline;
+mod polytonic;
+mod prefix;
+mod protect;
+mod punctuation;
+mod puzzle;
+mod report;
+mod romanize;
+mod span;
+mod spell;
+mod stem;
 mod syllabify;
 mod synizesis;
+mod tokenize;
+mod tts;
+mod whitespace;
+mod word;
+mod wrap;
 
 pub mod constants;
+#[cfg(feature = "corpus-tools")]
+pub mod corpus;
+pub mod explain;
+#[cfg(feature = "tei")]
+pub mod formats;
+#[cfg(feature = "html")]
+pub mod html;
+pub mod keyboard;
+pub mod lexicon;
+#[cfg(feature = "proptest-support")]
+pub mod strategies;
+#[cfg(feature = "testing")]
+pub mod testing;
+#[cfg(feature = "wiktionary")]
+pub mod wiktionary;
 
 pub use accents::Diacritic;
+pub use accents::acute_to_circumflex_at;
 pub use accents::add_acute_at;
+pub use accents::add_diacritic_at_char;
+pub use accents::circumflex_pos;
+pub use accents::diacritic_char_pos;
+pub use accents::diacritic_map;
 pub use accents::diacritic_pos;
+pub use accents::fix_required_diaeresis;
 pub use accents::has_any_diacritic;
+pub use accents::has_circumflex;
 pub use accents::has_diacritic;
+pub use accents::has_diacritic_at;
 pub use accents::has_diacritics;
 pub use accents::remove_all_diacritics;
+pub use accents::remove_all_diacritics_cow;
 pub use accents::remove_diacritic_at;
+pub use accents::remove_diacritic_at_char;
 pub use accents::remove_diacritics;
+pub use accents::remove_diacritics_chars;
+pub use accents::suggest_accented_forms;
+
+pub use cache::CachedSyllabifier;
+
+pub use case::AccentStyle;
+pub use case::CaseMap;
+pub use case::SentenceCaseOptions;
+pub use case::TitlecaseOptions;
+pub use case::fix_sentence_capitalization;
+pub use case::fix_sentence_capitalization_with_options;
+pub use case::fold_case_with_map;
+pub use case::titlecase_greek;
+pub use case::titlecase_greek_with_options;
+pub use case::to_allcaps;
 
 pub use chars::base_lower;
+pub use chars::base_lower_into;
+pub use chars::base_lower_str;
 pub use chars::ends_with_diphthong;
+pub use chars::ends_with_diphthong_chars;
+pub use chars::graphemes_el;
+pub use chars::greek_fraction;
+pub use chars::greek_fraction_sampled;
+pub use chars::is_archaic_letter;
 pub use chars::is_greek_char;
+pub use chars::is_greek_char_strict;
 pub use chars::is_greek_letter;
 pub use chars::is_greek_word;
+pub use chars::is_greek_word_chars;
+pub use chars::is_greek_word_strict;
+pub use chars::to_upper_char;
+pub use chars::to_upper_str;
+
+pub use context::GracContext;
+
+pub use crasis::expand_crasis;
+pub use crasis::expand_elision;
+pub use crasis::has_coronis;
+pub use crasis::is_elided;
+
+pub use exceptions::EXCEPTION_TABLE_VERSION;
+pub use exceptions::EmbeddedTable;
+pub use exceptions::ExceptionTable;
+pub use exceptions::ExceptionTableError;
+
+pub use ext::GreekStrExt;
+
+pub use hyphenate::HyphenationOptions;
+pub use hyphenate::HyphenationPoint;
+pub use hyphenate::hyphenate;
+pub use hyphenate::hyphenation_points;
+pub use hyphenate::hyphenation_points_with_options;
 
+pub use incremental::IncrementalConverter;
+
+pub use lint::Lint;
+pub use lint::LintKind;
+pub use lint::lint_monotonic;
+
+pub use monotonic::DiaeresisPolicy;
+pub use monotonic::MonotonicOptions;
+pub use monotonic::WordSeparators;
+pub use monotonic::monotonic_changes;
+pub use monotonic::monotonic_changes_utf16;
+pub use monotonic::normalize_diaereses;
+pub use monotonic::normalize_diaereses_cow;
 pub use monotonic::split_punctuation;
 pub use monotonic::to_monotonic;
+pub use monotonic::to_monotonic_cow;
+pub use monotonic::to_monotonic_stream;
+pub use monotonic::to_monotonic_with;
+pub use monotonic::to_monotonic_with_options;
+
+pub use ocr::dehyphenate;
+pub use ocr::ocr_diacritic_changes;
+pub use ocr::repair_ocr_diacritics;
+
+pub use patch::PatchError;
+pub use patch::apply_changes;
+pub use patch::invert_changes;
+pub use patch::unified_diff;
+
+pub use phonotactics::LengthRange;
+pub use phonotactics::generate_pseudowords;
+pub use phonotactics::invalid_clusters;
+pub use phonotactics::is_phonotactically_valid;
+
+pub use pipeline::Pipeline;
+pub use pipeline::PipelineOutput;
+
+pub use polytonic::PolytonicError;
+pub use polytonic::PolytonicErrorKind;
+pub use polytonic::apply_barytonesis;
+pub use polytonic::apply_nu_ephelkystikon;
+pub use polytonic::lift_graves;
+pub use polytonic::validate_polytonic;
+
+pub use prefix::segment_prefix;
+pub use prefix::split_compound;
+
+pub use protect::Delimiter;
+pub use protect::ProtectedRegions;
+
+pub use punctuation::DashStyle;
+pub use punctuation::GreekPunct;
+pub use punctuation::GreekPunctuationForm;
+pub use punctuation::QuoteStyle;
+pub use punctuation::normalize_dashes;
+pub use punctuation::normalize_dashes_cow;
+pub use punctuation::normalize_greek_punctuation;
+pub use punctuation::normalize_greek_punctuation_cow;
+pub use punctuation::normalize_quotes;
+pub use punctuation::normalize_quotes_cow;
+pub use punctuation::punctuation_kind;
+
+pub use puzzle::anagram_key;
+pub use puzzle::matches_pattern;
+
+pub use report::ConversionReport;
+pub use report::ReportedWord;
+pub use report::ReviewSample;
+pub use report::WordKind;
+pub use report::sample_for_review;
+pub use report::to_monotonic_with_report;
+
+pub use romanize::romanize;
+
+pub use span::Change;
+pub use span::OffsetEncoder;
+pub use span::SpanEncoding;
+pub use span::encode_changes;
+pub use span::encode_spans;
+
+pub use spell::EditCosts;
+pub use spell::greek_edit_distance;
+pub use spell::greek_edit_distance_with_costs;
+pub use spell::rank_corrections;
+
+pub use stem::stem;
 
 pub use syllabify::Merge;
+pub use syllabify::SyllabifyOptions;
 pub use syllabify::Syllables;
 pub use syllabify::is_vowel;
+pub use syllabify::lookup_syllables;
 pub use syllabify::syllabify;
+pub use syllabify::syllabify_chars;
 pub use syllabify::syllabify_with_merge;
+pub use syllabify::syllabify_with_options;
+pub use syllabify::syllable_spans;
+pub use syllabify::syllable_spans_utf16;
+
+pub use tokenize::Token;
+pub use tokenize::TokenKind;
+pub use tokenize::for_each_word_with_context;
+pub use tokenize::syllabify_words;
+pub use tokenize::syllabify_words_outside;
+pub use tokenize::syllabify_words_with_options;
+pub use tokenize::tokenize;
+pub use tokenize::tokenize_utf16;
+
+pub use tts::TtsSyllable;
+pub use tts::TtsToken;
+pub use tts::TtsTokenKind;
+pub use tts::number_to_words;
+pub use tts::tts_tokens;
+
+pub use whitespace::WhitespaceOptions;
+pub use whitespace::normalize_whitespace;
+pub use whitespace::normalize_whitespace_cow;
+
+pub use word::GreekWordBuf;
+
+pub use wrap::BreakKind;
+pub use wrap::BreakPoint;
+pub use wrap::FORCED_BREAK;
+pub use wrap::break_opportunities;
+pub use wrap::break_opportunities_with_options;
+pub use wrap::wrap_greek;
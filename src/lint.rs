@@ -0,0 +1,119 @@
+//! Monotonic linting.
+//!
+//! Reports the words that [`to_monotonic`](crate::to_monotonic) would change,
+//! together with the reason why.
+
+use crate::accents::{Diacritic, has_diacritics, has_diaeresis};
+use crate::monotonic::monotonic_changes;
+use crate::span::Change;
+use crate::syllabify::syllabify;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LintKind {
+    /// The word has polytonic diacritics: ἄνθρωπος
+    Polytonic,
+    /// The diaeresis is not needed after an accented vowel: γάϊδαρος
+    SuperfluousDiaeresis,
+    /// Monosyllables are not accented: πιά
+    AccentedMonosyllable,
+    /// Only one accent is kept: πρᾶγμά
+    DoubleAccent,
+    /// Any other change (ex. special cases: ποιὸς > ποιος)
+    Other,
+}
+
+impl LintKind {
+    pub const fn message(self) -> &'static str {
+        match self {
+            Self::Polytonic => "polytonic diacritics in monotonic text",
+            Self::SuperfluousDiaeresis => "superfluous diaeresis",
+            Self::AccentedMonosyllable => "accented monosyllable",
+            Self::DoubleAccent => "word with two accents",
+            Self::Other => "not in monotonic form",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lint {
+    pub kind: LintKind,
+    /// Byte span of the word, and its suggested replacement.
+    pub change: Change,
+}
+
+const POLYTONIC_DIACRITICS: [char; 5] = [
+    Diacritic::GRAVE,
+    Diacritic::CIRCUMFLEX,
+    Diacritic::IOTA_SUBSCRIPT,
+    Diacritic::SMOOTH,
+    Diacritic::ROUGH,
+];
+
+fn lint_kind(change: &Change) -> LintKind {
+    let old = change.old.as_str();
+    if has_diacritics(old, &POLYTONIC_DIACRITICS) {
+        LintKind::Polytonic
+    } else if has_diaeresis(old) && !has_diaeresis(change.new.as_str()) {
+        LintKind::SuperfluousDiaeresis
+    } else if syllabify(&change.new).len() == 1 {
+        LintKind::AccentedMonosyllable
+    } else if old.chars().count() == change.new.chars().count() {
+        LintKind::DoubleAccent
+    } else {
+        LintKind::Other
+    }
+}
+
+/// Lint text that is expected to be in monotonic Greek.
+///
+/// # Example
+///
+/// ```
+/// use grac::{LintKind, lint_monotonic};
+///
+/// let lints = lint_monotonic("Ο γάϊδαρος πιά δεν τρῶει.");
+/// let kinds: Vec<_> = lints.iter().map(|lint| lint.kind).collect();
+/// assert_eq!(
+///     kinds,
+///     [LintKind::SuperfluousDiaeresis, LintKind::AccentedMonosyllable, LintKind::Polytonic]
+/// );
+/// assert_eq!(lints[2].change.new, "τρώει");
+/// ```
+pub fn lint_monotonic(s: impl AsRef<str>) -> Vec<Lint> {
+    lint_changes(monotonic_changes(s))
+}
+
+pub(crate) fn lint_changes(changes: Vec<Change>) -> Vec<Lint> {
+    changes
+        .into_iter()
+        .map(|change| Lint {
+            kind: lint_kind(&change),
+            change,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lint_kinds() {
+        let cases = [
+            ("ἄνθρωπος", LintKind::Polytonic),
+            ("μπέϊκον", LintKind::SuperfluousDiaeresis),
+            ("πιό", LintKind::AccentedMonosyllable),
+            ("είναί", LintKind::DoubleAccent),
+        ];
+        for (word, expected) in cases {
+            let lints = lint_monotonic(word);
+            assert_eq!(lints.len(), 1, "{word}");
+            assert_eq!(lints[0].kind, expected, "{word}");
+        }
+    }
+
+    #[test]
+    fn test_lint_monotonic_text() {
+        assert!(lint_monotonic("Ο άνθρωπος είναι εδώ.").is_empty());
+    }
+}
@@ -2,7 +2,10 @@
 //!
 //! Cf. <https://users.rust-lang.org/t/expanding-const-str-arrays/126374/6>
 
-/// Expand the cartesian product of two const &str arrays.
+use crate::chars::{is_greek_char, is_greek_letter, to_upper_char};
+use crate::syllabify::{char_before, syllable_starts_rev};
+
+/// Expand the cartesian product of const &str arrays.
 ///
 /// # Example
 ///
@@ -12,45 +15,86 @@
 /// const LEMMAS: [&str; 2] = ["νέ", "νι"];
 /// const ENDINGS: [&str; 3] = ["ος", "ο", "ου"];
 /// const EXPANDED: [&str; 6] = expand!(LEMMAS, ENDINGS);
-/// assert_eq!(EXPANDED, ["νέος", "νέο", "νέου", "νιος", "νιο", "νιου"])
+/// assert_eq!(EXPANDED, ["νέος", "νέο", "νέου", "νιος", "νιο", "νιου"]);
+///
+/// // Any number of arrays, ex. prefix × stem × ending
+/// const PREFIXES: [&str; 2] = ["", "ξανα"];
+/// const STEMS: [&str; 1] = ["γράφ"];
+/// const VERB_ENDINGS: [&str; 2] = ["ω", "εις"];
+/// const VERBS: [&str; 4] = expand!(PREFIXES, STEMS, VERB_ENDINGS);
+/// assert_eq!(VERBS, ["γράφω", "γράφεις", "ξαναγράφω", "ξαναγράφεις"]);
 /// ```
 #[macro_export]
 macro_rules! expand {
+    // Base case: the product of two arrays
     ($l:expr, $r:expr $(,)?) => {{
+        const N: usize = $l.len() * $r.len();
         let (mem, indices) = &const {
             const L: usize = $crate::macros::__cartesian_product_capacity(&$l, &$r);
             let mut mem = [0; L];
-            let mut indices = [(0, 0); $l.len() * $r.len()];
+            let mut indices = [(0, 0); N];
             $crate::macros::__cartesian_product_populate_mem(&$l, &$r, &mut mem, &mut indices);
             (mem, indices)
         };
-        let mut strings = [""; $l.len() * $r.len()];
+        let mut strings = [""; N];
         $crate::macros::__mem_to_str_arr(mem, indices, &mut strings);
         strings
     }};
+
+    // Recursive case: expand the first two, then recurse
+    ($l:expr, $r:expr, $($rest:expr),+ $(,)?) => {{
+        expand!(expand!($l, $r), $($rest),+)
+    }};
 }
 
 /// Add capitalized versions of each string.
 ///
+/// Capitalizes the first letter, be it monotonic or polytonic Greek.
+///
 /// # Example
 ///
 /// ```
 /// use grac::with_capitalized;
 ///
-/// const WC: [&str; 4] = with_capitalized!(["άλφα", "αλλά"]);
-/// assert_eq!(WC, ["άλφα", "Άλφα", "αλλά", "Αλλά"]);
+/// const WC: [&str; 4] = with_capitalized!(["άλφα", "ἀλλά"]);
+/// assert_eq!(WC, ["άλφα", "Άλφα", "ἀλλά", "Ἀλλά"]);
+///
+/// // Digraphs and diphthongs only capitalize their first letter.
+/// const DIGRAPHS: [&str; 4] = with_capitalized!(["μπορώ", "οὐδέ"]);
+/// assert_eq!(DIGRAPHS, ["μπορώ", "Μπορώ", "οὐδέ", "Οὐδέ"]);
+/// ```
+///
+/// # Compilation errors
+///
+/// Entries must have a letter to capitalize. Some polytonic letters (ὐ, ῶ, ΐ)
+/// have no precomposed capital: entries starting with them must be written
+/// decomposed, so that the base letter is capitalized (ὐ > Υ\u{313}).
+///
+/// ```compile_fail
+/// use grac::with_capitalized;
+///
+/// // error: no letter to capitalize in 123
+/// const WC: [&str; 2] = with_capitalized!(["123"]);
+/// ```
+///
+/// ```
+/// use grac::with_capitalized;
+///
+/// const WC: [&str; 2] = with_capitalized!(["υ\u{313}δωρ"]);
+/// assert_eq!(WC[1], "Υ\u{313}δωρ");
 /// ```
 #[macro_export]
 macro_rules! with_capitalized {
     ($arr:expr) => {{
+        const N: usize = 2 * $arr.len();
         let (mem, indices) = &const {
             const L: usize = $crate::macros::__str_arr_capacity(&$arr);
             let mut mem = [0; 2 * L];
-            let mut indices = [(0, 0); 2 * $arr.len()];
+            let mut indices = [(0, 0); N];
             $crate::macros::__with_capitalized_populate_mem(&$arr, &mut mem, &mut indices);
             (mem, indices)
         };
-        let mut strings = [""; 2 * $arr.len()];
+        let mut strings = [""; N];
         $crate::macros::__mem_to_str_arr(mem, indices, &mut strings);
         strings
     }};
@@ -84,8 +128,9 @@ macro_rules! conc {
         let (mem, indices) = &const {
             const L: usize =
                 $crate::macros::__str_arr_capacity(&$l) + $crate::macros::__str_arr_capacity(&$r);
+            const N: usize = $l.len() + $r.len();
             let mut mem = [0; L];
-            let mut indices = [(0, 0); $l.len() + $r.len()];
+            let mut indices = [(0, 0); N];
             $crate::macros::__conc_populate_mem(&$l, &$r, &mut mem, &mut indices);
             (mem, indices)
         };
@@ -100,6 +145,59 @@ macro_rules! conc {
     }};
 }
 
+/// Syllabify a word at compile time.
+///
+/// Same as [`syllabify_with_merge`](crate::syllabify_with_merge) with
+/// [`Merge::Never`](crate::Merge::Never): synizesis is not detected, since the
+/// lookup table can not be queried in const contexts.
+///
+/// Expects precomposed (NFC) input.
+///
+/// # Example
+///
+/// ```
+/// use grac::syllabify_const;
+///
+/// const SYLLABLES: [&str; 3] = syllabify_const!("άνθρωπος");
+/// assert_eq!(SYLLABLES, ["άν", "θρω", "πος"]);
+/// ```
+#[macro_export]
+macro_rules! syllabify_const {
+    ($s:expr) => {{
+        const N: usize = $crate::macros::__syllabify_const_count($s);
+        const STARTS: [usize; N] = $crate::macros::__syllabify_const_starts($s);
+        let mut strings = [""; N];
+        $crate::macros::__split_at_starts($s, &STARTS, &mut strings);
+        strings
+    }};
+}
+
+/// Sort and deduplicate a const &str array.
+///
+/// Strings are sorted as by [`str::cmp`], so that the result can be queried
+/// with [`slice::binary_search`].
+///
+/// # Example
+///
+/// ```
+/// use grac::{conc, dedup_sorted};
+///
+/// const A: [&str; 3] = ["μου", "σου", "του"];
+/// const B: [&str; 2] = ["σου", "μας"];
+/// const AB: [&str; 4] = dedup_sorted!(conc!(A, B));
+/// assert_eq!(AB, ["μας", "μου", "σου", "του"]);
+/// assert!(AB.binary_search(&"σου").is_ok());
+/// ```
+#[macro_export]
+macro_rules! dedup_sorted {
+    ($arr:expr) => {{
+        const SORTED: [&str; $arr.len()] = $crate::macros::__sort_str_arr($arr);
+        const N: usize = $crate::macros::__count_unique_sorted(&SORTED);
+        const DEDUPED: [&str; N] = $crate::macros::__dedup_sorted(&SORTED);
+        DEDUPED
+    }};
+}
+
 const fn copy_bytes(x: &[u8], mem: &mut [u8], k: &mut usize) {
     let mut i = 0;
     while i < x.len() {
@@ -109,23 +207,81 @@ const fn copy_bytes(x: &[u8], mem: &mut [u8], k: &mut usize) {
     }
 }
 
-#[allow(clippy::many_single_char_names)]
-const fn copy_bytes_capitalized(x: &[u8], mem: &mut [u8], k: &mut usize) {
-    assert!(x.len() >= 2); // Only support Greek
+/// Copy `x`, capitalizing its first letter, or return why it can not be done.
+///
+/// Leading punctuation, digits etc. are kept as is: -ος > -Ος
+const fn copy_bytes_capitalized(
+    x: &[u8],
+    mem: &mut [u8],
+    k: &mut usize,
+) -> Result<(), &'static str> {
     let mut i = 0;
-
-    let (a, b) = to_uppercase_gr_bytes(x[0], x[1]);
-    mem[*k] = a;
-    *k += 1;
-    i += 1;
-    mem[*k] = b;
-    *k += 1;
-    i += 1;
+    let mut capitalized = false;
 
     while i < x.len() {
-        mem[*k] = x[i];
-        *k += 1;
-        i += 1;
+        let width = utf8_width(x[i]);
+        let (_, ch) = char_before(x, i + width);
+        if !capitalized && is_letter(ch) {
+            capitalized = true;
+            let upper = to_upper_char(ch);
+            if upper == ch && ch.is_lowercase() {
+                return Err(NO_CAPITAL);
+            }
+            // Uppercase Greek letters are never longer (cf. to_upper_char),
+            // which the capacity computation relies on.
+            assert!(upper.len_utf8() <= width);
+            let mut buf = [0; 4];
+            copy_bytes(upper.encode_utf8(&mut buf).as_bytes(), mem, k);
+        } else {
+            copy_bytes(x.split_at(i).1.split_at(width).0, mem, k);
+        }
+        i += width;
+    }
+    if capitalized { Ok(()) } else { Err(NO_LETTER) }
+}
+
+const NO_LETTER: &str = "with_capitalized!: no letter to capitalize in ";
+const NO_CAPITAL: &str =
+    "with_capitalized!: no precomposed capital (write it decomposed) for the first letter of ";
+
+/// Panic with `reason` followed by the offending entry, so that the compilation
+/// error tells which one it is.
+const fn panic_at_entry(reason: &str, entry: &str) -> ! {
+    const CAPACITY: usize = 256;
+    let mut buf = [0; CAPACITY];
+    let mut k = 0;
+    copy_bytes(reason.as_bytes(), &mut buf, &mut k);
+    let entry = entry.as_bytes();
+    let mut i = 0;
+    // Whole chars, as long as they fit
+    while i < entry.len() && k + utf8_width(entry[i]) <= CAPACITY {
+        let width = utf8_width(entry[i]);
+        copy_bytes(entry.split_at(i).1.split_at(width).0, &mut buf, &mut k);
+        i += width;
+    }
+    match std::str::from_utf8(buf.split_at(k).0) {
+        Ok(message) => panic!("{}", message),
+        Err(_) => panic!("{}", reason),
+    }
+}
+
+const fn utf8_width(first_byte: u8) -> usize {
+    match first_byte {
+        0x00..=0x7F => 1,
+        0xC0..=0xDF => 2,
+        0xE0..=0xEF => 3,
+        _ => 4,
+    }
+}
+
+/// Check if a char can be capitalized, or is a Greek letter.
+const fn is_letter(ch: char) -> bool {
+    if ch.is_ascii() {
+        ch.is_ascii_alphabetic()
+    } else if is_greek_char(ch) {
+        is_greek_letter(ch)
+    } else {
+        ch.is_lowercase() || ch.is_uppercase()
     }
 }
 
@@ -142,12 +298,20 @@ pub const fn __with_capitalized_populate_mem(
         copy_bytes(x[i].as_bytes(), mem, &mut k);
         indices[2 * i] = (k0, k);
         let k0 = k;
-        copy_bytes_capitalized(x[i].as_bytes(), mem, &mut k);
+        if let Err(reason) = copy_bytes_capitalized(x[i].as_bytes(), mem, &mut k) {
+            panic_at_entry(reason, x[i]);
+        }
         indices[2 * i + 1] = (k0, k);
         i += 1;
     }
 }
 
+/// Copy `x` then `y` into `mem`, with the span of every string in `indices`.
+///
+/// # Panics
+///
+/// Panics if `mem` is too small, or if `indices` does not have a slot for
+/// every string.
 pub const fn __conc_populate_mem(
     x: &[&str],
     y: &[&str],
@@ -173,6 +337,12 @@ pub const fn __conc_populate_mem(
     }
 }
 
+/// Copy every concatenation of a string of `x` and one of `y` into `mem`,
+/// with their spans in `indices`.
+///
+/// # Panics
+///
+/// Panics if `mem` or `indices` do not have the size of the product.
 #[allow(clippy::many_single_char_names)]
 pub const fn __cartesian_product_populate_mem(
     x: &[&str],
@@ -212,6 +382,75 @@ pub const fn __str_arr_capacity(x: &[&str]) -> usize {
     c
 }
 
+pub const fn __sort_str_arr<const N: usize>(mut arr: [&str; N]) -> [&str; N] {
+    // Insertion sort: the arrays are small, and this only runs at compile time.
+    let mut i = 1;
+    while i < N {
+        let mut j = i;
+        while j > 0 && str_lt(arr[j], arr[j - 1]) {
+            arr.swap(j, j - 1);
+            j -= 1;
+        }
+        i += 1;
+    }
+    arr
+}
+
+pub const fn __count_unique_sorted(sorted: &[&str]) -> usize {
+    let mut count = 0;
+    let mut i = 0;
+    while i < sorted.len() {
+        if i == 0 || !str_eq(sorted[i - 1], sorted[i]) {
+            count += 1;
+        }
+        i += 1;
+    }
+    count
+}
+
+/// The `N` distinct strings of `sorted`.
+///
+/// # Panics
+///
+/// Panics if there are not `N` distinct strings (cf. [`__count_unique_sorted`]).
+pub const fn __dedup_sorted<'a, const N: usize>(sorted: &[&'a str]) -> [&'a str; N] {
+    let mut out = [""; N];
+    let mut k = 0;
+    let mut i = 0;
+    while i < sorted.len() {
+        if i == 0 || !str_eq(sorted[i - 1], sorted[i]) {
+            out[k] = sorted[i];
+            k += 1;
+        }
+        i += 1;
+    }
+    assert!(k == N);
+    out
+}
+
+// Lexicographic byte order, which is the order of str::cmp
+const fn str_lt(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    let mut i = 0;
+    while i < a.len() && i < b.len() {
+        if a[i] != b[i] {
+            return a[i] < b[i];
+        }
+        i += 1;
+    }
+    a.len() < b.len()
+}
+
+const fn str_eq(a: &str, b: &str) -> bool {
+    !str_lt(a, b) && !str_lt(b, a)
+}
+
+/// The strings of `mem` at the spans of `indices`.
+///
+/// # Panics
+///
+/// Panics if `strings` does not have a slot for every span, or if a span is
+/// not valid UTF-8.
 pub const fn __mem_to_str_arr<'mem>(
     mem: &'mem [u8],
     indices: &[(usize, usize)],
@@ -229,52 +468,47 @@ pub const fn __mem_to_str_arr<'mem>(
     }
 }
 
-/// Expects valid modern Greek codepoints.
+pub const fn __syllabify_const_count(s: &str) -> usize {
+    if s.is_empty() {
+        0
+    } else {
+        syllable_starts_rev(s, &mut []) + 1
+    }
+}
+
+pub const fn __syllabify_const_starts<const N: usize>(s: &str) -> [usize; N] {
+    let mut starts = [0; N];
+    if N > 1 {
+        let (_, rest) = starts.split_at_mut(1);
+        syllable_starts_rev(s, rest);
+        // Sort ascending
+        let (mut i, mut j) = (1, N - 1);
+        while i < j {
+            starts.swap(i, j);
+            i += 1;
+            j -= 1;
+        }
+    }
+    starts
+}
+
+/// Split `s` at the byte offsets of `starts`.
 ///
-/// Generated with this python script:
+/// # Panics
 ///
-/// ```python
-/// a = "αάβγδεέζηήθιίκλμνξοόπρσςτυύφχψωώ"
-/// b = "ΑΆΒΓΔΕΈΖΗΉΘΙΊΚΛΜΝΞΟΌΠΡΣΣΤΥΎΦΧΨΩΏ"
-/// for x, y in zip(a, b):
-///     xb = x.encode('utf-8')
-///     yb = y.encode('utf-8')
-///     print(f"({xb[0]}, {xb[1]}) => ({yb[0]}, {yb[1]}),")
-/// ```
-const fn to_uppercase_gr_bytes(a: u8, b: u8) -> (u8, u8) {
-    match (a, b) {
-        (206, 177) => (206, 145),
-        (206, 172) => (206, 134),
-        (206, 178) => (206, 146),
-        (206, 179) => (206, 147),
-        (206, 180) => (206, 148),
-        (206, 181) => (206, 149),
-        (206, 173) => (206, 136),
-        (206, 182) => (206, 150),
-        (206, 183) => (206, 151),
-        (206, 174) => (206, 137),
-        (206, 184) => (206, 152),
-        (206, 185) => (206, 153),
-        (206, 175) => (206, 138),
-        (206, 186) => (206, 154),
-        (206, 187) => (206, 155),
-        (206, 188) => (206, 156),
-        (206, 189) => (206, 157),
-        (206, 190) => (206, 158),
-        (206, 191) => (206, 159),
-        (207, 140) => (206, 140),
-        (207, 128) => (206, 160),
-        (207, 129) => (206, 161),
-        (207, 131 | 130) => (206, 163), // σς > Σ
-        (207, 132) => (206, 164),
-        (207, 133) => (206, 165),
-        (207, 141) => (206, 142),
-        (207, 134) => (206, 166),
-        (207, 135) => (206, 167),
-        (207, 136) => (206, 168),
-        (207, 137) => (206, 169),
-        (207, 142) => (206, 143),
-        _ => unreachable!(),
+/// Panics if `strings` does not have a slot for every start, or if the starts
+/// are not increasing char boundaries of `s`.
+pub const fn __split_at_starts<'a>(s: &'a str, starts: &[usize], strings: &mut [&'a str]) {
+    assert!(starts.len() == strings.len());
+    let mut i = 0;
+    while i < starts.len() {
+        let end = if i + 1 < starts.len() {
+            starts[i + 1]
+        } else {
+            s.len()
+        };
+        strings[i] = s.split_at(end).0.split_at(starts[i]).1;
+        i += 1;
     }
 }
 
@@ -283,14 +517,114 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_to_uppercase_gr_bytes() {
+    fn test_syllabify_const() {
+        const EMPTY: [&str; 0] = syllabify_const!("");
+        const SINGLE: [&str; 1] = syllabify_const!("και");
+        const WORD: [&str; 5] = syllabify_const!("αστειάκια");
+        assert_eq!(EMPTY, [""; 0]);
+        assert_eq!(SINGLE, ["και"]);
+        assert_eq!(WORD, ["α", "στει", "ά", "κι", "α"]);
+    }
+
+    #[test]
+    fn test_syllabify_const_agrees() {
+        use crate::{Merge, syllabify_with_merge};
+
+        let dump = include_str!("../tests/fixtures/dump.txt");
+        for word in dump.split_whitespace().take(20_000) {
+            let mut starts = vec![0; word.len()];
+            let n = syllable_starts_rev(word, &mut starts);
+            let spans: Vec<usize> = syllabify_with_merge(word, Merge::Never)
+                .iter()
+                .scan(0, |start, syllable| {
+                    *start += syllable.len();
+                    Some(*start - syllable.len())
+                })
+                .skip(1)
+                .collect();
+            starts.truncate(n);
+            starts.reverse();
+            assert_eq!(starts, spans, "{word}");
+        }
+    }
+
+    #[test]
+    fn test_expand_variadic() {
+        const A: [&str; 2] = ["α", "β"];
+        const B: [&str; 1] = ["γ"];
+        const C: [&str; 2] = ["δ", "ε"];
+        const D: [&str; 1] = ["ζ"];
+        const ABCD: [&str; 4] = expand!(A, B, C, D);
+        assert_eq!(ABCD, ["αγδζ", "αγεζ", "βγδζ", "βγεζ"]);
+        const ABCD_WC: [&str; 8] = expand_with_capitalized!(expand!(A, B, C), D);
+        assert_eq!(ABCD_WC[1], "Αγδζ");
+    }
+
+    #[test]
+    fn test_dedup_sorted() {
+        const EMPTY: [&str; 0] = dedup_sorted!([""; 0]);
+        const WORDS: [&str; 4] = dedup_sorted!(["β", "α", "αα", "β", "", "α"]);
+        assert_eq!(EMPTY, [""; 0]);
+        assert_eq!(WORDS, ["", "α", "αα", "β"]);
+
+        const MP: [&str; 76] = dedup_sorted!(crate::constants::MULTIPLE_PRONUNCIATION);
+        let mut expected = crate::constants::MULTIPLE_PRONUNCIATION.to_vec();
+        expected.sort_unstable();
+        expected.dedup();
+        assert_eq!(MP.as_slice(), expected);
+    }
+
+    #[test]
+    fn test_capitalize_monotonic() {
         let low = "αάβγδεέζηήθιίκλμνξοόπρσςτυύφχψωώ";
         let upp = "ΑΆΒΓΔΕΈΖΗΉΘΙΊΚΛΜΝΞΟΌΠΡΣΣΤΥΎΦΧΨΩΏ";
         for (lower, upper) in low.chars().zip(upp.chars()) {
-            let l = lower.to_string().into_bytes();
-            let u = upper.to_string().into_bytes();
-            let res = to_uppercase_gr_bytes(l[0], l[1]);
-            assert_eq!(res, (u[0], u[1]));
+            assert_eq!(to_upper_char(lower), upper);
+        }
+    }
+
+    #[test]
+    fn test_copy_bytes_capitalized_errors() {
+        let mut mem = [0; 16];
+        for (entry, expected) in [
+            ("", Err(NO_LETTER)),
+            ("-1.", Err(NO_LETTER)),
+            ("ῶμος", Err(NO_CAPITAL)),
+            ("ΐ", Err(NO_CAPITAL)),
+            ("«ὦ»", Ok(())),
+            ("ῥ", Ok(())),
+        ] {
+            let mut k = 0;
+            let received = copy_bytes_capitalized(entry.as_bytes(), &mut mem, &mut k);
+            assert_eq!(received, expected, "{entry}");
         }
     }
+
+    #[test]
+    #[should_panic(expected = "no letter to capitalize in -+")]
+    fn test_panic_at_entry() {
+        let mut mem = [0; 8];
+        let mut indices = [(0, 0); 2];
+        __with_capitalized_populate_mem(&["-+"], &mut mem, &mut indices);
+    }
+
+    #[test]
+    fn test_with_capitalized_any_first_char() {
+        const WC: [&str; 10] = with_capitalized!(["ἄνθρωπος", "ᾄδω", "-ος", "abc", "Άλφα"]);
+        assert_eq!(
+            WC,
+            [
+                "ἄνθρωπος",
+                "Ἄνθρωπος",
+                "ᾄδω",
+                "ᾌδω",
+                "-ος",
+                "-Ος",
+                "abc",
+                "Abc",
+                "Άλφα",
+                "Άλφα"
+            ]
+        );
+    }
 }
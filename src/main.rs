@@ -1,8 +1,20 @@
-// For profiling and testing.
+// For profiling, testing and development.
+//
+// Usage:
+//   grac [profile]                         Profile syllabification over dump.txt
+//   grac explain <word>... [--merge M]     Trace the syllabification rules
+//   grac hyphenate-html [file] [--xhtml]   Insert soft hyphens in HTML (feature "html")
+//   grac import-wiktionary <dump> [--rust] Extract exceptions from a Wiktionary dump (feature "wiktionary")
+//   grac compare --against hypher <file>   Words where hypher disagrees (feature "compare")
+//
+// where M is one of: every, never, or comma separated indices (ex. 1,3).
 
-use grac::syllabify_with_merge;
+use grac::Merge;
+use grac::explain::{Explanation, State, explain, explain_with_merge};
+use grac::{SyllabifyOptions, syllabify_words_with_options};
 use std::fs::File;
-use std::io::{self, Read};
+use std::io::{self, IsTerminal, Read};
+use std::process::ExitCode;
 
 fn read_file(file_path: &str) -> io::Result<String> {
     let mut file = File::open(file_path)?;
@@ -12,29 +24,292 @@ fn read_file(file_path: &str) -> io::Result<String> {
 }
 
 fn run(text: &str) {
-    let words: Vec<&str> = text.split_whitespace().collect();
+    let options = SyllabifyOptions::new();
     println!(
         "Content size: {}. Number of words: {}",
         text.len(),
-        words.len()
+        syllabify_words_with_options(text, &options).count()
     );
 
     let times = 100;
     for _ in 0..times {
-        let _syls: Vec<_> = words
-            .iter()
-            .map(|cword| {
-                
-                syllabify_with_merge(cword, grac::Merge::Never)
-            })
-            .collect();
+        let _syls: Vec<_> = syllabify_words_with_options(text, &options).collect();
     }
 }
 
-fn main() {
+fn profile() -> ExitCode {
     let file_path = "tests/fixtures/dump.txt";
     match read_file(file_path) {
-        Ok(content) => run(&content),
-        Err(e) => eprintln!("Error reading file {file_path}: {e}"),
+        Ok(content) => {
+            run(&content);
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("Error reading file {file_path}: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// ANSI styling, disabled when not writing to a terminal or if `NO_COLOR` is set.
+struct Style {
+    enabled: bool,
+}
+
+impl Style {
+    fn new() -> Self {
+        Self {
+            enabled: io::stdout().is_terminal() && std::env::var_os("NO_COLOR").is_none(),
+        }
+    }
+
+    fn paint(&self, code: &str, s: &str) -> String {
+        if self.enabled {
+            format!("\x1b[{code}m{s}\x1b[0m")
+        } else {
+            s.to_string()
+        }
+    }
+}
+
+fn parse_merge(s: &str) -> Option<Merge> {
+    match s {
+        "every" => Some(Merge::Every),
+        "never" => Some(Merge::Never),
+        _ => s
+            .split(',')
+            .map(|idx| idx.trim().parse().ok())
+            .collect::<Option<Vec<usize>>>()
+            .map(Merge::Indices),
+    }
+}
+
+const fn state_name(state: State) -> &'static str {
+    match state {
+        State::Start => "Start",
+        State::FoundVowel => "FoundVowel",
+        State::FoundConsonant => "FoundConsonant",
+    }
+}
+
+fn print_explanation(explanation: &Explanation, style: &Style) {
+    println!("{} {}", style.paint("1", "Word:"), explanation.word);
+
+    if explanation.from_lookup {
+        println!("  Found in the synizesis table.");
+    } else {
+        println!(
+            "  {:>4}  {:<2}  {:<5}  {:<33}  {:>3}  {:<5}  buffer",
+            "byte", "ch", "vowel", "transition", "syl", "merge"
+        );
+        for step in &explanation.steps {
+            let transition = format!("{} > {}", state_name(step.from), state_name(step.to));
+            let transition = if step.from == step.to {
+                format!("{transition:<33}")
+            } else {
+                style.paint("33", &format!("{transition:<33}"))
+            };
+            let buffer: String = step
+                .buffer
+                .iter()
+                .filter(|(_, ch)| *ch != '\0')
+                .map(|(_, ch)| *ch)
+                .collect();
+            let boundary = step.boundary.map_or_else(String::new, |byte| {
+                style.paint("32", &format!("  | split at byte {byte}"))
+            });
+            println!(
+                "  {:>4}  {:<2}  {:<5}  {}  {:>3}  {:<5}  {}{}",
+                step.byte,
+                step.ch,
+                step.is_vowel,
+                transition,
+                step.syllable_idx,
+                step.merge,
+                buffer,
+                boundary,
+            );
+        }
+    }
+
+    println!(
+        "{} {}\n",
+        style.paint("1", "Syllables:"),
+        style.paint("1;32", &explanation.syllables.join("-"))
+    );
+}
+
+fn explain_cmd(args: &[String]) -> ExitCode {
+    let mut merge = None;
+    let mut words = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if arg == "--merge" {
+            let Some(parsed) = args.next().and_then(|m| parse_merge(m)) else {
+                eprintln!("Invalid --merge value: expected every, never or indices (ex. 1,3)");
+                return ExitCode::FAILURE;
+            };
+            merge = Some(parsed);
+        } else {
+            words.push(arg.as_str());
+        }
+    }
+
+    if words.is_empty() {
+        eprintln!("Usage: grac explain <word>... [--merge every|never|1,2]");
+        return ExitCode::FAILURE;
+    }
+
+    let style = Style::new();
+    for word in words {
+        let explanation = match &merge {
+            Some(merge) => explain_with_merge(word, merge.clone()),
+            None => explain(word),
+        };
+        print_explanation(&explanation, &style);
+    }
+    ExitCode::SUCCESS
+}
+
+/// Hyphenate a file, or stdin, to stdout.
+#[cfg(feature = "html")]
+fn hyphenate_html_cmd(args: &[String]) -> ExitCode {
+    let xhtml = args.iter().any(|arg| arg == "--xhtml");
+    let files: Vec<&String> = args.iter().filter(|arg| *arg != "--xhtml").collect();
+    let content = match files.as_slice() {
+        [] => {
+            let mut content = String::new();
+            io::stdin().read_to_string(&mut content).map(|_| content)
+        }
+        [file_path] => read_file(file_path),
+        _ => {
+            eprintln!("Usage: grac hyphenate-html [file] [--xhtml]");
+            return ExitCode::FAILURE;
+        }
+    };
+    match content {
+        Ok(content) => {
+            // XHTML does not define &shy;
+            let hyphen = if xhtml {
+                "&#173;"
+            } else {
+                grac::html::SOFT_HYPHEN
+            };
+            print!("{}", grac::html::soft_hyphenate_html_with(&content, hyphen));
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("Error reading input: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Print the exceptions of a Wiktionary dump, as runtime table entries or as
+/// entries of the generated lookup table.
+#[cfg(feature = "wiktionary")]
+fn import_wiktionary_cmd(args: &[String]) -> ExitCode {
+    let rust = args.iter().any(|arg| arg == "--rust");
+    let files: Vec<&String> = args.iter().filter(|arg| *arg != "--rust").collect();
+    let [file_path] = files.as_slice() else {
+        eprintln!("Usage: grac import-wiktionary <dump> [--rust]");
+        return ExitCode::FAILURE;
+    };
+    let exceptions = File::open(file_path)
+        .map_err(grac::formats::FormatError::from)
+        .and_then(|file| grac::wiktionary::exceptions_from_dump(io::BufReader::new(file)));
+    match exceptions {
+        Ok(exceptions) => {
+            for (word, syllables) in exceptions {
+                if rust {
+                    println!("    {}", grac::corpus::lookup_entry(&word, &syllables));
+                } else {
+                    println!("{}", grac::corpus::table_entry(&word, &syllables));
+                }
+            }
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("Error reading {file_path}: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Print the words of a file that grac and hypher syllabify differently.
+///
+/// hypher is given no minimum number of letters around a break, so that both
+/// split words into every syllable.
+#[cfg(feature = "compare")]
+fn compare_cmd(args: &[String]) -> ExitCode {
+    use grac::testing::{GoldEntry, evaluate_grac};
+
+    let usage = "Usage: grac compare --against hypher <file>";
+    let [flag, against, file_path] = args else {
+        eprintln!("{usage}");
+        return ExitCode::FAILURE;
+    };
+    if flag != "--against" || against != "hypher" {
+        eprintln!("{usage}");
+        return ExitCode::FAILURE;
+    }
+    let content = match read_file(file_path) {
+        Ok(content) => content,
+        Err(e) => {
+            eprintln!("Error reading {file_path}: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut seen = std::collections::HashSet::new();
+    let entries: Vec<GoldEntry> = syllabify_words_with_options(&content, &SyllabifyOptions::new())
+        .map(|(range, _)| &content[range])
+        .filter(|word| grac::is_greek_word(word) && seen.insert(*word))
+        .map(|word| GoldEntry {
+            word: word.to_string(),
+            expected: hypher::hyphenate_bounded(word, hypher::Lang::Greek, 1, 1)
+                .map(str::to_string)
+                .collect(),
+        })
+        .collect();
+    let evaluation = evaluate_grac(&entries);
+
+    let style = Style::new();
+    for mismatch in &evaluation.mismatches {
+        println!(
+            "{}\tgrac: {}\thypher: {}",
+            style.paint("1", &mismatch.word),
+            mismatch.got.join("-"),
+            mismatch.expected.join("-")
+        );
+    }
+    println!(
+        "{} of {} words agree ({:.2}%)",
+        evaluation.total - evaluation.mismatches.len(),
+        evaluation.total,
+        100.0 * evaluation.accuracy()
+    );
+    ExitCode::SUCCESS
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        None | Some("profile") => profile(),
+        Some("explain") => explain_cmd(&args[1..]),
+        #[cfg(feature = "html")]
+        Some("hyphenate-html") => hyphenate_html_cmd(&args[1..]),
+        #[cfg(feature = "wiktionary")]
+        Some("import-wiktionary") => import_wiktionary_cmd(&args[1..]),
+        #[cfg(feature = "compare")]
+        Some("compare") => compare_cmd(&args[1..]),
+        Some(cmd) => {
+            eprintln!(
+                "Unknown command: {cmd}. Expected one of: profile, explain, \
+                 hyphenate-html (feature html), import-wiktionary (feature wiktionary), \
+                 compare (feature compare)."
+            );
+            ExitCode::FAILURE
+        }
     }
 }
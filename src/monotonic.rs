@@ -1,27 +1,220 @@
-use aho_corasick::AhoCorasick;
-use unicode_normalization::UnicodeNormalization;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::{self, BufRead, Write};
+use std::sync::OnceLock;
+
+use aho_corasick::{AhoCorasick, MatchKind};
+use unicode_normalization::{IsNormalized, UnicodeNormalization, is_nfc_quick};
 
 use crate::accents::Diacritic;
-use crate::accents::{has_acute, remove_acute, remove_diacritic_at};
-use crate::chars::{ends_with_diphthong, is_greek_word};
-use crate::constants::{APOSTROPHES, MONOSYLLABLE_ACCENTED};
+use crate::accents::{has_acute, has_acute_char, remove_acute, remove_diacritic_at_syllables};
+use crate::case::SENTENCE_END;
+use crate::chars::{
+    ends_with_diphthong, has_combining_marks, is_combining_mark, is_greek_extended_char,
+};
+use crate::constants::{
+    APOSTROPHES, is_monosyllable_accented, is_monosyllable_accented_with_pronouns,
+    is_monosyllable_remove_accent,
+};
 use crate::is_greek_letter;
-use crate::syllabify::syllabify;
+use crate::protect::ProtectedRegions;
+use crate::span::{Change, SpanEncoding, encode_changes};
+use crate::syllabify::{Syllables, is_vowel, syllabify};
+use crate::word::GreekWordBuf;
+
+const DIAERESES_WRONG: [&str; 14] = with_capitalized!(["άϊ", "άϋ", "έϊ", "έϋ", "όϊ", "όϋ", "ούϊ"]);
+const DIAERESES_CORRECT: [&str; 14] =
+    with_capitalized!(["άι", "άυ", "έι", "έυ", "όι", "όυ", "ούι"]);
+
+/// What to do with the diaereses that an accent makes superfluous (άϊ → άι).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DiaeresisPolicy {
+    /// Remove the diaereses after an accented vowel: άϊ, έϋ, όϊ, ούϊ...
+    #[default]
+    RemoveSuperfluous,
+    /// Keep the spelling of the author.
+    Keep,
+    /// Remove superfluous diaereses, and replace the given (from, to) pairs too.
+    ///
+    /// When patterns overlap, the longest one wins.
+    Custom(Vec<(String, String)>),
+}
+
+/// Where [`to_monotonic_with_options`] splits the text into words.
+///
+/// Words are converted one at a time, so that the separators decide which
+/// syllables the rules see together. Hyphenated compounds that are kept as a
+/// single word go through the double accent rule as a whole: πού-πού becomes
+/// πού-που, as if it were a word followed by an enclitic, while its parts keep
+/// their accent when split. A monosyllable part (τρεῖς in δύο-τρεῖς) loses its
+/// accent only when split.
+#[derive(Debug, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum WordSeparators {
+    /// Only whitespace: hyphenated words (Σαντί-ντες) are converted as a whole.
+    Whitespace,
+    /// Whitespace, hyphens and em dashes.
+    #[default]
+    WhitespaceAndHyphens,
+    /// Any char for which the function returns true.
+    Custom(fn(char) -> bool),
+}
+
+impl WordSeparators {
+    /// Separators given by a function, that returns true for them.
+    ///
+    /// Pass them to [`MonotonicOptions::with_word_separators`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use grac::{MonotonicOptions, WordSeparators, to_monotonic_with_options};
+    ///
+    /// let separators = WordSeparators::custom(|ch| ch.is_whitespace() || ch == '/');
+    /// let options = MonotonicOptions::new().with_word_separators(separators);
+    /// // δω is a monosyllable of its own
+    /// assert_eq!(to_monotonic_with_options("ἔλα/δῶ", &options), "έλα/δω");
+    /// ```
+    pub const fn custom(is_separator: fn(char) -> bool) -> Self {
+        Self::Custom(is_separator)
+    }
+
+    /// Check if a char separates words.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use grac::WordSeparators;
+    ///
+    /// assert!(WordSeparators::default().is_separator('-'));
+    /// assert!(!WordSeparators::Whitespace.is_separator('-'));
+    /// assert!(WordSeparators::custom(|ch| ch == '/').is_separator('/'));
+    /// ```
+    pub fn is_separator(self, ch: char) -> bool {
+        match self {
+            Self::Whitespace => ch.is_whitespace(),
+            Self::WhitespaceAndHyphens => is_word_separator(ch),
+            Self::Custom(is_separator) => is_separator(ch),
+        }
+    }
+
+    /// Split into words, each followed by its separator (if any).
+    pub(crate) fn split(self, s: &str) -> impl Iterator<Item = &str> {
+        s.split_inclusive(move |ch| self.is_separator(ch))
+    }
+
+    /// Remove the separators at the end of a chunk given by [`split`](Self::split).
+    pub(crate) fn trim_end(self, chunk: &str) -> &str {
+        chunk.trim_end_matches(|ch| self.is_separator(ch))
+    }
+}
+
+/// The replacements of a [`DiaeresisPolicy`].
+#[derive(Clone)]
+pub(crate) struct DiaeresisFixer {
+    /// `None` when there is nothing to replace.
+    ac: Option<AhoCorasick>,
+    replacements: Vec<String>,
+}
+
+impl DiaeresisFixer {
+    pub fn new(policy: &DiaeresisPolicy) -> Self {
+        let default_pairs = DIAERESES_WRONG
+            .iter()
+            .zip(DIAERESES_CORRECT)
+            .map(|(from, to)| ((*from).to_string(), to.to_string()));
+        let pairs: Vec<(String, String)> = match policy {
+            DiaeresisPolicy::Keep => Vec::new(),
+            DiaeresisPolicy::RemoveSuperfluous => default_pairs.collect(),
+            DiaeresisPolicy::Custom(custom) => default_pairs
+                .chain(custom.iter().filter(|(from, _)| !from.is_empty()).cloned())
+                .collect(),
+        };
+        let ac = (!pairs.is_empty()).then(|| {
+            AhoCorasick::builder()
+                .match_kind(MatchKind::LeftmostLongest)
+                .build(pairs.iter().map(|(from, _)| from))
+                .expect("diaeresis patterns are small")
+        });
+        Self {
+            ac,
+            replacements: pairs.into_iter().map(|(_, to)| to).collect(),
+        }
+    }
+
+    /// Same as [`new`](Self::new), but the automaton of the default policy is
+    /// built once and shared between calls and threads.
+    pub fn shared(policy: &DiaeresisPolicy) -> Cow<'static, Self> {
+        static KEEP: DiaeresisFixer = DiaeresisFixer {
+            ac: None,
+            replacements: Vec::new(),
+        };
+        static REMOVE_SUPERFLUOUS: OnceLock<DiaeresisFixer> = OnceLock::new();
+
+        match policy {
+            DiaeresisPolicy::Keep => Cow::Borrowed(&KEEP),
+            DiaeresisPolicy::RemoveSuperfluous => {
+                Cow::Borrowed(REMOVE_SUPERFLUOUS.get_or_init(|| Self::new(policy)))
+            }
+            DiaeresisPolicy::Custom(_) => Cow::Owned(Self::new(policy)),
+        }
+    }
+
+    pub fn apply(&self, s: &str) -> String {
+        match &self.ac {
+            Some(ac) => ac.replace_all(s, &self.replacements),
+            None => s.to_string(),
+        }
+    }
+
+    pub fn apply_cow<'a>(&self, s: &'a str) -> Cow<'a, str> {
+        match &self.ac {
+            Some(ac) if ac.is_match(s) => Cow::Owned(ac.replace_all(s, &self.replacements)),
+            _ => Cow::Borrowed(s),
+        }
+    }
+}
 
-// Intended to be run over the entire text, and not individual words.
-// When ran over words, the cost of building the automata is too big,
-fn remove_superfluous_diaereses(s: &str) -> String {
-    const DIAERESES_WRONG: [&str; 14] =
-        with_capitalized!(["άϊ", "άϋ", "έϊ", "έϋ", "όϊ", "όϋ", "ούϊ"]);
-    const DIAERESES_CORRECT: [&str; 14] =
-        with_capitalized!(["άι", "άυ", "έι", "έυ", "όι", "όυ", "ούι"]);
-    let ac = AhoCorasick::new(DIAERESES_WRONG).unwrap();
-    ac.replace_all(s, &DIAERESES_CORRECT)
+/// Normalize the diaereses of a text, as [`to_monotonic`] does.
+///
+/// The automaton of the default policy is built on first use and shared. With
+/// [`DiaeresisPolicy::Custom`], it is built on every call: run this over the
+/// entire text, and not individual words, where that cost is too big.
+///
+/// # Example
+///
+/// ```
+/// use grac::{DiaeresisPolicy, normalize_diaereses};
+///
+/// let text = "Άϊντε, ο γάϊδαρος";
+/// assert_eq!(normalize_diaereses(text, &DiaeresisPolicy::default()), "Άιντε, ο γάιδαρος");
+/// assert_eq!(normalize_diaereses(text, &DiaeresisPolicy::Keep), text);
+///
+/// let policy = DiaeresisPolicy::Custom(vec![("αϊδ".to_string(), "αηδ".to_string())]);
+/// assert_eq!(normalize_diaereses("Άϊντε αϊδόνι", &policy), "Άιντε αηδόνι");
+/// ```
+pub fn normalize_diaereses(text: &str, policy: &DiaeresisPolicy) -> String {
+    DiaeresisFixer::shared(policy).apply(text)
+}
+
+/// Same as [`normalize_diaereses`], borrowing the text when there is nothing
+/// to replace.
+pub fn normalize_diaereses_cow<'a>(text: &'a str, policy: &DiaeresisPolicy) -> Cow<'a, str> {
+    DiaeresisFixer::shared(policy).apply_cow(text)
 }
 
 /// Convert text from polytonic to monotonic Greek.
 ///
-/// Leaves non greek words unchanged.
+/// Leaves non greek words unchanged, in their normalization. Greek words with
+/// combining marks are returned in NFC, so that NFC and NFD input give the
+/// same result. Words in capitals
+/// only lose their breathings, subscripts, grave and circumflex accents
+/// (ἙΛΛΑΣ ΚΑῚ → ΕΛΛΑΣ ΚΑΙ).
+///
+/// Whitespace, line breaks included, is kept exactly as written: only the
+/// words between it are converted (cf. [`normalize_whitespace`](crate::normalize_whitespace)
+/// to normalize it).
 ///
 /// ```
 /// use grac::*;
@@ -30,62 +223,565 @@ fn remove_superfluous_diaereses(s: &str) -> String {
 /// let result = to_monotonic(text);
 /// assert_eq!(result, "Ελλάς και κόσμος.\r\n...άνθρωπος.");
 /// ```
-pub fn to_monotonic(s: &str) -> String {
-    let out: String = s
-        .split_inclusive(|ch: char|
-        // Split on hyphens (and faulty variations)
-        ch == '-' || ch == '—'
-        // The main separator logic is whitespace
-        || ch.is_whitespace())
-        .map(to_monotonic_word)
-        .collect();
-    remove_superfluous_diaereses(&out)
+pub fn to_monotonic(s: impl AsRef<str>) -> String {
+    Converter::default().to_monotonic(s.as_ref())
+}
+
+/// Same as [`to_monotonic`], borrowing the text when it is already monotonic.
+///
+/// Words are first checked without allocating: those without polytonic
+/// diacritics, superfluous diaereses or an accent that the rules would remove
+/// are copied as they are, and the text is only allocated at the first word
+/// that changes.
+///
+/// # Example
+///
+/// ```
+/// use std::borrow::Cow;
+///
+/// use grac::to_monotonic_cow;
+///
+/// let text = "Η θάλασσα είναι ήρεμη, πού πας;";
+/// assert!(matches!(to_monotonic_cow(text), Cow::Borrowed(_)));
+///
+/// let text = "Ἡ θάλασσα εἶναι ἤρεμη.";
+/// assert_eq!(to_monotonic_cow(text), "Η θάλασσα είναι ήρεμη.");
+/// ```
+pub fn to_monotonic_cow(s: &str) -> Cow<'_, str> {
+    let converter = Converter::default();
+    let diaereses = DiaeresisFixer::shared(&DEFAULT_OPTIONS.diaeresis);
+    let mut buf = GreekWordBuf::new();
+    let mut out: Option<String> = None;
+    // Bytes of `s` before `start` are either copied to `out` or unchanged.
+    let mut start = 0;
+    let mut idx = 0;
+    for chunk in split_words(s) {
+        let word = chunk.trim_end_matches(is_word_separator);
+        if !is_monotonic_word(word) {
+            let converted = converter.convert_word(&diaereses, &mut buf, word);
+            if converted != word {
+                let out = out.get_or_insert_with(|| String::with_capacity(s.len()));
+                out.push_str(&s[start..idx]);
+                out.push_str(&converted);
+                start = idx + word.len();
+            }
+        }
+        idx += chunk.len();
+    }
+    match out {
+        Some(mut out) => {
+            out.push_str(&s[start..]);
+            Cow::Owned(out)
+        }
+        None => Cow::Borrowed(s),
+    }
+}
+
+/// Whether [`to_monotonic`] surely leaves a word (without its separator)
+/// unchanged, following the rules of `to_monotonic_word` without allocating.
+///
+/// False negatives only cost a conversion.
+fn is_monotonic_word(word: &str) -> bool {
+    let mut acutes = 0;
+    // Vowels split by a consonant are in different syllables, so that most
+    // words need not be syllabified.
+    let mut vowel_groups = 0;
+    let mut after_consonant = true;
+    for ch in word.chars() {
+        if is_greek_extended_char(ch) || is_combining_mark(ch) {
+            return false;
+        }
+        if !is_greek_letter(ch) {
+            continue;
+        }
+        if is_vowel(ch) {
+            acutes += usize::from(has_acute_char(ch));
+            vowel_groups += usize::from(after_consonant);
+            after_consonant = false;
+        } else {
+            after_consonant = true;
+        }
+    }
+    if acutes == 0 {
+        return is_nfc_quick(word.chars()) == IsNormalized::Yes;
+    }
+    if acutes > 1
+        || is_nfc_quick(word.chars()) != IsNormalized::Yes
+        || DIAERESES_WRONG.iter().any(|pattern| word.contains(pattern))
+    {
+        return false;
+    }
+    let (_, core, right_punct) = split_punctuation(word);
+    if vowel_groups > 1 {
+        return !is_monosyllable_remove_accent(core);
+    }
+    match syllabify(core).as_slice() {
+        [syllable] => {
+            is_monosyllable_accented(syllable)
+                || right_punct.starts_with(APOSTROPHES)
+                || ends_with_diphthong(core)
+        }
+        _ => !is_monosyllable_remove_accent(core),
+    }
+}
+
+/// Options for [`to_monotonic_with_options`].
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct MonotonicOptions {
+    /// Keep the accent of weak pronouns (μού, σού, τού...) if already present.
+    ///
+    /// In monotonic, an accent may be written on a pronoun to disambiguate:
+    /// ο πατέρας μού είπε (my father told me) / ο πατέρας μου είπε (my father said).
+    /// Since only the author knows when that is the case, the accent is kept
+    /// whenever it is found in the input.
+    pub keep_pronoun_accents: bool,
+    /// Regions of the text to leave untouched, like markup (cf. [`ProtectedRegions::xml`]).
+    pub protected: ProtectedRegions,
+    /// Monotonic conversions of whole words, without punctuation, that win over
+    /// the rules (cf. [`ExceptionTable`](crate::ExceptionTable)).
+    pub special_cases: BTreeMap<String, String>,
+    /// What to do with superfluous diaereses (άϊ → άι).
+    pub diaeresis: DiaeresisPolicy,
+    /// Leave words with Coptic letters (ϣ, ϫ...) unchanged (cf. [`is_greek_word_strict`]).
+    pub strict_greek: bool,
+    /// Whole words, without punctuation, to leave untouched: proper names
+    /// (Κέϋνς), transliterations or bibliographic references.
+    ///
+    /// Unlike [`special_cases`](Self::special_cases), they are not even passed
+    /// through the diaeresis policy.
+    pub protected_words: BTreeSet<String>,
+    /// Treat capitalized words that do not start a sentence as names, where
+    /// most conversion mistakes happen (Κέϋνς, Ντόϊλ): only replace their
+    /// polytonic diacritics, and leave accents and diaereses as written.
+    pub conservative_names: bool,
+    /// Where to split the text into words (cf. [`WordSeparators`]).
+    pub word_separators: WordSeparators,
+}
+
+impl MonotonicOptions {
+    pub const fn new() -> Self {
+        Self {
+            keep_pronoun_accents: false,
+            protected: ProtectedRegions::new(),
+            special_cases: BTreeMap::new(),
+            diaeresis: DiaeresisPolicy::RemoveSuperfluous,
+            strict_greek: false,
+            protected_words: BTreeSet::new(),
+            conservative_names: false,
+            word_separators: WordSeparators::WhitespaceAndHyphens,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_keep_pronoun_accents(mut self, keep_pronoun_accents: bool) -> Self {
+        self.keep_pronoun_accents = keep_pronoun_accents;
+        self
+    }
+
+    #[must_use]
+    pub fn with_protected(mut self, protected: ProtectedRegions) -> Self {
+        self.protected = protected;
+        self
+    }
+
+    /// Add a special case (cf. [`special_cases`](Self::special_cases)).
+    #[must_use]
+    pub fn with_special_case(mut self, word: &str, monotonic: &str) -> Self {
+        self.special_cases
+            .insert(word.to_string(), monotonic.to_string());
+        self
+    }
+
+    #[must_use]
+    pub fn with_diaeresis(mut self, diaeresis: DiaeresisPolicy) -> Self {
+        self.diaeresis = diaeresis;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_strict_greek(mut self, strict_greek: bool) -> Self {
+        self.strict_greek = strict_greek;
+        self
+    }
+
+    /// Add a protected word (cf. [`protected_words`](Self::protected_words)).
+    #[must_use]
+    pub fn with_protected_word(mut self, word: &str) -> Self {
+        self.protected_words.insert(word.to_string());
+        self
+    }
+
+    #[must_use]
+    pub const fn with_conservative_names(mut self, conservative_names: bool) -> Self {
+        self.conservative_names = conservative_names;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_word_separators(mut self, word_separators: WordSeparators) -> Self {
+        self.word_separators = word_separators;
+        self
+    }
+}
+
+const DEFAULT_OPTIONS: &MonotonicOptions = &MonotonicOptions::new();
+
+/// Same as [`to_monotonic`], with options.
+///
+/// ```
+/// use grac::{MonotonicOptions, ProtectedRegions, to_monotonic, to_monotonic_with_options};
+///
+/// let text = "ὁ πατέρας μοῦ εἶπε";
+/// assert_eq!(to_monotonic(text), "ο πατέρας μου είπε");
+///
+/// let options = MonotonicOptions::new().with_keep_pronoun_accents(true);
+/// assert_eq!(to_monotonic_with_options(text, &options), "ο πατέρας μού είπε");
+///
+/// let options = MonotonicOptions::new().with_protected(ProtectedRegions::xml());
+/// let text = "<hi rend=\"ἔντονα\">μοῦ</hi>";
+/// assert_eq!(to_monotonic_with_options(text, &options), "<hi rend=\"ἔντονα\">μου</hi>");
+/// ```
+pub fn to_monotonic_with_options(s: impl AsRef<str>, options: &MonotonicOptions) -> String {
+    Converter {
+        options,
+        ..Converter::default()
+    }
+    .to_monotonic(s.as_ref())
+}
+
+/// Same as [`to_monotonic_with_options`], reading and writing one line at a
+/// time, so that large files do not need to fit in memory.
+///
+/// Lines are converted independently of each other: protected regions and
+/// sentences (cf. [`MonotonicOptions::conservative_names`]) end with them.
+///
+/// # Errors
+///
+/// Returns an error if the input can not be read, or is not UTF-8, or if the
+/// output can not be written.
+///
+/// # Example
+///
+/// ```
+/// use grac::{MonotonicOptions, to_monotonic_stream};
+///
+/// let text = "Ἑλλάς καὶ κόσμος.\r\nὁ ἄνθρωπος";
+/// let mut out = Vec::new();
+/// to_monotonic_stream(text.as_bytes(), &mut out, &MonotonicOptions::new()).unwrap();
+/// assert_eq!(String::from_utf8(out).unwrap(), "Ελλάς και κόσμος.\r\nο άνθρωπος");
+/// ```
+pub fn to_monotonic_stream(
+    mut input: impl BufRead,
+    mut output: impl Write,
+    options: &MonotonicOptions,
+) -> io::Result<()> {
+    let converter = Converter {
+        options,
+        ..Converter::default()
+    };
+    let mut line = String::new();
+    while input.read_line(&mut line)? != 0 {
+        output.write_all(converter.to_monotonic(&line).as_bytes())?;
+        line.clear();
+    }
+    output.flush()
+}
+
+/// Same as [`to_monotonic`], passing every converted word through a callback.
+///
+/// The callback receives the original word, with its punctuation but without
+/// the whitespace or hyphen that follows it, and its conversion. It returns the
+/// word to write, so that words can be kept as they are or post-processed.
+///
+/// ```
+/// use grac::to_monotonic_with;
+///
+/// let text = "Πλάτων καὶ Ἀριστοτέλης";
+/// // Keep proper names untouched
+/// let result = to_monotonic_with(text, |word, converted| {
+///     if word.starts_with(char::is_uppercase) {
+///         word.to_string()
+///     } else {
+///         converted
+///     }
+/// });
+/// assert_eq!(result, "Πλάτων και Ἀριστοτέλης");
+/// ```
+pub fn to_monotonic_with(s: impl AsRef<str>, f: impl FnMut(&str, String) -> String) -> String {
+    Converter::default().to_monotonic_with(s.as_ref(), f)
+}
+
+/// A syllabification function, to allow for user exceptions (cf. [`crate::GracContext`]).
+pub(crate) type SyllabifyFn<'c> = &'c dyn for<'a> Fn(&'a str) -> Syllables<'a>;
+
+/// Configuration of the monotonic conversion.
+pub(crate) struct Converter<'c> {
+    pub syllabify: SyllabifyFn<'c>,
+    pub options: &'c MonotonicOptions,
+}
+
+impl Default for Converter<'_> {
+    fn default() -> Self {
+        Self {
+            syllabify: &syllabify,
+            options: DEFAULT_OPTIONS,
+        }
+    }
+}
+
+pub(crate) const fn is_word_separator(ch: char) -> bool {
+    // Split on hyphens (and faulty variations)
+    ch == '-' || ch == '—'
+    // The main separator logic is whitespace
+    || ch.is_whitespace()
+}
+
+/// Check if a word, with its punctuation, ends a sentence.
+pub(crate) fn ends_sentence(word: &str) -> bool {
+    split_punctuation(word).2.contains(SENTENCE_END)
+}
+
+/// Split into words, each followed by its separator (if any).
+pub(crate) fn split_words(s: &str) -> impl Iterator<Item = &str> {
+    WordSeparators::default().split(s)
+}
+
+/// Word level changes that [`to_monotonic`] would make.
+///
+/// Spans cover the word without its surrounding punctuation.
+///
+/// ```
+/// use grac::monotonic_changes;
+///
+/// let changes = monotonic_changes("«Τὸ σπίτι» καὶ");
+/// assert_eq!(changes.len(), 2);
+/// assert_eq!(changes[0].span, 2..7);
+/// assert_eq!((changes[0].old.as_str(), changes[0].new.as_str()), ("Τὸ", "Το"));
+/// assert_eq!((changes[1].old.as_str(), changes[1].new.as_str()), ("καὶ", "και"));
+/// ```
+pub fn monotonic_changes(s: impl AsRef<str>) -> Vec<Change> {
+    Converter::default().changes(s.as_ref())
+}
+
+impl Converter<'_> {
+    pub fn to_monotonic(&self, s: &str) -> String {
+        let protected = &self.options.protected;
+        if !protected.is_empty() {
+            return protected.apply(s, |segment| self.to_monotonic_unprotected(segment));
+        }
+        self.to_monotonic_unprotected(s)
+    }
+
+    fn to_monotonic_unprotected(&self, s: &str) -> String {
+        // Protected words and names have to skip the diaeresis pass over the whole text.
+        if !self.options.protected_words.is_empty() || self.options.conservative_names {
+            return self.to_monotonic_with_unprotected(s, &mut |_, converted| converted);
+        }
+        let separators = self.options.word_separators;
+        let mut buf = GreekWordBuf::new();
+        let out: String = separators
+            .split(s)
+            .map(|word| self.to_monotonic_word(&mut buf, word))
+            .collect();
+        normalize_diaereses(&out, &self.options.diaeresis)
+    }
+
+    /// Same as [`to_monotonic`](Self::to_monotonic), passing every converted
+    /// word outside of the protected regions through a callback (cf. [`to_monotonic_with`]).
+    pub fn to_monotonic_with(&self, s: &str, mut f: impl FnMut(&str, String) -> String) -> String {
+        let protected = &self.options.protected;
+        if !protected.is_empty() {
+            return protected.apply(s, |segment| {
+                self.to_monotonic_with_unprotected(segment, &mut f)
+            });
+        }
+        self.to_monotonic_with_unprotected(s, &mut f)
+    }
+
+    fn to_monotonic_with_unprotected(
+        &self,
+        s: &str,
+        f: &mut impl FnMut(&str, String) -> String,
+    ) -> String {
+        let diaereses = DiaeresisFixer::shared(&self.options.diaeresis);
+        let mut buf = GreekWordBuf::new();
+        let separators = self.options.word_separators;
+        let mut out = String::with_capacity(s.len());
+        let mut sentence_start = true;
+        for chunk in separators.split(s) {
+            let word = separators.trim_end(chunk);
+            if !word.is_empty() {
+                let converted = self.convert_word_at(&diaereses, &mut buf, word, sentence_start);
+                out.push_str(&f(word, converted));
+                sentence_start = ends_sentence(word);
+            }
+            out.push_str(&chunk[word.len()..]);
+        }
+        out
+    }
+
+    pub fn changes(&self, s: &str) -> Vec<Change> {
+        let diaereses = DiaeresisFixer::shared(&self.options.diaeresis);
+        let mut buf = GreekWordBuf::new();
+        let mut sentence_start = true;
+        let mut convert = |word: &str| {
+            let converted = self.convert_word_at(&diaereses, &mut buf, word, sentence_start);
+            sentence_start = ends_sentence(word);
+            converted
+        };
+        let protected = &self.options.protected;
+        let separators = self.options.word_separators;
+        if protected.is_empty() {
+            return word_changes_by(s, separators, convert);
+        }
+        let mut changes = Vec::new();
+        for (range, is_protected) in protected.segments(s) {
+            if is_protected {
+                continue;
+            }
+            let offset = range.start;
+            changes.extend(
+                word_changes_by(&s[range], separators, &mut convert)
+                    .into_iter()
+                    .map(|change| Change {
+                        span: change.span.start + offset..change.span.end + offset,
+                        ..change
+                    }),
+            );
+        }
+        changes
+    }
+
+    /// Convert a word (without its separator) to monotonic.
+    ///
+    /// Equivalent to [`to_monotonic`] over the word, minus the cost of building the automaton.
+    pub fn convert_word(
+        &self,
+        diaereses: &DiaeresisFixer,
+        buf: &mut GreekWordBuf,
+        word: &str,
+    ) -> String {
+        self.convert_word_at(diaereses, buf, word, true)
+    }
+
+    /// Same as [`convert_word`](Self::convert_word), for a word that may not
+    /// start a sentence.
+    pub(crate) fn convert_word_at(
+        &self,
+        diaereses: &DiaeresisFixer,
+        buf: &mut GreekWordBuf,
+        word: &str,
+        sentence_start: bool,
+    ) -> String {
+        if self.is_protected_word(word) {
+            return word.to_string();
+        }
+        if self.options.conservative_names
+            && !sentence_start
+            && let Some(out) = self.convert_name(buf, word)
+        {
+            return out;
+        }
+        diaereses.apply(&self.to_monotonic_word(buf, word))
+    }
+
+    /// Convert the diacritics of a capitalized word to the acute, if it is one.
+    fn convert_name(&self, buf: &mut GreekWordBuf, word: &str) -> Option<String> {
+        if !buf.set_if_greek(word, self.options.strict_greek) {
+            return None;
+        }
+        let (left_punct, core, right_punct) = buf.split_punctuation();
+        if !core.starts_with(char::is_uppercase) || buf.is_all_caps() {
+            return None;
+        }
+        if buf.is_core_plain() {
+            return Some(buf.as_str().to_string());
+        }
+        Some(format!(
+            "{left_punct}{}{right_punct}",
+            convert_to_acute(core)
+        ))
+    }
+
+    pub(crate) fn is_protected_word(&self, word: &str) -> bool {
+        let protected = &self.options.protected_words;
+        !protected.is_empty() && protected.contains(split_punctuation(word).1)
+    }
+}
+
+/// Compute the changes made by `convert` to each word.
+pub(crate) fn word_changes(s: &str, convert: impl FnMut(&str) -> String) -> Vec<Change> {
+    word_changes_by(s, WordSeparators::default(), convert)
+}
+
+/// Same as [`word_changes`], splitting words at the given separators.
+fn word_changes_by(
+    s: &str,
+    separators: WordSeparators,
+    mut convert: impl FnMut(&str) -> String,
+) -> Vec<Change> {
+    let mut changes = Vec::new();
+    let mut start = 0;
+
+    for chunk in separators.split(s) {
+        let word_start = start;
+        start += chunk.len();
+
+        let word = separators.trim_end(chunk);
+        if word.is_empty() {
+            continue;
+        }
+        let converted = convert(word);
+        if converted == word {
+            continue;
+        }
+
+        // Punctuation is left untouched by the conversion, up to normalization.
+        let (left_punct, core, _) = split_punctuation(word);
+        let (_, new, _) = split_punctuation(&converted);
+        if new == core || has_combining_marks(core) && new.chars().eq(core.nfc()) {
+            continue;
+        }
+        let span_start = word_start + left_punct.len();
+        changes.push(Change {
+            span: span_start..span_start + core.len(),
+            old: core.to_string(),
+            new: new.to_string(),
+        });
+    }
+
+    changes
+}
+
+/// Same as [`monotonic_changes`], with spans in UTF-16 code units.
+pub fn monotonic_changes_utf16(s: &str) -> Vec<Change> {
+    encode_changes(s, monotonic_changes(s), SpanEncoding::Utf16)
 }
 
 // Uses the is_greek_letter fast path
-fn not_punct(ch: char) -> bool {
-    is_greek_letter(ch) || (ch != '\u{02BC}' && ch.is_alphabetic())
+pub(crate) fn not_punct(ch: char) -> bool {
+    is_greek_letter(ch) || is_combining_mark(ch) || (ch != '\u{02BC}' && ch.is_alphabetic())
 }
 
 /// Split string into (left punctuation, core, right punctuation).
 ///
 /// Leaves punctuation inside the core untouched.
-#[allow(clippy::missing_panics_doc)]
-#[allow(clippy::option_if_let_else)]
 pub fn split_punctuation(s: &str) -> (&str, &str, &str) {
-    let start = s
-        .char_indices()
-        .find_map(|(i, ch)| not_punct(ch).then_some(i));
-
-    if let Some(start) = start {
-        // SAFETY: since we found some index "start" from the right, there
-        // must be some index (potentially start iself) from the left.
-        let end = s
-            .char_indices()
-            .rev()
-            .find_map(|(i, ch)| not_punct(ch).then_some(i + ch.len_utf8()))
-            .unwrap();
-        (&s[..start], &s[start..end], &s[end..])
-    } else {
+    let Some(start) = s.find(not_punct) else {
         // If there are not alphabetic chars, treat the word as left punctuation.
-        (s, "", "")
-    }
+        return (s, "", "");
+    };
+    // Some char matched from the left, so the search from the right finds one
+    // at or after it.
+    let end = s[start..]
+        .char_indices()
+        .rev()
+        .find_map(|(i, ch)| not_punct(ch).then_some(start + i + ch.len_utf8()))
+        .unwrap_or(s.len());
+    (&s[..start], &s[start..end], &s[end..])
 }
 
-/// Monosyllables from which we want to remove the accent.
-///
-/// These have an accent in polytonic that conflicts with our syllabify logic.
-/// We need to store them separatedly to treat them as monosyllables with no accent.
-/// Ex. πιὸ ταπεινά > πιό ταπεινά // Expected: πιο ταπεινά
-#[rustfmt::skip]
-const MONOSYL_REMOVE_ACCENT: [&str; 32] = with_capitalized!([
-    "πιό", "πιά", "μιά", "μιάς", "γιά", "γειά",
-    // πιώ
-    "πιώ",
-    "πίεις", "πίη", "πιή", "πίει", "πιεί", "πίης", "πιής",
-    "πιούν", "πιές",
-]);
-
 #[allow(unused_variables)]
 fn log(label: &str, value: impl std::fmt::Debug) {
     // println!("{:<30}: {:?}", label, value);
@@ -103,7 +799,7 @@ fn dbg_bytes(s: &str) {
 }
 
 /// Remove ancient diacritics and convert grave and circumflex to acute in a single pass.
-fn convert_to_acute(s: &str) -> String {
+pub(crate) fn convert_to_acute(s: &str) -> String {
     const DIACRITICS_TO_REMOVE: [char; 3] = [
         Diacritic::IOTA_SUBSCRIPT,
         Diacritic::ROUGH,
@@ -124,6 +820,26 @@ fn convert_to_acute(s: &str) -> String {
         .collect::<String>()
 }
 
+/// Remove the diacritics of a word in capitals, but for the acute and diaeresis.
+///
+/// Capitals take no accent in monotonic, apart from an optional tonos on the
+/// first letter (ΆΓΙΟΣ), so grave and circumflex accents are not turned into
+/// acutes. Adscripts are letters (ΤΩΙ ΟΝΤΙ), and are kept.
+fn convert_all_caps(s: &str) -> String {
+    const DIACRITICS_TO_REMOVE: [char; 5] = [
+        Diacritic::IOTA_SUBSCRIPT,
+        Diacritic::ROUGH,
+        Diacritic::SMOOTH,
+        Diacritic::GRAVE,
+        Diacritic::CIRCUMFLEX,
+    ];
+
+    s.nfd()
+        .filter(|ch| !DIACRITICS_TO_REMOVE.contains(ch))
+        .nfc()
+        .collect()
+}
+
 /// Special cases.
 ///
 /// Sometimes we need the polytonic word to make a decision:
@@ -132,7 +848,7 @@ fn convert_to_acute(s: &str) -> String {
 /// Sometimes, due to synizesis, the words with replaced acute accents
 /// does not exist:
 /// Ex: ποιὸς => ποιός (when it should be ποιος)
-fn special_cases(s: &str) -> Option<&str> {
+pub(crate) fn special_cases(s: &str) -> Option<&str> {
     match s {
         "ποὺ" => Some("που"),
         "Ποὺ" => Some("Που"),
@@ -147,79 +863,110 @@ fn special_cases(s: &str) -> Option<&str> {
     }
 }
 
-/// Convert a string representing a word to monotonic Greek.
-fn to_monotonic_word(s: &str) -> String {
-    // If the word is empty our segmentation logic is probably wrong.
-    debug_assert!(!s.is_empty());
-
-    // Do not remove accents if the word is not greek
-    if !is_greek_word(s) {
-        log("Not a greek word!", s);
-        return s.to_string();
+impl Converter<'_> {
+    fn keeps_monosyllable_accent(&self, syllable: &str) -> bool {
+        if self.options.keep_pronoun_accents {
+            is_monosyllable_accented_with_pronouns(syllable)
+        } else {
+            is_monosyllable_accented(syllable)
+        }
     }
 
-    // Decompose punctuation
-    let (left_punct, core, right_punct) = split_punctuation(s);
-    log("Left punct", left_punct);
-    log("Right punct", right_punct);
+    /// Convert a string representing a word to monotonic Greek.
+    ///
+    /// The word is decoded once into `buf`, that keeps its allocations between words.
+    fn to_monotonic_word(&self, buf: &mut GreekWordBuf, s: &str) -> String {
+        // Segmentation never gives empty words, but there is nothing to convert anyway.
+        if s.is_empty() {
+            return String::new();
+        }
+
+        // Work on NFC, so that decomposed input gives the same result.
+        // Do not remove accents if the word is not greek
+        if !buf.set_if_greek(s, self.options.strict_greek) {
+            log("Not a greek word!", s);
+            return s.to_string();
+        }
 
-    if let Some(ret) = special_cases(core) {
-        return format!("{left_punct}{ret}{right_punct}");
-    }
+        // Decompose punctuation
+        let (left_punct, core, right_punct) = buf.split_punctuation();
+        log("Left punct", left_punct);
+        log("Right punct", right_punct);
 
-    log("Input word", core);
-    dbg_bytes(core);
+        if let Some(ret) = self
+            .options
+            .special_cases
+            .get(core)
+            .map(String::as_str)
+            .or_else(|| special_cases(core))
+        {
+            return format!("{left_punct}{ret}{right_punct}");
+        }
 
-    let mut out: String = convert_to_acute(core);
+        log("Input word", core);
+        dbg_bytes(core);
 
-    let ends_with_abbreviation = right_punct
-        .chars()
-        .next()
-        .is_some_and(|fst_rpunct| APOSTROPHES.contains(&fst_rpunct));
+        if buf.is_all_caps() {
+            log("All caps", "Removing diacritics");
+            let out = convert_all_caps(core);
+            return format!("{left_punct}{out}{right_punct}");
+        }
 
-    log("Ends in abbreviation?", ends_with_abbreviation);
+        let mut out: String = if buf.is_core_plain() {
+            core.to_string()
+        } else {
+            convert_to_acute(core)
+        };
 
-    let syllables = syllabify(&out);
-    log("Syllabified word", &syllables);
+        let ends_with_abbreviation = right_punct
+            .chars()
+            .next()
+            .is_some_and(|fst_rpunct| APOSTROPHES.contains(&fst_rpunct));
 
-    out = match syllables.as_slice() {
-        // Do we remove the acute accent from a monosyllable?...
-        [syl] => {
-            // To remove the acute, the word should:
-            // - not be in the excluded list
-            // - not end in an abbreviation mark: έτσ' είναι
-            // - not end in a diphthong: σόι, Κάιν etc.
-            if !MONOSYLLABLE_ACCENTED.contains(syl)
-                && !ends_with_abbreviation
-                && !ends_with_diphthong(&out)
-            {
-                log("Monosyllable no accent", "Removing accents");
-                remove_acute(&out)
-            } else {
-                log("Word keeps accents", &out);
-                out
+        log("Ends in abbreviation?", ends_with_abbreviation);
+
+        let syllables = (self.syllabify)(&out);
+        log("Syllabified word", &syllables);
+
+        out = match syllables.as_slice() {
+            // Do we remove the acute accent from a monosyllable?...
+            [syl] => {
+                // To remove the acute, the word should:
+                // - not be in the excluded list
+                // - not end in an abbreviation mark: έτσ' είναι
+                // - not end in a diphthong: σόι, Κάιν etc.
+                if !self.keeps_monosyllable_accent(syl)
+                    && !ends_with_abbreviation
+                    && !ends_with_diphthong(&out)
+                {
+                    log("Monosyllable no accent", "Removing accents");
+                    remove_acute(&out)
+                } else {
+                    log("Word keeps accents", &out);
+                    out
+                }
             }
-        }
-        [.., syl1, syl2] => {
-            if MONOSYL_REMOVE_ACCENT.contains(&out.as_str()) {
-                log("Word in NOT_ACCENTED list", "Removing accents");
-                remove_acute(&out)
-            } else if has_acute(*syl1) && has_acute(*syl2) {
-                log("Two acute accents in two syllables", "Removing last acute");
-                remove_diacritic_at(&out, 1, Diacritic::ACUTE)
-            } else {
-                log("Word keeps accents", &out);
-                out
+            [.., syl1, syl2] => {
+                if is_monosyllable_remove_accent(&out) {
+                    log("Word in NOT_ACCENTED list", "Removing accents");
+                    remove_acute(&out)
+                } else if has_acute(*syl1) && has_acute(*syl2) {
+                    log("Two acute accents in two syllables", "Removing last acute");
+                    remove_diacritic_at_syllables(&syllables, 1, Diacritic::ACUTE)
+                } else {
+                    log("Word keeps accents", &out);
+                    out
+                }
             }
-        }
-        _ => out,
-    };
+            _ => out,
+        };
 
-    log("Final transformed word", &out);
-    dbg_bytes(&out);
-    log("======================", "");
+        log("Final transformed word", &out);
+        dbg_bytes(&out);
+        log("======================", "");
 
-    format!("{left_punct}{out}{right_punct}")
+        format!("{left_punct}{out}{right_punct}")
+    }
 }
 
 #[cfg(test)]
@@ -249,6 +996,127 @@ mod tests {
         assert_eq!(split_punctuation("2ος"), ("2", "ος", ""));
     }
 
+    #[test]
+    fn test_to_monotonic_with_identity() {
+        let text = "«Ἅμα πιῇς» τσάι,  γάϊδουρος—καὶ δύο-τρεῖς...\n\n ";
+        let mut words = Vec::new();
+        let result = to_monotonic_with(text, |word, converted| {
+            words.push(word.to_string());
+            converted
+        });
+        assert_eq!(result, to_monotonic(text));
+        assert_eq!(words[..3], ["«Ἅμα", "πιῇς»", "τσάι,"]);
+        assert_eq!(words.len(), 7);
+    }
+
+    #[test]
+    fn test_whitespace_preserved() {
+        let whitespace = |text: &str| -> (String, usize) {
+            (
+                text.chars().filter(|ch| ch.is_whitespace()).collect(),
+                text.split(char::is_whitespace).count(),
+            )
+        };
+        let dump = include_str!("../tests/fixtures/dump.txt");
+        let text = format!("{dump}\t«καὶ»\u{2009}τὸ\u{00A0}\u{00A0}σπίτι \r\n\u{2028} ");
+        let options = MonotonicOptions::new().with_conservative_names(true);
+        for converted in [
+            to_monotonic(&text),
+            to_monotonic_cow(&text).into_owned(),
+            to_monotonic_with_options(&text, &options),
+        ] {
+            assert_eq!(whitespace(&converted), whitespace(&text));
+        }
+    }
+
+    #[test]
+    fn test_to_monotonic_stream() {
+        let text = "καὶ\n\nτὸ σπίτι\n".repeat(1000) + "τοῦ";
+        let mut out = Vec::new();
+        let reader = io::BufReader::with_capacity(7, text.as_bytes());
+        to_monotonic_stream(reader, &mut out, DEFAULT_OPTIONS).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), to_monotonic(&text));
+
+        let err = to_monotonic_stream(&[0xFF][..], io::sink(), DEFAULT_OPTIONS).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_to_monotonic_cow() {
+        let dump = include_str!("../tests/fixtures/dump.txt");
+        let mut borrowed = 0;
+        for line in dump.lines() {
+            let monotonic = to_monotonic(line);
+            for text in [line, monotonic.as_str()] {
+                let cow = to_monotonic_cow(text);
+                assert_eq!(cow, to_monotonic(text), "{text}");
+                borrowed += usize::from(matches!(cow, Cow::Borrowed(_)));
+            }
+        }
+        assert!(borrowed > 0);
+        for text in [
+            "ά\u{301}",
+            "γιά",
+            "τί",
+            "σ' αγαπώ",
+            "πού;",
+            "Άϊντε",
+            "ΆΝΘΡΩΠΟΣ",
+            "",
+        ] {
+            assert_eq!(to_monotonic_cow(text), to_monotonic(text), "{text}");
+        }
+    }
+
+    #[test]
+    fn test_protected_changes() {
+        let options = MonotonicOptions {
+            protected: ProtectedRegions::xml(),
+            ..Default::default()
+        };
+        let converter = Converter {
+            options: &options,
+            ..Converter::default()
+        };
+        let text = "<l n=\"ἕν\">καὶ</l>";
+        let changes = converter.changes(text);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(&text[changes[0].span.clone()], "καὶ");
+        assert_eq!(converter.to_monotonic(text), "<l n=\"ἕν\">και</l>");
+        let mut words = Vec::new();
+        let result = converter.to_monotonic_with(text, |word, converted| {
+            words.push(word.to_string());
+            converted
+        });
+        assert_eq!(result, "<l n=\"ἕν\">και</l>");
+        assert_eq!(words, ["καὶ"]);
+    }
+
+    #[test]
+    fn test_monotonic_changes_apply() {
+        let text = "«Ἅμα πιῇς» τσάι, γάϊδουρος—καὶ δύο-τρεῖς...";
+        let mut out = text.to_string();
+        for change in monotonic_changes(text).iter().rev() {
+            assert_eq!(&text[change.span.clone()], change.old);
+            out.replace_range(change.span.clone(), &change.new);
+        }
+        assert_eq!(out, to_monotonic(text));
+    }
+
+    #[test]
+    fn test_monotonic_changes_decomposed() {
+        let text: String = "«Ἅμα πιῇς» τσάι, καλά".nfd().collect();
+        let changes = monotonic_changes(&text);
+        // Words that only differ by normalization are not reported.
+        assert_eq!(changes.len(), 2);
+        assert_eq!(
+            &text[changes[0].span.clone()],
+            "Ἅμα".nfd().collect::<String>()
+        );
+        assert_eq!(changes[0].new, "Άμα");
+        assert_eq!(changes[1].new, "πιης");
+    }
+
     macro_rules! mktest_mono {
         ($group_name:ident, $([$input:expr, $expected:expr]),* $(,)?) => {
             #[test]
@@ -324,6 +1192,160 @@ mod tests {
         ["Όϊπεν", "Όιπεν"],
     );
 
+    #[test]
+    fn test_strict_greek() {
+        let text = "ϣαϫὲ καὶ λόγος";
+        assert_eq!(to_monotonic(text), "ϣαϫέ και λόγος");
+        let options = MonotonicOptions {
+            strict_greek: true,
+            ..Default::default()
+        };
+        assert_eq!(to_monotonic_with_options(text, &options), "ϣαϫὲ και λόγος");
+        // Other words are kept as they are, even decomposed.
+        assert_eq!(
+            to_monotonic_with_options("ϣαϫε\u{300} cafe\u{301}", &options),
+            "ϣαϫε\u{300} cafe\u{301}"
+        );
+    }
+
+    #[test]
+    fn test_non_greek_words_unchanged() {
+        let text = "καὶ cafe\u{301} Ame\u{301}lie\u{300}";
+        assert_eq!(to_monotonic(text), "και cafe\u{301} Ame\u{301}lie\u{300}");
+        assert_eq!(to_monotonic_cow("cafe\u{301}"), "cafe\u{301}");
+    }
+
+    #[test]
+    fn test_word_separators() {
+        let text = "πού-πού, δύο-τρεῖς καὶ ἔλα/δῶ";
+        assert_eq!(to_monotonic(text), "πού-πού, δύο-τρεις και έλα/δώ");
+        for (separators, expected) in [
+            (WordSeparators::Whitespace, "πού-που, δύο-τρείς και έλα/δώ"),
+            (
+                WordSeparators::custom(|ch| ch.is_whitespace() || ch == '/'),
+                "πού-που, δύο-τρείς και έλα/δω",
+            ),
+        ] {
+            let options = MonotonicOptions::new().with_word_separators(separators);
+            assert_eq!(to_monotonic_with_options(text, &options), expected);
+            let report = crate::to_monotonic_with_report(text, &options);
+            assert_eq!(report.output, expected);
+            let converter = Converter {
+                options: &options,
+                ..Converter::default()
+            };
+            let changes = converter.changes(text);
+            assert_eq!(changes[0].span, 0..13);
+        }
+    }
+
+    #[test]
+    fn test_protected_words() {
+        let text = "Ὁ Κέϋνς καὶ ὁ «Ντόϊλ», Ντόϊλ-Κέϋνς";
+        assert_eq!(to_monotonic(text), "Ο Κέυνς και ο «Ντόιλ», Ντόιλ-Κέυνς");
+        let options = MonotonicOptions {
+            protected_words: ["Κέϋνς", "Ντόϊλ"].map(String::from).into(),
+            ..Default::default()
+        };
+        let expected = "Ο Κέϋνς και ο «Ντόϊλ», Ντόϊλ-Κέϋνς";
+        assert_eq!(to_monotonic_with_options(text, &options), expected);
+        let converter = Converter {
+            options: &options,
+            ..Converter::default()
+        };
+        let changes = converter.changes(text);
+        assert_eq!(changes.len(), 3);
+        assert!(changes.iter().all(|change| !change.old.contains('ϋ')));
+    }
+
+    #[test]
+    fn test_conservative_names() {
+        let text = "Ὁ Κέϋνς εἶπε στὸν Ντόϊλ-Ρέϊ: «Ναὶ». Ἢ Γάϊδαρος καὶ Ὅμηρος; ΚΑῚ";
+        let options = MonotonicOptions {
+            conservative_names: true,
+            ..Default::default()
+        };
+        let expected = "Ο Κέϋνς είπε στον Ντόϊλ-Ρέϊ: «Ναι». Ή Γάϊδαρος και Όμηρος; ΚΑΙ";
+        assert_eq!(to_monotonic_with_options(text, &options), expected);
+        let converter = Converter {
+            options: &options,
+            ..Converter::default()
+        };
+        let changed: String = converter
+            .changes(text)
+            .iter()
+            .map(|change| change.new.as_str())
+            .collect::<Vec<_>>()
+            .join(" ");
+        assert_eq!(changed, "Ο είπε στον Ναι Ή και Όμηρος ΚΑΙ");
+    }
+
+    #[test]
+    fn test_diaeresis_policy() {
+        let text = "Ὁ γάϊδαρος, ἄϋλος";
+        let options = MonotonicOptions {
+            diaeresis: DiaeresisPolicy::Keep,
+            ..Default::default()
+        };
+        assert_eq!(
+            to_monotonic_with_options(text, &options),
+            "Ο γάϊδαρος, άϋλος"
+        );
+        assert_eq!(
+            Converter {
+                options: &options,
+                ..Default::default()
+            }
+            .changes(text)
+            .len(),
+            2
+        );
+
+        let options = MonotonicOptions {
+            diaeresis: DiaeresisPolicy::Custom(vec![
+                ("γάϊδ".to_string(), "γάϊδ".to_string()),
+                (String::new(), "α".to_string()),
+            ]),
+            ..Default::default()
+        };
+        // Longest match wins over άϊ → άι
+        assert_eq!(
+            to_monotonic_with_options(text, &options),
+            "Ο γάϊδαρος, άυλος"
+        );
+    }
+
+    #[test]
+    fn test_shared_diaeresis_fixer() {
+        let policy = DiaeresisPolicy::default();
+        assert!(matches!(DiaeresisFixer::shared(&policy), Cow::Borrowed(_)));
+        assert!(matches!(
+            DiaeresisFixer::shared(&DiaeresisPolicy::Custom(Vec::new())),
+            Cow::Owned(_)
+        ));
+        let results: Vec<String> = std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..4)
+                .map(|_| scope.spawn(|| to_monotonic("Ὁ γάϊδαρος")))
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        });
+        assert!(results.iter().all(|result| result == "Ο γάιδαρος"));
+    }
+
+    #[test]
+    fn test_normalize_diaereses_cow() {
+        let policy = DiaeresisPolicy::default();
+        assert!(matches!(
+            normalize_diaereses_cow("γάιδαρος", &policy),
+            Cow::Borrowed(_)
+        ));
+        assert_eq!(normalize_diaereses_cow("γάϊδαρος", &policy), "γάιδαρος");
+        assert!(matches!(
+            normalize_diaereses_cow("γάϊδαρος", &DiaeresisPolicy::Keep),
+            Cow::Borrowed(_)
+        ));
+    }
+
     mktest_mono!(
         mono_not_greek,
         ["1808·", "1808·"],
@@ -419,7 +1441,15 @@ mod tests {
     mktest_mono!(
         mono_capital,
         ["ΩΙΔΗ ΑΙΘΟΥΣΑ ΗΙΩΝΑ", "ΩΙΔΗ ΑΙΘΟΥΣΑ ΗΙΩΝΑ"],
-        ["ΕΛΛΑΔΑ", "ΕΛΛΑΔΑ"]
+        ["ΕΛΛΑΔΑ", "ΕΛΛΑΔΑ"],
+        ["ΤΩΙ ΟΝΤΙ", "ΤΩΙ ΟΝΤΙ"],
+        ["ἙΛΛΑΣ ΚΑῚ ἘΛΕΥΘΕΡΙΑ", "ΕΛΛΑΣ ΚΑΙ ΕΛΕΥΘΕΡΙΑ"],
+        ["Τῼ ΘΕῼ", "ΤΩ ΘΕΩ"],
+        ["«ΤΗ͂Σ ΓΗ͂Σ»", "«ΤΗΣ ΓΗΣ»"],
+        ["ΆΓΙΟΣ ΠΟΎ", "ΆΓΙΟΣ ΠΟΎ"],
+        ["ΠΡΩΤΕΪΝΗ", "ΠΡΩΤΕΪΝΗ"],
+        // Not in capitals
+        ["Ἀθῆναι ᾤ Ἅ", "Αθήναι ω Α"],
     );
 
     mktest_mono!(
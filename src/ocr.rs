@@ -0,0 +1,344 @@
+//! Cleanup of digitized (OCR) text.
+
+use std::borrow::Cow;
+
+use unicode_normalization::UnicodeNormalization;
+
+use crate::accents::{Diacritic, has_diacritic, has_diacritics};
+use crate::chars::{is_combining_mark, is_greek_letter, is_greek_word};
+use crate::crasis::{coronis_idx, expand_crasis};
+use crate::monotonic::{split_punctuation, split_words, word_changes};
+use crate::span::Change;
+use crate::syllabify::{is_diphthong, is_vowel, syllabify, syllable_spans};
+
+/// Hyphen, soft hyphen and Unicode hyphen.
+const HYPHENS: [char; 3] = ['-', '\u{00AD}', '\u{2010}'];
+
+const ACCENTS: [char; 3] = [Diacritic::ACUTE, Diacritic::GRAVE, Diacritic::CIRCUMFLEX];
+const BREATHINGS: [char; 2] = [Diacritic::SMOOTH, Diacritic::ROUGH];
+
+/// Rejoin words split across lines with a hyphen.
+///
+/// The parts are only joined if the split falls on a syllable boundary of the
+/// joined word, the second part starts in lowercase, and at most one of the
+/// parts is accented (otherwise they are more likely two hyphenated words).
+///
+/// The line break is moved after the joined word.
+///
+/// Note that compounds hyphenated at the end of a line (Ελληνο-τουρκικός) can
+/// not be told apart from split words and will be joined.
+///
+/// # Example
+///
+/// ```
+/// use grac::dehyphenate;
+///
+/// let text = "Ὁ ἄν-\nθρωπος εἶναι ζῷον πολι-\n  τικόν.\nἕνα-\nἕνα";
+/// assert_eq!(
+///     dehyphenate(text),
+///     "Ὁ ἄνθρωπος\nεἶναι ζῷον πολιτικόν.\nἕνα-\nἕνα"
+/// );
+/// ```
+pub fn dehyphenate(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut lines = text.split_inclusive('\n');
+    let Some(first) = lines.next() else {
+        return out;
+    };
+    // Owned when the line ends with a joined word, that may be split again.
+    let mut line = Cow::Borrowed(first);
+
+    loop {
+        let next = lines.next();
+        if let Some(split) = next.and_then(|next| split_hyphenated(&line, next)) {
+            let rest = split.rest.trim_start_matches([' ', '\t']);
+            if rest.len() < split.rest.len() {
+                // Keep the line break after the joined word.
+                out.push_str(split.head);
+                out.push_str(split.tail);
+                out.push_str(split.eol);
+                line = Cow::Borrowed(rest);
+            } else {
+                // The joined word ends the next line.
+                line = Cow::Owned(format!("{}{}{}", split.head, split.tail, split.rest));
+            }
+        } else {
+            out.push_str(&line);
+            match next {
+                Some(next) => line = Cow::Borrowed(next),
+                None => break,
+            }
+        }
+    }
+
+    out
+}
+
+struct Split<'l, 'n> {
+    /// The line, up to the first part of the word (hyphen excluded).
+    head: &'l str,
+    /// The second part of the word, with trailing punctuation.
+    tail: &'n str,
+    /// What follows in the next line.
+    rest: &'n str,
+    /// The line terminator.
+    eol: &'static str,
+}
+
+fn split_hyphenated<'l, 'n>(line: &'l str, next: &'n str) -> Option<Split<'l, 'n>> {
+    let body = line.strip_suffix('\n')?;
+    let (body, eol) = match body.strip_suffix('\r') {
+        Some(body) => (body, "\r\n"),
+        None => (body, "\n"),
+    };
+    let head = body.trim_end_matches([' ', '\t']).strip_suffix(HYPHENS)?;
+    let first_part = &head[head
+        .char_indices()
+        .rev()
+        .take_while(|(_, ch)| is_greek_letter(*ch))
+        .last()?
+        .0..];
+
+    let next = next.trim_start_matches([' ', '\t']);
+    let second_part_len = next
+        .char_indices()
+        .find(|(_, ch)| !is_greek_letter(*ch))
+        .map_or(next.len(), |(i, _)| i);
+    let second_part = &next[..second_part_len];
+
+    if !is_plausible_split(first_part, second_part) {
+        return None;
+    }
+
+    let tail_len = next
+        .char_indices()
+        .find(|(_, ch)| ch.is_whitespace())
+        .map_or(next.len(), |(i, _)| i);
+    Some(Split {
+        head,
+        tail: &next[..tail_len],
+        rest: &next[tail_len..],
+        eol,
+    })
+}
+
+fn is_plausible_split(first: &str, second: &str) -> bool {
+    if first.is_empty() || !second.chars().next().is_some_and(char::is_lowercase) {
+        return false;
+    }
+    if has_diacritics(first, &ACCENTS) && has_diacritics(second, &ACCENTS) {
+        return false;
+    }
+    let word = format!("{first}{second}");
+    syllable_spans(&word)
+        .iter()
+        .any(|span| span.start == first.len())
+}
+
+/// Fix polytonic diacritics that are impossible, and usually OCR mistakes.
+///
+/// * Diacritics on consonants are removed, except the rough breathing of an initial ρ: ῥ
+/// * Only one accent and one breathing are kept per vowel.
+/// * Breathings are removed, except on the first vowel (or diphthong): ἀ, αὐ.
+/// * Initial υ and ρ take a rough breathing: ὐ > ὑ
+/// * Graves before the last syllable become acutes: ὰνθρωπος > άνθρωπος
+///
+/// Use [`ocr_diacritic_changes`] to report the changes instead.
+///
+/// # Example
+///
+/// ```
+/// use grac::repair_ocr_diacritics;
+///
+/// assert_eq!(repair_ocr_diacritics("ὐπὸ τὸν ἄνθρὠπον"), "ὑπὸ τὸν ἄνθρωπον");
+/// ```
+pub fn repair_ocr_diacritics(text: &str) -> String {
+    split_words(text).map(repair_word).collect()
+}
+
+/// Word level changes that [`repair_ocr_diacritics`] would make.
+///
+/// # Example
+///
+/// ```
+/// use grac::ocr_diacritic_changes;
+///
+/// let changes = ocr_diacritic_changes("ὐπὸ τὸν ἄνθρὠπον");
+/// assert_eq!(changes.len(), 2);
+/// assert_eq!(changes[1].span, 17..35);
+/// assert_eq!(changes[1].new, "ἄνθρωπον");
+/// ```
+pub fn ocr_diacritic_changes(text: &str) -> Vec<Change> {
+    word_changes(text, repair_word)
+}
+
+fn repair_word(word: &str) -> String {
+    if !is_greek_word(word) {
+        return word.to_string();
+    }
+    let (left_punct, core, right_punct) = split_punctuation(word);
+
+    // Base characters with their combining marks
+    let mut clusters: Vec<(char, Vec<char>)> = Vec::new();
+    for ch in core.nfd() {
+        match clusters.last_mut() {
+            Some((_, marks)) if is_combining_mark(ch) => marks.push(ch),
+            // Stray marks without a base character
+            None if is_combining_mark(ch) => {}
+            _ => clusters.push((ch, Vec::new())),
+        }
+    }
+
+    let initial_diphthong = matches!(
+        clusters.as_slice(),
+        [(a, _), (b, _), ..] if is_vowel(*a) && is_diphthong(*a, *b)
+    );
+    // The coronis of a known crasis is not a stray breathing: κἀγώ, τοὔνομα.
+    let coronis = if expand_crasis(core).is_empty() {
+        None
+    } else {
+        coronis_idx(core)
+    };
+    for (i, (base, marks)) in clusters.iter_mut().enumerate() {
+        if !is_vowel(*base) {
+            let rough = i == 0
+                && matches!(base, 'ρ' | 'Ρ')
+                && marks.iter().any(|mark| BREATHINGS.contains(mark));
+            marks.clear();
+            if rough {
+                marks.push(Diacritic::ROUGH);
+            }
+            continue;
+        }
+
+        keep_first_of(marks, &ACCENTS);
+        keep_first_of(marks, &BREATHINGS);
+        if i == 0 && matches!(base, 'υ' | 'Υ') {
+            for mark in marks.iter_mut() {
+                if *mark == Diacritic::SMOOTH {
+                    *mark = Diacritic::ROUGH;
+                }
+            }
+        } else if !(i == 0 || i == 1 && initial_diphthong || coronis == Some(i)) {
+            marks.retain(|mark| !BREATHINGS.contains(mark));
+        }
+    }
+
+    let repaired: String = clusters
+        .into_iter()
+        .flat_map(|(base, marks)| std::iter::once(base).chain(marks))
+        .nfc()
+        .collect();
+
+    let syllables = syllabify(&repaired);
+    let last = syllables.len().saturating_sub(1);
+    let repaired: String = syllables
+        .iter()
+        .enumerate()
+        .map(|(i, syllable)| {
+            if i < last && has_diacritic(*syllable, Diacritic::GRAVE) {
+                syllable
+                    .nfd()
+                    .map(|ch| {
+                        if ch == Diacritic::GRAVE {
+                            Diacritic::ACUTE
+                        } else {
+                            ch
+                        }
+                    })
+                    .nfc()
+                    .collect()
+            } else {
+                (*syllable).to_string()
+            }
+        })
+        .collect();
+
+    format!("{left_punct}{repaired}{right_punct}")
+}
+
+/// Remove every mark in `group` but the first.
+fn keep_first_of(marks: &mut Vec<char>, group: &[char]) {
+    let mut found = false;
+    marks.retain(|mark| {
+        if !group.contains(mark) {
+            return true;
+        }
+        !std::mem::replace(&mut found, true)
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dehyphenate() {
+        let cases = [
+            ("", ""),
+            ("καλη-\nμέρα", "καλημέρα"),
+            ("καλη-\r\nμέρα σας", "καλημέρα\r\nσας"),
+            ("καλη-  \nμέρα.\nΤι", "καλημέρα.\nΤι"),
+            // Soft hyphen
+            ("καλη\u{00AD}\nμέρα", "καλημέρα"),
+            // Not at a syllable boundary
+            ("καλ-\nημέρα", "καλ-\nημέρα"),
+            // Uppercase second part
+            ("Ελλάδα-\nΚύπρος", "Ελλάδα-\nΚύπρος"),
+            // Not Greek
+            ("well-\nknown", "well-\nknown"),
+            // Hyphen not at the end of the line
+            ("πρωτο-πόρος\nκαι", "πρωτο-πόρος\nκαι"),
+        ];
+        for (text, expected) in cases {
+            assert_eq!(dehyphenate(text), expected, "{text:?}");
+        }
+    }
+
+    #[test]
+    fn test_repair_ocr_diacritics() {
+        let cases = [
+            // Already correct
+            ("Ὁ ἄνθρωπος εἶναι ζῷον πολιτικόν, αὐτὸς υἱός.", None),
+            ("ῥήτωρ", None),
+            // Coronis of a crasis
+            ("κἀγώ, τοὔνομα, κἀκεῖνος, ταὐτό.", None),
+            ("Κἀγώ", None),
+            // Breathing on a non-initial vowel
+            ("ἄνθρὠπος", Some("ἄνθρωπος")),
+            // Two accents on a vowel
+            ("λο\u{301}\u{300}γος", Some("λόγος")),
+            // Two breathings on a vowel
+            ("ἀ\u{314}γαθός", Some("ἀγαθός")),
+            // Accent on a consonant
+            ("λ\u{301}όγος", Some("λόγος")),
+            // Smooth breathing on initial ρ and υ
+            ("ῤήτωρ", Some("ῥήτωρ")),
+            ("ὐμεῖς", Some("ὑμεῖς")),
+            // Grave before the last syllable
+            ("ὰνθρωπος", Some("άνθρωπος")),
+            // Not a known crasis
+            ("κἀλός", Some("καλός")),
+        ];
+        for (text, expected) in cases {
+            let expected = expected.unwrap_or(text);
+            assert_eq!(repair_ocr_diacritics(text), expected, "{text}");
+        }
+    }
+
+    #[test]
+    fn test_ocr_diacritic_changes_apply() {
+        let text = "«ὐπὸ» τὸν λὀγον, ἄνθρὠπε!";
+        let mut applied = text.to_string();
+        for change in ocr_diacritic_changes(text).iter().rev() {
+            applied.replace_range(change.span.clone(), &change.new);
+        }
+        assert_eq!(applied, repair_ocr_diacritics(text));
+    }
+
+    #[test]
+    fn test_dehyphenate_consecutive() {
+        let text = "α-\nνα-\nγνώ-\nριση";
+        assert_eq!(dehyphenate(text), "αναγνώριση");
+    }
+}
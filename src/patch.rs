@@ -0,0 +1,299 @@
+//! Changes as patches, to review conversions one change at a time.
+//!
+//! The changes returned by grac ([`monotonic_changes`](crate::monotonic_changes),
+//! [`ocr_diacritic_changes`](crate::ocr_diacritic_changes)...) are sorted and
+//! independent of each other, so that any subset of them can be applied, and
+//! reverted with [`invert_changes`].
+
+use std::fmt;
+use std::fmt::Write;
+use std::ops::Range;
+
+use crate::span::{self, Change};
+
+/// Lines of context around the changes of a [`unified_diff`].
+const CONTEXT_LINES: usize = 3;
+
+/// Errors when changes do not fit the text they are applied to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PatchError {
+    /// The change at `index` starts before the end of the previous one.
+    Overlap { index: usize },
+    /// The text at the span of the change at `index` is not its `old` text.
+    Mismatch { index: usize },
+}
+
+impl fmt::Display for PatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Overlap { index } => write!(f, "change {index} overlaps the previous one"),
+            Self::Mismatch { index } => write!(f, "change {index} does not match the text"),
+        }
+    }
+}
+
+impl std::error::Error for PatchError {}
+
+/// Check that changes are sorted, without overlaps, and match the text.
+fn check_changes(text: &str, changes: &[Change]) -> Result<(), PatchError> {
+    let mut end = 0;
+    for (index, change) in changes.iter().enumerate() {
+        if change.span.start < end {
+            return Err(PatchError::Overlap { index });
+        }
+        if text.get(change.span.clone()) != Some(change.old.as_str()) {
+            return Err(PatchError::Mismatch { index });
+        }
+        end = change.span.end;
+    }
+    Ok(())
+}
+
+/// Apply changes, sorted and without overlaps, to the text they were made to.
+///
+/// # Errors
+///
+/// Returns [`PatchError::Overlap`] if the changes are not sorted or overlap,
+/// and [`PatchError::Mismatch`] if one of them was not made to `text`.
+///
+/// # Example
+///
+/// ```
+/// use grac::{apply_changes, monotonic_changes};
+///
+/// let text = "Τὸ σπίτι καὶ ἡ αὐλή";
+/// let changes = monotonic_changes(text);
+/// assert_eq!(changes.len(), 4);
+/// assert_eq!(apply_changes(text, &changes).unwrap(), "Το σπίτι και η αυλή");
+///
+/// // Accept only the change to καὶ
+/// let accepted: Vec<_> = changes.into_iter().filter(|change| change.old == "καὶ").collect();
+/// assert_eq!(apply_changes(text, &accepted).unwrap(), "Τὸ σπίτι και ἡ αὐλή");
+/// ```
+pub fn apply_changes(text: &str, changes: &[Change]) -> Result<String, PatchError> {
+    check_changes(text, changes)?;
+    Ok(span::apply_changes(text, changes))
+}
+
+/// The changes that undo `changes`, with spans in the text they produce.
+///
+/// Like the original changes, they are independent of each other: any subset
+/// of them can be applied to revert some changes only.
+///
+/// # Example
+///
+/// ```
+/// use grac::{apply_changes, invert_changes, monotonic_changes};
+///
+/// let text = "Τὸ σπίτι καὶ ἡ αὐλή";
+/// let changes = monotonic_changes(text);
+/// let output = apply_changes(text, &changes).unwrap();
+///
+/// let inverse = invert_changes(&changes);
+/// assert_eq!(apply_changes(&output, &inverse).unwrap(), text);
+/// assert_eq!(apply_changes(&output, &inverse[1..2]).unwrap(), "Το σπίτι καὶ η αυλή");
+/// ```
+pub fn invert_changes(changes: &[Change]) -> Vec<Change> {
+    // Output length minus input length of the changes so far.
+    let mut delta = 0;
+    changes
+        .iter()
+        .map(|change| {
+            let start = change.span.start.saturating_add_signed(delta);
+            delta += change.new.len() as isize - change.span.len() as isize;
+            Change {
+                span: start..start + change.new.len(),
+                old: change.new.clone(),
+                new: change.old.clone(),
+            }
+        })
+        .collect()
+}
+
+/// Changes to a text as a unified diff, for `patch` or `git apply`.
+///
+/// # Errors
+///
+/// Returns [`PatchError::Overlap`] if the changes are not sorted or overlap,
+/// and [`PatchError::Mismatch`] if one of them was not made to `text`.
+///
+/// # Example
+///
+/// ```
+/// use grac::{monotonic_changes, unified_diff};
+///
+/// let text = "Ἑλλάς\nκαὶ\nκόσμος\n";
+/// let diff = unified_diff(text, &monotonic_changes(text), "text.txt").unwrap();
+/// assert_eq!(
+///     diff,
+///     "--- a/text.txt\n+++ b/text.txt\n@@ -1,3 +1,3 @@\n-Ἑλλάς\n-καὶ\n+Ελλάς\n+και\n κόσμος\n"
+/// );
+/// ```
+pub fn unified_diff(text: &str, changes: &[Change], path: &str) -> Result<String, PatchError> {
+    check_changes(text, changes)?;
+    let line_starts: Vec<usize> = std::iter::once(0)
+        .chain(text.match_indices('\n').map(|(idx, _)| idx + 1))
+        .filter(|&start| start < text.len())
+        .collect();
+    let line_of = |offset: usize| line_starts.partition_point(|&start| start <= offset).max(1) - 1;
+
+    // Changes grouped by the lines they touch.
+    let mut blocks: Vec<(Range<usize>, Range<usize>)> = Vec::new();
+    for (idx, change) in changes.iter().enumerate() {
+        let first = line_of(change.span.start);
+        let last = line_of(change.span.end.max(change.span.start + 1) - 1);
+        match blocks.last_mut() {
+            Some((lines, indices)) if first <= lines.end => {
+                lines.end = lines.end.max(last + 1);
+                indices.end = idx + 1;
+            }
+            _ => blocks.push((first..last + 1, idx..idx + 1)),
+        }
+    }
+
+    let line_count = line_starts.len();
+    let byte_of = |line: usize| line_starts.get(line).copied().unwrap_or(text.len());
+    let mut out = format!("--- a/{path}\n+++ b/{path}\n");
+    // Lines added minus lines removed by the previous hunks.
+    let mut delta = 0;
+    let mut blocks = blocks.into_iter().peekable();
+    while let Some(first_block) = blocks.next() {
+        let mut hunk = vec![first_block];
+        while let Some(next) = blocks.peek()
+            && next.0.start <= hunk[hunk.len() - 1].0.end + 2 * CONTEXT_LINES
+        {
+            hunk.extend(blocks.next());
+        }
+        let start = hunk[0].0.start.saturating_sub(CONTEXT_LINES);
+        let end = (hunk[hunk.len() - 1].0.end + CONTEXT_LINES).min(line_count);
+
+        let mut body = String::new();
+        let (mut old_len, mut new_len) = (0, 0);
+        let mut line = start;
+        for (lines, indices) in hunk {
+            let context = push_lines(&mut body, ' ', &text[byte_of(line)..byte_of(lines.start)]);
+            old_len += context;
+            new_len += context;
+            let (from, to) = (byte_of(lines.start), byte_of(lines.end));
+            let shifted: Vec<Change> = changes[indices]
+                .iter()
+                .map(|change| Change {
+                    span: change.span.start - from..change.span.end - from,
+                    ..change.clone()
+                })
+                .collect();
+            let new = span::apply_changes(&text[from..to], &shifted);
+            old_len += push_lines(&mut body, '-', &text[from..to]);
+            new_len += push_lines(&mut body, '+', &new);
+            line = lines.end;
+        }
+        let context = push_lines(&mut body, ' ', &text[byte_of(line)..byte_of(end)]);
+        old_len += context;
+        new_len += context;
+
+        let old_start = start + 1;
+        let new_start = old_start.saturating_add_signed(delta);
+        let header_start = |start: usize, len: usize| if len == 0 { start - 1 } else { start };
+        let _ = writeln!(
+            out,
+            "@@ -{},{old_len} +{},{new_len} @@",
+            header_start(old_start, old_len),
+            header_start(new_start, new_len),
+        );
+        out.push_str(&body);
+        delta += new_len as isize - old_len as isize;
+    }
+    Ok(out)
+}
+
+/// Write the lines of `text` with a prefix, and return their number.
+fn push_lines(out: &mut String, prefix: char, text: &str) -> usize {
+    let mut count = 0;
+    for line in text.split_inclusive('\n') {
+        out.push(prefix);
+        out.push_str(line);
+        if !line.ends_with('\n') {
+            out.push_str("\n\\ No newline at end of file\n");
+        }
+        count += 1;
+    }
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::monotonic::{monotonic_changes, to_monotonic};
+
+    #[test]
+    fn test_check_changes() {
+        let text = "καὶ τὸ";
+        let mut changes = monotonic_changes(text);
+        assert_eq!(apply_changes(text, &changes).unwrap(), to_monotonic(text));
+        assert_eq!(
+            apply_changes("καὶ το", &changes),
+            Err(PatchError::Mismatch { index: 1 })
+        );
+        changes.reverse();
+        assert_eq!(
+            apply_changes(text, &changes),
+            Err(PatchError::Overlap { index: 1 })
+        );
+        assert_eq!(
+            apply_changes("", &changes),
+            Err(PatchError::Mismatch { index: 0 })
+        );
+    }
+
+    #[test]
+    fn test_invert_changes() {
+        let dump = include_str!("../tests/fixtures/dump.txt");
+        let changes = monotonic_changes(dump);
+        let output = apply_changes(dump, &changes).unwrap();
+        let inverse = invert_changes(&changes);
+        assert_eq!(apply_changes(&output, &inverse).unwrap(), dump);
+        // Revert every other change
+        let kept: Vec<_> = changes.iter().step_by(2).cloned().collect();
+        let reverted: Vec<_> = inverse.iter().skip(1).step_by(2).cloned().collect();
+        assert_eq!(
+            apply_changes(&output, &reverted).unwrap(),
+            apply_changes(dump, &kept).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_unified_diff() {
+        let text = "α\nβ\nγ\nδ\nε\nζ\nη\nθ\nι\nκαὶ\nλ\nμ";
+        let diff = unified_diff(text, &monotonic_changes(text), "a.txt").unwrap();
+        assert_eq!(
+            diff,
+            "--- a/a.txt\n+++ b/a.txt\n@@ -7,6 +7,6 @@\n η\n θ\n ι\n-καὶ\n+και\n λ\n μ\n\\ No newline at end of file\n"
+        );
+
+        // Hunks closer than twice the context are merged
+        let text = "τὸ\nα\nβ\nγ\nδ\nε\nζ\nτὸ\n";
+        let diff = unified_diff(text, &monotonic_changes(text), "a.txt").unwrap();
+        assert_eq!(diff.matches("@@").count(), 2);
+        assert!(diff.contains("@@ -1,8 +1,8 @@\n-τὸ\n+το\n"));
+        let text = format!("τὸ{}\nτὸ", "\nα".repeat(7));
+        let diff = unified_diff(&text, &monotonic_changes(&text), "a.txt").unwrap();
+        assert_eq!(diff.matches("@@").count(), 4);
+        assert!(diff.contains("@@ -6,4 +6,4 @@\n α\n α\n α\n-τὸ\n"));
+
+        // A change that removes a line
+        let changes = [Change {
+            span: 3..6,
+            old: "β\n".to_string(),
+            new: String::new(),
+        }];
+        let diff = unified_diff("α\nβ\nγ\n", &changes, "a.txt").unwrap();
+        assert!(diff.ends_with("@@ -1,3 +1,2 @@\n α\n-β\n γ\n"));
+
+        assert_eq!(
+            unified_diff("καὶ", &[], "a.txt").unwrap(),
+            "--- a/a.txt\n+++ b/a.txt\n"
+        );
+    }
+}
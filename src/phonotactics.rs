@@ -0,0 +1,287 @@
+//! Phonotactic constraints of modern Greek.
+
+use std::ops::{Range, RangeInclusive};
+
+use crate::accents::{Diacritic, add_diacritic_at_syllables};
+use crate::chars::{graphemes_el, is_combining_mark, is_greek_letter};
+use crate::syllabify::{CONS_CLUSTERS_EL, is_consonant_cluster, is_vowel, syllabify};
+
+/// Check if every consonant cluster of the word can be pronounced.
+///
+/// Cf. [`invalid_clusters`] for the rules. Words with non Greek letters, or
+/// without vowels, are not valid.
+///
+/// # Example
+///
+/// ```
+/// use grac::is_phonotactically_valid;
+///
+/// assert!(is_phonotactically_valid("άνθρωπος"));
+/// assert!(is_phonotactically_valid("στρατός"));
+/// assert!(!is_phonotactically_valid("ρτσάλι"));
+/// assert!(!is_phonotactically_valid("κμπ"));
+/// ```
+pub fn is_phonotactically_valid(word: impl AsRef<str>) -> bool {
+    let word = word.as_ref();
+    !word.is_empty()
+        && word.chars().all(is_greek_letter_or_mark)
+        && word.chars().any(is_vowel)
+        && invalid_clusters(word).is_empty()
+}
+
+/// Consonant clusters of the word that can not be pronounced.
+///
+/// A cluster is valid if:
+/// * at the start of the word, it is a valid syllable onset: every pair of
+///   consecutive consonants is one of the clusters used by [`syllabify`](crate::syllabify) (στρ).
+/// * between vowels, it is a valid onset after at most one consonant (ν-θρ).
+/// * at the end of the word, it is ν or ς, as in native words (so that
+///   loanwords like μπαρ are not valid).
+///
+/// # Example
+///
+/// ```
+/// use grac::invalid_clusters;
+///
+/// assert_eq!(invalid_clusters("ρτσάλι"), ["ρτσ"]);
+/// assert_eq!(invalid_clusters("καλημέρα"), [] as [&str; 0]);
+/// assert_eq!(invalid_clusters("άρμτλοκ"), ["ρμτλ", "κ"]);
+/// ```
+pub fn invalid_clusters(word: &str) -> Vec<&str> {
+    consonant_runs(word)
+        .into_iter()
+        .filter(|(run, bases)| !is_valid_run(word, run, bases))
+        .map(|(run, _)| &word[run])
+        .collect()
+}
+
+const fn is_greek_letter_or_mark(ch: char) -> bool {
+    is_greek_letter(ch) || is_combining_mark(ch)
+}
+
+/// Maximal sequences of consonants, with their base chars.
+fn consonant_runs(word: &str) -> Vec<(Range<usize>, Vec<char>)> {
+    let mut runs: Vec<(Range<usize>, Vec<char>)> = Vec::new();
+    let mut start = 0;
+    let mut in_run = false;
+    for cluster in graphemes_el(word) {
+        let end = start + cluster.len();
+        let base = cluster.chars().next().unwrap_or_default();
+        if is_greek_letter(base) && !is_vowel(base) {
+            match runs.last_mut() {
+                Some((run, bases)) if in_run => {
+                    run.end = end;
+                    bases.push(base);
+                }
+                _ => runs.push((start..end, vec![base])),
+            }
+            in_run = true;
+        } else {
+            in_run = false;
+        }
+        start = end;
+    }
+    runs
+}
+
+fn is_valid_run(word: &str, run: &Range<usize>, bases: &[char]) -> bool {
+    let initial = !word[..run.start].chars().any(is_vowel);
+    let last = !word[run.end..].chars().any(is_vowel);
+    match (initial, last) {
+        // No vowel at all
+        (true, true) => false,
+        (true, false) => is_onset(bases),
+        (false, true) => matches!(bases, [] | ['ν' | 'ς' | 'σ' | 'Ν' | 'Σ']),
+        (false, false) => is_onset(bases) || is_onset(&bases[1..]),
+    }
+}
+
+fn is_onset(bases: &[char]) -> bool {
+    bases
+        .windows(2)
+        .all(|pair| is_consonant_cluster(pair[0], pair[1]))
+}
+
+/// Bounds on the number of syllables of the words of [`generate_pseudowords`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LengthRange {
+    pub min: usize,
+    pub max: usize,
+}
+
+impl LengthRange {
+    pub const fn new(min: usize, max: usize) -> Self {
+        Self { min, max }
+    }
+}
+
+impl From<RangeInclusive<usize>> for LengthRange {
+    fn from(range: RangeInclusive<usize>) -> Self {
+        Self::new(*range.start(), *range.end())
+    }
+}
+
+const CONSONANTS: [char; 17] = [
+    'β', 'γ', 'δ', 'ζ', 'θ', 'κ', 'λ', 'μ', 'ν', 'ξ', 'π', 'ρ', 'σ', 'τ', 'φ', 'χ', 'ψ',
+];
+
+const NUCLEI: [&str; 13] = [
+    "α", "ε", "η", "ι", "ο", "υ", "ω", "αι", "ει", "οι", "ου", "αυ", "ευ",
+];
+
+/// Consonants that can close a syllable before another consonant (άν-θρω-πος).
+const CODAS: [char; 4] = ['ν', 'ρ', 'λ', 'σ'];
+
+/// Generate `n` pronounceable words, that are usually not real words.
+///
+/// Words are sequences of syllables made of an onset (a consonant, or one of
+/// the consonant clusters of [`syllabify`]), a vowel or a digraph (ου, αι...),
+/// and sometimes a ν, ρ, λ or σ before the next onset. They end in a vowel, ν
+/// or ς, and are accented on one of the last three syllables, unless they are
+/// monosyllables. Every word passes [`is_phonotactically_valid`] and has
+/// a number of syllables in `lengths`.
+///
+/// `rng` is any source of random numbers, like a closure over
+/// `rand::RngCore::next_u64`, so that the output can be reproduced from a seed.
+///
+/// # Example
+///
+/// ```
+/// use grac::{generate_pseudowords, is_phonotactically_valid, syllabify};
+///
+/// // A xorshift generator
+/// let mut state = 0x2545_f491_4f6c_dd1d_u64;
+/// let rng = move || {
+///     state ^= state << 13;
+///     state ^= state >> 7;
+///     state ^= state << 17;
+///     state
+/// };
+///
+/// let words = generate_pseudowords(10, 2..=4, rng);
+/// assert_eq!(words.len(), 10);
+/// for word in &words {
+///     assert!(is_phonotactically_valid(word));
+///     assert!((2..=4).contains(&syllabify(word).len()));
+/// }
+/// ```
+pub fn generate_pseudowords(
+    n: usize,
+    lengths: impl Into<LengthRange>,
+    mut rng: impl FnMut() -> u64,
+) -> Vec<String> {
+    let LengthRange { min, max } = lengths.into();
+    let min = min.max(1);
+    let max = max.max(min);
+    let mut words = Vec::with_capacity(n);
+    while words.len() < n {
+        let n_syllables = min + pick(&mut rng, max - min + 1);
+        let word = generate_pseudoword(n_syllables, &mut rng);
+        // Clusters across syllables may still merge into invalid runs (ρ-σπ)
+        if is_phonotactically_valid(&word) && syllabify(&word).len() == n_syllables {
+            words.push(word);
+        }
+    }
+    words
+}
+
+fn generate_pseudoword(n_syllables: usize, rng: &mut impl FnMut() -> u64) -> String {
+    let mut syllables: Vec<String> = Vec::with_capacity(n_syllables);
+    for idx in 0..n_syllables {
+        let mut syllable = String::new();
+        // Only the first syllable may start with a vowel, to avoid hiatus.
+        match pick(rng, if idx == 0 { 4 } else { 3 }) {
+            0 => {
+                let (a, b) = CONS_CLUSTERS_EL[pick(rng, CONS_CLUSTERS_EL.len())];
+                syllable.push(a);
+                syllable.push(b);
+            }
+            3 => (),
+            _ => syllable.push(CONSONANTS[pick(rng, CONSONANTS.len())]),
+        }
+        syllable.push_str(NUCLEI[pick(rng, NUCLEI.len())]);
+        let last = idx + 1 == n_syllables;
+        match pick(rng, 4) {
+            0 if last => syllable.push('ς'),
+            1 if last => syllable.push('ν'),
+            0 if !last => syllable.push(CODAS[pick(rng, CODAS.len())]),
+            _ => (),
+        }
+        syllables.push(syllable);
+    }
+
+    let syllables: Vec<&str> = syllables.iter().map(String::as_str).collect();
+    let stress = if n_syllables == 1 {
+        0
+    } else {
+        1 + pick(rng, n_syllables.min(3))
+    };
+    add_diacritic_at_syllables(&syllables, stress, Diacritic::ACUTE)
+}
+
+/// A random number in `0..bound`.
+fn pick(rng: &mut impl FnMut() -> u64, bound: usize) -> usize {
+    (rng() % bound as u64) as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_phonotactically_valid() {
+        let valid = [
+            "άνθρωπος",
+            "σπλήνα",
+            "εκστρατεία",
+            "αλλά",
+            "Μπαμπάς",
+            "ἄνθρωπος",
+            "ζῷον",
+            "γκρεμός",
+            "σμπαράλια",
+        ];
+        for word in valid {
+            assert!(is_phonotactically_valid(word), "{word}");
+        }
+        let invalid = ["", "ξλα", "καλημέρατ", "ρρρ", "λόγος!", "λογοςκ"];
+        for word in invalid {
+            assert!(!is_phonotactically_valid(word), "{word}");
+        }
+    }
+
+    #[test]
+    fn test_invalid_clusters_decomposed() {
+        let word = "ρτσα\u{301}λι";
+        assert_eq!(invalid_clusters(word), ["ρτσ"]);
+        assert_eq!(invalid_clusters("ε\u{301}ξπλα"), [] as [&str; 0]);
+    }
+
+    #[test]
+    fn test_generate_pseudowords() {
+        let mut state = 1_u64;
+        let mut rng = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+        assert!(generate_pseudowords(0, 1..=3, &mut rng).is_empty());
+        for word in generate_pseudowords(200, 1..=1, &mut rng) {
+            assert_eq!(syllabify(&word).len(), 1, "{word}");
+            assert!(!word.chars().any(|ch| "άέήίόύώ".contains(ch)), "{word}");
+        }
+        for word in generate_pseudowords(200, LengthRange::new(3, 5), &mut rng) {
+            let syllables = syllabify(&word);
+            assert!((3..=5).contains(&syllables.len()), "{word}");
+            assert!(is_phonotactically_valid(&word), "{word}");
+            // A single accent, on one of the last three syllables
+            let is_accented = |syllable: &str| syllable.chars().any(|ch| "άέήίόύώ".contains(ch));
+            let accented: Vec<usize> = (0..syllables.len())
+                .filter(|idx| is_accented(syllables[*idx]))
+                .collect();
+            assert_eq!(accented.len(), 1, "{word}");
+            assert!(accented[0] + 3 >= syllables.len(), "{word}");
+        }
+    }
+}
@@ -0,0 +1,255 @@
+//! Chains of conversions that keep track of where every change came from.
+
+use std::ops::Range;
+
+use crate::span::{Change, apply_changes};
+
+/// A pass of a [`Pipeline`]: the changes it makes to its input.
+type Pass<'a> = Box<dyn Fn(&str) -> Vec<Change> + 'a>;
+
+/// A sequence of conversions, run one after the other, whose offsets can be
+/// mapped back to the original text.
+///
+/// Passes either return the changes they make, like [`monotonic_changes`](crate::monotonic_changes),
+/// or the converted text, which is then compared to their input word by word.
+///
+/// # Example
+///
+/// ```
+/// use grac::{Pipeline, QuoteStyle, monotonic_changes, normalize_quotes};
+///
+/// let pipeline = Pipeline::new()
+///     .then(|text| normalize_quotes(text, QuoteStyle::Greek))
+///     .then_changes(|text| monotonic_changes(text));
+///
+/// let text = "Εἶπε \"καλημέρα\" στὸν Γιάννη.";
+/// let result = pipeline.run(text);
+/// assert_eq!(result.output, "Είπε «καλημέρα» στον Γιάννη.");
+///
+/// // The output span of «καλημέρα» comes from "καλημέρα"
+/// let start = result.output.find('«').unwrap();
+/// let span = result.span_to_input(start..start + "«καλημέρα»".len());
+/// assert_eq!(&text[span], "\"καλημέρα\"");
+/// ```
+#[derive(Default)]
+pub struct Pipeline<'a> {
+    passes: Vec<Pass<'a>>,
+}
+
+impl<'a> Pipeline<'a> {
+    pub fn new() -> Self {
+        Self { passes: Vec::new() }
+    }
+
+    /// Add a pass that returns the converted text.
+    ///
+    /// Its changes are found by comparing its input and output word by word,
+    /// without their common prefix and suffix, which is exact as long as it
+    /// keeps the whitespace as it is (as every conversion of grac does).
+    /// Otherwise, the whole text that differs is a single change.
+    #[must_use]
+    pub fn then(mut self, pass: impl Fn(&str) -> String + 'a) -> Self {
+        self.passes
+            .push(Box::new(move |text| diff_words(text, &pass(text))));
+        self
+    }
+
+    /// Add a pass that returns its changes, sorted and without overlaps.
+    #[must_use]
+    pub fn then_changes(mut self, pass: impl Fn(&str) -> Vec<Change> + 'a) -> Self {
+        self.passes.push(Box::new(pass));
+        self
+    }
+
+    /// Number of passes.
+    pub fn len(&self) -> usize {
+        self.passes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.passes.is_empty()
+    }
+
+    /// Run every pass over the text, in order.
+    pub fn run(&self, text: &str) -> PipelineOutput {
+        let mut output = text.to_string();
+        let mut passes = Vec::with_capacity(self.passes.len());
+        for pass in &self.passes {
+            let changes = pass(&output);
+            output = apply_changes(&output, &changes);
+            passes.push(changes);
+        }
+        PipelineOutput { output, passes }
+    }
+}
+
+/// Result of [`Pipeline::run`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PipelineOutput {
+    pub output: String,
+    /// The changes of every pass, with spans in the text given to that pass.
+    pub passes: Vec<Vec<Change>>,
+}
+
+impl PipelineOutput {
+    /// Byte offset in the input of a byte offset in the output.
+    ///
+    /// Offsets inside a change are rounded down to its start.
+    pub fn to_input(&self, offset: usize) -> usize {
+        self.passes
+            .iter()
+            .rev()
+            .fold(offset, |offset, changes| map_back(changes, offset, false))
+    }
+
+    /// Span of the input that became a span of the output.
+    ///
+    /// The span grows to cover the changes that it overlaps.
+    pub fn span_to_input(&self, span: Range<usize>) -> Range<usize> {
+        let end = self
+            .passes
+            .iter()
+            .rev()
+            .fold(span.end, |offset, changes| map_back(changes, offset, true));
+        self.to_input(span.start)..end
+    }
+}
+
+/// Map an offset of the text after `changes` to the text before them.
+///
+/// Offsets inside a change go to its end when `round_up` is set, and to its
+/// start otherwise.
+fn map_back(changes: &[Change], offset: usize, round_up: bool) -> usize {
+    // Output length minus input length of the changes so far.
+    let mut delta = 0;
+    for change in changes {
+        let start = change.span.start.saturating_add_signed(delta);
+        if offset <= start {
+            break;
+        }
+        let end = start + change.new.len();
+        if offset < end {
+            return if round_up {
+                change.span.end
+            } else {
+                change.span.start
+            };
+        }
+        delta += change.new.len() as isize - change.span.len() as isize;
+    }
+    offset.saturating_add_signed(-delta)
+}
+
+/// Changes between two versions of a text, word by word.
+fn diff_words(old: &str, new: &str) -> Vec<Change> {
+    let is_whitespace = char::is_whitespace;
+    if old.split(is_whitespace).count() != new.split(is_whitespace).count() {
+        return trimmed_change(old, new, 0).into_iter().collect();
+    }
+    let mut changes = Vec::new();
+    let mut offset = 0;
+    for (old_word, new_word) in old
+        .split_inclusive(is_whitespace)
+        .zip(new.split_inclusive(is_whitespace))
+    {
+        changes.extend(trimmed_change(old_word, new_word, offset));
+        offset += old_word.len();
+    }
+    changes
+}
+
+/// The change from `old` to `new`, without their common prefix and suffix,
+/// if they differ.
+fn trimmed_change(old: &str, new: &str, offset: usize) -> Option<Change> {
+    if old == new {
+        return None;
+    }
+    let prefix = common_len(old.chars().zip(new.chars()));
+    let (old, new) = (&old[prefix..], &new[prefix..]);
+    let suffix = common_len(old.chars().rev().zip(new.chars().rev()));
+    let (old, new) = (&old[..old.len() - suffix], &new[..new.len() - suffix]);
+    let start = offset + prefix;
+    Some(Change {
+        span: start..start + old.len(),
+        old: old.to_string(),
+        new: new.to_string(),
+    })
+}
+
+/// Length in bytes of the leading pairs of equal chars.
+fn common_len(pairs: impl Iterator<Item = (char, char)>) -> usize {
+    pairs
+        .take_while(|(a, b)| a == b)
+        .map(|(a, _)| a.len_utf8())
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::monotonic::{monotonic_changes, to_monotonic};
+    use crate::polytonic::apply_nu_ephelkystikon;
+    use crate::punctuation::{QuoteStyle, normalize_quotes};
+
+    #[test]
+    fn test_diff_words() {
+        let old = "καὶ τὸ  σπίτι\n";
+        let changes = diff_words(old, &to_monotonic(old));
+        let spans: Vec<_> = changes.iter().map(|change| change.span.clone()).collect();
+        assert_eq!(spans, [4..7, 10..13]);
+        assert_eq!(apply_changes(old, &changes), to_monotonic(old));
+
+        let changes = diff_words("α β γ", "α βγ");
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].span, 5..6);
+        assert!(diff_words("αβ", "αβ").is_empty());
+    }
+
+    #[test]
+    fn test_pipeline() {
+        let pipeline = Pipeline::new()
+            .then(|text| normalize_quotes(text, QuoteStyle::Greek))
+            // Latin o for omicron
+            .then(|text| text.replace("ἀνθρώπoις", "ἀνθρώποις"))
+            .then(|text| apply_nu_ephelkystikon(text))
+            .then_changes(|text| monotonic_changes(text));
+        assert_eq!(pipeline.len(), 4);
+
+        let text = "\"πᾶσι ἀνθρώπoις\" λέγουσιν τοῦτο.";
+        let result = pipeline.run(text);
+        assert_eq!(result.output, "«πάσιν ανθρώποις» λέγουσι τούτο.");
+        assert_eq!(
+            result.passes.iter().map(Vec::len).collect::<Vec<_>>(),
+            [2, 1, 2, 3]
+        );
+
+        let find = |word: &str| {
+            let start = result.output.find(word).unwrap();
+            result.span_to_input(start..start + word.len())
+        };
+        assert_eq!(&text[find("πάσιν")], "πᾶσι");
+        assert_eq!(&text[find("ανθρώποις»")], "ἀνθρώπoις\"");
+        assert_eq!(&text[find("λέγουσι ")], "λέγουσιν ");
+        assert_eq!(&text[find("τούτο.")], "τοῦτο.");
+        assert_eq!(result.to_input(result.output.len()), text.len());
+        assert_eq!(result.to_input(0), 0);
+
+        assert_eq!(Pipeline::new().run(text).output, text);
+    }
+
+    #[test]
+    fn test_map_back() {
+        // "αβγ" → "αδδγ" → "γ"
+        let changes = [Change {
+            span: 2..4,
+            old: "β".to_string(),
+            new: "δδ".to_string(),
+        }];
+        assert_eq!(map_back(&changes, 2, true), 2);
+        assert_eq!(map_back(&changes, 4, false), 2);
+        assert_eq!(map_back(&changes, 4, true), 4);
+        assert_eq!(map_back(&changes, 6, false), 4);
+        assert_eq!(map_back(&changes, 8, false), 6);
+    }
+}
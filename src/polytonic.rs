@@ -0,0 +1,484 @@
+//! Polytonic accentuation.
+//!
+//! Checks the placement of breathings, accents and iota subscripts against the
+//! rules of classical Greek, and adjusts running text to the words around:
+//! graves for final acutes, and movable ν.
+
+use unicode_normalization::UnicodeNormalization;
+
+use crate::accents::{Diacritic, has_diacritic, replace_diacritic_into};
+use crate::chars::{base_lower, base_lower_str, graphemes_el, is_greek_char, is_greek_word};
+use crate::crasis::{coronis_idx, expand_crasis};
+use crate::monotonic::split_punctuation;
+use crate::syllabify::{is_diphthong, is_vowel, syllabify};
+use crate::tokenize::{Token, TokenKind, for_each_word_with_context, tokenize};
+
+/// Unaccented enclitics, before which a final acute stays: καλός τις.
+#[rustfmt::skip]
+const ENCLITICS: [&str; 38] = [
+    "μου", "μοι", "με", "σου", "σοι", "σε",
+    "τις", "τι", "του", "τῳ", "τινος", "τινι", "τινα", "τινες", "τινων", "τισι", "τισιν", "τινας",
+    "που", "πῃ", "ποι", "ποθεν", "ποτε", "πω", "πως",
+    "γε", "τε", "τοι", "περ",
+    "εἰμι", "ἐστι", "ἐστιν", "ἐσμεν", "εἰσι", "εἰσιν",
+    "φημι", "φησι", "φησιν",
+];
+
+fn is_enclitic(word: &str) -> bool {
+    let word: String = word.nfc().collect::<String>().to_lowercase();
+    ENCLITICS.contains(&word.as_str())
+}
+
+/// The interrogative τίς and τί always keep their acute.
+fn is_interrogative(word: &str) -> bool {
+    let word: String = word.nfc().collect::<String>().to_lowercase();
+    matches!(word.as_str(), "τίς" | "τί")
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PolytonicErrorKind {
+    /// Breathings go on the initial vowel or ρ, or on the second vowel of an
+    /// initial diphthong: ἀι, λἰθος
+    ///
+    /// The coronis of a known crasis (cf. [`expand_crasis`](crate::expand_crasis)),
+    /// after the first consonant, is not a breathing: κἀγώ, τοὔνομα
+    MisplacedBreathing,
+    /// The circumflex only goes on long vowels, never on ε or ο: λο͂γος
+    CircumflexOnShortVowel,
+    /// The circumflex only goes on the last two syllables: ἆνθρωπος
+    CircumflexTooEarly,
+    /// The acute only goes on the last three syllables: ἄνθρωποισιν
+    AccentTooEarly,
+    /// The iota subscript only goes under α, η and ω: λόγοͅ
+    MisplacedIotaSubscript,
+}
+
+impl PolytonicErrorKind {
+    pub const fn message(self) -> &'static str {
+        match self {
+            Self::MisplacedBreathing => "breathing not on the initial vowel or rho",
+            Self::CircumflexOnShortVowel => "circumflex on a short vowel",
+            Self::CircumflexTooEarly => "circumflex before the penult",
+            Self::AccentTooEarly => "acute before the antepenult",
+            Self::MisplacedIotaSubscript => "iota subscript not under alpha, eta or omega",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct PolytonicError {
+    pub kind: PolytonicErrorKind,
+    /// Index of the character with the misplaced diacritic.
+    ///
+    /// The index counts user-perceived characters (cf. [`graphemes_el`]) and
+    /// starts at zero, as in [`remove_diacritic_at_char`](crate::remove_diacritic_at_char).
+    pub idx: usize,
+}
+
+/// Base lowercase letter of a character, with its combining marks.
+pub(crate) fn base_of(cluster: &str) -> char {
+    cluster.nfc().next().map_or(' ', base_lower)
+}
+
+/// Index of the character that takes the breathing of a word starting with a
+/// vowel: the first one, or the second vowel of an initial diphthong (αἰ).
+pub(crate) fn breathing_idx(word: &str) -> usize {
+    let mut clusters = graphemes_el(word);
+    match (clusters.next(), clusters.next()) {
+        // base_of drops the diaeresis that breaks the diphthong: ἀϋτή
+        (Some(a), Some(b)) => usize::from(
+            is_diphthong(base_of(a), base_of(b)) && !has_diacritic(b, Diacritic::DIAERESIS),
+        ),
+        _ => 0,
+    }
+}
+
+/// Check the diacritics of a polytonic word against the rules of classical Greek.
+///
+/// Punctuation around the word is ignored. Errors are sorted by index, and the
+/// syllable positions are those of [`syllabify`].
+///
+/// # Example
+///
+/// ```
+/// use grac::{PolytonicErrorKind, validate_polytonic};
+///
+/// assert!(validate_polytonic("ἄνθρωπος").is_empty());
+/// assert!(validate_polytonic("Αἰσχύλου,").is_empty());
+///
+/// let errors = validate_polytonic("ἆνθρωπος");
+/// assert_eq!(errors.len(), 1);
+/// assert_eq!(errors[0].kind, PolytonicErrorKind::CircumflexTooEarly);
+/// assert_eq!(errors[0].idx, 0);
+///
+/// let kinds: Vec<_> = validate_polytonic("λὁγιͅ").iter().map(|error| error.kind).collect();
+/// assert_eq!(
+///     kinds,
+///     [PolytonicErrorKind::MisplacedBreathing, PolytonicErrorKind::MisplacedIotaSubscript]
+/// );
+/// ```
+pub fn validate_polytonic(word: impl AsRef<str>) -> Vec<PolytonicError> {
+    let (left, core, _) = split_punctuation(word.as_ref());
+    let offset = graphemes_el(left).count();
+    let initial = breathing_idx(core);
+    // Only known crases, or any breathing after a consonant would pass: πἀτήρ
+    let coronis = if expand_crasis(core).is_empty() {
+        None
+    } else {
+        coronis_idx(core)
+    };
+
+    let syllables = syllabify(core);
+    let mut errors = Vec::new();
+    let mut idx = 0;
+    for (i, syllable) in syllables.iter().enumerate() {
+        let pos = syllables.len() - i;
+        for cluster in graphemes_el(syllable) {
+            let base = base_of(cluster);
+            let mut push = |kind| {
+                errors.push(PolytonicError {
+                    kind,
+                    idx: offset + idx,
+                });
+            };
+            if has_diacritic(cluster, Diacritic::SMOOTH) || has_diacritic(cluster, Diacritic::ROUGH)
+            {
+                let is_initial = idx == initial && (is_vowel(base) || base == 'ρ');
+                let is_coronis = Some(idx) == coronis
+                    && is_vowel(base)
+                    && has_diacritic(cluster, Diacritic::SMOOTH);
+                if !is_initial && !is_coronis {
+                    push(PolytonicErrorKind::MisplacedBreathing);
+                }
+            }
+            if has_diacritic(cluster, Diacritic::CIRCUMFLEX) {
+                if matches!(base, 'ε' | 'ο') {
+                    push(PolytonicErrorKind::CircumflexOnShortVowel);
+                }
+                if pos > 2 {
+                    push(PolytonicErrorKind::CircumflexTooEarly);
+                }
+            }
+            if has_diacritic(cluster, Diacritic::ACUTE) && pos > 3 {
+                push(PolytonicErrorKind::AccentTooEarly);
+            }
+            if has_diacritic(cluster, Diacritic::IOTA_SUBSCRIPT) && !matches!(base, 'α' | 'η' | 'ω')
+            {
+                push(PolytonicErrorKind::MisplacedIotaSubscript);
+            }
+            idx += 1;
+        }
+    }
+    errors
+}
+
+/// Turn final acutes into graves when another word follows (barytonesis).
+///
+/// In polytonic text, an acute on the last syllable of a word becomes a grave
+/// if the word is followed by another one without punctuation in between. It
+/// stays before enclitics (καλός τις), on the interrogative τίς and τί, and
+/// at the end of the text. Other acutes, and words that are not Greek (cf.
+/// [`is_greek_word`]), are left as they are.
+///
+/// Changed characters are returned in NFC.
+///
+/// # Example
+///
+/// ```
+/// use grac::apply_barytonesis;
+///
+/// assert_eq!(
+///     apply_barytonesis("ὁ ἀγαθός ἀνήρ, ὁ καλός."),
+///     "ὁ ἀγαθὸς ἀνήρ, ὁ καλός."
+/// );
+/// assert_eq!(apply_barytonesis("ἀνθρωπός τις ἦλθεν"), "ἀνθρωπός τις ἦλθεν");
+/// ```
+pub fn apply_barytonesis(text: impl AsRef<str>) -> String {
+    let text = text.as_ref();
+    for_each_word_with_context(text, 1, |_, word, next| {
+        let next = next.first()?;
+        let gap = &text[word.span.end..next.span.start];
+        if !gap.chars().all(char::is_whitespace)
+            || !is_greek_word(word.text)
+            || is_enclitic(next.text)
+            || is_interrogative(word.text)
+        {
+            return None;
+        }
+        let syllables = syllabify(word.text);
+        let last = syllables.last().map_or("", |syllable| *syllable);
+        let mut out = word.text[..word.text.len() - last.len()].to_string();
+        replace_diacritic_into(last, Diacritic::ACUTE, Diacritic::GRAVE, &mut out);
+        Some(out)
+    })
+}
+
+/// Endings of the words that take a movable ν: dative plurals and verbs.
+///
+/// Not any -σι, that is also the end of adverbs like ἔτσι.
+#[rustfmt::skip]
+const MOVABLE_NU_ENDINGS: [&str; 10] = [
+    "οισι", "αισι", "ουσι", "ασι", "εσι", "ησι", "ωσι", "υσι", "ξι", "ψι",
+];
+
+/// The movable letter that a word can take, and whether it has it.
+fn movable_letter(word: &str) -> Option<(char, bool)> {
+    let base = base_lower_str(word);
+    // Capitals lowercase to σ: ΟΥΤΩΣ
+    if matches!(base.as_str(), "ουτω" | "ουτως" | "ουτωσ") {
+        return Some(('ς', base != "ουτω"));
+    }
+    if MOVABLE_NU_ENDINGS.iter().any(|end| base.ends_with(end))
+        || matches!(base.as_str(), "εστι" | "εισι")
+    {
+        return Some(('ν', false));
+    }
+    // Not every -σιν: φύσιν and τάξιν are accusatives.
+    if base.ends_with("ουσιν") || matches!(base.as_str(), "εστιν" | "εισιν" | "φησιν")
+    {
+        return Some(('ν', true));
+    }
+    None
+}
+
+/// Add or remove the movable ν (nu ephelkystikon) depending on the next word.
+///
+/// Dative plurals and verbs in -σι after a vowel (-οισι, -ασι, -ουσι...),
+/// words in -ξι and -ψι, ἐστί and εἰσί take a ν before a vowel, before
+/// punctuation and at the end of the text. Before a consonant, it is removed
+/// from the words where it can only be movable: verbs in -ουσιν, ἐστίν, εἰσίν
+/// and φησίν, since φύσιν or τάξιν are accusatives. The movable ς of οὕτω(ς)
+/// follows the same rule.
+///
+/// Verbs in -ε(ν) (ἔλυσε) are left as they are, since they can not be told
+/// apart from vocatives (ἄνθρωπε) and other words in -εν.
+///
+/// # Example
+///
+/// ```
+/// use grac::apply_nu_ephelkystikon;
+///
+/// assert_eq!(
+///     apply_nu_ephelkystikon("πᾶσι ἀνθρώποις λέγουσιν τοῦτο, ὡς ἐστί."),
+///     "πᾶσιν ἀνθρώποις λέγουσι τοῦτο, ὡς ἐστίν."
+/// );
+/// assert_eq!(apply_nu_ephelkystikon("οὕτως ποιεῖ"), "οὕτω ποιεῖ");
+/// ```
+pub fn apply_nu_ephelkystikon(text: impl AsRef<str>) -> String {
+    let text = text.as_ref();
+    let tokens: Vec<Token> = tokenize(text).collect();
+    let mut out = String::with_capacity(text.len());
+    for (idx, token) in tokens.iter().enumerate() {
+        let word = token.text;
+        let movable = if token.kind == TokenKind::Word {
+            movable_letter(word)
+        } else {
+            None
+        };
+        let Some((letter, has_letter)) = movable else {
+            out.push_str(word);
+            continue;
+        };
+        let wants_letter = match tokens.get(idx + 1) {
+            Some(next) if next.kind == TokenKind::Whitespace => tokens[idx + 1..]
+                .iter()
+                .find(|token| token.kind == TokenKind::Word)
+                .and_then(|next| next.text.chars().next())
+                .is_none_or(|ch| is_vowel(base_lower(ch))),
+            // Before punctuation, or at the end of the text.
+            _ => true,
+        };
+        match (wants_letter, has_letter) {
+            (true, false) => {
+                out.push_str(word);
+                if word.ends_with(char::is_uppercase) {
+                    out.extend(letter.to_uppercase());
+                } else {
+                    out.push(letter);
+                }
+            }
+            (false, true) => {
+                let last = word.chars().next_back().map_or(0, char::len_utf8);
+                out.push_str(&word[..word.len() - last]);
+            }
+            _ => out.push_str(word),
+        }
+    }
+    out
+}
+
+/// Turn every grave into an acute, reversing [`apply_barytonesis`].
+///
+/// Only Greek letters are changed (cf. [`is_greek_char`]), so that the graves
+/// of other scripts stay: très. Changed characters are returned in NFC.
+///
+/// # Example
+///
+/// ```
+/// use grac::lift_graves;
+///
+/// assert_eq!(lift_graves("ὁ ἀγαθὸς ἀνήρ"), "ὁ ἀγαθός ἀνήρ");
+/// ```
+pub fn lift_graves(text: impl AsRef<str>) -> String {
+    let text = text.as_ref();
+    let mut out = String::with_capacity(text.len());
+    for cluster in graphemes_el(text) {
+        if cluster.chars().next().is_some_and(is_greek_char) {
+            replace_diacritic_into(cluster, Diacritic::GRAVE, Diacritic::ACUTE, &mut out);
+        } else {
+            out.push_str(cluster);
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(word: &str) -> Vec<PolytonicErrorKind> {
+        validate_polytonic(word)
+            .iter()
+            .map(|error| error.kind)
+            .collect()
+    }
+
+    #[test]
+    fn test_valid_words() {
+        for word in [
+            "ἄνθρωπος",
+            "ἀνθρώπου",
+            "οἶκος",
+            "Αἰσχύλου",
+            "εὐχή",
+            "ῥήτωρ",
+            "ἀϋτή",
+            "τῇ",
+            "ᾠδῇ",
+            "ᾍδης",
+            "ἐστὶ",
+            "«θεῶν»",
+            "κἀγώ",
+            "τοὔνομα",
+            "",
+        ] {
+            assert_eq!(kinds(word), [], "{word}");
+            let decomposed: String = word.nfd().collect();
+            assert_eq!(kinds(&decomposed), [], "{word}");
+        }
+    }
+
+    #[test]
+    fn test_misplaced_breathing() {
+        use PolytonicErrorKind::MisplacedBreathing;
+        assert_eq!(kinds("ἀι"), [MisplacedBreathing]);
+        assert_eq!(kinds("λἰθος"), [MisplacedBreathing]);
+        assert_eq!(kinds("πἀτήρ"), [MisplacedBreathing]);
+        assert_eq!(kinds("λιθὁς"), [MisplacedBreathing]);
+        assert_eq!(kinds("λ\u{314}ίθος"), [MisplacedBreathing]);
+        assert_eq!(validate_polytonic("«λιθὁς»")[0].idx, 4);
+        // A coronis is always smooth.
+        assert_eq!(kinds("κἁγώ"), [MisplacedBreathing]);
+    }
+
+    #[test]
+    fn test_circumflex() {
+        use PolytonicErrorKind::{CircumflexOnShortVowel, CircumflexTooEarly};
+        assert_eq!(kinds("λο\u{342}γος"), [CircumflexOnShortVowel]);
+        assert_eq!(kinds("ἆνθρωπος"), [CircumflexTooEarly]);
+        assert_eq!(
+            kinds("ἀνε\u{342}θρωπος"),
+            [CircumflexOnShortVowel, CircumflexTooEarly]
+        );
+    }
+
+    #[test]
+    fn test_accent_too_early() {
+        let errors = validate_polytonic("ἀνάθρωποισι");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].kind, PolytonicErrorKind::AccentTooEarly);
+        assert_eq!(errors[0].idx, 2);
+    }
+
+    #[test]
+    fn test_apply_barytonesis() {
+        let cases = [
+            ("καλός καλός", "καλὸς καλός"),
+            ("καλός\nκαλός", "καλὸς\nκαλός"),
+            ("καλός· καλός", "καλός· καλός"),
+            ("καλός «καλός»", "καλός «καλός»"),
+            ("τί λέγεις", "τί λέγεις"),
+            ("Τίς ἐστιν", "Τίς ἐστιν"),
+            ("ἄνθρωπος καλός", "ἄνθρωπος καλός"),
+            ("καλή γε", "καλή γε"),
+            ("αὐτή ἡ", "αὐτὴ ἡ"),
+            ("café x", "café x"),
+            ("très bien", "très bien"),
+            ("café καλός", "café καλός"),
+            ("", ""),
+        ];
+        for (text, expected) in cases {
+            assert_eq!(apply_barytonesis(text), expected, "{text}");
+        }
+        // Decomposed input
+        let decomposed: String = "καλός καλός".nfd().collect();
+        let expected: String = "καλὸς καλός".nfd().collect();
+        assert_eq!(
+            apply_barytonesis(&decomposed).nfd().collect::<String>(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_lift_graves() {
+        let text = "ὁ ἀγαθὸς ἀνὴρ καλός, ἢ οὔ.";
+        assert_eq!(lift_graves(text), "ὁ ἀγαθός ἀνήρ καλός, ἤ οὔ.");
+        assert_eq!(lift_graves(apply_barytonesis(text)), lift_graves(text));
+        assert_eq!(lift_graves("ῒ"), "ΐ");
+        assert_eq!(lift_graves("très bien, καὶ"), "très bien, καί");
+        let decomposed: String = "très".nfd().collect();
+        assert_eq!(lift_graves(&decomposed), decomposed);
+    }
+
+    #[test]
+    fn test_apply_nu_ephelkystikon() {
+        let cases = [
+            ("λέγουσι ὅτι", "λέγουσιν ὅτι"),
+            ("λέγουσιν τοῦτο", "λέγουσι τοῦτο"),
+            ("λέγουσι", "λέγουσιν"),
+            ("λέγουσι· καὶ", "λέγουσιν· καὶ"),
+            ("λέγουσι «ἀεί»", "λέγουσιν «ἀεί»"),
+            ("φύλαξι ἐν", "φύλαξιν ἐν"),
+            ("ἐστὶ ῥᾴδιον", "ἐστὶ ῥᾴδιον"),
+            ("ἐστι Ἕλλην", "ἐστιν Ἕλλην"),
+            ("ΛΕΓΟΥΣΙ ΟΤΙ", "ΛΕΓΟΥΣΙΝ ΟΤΙ"),
+            ("οὕτω ἔχει", "οὕτως ἔχει"),
+            ("ΟΥΤΩΣ ΠΟΙΕΙ", "ΟΥΤΩ ΠΟΙΕΙ"),
+            // Accusatives keep their ν.
+            ("τὴν φύσιν τοῦ", "τὴν φύσιν τοῦ"),
+            ("τὴν τάξιν τοῦ", "τὴν τάξιν τοῦ"),
+            ("ἔλυσε ἐκεῖνον", "ἔλυσε ἐκεῖνον"),
+            ("πᾶσι ἀνθρώποις", "πᾶσιν ἀνθρώποις"),
+            ("παισὶ ἐν", "παισὶν ἐν"),
+            ("ἔπεσι ἐν", "ἔπεσιν ἐν"),
+            ("τίθησι αὐτό", "τίθησιν αὐτό"),
+            ("εἰσὶ ἐν", "εἰσὶν ἐν"),
+            // Not every -σι
+            ("έτσι ένα", "έτσι ένα"),
+            ("ἔτσι.", "ἔτσι."),
+            ("", ""),
+        ];
+        for (text, expected) in cases {
+            assert_eq!(apply_nu_ephelkystikon(text), expected, "{text}");
+        }
+    }
+
+    #[test]
+    fn test_misplaced_iota_subscript() {
+        use PolytonicErrorKind::MisplacedIotaSubscript;
+        assert_eq!(kinds("λόγοͅ"), [MisplacedIotaSubscript]);
+        assert_eq!(kinds("Τῌ"), []);
+    }
+}
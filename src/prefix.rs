@@ -0,0 +1,183 @@
+//! Prefixes and parts of compound words.
+
+use crate::accents::{Diacritic, has_diacritic};
+use crate::chars::graphemes_el;
+use crate::phonotactics::is_phonotactically_valid;
+use crate::polytonic::base_of;
+use crate::syllabify::{is_vowel, syllabify};
+
+/// Productive prefixes, longest first, and whether they are kept before a
+/// vowel.
+///
+/// Most prefixes that end with a vowel lose it before another one (απ-άγω,
+/// κατ-έχω), and εκ becomes εξ (εξ-άγω), so that matching them there would
+/// mostly split words that are not compounds (εκατό). Elided forms are left
+/// out for the same reason (απλός).
+#[rustfmt::skip]
+const PREFIXES: [(&str, bool); 23] = [
+    ("αμφι", false), ("αντι", false), ("κατα", false), ("μετα", false), ("παρα", false),
+    ("περι", true), ("προσ", true), ("υπερ", true),
+    ("ανα", false), ("απο", false), ("δια", false), ("δυσ", true), ("εισ", true),
+    ("επι", false), ("προ", true), ("συγ", false), ("συμ", false), ("συν", true),
+    ("υπο", false),
+    ("εκ", false), ("εμ", false), ("εν", true), ("εξ", true),
+];
+
+/// Split a word after a productive prefix (εκ, εισ, προσ, υπερ, αντι...).
+///
+/// Case and diacritics are ignored, and the rest of the word must be
+/// pronounceable by itself (cf. [`is_phonotactically_valid`]), with at least
+/// one consonant, so that ενώ is not split. The longest prefix is tried
+/// first. This only looks at the letters, so that words that only look
+/// prefixed are split too (πρόσωπο).
+///
+/// # Example
+///
+/// ```
+/// use grac::segment_prefix;
+///
+/// assert_eq!(segment_prefix("εισπνοή"), Some(("εισ", "πνοή")));
+/// assert_eq!(segment_prefix("Αντίθεση"), Some(("Αντί", "θεση")));
+/// assert_eq!(segment_prefix("προσκαλώ"), Some(("προσ", "καλώ")));
+/// // εκ is εξ before a vowel
+/// assert_eq!(segment_prefix("εκεί"), None);
+/// assert_eq!(segment_prefix("λόγος"), None);
+/// ```
+pub fn segment_prefix(word: &str) -> Option<(&str, &str)> {
+    PREFIXES.iter().find_map(|&(prefix, before_vowel)| {
+        let mut end = 0;
+        let mut clusters = graphemes_el(word);
+        for ch in prefix.chars() {
+            let cluster = clusters.next()?;
+            if base_of(cluster) != ch {
+                return None;
+            }
+            end += cluster.len();
+        }
+        let rest = &word[end..];
+        let first = clusters.next()?;
+        let valid = (before_vowel || !is_vowel(base_of(first)))
+            && graphemes_el(rest).any(|cluster| !is_vowel(base_of(cluster)))
+            && is_phonotactically_valid(rest);
+        valid.then(|| (&word[..end], rest))
+    })
+}
+
+/// Split a word after a prefix that ends with a consonant, when the rest
+/// starts with a consonant too.
+pub(crate) fn split_consonant_prefix(word: &str) -> Option<(&str, &str)> {
+    let (prefix, rest) = segment_prefix(word)?;
+    let last = graphemes_el(prefix).last()?;
+    let first = graphemes_el(rest).next()?;
+    (!is_vowel(base_of(last)) && !is_vowel(base_of(first))).then_some((prefix, rest))
+}
+
+/// Split a compound word after its linking vowel -ο- (αερο-ελεγκτής).
+///
+/// Without a lexicon, the only sign of a compound is a hiatus after the
+/// linking vowel, which native words rarely have: ραδιο-αστρονομία, but not
+/// ραδιοσταθμός (cf. [`split_compound_with_lexicon`](crate::lexicon::split_compound_with_lexicon)).
+/// Both parts must be pronounceable by themselves (cf.
+/// [`is_phonotactically_valid`]) and have at least two syllables. The word
+/// is returned as a single part if it is not split.
+///
+/// # Example
+///
+/// ```
+/// use grac::split_compound;
+///
+/// assert_eq!(split_compound("αεροελεγκτής"), ["αερο", "ελεγκτής"]);
+/// assert_eq!(split_compound("ηλεκτροακουστικός"), ["ηλεκτρο", "ακουστικός"]);
+/// assert_eq!(split_compound("Νοέμβριος"), ["Νοέμβριος"]);
+/// assert_eq!(split_compound("ποίημα"), ["ποίημα"]);
+/// ```
+pub fn split_compound(word: &str) -> Vec<&str> {
+    split_compound_by(word, |right| {
+        let next = graphemes_el(right).next().unwrap_or_default();
+        // ο with ι or υ is a diphthong, unless there is a diaeresis.
+        match base_of(next) {
+            'α' | 'ε' | 'η' | 'ο' | 'ω' => true,
+            'ι' | 'υ' => has_diacritic(next, Diacritic::DIAERESIS),
+            _ => false,
+        }
+    })
+}
+
+/// Split a word after every linking vowel -ο- where `is_split` accepts the
+/// rest of the word.
+///
+/// The leftmost split is taken first, so that the rest is as long as
+/// possible.
+pub(crate) fn split_compound_by(word: &str, mut is_split: impl FnMut(&str) -> bool) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut start = 0;
+    let mut end = 0;
+    for cluster in graphemes_el(word) {
+        end += cluster.len();
+        if base_of(cluster) != 'ο' {
+            continue;
+        }
+        let (left, right) = (&word[start..end], &word[end..]);
+        if is_compound_part(left) && is_compound_part(right) && is_split(right) {
+            parts.push(left);
+            start = end;
+        }
+    }
+    parts.push(&word[start..]);
+    parts
+}
+
+fn is_compound_part(part: &str) -> bool {
+    is_phonotactically_valid(part) && syllabify(part).len() >= 2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prefixes_sorted() {
+        for pair in PREFIXES.windows(2) {
+            let [(a, _), (b, _)] = pair else {
+                unreachable!()
+            };
+            assert!(a.chars().count() >= b.chars().count(), "{a} {b}");
+        }
+    }
+
+    #[test]
+    fn test_segment_prefix() {
+        assert_eq!(segment_prefix("ὑπερβολή"), Some(("ὑπερ", "βολή")));
+        assert_eq!(segment_prefix("περιέχω"), Some(("περι", "έχω")));
+        assert_eq!(segment_prefix("συνεργάτης"), Some(("συν", "εργάτης")));
+        assert_eq!(segment_prefix("ΕΚΛΟΓΗ"), Some(("ΕΚ", "ΛΟΓΗ")));
+        assert_eq!(segment_prefix("εκατό"), None);
+        assert_eq!(segment_prefix("ενώ"), None);
+        assert_eq!(segment_prefix("απλός"), None);
+        assert_eq!(segment_prefix("αντί"), None);
+        assert_eq!(segment_prefix("εκ"), None);
+        assert_eq!(segment_prefix(""), None);
+    }
+
+    #[test]
+    fn test_split_compound() {
+        assert_eq!(split_compound("ραδιοαστρονομία"), ["ραδιο", "αστρονομία"]);
+        assert_eq!(split_compound("ζωοειδής"), ["ζωο", "ειδής"]);
+        assert_eq!(split_compound("πρωτοϊστορία"), ["πρωτο", "ϊστορία"]);
+        assert_eq!(split_compound("ΑΕΡΟΕΛΕΓΚΤΗΣ"), ["ΑΕΡΟ", "ΕΛΕΓΚΤΗΣ"]);
+        assert_eq!(split_compound("ραδιοσταθμός"), ["ραδιοσταθμός"]);
+        assert_eq!(split_compound("ποιος"), ["ποιος"]);
+        assert_eq!(split_compound("ο"), ["ο"]);
+        assert_eq!(split_compound(""), [""]);
+    }
+
+    #[test]
+    fn test_split_consonant_prefix() {
+        assert_eq!(split_consonant_prefix("εισπνοή"), Some(("εισ", "πνοή")));
+        assert_eq!(split_consonant_prefix("ἐκλογή"), Some(("ἐκ", "λογή")));
+        assert_eq!(split_consonant_prefix("συνεργάτης"), None);
+        assert_eq!(split_consonant_prefix("αντίθεση"), None);
+        assert_eq!(split_consonant_prefix("εκεί"), None);
+        assert_eq!(split_consonant_prefix("ενώ"), None);
+    }
+}
@@ -0,0 +1,258 @@
+//! Regions of marked-up text that converters leave untouched.
+
+use std::ops::Range;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Delimiter {
+    /// From the opening to the closing delimiter, both included.
+    ///
+    /// An unclosed region goes on until the end of the text.
+    Pair(String, String),
+    /// A prefix followed by ASCII letters, like LaTeX's `\textbf`.
+    ///
+    /// If no letter follows, the region is the prefix and the next char (`\,`).
+    Command(String),
+    /// An entity or character reference of XML and HTML: `&amp;`, `&#x3B1;`.
+    ///
+    /// A `&` that does not start a reference, as in `Α & Β;`, is not protected.
+    EntityReference,
+}
+
+impl Delimiter {
+    /// Length of the region starting at the start of `s`, if any.
+    fn match_len(&self, s: &str) -> Option<usize> {
+        match self {
+            Self::Pair(open, close) => {
+                let rest = s.strip_prefix(open.as_str())?;
+                let len = rest
+                    .find(close.as_str())
+                    .map_or(rest.len(), |idx| idx + close.len());
+                Some(open.len() + len)
+            }
+            Self::Command(prefix) => {
+                let rest = s.strip_prefix(prefix.as_str())?;
+                let letters = rest
+                    .find(|ch: char| !ch.is_ascii_alphabetic())
+                    .unwrap_or(rest.len());
+                let len = match letters {
+                    0 => rest.chars().next().map_or(0, char::len_utf8),
+                    _ => letters,
+                };
+                Some(prefix.len() + len)
+            }
+            Self::EntityReference => {
+                let rest = s.strip_prefix('&')?;
+                let name = rest.strip_prefix('#').unwrap_or(rest);
+                let len = name
+                    .find(|ch: char| !ch.is_ascii_alphanumeric())
+                    .unwrap_or(name.len());
+                (len > 0 && name[len..].starts_with(';'))
+                    .then(|| 1 + rest.len() - name.len() + len + 1)
+            }
+        }
+    }
+}
+
+/// Delimiters of the regions to protect from conversion.
+///
+/// When several delimiters match at the same position, the first one added wins,
+/// so longer delimiters should be added first (```` ``` ```` before `` ` ``).
+///
+/// Regions are skipped by the monotonic conversion (cf.
+/// [`MonotonicOptions::protected`](crate::MonotonicOptions::protected)) and
+/// [`syllabify_words_outside`](crate::syllabify_words_outside). Other
+/// conversions can be run outside of them with [`apply`](Self::apply).
+///
+/// # Example
+///
+/// ```
+/// use grac::ProtectedRegions;
+///
+/// let protected = ProtectedRegions::new().with_pair("[[", "]]");
+/// let text = "Ὁ [[Ὅμηρος]] καὶ ἡ Ἰλιάς";
+/// assert_eq!(protected.regions(text), [4..21]);
+/// let result = protected.apply(text, |s| grac::to_monotonic(s));
+/// assert_eq!(result, "Ο [[Ὅμηρος]] και η Ιλιάς");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProtectedRegions {
+    delimiters: Vec<Delimiter>,
+}
+
+impl ProtectedRegions {
+    pub const fn new() -> Self {
+        Self {
+            delimiters: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_pair(mut self, open: &str, close: &str) -> Self {
+        self.delimiters
+            .push(Delimiter::Pair(open.to_string(), close.to_string()));
+        self
+    }
+
+    #[must_use]
+    pub fn with_command(mut self, prefix: &str) -> Self {
+        self.delimiters.push(Delimiter::Command(prefix.to_string()));
+        self
+    }
+
+    #[must_use]
+    pub fn with_entity_references(mut self) -> Self {
+        self.delimiters.push(Delimiter::EntityReference);
+        self
+    }
+
+    /// Code blocks, inline code, HTML comments and link targets.
+    pub fn markdown() -> Self {
+        Self::new()
+            .with_pair("```", "```")
+            .with_pair("~~~", "~~~")
+            .with_pair("`", "`")
+            .with_pair("<!--", "-->")
+            .with_pair("](", ")")
+    }
+
+    /// Comments, math, verbatim environments and command names.
+    ///
+    /// Command arguments are not protected: `\emph{Ἑλλάς}` becomes `\emph{Ελλάς}`.
+    pub fn latex() -> Self {
+        Self::new()
+            .with_pair("%", "\n")
+            .with_pair("\\begin{verbatim}", "\\end{verbatim}")
+            .with_pair("$$", "$$")
+            .with_pair("$", "$")
+            .with_pair("\\[", "\\]")
+            .with_pair("\\(", "\\)")
+            .with_command("\\")
+    }
+
+    /// Tags, comments, processing instructions and entity references (XML, TEI, HTML).
+    pub fn xml() -> Self {
+        Self::new()
+            .with_pair("<!--", "-->")
+            .with_pair("<?", "?>")
+            .with_pair("<", ">")
+            .with_entity_references()
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.delimiters.is_empty()
+    }
+
+    /// Byte ranges of the protected regions of the text, in order.
+    pub fn regions(&self, text: &str) -> Vec<Range<usize>> {
+        let mut regions = Vec::new();
+        if self.is_empty() {
+            return regions;
+        }
+        let mut idx = 0;
+        while idx < text.len() {
+            let rest = &text[idx..];
+            match self.delimiters.iter().find_map(|d| d.match_len(rest)) {
+                Some(len) if len > 0 => {
+                    regions.push(idx..idx + len);
+                    idx += len;
+                }
+                _ => idx += rest.chars().next().map_or(1, char::len_utf8),
+            }
+        }
+        regions
+    }
+
+    /// Apply `f` to the text outside of the protected regions.
+    ///
+    /// `f` is called once per unprotected segment, so words split by markup are
+    /// converted in parts.
+    pub fn apply(&self, text: &str, mut f: impl FnMut(&str) -> String) -> String {
+        let mut out = String::with_capacity(text.len());
+        for (range, protected) in self.segments(text) {
+            if protected {
+                out.push_str(&text[range]);
+            } else {
+                out.push_str(&f(&text[range]));
+            }
+        }
+        out
+    }
+
+    /// Split the text into (range, is protected) segments that cover it.
+    pub(crate) fn segments(&self, text: &str) -> Vec<(Range<usize>, bool)> {
+        let mut segments = Vec::new();
+        let mut start = 0;
+        for region in self.regions(text) {
+            if region.start > start {
+                segments.push((start..region.start, false));
+            }
+            start = region.end;
+            segments.push((region, true));
+        }
+        if start < text.len() {
+            segments.push((start..text.len(), false));
+        }
+        segments
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::to_monotonic;
+
+    #[test]
+    fn test_markdown() {
+        let text = "Ἡ `λέξις` καὶ [ὁ σύνδεσμος](https://el.wikipedia.org/wiki/Ἑλλάς).\n\n```\nτὸ ἔργον\n```\nτέλος";
+        let result = ProtectedRegions::markdown().apply(text, |s| to_monotonic(s));
+        assert_eq!(
+            result,
+            "Η `λέξις` και [ο σύνδεσμος](https://el.wikipedia.org/wiki/Ἑλλάς).\n\n```\nτὸ ἔργον\n```\nτέλος"
+        );
+    }
+
+    #[test]
+    fn test_latex() {
+        let text = "\\textbf{Ἑλλὰς} % ἡ Ἑλλάς\n$\\alpha$ καὶ \\\\ τέλος";
+        let regions: Vec<&str> = ProtectedRegions::latex()
+            .regions(text)
+            .into_iter()
+            .map(|range| &text[range])
+            .collect();
+        assert_eq!(regions, ["\\textbf", "% ἡ Ἑλλάς\n", "$\\alpha$", "\\\\"]);
+        let result = ProtectedRegions::latex().apply(text, |s| to_monotonic(s));
+        assert_eq!(
+            result,
+            "\\textbf{Ελλάς} % ἡ Ἑλλάς\n$\\alpha$ και \\\\ τέλος"
+        );
+    }
+
+    #[test]
+    fn test_xml() {
+        let text = "<p rend=\"ἔμφασις\">Ὁ κόσμος&amp;<!-- ὁ --></p>";
+        let result = ProtectedRegions::xml().apply(text, |s| to_monotonic(s));
+        assert_eq!(result, "<p rend=\"ἔμφασις\">Ο κόσμος&amp;<!-- ὁ --></p>");
+
+        let text = "Ἁ & Β; καὶ &#x3B1;&#945;τὸ &;";
+        let regions: Vec<&str> = ProtectedRegions::xml()
+            .regions(text)
+            .into_iter()
+            .map(|range| &text[range])
+            .collect();
+        assert_eq!(regions, ["&#x3B1;", "&#945;"]);
+        let result = ProtectedRegions::xml().apply(text, |s| to_monotonic(s));
+        assert_eq!(result, "Α & Β; και &#x3B1;&#945;το &;");
+    }
+
+    #[test]
+    fn test_segments_cover_text() {
+        let protected = ProtectedRegions::xml();
+        for text in ["", "<a>", "α<b>β", "<a", "&&;"] {
+            let segments = protected.segments(text);
+            let joined: String = segments.iter().map(|(r, _)| &text[r.clone()]).collect();
+            assert_eq!(joined, text);
+        }
+        assert!(ProtectedRegions::new().regions("<a>").is_empty());
+    }
+}
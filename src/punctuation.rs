@@ -0,0 +1,549 @@
+//! Normalization of Greek punctuation.
+
+use std::borrow::Cow;
+
+use crate::chars::{is_combining_mark, is_greek_letter};
+use crate::constants::APOSTROPHES;
+
+/// Chars that are treated as dashes or hyphens.
+const DASHES: [char; 8] = [
+    '-',        // HYPHEN-MINUS
+    '\u{2010}', // HYPHEN
+    '\u{2011}', // NON-BREAKING HYPHEN
+    '\u{2012}', // FIGURE DASH
+    '\u{2013}', // EN DASH
+    '\u{2014}', // EM DASH
+    '\u{2015}', // HORIZONTAL BAR, the quotation dash of Greek dialogue
+    '\u{2212}', // MINUS SIGN
+];
+
+/// Chars that [`normalize_quotes`] may replace.
+const QUOTES: [char; 10] = ['«', '»', '“', '”', '„', '‹', '›', '‘', '’', '"'];
+
+/// Borrow the text when it has none of `chars`, or when `normalize` gives it
+/// back unchanged.
+///
+/// Most texts have none of the chars, and are then only scanned once.
+fn normalize_cow<'a>(
+    text: &'a str,
+    chars: &[char],
+    normalize: impl FnOnce(&str) -> String,
+) -> Cow<'a, str> {
+    if !text.contains(chars) {
+        return Cow::Borrowed(text);
+    }
+    let out = normalize(text);
+    if out == text {
+        Cow::Borrowed(text)
+    } else {
+        Cow::Owned(out)
+    }
+}
+
+/// Target chars of [`normalize_dashes`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct DashStyle {
+    /// Between letters or numbers of a word: Ελληνο-τουρκικός, COVID-19.
+    pub hyphen: char,
+    /// Between numbers: 1821–1830.
+    pub range: char,
+    /// Inside a sentence, as punctuation: «Ήρθε — επιτέλους».
+    pub dash: char,
+    /// At the start of a line, before the words of a speaker.
+    pub dialogue: char,
+}
+
+impl DashStyle {
+    pub const fn new() -> Self {
+        Self {
+            hyphen: '-',
+            range: '\u{2013}',
+            dash: '\u{2014}',
+            dialogue: '\u{2015}',
+        }
+    }
+
+    #[must_use]
+    pub const fn with_hyphen(mut self, hyphen: char) -> Self {
+        self.hyphen = hyphen;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_range(mut self, range: char) -> Self {
+        self.range = range;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_dash(mut self, dash: char) -> Self {
+        self.dash = dash;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_dialogue(mut self, dialogue: char) -> Self {
+        self.dialogue = dialogue;
+        self
+    }
+}
+
+impl Default for DashStyle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Replace hyphens and dashes by the char of their role in the text.
+///
+/// A run of dashes is:
+/// * a dialogue dash at the start of a line,
+/// * a hyphen between letters, or between a letter and a number,
+/// * a range between two numbers,
+/// * a hyphen between numbers chained by more dashes, as in dates (2023-01-05)
+///   or phone numbers,
+/// * a dash otherwise, `--` included.
+///
+/// A hyphen-minus or a minus sign before a number (-5) is a sign, and is left as it is.
+///
+/// # Example
+///
+/// ```
+/// use grac::{DashStyle, normalize_dashes};
+///
+/// let text = "- Πόσα; - ρώτησε.\nΔύο—τρία, 1821-1830 -- ή -5.";
+/// assert_eq!(
+///     normalize_dashes(text, &DashStyle::new()),
+///     "― Πόσα; — ρώτησε.\nΔύο-τρία, 1821–1830 — ή -5."
+/// );
+///
+/// let style = DashStyle::new().with_dialogue('\u{2014}');
+/// assert_eq!(normalize_dashes("―Ναι.", &style), "—Ναι.");
+/// ```
+pub fn normalize_dashes(text: &str, style: &DashStyle) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find(DASHES) {
+        let (before, run) = rest.split_at(start);
+        let len = run.find(|ch| !DASHES.contains(&ch)).unwrap_or(run.len());
+        let (run, after) = run.split_at(len);
+        out.push_str(before);
+
+        let prev = out.chars().next_back();
+        let next = after.chars().next();
+        let is_line_start = out
+            .chars()
+            .rev()
+            .find(|ch| !matches!(ch, ' ' | '\t'))
+            .is_none_or(|ch| ch == '\n');
+        let is_single = run.chars().count() == 1;
+        let is_digit = |ch: Option<char>| ch.is_some_and(|ch| ch.is_ascii_digit());
+        let is_alphanumeric = |ch: Option<char>| ch.is_some_and(char::is_alphanumeric);
+
+        if matches!(run, "-" | "\u{2212}") && is_digit(next) && !is_alphanumeric(prev) {
+            out.push_str(run);
+        } else if is_line_start && !is_digit(next) {
+            out.push(style.dialogue);
+        } else if is_single && is_digit(prev) && is_digit(next) {
+            // 2023-01-05: the numbers before or after are chained by another dash
+            let is_digits = |ch: char| ch.is_ascii_digit();
+            let is_chained = out
+                .trim_end_matches(is_digits)
+                .ends_with(|ch| DASHES.contains(&ch) || ch == style.hyphen)
+                || after.trim_start_matches(is_digits).starts_with(DASHES);
+            out.push(if is_chained {
+                style.hyphen
+            } else {
+                style.range
+            });
+        } else if is_single && is_alphanumeric(prev) && is_alphanumeric(next) {
+            out.push(style.hyphen);
+        } else {
+            out.push(style.dash);
+        }
+        rest = after;
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Same as [`normalize_dashes`], borrowing the text when it is unchanged.
+pub fn normalize_dashes_cow<'a>(text: &'a str, style: &DashStyle) -> Cow<'a, str> {
+    normalize_cow(text, &DASHES, |text| normalize_dashes(text, style))
+}
+
+/// Quotation marks of [`normalize_quotes`], from the outermost level inwards.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum QuoteStyle {
+    /// «εισαγωγικά», with “quotes” inside.
+    #[default]
+    Greek,
+    /// “Curly quotes”, with ‘single quotes’ inside.
+    Curly,
+    /// "Straight quotes", with 'single quotes' inside.
+    Straight,
+}
+
+impl QuoteStyle {
+    /// Opening and closing marks at a nesting depth (0 is the outermost).
+    const fn marks(self, depth: usize) -> (char, char) {
+        let inner = depth % 2 == 1;
+        match (self, inner) {
+            (Self::Greek, false) => ('«', '»'),
+            (Self::Greek, true) | (Self::Curly, false) => ('“', '”'),
+            (Self::Curly, true) => ('‘', '’'),
+            (Self::Straight, false) => ('"', '"'),
+            (Self::Straight, true) => ('\'', '\''),
+        }
+    }
+}
+
+/// Replace quotation marks by those of a style, according to their nesting.
+///
+/// Opening marks are `«`, `“`, `„`, `‹` and `‘`, closing marks are `»`, `”`
+/// and `›`, `“` closes a `„`, and `’` closes a `‘` or a `‹`: otherwise it is an
+/// apostrophe (σ’ αγαπώ), as are straight single quotes, that are left untouched. A straight
+/// double quote opens after whitespace or an opening bracket, and closes
+/// otherwise. Levels alternate between the outer and inner marks of the style.
+///
+/// # Example
+///
+/// ```
+/// use grac::{QuoteStyle, normalize_quotes};
+///
+/// let text = r#"Είπε: "Μου είπε "ναι" χθες"."#;
+/// assert_eq!(normalize_quotes(text, QuoteStyle::Greek), "Είπε: «Μου είπε “ναι” χθες».");
+/// assert_eq!(normalize_quotes("«σ’ αγαπώ»", QuoteStyle::Curly), "“σ’ αγαπώ”");
+/// ```
+pub fn normalize_quotes(text: &str, style: QuoteStyle) -> String {
+    let mut out = String::with_capacity(text.len());
+    // Opening marks of the open quotes.
+    let mut stack: Vec<char> = Vec::new();
+    let mut prev: Option<char> = None;
+    for ch in text.chars() {
+        let opens = match ch {
+            // German style: „…“
+            '“' => Some(stack.last() != Some(&'„')),
+            '«' | '„' | '‹' | '‘' => Some(true),
+            '»' | '”' | '›' => Some(false),
+            '’' if matches!(stack.last(), Some('‘' | '‹')) => Some(false),
+            '"' => Some(
+                stack.is_empty()
+                    || prev.is_none_or(|prev| prev.is_whitespace() || "([{«“„‹‘\"".contains(prev)),
+            ),
+            _ => None,
+        };
+        match opens {
+            Some(true) => {
+                out.push(style.marks(stack.len()).0);
+                stack.push(ch);
+            }
+            Some(false) => {
+                stack.pop();
+                out.push(style.marks(stack.len()).1);
+            }
+            None => out.push(ch),
+        }
+        prev = Some(ch);
+    }
+    out
+}
+
+/// Same as [`normalize_quotes`], borrowing the text when it is unchanged.
+pub fn normalize_quotes_cow(text: &str, style: QuoteStyle) -> Cow<'_, str> {
+    normalize_cow(text, &QUOTES, |text| normalize_quotes(text, style))
+}
+
+/// Dots that stand for an ano teleia after a word.
+const ANO_TELEIA_LOOKALIKES: [char; 4] = [
+    '\u{2022}', // BULLET
+    '\u{2027}', // HYPHENATION POINT
+    '\u{2219}', // BULLET OPERATOR
+    '\u{22C5}', // DOT OPERATOR
+];
+
+/// Codepoints of the Greek question mark and ano teleia.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum GreekPunctuationForm {
+    /// U+003B SEMICOLON and U+00B7 MIDDLE DOT, as NFC normalizes them.
+    #[default]
+    Canonical,
+    /// U+037E GREEK QUESTION MARK and U+0387 GREEK ANO TELEIA, for tools that
+    /// tell them apart (cf. [`is_greek_char`](crate::is_greek_char)).
+    Dedicated,
+}
+
+impl GreekPunctuationForm {
+    const fn question_mark(self) -> char {
+        match self {
+            Self::Canonical => ';',
+            Self::Dedicated => '\u{037E}',
+        }
+    }
+
+    const fn ano_teleia(self) -> char {
+        match self {
+            Self::Canonical => '\u{00B7}',
+            Self::Dedicated => '\u{0387}',
+        }
+    }
+}
+
+/// Write the Greek question mark and ano teleia with the codepoints of `form`.
+///
+/// Both forms are always converted. A semicolon is only converted to the
+/// dedicated question mark after a Greek word, and bullets and dot operators
+/// are only taken for an ano teleia right after a word.
+///
+/// # Example
+///
+/// ```
+/// use grac::{GreekPunctuationForm, normalize_greek_punctuation};
+///
+/// let text = "Τι είπες\u{037E} Ναι\u{2219} όχι. a; b";
+/// assert_eq!(
+///     normalize_greek_punctuation(text, GreekPunctuationForm::Canonical),
+///     "Τι είπες; Ναι\u{00B7} όχι. a; b"
+/// );
+/// assert_eq!(
+///     normalize_greek_punctuation(text, GreekPunctuationForm::Dedicated),
+///     "Τι είπες\u{037E} Ναι\u{0387} όχι. a; b"
+/// );
+/// ```
+pub fn normalize_greek_punctuation(text: &str, form: GreekPunctuationForm) -> String {
+    let mut out = String::with_capacity(text.len());
+    for ch in text.chars() {
+        let after_word = || {
+            out.chars()
+                .rev()
+                .find(|ch| !matches!(ch, '»' | '”' | '’' | ')' | ']') && !is_combining_mark(*ch))
+        };
+        let normalized = match ch {
+            '\u{037E}' => form.question_mark(),
+            ';' if after_word().is_some_and(is_greek_letter) => form.question_mark(),
+            '\u{00B7}' | '\u{0387}' => form.ano_teleia(),
+            ch if ANO_TELEIA_LOOKALIKES.contains(&ch)
+                && after_word().is_some_and(char::is_alphabetic) =>
+            {
+                form.ano_teleia()
+            }
+            ch => ch,
+        };
+        out.push(normalized);
+    }
+    out
+}
+
+/// Same as [`normalize_greek_punctuation`], borrowing the text when it is
+/// unchanged.
+///
+/// # Example
+///
+/// ```
+/// use std::borrow::Cow;
+///
+/// use grac::{GreekPunctuationForm, normalize_greek_punctuation_cow};
+///
+/// let form = GreekPunctuationForm::Canonical;
+/// assert!(matches!(normalize_greek_punctuation_cow("Ναι, όχι.", form), Cow::Borrowed(_)));
+/// assert!(matches!(normalize_greek_punctuation_cow("Τι; Ναι\u{00B7}", form), Cow::Borrowed(_)));
+/// assert_eq!(normalize_greek_punctuation_cow("Τι\u{037E}", form), "Τι;");
+/// ```
+pub fn normalize_greek_punctuation_cow(text: &str, form: GreekPunctuationForm) -> Cow<'_, str> {
+    const CHARS: [char; 8] = [
+        ';',
+        '\u{037E}',
+        '\u{00B7}',
+        '\u{0387}',
+        ANO_TELEIA_LOOKALIKES[0],
+        ANO_TELEIA_LOOKALIKES[1],
+        ANO_TELEIA_LOOKALIKES[2],
+        ANO_TELEIA_LOOKALIKES[3],
+    ];
+    normalize_cow(text, &CHARS, |text| normalize_greek_punctuation(text, form))
+}
+
+/// Kinds of punctuation of Greek texts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GreekPunct {
+    FullStop,
+    Comma,
+    /// Ερωτηματικό: `;`, as U+003B or U+037E.
+    QuestionMark,
+    /// Άνω τελεία: `·`, as U+00B7 or U+0387.
+    AnoTeleia,
+    ExclamationMark,
+    Ellipsis,
+    /// Κεραία, the numeral sign of Greek numerals: αʹ.
+    Keraia,
+    /// Αριστερή κεραία, the numeral sign of thousands: ͵α.
+    LowerKeraia,
+    /// Κορωνίς, of crasis: κα᾽γώ.
+    Koronis,
+    /// Any of [`APOSTROPHES`](crate::constants::APOSTROPHES), but the koronis.
+    Apostrophe,
+    /// Παύλα of dialogue: `―`.
+    QuotationDash,
+    /// En and em dashes.
+    Dash,
+    /// Hyphens of compound words.
+    Hyphen,
+    /// Εισαγωγικά, curly and straight double quotes.
+    QuotationMark,
+    /// Parentheses and brackets, opening and closing.
+    Bracket,
+}
+
+/// Classify a punctuation char.
+///
+/// The koronis is also accepted as an apostrophe by the rest of the crate, as a
+/// common (wrong) variant. `’` is an apostrophe, and not a quotation mark.
+///
+/// # Example
+///
+/// ```
+/// use grac::{GreekPunct, punctuation_kind};
+///
+/// assert_eq!(punctuation_kind(';'), Some(GreekPunct::QuestionMark));
+/// assert_eq!(punctuation_kind('\u{0387}'), Some(GreekPunct::AnoTeleia));
+/// assert_eq!(punctuation_kind('’'), Some(GreekPunct::Apostrophe));
+/// assert_eq!(punctuation_kind('᾽'), Some(GreekPunct::Koronis));
+/// assert_eq!(punctuation_kind('α'), None);
+/// ```
+pub fn punctuation_kind(ch: char) -> Option<GreekPunct> {
+    let kind = match ch {
+        '.' => GreekPunct::FullStop,
+        ',' => GreekPunct::Comma,
+        ';' | '\u{037E}' => GreekPunct::QuestionMark,
+        '\u{00B7}' | '\u{0387}' => GreekPunct::AnoTeleia,
+        '!' => GreekPunct::ExclamationMark,
+        '…' => GreekPunct::Ellipsis,
+        '\u{0374}' | '\u{02B9}' => GreekPunct::Keraia,
+        '\u{0375}' => GreekPunct::LowerKeraia,
+        '\u{1FBD}' => GreekPunct::Koronis,
+        ch if APOSTROPHES.contains(&ch) => GreekPunct::Apostrophe,
+        '\u{2015}' => GreekPunct::QuotationDash,
+        '\u{2013}' | '\u{2014}' => GreekPunct::Dash,
+        '-' | '\u{2010}' | '\u{2011}' => GreekPunct::Hyphen,
+        '«' | '»' | '“' | '”' | '„' | '"' | '‹' | '›' => GreekPunct::QuotationMark,
+        '(' | ')' | '[' | ']' | '{' | '}' => GreekPunct::Bracket,
+        _ => return None,
+    };
+    Some(kind)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_dashes() {
+        let style = DashStyle::new();
+        let cases = [
+            ("λογιών-των-λογιών", "λογιών-των-λογιών"),
+            ("Ελληνο\u{2010}τουρκικός", "Ελληνο-τουρκικός"),
+            ("COVID\u{2013}19", "COVID-19"),
+            ("  \u{2014}Τι;\n\t--Τίποτα.", "  ―Τι;\n\t―Τίποτα."),
+            ("\n\n―\u{2009}Την", "\n\n―\u{2009}Την"),
+            ("σελ. 12 - 15", "σελ. 12 — 15"),
+            ("σελ. 12-15", "σελ. 12–15"),
+            ("στις 2023-01-05.", "στις 2023-01-05."),
+            ("2023\u{2013}01\u{2013}05", "2023-01-05"),
+            ("210-555-1234 και 5-6", "210-555-1234 και 5–6"),
+            ("θερμοκρασία −5 και -3", "θερμοκρασία −5 και -3"),
+            ("-5 βαθμοί", "-5 βαθμοί"),
+            ("τέλος -", "τέλος —"),
+            ("", ""),
+        ];
+        for (text, expected) in cases {
+            assert_eq!(normalize_dashes(text, &style), expected, "{text}");
+            let cow = normalize_dashes_cow(text, &style);
+            assert_eq!(matches!(cow, Cow::Borrowed(_)), text == expected, "{text}");
+            assert_eq!(cow, expected);
+        }
+    }
+
+    #[test]
+    fn test_normalize_greek_punctuation() {
+        let form = GreekPunctuationForm::Dedicated;
+        let cases = [
+            ("Ποιος;", "Ποιος\u{037E}"),
+            ("«Ποιος»;", "«Ποιος»\u{037E}"),
+            ("ποιὸς;", "ποιὸς\u{037E}"),
+            ("ποιο\u{301}ς;", "ποιο\u{301}ς\u{037E}"),
+            ("x = 1; y", "x = 1; y"),
+            ("\u{2022} Ένα", "\u{2022} Ένα"),
+            ("Ένα\u{2022}", "Ένα\u{0387}"),
+            ("", ""),
+        ];
+        for (text, expected) in cases {
+            assert_eq!(normalize_greek_punctuation(text, form), expected, "{text}");
+            let canonical = normalize_greek_punctuation(text, GreekPunctuationForm::Canonical);
+            let expected = expected
+                .replace('\u{037E}', ";")
+                .replace('\u{0387}', "\u{00B7}");
+            assert_eq!(canonical, expected);
+        }
+    }
+
+    #[test]
+    fn test_punctuation_kind() {
+        for ch in APOSTROPHES {
+            assert!(matches!(
+                punctuation_kind(ch),
+                Some(GreekPunct::Apostrophe | GreekPunct::Koronis)
+            ));
+        }
+        assert_eq!(punctuation_kind('ʹ'), Some(GreekPunct::Keraia));
+        assert_eq!(punctuation_kind('͵'), Some(GreekPunct::LowerKeraia));
+        assert_eq!(punctuation_kind('―'), Some(GreekPunct::QuotationDash));
+        // Every form written by the normalizers is classified.
+        for form in [
+            GreekPunctuationForm::Canonical,
+            GreekPunctuationForm::Dedicated,
+        ] {
+            assert_eq!(
+                punctuation_kind(form.question_mark()),
+                Some(GreekPunct::QuestionMark)
+            );
+            assert_eq!(
+                punctuation_kind(form.ano_teleia()),
+                Some(GreekPunct::AnoTeleia)
+            );
+        }
+        for ch in ['1', ' ', '\u{0301}', 'ς'] {
+            assert_eq!(punctuation_kind(ch), None, "{ch}");
+        }
+    }
+
+    #[test]
+    fn test_normalize_quotes() {
+        let cases = [
+            ("«Α “Β «Γ» Β” Α»", "“Α ‘Β “Γ” Β’ Α”"),
+            ("„Α“ και ‹Β›", "“Α” και “Β”"),
+            ("‘Β’ και σ’ αγαπώ", "“Β” και σ’ αγαπώ"),
+            ("(\"Α\") \"", "(“Α”) “"),
+            ("Α» τέλος", "Α” τέλος"),
+            ("'Α'", "'Α'"),
+            ("", ""),
+        ];
+        for (text, expected) in cases {
+            assert_eq!(
+                normalize_quotes(text, QuoteStyle::Curly),
+                expected,
+                "{text}"
+            );
+            let cow = normalize_quotes_cow(text, QuoteStyle::Curly);
+            assert_eq!(matches!(cow, Cow::Borrowed(_)), text == expected, "{text}");
+        }
+        let text = "«Α “Β” Α»";
+        assert_eq!(normalize_quotes(text, QuoteStyle::Straight), "\"Α 'Β' Α\"");
+        for style in [QuoteStyle::Greek, QuoteStyle::Curly] {
+            let normalized = normalize_quotes(text, style);
+            assert_eq!(normalize_quotes(&normalized, QuoteStyle::Greek), text);
+        }
+    }
+}
@@ -0,0 +1,112 @@
+//! Helpers for word games: anagrams and crossword patterns.
+
+use crate::chars::{base_lower, is_combining_mark};
+
+/// Letters of a word, for comparisons that ignore case, diacritics and final sigma.
+fn folded_letters(word: &str) -> impl Iterator<Item = char> + '_ {
+    word.chars()
+        .filter(|ch| !is_combining_mark(*ch))
+        .map(|ch| match base_lower(ch) {
+            'ς' => 'σ',
+            ch => ch,
+        })
+}
+
+/// A key shared by all the anagrams of a word.
+///
+/// The letters are lowercased, stripped of diacritics, with final sigma
+/// replaced by σ, and sorted. Non alphabetic chars are ignored.
+///
+/// # Example
+///
+/// ```
+/// use grac::anagram_key;
+///
+/// assert_eq!(anagram_key("Σπάρτη"), "αηπρστ");
+/// assert_eq!(anagram_key("πάστρη"), anagram_key("Σπάρτη"));
+/// assert_eq!(anagram_key("άλας"), anagram_key("σάλα"));
+/// ```
+pub fn anagram_key(word: impl AsRef<str>) -> String {
+    let mut letters: Vec<char> = folded_letters(word.as_ref())
+        .filter(|ch| ch.is_alphabetic())
+        .collect();
+    letters.sort_unstable();
+    letters.into_iter().collect()
+}
+
+/// Check if a word matches a crossword pattern.
+///
+/// In the pattern, `?` stands for exactly one letter and `*` for any number of
+/// letters (none included). Other letters are compared ignoring case,
+/// diacritics and final sigma, in both the word and the pattern.
+///
+/// # Example
+///
+/// ```
+/// use grac::matches_pattern;
+///
+/// assert!(matches_pattern("καλημέρα", "κ?λ?μέρ?"));
+/// assert!(matches_pattern("ΚΑΛΗΜΈΡΑ", "κ?λ?μερ?"));
+/// assert!(matches_pattern("καλημέρα", "κα*"));
+/// assert!(!matches_pattern("καλημέρες", "κ?λ?μέρ?"));
+/// ```
+pub fn matches_pattern(word: impl AsRef<str>, pattern: impl AsRef<str>) -> bool {
+    let word: Vec<char> = folded_letters(word.as_ref()).collect();
+    let pattern: Vec<char> = folded_letters(pattern.as_ref()).collect();
+
+    // Greedy matching with backtracking to the last `*`.
+    let (mut w, mut p) = (0, 0);
+    let mut star: Option<(usize, usize)> = None;
+    while w < word.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p, w));
+                p += 1;
+            }
+            Some(&ch) if ch == '?' || ch == word[w] => {
+                w += 1;
+                p += 1;
+            }
+            _ => match star {
+                Some((star_p, star_w)) => {
+                    p = star_p + 1;
+                    w = star_w + 1;
+                    star = Some((star_p, star_w + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|ch| *ch == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_anagram_key_normalization() {
+        let decomposed = "Σπα\u{301}ρτη";
+        assert_eq!(anagram_key(decomposed), anagram_key("σπάρτη"));
+        assert_eq!(anagram_key("ό,τι"), "ιοτ");
+        assert_eq!(anagram_key(""), "");
+    }
+
+    #[test]
+    fn test_matches_pattern() {
+        let cases = [
+            ("λόγος", "λ?γοσ", true),
+            ("λόγος", "*", true),
+            ("", "*", true),
+            ("", "?", false),
+            ("λόγος", "*γ*", true),
+            ("λόγος", "*ο", false),
+            ("ἄνθρωπος", "ανθρωπος", true),
+            ("άνθρωπος", "a*", false),
+            ("αβαβγ", "*αβγ", true),
+        ];
+        for (word, pattern, expected) in cases {
+            assert_eq!(matches_pattern(word, pattern), expected, "{word} {pattern}");
+        }
+    }
+}
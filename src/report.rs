@@ -0,0 +1,318 @@
+//! Coverage of the monotonic conversion, for quality assurance.
+
+use std::ops::Range;
+
+use unicode_normalization::UnicodeNormalization;
+
+use crate::chars::is_greek_letter;
+use crate::monotonic::{
+    Converter, DiaeresisFixer, MonotonicOptions, convert_to_acute, ends_sentence, special_cases,
+};
+use crate::word::GreekWordBuf;
+
+/// Weak pronouns that are only written with an accent to disambiguate.
+///
+/// The article (τοῦ, τῆς...) is accented in polytonic, so that its accent says
+/// nothing.
+const ACCENTED_PRONOUNS: [&str; 8] = with_capitalized!(["μού", "σού", "μάς", "σάς"]);
+
+/// How a word was handled by the monotonic conversion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WordKind {
+    /// Changed by the rules.
+    Converted,
+    /// A Greek word that was already monotonic.
+    Unchanged,
+    /// Left as is: not Greek, or without letters (numbers, punctuation).
+    NotGreek,
+    /// Converted with a special case (cf. [`MonotonicOptions::special_cases`]).
+    SpecialCase,
+    /// Left as is (cf. [`MonotonicOptions::protected_words`]).
+    Protected,
+    /// A weak pronoun written with an accent (μοῦ), that only the author
+    /// knows whether to keep (cf. [`MonotonicOptions::keep_pronoun_accents`]).
+    Ambiguous,
+}
+
+/// A word of the converted text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReportedWord {
+    /// Byte range of the word, with its punctuation, in the original text.
+    pub span: Range<usize>,
+    pub word: String,
+    pub converted: String,
+    pub kind: WordKind,
+}
+
+/// Result of [`to_monotonic_with_report`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConversionReport {
+    /// Same as [`to_monotonic_with_options`](crate::to_monotonic_with_options).
+    pub output: String,
+    /// Every word, in order, outside of protected regions.
+    pub words: Vec<ReportedWord>,
+}
+
+impl ConversionReport {
+    /// Number of words of the given kind.
+    pub fn count(&self, kind: WordKind) -> usize {
+        self.words.iter().filter(|word| word.kind == kind).count()
+    }
+}
+
+/// Same as [`to_monotonic_with_options`](crate::to_monotonic_with_options),
+/// reporting how every word was handled.
+///
+/// # Example
+///
+/// ```
+/// use grac::{MonotonicOptions, WordKind, to_monotonic_with_report};
+///
+/// let report = to_monotonic_with_report("Ὁ πατέρας μοῦ εἶπε: Hello 1891", &MonotonicOptions::new());
+/// assert_eq!(report.output, "Ο πατέρας μου είπε: Hello 1891");
+/// assert_eq!(report.count(WordKind::Converted), 2);
+/// assert_eq!(report.count(WordKind::Unchanged), 1);
+/// assert_eq!(report.count(WordKind::NotGreek), 2);
+/// assert_eq!(report.count(WordKind::Ambiguous), 1);
+/// assert_eq!(report.words[2].converted, "μου");
+/// ```
+pub fn to_monotonic_with_report(
+    s: impl AsRef<str>,
+    options: &MonotonicOptions,
+) -> ConversionReport {
+    Converter {
+        options,
+        ..Converter::default()
+    }
+    .report(s.as_ref())
+}
+
+impl Converter<'_> {
+    pub(crate) fn report(&self, s: &str) -> ConversionReport {
+        let diaereses = DiaeresisFixer::shared(&self.options.diaeresis);
+        let mut buf = GreekWordBuf::new();
+        let mut report = ConversionReport {
+            output: String::with_capacity(s.len()),
+            words: Vec::new(),
+        };
+        for (range, is_protected) in self.options.protected.segments(s) {
+            if is_protected {
+                report.output.push_str(&s[range]);
+                continue;
+            }
+            // Every segment is converted on its own, as by `to_monotonic`.
+            let mut sentence_start = true;
+            let mut start = range.start;
+            let separators = self.options.word_separators;
+            for chunk in separators.split(&s[range]) {
+                let word = separators.trim_end(chunk);
+                if !word.is_empty() {
+                    let converted =
+                        self.convert_word_at(&diaereses, &mut buf, word, sentence_start);
+                    report.output.push_str(&converted);
+                    report.words.push(ReportedWord {
+                        span: start..start + word.len(),
+                        word: word.to_string(),
+                        kind: self.word_kind(&mut buf, word, &converted),
+                        converted,
+                    });
+                    sentence_start = ends_sentence(word);
+                }
+                report.output.push_str(&chunk[word.len()..]);
+                start += chunk.len();
+            }
+        }
+        report
+    }
+
+    fn word_kind(&self, buf: &mut GreekWordBuf, word: &str, converted: &str) -> WordKind {
+        if self.is_protected_word(word) {
+            return WordKind::Protected;
+        }
+        if !buf.set_if_greek(word, self.options.strict_greek) {
+            return WordKind::NotGreek;
+        }
+        let (_, core, _) = buf.split_punctuation();
+        if !core.chars().any(is_greek_letter) {
+            return WordKind::NotGreek;
+        }
+        if self.options.special_cases.contains_key(core) || special_cases(core).is_some() {
+            return WordKind::SpecialCase;
+        }
+        let acute = convert_to_acute(core);
+        if ACCENTED_PRONOUNS.contains(&acute.as_str()) {
+            return WordKind::Ambiguous;
+        }
+        if converted.chars().eq(word.nfc()) {
+            WordKind::Unchanged
+        } else {
+            WordKind::Converted
+        }
+    }
+}
+
+/// Number of words of context on each side of a [`ReviewSample`].
+const CONTEXT_WORDS: usize = 5;
+
+/// A word picked by [`sample_for_review`], with its context.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReviewSample<'a> {
+    /// Index of the word in [`ConversionReport::words`].
+    pub index: usize,
+    pub word: &'a ReportedWord,
+    /// Up to five words before the word, as written, joined by spaces.
+    pub before: String,
+    /// Up to five words after the word, as written, joined by spaces.
+    pub after: String,
+}
+
+/// A reproducible sample of `n` words that were changed or flagged as
+/// ambiguous, in the order of the text.
+///
+/// The same report and seed always give the same sample, on every platform.
+/// If there are at most `n` such words, all of them are returned.
+///
+/// # Example
+///
+/// ```
+/// use grac::{MonotonicOptions, sample_for_review, to_monotonic_with_report};
+///
+/// let text = "Ὁ πατέρας μοῦ εἶπε ὅτι ἡ θάλασσα εἶναι ἤρεμη.";
+/// let report = to_monotonic_with_report(text, &MonotonicOptions::new());
+/// let sample = sample_for_review(&report, 2, 42);
+/// assert_eq!(sample.len(), 2);
+/// assert_eq!(sample, sample_for_review(&report, 2, 42));
+/// assert!(sample[0].index < sample[1].index);
+///
+/// let all = sample_for_review(&report, 100, 42);
+/// assert_eq!(all.len(), 7);
+/// assert_eq!(all[1].word.word, "μοῦ");
+/// assert_eq!((all[1].before.as_str(), all[1].after.as_str()), ("Ὁ πατέρας", "εἶπε ὅτι ἡ θάλασσα εἶναι"));
+/// ```
+pub fn sample_for_review(report: &ConversionReport, n: usize, seed: u64) -> Vec<ReviewSample<'_>> {
+    let mut candidates: Vec<usize> = report
+        .words
+        .iter()
+        .enumerate()
+        .filter(|(_, word)| {
+            matches!(
+                word.kind,
+                WordKind::Converted | WordKind::SpecialCase | WordKind::Ambiguous
+            )
+        })
+        .map(|(idx, _)| idx)
+        .collect();
+
+    // Partial Fisher-Yates shuffle
+    let n = n.min(candidates.len());
+    let mut state = seed;
+    for idx in 0..n {
+        let remaining = (candidates.len() - idx) as u64;
+        let pick = idx + (splitmix64(&mut state) % remaining) as usize;
+        candidates.swap(idx, pick);
+    }
+    candidates.truncate(n);
+    candidates.sort_unstable();
+
+    let join = |words: &[ReportedWord]| {
+        words
+            .iter()
+            .map(|word| word.word.as_str())
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    candidates
+        .into_iter()
+        .map(|index| {
+            let words = &report.words;
+            let after_end = (index + 1 + CONTEXT_WORDS).min(words.len());
+            ReviewSample {
+                index,
+                word: &words[index],
+                before: join(&words[index.saturating_sub(CONTEXT_WORDS)..index]),
+                after: join(&words[index + 1..after_end]),
+            }
+        })
+        .collect()
+}
+
+/// The SplitMix64 generator, to be reproducible without depending on `rand`.
+const fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::{BTreeMap, BTreeSet};
+
+    use crate::monotonic::to_monotonic_with_options;
+    use crate::protect::ProtectedRegions;
+
+    #[test]
+    fn test_report_output() {
+        let options = MonotonicOptions {
+            protected: ProtectedRegions::xml(),
+            conservative_names: true,
+            ..Default::default()
+        };
+        for text in [
+            "",
+            "Ἑλλάς καὶ κόσμος.\r\n...ἄνθρωπος.",
+            "<hi rend=\"ἔντονα\">μοῦ</hi> Κέϋνς — Ἀθῆναι-Πειραιεύς",
+            "ά\u{301}ϊ  καὶ\n",
+        ] {
+            let report = to_monotonic_with_report(text, &options);
+            assert_eq!(report.output, to_monotonic_with_options(text, &options));
+            for word in &report.words {
+                assert_eq!(text[word.span.clone()], word.word);
+            }
+        }
+    }
+
+    #[test]
+    fn test_report_kinds() {
+        let options = MonotonicOptions {
+            special_cases: BTreeMap::from([("Κάϊρο".to_string(), "Κάιρο".to_string())]),
+            protected_words: BTreeSet::from(["Κέϋνς".to_string()]),
+            ..Default::default()
+        };
+        let report = to_monotonic_with_report("«ποὺ» Κάϊρο, Κέϋνς σοῦ τῆς", &options);
+        let kinds: Vec<WordKind> = report.words.iter().map(|word| word.kind).collect();
+        assert_eq!(
+            kinds,
+            [
+                WordKind::SpecialCase,
+                WordKind::SpecialCase,
+                WordKind::Protected,
+                WordKind::Ambiguous,
+                WordKind::Converted
+            ]
+        );
+        assert_eq!(report.words[1].span, 12..23);
+    }
+
+    #[test]
+    fn test_sample_for_review() {
+        let text = "καὶ τὸ σπίτι καὶ ἡ αὐλὴ καὶ ὁ κῆπος ".repeat(10);
+        let report = to_monotonic_with_report(&text, &MonotonicOptions::new());
+        let sample = sample_for_review(&report, 5, 7);
+        assert_eq!(sample.len(), 5);
+        assert!(sample.windows(2).all(|pair| pair[0].index < pair[1].index));
+        assert!(sample.iter().all(|s| s.word.kind == WordKind::Converted));
+        assert_ne!(sample, sample_for_review(&report, 5, 8));
+        assert!(sample_for_review(&report, 0, 7).is_empty());
+        assert!(sample_for_review(&ConversionReport::default(), 5, 7).is_empty());
+
+        let first = &sample_for_review(&report, 100, 7)[0];
+        assert_eq!(
+            (first.before.as_str(), first.after.as_str()),
+            ("", "τὸ σπίτι καὶ ἡ αὐλὴ")
+        );
+    }
+}
@@ -0,0 +1,175 @@
+//! Romanization of modern Greek, following ELOT 743.
+
+use unicode_normalization::UnicodeNormalization;
+
+use crate::accents::has_diaeresis_char;
+use crate::chars::base_lower;
+
+/// Consonants before which αυ, ευ and ηυ are read af, ef and if.
+const VOICELESS: &str = "θκξπστφχψς";
+
+/// Latin letters of a lowercase Greek letter without diacritics.
+const fn letter(ch: char) -> Option<&'static str> {
+    Some(match ch {
+        'α' => "a",
+        'β' => "v",
+        'γ' => "g",
+        'δ' => "d",
+        'ε' => "e",
+        'ζ' => "z",
+        'η' => "i",
+        'θ' => "th",
+        'ι' => "i",
+        'κ' => "k",
+        'λ' => "l",
+        'μ' => "m",
+        'ν' => "n",
+        'ξ' => "x",
+        'ο' => "o",
+        'π' => "p",
+        'ρ' => "r",
+        'σ' | 'ς' => "s",
+        'τ' => "t",
+        'υ' => "y",
+        'φ' => "f",
+        'χ' => "ch",
+        'ψ' => "ps",
+        'ω' => "o",
+        _ => return None,
+    })
+}
+
+/// Romanize Greek text, following the transcription of ELOT 743 (that of
+/// Greek passports and road signs).
+///
+/// * αυ, ευ and ηυ are av, ev and iv, or af, ef and if before a voiceless
+///   consonant and at the end of a word,
+/// * ου is ou, and γγ, γξ and γχ are ng, nx and nch,
+/// * μπ is b at the start and the end of a word, and mp inside it,
+/// * diacritics are dropped, but a diaeresis or an accent on the first vowel
+///   keeps the vowels apart: αϋπνία is aypnia, άυλος is aylos.
+///
+/// Capitals are kept: a digraph is capitalized (Θ → Th), or in uppercase in
+/// an uppercase word (ΘΕΑ → THEA). Other chars are left untouched.
+///
+/// # Example
+///
+/// ```
+/// use grac::romanize;
+///
+/// assert_eq!(romanize("Καλημέρα, Ελλάδα!"), "Kalimera, Ellada!");
+/// assert_eq!(romanize("αυτοκίνητο"), "aftokinito");
+/// assert_eq!(romanize("ΜΠΟΥΖΟΥΚΙ"), "BOUZOUKI");
+/// assert_eq!(romanize("Θεσσαλονίκη"), "Thessaloniki");
+/// ```
+pub fn romanize(text: impl AsRef<str>) -> String {
+    let chars: Vec<char> = text.as_ref().nfc().collect();
+    let mut out = String::with_capacity(chars.len());
+    let mut rest = chars.as_slice();
+    while !rest.is_empty() {
+        let len = rest
+            .iter()
+            .position(|&ch| letter(base_lower(ch)).is_none())
+            .unwrap_or(rest.len());
+        if len == 0 {
+            out.push(rest[0]);
+            rest = &rest[1..];
+        } else {
+            romanize_word(&rest[..len], &mut out);
+            rest = &rest[len..];
+        }
+    }
+    out
+}
+
+/// Romanize a run of Greek letters.
+fn romanize_word(word: &[char], out: &mut String) {
+    let base: Vec<char> = word.iter().map(|&ch| base_lower(ch)).collect();
+    let uppercase = word.len() > 1 && word.iter().all(|ch| ch.is_uppercase());
+    let mut idx = 0;
+    while idx < word.len() {
+        let (latin, len) = romanize_at(word, &base, idx);
+        if word[idx].is_uppercase() {
+            if uppercase {
+                out.push_str(&latin.to_uppercase());
+            } else {
+                let mut latin = latin.chars();
+                out.extend(latin.next().map(|ch| ch.to_ascii_uppercase()));
+                out.extend(latin);
+            }
+        } else {
+            out.push_str(latin);
+        }
+        idx += len;
+    }
+}
+
+/// Latin letters of the letters at `idx`, with the number of letters they
+/// stand for.
+fn romanize_at(word: &[char], base: &[char], idx: usize) -> (&'static str, usize) {
+    // The second vowel of a diphthong: without diaeresis, and after a vowel
+    // without accent.
+    let diphthong_y = base.get(idx + 1) == Some(&'υ')
+        && !has_diaeresis_char(word[idx + 1])
+        && word[idx].to_lowercase().eq([base[idx]]);
+    match (base[idx], base.get(idx + 1)) {
+        (vowel @ ('α' | 'ε' | 'η'), _) if diphthong_y => {
+            let voiceless = base.get(idx + 2).is_none_or(|ch| VOICELESS.contains(*ch));
+            let latin = match (vowel, voiceless) {
+                ('α', false) => "av",
+                ('α', true) => "af",
+                ('ε', false) => "ev",
+                ('ε', true) => "ef",
+                ('η', false) => "iv",
+                _ => "if",
+            };
+            (latin, 2)
+        }
+        ('ο', _) if diphthong_y => ("ou", 2),
+        ('μ', Some('π')) if idx == 0 || idx + 2 == base.len() => ("b", 2),
+        ('γ', Some('γ')) => ("ng", 2),
+        ('γ', Some('ξ')) => ("nx", 2),
+        ('γ', Some('χ')) => ("nch", 2),
+        (ch, _) => (letter(ch).unwrap_or_default(), 1),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_romanize() {
+        let cases = [
+            ("", ""),
+            ("αυγό", "avgo"),
+            ("αύριο", "avrio"),
+            ("ευχαριστώ", "efcharisto"),
+            ("Εύβοια", "Evvoia"),
+            ("ηύρα", "ivra"),
+            ("μαυ", "maf"),
+            ("αϋπνία", "aypnia"),
+            ("άυλος", "aylos"),
+            ("προϋπόθεση", "proypothesi"),
+            ("μπαμπάς", "bampas"),
+            ("λάμπ", "lab"),
+            ("άγγελος", "angelos"),
+            ("σφίγξ", "sfinx"),
+            ("μελαγχολία", "melancholia"),
+            ("αγκάθι", "agkathi"),
+            ("ψυχή", "psychi"),
+            ("ντομάτα", "ntomata"),
+            ("Χανιά", "Chania"),
+            ("ΧΑΝΙΑ", "CHANIA"),
+            ("Ψ", "Ps"),
+            ("ΑΥΓΟ", "AVGO"),
+            ("Ἀθῆναι", "Athinai"),
+            // Decomposed
+            ("α\u{301}υλος", "aylos"),
+            ("abc 123 αβγ;", "abc 123 avg;"),
+        ];
+        for (text, expected) in cases {
+            assert_eq!(romanize(text), expected, "{text}");
+        }
+    }
+}
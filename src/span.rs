@@ -0,0 +1,147 @@
+//! Spans and offset encodings.
+//!
+//! Spans are always computed as byte ranges into the input. Editors (VS Code,
+//! LSP) count in UTF-16 code units instead, so every span-returning API has a
+//! `_utf16` variant that re-encodes the offsets.
+
+use std::ops::Range;
+
+/// Unit in which offsets are counted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum SpanEncoding {
+    /// Bytes (native Rust `&str` indices).
+    #[default]
+    Utf8,
+    /// UTF-16 code units (JavaScript, VS Code, LSP).
+    Utf16,
+    /// Unicode scalar values (Python `str` indices).
+    Chars,
+}
+
+/// A replacement of the bytes at `span` (in the original text) by `new`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Change {
+    pub span: Range<usize>,
+    pub old: String,
+    pub new: String,
+}
+
+/// Converts byte offsets into another [`SpanEncoding`].
+///
+/// Offsets are resolved incrementally, so encoding them in increasing order
+/// costs a single pass over the text.
+pub struct OffsetEncoder<'a> {
+    text: &'a str,
+    encoding: SpanEncoding,
+    byte: usize,
+    unit: usize,
+}
+
+impl<'a> OffsetEncoder<'a> {
+    pub const fn new(text: &'a str, encoding: SpanEncoding) -> Self {
+        Self {
+            text,
+            encoding,
+            byte: 0,
+            unit: 0,
+        }
+    }
+
+    /// Encode a byte offset.
+    ///
+    /// Offsets that are not on a char boundary are rounded down to one.
+    pub fn encode(&mut self, byte: usize) -> usize {
+        let byte = byte.min(self.text.len());
+        if self.encoding == SpanEncoding::Utf8 {
+            return byte;
+        }
+        if byte < self.byte {
+            self.byte = 0;
+            self.unit = 0;
+        }
+        for ch in self.text[self.byte..].chars() {
+            if self.byte + ch.len_utf8() > byte {
+                break;
+            }
+            self.byte += ch.len_utf8();
+            self.unit += match self.encoding {
+                SpanEncoding::Utf16 => ch.len_utf16(),
+                _ => 1,
+            };
+        }
+        self.unit
+    }
+
+    pub fn encode_span(&mut self, span: Range<usize>) -> Range<usize> {
+        self.encode(span.start)..self.encode(span.end)
+    }
+}
+
+/// Re-encode byte spans of `text`.
+///
+/// # Example
+///
+/// ```
+/// use grac::{SpanEncoding, encode_spans};
+///
+/// let text = "𝔸 και";
+/// assert_eq!(encode_spans(text, [5..11], SpanEncoding::Utf16), [3..6]);
+/// assert_eq!(encode_spans(text, [5..11], SpanEncoding::Chars), [2..5]);
+/// ```
+pub fn encode_spans<I>(text: &str, spans: I, encoding: SpanEncoding) -> Vec<Range<usize>>
+where
+    I: IntoIterator<Item = Range<usize>>,
+{
+    let mut encoder = OffsetEncoder::new(text, encoding);
+    spans
+        .into_iter()
+        .map(|span| encoder.encode_span(span))
+        .collect()
+}
+
+/// Re-encode the spans of changes made to `text`.
+pub fn encode_changes(text: &str, changes: Vec<Change>, encoding: SpanEncoding) -> Vec<Change> {
+    let mut encoder = OffsetEncoder::new(text, encoding);
+    changes
+        .into_iter()
+        .map(|change| Change {
+            span: encoder.encode_span(change.span),
+            ..change
+        })
+        .collect()
+}
+
+/// Apply changes, sorted and without overlaps, to the text they were made to.
+pub(crate) fn apply_changes(text: &str, changes: &[Change]) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut start = 0;
+    for change in changes {
+        out.push_str(&text[start..change.span.start]);
+        out.push_str(&change.new);
+        start = change.span.end;
+    }
+    out.push_str(&text[start..]);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_out_of_order() {
+        let text = "αβγ";
+        let mut encoder = OffsetEncoder::new(text, SpanEncoding::Chars);
+        assert_eq!(encoder.encode(4), 2);
+        assert_eq!(encoder.encode(2), 1);
+        assert_eq!(encoder.encode(6), 3);
+        assert_eq!(encoder.encode(100), 3);
+    }
+
+    #[test]
+    fn test_encode_not_char_boundary() {
+        let mut encoder = OffsetEncoder::new("αβγ", SpanEncoding::Utf16);
+        assert_eq!(encoder.encode(3), 1);
+    }
+}
@@ -0,0 +1,259 @@
+//! Edit distance for spell checking.
+
+use unicode_normalization::UnicodeNormalization;
+
+use crate::chars::base_lower;
+
+/// Spellings of the same sound, that are common misspellings of each other.
+const HOMOPHONES: [&[&str]; 3] = [
+    &["ι", "η", "υ", "ει", "οι", "υι"],
+    &["ο", "ω"],
+    &["ε", "αι"],
+];
+
+/// Costs of [`greek_edit_distance_with_costs`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct EditCosts {
+    /// Insertion, deletion or substitution of a letter.
+    pub edit: u32,
+    /// Letters that only differ by their diacritics: ά / α.
+    pub accent: u32,
+    /// Letters that only differ by their case: Α / α.
+    pub case: u32,
+    /// Final and medial sigma: ς / σ.
+    pub final_sigma: u32,
+    /// Spellings of the same vowel: η / ι / υ / ει / οι, ο / ω, ε / αι.
+    pub homophone: u32,
+}
+
+impl EditCosts {
+    pub const fn new() -> Self {
+        Self {
+            edit: 10,
+            accent: 1,
+            case: 1,
+            final_sigma: 1,
+            homophone: 4,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_edit(mut self, edit: u32) -> Self {
+        self.edit = edit;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_accent(mut self, accent: u32) -> Self {
+        self.accent = accent;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_case(mut self, case: u32) -> Self {
+        self.case = case;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_final_sigma(mut self, final_sigma: u32) -> Self {
+        self.final_sigma = final_sigma;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_homophone(mut self, homophone: u32) -> Self {
+        self.homophone = homophone;
+        self
+    }
+}
+
+impl Default for EditCosts {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+const DEFAULT_COSTS: &EditCosts = &EditCosts::new();
+
+/// Edit distance where the usual Greek misspellings are cheap.
+///
+/// See [`EditCosts`] for the default costs.
+///
+/// # Example
+///
+/// ```
+/// use grac::greek_edit_distance;
+///
+/// assert_eq!(greek_edit_distance("καλημέρα", "καλημέρα"), 0);
+/// // Missing accent
+/// assert_eq!(greek_edit_distance("καλημερα", "καλημέρα"), 1);
+/// // η for ι
+/// assert_eq!(greek_edit_distance("καλιμέρα", "καλημέρα"), 4);
+/// // ει for ι
+/// assert_eq!(greek_edit_distance("καλειμέρα", "καλημέρα"), 4);
+/// // A different letter
+/// assert_eq!(greek_edit_distance("καλημέρς", "καλημέρα"), 10);
+/// ```
+pub fn greek_edit_distance(a: impl AsRef<str>, b: impl AsRef<str>) -> u32 {
+    greek_edit_distance_with_costs(a, b, DEFAULT_COSTS)
+}
+
+/// Same as [`greek_edit_distance`], with custom costs.
+pub fn greek_edit_distance_with_costs(
+    a: impl AsRef<str>,
+    b: impl AsRef<str>,
+    costs: &EditCosts,
+) -> u32 {
+    let a: Vec<char> = a.as_ref().nfc().collect();
+    let b: Vec<char> = b.as_ref().nfc().collect();
+
+    // dist[i][j] is the distance between a[..i] and b[..j]
+    let mut dist = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for i in 0..=a.len() {
+        for j in 0..=b.len() {
+            if i == 0 || j == 0 {
+                dist[i][j] = (i + j) as u32 * costs.edit;
+                continue;
+            }
+            let mut best = (dist[i - 1][j] + costs.edit)
+                .min(dist[i][j - 1] + costs.edit)
+                .min(dist[i - 1][j - 1] + char_cost(a[i - 1], b[j - 1], costs));
+            for (la, lb) in [(1, 1), (1, 2), (2, 1), (2, 2)] {
+                if la <= i
+                    && lb <= j
+                    && let Some(cost) = homophone_cost(&a[i - la..i], &b[j - lb..j], costs)
+                {
+                    best = best.min(dist[i - la][j - lb] + cost);
+                }
+            }
+            dist[i][j] = best;
+        }
+    }
+    dist[a.len()][b.len()]
+}
+
+/// Sort candidates by [`greek_edit_distance`] to `word`.
+///
+/// The sort is stable: candidates at the same distance keep their order.
+///
+/// # Example
+///
+/// ```
+/// use grac::rank_corrections;
+///
+/// let ranked = rank_corrections("καλιμερα", ["καλαμαρα", "καλημέρα", "καλημερα"]);
+/// assert_eq!(ranked[0], ("καλημερα", 4));
+/// assert_eq!(ranked[1], ("καλημέρα", 5));
+/// ```
+pub fn rank_corrections<S: AsRef<str>>(
+    word: impl AsRef<str>,
+    candidates: impl IntoIterator<Item = S>,
+) -> Vec<(S, u32)> {
+    let word = word.as_ref();
+    let mut ranked: Vec<(S, u32)> = candidates
+        .into_iter()
+        .map(|candidate| {
+            let distance = greek_edit_distance(word, candidate.as_ref());
+            (candidate, distance)
+        })
+        .collect();
+    ranked.sort_by_key(|(_, distance)| *distance);
+    ranked
+}
+
+/// Lowercase letter without diacritics, and with a medial sigma.
+const fn fold(ch: char) -> char {
+    match base_lower(ch) {
+        'ς' => 'σ',
+        ch => ch,
+    }
+}
+
+fn marks(chars: &[char]) -> impl Iterator<Item = char> + '_ {
+    chars
+        .iter()
+        .flat_map(|ch| std::iter::once(*ch).nfd().skip(1))
+}
+
+fn char_cost(a: char, b: char, costs: &EditCosts) -> u32 {
+    if a == b {
+        return 0;
+    }
+    if fold(a) != fold(b) {
+        return costs.edit;
+    }
+    let mut cost = 0;
+    if marks(&[a]).ne(marks(&[b])) {
+        cost += costs.accent;
+    }
+    if a.is_uppercase() != b.is_uppercase() {
+        cost += costs.case;
+    }
+    if base_lower(a) != base_lower(b) {
+        cost += costs.final_sigma;
+    }
+    cost.min(costs.edit)
+}
+
+/// Cost of replacing a spelling of a vowel by another spelling.
+fn homophone_cost(a: &[char], b: &[char], costs: &EditCosts) -> Option<u32> {
+    let folded_a: String = a.iter().map(|ch| fold(*ch)).collect();
+    let folded_b: String = b.iter().map(|ch| fold(*ch)).collect();
+    if folded_a == folded_b {
+        return None;
+    }
+    HOMOPHONES
+        .iter()
+        .any(|group| group.contains(&folded_a.as_str()) && group.contains(&folded_b.as_str()))
+        .then(|| {
+            let accent = if marks(a).ne(marks(b)) {
+                costs.accent
+            } else {
+                0
+            };
+            costs.homophone + accent
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_greek_edit_distance() {
+        let cases = [
+            ("", "", 0),
+            ("", "και", 30),
+            ("ΚΑΙ", "και", 3),
+            ("λογος", "λογοσ", 1),
+            ("Ἄνθρωπος", "ανθρωπος", 2),
+            ("ωραίο", "οραίο", 4),
+            ("έλα", "αίλα", 4),
+            ("ήταν", "ίταν", 4),
+            ("οικονομία", "ικονομία", 4),
+            // Both a vowel confusion and a missing accent
+            ("είναι", "ινε", 9),
+        ];
+        for (a, b, expected) in cases {
+            assert_eq!(greek_edit_distance(a, b), expected, "{a} {b}");
+            assert_eq!(greek_edit_distance(b, a), expected, "{b} {a}");
+        }
+    }
+
+    #[test]
+    fn test_greek_edit_distance_decomposed() {
+        let decomposed: String = "καλημέρα".nfd().collect();
+        assert_eq!(greek_edit_distance(&decomposed, "καλημέρα"), 0);
+    }
+
+    #[test]
+    fn test_custom_costs() {
+        let costs = EditCosts::new().with_accent(0);
+        assert_eq!(
+            greek_edit_distance_with_costs("καλημερα", "καλημέρα", &costs),
+            0
+        );
+    }
+}
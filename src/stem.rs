@@ -0,0 +1,1307 @@
+//! Stemming of modern Greek, for search indexing.
+//!
+//! A port of the [Snowball Greek stemmer](https://snowballstem.org/algorithms/greek/stemmer.html),
+//! which implements the algorithm of:
+//!
+//! * Ntais, Georgios. Development of a Stemmer for the Greek Language.
+//!   Royal Institute of Technology, 2006.
+//! * Saroukos, Spyridon. Enhancing a Greek language stemmer. University of
+//!   Tampere, 2008.
+//!
+//! The suffix lists are those of the Snowball implementation (Copyright (c)
+//! 2001, Dr Martin Porter, Copyright (c) 2004,2005, Richard Boulton, under
+//! the BSD 3-clause license), without diacritics and with a medial sigma
+//! only, like the words they are matched against.
+
+use crate::chars::{base_lower, is_combining_mark};
+
+/// Vowels, for steps 3 and 4.
+const VOWELS: &str = "αεηιουω";
+
+/// Vowels without upsilon, for steps 5b and 5c.
+const VOWELS_NO_Y: &str = "αεηιοω";
+
+/// A condition on the stem left by a [`Rule`].
+enum Cond {
+    /// The stem is one of these.
+    Is(&'static [&'static str]),
+    /// The stem ends with one of these.
+    EndsWith(&'static [&'static str]),
+    /// The stem ends with one of these vowels.
+    Vowel(&'static str),
+}
+
+/// A step that removes the longest of some suffixes, and then adds back part
+/// of it after the stems of the first condition they meet.
+struct Rule {
+    suffixes: &'static [&'static str],
+    /// Removing the suffix keeps step 6 from running.
+    unset_test1: bool,
+    restore: &'static [(Cond, &'static str)],
+}
+
+const STEPS1: Rule = Rule {
+    suffixes: &[
+        "ιζα",
+        "ιζεσ",
+        "ιζε",
+        "ιζαμε",
+        "ιζατε",
+        "ιζαν",
+        "ιζανε",
+        "ιζω",
+        "ιζεισ",
+        "ιζει",
+        "ιζουμε",
+        "ιζετε",
+        "ιζουν",
+        "ιζουνε",
+    ],
+    unset_test1: true,
+    restore: &[
+        (
+            Cond::Is(&[
+                "αναμπα",
+                "εμπα",
+                "επα",
+                "ξαναπα",
+                "πα",
+                "περιπα",
+                "αθρο",
+                "συναθρο",
+                "δανε",
+            ]),
+            "ι",
+        ),
+        (
+            Cond::Is(&[
+                "μαρκ",
+                "κορν",
+                "αμπαρ",
+                "αρρ",
+                "βαθυρι",
+                "βαρκ",
+                "β",
+                "βολβορ",
+                "γκρ",
+                "γλυκορ",
+                "γλυκυρ",
+                "ιμπ",
+                "λ",
+                "λου",
+                "μαρ",
+                "μ",
+                "πρ",
+                "μπρ",
+                "πολυρ",
+                "π",
+                "ρ",
+                "πιπερορ",
+            ]),
+            "ιζ",
+        ),
+    ],
+};
+
+const STEPS2: Rule = Rule {
+    suffixes: &[
+        "ωθηκα",
+        "ωθηκεσ",
+        "ωθηκε",
+        "ωθηκαμε",
+        "ωθηκατε",
+        "ωθηκαν",
+        "ωθηκανε",
+    ],
+    unset_test1: true,
+    restore: &[(
+        Cond::Is(&["αλ", "βι", "εν", "υψ", "λι", "ζω", "σ", "χ"]),
+        "ων",
+    )],
+};
+
+/// Stems that take back an iota, in steps s3 and s4.
+const STEMS_Y: &[&str] = &[
+    "αναμπα",
+    "αθρο",
+    "εμπα",
+    "εσε",
+    "εσωκλε",
+    "επα",
+    "ξαναπα",
+    "επε",
+    "περιπα",
+    "συναθρο",
+    "δανε",
+    "κλε",
+    "χαρτοπα",
+    "εξαρχα",
+    "μετεπε",
+    "αποκλε",
+    "απεκλε",
+    "εκλε",
+    "πε",
+];
+
+// The reference also turns a stem ισα into ισ, which only nonwords such as
+// ισαισα leave.
+const STEPS3: Rule = Rule {
+    suffixes: &["ισα", "ισεσ", "ισε", "ισαμε", "ισατε", "ισαν", "ισανε"],
+    unset_test1: true,
+    restore: &[
+        (Cond::Is(STEMS_Y), "ι"),
+        (
+            Cond::Is(&[
+                "αν",
+                "αφ",
+                "γε",
+                "γιγαντοαφ",
+                "γκε",
+                "δημοκρατ",
+                "κομ",
+                "γκ",
+                "μ",
+                "π",
+                "πουκαμ",
+                "ολο",
+                "λαρ",
+            ]),
+            "ισ",
+        ),
+    ],
+};
+
+const STEPS4: Rule = Rule {
+    suffixes: &["ισω", "ισεισ", "ισει", "ισουμε", "ισετε", "ισουν", "ισουνε"],
+    unset_test1: true,
+    restore: &[(Cond::Is(STEMS_Y), "ι")],
+};
+
+const STEPS5: Rule = Rule {
+    suffixes: &[
+        "ιστοσ",
+        "ιστου",
+        "ιστο",
+        "ιστε",
+        "ιστοι",
+        "ιστων",
+        "ιστουσ",
+        "ιστη",
+        "ιστησ",
+        "ιστα",
+        "ιστεσ",
+    ],
+    unset_test1: true,
+    restore: &[
+        (
+            Cond::Is(&["δανε", "συναθρο", "κλε", "σε", "εσωκλε", "ασε", "πλε"]),
+            "ι",
+        ),
+        (
+            Cond::Is(&[
+                "μ", "π", "απ", "αρ", "ηδ", "κτ", "σκ", "σχ", "υψ", "φα", "χρ", "χτ", "ακτ", "αορ",
+                "ασχ", "ατα", "αχν", "αχτ", "γεμ", "γυρ", "εμπ", "ευπ", "εχθ", "ηφα", "καθ", "κακ",
+                "κυλ", "λυγ", "μακ", "μεγ", "ταχ", "φιλ", "χωρ",
+            ]),
+            "ιστ",
+        ),
+    ],
+};
+
+const STEPS6: Rule = Rule {
+    suffixes: &["ισμο", "ισμοι", "ισμοσ", "ισμου", "ισμουσ", "ισμων"],
+    unset_test1: true,
+    restore: &[
+        (
+            Cond::Is(&["σε", "μετασε", "μικροσε", "εγκλε", "αποκλε"]),
+            "ισμ",
+        ),
+        (Cond::Is(&["δανε", "αντιδανε"]), "ι"),
+    ],
+};
+
+/// Adjectives in -ικ and -ιν whose ending goes with -ισμός, after [`STEPS6`].
+const STEPS6_ADJECTIVES: &[(&[&str], &str)] = &[
+    (&["αγνωστικ"], "αγνωστ"),
+    (&["ατομικ"], "ατομ"),
+    (&["γνωστικ"], "γνωστ"),
+    (&["εθνικ"], "εθν"),
+    (&["εκλεκτικ"], "εκλεκτ"),
+    (&["σκεπτικ"], "σκεπτ"),
+    (&["τοπικ"], "τοπ"),
+    (&["αλεξανδριν"], "αλεξανδρ"),
+    (&["βυζαντιν"], "βυζαντ"),
+    (&["θεατριν"], "θεατρ"),
+];
+
+const STEPS7: Rule = Rule {
+    suffixes: &["αρακι", "αρακια", "ουδακι", "ουδακια"],
+    unset_test1: true,
+    restore: &[(Cond::Is(&["σ", "χ"]), "αρακ")],
+};
+
+const STEPS8: Rule = Rule {
+    suffixes: &[
+        "ακι",
+        "ακια",
+        "ιτσα",
+        "ιτσασ",
+        "ιτσεσ",
+        "ιτσων",
+        "αρακι",
+        "αρακια",
+    ],
+    unset_test1: true,
+    restore: &[
+        (
+            Cond::Is(&[
+                "βαμβ",
+                "βρ",
+                "καιμ",
+                "κον",
+                "κορ",
+                "λαβρ",
+                "λουλ",
+                "μερ",
+                "μουστ",
+                "ναγκασ",
+                "πλ",
+                "ρ",
+                "ρυ",
+                "σ",
+                "σκ",
+                "σοκ",
+                "σπαν",
+                "τζ",
+                "φαρμ",
+                "χ",
+                "καπακ",
+                "αλισφ",
+                "αμβρ",
+                "ανθρ",
+                "κ",
+                "φυλ",
+                "κατραπ",
+                "κλιμ",
+                "μαλ",
+                "σλοβ",
+                "φ",
+                "σφ",
+                "τσεχοσλοβ",
+            ]),
+            "ακ",
+        ),
+        (
+            Cond::Is(&[
+                "β",
+                "βαλ",
+                "γιαν",
+                "γλ",
+                "ζ",
+                "ηγουμεν",
+                "καρδ",
+                "κον",
+                "μακρυν",
+                "νυφ",
+                "πατερ",
+                "π",
+                "σκ",
+                "τοσ",
+                "τριπολ",
+            ]),
+            "ιτσ",
+        ),
+        (Cond::EndsWith(&["κορ"]), "ιτσ"),
+    ],
+};
+
+const STEPS9: Rule = Rule {
+    suffixes: &["ιδιο", "ιδια", "ιδιων"],
+    unset_test1: true,
+    restore: &[
+        (Cond::Is(&["αιφν", "ιρ", "ολο", "ψαλ"]), "ιδ"),
+        (Cond::EndsWith(&["ε", "παιχν"]), "ιδ"),
+    ],
+};
+
+const STEPS10: Rule = Rule {
+    suffixes: &["ισκοσ", "ισκου", "ισκο", "ισκε"],
+    unset_test1: true,
+    restore: &[(
+        Cond::Is(&["δ", "ιβ", "μην", "ρ", "φραγκ", "λυκ", "οβελ"]),
+        "ισκ",
+    )],
+};
+
+const STEP2B: Rule = Rule {
+    suffixes: &["εδεσ", "εδων"],
+    unset_test1: false,
+    restore: &[(
+        Cond::EndsWith(&["οπ", "ιπ", "εμπ", "υπ", "γηπ", "δαπ", "κρασπ", "μιλ"]),
+        "εδ",
+    )],
+};
+
+const STEP2C: Rule = Rule {
+    suffixes: &["ουδεσ", "ουδων"],
+    unset_test1: false,
+    restore: &[(
+        Cond::EndsWith(&[
+            "αρκ",
+            "καλιακ",
+            "πεταλ",
+            "λιχ",
+            "πλεξ",
+            "σκ",
+            "σ",
+            "φλ",
+            "φρ",
+            "βελ",
+            "λουλ",
+            "χν",
+            "σπ",
+            "τραγ",
+            "φε",
+        ]),
+        "ουδ",
+    )],
+};
+
+const STEP2D: Rule = Rule {
+    suffixes: &["εωσ", "εων"],
+    unset_test1: true,
+    restore: &[(
+        Cond::Is(&["θ", "δ", "ελ", "γαλ", "ν", "π", "ιδ", "παρ"]),
+        "ε",
+    )],
+};
+
+const STEP3: Rule = Rule {
+    suffixes: &["ια", "ιου", "ιων"],
+    unset_test1: true,
+    restore: &[(Cond::Vowel(VOWELS), "ι")],
+};
+
+const STEP4: Rule = Rule {
+    suffixes: &["ικα", "ικο", "ικου", "ικων"],
+    unset_test1: true,
+    restore: &[
+        (Cond::Vowel(VOWELS), "ικ"),
+        (
+            Cond::Is(&[
+                "αλ",
+                "αδ",
+                "ενδ",
+                "αμαν",
+                "αμμοχαλ",
+                "ηθ",
+                "ανηθ",
+                "αντιδ",
+                "φυσ",
+                "βρωμ",
+                "γερ",
+                "εξωδ",
+                "καλπ",
+                "καλλιν",
+                "καταδ",
+                "μουλ",
+                "μπαν",
+                "μπαγιατ",
+                "μπολ",
+                "μποσ",
+                "νιτ",
+                "ξικ",
+                "συνομηλ",
+                "πετσ",
+                "πιτσ",
+                "πικαντ",
+                "πλιατσ",
+                "ποστελν",
+                "πρωτοδ",
+                "σερτ",
+                "συναδ",
+                "τσαμ",
+                "υποδ",
+                "φιλον",
+                "φυλοδ",
+                "χασ",
+            ]),
+            "ικ",
+        ),
+    ],
+};
+
+const STEP5A_PREFIX: Rule = Rule {
+    suffixes: &["αγαμε", "ησαμε", "ουσαμε", "ηκαμε", "ηθηκαμε"],
+    unset_test1: true,
+    restore: &[],
+};
+
+const STEP5A: Rule = Rule {
+    suffixes: &["αμε"],
+    unset_test1: true,
+    restore: &[(
+        Cond::Is(&[
+            "αναπ",
+            "αποθ",
+            "αποκ",
+            "αποστ",
+            "βουβ",
+            "ξεθ",
+            "ουλ",
+            "πεθ",
+            "πικρ",
+            "ποτ",
+            "σιχ",
+            "χ",
+        ]),
+        "αμ",
+    )],
+};
+
+const STEP5B_PREFIX: Rule = Rule {
+    suffixes: &[
+        "αγανε",
+        "ησανε",
+        "ουσανε",
+        "ιοντανε",
+        "ιοτανε",
+        "ιουντανε",
+        "οντανε",
+        "οτανε",
+        "ουντανε",
+        "ηκανε",
+        "ηθηκανε",
+    ],
+    unset_test1: true,
+    restore: &[(Cond::Is(&["τρ", "τσ"]), "αγαν")],
+};
+
+const STEP5B: Rule = Rule {
+    suffixes: &["ανε"],
+    unset_test1: true,
+    restore: &[
+        (Cond::Vowel(VOWELS_NO_Y), "αν"),
+        (
+            Cond::Is(&[
+                "βετερ",
+                "βουλκ",
+                "βραχμ",
+                "γ",
+                "δραδουμ",
+                "θ",
+                "καλπουζ",
+                "καστελ",
+                "κορμορ",
+                "λαοπλ",
+                "μωαμεθ",
+                "μ",
+                "μουσουλμ",
+                "ν",
+                "ουλ",
+                "π",
+                "πελεκ",
+                "πλ",
+                "πολισ",
+                "πορτολ",
+                "σαρακατσ",
+                "σουλτ",
+                "τσαρλατ",
+                "ορφ",
+                "τσιγγ",
+                "τσοπ",
+                "φωτοστεφ",
+                "χ",
+                "ψυχοπλ",
+                "αγ",
+                "γαλ",
+                "γερ",
+                "δεκ",
+                "διπλ",
+                "αμερικαν",
+                "ουρ",
+                "πιθ",
+                "πουριτ",
+                "σ",
+                "ζωντ",
+                "ικ",
+                "καστ",
+                "κοπ",
+                "λιχ",
+                "λουθηρ",
+                "μαιντ",
+                "μελ",
+                "σιγ",
+                "σπ",
+                "στεγ",
+                "τραγ",
+                "τσαγ",
+                "φ",
+                "ερ",
+                "αδαπ",
+                "αθιγγ",
+                "αμηχ",
+                "ανικ",
+                "ανοργ",
+                "απηγ",
+                "απιθ",
+                "ατσιγγ",
+                "βασ",
+                "βασκ",
+                "βαθυγαλ",
+                "βιομηχ",
+                "βραχυκ",
+                "διατ",
+                "διαφ",
+                "ενοργ",
+                "θυσ",
+                "καπνοβιομηχ",
+                "καταγαλ",
+                "κλιβ",
+                "κοιλαρφ",
+                "λιβ",
+                "μεγλοβιομηχ",
+                "μικροβιομηχ",
+                "νταβ",
+                "ξηροκλιβ",
+                "ολιγοδαμ",
+                "ολογαλ",
+                "πενταρφ",
+                "περηφ",
+                "περιτρ",
+                "πλατ",
+                "πολυδαπ",
+                "πολυμηχ",
+                "στεφ",
+                "ταβ",
+                "τετ",
+                "υπερηφ",
+                "υποκοπ",
+                "χαμηλοδαπ",
+                "ψηλοταβ",
+            ]),
+            "αν",
+        ),
+    ],
+};
+
+const STEP5C_PREFIX: Rule = Rule {
+    suffixes: &["ησετε"],
+    unset_test1: true,
+    restore: &[],
+};
+
+const STEP5C: Rule = Rule {
+    suffixes: &["ετε"],
+    unset_test1: true,
+    restore: &[
+        (Cond::Vowel(VOWELS_NO_Y), "ετ"),
+        (
+            Cond::EndsWith(&[
+                "οδ",
+                "αιρ",
+                "φορ",
+                "ταθ",
+                "διαθ",
+                "σχ",
+                "ενδ",
+                "ευρ",
+                "τιθ",
+                "υπερθ",
+                "ραθ",
+                "ενθ",
+                "ροθ",
+                "σθ",
+                "πυρ",
+                "αιν",
+                "συνδ",
+                "συν",
+                "συνθ",
+                "χωρ",
+                "πον",
+                "βρ",
+                "καθ",
+                "ευθ",
+                "εκθ",
+                "νετ",
+                "ρον",
+                "αρκ",
+                "βαρ",
+                "βολ",
+                "ωφελ",
+            ]),
+            "ετ",
+        ),
+        (
+            Cond::Is(&[
+                "αβαρ",
+                "βεν",
+                "εναρ",
+                "αβρ",
+                "αδ",
+                "αθ",
+                "αν",
+                "απλ",
+                "βαρον",
+                "ντρ",
+                "σκ",
+                "κοπ",
+                "μπορ",
+                "νιφ",
+                "παγ",
+                "παρακαλ",
+                "σερπ",
+                "σκελ",
+                "συρφ",
+                "τοκ",
+                "υ",
+                "δ",
+                "εμ",
+                "θαρρ",
+                "θ",
+            ]),
+            "ετ",
+        ),
+    ],
+};
+
+const STEP5D: Rule = Rule {
+    suffixes: &["οντασ", "ωντασ"],
+    unset_test1: true,
+    restore: &[
+        (Cond::Is(&["αρχ"]), "οντ"),
+        (Cond::EndsWith(&["κρε"]), "ωντ"),
+    ],
+};
+
+const STEP5E: Rule = Rule {
+    suffixes: &["ομαστε", "ιομαστε"],
+    unset_test1: true,
+    restore: &[(Cond::Is(&["ον"]), "ομαστ")],
+};
+
+const STEP5F_PREFIX: Rule = Rule {
+    suffixes: &["ιεστε"],
+    unset_test1: true,
+    restore: &[(
+        Cond::Is(&["π", "απ", "συμπ", "ασυμπ", "ακαταπ", "αμεταμφ"]),
+        "ιεστ",
+    )],
+};
+
+const STEP5F: Rule = Rule {
+    suffixes: &["εστε"],
+    unset_test1: true,
+    restore: &[(
+        Cond::Is(&["αλ", "αρ", "εκτελ", "ζ", "μ", "ξ", "παρακαλ", "προ", "νισ"]),
+        "ιεστ",
+    )],
+};
+
+const STEP5G_PREFIX: Rule = Rule {
+    suffixes: &["ηθηκα", "ηθηκεσ", "ηθηκε"],
+    unset_test1: true,
+    restore: &[],
+};
+
+const STEP5G: Rule = Rule {
+    suffixes: &["ηκα", "ηκεσ", "ηκε"],
+    unset_test1: true,
+    restore: &[
+        (
+            Cond::EndsWith(&["σκωλ", "σκουλ", "ναρθ", "σφ", "οθ", "πιθ"]),
+            "ηκ",
+        ),
+        (Cond::Is(&["διαθ", "θ", "παρακαταθ", "προσθ", "συνθ"]), "ηκ"),
+    ],
+};
+
+const STEP5H: Rule = Rule {
+    suffixes: &["ουσα", "ουσεσ", "ουσε"],
+    unset_test1: true,
+    restore: &[
+        (
+            Cond::EndsWith(&[
+                "ποδαρ",
+                "βλεπ",
+                "πανταχ",
+                "φρυδ",
+                "μαντιλ",
+                "μαλλ",
+                "κυματ",
+                "λαχ",
+                "ληγ",
+                "φαγ",
+                "ομ",
+                "πρωτ",
+            ]),
+            "ουσ",
+        ),
+        (
+            Cond::Is(&[
+                "φαρμακ",
+                "χαδ",
+                "αγκ",
+                "αναρρ",
+                "βρομ",
+                "εκλιπ",
+                "λαμπιδ",
+                "λεχ",
+                "μ",
+                "πατ",
+                "ρ",
+                "λ",
+                "μεδ",
+                "μεσαζ",
+                "υποτειν",
+                "αμ",
+                "αιθ",
+                "ανηκ",
+                "δεσποζ",
+                "ενδιαφερ",
+                "δε",
+                "δευτερευ",
+                "καθαρευ",
+                "πλε",
+                "τσα",
+            ]),
+            "ουσ",
+        ),
+    ],
+};
+
+const STEP5I: Rule = Rule {
+    suffixes: &["αγα", "αγεσ", "αγε"],
+    unset_test1: true,
+    restore: &[(Cond::EndsWith(&["κολλ"]), "αγ")],
+};
+
+/// Stems that keep their -αγ in step 5i, unless they end with ψοφ or ναυλοχ.
+const STEP5I_STEMS: [(Cond, &str); 2] = [
+    (
+        Cond::EndsWith(&[
+            "οφ", "πελ", "χορτ", "λλ", "σφ", "ρπ", "φρ", "πρ", "λοχ", "σμην",
+        ]),
+        "αγ",
+    ),
+    (
+        Cond::Is(&[
+            "αβαστ",
+            "πολυφ",
+            "αδηφ",
+            "παμφ",
+            "ρ",
+            "ασπ",
+            "αφ",
+            "αμαλ",
+            "αμαλλι",
+            "ανυστ",
+            "απερ",
+            "ασπαρ",
+            "αχαρ",
+            "δερβεν",
+            "δροσοπ",
+            "ξεφ",
+            "νεοπ",
+            "νομοτ",
+            "ολοπ",
+            "ομοτ",
+            "προστ",
+            "προσωποπ",
+            "συμπ",
+            "συντ",
+            "τ",
+            "υποτ",
+            "χαρ",
+            "αειπ",
+            "αιμοστ",
+            "ανυπ",
+            "αποτ",
+            "αρτιπ",
+            "διατ",
+            "εν",
+            "επιτ",
+            "κροκαλοπ",
+            "σιδηροπ",
+            "λ",
+            "ναυ",
+            "ουλαμ",
+            "ουρ",
+            "π",
+            "τρ",
+            "μ",
+        ]),
+        "αγ",
+    ),
+];
+
+const STEP5J: Rule = Rule {
+    suffixes: &["ησε", "ησου", "ησα"],
+    unset_test1: true,
+    restore: &[(
+        Cond::Is(&["ν", "χερσον", "δωδεκαν", "ερημον", "μεγαλον", "επταν"]),
+        "ησ",
+    )],
+};
+
+const STEP5K: Rule = Rule {
+    suffixes: &["ηστε"],
+    unset_test1: true,
+    restore: &[(
+        Cond::Is(&[
+            "ασβ",
+            "σβ",
+            "αχρ",
+            "χρ",
+            "απλ",
+            "αειμν",
+            "δυσχρ",
+            "ευχρ",
+            "κοινοχρ",
+            "παλιμψ",
+        ]),
+        "ηστ",
+    )],
+};
+
+const STEP5L: Rule = Rule {
+    suffixes: &["ουνε", "ησουνε", "ηθουνε"],
+    unset_test1: true,
+    restore: &[(
+        Cond::Is(&["ν", "ρ", "σπι", "στραβομουτσ", "κακομουτσ", "εξων"]),
+        "ουν",
+    )],
+};
+
+const STEP5M: Rule = Rule {
+    suffixes: &["ουμε", "ησουμε", "ηθουμε"],
+    unset_test1: true,
+    restore: &[(
+        Cond::Is(&["παρασουσ", "φ", "χ", "ωριοπλ", "αζ", "αλλοσουσ", "ασουσ"]),
+        "ουμ",
+    )],
+};
+
+/// Irregular nouns and compounds, replaced by their stem in step 1.
+const STEP1: &[(&[&str], &str)] = &[
+    (&["φαγια", "φαγιου", "φαγιων"], "φα"),
+    (&["σκαγια", "σκαγιου", "σκαγιων"], "σκα"),
+    (&["ολογιου", "ολογια", "ολογιων"], "ολο"),
+    (&["σογιου", "σογια", "σογιων"], "σο"),
+    (&["τατογια", "τατογιου", "τατογιων"], "τατο"),
+    (&["κρεασ", "κρεατοσ", "κρεατα", "κρεατων"], "κρε"),
+    (&["περασ", "περατοσ", "περατη", "περατα", "περατων"], "περ"),
+    (&["τερασ", "τερατοσ", "τερατα", "τερατων"], "τερ"),
+    (&["φωσ", "φωτοσ", "φωτα", "φωτων"], "φω"),
+    (
+        &["καθεστωσ", "καθεστωτοσ", "καθεστωτα", "καθεστωτων"],
+        "καθεστ",
+    ),
+    (&["γεγονοσ", "γεγονοτοσ", "γεγονοτα", "γεγονοτων"], "γεγον"),
+];
+
+/// Stems that keep their -αδ in step 2a.
+const STEP2A_STEMS: &[&str] = &[
+    "οκ",
+    "μαμ",
+    "μαν",
+    "μπαμπ",
+    "πατερ",
+    "γιαγι",
+    "νταντ",
+    "κυρ",
+    "θει",
+    "πεθερ",
+];
+
+/// Inflectional endings, removed in step 6 if no other step removed a suffix.
+const STEP6: &[&str] = &[
+    "α",
+    "αγατε",
+    "αγαν",
+    "αει",
+    "αμαι",
+    "αν",
+    "ασ",
+    "ασαι",
+    "αται",
+    "αω",
+    "ε",
+    "ει",
+    "εισ",
+    "ειτε",
+    "εσαι",
+    "εσ",
+    "εται",
+    "ι",
+    "ιεμαι",
+    "ιεμαστε",
+    "ιεται",
+    "ιεσαι",
+    "ιεσαστε",
+    "ιομασταν",
+    "ιομουν",
+    "ιομουνα",
+    "ιονταν",
+    "ιοντουσαν",
+    "ιοσασταν",
+    "ιοσαστε",
+    "ιοσουν",
+    "ιοσουνα",
+    "ιοταν",
+    "ιουμα",
+    "ιουμαστε",
+    "ιουνται",
+    "ιουνταν",
+    "η",
+    "ηδεσ",
+    "ηδων",
+    "ηθει",
+    "ηθεισ",
+    "ηθειτε",
+    "ηθηκατε",
+    "ηθηκαν",
+    "ηθουν",
+    "ηθω",
+    "ηκατε",
+    "ηκαν",
+    "ησ",
+    "ησαν",
+    "ησατε",
+    "ησει",
+    "ησεσ",
+    "ησουν",
+    "ησω",
+    "ο",
+    "οι",
+    "ομαι",
+    "ομασταν",
+    "ομουν",
+    "ομουνα",
+    "ονται",
+    "ονταν",
+    "οντουσαν",
+    "οσ",
+    "οσασταν",
+    "οσαστε",
+    "οσουν",
+    "οσουνα",
+    "οταν",
+    "ου",
+    "ουμαι",
+    "ουμαστε",
+    "ουν",
+    "ουνται",
+    "ουνταν",
+    "ουσ",
+    "ουσαν",
+    "ουσατε",
+    "υ",
+    "υσ",
+    "ω",
+    "ων",
+];
+
+/// Suffixes of comparatives and superlatives, removed in step 7.
+const STEP7: &[&str] = &[
+    "εστερ",
+    "εστατ",
+    "οτερ",
+    "οτατ",
+    "υτερ",
+    "υτατ",
+    "ωτερ",
+    "ωτατ",
+];
+
+/// Minimum number of chars of a word for it to be stemmed.
+const MIN_LEN: usize = 3;
+
+/// Reduce a word to a stem, so that its inflected forms can be matched.
+///
+/// This is the stemmer of Ntais (2006), as improved by Saroukos (2008) and
+/// implemented by the Snowball project. The word is lowercased, without
+/// diacritics and with a medial sigma only, and words shorter than three
+/// chars are not stemmed further.
+///
+/// Stems are meant to be compared with other stems and are usually not words.
+///
+/// # Example
+///
+/// ```
+/// use grac::stem;
+///
+/// assert_eq!(stem("Αυτοκίνητο"), "αυτοκινητ");
+/// assert_eq!(stem("αυτοκινήτων"), "αυτοκινητ");
+/// assert_eq!(stem("χορδίζουμε"), "χορδ");
+/// assert_eq!(stem("ως"), "ωσ");
+/// ```
+pub fn stem(word: impl AsRef<str>) -> String {
+    let word: String = word
+        .as_ref()
+        .chars()
+        .filter(|&ch| !is_combining_mark(ch))
+        .map(|ch| match base_lower(ch) {
+            'ς' => 'σ',
+            ch => ch,
+        })
+        .collect();
+    if word.chars().count() < MIN_LEN {
+        return word;
+    }
+    let mut stemmer = Stemmer { word, test1: true };
+    stemmer.run();
+    stemmer.word
+}
+
+struct Stemmer {
+    word: String,
+    /// No step before step 6 removed a suffix.
+    test1: bool,
+}
+
+impl Stemmer {
+    fn run(&mut self) {
+        if self.replace_suffix(STEP1) {
+            self.test1 = false;
+        }
+        for rule in [&STEPS1, &STEPS2, &STEPS3, &STEPS4, &STEPS5] {
+            self.apply(rule);
+        }
+        if self.apply(&STEPS6) == Some(false) {
+            self.replace_suffix(STEPS6_ADJECTIVES);
+        }
+        for rule in [&STEPS7, &STEPS8, &STEPS9, &STEPS10] {
+            self.apply(rule);
+        }
+        self.step2a();
+        for rule in [&STEP2B, &STEP2C, &STEP2D, &STEP3, &STEP4] {
+            self.apply(rule);
+        }
+
+        if self.word == "αγαμε" {
+            self.word.truncate(self.word.len() - "ε".len());
+        }
+        self.apply(&STEP5A_PREFIX);
+        self.apply(&STEP5A);
+        self.apply(&STEP5B_PREFIX);
+        self.apply(&STEP5B);
+        self.apply(&STEP5C_PREFIX);
+        for rule in [
+            &STEP5C,
+            &STEP5D,
+            &STEP5E,
+            &STEP5F_PREFIX,
+            &STEP5F,
+            &STEP5G_PREFIX,
+            &STEP5G,
+            &STEP5H,
+            &STEP5J,
+        ] {
+            self.apply(rule);
+        }
+        if self.apply(&STEP5I) == Some(false) && !self.ends_with_any(&["ψοφ", "ναυλοχ"]) {
+            self.restore(&STEP5I_STEMS);
+        }
+        for rule in [&STEP5K, &STEP5L, &STEP5M] {
+            self.apply(rule);
+        }
+
+        self.replace_suffix(&[(&["ματα", "ματων", "ματοσ"], "μα")]);
+        if self.test1 {
+            self.strip(STEP6);
+        }
+        self.strip(STEP7);
+    }
+
+    /// Apply a rule: `None` if none of its suffixes ends the word, otherwise
+    /// whether part of the suffix was added back.
+    fn apply(&mut self, rule: &Rule) -> Option<bool> {
+        if !self.strip(rule.suffixes) {
+            return None;
+        }
+        if rule.unset_test1 {
+            self.test1 = false;
+        }
+        Some(self.restore(rule.restore))
+    }
+
+    /// Append the suffix of the first condition that the stem meets.
+    fn restore(&mut self, restore: &[(Cond, &str)]) -> bool {
+        let found = restore.iter().find(|(cond, _)| match cond {
+            Cond::Is(stems) => stems.contains(&self.word.as_str()),
+            Cond::EndsWith(suffixes) => self.ends_with_any(suffixes),
+            Cond::Vowel(vowels) => self
+                .word
+                .chars()
+                .next_back()
+                .is_some_and(|ch| vowels.contains(ch)),
+        });
+        if let Some((_, suffix)) = found {
+            self.word.push_str(suffix);
+        }
+        found.is_some()
+    }
+
+    /// Remove -αδες and -αδων, keeping the -αδ of most stems.
+    fn step2a(&mut self) {
+        if self.strip(&["αδεσ", "αδων"]) && !self.ends_with_any(STEP2A_STEMS) {
+            self.word.push_str("αδ");
+        }
+    }
+
+    fn ends_with_any(&self, suffixes: &[&str]) -> bool {
+        suffixes.iter().any(|suffix| self.word.ends_with(suffix))
+    }
+
+    /// The longest of the suffixes that ends the word.
+    fn longest_suffix<'a>(&self, suffixes: &[&'a str]) -> Option<&'a str> {
+        suffixes
+            .iter()
+            .copied()
+            .filter(|suffix| self.word.ends_with(suffix))
+            .max_by_key(|suffix| suffix.len())
+    }
+
+    /// Remove the longest of the suffixes that ends the word, if any.
+    fn strip(&mut self, suffixes: &[&str]) -> bool {
+        let Some(suffix) = self.longest_suffix(suffixes) else {
+            return false;
+        };
+        self.word.truncate(self.word.len() - suffix.len());
+        true
+    }
+
+    /// Replace the longest of the suffixes that ends the word by its stem.
+    fn replace_suffix(&mut self, table: &[(&[&str], &str)]) -> bool {
+        let Some((suffix, stem)) = table
+            .iter()
+            .filter_map(|(suffixes, stem)| Some((self.longest_suffix(suffixes)?, *stem)))
+            .max_by_key(|(suffix, _)| suffix.len())
+        else {
+            return false;
+        };
+        self.word.truncate(self.word.len() - suffix.len());
+        self.word.push_str(stem);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stem_reference() {
+        // The test vocabulary of the Snowball implementation
+        let cases = [
+            ("αυτοκινηση", "αυτοκινησ"),
+            ("αυτοκινησης", "αυτοκινησ"),
+            ("αυτοκινητα", "αυτοκινητ"),
+            ("αυτοκινητε", "αυτοκινητ"),
+            ("αυτοκινητες", "αυτοκινητ"),
+            ("αυτοκινητη", "αυτοκινητ"),
+            ("αυτοκινητη", "αυτοκινητ"),
+            ("αυτοκινητο", "αυτοκινητ"),
+            ("αυτοκινητοι", "αυτοκινητ"),
+            ("αυτοκινητος", "αυτοκινητ"),
+            ("αυτοκινητου", "αυτοκινητ"),
+            ("αυτοκινητους", "αυτοκινητ"),
+            ("αυτοκινητων", "αυτοκινητ"),
+            ("χορδιζαμε", "χορδ"),
+            ("χορδιζανε", "χορδ"),
+            ("χορδιζατε", "χορδ"),
+            ("χορδιζει", "χορδ"),
+            ("χορδιζεις", "χορδ"),
+            ("χορδιζετε", "χορδ"),
+            ("χορδιζομε", "χορδιζομ"),
+            ("χορδιζοντας", "χορδιζ"),
+            ("χορδιζουμε", "χορδ"),
+            ("χορδιζουν", "χορδ"),
+            ("χορδιζουνε", "χορδ"),
+            ("χορδιζω", "χορδ"),
+            ("χορδιξει", "χορδιξ"),
+            ("χορδισαμε", "χορδ"),
+            ("χορδισαν", "χορδ"),
+            ("μελλον", "μελλον"),
+            ("μελλοντα", "μελλοντ"),
+            ("μελλοντ", "μελλοντ"),
+            ("μελλοντας", "μελλ"),
+            ("μελλοντες", "μελλοντ"),
+            ("μελλοντος", "μελλοντ"),
+            ("αρειε", "αρει"),
+            ("αρειο", "αρει"),
+            ("αρειοι", "αρει"),
+            ("αρειος", "αρει"),
+            ("αρειου", "αρει"),
+            ("υδροθεραπεια", "υδροθεραπει"),
+            ("υδροθεραπειας", "υδροθεραπει"),
+            ("υδροθεραπειες", "υδροθεραπει"),
+            ("υδροθεραπειων", "υδροθεραπει"),
+            ("παιδοποδηλατα", "παιδοποδηλατ"),
+            ("παιδοποδηλατο", "παιδοποδηλατ"),
+            ("παιδοποδηλατου", "παιδοποδηλατ"),
+            ("παιδοποδηλατων", "παιδοποδηλατ"),
+            ("παιδοποδηλατου", "παιδοποδηλατ"),
+            ("παιδοποδηλατων", "παιδοποδηλατ"),
+            ("βροχοχορευα", "βροχοχορευ"),
+            ("βροχοχορευαν", "βροχοχορευ"),
+            ("βροχοχορευε", "βροχοχορευ"),
+            ("βροχοχορευες", "βροχοχορευ"),
+            ("βροχοχορεψα", "βροχοχορεψ"),
+            ("βροχοχορεψαν", "βροχοχορεψ"),
+            ("βροχοχορεψε", "βροχοχορεψ"),
+            ("βροχοχορεψες", "βροχοχορεψ"),
+            ("επιτροπο", "επιτροπ"),
+            ("επιτροποι", "επιτροπ"),
+            ("επιτροπος", "επιτροπ"),
+            ("επιτροπου", "επιτροπ"),
+            ("επιτροπους", "επιτροπ"),
+            ("επιτροπων", "επιτροπ"),
+            ("βορειοασιατικα", "βορειοασιατ"),
+            ("βορειοασιατικε", "βορειοασιατικ"),
+            ("βορειοασιατικες", "βορειοασιατικ"),
+            ("βορειοασιατικη", "βορειοασιατικ"),
+            ("βορειοασιατικης", "βορειοασιατικ"),
+            ("βορειοασιατικοι", "βορειοασιατικ"),
+            ("βορειοασιατικου", "βορειοασιατ"),
+            ("βορειοασιατικους", "βορειοασιατικ"),
+            ("βορειοασιατικο", "βορειοασιατ"),
+            ("βορειοασιατικος", "βορειοασιατικ"),
+            ("βορειοασιατικων", "βορειοασιατ"),
+            ("υπομελη", "υπομελ"),
+            ("υπομελος", "υπομελ"),
+            ("υπομελους", "υπομελ"),
+            ("υπομελων", "υπομελ"),
+        ];
+        for (word, expected) in cases {
+            assert_eq!(stem(word), expected, "{word}");
+        }
+    }
+
+    #[test]
+    fn test_stem_normalizes() {
+        assert_eq!(stem("ΑΥΤΟΚΊΝΗΤΟΣ"), "αυτοκινητ");
+        assert_eq!(stem("αυτοκινήτων"), "αυτοκινητ");
+        // Decomposed
+        assert_eq!(stem("αυτοκινη\u{301}των"), "αυτοκινητ");
+        assert_eq!(stem("Ὁ"), "ο");
+        assert_eq!(stem(""), "");
+    }
+
+    #[test]
+    fn test_stem_exceptions() {
+        let cases = [
+            ("φώτα", "φω"),
+            ("κρέατος", "κρε"),
+            ("εθνικισμός", "εθν"),
+            ("αγαμε", "αγαμ"),
+            ("πολιτισμού", "πολιτ"),
+            ("μεγαλύτερος", "μεγαλ"),
+            ("φάγαμε", "φ"),
+        ];
+        for (word, expected) in cases {
+            assert_eq!(stem(word), expected, "{word}");
+        }
+    }
+}
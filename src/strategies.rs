@@ -0,0 +1,189 @@
+//! [Proptest](https://docs.rs/proptest) strategies for realistic Greek text.
+//!
+//! Words are built from syllables that follow the phonotactics of modern Greek,
+//! so that they exercise the syllabification rules instead of random codepoints.
+//! Shrinking removes syllables, then simplifies onsets and nuclei.
+//!
+//! Requires the `proptest-support` feature.
+//!
+//! # Example
+//!
+//! ```
+//! use grac::strategies::syllabified_word;
+//! use proptest::prelude::*;
+//!
+//! proptest!(|(word in syllabified_word())| {
+//!     prop_assert_eq!(grac::syllabify(&word.word).to_vec(), word.syllables);
+//! });
+//! ```
+
+use proptest::collection::vec;
+use proptest::prelude::*;
+use proptest::sample::select;
+use unicode_normalization::UnicodeNormalization;
+
+use crate::accents::Diacritic;
+use crate::chars::to_upper_str;
+
+/// Consonants and clusters that can start a syllable, simplest first.
+#[rustfmt::skip]
+const ONSETS: [&str; 41] = [
+    "τ", "κ", "π", "μ", "ν", "λ", "ρ", "σ", "δ", "γ", "θ", "χ", "φ", "β", "ζ", "ξ", "ψ",
+    "μπ", "ντ", "γκ", "τσ", "τζ",
+    "στ", "σπ", "σκ", "σμ", "τρ", "πρ", "κρ", "γρ", "δρ", "θρ", "φρ", "χρ", "βρ",
+    "πλ", "κλ", "γλ", "φλ", "χλ",
+    "στρ",
+];
+
+/// Vowels and diphthongs, simplest first.
+const NUCLEI: [&str; 13] = [
+    "α", "ε", "ο", "ι", "η", "υ", "ω", "ου", "αι", "ει", "οι", "αυ", "ευ",
+];
+
+/// Consonants that can end a word.
+const CODAS: [&str; 3] = ["", "ς", "ν"];
+
+/// A word together with its expected syllables.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyllabifiedWord {
+    pub word: String,
+    /// The syllables that [`syllabify`](crate::syllabify) gives.
+    pub syllables: Vec<String>,
+}
+
+impl Arbitrary for SyllabifiedWord {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        syllabified_word().boxed()
+    }
+}
+
+fn accented(vowel: &str) -> String {
+    // The accent of a diphthong goes on its second vowel.
+    let mut chars: Vec<char> = vowel.chars().collect();
+    if let Some(last) = chars.last_mut() {
+        *last = format!("{last}{}", Diacritic::ACUTE)
+            .nfc()
+            .next()
+            .unwrap_or(*last);
+    }
+    chars.into_iter().collect()
+}
+
+prop_compose! {
+    /// A monotonic word of one to six syllables, with its syllables.
+    ///
+    /// Words of more than one syllable are accented on one of their last three.
+    pub fn syllabified_word()(
+        vowel_initial in any::<bool>(),
+        syllables in vec((select(&ONSETS[..]), select(&NUCLEI[..])), 1..=6),
+        coda in select(&CODAS[..]),
+        accent in 0..3usize,
+    ) -> SyllabifiedWord {
+        let n = syllables.len();
+        let accent = (n > 1).then(|| n - 1 - accent.min(n - 1));
+        let syllables: Vec<String> = syllables
+            .into_iter()
+            .enumerate()
+            .map(|(idx, (onset, nucleus))| {
+                let onset = if idx == 0 && vowel_initial { "" } else { onset };
+                let nucleus = if Some(idx) == accent {
+                    accented(nucleus)
+                } else {
+                    nucleus.to_string()
+                };
+                let coda = if idx == n - 1 { coda } else { "" };
+                format!("{onset}{nucleus}{coda}")
+            })
+            .collect();
+        SyllabifiedWord {
+            word: syllables.concat(),
+            syllables,
+        }
+    }
+}
+
+/// A monotonic word of one to six syllables.
+pub fn greek_word() -> impl Strategy<Value = String> {
+    syllabified_word().prop_map(|word| word.word)
+}
+
+prop_compose! {
+    /// A polytonic word, in NFC.
+    ///
+    /// The accent of [`greek_word`] becomes an acute, a grave or a circumflex,
+    /// words starting with a vowel take a breathing, and words ending in α, η
+    /// or ω may take an iota subscript.
+    pub fn polytonic_word()(
+        word in greek_word(),
+        accent in select(&[Diacritic::ACUTE, Diacritic::GRAVE, Diacritic::CIRCUMFLEX][..]),
+        breathing in select(&[Diacritic::SMOOTH, Diacritic::ROUGH][..]),
+        subscript in any::<bool>(),
+    ) -> String {
+        let mut chars: Vec<char> = word.nfd().collect();
+        for ch in &mut chars {
+            if *ch == Diacritic::ACUTE {
+                *ch = accent;
+            }
+        }
+        if chars.first().is_some_and(|ch| crate::is_vowel(*ch)) {
+            // On the second vowel of a diphthong: εὐχή, οἶκος.
+            let is_diphthong = chars.get(1).is_some_and(|ch| matches!(ch, 'ι' | 'υ'));
+            chars.insert(usize::from(is_diphthong) + 1, breathing);
+        }
+        if subscript && chars.last().is_some_and(|ch| matches!(ch, 'α' | 'η' | 'ω')) {
+            chars.push(Diacritic::IOTA_SUBSCRIPT);
+        }
+        chars.into_iter().nfc().collect()
+    }
+}
+
+prop_compose! {
+    /// Polytonic text of one to twenty words, with punctuation, starting with a capital.
+    pub fn polytonic_text()(
+        words in vec(
+            (polytonic_word(), select(&[" ", " ", " ", ", ", "· ", ". ", "; "][..])),
+            1..=20,
+        ),
+    ) -> String {
+        let mut text = String::new();
+        for (word, separator) in words {
+            text.push_str(&word);
+            text.push_str(separator);
+        }
+        let text = text.trim_end();
+        let first_len = text.chars().next().map_or(0, char::len_utf8);
+        format!("{}{}", to_upper_str(&text[..first_len]), &text[first_len..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{syllabify, to_monotonic};
+
+    proptest! {
+        #[test]
+        fn test_syllabified_word(word in syllabified_word()) {
+            prop_assert_eq!(syllabify(&word.word).to_vec(), word.syllables);
+        }
+
+        #[test]
+        fn test_polytonic_word(word in polytonic_word()) {
+            prop_assert!(word.nfc().eq(word.chars()));
+            let monotonic = to_monotonic(&word);
+            let has_breathing = |ch: char| ch.nfd().any(|mark| mark == Diacritic::SMOOTH);
+            prop_assert!(!monotonic.contains(has_breathing));
+            prop_assert_eq!(syllabify(&monotonic).len(), syllabify(&word).len());
+        }
+
+        #[test]
+        fn test_polytonic_text(text in polytonic_text()) {
+            prop_assert!(text.starts_with(|ch: char| !ch.is_lowercase()));
+            prop_assert!(!text.ends_with(char::is_whitespace));
+        }
+    }
+}
@@ -1,7 +1,12 @@
-use std::ops::{Deref, DerefMut};
+use std::ops::{Deref, DerefMut, Range};
 
-use crate::accents::has_diaeresis;
-use crate::chars::base_lower;
+use crate::accents::has_diaeresis_char;
+use unicode_normalization::UnicodeNormalization;
+
+use crate::chars::{base_lower, compose_marks, has_combining_marks, is_combining_mark};
+use crate::explain::Step;
+use crate::prefix::split_consonant_prefix;
+use crate::span::{SpanEncoding, encode_spans};
 use crate::synizesis::lookup_synizesis;
 
 const DIPHTHONGS_EL: [(char, char); 8] = [
@@ -16,7 +21,7 @@ const DIPHTHONGS_EL: [(char, char); 8] = [
 ];
 
 #[rustfmt::skip]
-const CONS_CLUSTERS_EL: [(char, char); 45] = [
+pub(crate) const CONS_CLUSTERS_EL: [(char, char); 45] = [
     ('β', 'δ'), ('β', 'λ'), ('β', 'ρ'), ('β', 'γ'),
     ('γ', 'κ'), ('γ', 'λ'), ('γ', 'ν'), ('γ', 'ρ'),
     ('δ', 'ρ'),
@@ -56,6 +61,7 @@ const CANDIDATE_MERGING_DIPHTHONGS_EL: [(char, char); 19] = [
 /// In case of multiple indices, they should refer to the syllable positions
 /// after the desired merging takes place (cf. syllabify documentation).
 #[derive(Debug, Clone)]
+#[non_exhaustive]
 pub enum Merge {
     Every,
     Never,
@@ -81,18 +87,85 @@ impl Merge {
 ///
 /// Automatically detects synizesis.
 ///
+/// Combining marks (NFD input) are kept with their base character, so that
+/// decomposed words are syllabified as their precomposed versions.
+///
 /// # Example
 ///
 /// ```
 /// use grac::syllabify;
 /// assert_eq!(syllabify("αρρώστια").join("-"), "αρ-ρώ-στια");
+/// // Decomposed
+/// assert_eq!(syllabify("αρρω\u{301}στια").join("-"), "αρ-ρω\u{301}-στια");
 /// ```
-#[allow(clippy::option_if_let_else)]
 pub fn syllabify(s: &str) -> Syllables<'_> {
-    match lookup_synizesis(s) {
-        Some(res) => Syllables::from(res),
-        _ => syllabify_impl(s, Merge::Never),
+    lookup_syllables(s).unwrap_or_else(|| syllabify_impl(s, &Merge::Never))
+}
+
+/// Syllables of a word from the synizesis lookup table, or `None` if it is not there.
+///
+/// The lookup ignores case and combining marks: ΜΆΓΙΑ, Μάγια and μάγια all
+/// use the entry of μάγια. This is what [`syllabify`] checks before its rules.
+///
+/// # Example
+///
+/// ```
+/// use grac::lookup_syllables;
+///
+/// assert_eq!(lookup_syllables("Μάγια").unwrap().join("-"), "Μά-για");
+/// assert_eq!(lookup_syllables("ΜΆΓΙΑ").unwrap().join("-"), "ΜΆ-ΓΙΑ");
+/// assert!(lookup_syllables("άνθρωπος").is_none());
+/// ```
+pub fn lookup_syllables(s: &str) -> Option<Syllables<'_>> {
+    if let Some(syllables) = lookup_synizesis(s) {
+        return Some(Syllables::from(syllables));
+    }
+    let key = folded_key(s)?;
+    map_folded_syllables(s, lookup_synizesis(&key)?)
+}
+
+/// The composed lowercase form under which `s` is looked up, or `None` if it
+/// is `s` itself.
+pub(crate) fn folded_key(s: &str) -> Option<String> {
+    let has_marks = has_combining_marks(s);
+    if !has_marks && !s.chars().any(char::is_uppercase) {
+        return None;
     }
+    let composed: String = if has_marks {
+        s.nfc().collect()
+    } else {
+        s.to_string()
+    };
+    Some(composed.to_lowercase())
+}
+
+/// Split `s` as the syllables of its [`folded_key`].
+pub(crate) fn map_folded_syllables<'a>(s: &'a str, syllables: &[&str]) -> Option<Syllables<'a>> {
+    // Every composed char should be a base char of `s`, followed by its marks.
+    let bases: Vec<usize> = s
+        .char_indices()
+        .filter(|(_, ch)| !is_combining_mark(*ch))
+        .map(|(idx, _)| idx)
+        .collect();
+    let n_syllable_chars: usize = syllables.iter().map(|syl| syl.chars().count()).sum();
+    if n_syllable_chars != bases.len() || bases.first() != Some(&0) {
+        return None;
+    }
+
+    let mut n_chars = 0;
+    let mut start = 0;
+    Some(
+        syllables
+            .iter()
+            .map(|syllable| {
+                n_chars += syllable.chars().count();
+                let end = bases.get(n_chars).copied().unwrap_or(s.len());
+                let syllable = &s[start..end];
+                start = end;
+                syllable
+            })
+            .collect(),
+    )
 }
 
 /// Syllabify a modern Greek word.
@@ -115,7 +188,165 @@ pub fn syllabify(s: &str) -> Syllables<'_> {
 /// assert_eq!(syllabify_with_merge(word, idxs).join("-"), "α-στειά-κια");
 /// ```
 pub fn syllabify_with_merge(s: &str, merge: Merge) -> Syllables<'_> {
-    syllabify_impl(s, merge)
+    syllabify_impl(s, &merge)
+}
+
+/// Same as [`syllabify_with_merge`], over the chars of a word, returning the
+/// char ranges of its syllables.
+///
+/// Callers that already hold the chars of a word (editors) avoid encoding it
+/// to UTF-8 and back.
+///
+/// # Example
+///
+/// ```
+/// use grac::{Merge, syllabify_chars};
+///
+/// let word: Vec<char> = "αστειάκια".chars().collect();
+/// assert_eq!(syllabify_chars(&word, Merge::Every), [0..1, 1..6, 6..9]);
+/// assert_eq!(syllabify_chars(&word, Merge::Never).len(), 5);
+/// ```
+pub fn syllabify_chars(chars: &[char], merge: Merge) -> Vec<Range<usize>> {
+    let mut next_base = chars.len();
+    let rev_chars = chars.iter().enumerate().rev().filter_map(|(idx, &ch)| {
+        if is_combining_mark(ch) {
+            return None;
+        }
+        let marks = &chars[idx + 1..next_base];
+        next_base = idx;
+        Some((idx, compose_marks(ch, marks.iter().copied())))
+    });
+    let mut out = Vec::new();
+    split_syllables(rev_chars, chars.len(), &merge, is_vowel, &mut (), |range| {
+        out.push(range);
+    });
+    out.reverse();
+    out
+}
+
+/// Options for [`syllabify_with_options`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct SyllabifyOptions {
+    pub merge: Merge,
+    /// Treat digamma (ϝ) as a consonant, as in epigraphic texts (ϝοῖκος).
+    ///
+    /// By default it is a vowel, like the other letters that are not consonants.
+    /// The other archaic letters (koppa, sampi, san, stigma, yot...) are always
+    /// consonants.
+    pub digamma_as_consonant: bool,
+    /// Keep the boundary of prefixes that end with a consonant (εκ-, εισ-,
+    /// συν-, υπερ-...): εισ-πνο-ή instead of ει-σπνο-ή.
+    ///
+    /// By default, the syllabification is phonetic, as taught by the modern
+    /// school grammar (Τριανταφυλλίδης), where a consonant cluster that can
+    /// start a word starts the syllable. The morphological one follows the
+    /// older grammars of katharevousa and ancient Greek, that split compounds
+    /// into their parts. Prefixes come from a table, so words that only look
+    /// prefixed (έκτος) are split too.
+    pub morphological: bool,
+}
+
+impl SyllabifyOptions {
+    pub const fn new() -> Self {
+        Self {
+            merge: Merge::Never,
+            digamma_as_consonant: false,
+            morphological: false,
+        }
+    }
+
+    #[must_use]
+    pub fn with_merge(mut self, merge: Merge) -> Self {
+        self.merge = merge;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_digamma_as_consonant(mut self, digamma_as_consonant: bool) -> Self {
+        self.digamma_as_consonant = digamma_as_consonant;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_morphological(mut self, morphological: bool) -> Self {
+        self.morphological = morphological;
+        self
+    }
+}
+
+impl Default for SyllabifyOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Same as [`syllabify_with_merge`], with [`SyllabifyOptions`].
+///
+/// # Example
+///
+/// ```
+/// use grac::{SyllabifyOptions, syllabify_with_options};
+///
+/// let options = SyllabifyOptions::new();
+/// assert_eq!(syllabify_with_options("ϝοῖκος", &options).join("-"), "ϝ-οῖ-κος");
+///
+/// let options = options.with_digamma_as_consonant(true);
+/// assert_eq!(syllabify_with_options("ϝοῖκος", &options).join("-"), "ϝοῖ-κος");
+/// assert_eq!(syllabify_with_options("Ϙόρινθος", &options).join("-"), "Ϙό-ριν-θος");
+///
+/// let options = SyllabifyOptions::new().with_morphological(true);
+/// assert_eq!(syllabify_with_options("εισπνοή", &options).join("-"), "εισ-πνο-ή");
+/// assert_eq!(syllabify_with_options("προσκαλώ", &options).join("-"), "προσ-κα-λώ");
+/// ```
+pub fn syllabify_with_options<'a>(s: &'a str, options: &SyllabifyOptions) -> Syllables<'a> {
+    if options.morphological
+        && let Some((prefix, rest)) = split_consonant_prefix(s)
+    {
+        let prefix = syllabify_phonetic(prefix, &Merge::Never, options);
+        let rest = syllabify_phonetic(rest, &options.merge, options);
+        return prefix.iter().chain(rest.iter()).copied().collect();
+    }
+    syllabify_phonetic(s, &options.merge, options)
+}
+
+fn syllabify_phonetic<'a>(s: &'a str, merge: &Merge, options: &SyllabifyOptions) -> Syllables<'a> {
+    if options.digamma_as_consonant {
+        syllabify_impl_generic(s, merge, is_vowel_digamma_consonant, &mut ())
+    } else {
+        syllabify_impl(s, merge)
+    }
+}
+
+/// Byte ranges of the syllables of a word, as given by [`syllabify`].
+///
+/// # Example
+///
+/// ```
+/// use grac::syllable_spans;
+/// assert_eq!(syllable_spans("πάντα"), [0..4, 4..10]);
+/// ```
+pub fn syllable_spans(s: &str) -> Vec<Range<usize>> {
+    let mut start = 0;
+    syllabify(s)
+        .iter()
+        .map(|syllable| {
+            start += syllable.len();
+            start - syllable.len()..start
+        })
+        .collect()
+}
+
+/// Same as [`syllable_spans`], with offsets in UTF-16 code units.
+///
+/// # Example
+///
+/// ```
+/// use grac::syllable_spans_utf16;
+/// assert_eq!(syllable_spans_utf16("πάντα"), [0..2, 2..5]);
+/// ```
+pub fn syllable_spans_utf16(s: &str) -> Vec<Range<usize>> {
+    encode_spans(s, syllable_spans(s), SpanEncoding::Utf16)
 }
 
 /// Return true if ch normalizes to a vowel (αοειηυω).
@@ -142,30 +373,126 @@ const fn is_consonant(ch: char) -> bool {
 
         // uppercase
         'Β' | 'Γ' | 'Δ' | 'Ζ' | 'Θ' | 'Κ' | 'Λ' | 'Μ' | 'Ν' | 'Ξ' |
-        'Π' | 'Ρ' | 'Σ' | 'Τ' | 'Φ' | 'Χ' | 'Ψ'
+        'Π' | 'Ρ' | 'Σ' | 'Τ' | 'Φ' | 'Χ' | 'Ψ' |
+
+        // archaic (heta, sampi, yot, koppa, stigma, san), but digamma
+        'ͱ' | 'ͳ' | 'ϳ' | 'ϙ' | 'ϛ' | 'ϟ' | 'ϡ' | 'ϻ' |
+        'Ͱ' | 'Ͳ' | 'Ϳ' | 'Ϙ' | 'Ϛ' | 'Ϟ' | 'Ϡ' | 'Ϻ'
     )
 }
 
-pub fn is_diphthong(a: char, b: char) -> bool {
-    let pair = (base_lower(a), base_lower(b));
-    DIPHTHONGS_EL.contains(&pair) && !has_diaeresis(b)
+/// Same as [`is_vowel`], with digamma as a consonant.
+const fn is_vowel_digamma_consonant(ch: char) -> bool {
+    is_vowel(ch) && !matches!(ch, 'ϝ' | 'Ϝ' | 'ͷ' | 'Ͷ')
 }
 
-fn is_candidate_diphthong(a: char, b: char) -> bool {
-    CANDIDATE_MERGING_DIPHTHONGS_EL.contains(&(a, b))
+pub const fn is_diphthong(a: char, b: char) -> bool {
+    contains_pair(&DIPHTHONGS_EL, (base_lower(a), base_lower(b))) && !has_diaeresis_char(b)
 }
 
-fn is_consonant_cluster(a: char, b: char) -> bool {
-    let pair = (base_lower(a), base_lower(b));
-    CONS_CLUSTERS_EL.contains(&pair)
+const fn is_candidate_diphthong(a: char, b: char) -> bool {
+    contains_pair(&CANDIDATE_MERGING_DIPHTHONGS_EL, (a, b))
+}
+
+pub(crate) const fn is_consonant_cluster(a: char, b: char) -> bool {
+    contains_pair(&CONS_CLUSTERS_EL, (base_lower(a), base_lower(b)))
+}
+
+// Slice::contains is not const
+const fn contains_pair(pairs: &[(char, char)], pair: (char, char)) -> bool {
+    let mut i = 0;
+    while i < pairs.len() {
+        if pairs[i].0 == pair.0 && pairs[i].1 == pair.1 {
+            return true;
+        }
+        i += 1;
+    }
+    false
 }
 
 type S<'a> = &'a str;
-// type Ty<'a> = smallvec::SmallVec<[S<'a>; 8]>;
-type Ty<'a> = Vec<S<'a>>;
 
-// Wrapper type to allow for internal experimentation with Vec and SmallVec
-#[derive(Debug, PartialEq, Eq)]
+/// Syllables stored without allocating: most words have at most four.
+const INLINE_SYLLABLES: usize = 4;
+
+/// A vector of syllables that only allocates for long words.
+///
+/// Most tokens of a text are short, so that skipping the allocation for them
+/// is the main win of the fast path (cf. `cargo bench syllabify`).
+#[derive(Clone)]
+enum Ty<'a> {
+    Inline {
+        len: usize,
+        buf: [S<'a>; INLINE_SYLLABLES],
+    },
+    Heap(Vec<S<'a>>),
+}
+
+impl<'a> Ty<'a> {
+    const fn new() -> Self {
+        Self::Inline {
+            len: 0,
+            buf: [""; INLINE_SYLLABLES],
+        }
+    }
+
+    fn push(&mut self, syllable: S<'a>) {
+        match self {
+            Self::Inline { len, buf } if *len < INLINE_SYLLABLES => {
+                buf[*len] = syllable;
+                *len += 1;
+            }
+            Self::Inline { buf, .. } => {
+                let mut heap = Vec::with_capacity(2 * INLINE_SYLLABLES);
+                heap.extend_from_slice(buf);
+                heap.push(syllable);
+                *self = Self::Heap(heap);
+            }
+            Self::Heap(heap) => heap.push(syllable),
+        }
+    }
+
+    const fn as_slice(&self) -> &[S<'a>] {
+        match self {
+            Self::Inline { len, buf } => buf.split_at(*len).0,
+            Self::Heap(heap) => heap.as_slice(),
+        }
+    }
+}
+
+impl<'a> Deref for Ty<'a> {
+    type Target = [S<'a>];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl DerefMut for Ty<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        match self {
+            Self::Inline { len, buf } => &mut buf[..*len],
+            Self::Heap(heap) => heap,
+        }
+    }
+}
+
+impl std::fmt::Debug for Ty<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl PartialEq for Ty<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl Eq for Ty<'_> {}
+
+// Wrapper type to allow for internal experimentation with the storage
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Syllables<'a> {
     inner: Ty<'a>,
 }
@@ -178,9 +505,11 @@ impl Syllables<'_> {
 
 impl<'a> FromIterator<S<'a>> for Syllables<'a> {
     fn from_iter<T: IntoIterator<Item = S<'a>>>(iter: T) -> Self {
-        Syllables {
-            inner: Ty::from_iter(iter),
+        let mut inner = Ty::new();
+        for syllable in iter {
+            inner.push(syllable);
         }
+        Syllables { inner }
     }
 }
 
@@ -205,56 +534,134 @@ impl<'a> From<&[S<'a>]> for Syllables<'a> {
     }
 }
 
-#[derive(Debug)]
-enum State {
+/// State of the syllabification automaton.
+///
+/// The automaton walks the word backwards, from the last char to the first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum State {
+    /// No vowel found yet in the current syllable.
     Start,
+    /// Last char read was a vowel.
     FoundVowel,
+    /// Last char read was a consonant, after some vowel.
     FoundConsonant,
 }
 
+/// Hook into the automaton transitions (cf. [`crate::explain`]).
+///
+/// The no-op implementation for `()` is used by default and is compiled away.
+pub(crate) trait Tracer {
+    const ENABLED: bool;
+    fn step(&mut self, step: Step);
+}
+
+impl Tracer for () {
+    const ENABLED: bool = false;
+    fn step(&mut self, _step: Step) {}
+}
+
+impl Tracer for Vec<Step> {
+    const ENABLED: bool = true;
+    fn step(&mut self, step: Step) {
+        self.push(step);
+    }
+}
+
 // Because we yield syllables in reverse order, we cannot refactor this into
 // returning only an iterator (we need to allocate the syllables somehow).
 //
 // Writing the reverse of this finite state automaton should be possible, but
 // I am unsure it deserves the effort.
-fn syllabify_impl(s: &str, merge: Merge) -> Syllables<'_> {
-    let mut out = Ty::with_capacity(8); // Found experimentally
+fn syllabify_impl<'a>(s: &'a str, merge: &Merge) -> Syllables<'a> {
+    syllabify_impl_traced(s, merge, &mut ())
+}
+
+pub(crate) fn syllabify_impl_traced<'a, T: Tracer>(
+    s: &'a str,
+    merge: &Merge,
+    tracer: &mut T,
+) -> Syllables<'a> {
+    syllabify_impl_generic(s, merge, is_vowel, tracer)
+}
+
+fn syllabify_impl_generic<'a, T: Tracer>(
+    s: &'a str,
+    merge: &Merge,
+    is_vowel: impl Fn(char) -> bool,
+    tracer: &mut T,
+) -> Syllables<'a> {
+    let mut out = Ty::new();
+    split_syllables(
+        base_chars_rev(s),
+        s.len(),
+        merge,
+        is_vowel,
+        tracer,
+        |range| out.push(&s[range]),
+    );
+    out.reverse();
+    Syllables { inner: out }
+}
+
+/// The base chars of a word from its end, with their byte index, and their
+/// combining marks composed into them.
+fn base_chars_rev(s: &str) -> impl Iterator<Item = (usize, char)> {
+    // Start of the last base char read, to find the combining marks of the next one.
+    let mut next_base = s.len();
+    s.char_indices().rev().filter_map(move |(idx, ch)| {
+        // Combining marks are read together with their base char
+        if is_combining_mark(ch) {
+            return None;
+        }
+        let marks = &s[idx + ch.len_utf8()..next_base];
+        next_base = idx;
+        if marks.is_empty() {
+            Some((idx, ch))
+        } else {
+            Some((idx, compose_marks(ch, marks.chars())))
+        }
+    })
+}
 
+/// Run the automaton over the base chars of a word of length `len`, given
+/// from its end with their index, and pass the range of every syllable to
+/// `emit`, from the last one to the first.
+///
+/// Indices can be bytes or chars, as long as they are those of the word.
+fn split_syllables<T: Tracer>(
+    rev_chars: impl Iterator<Item = (usize, char)>,
+    len: usize,
+    merge: &Merge,
+    is_vowel: impl Fn(char) -> bool,
+    tracer: &mut T,
+    mut emit: impl FnMut(Range<usize>),
+) {
     let mut state = State::Start;
     let mut idx_syllable = 1;
     let mut cur_merge = merge.to_bool(idx_syllable);
 
-    // We'll walk backwards using char_indices().rev(), and buffer recent chars
-    let mut to_byte = s.len();
+    // We'll walk backwards, and buffer recent chars
+    let mut to_byte = len;
     let mut buffer: [(usize, char); 3] = [(0, '\0'); 3]; // for peeking ahead
 
     macro_rules! dump_at {
         ($fr_byte:expr) => {{
-            out.push(&s[$fr_byte..to_byte]);
+            emit($fr_byte..to_byte);
             to_byte = $fr_byte;
             idx_syllable += 1;
             cur_merge = merge.to_bool(idx_syllable);
         }};
     }
 
-    for (fr_byte, ch) in s.char_indices().rev() {
+    for (fr_byte, ch) in rev_chars {
         // Slide buffer
         buffer.copy_within(0..2, 1);
         buffer[0] = (fr_byte, ch);
 
         let vowel = is_vowel(ch);
 
-        // eprintln!(
-        //     "* {:<15} {} {} {} -- {} {} -- {} \n| \x1b[33mBuf {:?}\x1b[0m",
-        //     format!("{state:?}"),
-        //     fr_byte,
-        //     ch,
-        //     vowel,
-        //     idx_syllable,
-        //     cur_merge,
-        //     out.join("-"),
-        //     buffer
-        // );
+        // Snapshot for the tracer (cf. `grac explain <word>`)
+        let (from, step_idx_syllable, step_merge) = (state, idx_syllable, cur_merge);
 
         match state {
             State::Start => {
@@ -275,7 +682,7 @@ fn syllabify_impl(s: &str, merge: Merge) -> Syllables<'_> {
                         let (_, after_next_ch) = buffer[2];
                         if is_vowel(next_ch)
                             && is_vowel(after_next_ch)
-                            && !matches!(out.last(), Some(after_next_ch))
+                            && idx_syllable == 1
                             && !merge.to_bool(idx_syllable + 1)
                         {
                             // όια
@@ -310,15 +717,125 @@ fn syllabify_impl(s: &str, merge: Merge) -> Syllables<'_> {
                 // keep advancing (=merge)
             }
         }
+
+        if T::ENABLED {
+            tracer.step(Step {
+                byte: fr_byte,
+                ch,
+                is_vowel: vowel,
+                buffer,
+                from,
+                to: state,
+                syllable_idx: step_idx_syllable,
+                merge: step_merge,
+                boundary: (idx_syllable > step_idx_syllable).then_some(to_byte),
+            });
+        }
     }
 
     if to_byte > 0 {
-        out.push(&s[..to_byte]);
+        emit(0..to_byte);
     }
+}
 
-    out.reverse();
+/// Same as [`syllabify_impl`] with [`Merge::Never`], usable in const contexts
+/// (cf. [`syllabify_const`](crate::syllabify_const)).
+///
+/// Write the starting bytes of every syllable but the first into `out`, from
+/// the end of the word, and return how many there are, even if `out` is shorter.
+pub(crate) const fn syllable_starts_rev(s: &str, out: &mut [usize]) -> usize {
+    let bytes = s.as_bytes();
+    let mut n = 0;
 
-    Syllables { inner: out }
+    let mut state = State::Start;
+    let mut to_byte = s.len();
+    let mut buffer: [(usize, char); 3] = [(0, '\0'); 3];
+
+    let mut end = s.len();
+
+    while end > 0 {
+        let (fr_byte, ch) = char_before(bytes, end);
+        end = fr_byte;
+
+        // Slide buffer
+        buffer[2] = buffer[1];
+        buffer[1] = buffer[0];
+        buffer[0] = (fr_byte, ch);
+
+        let vowel = is_vowel(ch);
+        let mut dump = None;
+
+        match state {
+            State::Start => {
+                if vowel {
+                    state = State::FoundVowel;
+                }
+            }
+            State::FoundVowel => {
+                if vowel {
+                    let (next_idx, next_ch) = buffer[1];
+                    if !is_candidate_diphthong(ch, next_ch) && is_diphthong(ch, next_ch) {
+                        let (after_next_idx, after_next_ch) = buffer[2];
+                        if after_next_ch == 'ι' && to_byte > after_next_idx {
+                            dump = Some(after_next_idx);
+                        }
+                    } else {
+                        dump = Some(next_idx);
+                    }
+                } else {
+                    state = State::FoundConsonant;
+                }
+            }
+            State::FoundConsonant => {
+                let (next_idx, next_ch) = buffer[1];
+                if vowel {
+                    dump = Some(next_idx);
+                    state = State::FoundVowel;
+                } else if !is_consonant_cluster(ch, next_ch) {
+                    dump = Some(next_idx);
+                    state = State::Start;
+                }
+            }
+        }
+
+        if let Some(at) = dump {
+            if n < out.len() {
+                out[n] = at;
+            }
+            n += 1;
+            to_byte = at;
+        }
+    }
+
+    n
+}
+
+/// Decode the char ending at byte `end`.
+pub(crate) const fn char_before(bytes: &[u8], end: usize) -> (usize, char) {
+    let mut start = end - 1;
+    while bytes[start] & 0xC0 == 0x80 {
+        start -= 1;
+    }
+    let b0 = bytes[start] as u32;
+    let code = match end - start {
+        1 => b0,
+        2 => (b0 & 0x1F) << 6 | (bytes[start + 1] as u32 & 0x3F),
+        3 => {
+            (b0 & 0x0F) << 12
+                | (bytes[start + 1] as u32 & 0x3F) << 6
+                | (bytes[start + 2] as u32 & 0x3F)
+        }
+        _ => {
+            (b0 & 0x07) << 18
+                | (bytes[start + 1] as u32 & 0x3F) << 12
+                | (bytes[start + 2] as u32 & 0x3F) << 6
+                | (bytes[start + 3] as u32 & 0x3F)
+        }
+    };
+    match char::from_u32(code) {
+        Some(ch) => (start, ch),
+        None => panic!("invalid UTF-8"),
+    }
 }
 
 #[cfg(test)]
@@ -338,6 +855,21 @@ mod tests {
         assert_eq!(syllabify("Ώστε").len(), 2);
     }
 
+    #[test]
+    fn test_syllables_inline() {
+        // Up to INLINE_SYLLABLES inline, then on the heap.
+        let short = syllabify("πάντα");
+        assert!(matches!(short.inner, Ty::Inline { len: 2, .. }));
+        let long = syllabify("διαμερίσματα");
+        assert!(matches!(long.inner, Ty::Heap(_)));
+        assert_eq!(long.join("-"), "δι-α-με-ρί-σμα-τα");
+        assert_eq!(
+            format!("{short:?}"),
+            r#"Syllables { inner: ["πά", "ντα"] }"#
+        );
+        assert_eq!(short, ["πά", "ντα"].into_iter().collect());
+    }
+
     #[test]
     fn test_syllabify_consonant_cluster() {
         assert_eq!(syllabify("στρες").len(), 1);
@@ -351,6 +883,27 @@ mod tests {
         assert_eq!(syllabify("φαϊ").len(), 2);
     }
 
+    #[test]
+    fn test_syllabify_chars() {
+        let dump = include_str!("../tests/fixtures/dump.txt");
+        let decomposed: String = dump.nfd().collect();
+        for word in dump.split_whitespace().chain(decomposed.split_whitespace()) {
+            let chars: Vec<char> = word.chars().collect();
+            for merge in [Merge::Never, Merge::Every, Merge::from_indices(&[1])] {
+                let expected: Vec<String> = syllabify_with_merge(word, merge.clone())
+                    .iter()
+                    .map(|syllable| (*syllable).to_string())
+                    .collect();
+                let got: Vec<String> = syllabify_chars(&chars, merge)
+                    .into_iter()
+                    .map(|range| chars[range].iter().collect())
+                    .collect();
+                assert_eq!(got, expected, "{word}");
+            }
+        }
+        assert!(syllabify_chars(&[], Merge::Never).is_empty());
+    }
+
     #[test]
     fn test_merge_never() {
         assert_eq!(syllabify_with_merge("αστειάκιαν", Merge::Never).len(), 5);
@@ -369,12 +922,77 @@ mod tests {
         assert_eq!(syllabify_with_merge("παλαιικά", Merge::Never).len(), 4);
     }
 
+    #[test]
+    fn test_syllabify_decomposed() {
+        use crate::split_punctuation;
+
+        let dump = include_str!("../tests/fixtures/dump.txt");
+        let polytonic = include_str!("../tests/fixtures/polytonic.txt");
+        let words = dump.split_whitespace().take(20_000);
+        for word in words.chain(polytonic.split_whitespace()) {
+            let (_, word, _) = split_punctuation(word);
+            let decomposed: String = word.nfd().collect();
+            for merge in [None, Some(Merge::Every), Some(Merge::Never)] {
+                let (expected, received) = match &merge {
+                    None => (syllabify(word), syllabify(&decomposed)),
+                    Some(merge) => (
+                        syllabify_with_merge(word, merge.clone()),
+                        syllabify_with_merge(&decomposed, merge.clone()),
+                    ),
+                };
+                let nfc = |syllables: Syllables| -> Vec<String> {
+                    syllables.iter().map(|s| s.nfc().collect()).collect()
+                };
+                assert_eq!(nfc(expected), nfc(received), "{word} {merge:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_lookup_syllables_case() {
+        let cases = [
+            ("χρόνια", "χρό-νια"),
+            ("Χρόνια", "Χρό-νια"),
+            ("ΧΡΌΝΙΑ", "ΧΡΌ-ΝΙΑ"),
+            ("ΧΡΟ\u{301}ΝΙΑ", "ΧΡΟ\u{301}-ΝΙΑ"),
+            ("χΡόΝιΑ", "χΡό-ΝιΑ"),
+        ];
+        for (word, expected) in cases {
+            let syllables = lookup_syllables(word).expect(word);
+            assert_eq!(syllables.join("-"), expected, "{word}");
+        }
+        // Accents are not folded.
+        assert!(lookup_syllables("ΧΡΟΝΙΑ").is_none());
+    }
+
     #[test]
     fn test_merge_never_extended() {
         assert_eq!(syllabify_with_merge("κίᾳ", Merge::Never).len(), 2);
         assert_eq!(syllabify_with_merge("ά̓κίᾳ", Merge::Never).len(), 3);
     }
 
+    #[test]
+    fn test_syllabify_morphological() {
+        let options = SyllabifyOptions::new().with_morphological(true);
+        for (word, expected) in [
+            ("εκλογή", "εκ-λο-γή"),
+            ("δυστυχία", "δυσ-τυ-χί-α"),
+            ("Έμπορος", "Έμ-πο-ρος"),
+            ("συγκρούω", "συγ-κρού-ω"),
+            ("ὑπερβολή", "ὑ-περ-βο-λή"),
+            ("συνεργάτης", "συ-νερ-γά-της"),
+            ("εκεί", "ε-κεί"),
+            ("πνοή", "πνο-ή"),
+        ] {
+            assert_eq!(syllabify_with_options(word, &options).join("-"), expected);
+        }
+        let options = options.with_merge(Merge::Every);
+        assert_eq!(
+            syllabify_with_options("συγκοινωνιακός", &options).join("-"),
+            "συγ-κοι-νω-νια-κός"
+        );
+    }
+
     #[test]
     fn test_is_vowel_opt() {
         const VOWELS_LOWER: &str = "αειουωη";
@@ -4,5305 +4,6 @@
 use phf::phf_map;
 
 static LOOKUP: phf::Map<&'static str, &'static [&'static str]> = phf_map! {
-    "Αγάλια" => &["Α", "γά", "λια"],
-    "Αγαλματάκια" => &["Α", "γαλ", "μα", "τά", "κια"],
-    "Αγαλματένια" => &["Α", "γαλ", "μα", "τέ", "νια"],
-    "Αγαλματένιας" => &["Α", "γαλ", "μα", "τέ", "νιας"],
-    "Αγαλματένιε" => &["Α", "γαλ", "μα", "τέ", "νιε"],
-    "Αγαλματένιες" => &["Α", "γαλ", "μα", "τέ", "νιες"],
-    "Αγαλματένιο" => &["Α", "γαλ", "μα", "τέ", "νιο"],
-    "Αγαλματένιοι" => &["Α", "γαλ", "μα", "τέ", "νιοι"],
-    "Αγαλματένιος" => &["Α", "γαλ", "μα", "τέ", "νιος"],
-    "Αγαλματένιου" => &["Α", "γαλ", "μα", "τέ", "νιου"],
-    "Αγαλματένιους" => &["Α", "γαλ", "μα", "τέ", "νιους"],
-    "Αγαλματένιων" => &["Α", "γαλ", "μα", "τέ", "νιων"],
-    "Αγγελάκια" => &["Αγ", "γε", "λά", "κια"],
-    "Αγγελούδια" => &["Αγ", "γε", "λού", "δια"],
-    "Αγγόνια" => &["Αγ", "γό", "νια"],
-    "Αγγουράκια" => &["Αγ", "γου", "ρά", "κια"],
-    "Αγγούρια" => &["Αγ", "γού", "ρια"],
-    "Αγελαδίσια" => &["Α", "γε", "λα", "δί", "σια"],
-    "Αγελαδίσιας" => &["Α", "γε", "λα", "δί", "σιας"],
-    "Αγελαδίσιε" => &["Α", "γε", "λα", "δί", "σιε"],
-    "Αγελαδίσιες" => &["Α", "γε", "λα", "δί", "σιες"],
-    "Αγελαδίσιο" => &["Α", "γε", "λα", "δί", "σιο"],
-    "Αγελαδίσιοι" => &["Α", "γε", "λα", "δί", "σιοι"],
-    "Αγελαδίσιος" => &["Α", "γε", "λα", "δί", "σιος"],
-    "Αγελαδίσιου" => &["Α", "γε", "λα", "δί", "σιου"],
-    "Αγελαδίσιους" => &["Α", "γε", "λα", "δί", "σιους"],
-    "Αγελαδίσιων" => &["Α", "γε", "λα", "δί", "σιων"],
-    "Αγεράκια" => &["Α", "γε", "ρά", "κια"],
-    "Αγέρια" => &["Α", "γέ", "ρια"],
-    "Αγιάζια" => &["Α", "γι", "ά", "ζια"],
-    "Αγιοκεράκια" => &["Α", "γι", "ο", "κε", "ρά", "κια"],
-    "Αγιοκέρια" => &["Α", "γι", "ο", "κέ", "ρια"],
-    "Αγκαθένια" => &["Α", "γκα", "θέ", "νια"],
-    "Αγκαθένιας" => &["Α", "γκα", "θέ", "νιας"],
-    "Αγκαθένιε" => &["Α", "γκα", "θέ", "νιε"],
-    "Αγκαθένιες" => &["Α", "γκα", "θέ", "νιες"],
-    "Αγκαθένιο" => &["Α", "γκα", "θέ", "νιο"],
-    "Αγκαθένιοι" => &["Α", "γκα", "θέ", "νιοι"],
-    "Αγκαθένιος" => &["Α", "γκα", "θέ", "νιος"],
-    "Αγκαθένιου" => &["Α", "γκα", "θέ", "νιου"],
-    "Αγκαθένιους" => &["Α", "γκα", "θέ", "νιους"],
-    "Αγκαθένιων" => &["Α", "γκα", "θέ", "νιων"],
-    "Αγκάθια" => &["Α", "γκά", "θια"],
-    "Αγκίστρια" => &["Α", "γκί", "στρια"],
-    "Αγκιτάτσια" => &["Α", "γκι", "τά", "τσια"],
-    "Αγκιτάτσιας" => &["Α", "γκι", "τά", "τσιας"],
-    "Αγκιτάτσιες" => &["Α", "γκι", "τά", "τσιες"],
-    "Αγκύλια" => &["Α", "γκύ", "λια"],
-    "Αγκυροβόλια" => &["Α", "γκυ", "ρο", "βό", "λια"],
-    "Αγκωναράκια" => &["Α", "γκω", "να", "ρά", "κια"],
-    "Αγκωνάρια" => &["Α", "γκω", "νά", "ρια"],
-    "Αγνάντια" => &["Α", "γνά", "ντια"],
-    "Αγοράκια" => &["Α", "γο", "ρά", "κια"],
-    "Αγόρια" => &["Α", "γό", "ρια"],
-    "Αγορίσια" => &["Α", "γο", "ρί", "σια"],
-    "Αγορίσιας" => &["Α", "γο", "ρί", "σιας"],
-    "Αγορίσιε" => &["Α", "γο", "ρί", "σιε"],
-    "Αγορίσιες" => &["Α", "γο", "ρί", "σιες"],
-    "Αγορίσιο" => &["Α", "γο", "ρί", "σιο"],
-    "Αγορίσιοι" => &["Α", "γο", "ρί", "σιοι"],
-    "Αγορίσιος" => &["Α", "γο", "ρί", "σιος"],
-    "Αγορίσιου" => &["Α", "γο", "ρί", "σιου"],
-    "Αγορίσιους" => &["Α", "γο", "ρί", "σιους"],
-    "Αγορίσιων" => &["Α", "γο", "ρί", "σιων"],
-    "Αγριλίσια" => &["Α", "γρι", "λί", "σια"],
-    "Αγριλίσιας" => &["Α", "γρι", "λί", "σιας"],
-    "Αγριλίσιε" => &["Α", "γρι", "λί", "σιε"],
-    "Αγριλίσιες" => &["Α", "γρι", "λί", "σιες"],
-    "Αγριλίσιο" => &["Α", "γρι", "λί", "σιο"],
-    "Αγριλίσιοι" => &["Α", "γρι", "λί", "σιοι"],
-    "Αγριλίσιος" => &["Α", "γρι", "λί", "σιος"],
-    "Αγριλίσιου" => &["Α", "γρι", "λί", "σιου"],
-    "Αγριλίσιους" => &["Α", "γρι", "λί", "σιους"],
-    "Αγριλίσιων" => &["Α", "γρι", "λί", "σιων"],
-    "Αγριμάκια" => &["Α", "γρι", "μά", "κια"],
-    "Αγρίμια" => &["Α", "γρί", "μια"],
-    "Αγριοβοράκια" => &["Α", "γρι", "ο", "βο", "ρά", "κια"],
-    "Αγριοβόρια" => &["Α", "γρι", "ο", "βό", "ρια"],
-    "Αγριοπούλια" => &["Α", "γρι", "ο", "πού", "λια"],
-    "Αγρύπνια" => &["Α", "γρύ", "πνια"],
-    "Αγρύπνιας" => &["Α", "γρύ", "πνιας"],
-    "Αγρύπνιες" => &["Α", "γρύ", "πνιες"],
-    "Αγωγάκια" => &["Α", "γω", "γά", "κια"],
-    "Αγώγια" => &["Α", "γώ", "για"],
-    "Αγώια" => &["Α", "γώ", "ια"],
-    "Άδειε" => &["Ά", "δειε"],
-    "Άδειο" => &["Ά", "δειο"],
-    "Άδειοι" => &["Ά", "δειοι"],
-    "Άδειος" => &["Ά", "δειος"],
-    "Άδειου" => &["Ά", "δειου"],
-    "Άδειους" => &["Ά", "δειους"],
-    "Άδειων" => &["Ά", "δειων"],
-    "Αδελφάκια" => &["Α", "δελ", "φά", "κια"],
-    "Αδέλφια" => &["Α", "δέλ", "φια"],
-    "Αδελφομοιράδια" => &["Α", "δελ", "φο", "μοι", "ρά", "δια"],
-    "Αδέρφια" => &["Α", "δέρ", "φια"],
-    "Αδερφομεράδια" => &["Α", "δερ", "φο", "με", "ρά", "δια"],
-    "Αδερφομοιράδια" => &["Α", "δερ", "φο", "μοι", "ρά", "δια"],
-    "Αδερφοπαίδια" => &["Α", "δερ", "φο", "παί", "δια"],
-    "Αδράχτια" => &["Α", "δρά", "χτια"],
-    "Αεράκια" => &["Α", "ε", "ρά", "κια"],
-    "Αέρια" => &["Α", "έ", "ρια"],
-    "Αερομπαλόνια" => &["Α", "ε", "ρο", "μπα", "λό", "νια"],
-    "Αεροπλανάκια" => &["Α", "ε", "ρο", "πλα", "νά", "κια"],
-    "Αετίσια" => &["Α", "ε", "τί", "σια"],
-    "Αετίσιας" => &["Α", "ε", "τί", "σιας"],
-    "Αετίσιε" => &["Α", "ε", "τί", "σιε"],
-    "Αετίσιες" => &["Α", "ε", "τί", "σιες"],
-    "Αετίσιο" => &["Α", "ε", "τί", "σιο"],
-    "Αετίσιοι" => &["Α", "ε", "τί", "σιοι"],
-    "Αετίσιος" => &["Α", "ε", "τί", "σιος"],
-    "Αετίσιου" => &["Α", "ε", "τί", "σιου"],
-    "Αετίσιους" => &["Α", "ε", "τί", "σιους"],
-    "Αετίσιων" => &["Α", "ε", "τί", "σιων"],
-    "Αηδόνι" => &["Αη", "δό", "νι"],
-    "Αηδόνια" => &["Αη", "δό", "νια"],
-    "Αηδονιού" => &["Αη", "δο", "νιού"],
-    "Αηδονίσια" => &["Αη", "δο", "νί", "σια"],
-    "Αηδονίσιας" => &["Αη", "δο", "νί", "σιας"],
-    "Αηδονίσιε" => &["Αη", "δο", "νί", "σιε"],
-    "Αηδονίσιες" => &["Αη", "δο", "νί", "σιες"],
-    "Αηδονίσιο" => &["Αη", "δο", "νί", "σιο"],
-    "Αηδονίσιοι" => &["Αη", "δο", "νί", "σιοι"],
-    "Αηδονίσιος" => &["Αη", "δο", "νί", "σιος"],
-    "Αηδονίσιου" => &["Αη", "δο", "νί", "σιου"],
-    "Αηδονίσιους" => &["Αη", "δο", "νί", "σιους"],
-    "Αηδονίσιων" => &["Αη", "δο", "νί", "σιων"],
-    "Αηδονιών" => &["Αη", "δο", "νιών"],
-    "Ακόνια" => &["Α", "κό", "νια"],
-    "Ακονιστήρια" => &["Α", "κο", "νι", "στή", "ρια"],
-    "Ακουμπιστήρια" => &["Α", "κου", "μπι", "στή", "ρια"],
-    "Ακρογιάλια" => &["Α", "κρο", "γι", "ά", "λια"],
-    "Ακροθαλάσσια" => &["Α", "κρο", "θα", "λάσ", "σια"],
-    "Ακροστόλια" => &["Α", "κρο", "στό", "λια"],
-    "Ακρωτήρια" => &["Α", "κρω", "τή", "ρια"],
-    "Αλανάκια" => &["Α", "λα", "νά", "κια"],
-    "Αλάνια" => &["Α", "λά", "νια"],
-    "Αλατάκια" => &["Α", "λα", "τά", "κια"],
-    "Αλάτια" => &["Α", "λά", "τια"],
-    "Αλάφια" => &["Α", "λά", "φια"],
-    "Αλειμματοκέρια" => &["Α", "λειμ", "μα", "το", "κέ", "ρια"],
-    "Αλεπουδάκια" => &["Α", "λε", "που", "δά", "κια"],
-    "Αλεπουδίσια" => &["Α", "λε", "που", "δί", "σια"],
-    "Αλεπουδίσιας" => &["Α", "λε", "που", "δί", "σιας"],
-    "Αλεπουδίσιε" => &["Α", "λε", "που", "δί", "σιε"],
-    "Αλεπουδίσιες" => &["Α", "λε", "που", "δί", "σιες"],
-    "Αλεπουδίσιο" => &["Α", "λε", "που", "δί", "σιο"],
-    "Αλεπουδίσιοι" => &["Α", "λε", "που", "δί", "σιοι"],
-    "Αλεπουδίσιος" => &["Α", "λε", "που", "δί", "σιος"],
-    "Αλεπουδίσιου" => &["Α", "λε", "που", "δί", "σιου"],
-    "Αλεπουδίσιους" => &["Α", "λε", "που", "δί", "σιους"],
-    "Αλεπουδίσιων" => &["Α", "λε", "που", "δί", "σιων"],
-    "Αλέτρια" => &["Α", "λέ", "τρια"],
-    "Αλετροπόδια" => &["Α", "λε", "τρο", "πό", "δια"],
-    "Αλευρένια" => &["Α", "λευ", "ρέ", "νια"],
-    "Αλευρένιας" => &["Α", "λευ", "ρέ", "νιας"],
-    "Αλευρένιε" => &["Α", "λευ", "ρέ", "νιε"],
-    "Αλευρένιες" => &["Α", "λευ", "ρέ", "νιες"],
-    "Αλευρένιο" => &["Α", "λευ", "ρέ", "νιο"],
-    "Αλευρένιοι" => &["Α", "λευ", "ρέ", "νιοι"],
-    "Αλευρένιος" => &["Α", "λευ", "ρέ", "νιος"],
-    "Αλευρένιου" => &["Α", "λευ", "ρέ", "νιου"],
-    "Αλευρένιους" => &["Α", "λευ", "ρέ", "νιους"],
-    "Αλευρένιων" => &["Α", "λευ", "ρέ", "νιων"],
-    "Αλεύρια" => &["Α", "λεύ", "ρια"],
-    "Αλήθεια" => &["Α", "λή", "θεια"],
-    "Αλήθειας" => &["Α", "λή", "θειας"],
-    "Αλήθειες" => &["Α", "λή", "θειες"],
-    "Αλητάκια" => &["Α", "λη", "τά", "κια"],
-    "Αλισβερίσια" => &["Α", "λι", "σβε", "ρί", "σια"],
-    "Αλκάλια" => &["Αλ", "κά", "λια"],
-    "Αλκοολίκια" => &["Αλ", "κο", "ο", "λί", "κια"],
-    "Αλμυρίκια" => &["Αλ", "μυ", "ρί", "κια"],
-    "Αλογάκια" => &["Α", "λο", "γά", "κια"],
-    "Αλογατάκια" => &["Α", "λο", "γα", "τά", "κια"],
-    "Αλογίσια" => &["Α", "λο", "γί", "σια"],
-    "Αλογίσιας" => &["Α", "λο", "γί", "σιας"],
-    "Αλογίσιε" => &["Α", "λο", "γί", "σιε"],
-    "Αλογίσιες" => &["Α", "λο", "γί", "σιες"],
-    "Αλογίσιο" => &["Α", "λο", "γί", "σιο"],
-    "Αλογίσιοι" => &["Α", "λο", "γί", "σιοι"],
-    "Αλογίσιος" => &["Α", "λο", "γί", "σιος"],
-    "Αλογίσιου" => &["Α", "λο", "γί", "σιου"],
-    "Αλογίσιους" => &["Α", "λο", "γί", "σιους"],
-    "Αλογίσιων" => &["Α", "λο", "γί", "σιων"],
-    "Αλουμινένια" => &["Α", "λου", "μι", "νέ", "νια"],
-    "Αλουμινένιας" => &["Α", "λου", "μι", "νέ", "νιας"],
-    "Αλουμινένιε" => &["Α", "λου", "μι", "νέ", "νιε"],
-    "Αλουμινένιες" => &["Α", "λου", "μι", "νέ", "νιες"],
-    "Αλουμινένιο" => &["Α", "λου", "μι", "νέ", "νιο"],
-    "Αλουμινένιοι" => &["Α", "λου", "μι", "νέ", "νιοι"],
-    "Αλουμινένιος" => &["Α", "λου", "μι", "νέ", "νιος"],
-    "Αλουμινένιου" => &["Α", "λου", "μι", "νέ", "νιου"],
-    "Αλουμινένιους" => &["Α", "λου", "μι", "νέ", "νιους"],
-    "Αλουμινένιων" => &["Α", "λου", "μι", "νέ", "νιων"],
-    "Αλπακαδένια" => &["Αλ", "πα", "κα", "δέ", "νια"],
-    "Αλπακαδένιας" => &["Αλ", "πα", "κα", "δέ", "νιας"],
-    "Αλπακαδένιε" => &["Αλ", "πα", "κα", "δέ", "νιε"],
-    "Αλπακαδένιες" => &["Αλ", "πα", "κα", "δέ", "νιες"],
-    "Αλπακαδένιο" => &["Αλ", "πα", "κα", "δέ", "νιο"],
-    "Αλπακαδένιοι" => &["Αλ", "πα", "κα", "δέ", "νιοι"],
-    "Αλπακαδένιος" => &["Αλ", "πα", "κα", "δέ", "νιος"],
-    "Αλπακαδένιου" => &["Αλ", "πα", "κα", "δέ", "νιου"],
-    "Αλπακαδένιους" => &["Αλ", "πα", "κα", "δέ", "νιους"],
-    "Αλπακαδένιων" => &["Αλ", "πα", "κα", "δέ", "νιων"],
-    "Αλυσιδάκια" => &["Α", "λυ", "σι", "δά", "κια"],
-    "Αλφαβητάρια" => &["Αλ", "φα", "βη", "τά", "ρια"],
-    "Αλφάδια" => &["Αλ", "φά", "δια"],
-    "Αλώνια" => &["Α", "λώ", "νια"],
-    "Αμανάτια" => &["Α", "μα", "νά", "τια"],
-    "Αμαξάκια" => &["Α", "μα", "ξά", "κια"],
-    "Αμάξια" => &["Α", "μά", "ξια"],
-    "Αμερικανάκια" => &["Α", "με", "ρι", "κα", "νά", "κια"],
-    "Άμια" => &["Ά", "μια"],
-    "Άμιας" => &["Ά", "μιας"],
-    "Άμιες" => &["Ά", "μιες"],
-    "Αμμουδένια" => &["Αμ", "μου", "δέ", "νια"],
-    "Αμμουδένιας" => &["Αμ", "μου", "δέ", "νιας"],
-    "Αμμουδένιε" => &["Αμ", "μου", "δέ", "νιε"],
-    "Αμμουδένιες" => &["Αμ", "μου", "δέ", "νιες"],
-    "Αμμουδένιο" => &["Αμ", "μου", "δέ", "νιο"],
-    "Αμμουδένιοι" => &["Αμ", "μου", "δέ", "νιοι"],
-    "Αμμουδένιος" => &["Αμ", "μου", "δέ", "νιος"],
-    "Αμμουδένιου" => &["Αμ", "μου", "δέ", "νιου"],
-    "Αμμουδένιους" => &["Αμ", "μου", "δέ", "νιους"],
-    "Αμμουδένιων" => &["Αμ", "μου", "δέ", "νιων"],
-    "Αμόνια" => &["Α", "μό", "νια"],
-    "Αμπάγια" => &["Α", "μπά", "για"],
-    "Αμπάγιας" => &["Α", "μπά", "γιας"],
-    "Αμπάγιες" => &["Α", "μπά", "γιες"],
-    "Αμπάρια" => &["Α", "μπά", "ρια"],
-    "Αμπελάκια" => &["Α", "μπε", "λά", "κια"],
-    "Αμπέλια" => &["Α", "μπέ", "λια"],
-    "Αναγάλλια" => &["Α", "να", "γάλ", "λια"],
-    "Αναγάλλιας" => &["Α", "να", "γάλ", "λιας"],
-    "Αναγάλλιες" => &["Α", "να", "γάλ", "λιες"],
-    "Αναδεξίμια" => &["Α", "να", "δε", "ξί", "μια"],
-    "Ανάρια" => &["Α", "νά", "ρια"],
-    "Ανάριας" => &["Α", "νά", "ριας"],
-    "Ανάριε" => &["Α", "νά", "ριε"],
-    "Ανάριες" => &["Α", "νά", "ριες"],
-    "Ανάριο" => &["Α", "νά", "ριο"],
-    "Ανάριοι" => &["Α", "νά", "ριοι"],
-    "Ανάριος" => &["Α", "νά", "ριος"],
-    "Ανάριου" => &["Α", "νά", "ριου"],
-    "Ανάριους" => &["Α", "νά", "ριους"],
-    "Ανάριων" => &["Α", "νά", "ριων"],
-    "Ανδράκια" => &["Αν", "δρά", "κια"],
-    "Ανεμίδια" => &["Α", "νε", "μί", "δια"],
-    "Ανεμοβόρια" => &["Α", "νε", "μο", "βό", "ρια"],
-    "Ανεμοβρόχια" => &["Α", "νε", "μο", "βρό", "χια"],
-    "Ανεμογκάστρια" => &["Α", "νε", "μο", "γκά", "στρια"],
-    "Ανεμούρια" => &["Α", "νε", "μού", "ρια"],
-    "Ανημπόρια" => &["Α", "νη", "μπό", "ρια"],
-    "Ανημπόριας" => &["Α", "νη", "μπό", "ριας"],
-    "Ανημπόριες" => &["Α", "νη", "μπό", "ριες"],
-    "Ανηφόρια" => &["Α", "νη", "φό", "ρια"],
-    "Ανθάκια" => &["Αν", "θά", "κια"],
-    "Άνθια" => &["Άν", "θια"],
-    "Ανθιβόλια" => &["Αν", "θι", "βό", "λια"],
-    "Ανθογυάλια" => &["Αν", "θο", "γυ", "ά", "λια"],
-    "Ανθοκλάδια" => &["Αν", "θο", "κλά", "δια"],
-    "Ανθοτόπια" => &["Αν", "θο", "τό", "πια"],
-    "Ανθρωπάκια" => &["Αν", "θρω", "πά", "κια"],
-    "Ανθύλλια" => &["Αν", "θύλ", "λια"],
-    "Ανίσκια" => &["Α", "νί", "σκια"],
-    "Ανίσκιας" => &["Α", "νί", "σκιας"],
-    "Ανίσκιε" => &["Α", "νί", "σκιε"],
-    "Ανίσκιες" => &["Α", "νί", "σκιες"],
-    "Ανίσκιο" => &["Α", "νί", "σκιο"],
-    "Ανίσκιοι" => &["Α", "νί", "σκιοι"],
-    "Ανίσκιος" => &["Α", "νί", "σκιος"],
-    "Ανίσκιου" => &["Α", "νί", "σκιου"],
-    "Ανίσκιους" => &["Α", "νί", "σκιους"],
-    "Ανίσκιων" => &["Α", "νί", "σκιων"],
-    "Ανιψάκια" => &["Α", "νι", "ψά", "κια"],
-    "Ανίψια" => &["Α", "νί", "ψια"],
-    "Ανοικτήρια" => &["Α", "νοι", "κτή", "ρια"],
-    "Ανοιχτήρια" => &["Α", "νοι", "χτή", "ρια"],
-    "Αντέτια" => &["Α", "ντέ", "τια"],
-    "Αντζούγια" => &["Α", "ντζού", "για"],
-    "Αντζούγιας" => &["Α", "ντζού", "γιας"],
-    "Αντζούγιες" => &["Α", "ντζού", "γιες"],
-    "Αντίδια" => &["Α", "ντί", "δια"],
-    "Αντικλείδια" => &["Α", "ντι", "κλεί", "δια"],
-    "Αντιπροίκια" => &["Α", "ντι", "προί", "κια"],
-    "Αντιστύλια" => &["Α", "ντι", "στύ", "λια"],
-    "Αντράκια" => &["Α", "ντρά", "κια"],
-    "Αντρίκεια" => &["Α", "ντρί", "κεια"],
-    "Αντρίκειας" => &["Α", "ντρί", "κειας"],
-    "Αντρίκειε" => &["Α", "ντρί", "κειε"],
-    "Αντρίκειες" => &["Α", "ντρί", "κειες"],
-    "Αντρίκειο" => &["Α", "ντρί", "κειο"],
-    "Αντρίκειοι" => &["Α", "ντρί", "κειοι"],
-    "Αντρίκειος" => &["Α", "ντρί", "κειος"],
-    "Αντρίκειου" => &["Α", "ντρί", "κειου"],
-    "Αντρίκειους" => &["Α", "ντρί", "κειους"],
-    "Αντρίκειων" => &["Α", "ντρί", "κειων"],
-    "Αντρίκια" => &["Α", "ντρί", "κια"],
-    "Αντρίκιας" => &["Α", "ντρί", "κιας"],
-    "Αντρίκιε" => &["Α", "ντρί", "κιε"],
-    "Αντρίκιες" => &["Α", "ντρί", "κιες"],
-    "Αντρίκιο" => &["Α", "ντρί", "κιο"],
-    "Αντρίκιοι" => &["Α", "ντρί", "κιοι"],
-    "Αντρίκιος" => &["Α", "ντρί", "κιος"],
-    "Αντρίκιου" => &["Α", "ντρί", "κιου"],
-    "Αντρίκιους" => &["Α", "ντρί", "κιους"],
-    "Αντρίκιων" => &["Α", "ντρί", "κιων"],
-    "Αντσούγια" => &["Α", "ντσού", "για"],
-    "Αντσούγιας" => &["Α", "ντσού", "γιας"],
-    "Αντσούγιες" => &["Α", "ντσού", "γιες"],
-    "Ανώγια" => &["Α", "νώ", "για"],
-    "Ανώια" => &["Α", "νώ", "ια"],
-    "Ανώφλια" => &["Α", "νώ", "φλια"],
-    "Απάγγεια" => &["Α", "πάγ", "γεια"],
-    "Απάγγειας" => &["Α", "πάγ", "γειας"],
-    "Απάγγειε" => &["Α", "πάγ", "γειε"],
-    "Απάγγειες" => &["Α", "πάγ", "γειες"],
-    "Απάγγειο" => &["Α", "πάγ", "γειο"],
-    "Απάγγειοι" => &["Α", "πάγ", "γειοι"],
-    "Απάγγειος" => &["Α", "πάγ", "γειος"],
-    "Απάγγειου" => &["Α", "πάγ", "γειου"],
-    "Απάγγειους" => &["Α", "πάγ", "γειους"],
-    "Απάγγειων" => &["Α", "πάγ", "γειων"],
-    "Απάγκεια" => &["Α", "πά", "γκεια"],
-    "Απάγκειας" => &["Α", "πά", "γκειας"],
-    "Απάγκειε" => &["Α", "πά", "γκειε"],
-    "Απάγκειες" => &["Α", "πά", "γκειες"],
-    "Απάγκειο" => &["Α", "πά", "γκειο"],
-    "Απάγκειοι" => &["Α", "πά", "γκειοι"],
-    "Απάγκειος" => &["Α", "πά", "γκειος"],
-    "Απάγκειου" => &["Α", "πά", "γκειου"],
-    "Απάγκειους" => &["Α", "πά", "γκειους"],
-    "Απάγκειων" => &["Α", "πά", "γκειων"],
-    "Απάγκια" => &["Α", "πά", "γκια"],
-    "Απάγκιας" => &["Α", "πά", "γκιας"],
-    "Απάγκιε" => &["Α", "πά", "γκιε"],
-    "Απάγκιες" => &["Α", "πά", "γκιες"],
-    "Απάγκιο" => &["Α", "πά", "γκιο"],
-    "Απάγκιοι" => &["Α", "πά", "γκιοι"],
-    "Απάγκιος" => &["Α", "πά", "γκιος"],
-    "Απάγκιου" => &["Α", "πά", "γκιου"],
-    "Απάγκιους" => &["Α", "πά", "γκιους"],
-    "Απάγκιων" => &["Α", "πά", "γκιων"],
-    "Απανωπροίκια" => &["Α", "πα", "νω", "προί", "κια"],
-    "Απελατίκια" => &["Α", "πε", "λα", "τί", "κια"],
-    "Απίδια" => &["Α", "πί", "δια"],
-    "Απογευματάκια" => &["Α", "πο", "γευ", "μα", "τά", "κια"],
-    "Αποκαΐδια" => &["Α", "πο", "κα", "ΐ", "δια"],
-    "Αποκλάδια" => &["Α", "πο", "κλά", "δια"],
-    "Αποκούμπια" => &["Α", "πο", "κού", "μπια"],
-    "Απολειφάδια" => &["Α", "πο", "λει", "φά", "δια"],
-    "Απομεινάρια" => &["Α", "πο", "μει", "νά", "ρια"],
-    "Απονύχια" => &["Α", "πο", "νύ", "χια"],
-    "Αποπαιδάκια" => &["Α", "πο", "παι", "δά", "κια"],
-    "Αποπαίδια" => &["Α", "πο", "παί", "δια"],
-    "Αποσαρίδια" => &["Α", "πο", "σα", "ρί", "δια"],
-    "Απόσκια" => &["Α", "πό", "σκια"],
-    "Απόσκιας" => &["Α", "πό", "σκιας"],
-    "Απόσκιε" => &["Α", "πό", "σκιε"],
-    "Απόσκιες" => &["Α", "πό", "σκιες"],
-    "Απόσκιο" => &["Α", "πό", "σκιο"],
-    "Απόσκιοι" => &["Α", "πό", "σκιοι"],
-    "Απόσκιος" => &["Α", "πό", "σκιος"],
-    "Απόσκιου" => &["Α", "πό", "σκιου"],
-    "Απόσκιους" => &["Α", "πό", "σκιους"],
-    "Απόσκιων" => &["Α", "πό", "σκιων"],
-    "Αποσπόρια" => &["Α", "πο", "σπό", "ρια"],
-    "Αποστραγγίδια" => &["Α", "πο", "στραγ", "γί", "δια"],
-    "Αποτρύγια" => &["Α", "πο", "τρύ", "για"],
-    "Αποτρυγίδια" => &["Α", "πο", "τρυ", "γί", "δια"],
-    "Αποφάγια" => &["Α", "πο", "φά", "για"],
-    "Αποφόρια" => &["Α", "πο", "φό", "ρια"],
-    "Αποχτενίδια" => &["Α", "πο", "χτε", "νί", "δια"],
-    "Αραλίκια" => &["Α", "ρα", "λί", "κια"],
-    "Αραξοβόλια" => &["Α", "ρα", "ξο", "βό", "λια"],
-    "Αραπάκια" => &["Α", "ρα", "πά", "κια"],
-    "Αραποσίτια" => &["Α", "ρα", "πο", "σί", "τια"],
-    "Αραρούτια" => &["Α", "ρα", "ρού", "τια"],
-    "Αραχνένια" => &["Α", "ρα", "χνέ", "νια"],
-    "Αραχνένιας" => &["Α", "ρα", "χνέ", "νιας"],
-    "Αραχνένιε" => &["Α", "ρα", "χνέ", "νιε"],
-    "Αραχνένιες" => &["Α", "ρα", "χνέ", "νιες"],
-    "Αραχνένιο" => &["Α", "ρα", "χνέ", "νιο"],
-    "Αραχνένιοι" => &["Α", "ρα", "χνέ", "νιοι"],
-    "Αραχνένιος" => &["Α", "ρα", "χνέ", "νιος"],
-    "Αραχνένιου" => &["Α", "ρα", "χνέ", "νιου"],
-    "Αραχνένιους" => &["Α", "ρα", "χνέ", "νιους"],
-    "Αραχνένιων" => &["Α", "ρα", "χνέ", "νιων"],
-    "Αρβανιτοχώρια" => &["Αρ", "βα", "νι", "το", "χώ", "ρια"],
-    "Αργαστήρια" => &["Αρ", "γα", "στή", "ρια"],
-    "Αρκουδάκια" => &["Αρ", "κου", "δά", "κια"],
-    "Αρκούδια" => &["Αρ", "κού", "δια"],
-    "Αρκουδίσια" => &["Αρ", "κου", "δί", "σια"],
-    "Αρκουδίσιας" => &["Αρ", "κου", "δί", "σιας"],
-    "Αρκουδίσιε" => &["Αρ", "κου", "δί", "σιε"],
-    "Αρκουδίσιες" => &["Αρ", "κου", "δί", "σιες"],
-    "Αρκουδίσιο" => &["Αρ", "κου", "δί", "σιο"],
-    "Αρκουδίσιοι" => &["Αρ", "κου", "δί", "σιοι"],
-    "Αρκουδίσιος" => &["Αρ", "κου", "δί", "σιος"],
-    "Αρκουδίσιου" => &["Αρ", "κου", "δί", "σιου"],
-    "Αρκουδίσιους" => &["Αρ", "κου", "δί", "σιους"],
-    "Αρκουδίσιων" => &["Αρ", "κου", "δί", "σιων"],
-    "Αρμάρια" => &["Αρ", "μά", "ρια"],
-    "Αρματολίκια" => &["Αρ", "μα", "το", "λί", "κια"],
-    "Αρμίδια" => &["Αρ", "μί", "δια"],
-    "Αρμυρίκια" => &["Αρ", "μυ", "ρί", "κια"],
-    "Αρνάκια" => &["Αρ", "νά", "κια"],
-    "Αρνίσια" => &["Αρ", "νί", "σια"],
-    "Αρνίσιας" => &["Αρ", "νί", "σιας"],
-    "Αρνίσιε" => &["Αρ", "νί", "σιε"],
-    "Αρνίσιες" => &["Αρ", "νί", "σιες"],
-    "Αρνίσιο" => &["Αρ", "νί", "σιο"],
-    "Αρνίσιοι" => &["Αρ", "νί", "σιοι"],
-    "Αρνίσιος" => &["Αρ", "νί", "σιος"],
-    "Αρνίσιου" => &["Αρ", "νί", "σιου"],
-    "Αρνίσιους" => &["Αρ", "νί", "σιους"],
-    "Αρνίσιων" => &["Αρ", "νί", "σιων"],
-    "Αρρώστεια" => &["Αρ", "ρώ", "στεια"],
-    "Αρρώστειας" => &["Αρ", "ρώ", "στειας"],
-    "Αρρώστειες" => &["Αρ", "ρώ", "στειες"],
-    "Αρρώστια" => &["Αρ", "ρώ", "στια"],
-    "Αρρώστιας" => &["Αρ", "ρώ", "στιας"],
-    "Αρρώστιες" => &["Αρ", "ρώ", "στιες"],
-    "Άρτια" => &["Άρ", "τια"],
-    "Αρχίδια" => &["Αρ", "χί", "δια"],
-    "Αρχονταρίκια" => &["Αρ", "χο", "ντα", "ρί", "κια"],
-    "Ασημένια" => &["Α", "ση", "μέ", "νια"],
-    "Ασημένιας" => &["Α", "ση", "μέ", "νιας"],
-    "Ασημένιε" => &["Α", "ση", "μέ", "νιε"],
-    "Ασημένιες" => &["Α", "ση", "μέ", "νιες"],
-    "Ασημένιο" => &["Α", "ση", "μέ", "νιο"],
-    "Ασημένιοι" => &["Α", "ση", "μέ", "νιοι"],
-    "Ασημένιος" => &["Α", "ση", "μέ", "νιος"],
-    "Ασημένιου" => &["Α", "ση", "μέ", "νιου"],
-    "Ασημένιους" => &["Α", "ση", "μέ", "νιους"],
-    "Ασημένιων" => &["Α", "ση", "μέ", "νιων"],
-    "Ασήμια" => &["Α", "σή", "μια"],
-    "Ασικλίκια" => &["Α", "σι", "κλί", "κια"],
-    "Ασκέρια" => &["Α", "σκέ", "ρια"],
-    "Ασλάνια" => &["Ασ", "λά", "νια"],
-    "Ασπράδια" => &["Α", "σπρά", "δια"],
-    "Αστάρια" => &["Α", "στά", "ρια"],
-    "Αστειάκια" => &["Α", "στει", "ά", "κια"],
-    "Αστεράκια" => &["Α", "στε", "ρά", "κια"],
-    "Αστέρια" => &["Α", "στέ", "ρια"],
-    "Αστράκια" => &["Α", "στρά", "κια"],
-    "Άστρια" => &["Ά", "στρια"],
-    "Αστροπελέκια" => &["Α", "στρο", "πε", "λέ", "κια"],
-    "Ασυμπόνια" => &["Α", "συ", "μπό", "νια"],
-    "Ασυμπόνιας" => &["Α", "συ", "μπό", "νιας"],
-    "Ασυμπόνιες" => &["Α", "συ", "μπό", "νιες"],
-    "Ασφοδέλια" => &["Α", "σφο", "δέ", "λια"],
-    "Ασφοδίλια" => &["Α", "σφο", "δί", "λια"],
-    "Ασχήμια" => &["Α", "σχή", "μια"],
-    "Ασχήμιας" => &["Α", "σχή", "μιας"],
-    "Ασχήμιες" => &["Α", "σχή", "μιες"],
-    "Ατζούγια" => &["Α", "τζού", "για"],
-    "Ατζούγιας" => &["Α", "τζού", "γιας"],
-    "Ατζούγιες" => &["Α", "τζού", "γιες"],
-    "Άτια" => &["Ά", "τια"],
-    "Ατλαζένια" => &["Α", "τλα", "ζέ", "νια"],
-    "Ατλαζένιας" => &["Α", "τλα", "ζέ", "νιας"],
-    "Ατλαζένιε" => &["Α", "τλα", "ζέ", "νιε"],
-    "Ατλαζένιες" => &["Α", "τλα", "ζέ", "νιες"],
-    "Ατλαζένιο" => &["Α", "τλα", "ζέ", "νιο"],
-    "Ατλαζένιοι" => &["Α", "τλα", "ζέ", "νιοι"],
-    "Ατλαζένιος" => &["Α", "τλα", "ζέ", "νιος"],
-    "Ατλαζένιου" => &["Α", "τλα", "ζέ", "νιου"],
-    "Ατλαζένιους" => &["Α", "τλα", "ζέ", "νιους"],
-    "Ατλαζένιων" => &["Α", "τλα", "ζέ", "νιων"],
-    "Ατλάζια" => &["Α", "τλά", "ζια"],
-    "Ατσαλένια" => &["Α", "τσα", "λέ", "νια"],
-    "Ατσαλένιας" => &["Α", "τσα", "λέ", "νιας"],
-    "Ατσαλένιε" => &["Α", "τσα", "λέ", "νιε"],
-    "Ατσαλένιες" => &["Α", "τσα", "λέ", "νιες"],
-    "Ατσαλένιο" => &["Α", "τσα", "λέ", "νιο"],
-    "Ατσαλένιοι" => &["Α", "τσα", "λέ", "νιοι"],
-    "Ατσαλένιος" => &["Α", "τσα", "λέ", "νιος"],
-    "Ατσαλένιου" => &["Α", "τσα", "λέ", "νιου"],
-    "Ατσαλένιους" => &["Α", "τσα", "λέ", "νιους"],
-    "Ατσαλένιων" => &["Α", "τσα", "λέ", "νιων"],
-    "Ατσάλια" => &["Α", "τσά", "λια"],
-    "Αυλάκια" => &["Αυ", "λά", "κια"],
-    "Αυτάκια" => &["Αυ", "τά", "κια"],
-    "Αυτοκινητάκια" => &["Αυ", "το", "κι", "νη", "τά", "κια"],
-    "Αυτοφωράκια" => &["Αυ", "το", "φω", "ρά", "κια"],
-    "Αυτοφωράκιας" => &["Αυ", "το", "φω", "ρά", "κιας"],
-    "Αυτοφωράκιες" => &["Αυ", "το", "φω", "ρά", "κιες"],
-    "Αφάλια" => &["Α", "φά", "λια"],
-    "Αφιόνια" => &["Α", "φι", "ό", "νια"],
-    "Αχάμνια" => &["Α", "χά", "μνια"],
-    "Αχάμνιας" => &["Α", "χά", "μνιας"],
-    "Αχάμνιες" => &["Α", "χά", "μνιες"],
-    "Αχείλια" => &["Α", "χεί", "λια"],
-    "Αχλάδια" => &["Α", "χλά", "δια"],
-    "Αχνάρια" => &["Α", "χνά", "ρια"],
-    "Αχνοκέρια" => &["Α", "χνο", "κέ", "ρια"],
-    "Αχολόγια" => &["Α", "χο", "λό", "για"],
-    "Αχούρια" => &["Α", "χού", "ρια"],
-    "Αχυρένια" => &["Α", "χυ", "ρέ", "νια"],
-    "Αχυρένιας" => &["Α", "χυ", "ρέ", "νιας"],
-    "Αχυρένιε" => &["Α", "χυ", "ρέ", "νιε"],
-    "Αχυρένιες" => &["Α", "χυ", "ρέ", "νιες"],
-    "Αχυρένιο" => &["Α", "χυ", "ρέ", "νιο"],
-    "Αχυρένιοι" => &["Α", "χυ", "ρέ", "νιοι"],
-    "Αχυρένιος" => &["Α", "χυ", "ρέ", "νιος"],
-    "Αχυρένιου" => &["Α", "χυ", "ρέ", "νιου"],
-    "Αχυρένιους" => &["Α", "χυ", "ρέ", "νιους"],
-    "Αχυρένιων" => &["Α", "χυ", "ρέ", "νιων"],
-    "Αψέντια" => &["Α", "ψέ", "ντια"],
-    "Βαβούλια" => &["Βα", "βού", "λια"],
-    "Βαγένια" => &["Βα", "γέ", "νια"],
-    "Βάγια" => &["Βά", "για"],
-    "Βάγιας" => &["Βά", "γιας"],
-    "Βάγιες" => &["Βά", "γιες"],
-    "Βάγιο" => &["Βά", "γιο"],
-    "Βάγιου" => &["Βά", "γιου"],
-    "Βάγιων" => &["Βά", "γιων"],
-    "Βαγονάκια" => &["Βα", "γο", "νά", "κια"],
-    "Βαγόνια" => &["Βα", "γό", "νια"],
-    "Βαζάκια" => &["Βα", "ζά", "κια"],
-    "Βαθύσκια" => &["Βα", "θύ", "σκια"],
-    "Βαθύσκιας" => &["Βα", "θύ", "σκιας"],
-    "Βαθύσκιε" => &["Βα", "θύ", "σκιε"],
-    "Βαθύσκιες" => &["Βα", "θύ", "σκιες"],
-    "Βαθύσκιο" => &["Βα", "θύ", "σκιο"],
-    "Βαθύσκιοι" => &["Βα", "θύ", "σκιοι"],
-    "Βαθύσκιος" => &["Βα", "θύ", "σκιος"],
-    "Βαθύσκιου" => &["Βα", "θύ", "σκιου"],
-    "Βαθύσκιους" => &["Βα", "θύ", "σκιους"],
-    "Βαθύσκιων" => &["Βα", "θύ", "σκιων"],
-    "Βακούφια" => &["Βα", "κού", "φια"],
-    "Βαλανίδια" => &["Βα", "λα", "νί", "δια"],
-    "Βαλιτσάκια" => &["Βα", "λι", "τσά", "κια"],
-    "Βαλτονέρια" => &["Βαλ", "το", "νέ", "ρια"],
-    "Βαλτοτόπια" => &["Βαλ", "το", "τό", "πια"],
-    "Βαμβακάκια" => &["Βαμ", "βα", "κά", "κια"],
-    "Βαμβακένια" => &["Βαμ", "βα", "κέ", "νια"],
-    "Βαμβακένιας" => &["Βαμ", "βα", "κέ", "νιας"],
-    "Βαμβακένιε" => &["Βαμ", "βα", "κέ", "νιε"],
-    "Βαμβακένιες" => &["Βαμ", "βα", "κέ", "νιες"],
-    "Βαμβακένιο" => &["Βαμ", "βα", "κέ", "νιο"],
-    "Βαμβακένιοι" => &["Βαμ", "βα", "κέ", "νιοι"],
-    "Βαμβακένιος" => &["Βαμ", "βα", "κέ", "νιος"],
-    "Βαμβακένιου" => &["Βαμ", "βα", "κέ", "νιου"],
-    "Βαμβακένιους" => &["Βαμ", "βα", "κέ", "νιους"],
-    "Βαμβακένιων" => &["Βαμ", "βα", "κέ", "νιων"],
-    "Βαμβάκια" => &["Βαμ", "βά", "κια"],
-    "Βανίλια" => &["Βα", "νί", "λια"],
-    "Βανίλιας" => &["Βα", "νί", "λιας"],
-    "Βανίλιες" => &["Βα", "νί", "λιες"],
-    "Βαποράκια" => &["Βα", "πο", "ρά", "κια"],
-    "Βαπόρια" => &["Βα", "πό", "ρια"],
-    "Βαπορίσια" => &["Βα", "πο", "ρί", "σια"],
-    "Βαπορίσιας" => &["Βα", "πο", "ρί", "σιας"],
-    "Βαπορίσιε" => &["Βα", "πο", "ρί", "σιε"],
-    "Βαπορίσιες" => &["Βα", "πο", "ρί", "σιες"],
-    "Βαπορίσιο" => &["Βα", "πο", "ρί", "σιο"],
-    "Βαπορίσιοι" => &["Βα", "πο", "ρί", "σιοι"],
-    "Βαπορίσιος" => &["Βα", "πο", "ρί", "σιος"],
-    "Βαπορίσιου" => &["Βα", "πο", "ρί", "σιου"],
-    "Βαπορίσιους" => &["Βα", "πο", "ρί", "σιους"],
-    "Βαπορίσιων" => &["Βα", "πο", "ρί", "σιων"],
-    "Βαρελάκια" => &["Βα", "ρε", "λά", "κια"],
-    "Βαρέλια" => &["Βα", "ρέ", "λια"],
-    "Βαρελίσια" => &["Βα", "ρε", "λί", "σια"],
-    "Βαρελίσιας" => &["Βα", "ρε", "λί", "σιας"],
-    "Βαρελίσιε" => &["Βα", "ρε", "λί", "σιε"],
-    "Βαρελίσιες" => &["Βα", "ρε", "λί", "σιες"],
-    "Βαρελίσιο" => &["Βα", "ρε", "λί", "σιο"],
-    "Βαρελίσιοι" => &["Βα", "ρε", "λί", "σιοι"],
-    "Βαρελίσιος" => &["Βα", "ρε", "λί", "σιος"],
-    "Βαρελίσιου" => &["Βα", "ρε", "λί", "σιου"],
-    "Βαρελίσιους" => &["Βα", "ρε", "λί", "σιους"],
-    "Βαρελίσιων" => &["Βα", "ρε", "λί", "σιων"],
-    "Βαρίδια" => &["Βα", "ρί", "δια"],
-    "Βαρκάκια" => &["Βαρ", "κά", "κια"],
-    "Βαρυγκόμια" => &["Βα", "ρυ", "γκό", "μια"],
-    "Βαρυγκόμιας" => &["Βα", "ρυ", "γκό", "μιας"],
-    "Βαρυγκόμιες" => &["Βα", "ρυ", "γκό", "μιες"],
-    "Βαρυγκώμια" => &["Βα", "ρυ", "γκώ", "μια"],
-    "Βαρυγκώμιας" => &["Βα", "ρυ", "γκώ", "μιας"],
-    "Βαρυγκώμιες" => &["Βα", "ρυ", "γκώ", "μιες"],
-    "Βασανάκια" => &["Βα", "σα", "νά", "κια"],
-    "Βασιλίκια" => &["Βα", "σι", "λί", "κια"],
-    "Βασιλοπαίδια" => &["Βα", "σι", "λο", "παί", "δια"],
-    "Βατραχάκια" => &["Βα", "τρα", "χά", "κια"],
-    "Βατράχια" => &["Βα", "τρά", "χια"],
-    "Βαφτιστήρια" => &["Βα", "φτι", "στή", "ρια"],
-    "Βεδούρια" => &["Βε", "δού", "ρια"],
-    "Βελάγια" => &["Βε", "λά", "για"],
-    "Βελάγιο" => &["Βε", "λά", "γιο"],
-    "Βελάγιου" => &["Βε", "λά", "γιου"],
-    "Βελάγιων" => &["Βε", "λά", "γιων"],
-    "Βελάκια" => &["Βε", "λά", "κια"],
-    "Βελανιδένια" => &["Βε", "λα", "νι", "δέ", "νια"],
-    "Βελανιδένιας" => &["Βε", "λα", "νι", "δέ", "νιας"],
-    "Βελανιδένιε" => &["Βε", "λα", "νι", "δέ", "νιε"],
-    "Βελανιδένιες" => &["Βε", "λα", "νι", "δέ", "νιες"],
-    "Βελανιδένιο" => &["Βε", "λα", "νι", "δέ", "νιο"],
-    "Βελανιδένιοι" => &["Βε", "λα", "νι", "δέ", "νιοι"],
-    "Βελανιδένιος" => &["Βε", "λα", "νι", "δέ", "νιος"],
-    "Βελανιδένιου" => &["Βε", "λα", "νι", "δέ", "νιου"],
-    "Βελανιδένιους" => &["Βε", "λα", "νι", "δέ", "νιους"],
-    "Βελανιδένιων" => &["Βε", "λα", "νι", "δέ", "νιων"],
-    "Βελανίδια" => &["Βε", "λα", "νί", "δια"],
-    "Βελονάκια" => &["Βε", "λο", "νά", "κια"],
-    "Βελονάκιας" => &["Βε", "λο", "νά", "κιας"],
-    "Βελονάκιες" => &["Βε", "λο", "νά", "κιες"],
-    "Βελόνια" => &["Βε", "λό", "νια"],
-    "Βελουδένια" => &["Βε", "λου", "δέ", "νια"],
-    "Βελουδένιας" => &["Βε", "λου", "δέ", "νιας"],
-    "Βελουδένιε" => &["Βε", "λου", "δέ", "νιε"],
-    "Βελουδένιες" => &["Βε", "λου", "δέ", "νιες"],
-    "Βελουδένιο" => &["Βε", "λου", "δέ", "νιο"],
-    "Βελουδένιοι" => &["Βε", "λου", "δέ", "νιοι"],
-    "Βελουδένιος" => &["Βε", "λου", "δέ", "νιος"],
-    "Βελουδένιου" => &["Βε", "λου", "δέ", "νιου"],
-    "Βελουδένιους" => &["Βε", "λου", "δέ", "νιους"],
-    "Βελουδένιων" => &["Βε", "λου", "δέ", "νιων"],
-    "Βελούχια" => &["Βε", "λού", "χια"],
-    "Βεντάγια" => &["Βε", "ντά", "για"],
-    "Βεντάγιας" => &["Βε", "ντά", "γιας"],
-    "Βεντάγιες" => &["Βε", "ντά", "γιες"],
-    "Βεντάλια" => &["Βε", "ντά", "λια"],
-    "Βεντάλιας" => &["Βε", "ντά", "λιας"],
-    "Βεντάλιες" => &["Βε", "ντά", "λιες"],
-    "Βερεσέδια" => &["Βε", "ρε", "σέ", "δια"],
-    "Βερνίκια" => &["Βερ", "νί", "κια"],
-    "Βηματάκια" => &["Βη", "μα", "τά", "κια"],
-    "Βια" => &["Βια"],
-    "Βιας" => &["Βιας"],
-    "Βιβάρια" => &["Βι", "βά", "ρια"],
-    "Βιβλιαράκια" => &["Βι", "βλι", "α", "ρά", "κια"],
-    "Βιγόνια" => &["Βι", "γό", "νια"],
-    "Βιγόνιας" => &["Βι", "γό", "νιας"],
-    "Βιγόνιες" => &["Βι", "γό", "νιες"],
-    "Βιδωτήρια" => &["Βι", "δω", "τή", "ρια"],
-    "Βιες" => &["Βιες"],
-    "Βιντεοπαιχνίδια" => &["Βι", "ντε", "ο", "παι", "χνί", "δια"],
-    "Βίντσια" => &["Βί", "ντσια"],
-    "Βιο" => &["Βιο"],
-    "Βιος" => &["Βιος"],
-    "Βιτριόλια" => &["Βι", "τρι", "ό", "λια"],
-    "Βλαστάρια" => &["Βλα", "στά", "ρια"],
-    "Βλαστήμια" => &["Βλα", "στή", "μια"],
-    "Βλαστήμιας" => &["Βλα", "στή", "μιας"],
-    "Βλαστήμιες" => &["Βλα", "στή", "μιες"],
-    "Βλαχάκια" => &["Βλα", "χά", "κια"],
-    "Βλαχοχώρια" => &["Βλα", "χο", "χώ", "ρια"],
-    "Βόδια" => &["Βό", "δια"],
-    "Βόιδι" => &["Βόι", "δι"],
-    "Βόιδια" => &["Βόι", "δια"],
-    "Βοϊδιού" => &["Βοϊ", "διού"],
-    "Βοϊδιών" => &["Βοϊ", "διών"],
-    "Βοκαμβίλια" => &["Βο", "καμ", "βί", "λια"],
-    "Βοκαμβίλιας" => &["Βο", "καμ", "βί", "λιας"],
-    "Βοκαμβίλιες" => &["Βο", "καμ", "βί", "λιες"],
-    "Βολεψάκια" => &["Βο", "λε", "ψά", "κια"],
-    "Βολεψάκιας" => &["Βο", "λε", "ψά", "κιας"],
-    "Βολεψάκιες" => &["Βο", "λε", "ψά", "κιες"],
-    "Βόλια" => &["Βό", "λια"],
-    "Βοριαδάκια" => &["Βο", "ρι", "α", "δά", "κια"],
-    "Βοσκοτόπια" => &["Βο", "σκο", "τό", "πια"],
-    "Βοτάνια" => &["Βο", "τά", "νια"],
-    "Βουβάλια" => &["Βου", "βά", "λια"],
-    "Βουβαλίσια" => &["Βου", "βα", "λί", "σια"],
-    "Βουβαλίσιας" => &["Βου", "βα", "λί", "σιας"],
-    "Βουβαλίσιε" => &["Βου", "βα", "λί", "σιε"],
-    "Βουβαλίσιες" => &["Βου", "βα", "λί", "σιες"],
-    "Βουβαλίσιο" => &["Βου", "βα", "λί", "σιο"],
-    "Βουβαλίσιοι" => &["Βου", "βα", "λί", "σιοι"],
-    "Βουβαλίσιος" => &["Βου", "βα", "λί", "σιος"],
-    "Βουβαλίσιου" => &["Βου", "βα", "λί", "σιου"],
-    "Βουβαλίσιους" => &["Βου", "βα", "λί", "σιους"],
-    "Βουβαλίσιων" => &["Βου", "βα", "λί", "σιων"],
-    "Βουλευτιλίκια" => &["Βου", "λευ", "τι", "λί", "κια"],
-    "Βουλοκέρια" => &["Βου", "λο", "κέ", "ρια"],
-    "Βουνάκια" => &["Βου", "νά", "κια"],
-    "Βουναλάκια" => &["Βου", "να", "λά", "κια"],
-    "Βουνίσια" => &["Βου", "νί", "σια"],
-    "Βουνίσιας" => &["Βου", "νί", "σιας"],
-    "Βουνίσιε" => &["Βου", "νί", "σιε"],
-    "Βουνίσιες" => &["Βου", "νί", "σιες"],
-    "Βουνίσιο" => &["Βου", "νί", "σιο"],
-    "Βουνίσιοι" => &["Βου", "νί", "σιοι"],
-    "Βουνίσιος" => &["Βου", "νί", "σιος"],
-    "Βουνίσιου" => &["Βου", "νί", "σιου"],
-    "Βουνίσιους" => &["Βου", "νί", "σιους"],
-    "Βουνίσιων" => &["Βου", "νί", "σιων"],
-    "Βουρκάρια" => &["Βουρ", "κά", "ρια"],
-    "Βουρκονέρια" => &["Βουρ", "κο", "νέ", "ρια"],
-    "Βουρκοτόπια" => &["Βουρ", "κο", "τό", "πια"],
-    "Βουρτσάκια" => &["Βουρ", "τσά", "κια"],
-    "Βουτυρένια" => &["Βου", "τυ", "ρέ", "νια"],
-    "Βουτυρένιας" => &["Βου", "τυ", "ρέ", "νιας"],
-    "Βουτυρένιε" => &["Βου", "τυ", "ρέ", "νιε"],
-    "Βουτυρένιες" => &["Βου", "τυ", "ρέ", "νιες"],
-    "Βουτυρένιο" => &["Βου", "τυ", "ρέ", "νιο"],
-    "Βουτυρένιοι" => &["Βου", "τυ", "ρέ", "νιοι"],
-    "Βουτυρένιος" => &["Βου", "τυ", "ρέ", "νιος"],
-    "Βουτυρένιου" => &["Βου", "τυ", "ρέ", "νιου"],
-    "Βουτυρένιους" => &["Βου", "τυ", "ρέ", "νιους"],
-    "Βουτυρένιων" => &["Βου", "τυ", "ρέ", "νιων"],
-    "Βραδάκια" => &["Βρα", "δά", "κια"],
-    "Βράδια" => &["Βρά", "δια"],
-    "Βρακάκια" => &["Βρα", "κά", "κια"],
-    "Βραχάκια" => &["Βρα", "χά", "κια"],
-    "Βραχιόλια" => &["Βρα", "χι", "ό", "λια"],
-    "Βραχονήσια" => &["Βρα", "χο", "νή", "σια"],
-    "Βραχοτόπια" => &["Βρα", "χο", "τό", "πια"],
-    "Βρεθίκια" => &["Βρε", "θί", "κια"],
-    "Βρεσίδια" => &["Βρε", "σί", "δια"],
-    "Βρετίκια" => &["Βρε", "τί", "κια"],
-    "Βρισίδια" => &["Βρι", "σί", "δια"],
-    "Βρομονέρια" => &["Βρο", "μο", "νέ", "ρια"],
-    "Βρόχια" => &["Βρό", "χια"],
-    "Βυζάκια" => &["Βυ", "ζά", "κια"],
-    "Βυζασταρούδια" => &["Βυ", "ζα", "στα", "ρού", "δια"],
-    "Γαζάκια" => &["Γα", "ζά", "κια"],
-    "Γάιδαρε" => &["Γάι", "δα", "ρε"],
-    "Γάιδαρο" => &["Γάι", "δα", "ρο"],
-    "Γάιδαροι" => &["Γάι", "δα", "ροι"],
-    "Γάιδαρος" => &["Γάι", "δα", "ρος"],
-    "Γάιδαρου" => &["Γάι", "δα", "ρου"],
-    "Γάιδαρους" => &["Γάι", "δα", "ρους"],
-    "Γάιδαρων" => &["Γάι", "δα", "ρων"],
-    "Γαϊδουράκια" => &["Γα", "ϊ", "δου", "ρά", "κια"],
-    "Γαϊδούρι" => &["Γαϊ", "δού", "ρι"],
-    "Γαϊδούρια" => &["Γαϊ", "δού", "ρια"],
-    "Γαϊδουριού" => &["Γαϊ", "δου", "ριού"],
-    "Γαϊδουρίσια" => &["Γαϊ", "δου", "ρί", "σια"],
-    "Γαϊδουρίσιας" => &["Γαϊ", "δου", "ρί", "σιας"],
-    "Γαϊδουρίσιε" => &["Γαϊ", "δου", "ρί", "σιε"],
-    "Γαϊδουρίσιες" => &["Γαϊ", "δου", "ρί", "σιες"],
-    "Γαϊδουρίσιο" => &["Γαϊ", "δου", "ρί", "σιο"],
-    "Γαϊδουρίσιοι" => &["Γαϊ", "δου", "ρί", "σιοι"],
-    "Γαϊδουρίσιος" => &["Γαϊ", "δου", "ρί", "σιος"],
-    "Γαϊδουρίσιου" => &["Γαϊ", "δου", "ρί", "σιου"],
-    "Γαϊδουρίσιους" => &["Γαϊ", "δου", "ρί", "σιους"],
-    "Γαϊδουρίσιων" => &["Γαϊ", "δου", "ρί", "σιων"],
-    "Γαϊδουριών" => &["Γαϊ", "δου", "ριών"],
-    "Γαϊτανάκια" => &["Γα", "ϊ", "τα", "νά", "κια"],
-    "Γαϊτάνι" => &["Γαϊ", "τά", "νι"],
-    "Γαϊτάνια" => &["Γαϊ", "τά", "νια"],
-    "Γαϊτανιού" => &["Γαϊ", "τα", "νιού"],
-    "Γαϊτανιών" => &["Γαϊ", "τα", "νιών"],
-    "Γαλάζια" => &["Γα", "λά", "ζια"],
-    "Γαλάζιας" => &["Γα", "λά", "ζιας"],
-    "Γαλάζιε" => &["Γα", "λά", "ζιε"],
-    "Γαλάζιες" => &["Γα", "λά", "ζιες"],
-    "Γαλάζιο" => &["Γα", "λά", "ζιο"],
-    "Γαλάζιοι" => &["Γα", "λά", "ζιοι"],
-    "Γαλάζιος" => &["Γα", "λά", "ζιος"],
-    "Γαλάζιου" => &["Γα", "λά", "ζιου"],
-    "Γαλάζιους" => &["Γα", "λά", "ζιους"],
-    "Γαλάζιων" => &["Γα", "λά", "ζιων"],
-    "Γαλατένια" => &["Γα", "λα", "τέ", "νια"],
-    "Γαλατένιας" => &["Γα", "λα", "τέ", "νιας"],
-    "Γαλατένιε" => &["Γα", "λα", "τέ", "νιε"],
-    "Γαλατένιες" => &["Γα", "λα", "τέ", "νιες"],
-    "Γαλατένιο" => &["Γα", "λα", "τέ", "νιο"],
-    "Γαλατένιοι" => &["Γα", "λα", "τέ", "νιοι"],
-    "Γαλατένιος" => &["Γα", "λα", "τέ", "νιος"],
-    "Γαλατένιου" => &["Γα", "λα", "τέ", "νιου"],
-    "Γαλατένιους" => &["Γα", "λα", "τέ", "νιους"],
-    "Γαλατένιων" => &["Γα", "λα", "τέ", "νιων"],
-    "Γαλόνια" => &["Γα", "λό", "νια"],
-    "Γαμήσια" => &["Γα", "μή", "σια"],
-    "Γάμπια" => &["Γά", "μπια"],
-    "Γάμπιας" => &["Γά", "μπιας"],
-    "Γάμπιες" => &["Γά", "μπιες"],
-    "Γαμπριλίκια" => &["Γα", "μπρι", "λί", "κια"],
-    "Γάντια" => &["Γά", "ντια"],
-    "Γαρδέλια" => &["Γαρ", "δέ", "λια"],
-    "Γαρδένια" => &["Γαρ", "δέ", "νια"],
-    "Γαρδένιας" => &["Γαρ", "δέ", "νιας"],
-    "Γαρδένιες" => &["Γαρ", "δέ", "νιες"],
-    "Γαριδάκια" => &["Γα", "ρι", "δά", "κια"],
-    "Γατάκια" => &["Γα", "τά", "κια"],
-    "Γατίσια" => &["Γα", "τί", "σια"],
-    "Γατίσιας" => &["Γα", "τί", "σιας"],
-    "Γατίσιε" => &["Γα", "τί", "σιε"],
-    "Γατίσιες" => &["Γα", "τί", "σιες"],
-    "Γατίσιο" => &["Γα", "τί", "σιο"],
-    "Γατίσιοι" => &["Γα", "τί", "σιοι"],
-    "Γατίσιος" => &["Γα", "τί", "σιος"],
-    "Γατίσιου" => &["Γα", "τί", "σιου"],
-    "Γατίσιους" => &["Γα", "τί", "σιους"],
-    "Γατίσιων" => &["Γα", "τί", "σιων"],
-    "Γεια" => &["Γεια"],
-    "Γειτονάκια" => &["Γει", "το", "νά", "κια"],
-    "Γελάδια" => &["Γε", "λά", "δια"],
-    "Γελαδίσια" => &["Γε", "λα", "δί", "σια"],
-    "Γελαδίσιας" => &["Γε", "λα", "δί", "σιας"],
-    "Γελαδίσιε" => &["Γε", "λα", "δί", "σιε"],
-    "Γελαδίσιες" => &["Γε", "λα", "δί", "σιες"],
-    "Γελαδίσιο" => &["Γε", "λα", "δί", "σιο"],
-    "Γελαδίσιοι" => &["Γε", "λα", "δί", "σιοι"],
-    "Γελαδίσιος" => &["Γε", "λα", "δί", "σιος"],
-    "Γελαδίσιου" => &["Γε", "λα", "δί", "σιου"],
-    "Γελαδίσιους" => &["Γε", "λα", "δί", "σιους"],
-    "Γελαδίσιων" => &["Γε", "λα", "δί", "σιων"],
-    "Γελάκια" => &["Γε", "λά", "κια"],
-    "Γελεκάκια" => &["Γε", "λε", "κά", "κια"],
-    "Γελέκια" => &["Γε", "λέ", "κια"],
-    "Γέλια" => &["Γέ", "λια"],
-    "Γέλιο" => &["Γέ", "λιο"],
-    "Γέλιου" => &["Γέ", "λιου"],
-    "Γέλιων" => &["Γέ", "λιων"],
-    "Γενάκια" => &["Γε", "νά", "κια"],
-    "Γένεια" => &["Γέ", "νεια"],
-    "Γένια" => &["Γέ", "νια"],
-    "Γεράκια" => &["Γε", "ρά", "κια"],
-    "Γεράνια" => &["Γε", "ρά", "νια"],
-    "Γεροντάκια" => &["Γε", "ρο", "ντά", "κια"],
-    "Γεροντοκοριλίκια" => &["Γε", "ρο", "ντο", "κο", "ρι", "λί", "κια"],
-    "Γεφυράκια" => &["Γε", "φυ", "ρά", "κια"],
-    "Γεφύρια" => &["Γε", "φύ", "ρια"],
-    "Για" => &["Για"],
-    "Γιαουρτάκια" => &["Γι", "α", "ουρ", "τά", "κια"],
-    "Γιαούρτια" => &["Γι", "α", "ούρ", "τια"],
-    "Γιαπράκια" => &["Γι", "α", "πρά", "κια"],
-    "Γιαπωνεζάκια" => &["Γι", "α", "πω", "νε", "ζά", "κια"],
-    "Γιασμάκια" => &["Γι", "α", "σμά", "κια"],
-    "Γιαταγάνια" => &["Γι", "α", "τα", "γά", "νια"],
-    "Γιατάκια" => &["Γι", "α", "τά", "κια"],
-    "Γιατροσόφια" => &["Γι", "α", "τρο", "σό", "φια"],
-    "Γιδάκια" => &["Γι", "δά", "κια"],
-    "Γίδια" => &["Γί", "δια"],
-    "Γιδίσια" => &["Γι", "δί", "σια"],
-    "Γιδίσιας" => &["Γι", "δί", "σιας"],
-    "Γιδίσιε" => &["Γι", "δί", "σιε"],
-    "Γιδίσιες" => &["Γι", "δί", "σιες"],
-    "Γιδίσιο" => &["Γι", "δί", "σιο"],
-    "Γιδίσιοι" => &["Γι", "δί", "σιοι"],
-    "Γιδίσιος" => &["Γι", "δί", "σιος"],
-    "Γιδίσιου" => &["Γι", "δί", "σιου"],
-    "Γιδίσιους" => &["Γι", "δί", "σιους"],
-    "Γιδίσιων" => &["Γι", "δί", "σιων"],
-    "Γιε" => &["Γιε"],
-    "Γιεν" => &["Γιεν"],
-    "Γιλεκάκια" => &["Γι", "λε", "κά", "κια"],
-    "Γινάτια" => &["Γι", "νά", "τια"],
-    "Γιο" => &["Γιο"],
-    "Γιοι" => &["Γιοι"],
-    "Γιοματάρια" => &["Γι", "ο", "μα", "τά", "ρια"],
-    "Γιορντάνια" => &["Γι", "ορ", "ντά", "νια"],
-    "Γιορτάσια" => &["Γι", "ορ", "τά", "σια"],
-    "Γιος" => &["Γιος"],
-    "Γιου" => &["Γιου"],
-    "Γιουβαρλάκια" => &["Γι", "ου", "βαρ", "λά", "κια"],
-    "Γιουβέτσια" => &["Γι", "ου", "βέ", "τσια"],
-    "Γιουγκοσλαβάκια" => &["Γι", "ου", "γκοσ", "λα", "βά", "κια"],
-    "Γιουρούσια" => &["Γι", "ου", "ρού", "σια"],
-    "Γιους" => &["Γιους"],
-    "Γιοφύλλια" => &["Γι", "ο", "φύλ", "λια"],
-    "Γιοφύρια" => &["Γι", "ο", "φύ", "ρια"],
-    "Γιων" => &["Γιων"],
-    "Γκαζάκια" => &["Γκα", "ζά", "κια"],
-    "Γκάζια" => &["Γκά", "ζια"],
-    "Γκαρσόνια" => &["Γκαρ", "σό", "νια"],
-    "Γκάστρια" => &["Γκά", "στρια"],
-    "Γκατζετάκια" => &["Γκα", "τζε", "τά", "κια"],
-    "Γκατζετάκιας" => &["Γκα", "τζε", "τά", "κιας"],
-    "Γκατζετάκιες" => &["Γκα", "τζε", "τά", "κιες"],
-    "Γκέμια" => &["Γκέ", "μια"],
-    "Γκεσέμια" => &["Γκε", "σέ", "μια"],
-    "Γκίνια" => &["Γκί", "νια"],
-    "Γκίνιας" => &["Γκί", "νιας"],
-    "Γκίνιες" => &["Γκί", "νιες"],
-    "Γκιουβέτσια" => &["Γκι", "ου", "βέ", "τσια"],
-    "Γκόλφια" => &["Γκόλ", "φια"],
-    "Γκομενάκια" => &["Γκο", "με", "νά", "κια"],
-    "Γκομενάκιας" => &["Γκο", "με", "νά", "κιας"],
-    "Γκομενάκιες" => &["Γκο", "με", "νά", "κιες"],
-    "Γκρίνια" => &["Γκρί", "νια"],
-    "Γκρίνιας" => &["Γκρί", "νιας"],
-    "Γκρίνιες" => &["Γκρί", "νιες"],
-    "Γλαράκια" => &["Γλα", "ρά", "κια"],
-    "Γλαρόνια" => &["Γλα", "ρό", "νια"],
-    "Γλαροπούλια" => &["Γλα", "ρο", "πού", "λια"],
-    "Γλειφιτζούρια" => &["Γλει", "φι", "τζού", "ρια"],
-    "Γλεντάκια" => &["Γλε", "ντά", "κια"],
-    "Γλέντια" => &["Γλέ", "ντια"],
-    "Γλυκάδια" => &["Γλυ", "κά", "δια"],
-    "Γλυκάκια" => &["Γλυ", "κά", "κια"],
-    "Γλυκισμάκια" => &["Γλυ", "κι", "σμά", "κια"],
-    "Γλωσσάκια" => &["Γλωσ", "σά", "κια"],
-    "Γλωσσάρια" => &["Γλωσ", "σά", "ρια"],
-    "Γλωσσίδια" => &["Γλωσ", "σί", "δια"],
-    "Γοβάκια" => &["Γο", "βά", "κια"],
-    "Γογγύλια" => &["Γογ", "γύ", "λια"],
-    "Γομάρια" => &["Γο", "μά", "ρια"],
-    "Γουδοχέρια" => &["Γου", "δο", "χέ", "ρια"],
-    "Γούλια" => &["Γού", "λια"],
-    "Γούρια" => &["Γού", "ρια"],
-    "Γουρουνάκια" => &["Γου", "ρου", "νά", "κια"],
-    "Γουρούνια" => &["Γου", "ρού", "νια"],
-    "Γουρουνίσια" => &["Γου", "ρου", "νί", "σια"],
-    "Γουρουνίσιας" => &["Γου", "ρου", "νί", "σιας"],
-    "Γουρουνίσιε" => &["Γου", "ρου", "νί", "σιε"],
-    "Γουρουνίσιες" => &["Γου", "ρου", "νί", "σιες"],
-    "Γουρουνίσιο" => &["Γου", "ρου", "νί", "σιο"],
-    "Γουρουνίσιοι" => &["Γου", "ρου", "νί", "σιοι"],
-    "Γουρουνίσιος" => &["Γου", "ρου", "νί", "σιος"],
-    "Γουρουνίσιου" => &["Γου", "ρου", "νί", "σιου"],
-    "Γουρουνίσιους" => &["Γου", "ρου", "νί", "σιους"],
-    "Γουρουνίσιων" => &["Γου", "ρου", "νί", "σιων"],
-    "Γρανάζια" => &["Γρα", "νά", "ζια"],
-    "Γρανιτένια" => &["Γρα", "νι", "τέ", "νια"],
-    "Γρανιτένιας" => &["Γρα", "νι", "τέ", "νιας"],
-    "Γρανιτένιε" => &["Γρα", "νι", "τέ", "νιε"],
-    "Γρανιτένιες" => &["Γρα", "νι", "τέ", "νιες"],
-    "Γρανιτένιο" => &["Γρα", "νι", "τέ", "νιο"],
-    "Γρανιτένιοι" => &["Γρα", "νι", "τέ", "νιοι"],
-    "Γρανιτένιος" => &["Γρα", "νι", "τέ", "νιος"],
-    "Γρανιτένιου" => &["Γρα", "νι", "τέ", "νιου"],
-    "Γρανιτένιους" => &["Γρα", "νι", "τέ", "νιους"],
-    "Γρανιτένιων" => &["Γρα", "νι", "τέ", "νιων"],
-    "Γρασίδια" => &["Γρα", "σί", "δια"],
-    "Γραφειάκια" => &["Γρα", "φει", "ά", "κια"],
-    "Γρίλια" => &["Γρί", "λια"],
-    "Γρίλιας" => &["Γρί", "λιας"],
-    "Γρίλιες" => &["Γρί", "λιες"],
-    "Γρίνια" => &["Γρί", "νια"],
-    "Γρίνιας" => &["Γρί", "νιας"],
-    "Γρίνιες" => &["Γρί", "νιες"],
-    "Γρόσια" => &["Γρό", "σια"],
-    "Γρουμπούλια" => &["Γρου", "μπού", "λια"],
-    "Γυαλάκια" => &["Γυ", "α", "λά", "κια"],
-    "Γυαλάκιας" => &["Γυ", "α", "λά", "κιας"],
-    "Γυαλάκιες" => &["Γυ", "α", "λά", "κιες"],
-    "Γυαλένια" => &["Γυ", "α", "λέ", "νια"],
-    "Γυαλένιας" => &["Γυ", "α", "λέ", "νιας"],
-    "Γυαλένιε" => &["Γυ", "α", "λέ", "νιε"],
-    "Γυαλένιες" => &["Γυ", "α", "λέ", "νιες"],
-    "Γυαλένιο" => &["Γυ", "α", "λέ", "νιο"],
-    "Γυαλένιοι" => &["Γυ", "α", "λέ", "νιοι"],
-    "Γυαλένιος" => &["Γυ", "α", "λέ", "νιος"],
-    "Γυαλένιου" => &["Γυ", "α", "λέ", "νιου"],
-    "Γυαλένιους" => &["Γυ", "α", "λέ", "νιους"],
-    "Γυαλένιων" => &["Γυ", "α", "λέ", "νιων"],
-    "Γυιε" => &["Γυιε"],
-    "Γυιο" => &["Γυιο"],
-    "Γυιοι" => &["Γυιοι"],
-    "Γυιος" => &["Γυιος"],
-    "Γυιου" => &["Γυιου"],
-    "Γυιους" => &["Γυιους"],
-    "Γυιων" => &["Γυιων"],
-    "Γύμνια" => &["Γύ", "μνια"],
-    "Γύμνιας" => &["Γύ", "μνιας"],
-    "Γύμνιες" => &["Γύ", "μνιες"],
-    "Γυναικάκια" => &["Γυ", "ναι", "κά", "κια"],
-    "Γυναικάκιας" => &["Γυ", "ναι", "κά", "κιας"],
-    "Γυναικάκιες" => &["Γυ", "ναι", "κά", "κιες"],
-    "Γυναίκεια" => &["Γυ", "ναί", "κεια"],
-    "Γυναίκειας" => &["Γυ", "ναί", "κειας"],
-    "Γυναίκειε" => &["Γυ", "ναί", "κειε"],
-    "Γυναίκειες" => &["Γυ", "ναί", "κειες"],
-    "Γυναίκειο" => &["Γυ", "ναί", "κειο"],
-    "Γυναίκειοι" => &["Γυ", "ναί", "κειοι"],
-    "Γυναίκειος" => &["Γυ", "ναί", "κειος"],
-    "Γυναίκειου" => &["Γυ", "ναί", "κειου"],
-    "Γυναίκειους" => &["Γυ", "ναί", "κειους"],
-    "Γυναίκειων" => &["Γυ", "ναί", "κειων"],
-    "Γυναικίσια" => &["Γυ", "ναι", "κί", "σια"],
-    "Γυναικίσιας" => &["Γυ", "ναι", "κί", "σιας"],
-    "Γυναικίσιε" => &["Γυ", "ναι", "κί", "σιε"],
-    "Γυναικίσιες" => &["Γυ", "ναι", "κί", "σιες"],
-    "Γυναικίσιο" => &["Γυ", "ναι", "κί", "σιο"],
-    "Γυναικίσιοι" => &["Γυ", "ναι", "κί", "σιοι"],
-    "Γυναικίσιος" => &["Γυ", "ναι", "κί", "σιος"],
-    "Γυναικίσιου" => &["Γυ", "ναι", "κί", "σιου"],
-    "Γυναικίσιους" => &["Γυ", "ναι", "κί", "σιους"],
-    "Γυναικίσιων" => &["Γυ", "ναι", "κί", "σιων"],
-    "Γυφτάκια" => &["Γυ", "φτά", "κια"],
-    "Δαδάκια" => &["Δα", "δά", "κια"],
-    "Δακτυλάκια" => &["Δα", "κτυ", "λά", "κια"],
-    "Δακτυλιδάκια" => &["Δα", "κτυ", "λι", "δά", "κια"],
-    "Δακτυλίδια" => &["Δα", "κτυ", "λί", "δια"],
-    "Δαμάλια" => &["Δα", "μά", "λια"],
-    "Δαμαλίσια" => &["Δα", "μα", "λί", "σια"],
-    "Δαμαλίσιας" => &["Δα", "μα", "λί", "σιας"],
-    "Δαμαλίσιε" => &["Δα", "μα", "λί", "σιε"],
-    "Δαμαλίσιες" => &["Δα", "μα", "λί", "σιες"],
-    "Δαμαλίσιο" => &["Δα", "μα", "λί", "σιο"],
-    "Δαμαλίσιοι" => &["Δα", "μα", "λί", "σιοι"],
-    "Δαμαλίσιος" => &["Δα", "μα", "λί", "σιος"],
-    "Δαμαλίσιου" => &["Δα", "μα", "λί", "σιου"],
-    "Δαμαλίσιους" => &["Δα", "μα", "λί", "σιους"],
-    "Δαμαλίσιων" => &["Δα", "μα", "λί", "σιων"],
-    "Δαντελένια" => &["Δα", "ντε", "λέ", "νια"],
-    "Δαντελένιας" => &["Δα", "ντε", "λέ", "νιας"],
-    "Δαντελένιε" => &["Δα", "ντε", "λέ", "νιε"],
-    "Δαντελένιες" => &["Δα", "ντε", "λέ", "νιες"],
-    "Δαντελένιο" => &["Δα", "ντε", "λέ", "νιο"],
-    "Δαντελένιοι" => &["Δα", "ντε", "λέ", "νιοι"],
-    "Δαντελένιος" => &["Δα", "ντε", "λέ", "νιος"],
-    "Δαντελένιου" => &["Δα", "ντε", "λέ", "νιου"],
-    "Δαντελένιους" => &["Δα", "ντε", "λέ", "νιους"],
-    "Δαντελένιων" => &["Δα", "ντε", "λέ", "νιων"],
-    "Δασκαλίκια" => &["Δα", "σκα", "λί", "κια"],
-    "Δασκαλοπαίδια" => &["Δα", "σκα", "λο", "παί", "δια"],
-    "Δασοτόπια" => &["Δα", "σο", "τό", "πια"],
-    "Δαχτυλάκια" => &["Δα", "χτυ", "λά", "κια"],
-    "Δαχτυλιδάκια" => &["Δα", "χτυ", "λι", "δά", "κια"],
-    "Δαχτυλιδένια" => &["Δα", "χτυ", "λι", "δέ", "νια"],
-    "Δαχτυλιδένιας" => &["Δα", "χτυ", "λι", "δέ", "νιας"],
-    "Δαχτυλιδένιε" => &["Δα", "χτυ", "λι", "δέ", "νιε"],
-    "Δαχτυλιδένιες" => &["Δα", "χτυ", "λι", "δέ", "νιες"],
-    "Δαχτυλιδένιο" => &["Δα", "χτυ", "λι", "δέ", "νιο"],
-    "Δαχτυλιδένιοι" => &["Δα", "χτυ", "λι", "δέ", "νιοι"],
-    "Δαχτυλιδένιος" => &["Δα", "χτυ", "λι", "δέ", "νιος"],
-    "Δαχτυλιδένιου" => &["Δα", "χτυ", "λι", "δέ", "νιου"],
-    "Δαχτυλιδένιους" => &["Δα", "χτυ", "λι", "δέ", "νιους"],
-    "Δαχτυλιδένιων" => &["Δα", "χτυ", "λι", "δέ", "νιων"],
-    "Δαχτυλίδια" => &["Δα", "χτυ", "λί", "δια"],
-    "Δείλια" => &["Δεί", "λια"],
-    "Δείλιας" => &["Δεί", "λιας"],
-    "Δείλιες" => &["Δεί", "λιες"],
-    "Δεκανίκια" => &["Δε", "κα", "νί", "κια"],
-    "Δεκαράκια" => &["Δε", "κα", "ρά", "κια"],
-    "Δεκάρια" => &["Δε", "κά", "ρια"],
-    "Δελφίνια" => &["Δελ", "φί", "νια"],
-    "Δεματάκια" => &["Δε", "μα", "τά", "κια"],
-    "Δεμάτια" => &["Δε", "μά", "τια"],
-    "Δενδράκια" => &["Δεν", "δρά", "κια"],
-    "Δεντράκια" => &["Δε", "ντρά", "κια"],
-    "Δεντρίσια" => &["Δε", "ντρί", "σια"],
-    "Δεντρίσιας" => &["Δε", "ντρί", "σιας"],
-    "Δεντρίσιε" => &["Δε", "ντρί", "σιε"],
-    "Δεντρίσιες" => &["Δε", "ντρί", "σιες"],
-    "Δεντρίσιο" => &["Δε", "ντρί", "σιο"],
-    "Δεντρίσιοι" => &["Δε", "ντρί", "σιοι"],
-    "Δεντρίσιος" => &["Δε", "ντρί", "σιος"],
-    "Δεντρίσιου" => &["Δε", "ντρί", "σιου"],
-    "Δεντρίσιους" => &["Δε", "ντρί", "σιους"],
-    "Δεντρίσιων" => &["Δε", "ντρί", "σιων"],
-    "Δεξίμια" => &["Δε", "ξί", "μια"],
-    "Δερβένια" => &["Δερ", "βέ", "νια"],
-    "Δερματένια" => &["Δερ", "μα", "τέ", "νια"],
-    "Δερματένιας" => &["Δερ", "μα", "τέ", "νιας"],
-    "Δερματένιε" => &["Δερ", "μα", "τέ", "νιε"],
-    "Δερματένιες" => &["Δερ", "μα", "τέ", "νιες"],
-    "Δερματένιο" => &["Δερ", "μα", "τέ", "νιο"],
-    "Δερματένιοι" => &["Δερ", "μα", "τέ", "νιοι"],
-    "Δερματένιος" => &["Δερ", "μα", "τέ", "νιος"],
-    "Δερματένιου" => &["Δερ", "μα", "τέ", "νιου"],
-    "Δερματένιους" => &["Δερ", "μα", "τέ", "νιους"],
-    "Δερματένιων" => &["Δερ", "μα", "τέ", "νιων"],
-    "Δεφτέρια" => &["Δε", "φτέ", "ρια"],
-    "Δημαρχιλίκια" => &["Δη", "μαρ", "χι", "λί", "κια"],
-    "Δημοσιοϋπαλληλίκια" => &["Δη", "μο", "σι", "ο", "ϋ", "παλ", "λη", "λί", "κια"],
-    "Δια" => &["Δια"],
-    "Διαβολάκια" => &["Δι", "α", "βο", "λά", "κια"],
-    "Διάκια" => &["Δι", "ά", "κια"],
-    "Διακοσάρια" => &["Δι", "α", "κο", "σά", "ρια"],
-    "Διακόσια" => &["Δι", "α", "κό", "σια"],
-    "Διαλυστήρια" => &["Δι", "α", "λυ", "στή", "ρια"],
-    "Διαμαντάκια" => &["Δι", "α", "μα", "ντά", "κια"],
-    "Διαμαντένια" => &["Δι", "α", "μα", "ντέ", "νια"],
-    "Διαμαντένιας" => &["Δι", "α", "μα", "ντέ", "νιας"],
-    "Διαμαντένιε" => &["Δι", "α", "μα", "ντέ", "νιε"],
-    "Διαμαντένιες" => &["Δι", "α", "μα", "ντέ", "νιες"],
-    "Διαμαντένιο" => &["Δι", "α", "μα", "ντέ", "νιο"],
-    "Διαμαντένιοι" => &["Δι", "α", "μα", "ντέ", "νιοι"],
-    "Διαμαντένιος" => &["Δι", "α", "μα", "ντέ", "νιος"],
-    "Διαμαντένιου" => &["Δι", "α", "μα", "ντέ", "νιου"],
-    "Διαμαντένιους" => &["Δι", "α", "μα", "ντέ", "νιους"],
-    "Διαμαντένιων" => &["Δι", "α", "μα", "ντέ", "νιων"],
-    "Διαμάντια" => &["Δι", "α", "μά", "ντια"],
-    "Διαμερισμάκια" => &["Δι", "α", "με", "ρι", "σμά", "κια"],
-    "Διασίδια" => &["Δι", "α", "σί", "δια"],
-    "Διβάνια" => &["Δι", "βά", "νια"],
-    "Διβάρια" => &["Δι", "βά", "ρια"],
-    "Διει" => &["Διει"],
-    "Διεις" => &["Διεις"],
-    "Διες" => &["Διες"],
-    "Διηγημάκια" => &["Δι", "η", "γη", "μά", "κια"],
-    "Δικέλλια" => &["Δι", "κέλ", "λια"],
-    "Δίκια" => &["Δί", "κια"],
-    "Δίκιο" => &["Δί", "κιο"],
-    "Δίκιου" => &["Δί", "κιου"],
-    "Δίκιων" => &["Δί", "κιων"],
-    "Δικράνια" => &["Δι", "κρά", "νια"],
-    "Δισάκια" => &["Δι", "σά", "κια"],
-    "Δισάκκια" => &["Δι", "σάκ", "κια"],
-    "Δισεγγόνια" => &["Δι", "σεγ", "γό", "νια"],
-    "Δισκάκια" => &["Δι", "σκά", "κια"],
-    "Δίχτυα" => &["Δί", "χτυα"],
-    "Διω" => &["Διω"],
-    "Δοβλέτια" => &["Δο", "βλέ", "τια"],
-    "Δοιάκια" => &["Δοι", "ά", "κια"],
-    "Δοκαράκια" => &["Δο", "κα", "ρά", "κια"],
-    "Δοκάρια" => &["Δο", "κά", "ρια"],
-    "Δοκίμια" => &["Δο", "κί", "μια"],
-    "Δοντάκια" => &["Δο", "ντά", "κια"],
-    "Δόντια" => &["Δό", "ντια"],
-    "Δοξάρια" => &["Δο", "ξά", "ρια"],
-    "Δουλάκια" => &["Δου", "λά", "κια"],
-    "Δρακόντια" => &["Δρα", "κό", "ντια"],
-    "Δράμια" => &["Δρά", "μια"],
-    "Δρεπάνια" => &["Δρε", "πά", "νια"],
-    "Δρολάπια" => &["Δρο", "λά", "πια"],
-    "Δρομάκια" => &["Δρο", "μά", "κια"],
-    "Δυάρια" => &["Δυ", "ά", "ρια"],
-    "Δυο" => &["Δυο"],
-    "Δωδεκάρια" => &["Δω", "δε", "κά", "ρια"],
-    "Δωματιάκια" => &["Δω", "μα", "τι", "ά", "κια"],
-    "Δωράκια" => &["Δω", "ρά", "κια"],
-    "Εγγονάκια" => &["Εγ", "γο", "νά", "κια"],
-    "Εγγόνια" => &["Εγ", "γό", "νια"],
-    "Έγνοια" => &["Έ", "γνοια"],
-    "Έγνοιας" => &["Έ", "γνοιας"],
-    "Έγνοιες" => &["Έ", "γνοιες"],
-    "Εικονοστάσια" => &["Ει", "κο", "νο", "στά", "σια"],
-    "Εκατομμυριάκια" => &["Ε", "κα", "τομ", "μυ", "ρι", "ά", "κια"],
-    "Εκατοστάρια" => &["Ε", "κα", "το", "στά", "ρια"],
-    "Εκκλησάκια" => &["Εκ", "κλη", "σά", "κια"],
-    "Ελατένια" => &["Ε", "λα", "τέ", "νια"],
-    "Ελατένιας" => &["Ε", "λα", "τέ", "νιας"],
-    "Ελατένιε" => &["Ε", "λα", "τέ", "νιε"],
-    "Ελατένιες" => &["Ε", "λα", "τέ", "νιες"],
-    "Ελατένιο" => &["Ε", "λα", "τέ", "νιο"],
-    "Ελατένιοι" => &["Ε", "λα", "τέ", "νιοι"],
-    "Ελατένιος" => &["Ε", "λα", "τέ", "νιος"],
-    "Ελατένιου" => &["Ε", "λα", "τέ", "νιου"],
-    "Ελατένιους" => &["Ε", "λα", "τέ", "νιους"],
-    "Ελατένιων" => &["Ε", "λα", "τέ", "νιων"],
-    "Ελάτια" => &["Ε", "λά", "τια"],
-    "Ελατίσια" => &["Ε", "λα", "τί", "σια"],
-    "Ελατίσιας" => &["Ε", "λα", "τί", "σιας"],
-    "Ελατίσιε" => &["Ε", "λα", "τί", "σιε"],
-    "Ελατίσιες" => &["Ε", "λα", "τί", "σιες"],
-    "Ελατίσιο" => &["Ε", "λα", "τί", "σιο"],
-    "Ελατίσιοι" => &["Ε", "λα", "τί", "σιοι"],
-    "Ελατίσιος" => &["Ε", "λα", "τί", "σιος"],
-    "Ελατίσιου" => &["Ε", "λα", "τί", "σιου"],
-    "Ελατίσιους" => &["Ε", "λα", "τί", "σιους"],
-    "Ελατίσιων" => &["Ε", "λα", "τί", "σιων"],
-    "Ελαφάκια" => &["Ε", "λα", "φά", "κια"],
-    "Ελάφια" => &["Ε", "λά", "φια"],
-    "Ελαφίσια" => &["Ε", "λα", "φί", "σια"],
-    "Ελαφίσιας" => &["Ε", "λα", "φί", "σιας"],
-    "Ελαφίσιε" => &["Ε", "λα", "φί", "σιε"],
-    "Ελαφίσιες" => &["Ε", "λα", "φί", "σιες"],
-    "Ελαφίσιο" => &["Ε", "λα", "φί", "σιο"],
-    "Ελαφίσιοι" => &["Ε", "λα", "φί", "σιοι"],
-    "Ελαφίσιος" => &["Ε", "λα", "φί", "σιος"],
-    "Ελαφίσιου" => &["Ε", "λα", "φί", "σιου"],
-    "Ελαφίσιους" => &["Ε", "λα", "φί", "σιους"],
-    "Ελαφίσιων" => &["Ε", "λα", "φί", "σιων"],
-    "Ελεφαντένια" => &["Ε", "λε", "φα", "ντέ", "νια"],
-    "Ελεφαντένιας" => &["Ε", "λε", "φα", "ντέ", "νιας"],
-    "Ελεφαντένιε" => &["Ε", "λε", "φα", "ντέ", "νιε"],
-    "Ελεφαντένιες" => &["Ε", "λε", "φα", "ντέ", "νιες"],
-    "Ελεφαντένιο" => &["Ε", "λε", "φα", "ντέ", "νιο"],
-    "Ελεφαντένιοι" => &["Ε", "λε", "φα", "ντέ", "νιοι"],
-    "Ελεφαντένιος" => &["Ε", "λε", "φα", "ντέ", "νιος"],
-    "Ελεφαντένιου" => &["Ε", "λε", "φα", "ντέ", "νιου"],
-    "Ελεφαντένιους" => &["Ε", "λε", "φα", "ντέ", "νιους"],
-    "Ελεφαντένιων" => &["Ε", "λε", "φα", "ντέ", "νιων"],
-    "Ενεσάκια" => &["Ε", "νε", "σά", "κια"],
-    "Ενεσάκιας" => &["Ε", "νε", "σά", "κιας"],
-    "Ενεσάκιες" => &["Ε", "νε", "σά", "κιες"],
-    "Εννιαράκια" => &["Εν", "νι", "α", "ρά", "κια"],
-    "Εννιάρια" => &["Εν", "νι", "ά", "ρια"],
-    "Εξαράκια" => &["Ε", "ξα", "ρά", "κια"],
-    "Εξάρια" => &["Ε", "ξά", "ρια"],
-    "Εξυπνάκια" => &["Ε", "ξυ", "πνά", "κια"],
-    "Εξυπνάκιας" => &["Ε", "ξυ", "πνά", "κιας"],
-    "Εξυπνάκιες" => &["Ε", "ξυ", "πνά", "κιες"],
-    "Εξωκλήσια" => &["Ε", "ξω", "κλή", "σια"],
-    "Επανωφόρια" => &["Ε", "πα", "νω", "φό", "ρια"],
-    "Εργαστήρια" => &["Ερ", "γα", "στή", "ρια"],
-    "Εργενιλίκια" => &["Ερ", "γε", "νι", "λί", "κια"],
-    "Ερημοκλήσια" => &["Ε", "ρη", "μο", "κλή", "σια"],
-    "Ερημονήσια" => &["Ε", "ρη", "μο", "νή", "σια"],
-    "Ερίφια" => &["Ε", "ρί", "φια"],
-    "Ερμάρια" => &["Ερ", "μά", "ρια"],
-    "Εσπαντρίγια" => &["Ε", "σπα", "ντρί", "για"],
-    "Εσπαντρίγιας" => &["Ε", "σπα", "ντρί", "γιας"],
-    "Εσπαντρίγιες" => &["Ε", "σπα", "ντρί", "γιες"],
-    "Εφτάρια" => &["Ε", "φτά", "ρια"],
-    "Ζαγάρια" => &["Ζα", "γά", "ρια"],
-    "Ζακόνια" => &["Ζα", "κό", "νια"],
-    "Ζαλίκια" => &["Ζα", "λί", "κια"],
-    "Ζαμάνια" => &["Ζα", "μά", "νια"],
-    "Ζαμπάκια" => &["Ζα", "μπά", "κια"],
-    "Ζαρζαβάτια" => &["Ζαρ", "ζα", "βά", "τια"],
-    "Ζάρια" => &["Ζά", "ρια"],
-    "Ζαριφλίκια" => &["Ζα", "ρι", "φλί", "κια"],
-    "Ζαρκάδια" => &["Ζαρ", "κά", "δια"],
-    "Ζαρκαδίσια" => &["Ζαρ", "κα", "δί", "σια"],
-    "Ζαρκαδίσιας" => &["Ζαρ", "κα", "δί", "σιας"],
-    "Ζαρκαδίσιε" => &["Ζαρ", "κα", "δί", "σιε"],
-    "Ζαρκαδίσιες" => &["Ζαρ", "κα", "δί", "σιες"],
-    "Ζαρκαδίσιο" => &["Ζαρ", "κα", "δί", "σιο"],
-    "Ζαρκαδίσιοι" => &["Ζαρ", "κα", "δί", "σιοι"],
-    "Ζαρκαδίσιος" => &["Ζαρ", "κα", "δί", "σιος"],
-    "Ζαρκαδίσιου" => &["Ζαρ", "κα", "δί", "σιου"],
-    "Ζαρκαδίσιους" => &["Ζαρ", "κα", "δί", "σιους"],
-    "Ζαρκαδίσιων" => &["Ζαρ", "κα", "δί", "σιων"],
-    "Ζατρίκια" => &["Ζα", "τρί", "κια"],
-    "Ζαφειρένια" => &["Ζα", "φει", "ρέ", "νια"],
-    "Ζαφειρένιας" => &["Ζα", "φει", "ρέ", "νιας"],
-    "Ζαφειρένιε" => &["Ζα", "φει", "ρέ", "νιε"],
-    "Ζαφειρένιες" => &["Ζα", "φει", "ρέ", "νιες"],
-    "Ζαφειρένιο" => &["Ζα", "φει", "ρέ", "νιο"],
-    "Ζαφειρένιοι" => &["Ζα", "φει", "ρέ", "νιοι"],
-    "Ζαφειρένιος" => &["Ζα", "φει", "ρέ", "νιος"],
-    "Ζαφειρένιου" => &["Ζα", "φει", "ρέ", "νιου"],
-    "Ζαφειρένιους" => &["Ζα", "φει", "ρέ", "νιους"],
-    "Ζαφειρένιων" => &["Ζα", "φει", "ρέ", "νιων"],
-    "Ζαφείρια" => &["Ζα", "φεί", "ρια"],
-    "Ζαχαρένια" => &["Ζα", "χα", "ρέ", "νια"],
-    "Ζαχαρένιας" => &["Ζα", "χα", "ρέ", "νιας"],
-    "Ζαχαρένιε" => &["Ζα", "χα", "ρέ", "νιε"],
-    "Ζαχαρένιες" => &["Ζα", "χα", "ρέ", "νιες"],
-    "Ζαχαρένιο" => &["Ζα", "χα", "ρέ", "νιο"],
-    "Ζαχαρένιοι" => &["Ζα", "χα", "ρέ", "νιοι"],
-    "Ζαχαρένιος" => &["Ζα", "χα", "ρέ", "νιος"],
-    "Ζαχαρένιου" => &["Ζα", "χα", "ρέ", "νιου"],
-    "Ζαχαρένιους" => &["Ζα", "χα", "ρέ", "νιους"],
-    "Ζαχαρένιων" => &["Ζα", "χα", "ρέ", "νιων"],
-    "Ζεια" => &["Ζεια"],
-    "Ζειας" => &["Ζειας"],
-    "Ζειες" => &["Ζειες"],
-    "Ζεμπίλια" => &["Ζε", "μπί", "λια"],
-    "Ζευγαράκια" => &["Ζευ", "γα", "ρά", "κια"],
-    "Ζευγάρια" => &["Ζευ", "γά", "ρια"],
-    "Ζεύκια" => &["Ζεύ", "κια"],
-    "Ζηλαδέλφια" => &["Ζη", "λα", "δέλ", "φια"],
-    "Ζηλαδέρφια" => &["Ζη", "λα", "δέρ", "φια"],
-    "Ζήλεια" => &["Ζή", "λεια"],
-    "Ζήλειας" => &["Ζή", "λειας"],
-    "Ζήλειες" => &["Ζή", "λειες"],
-    "Ζήλια" => &["Ζή", "λια"],
-    "Ζήλιας" => &["Ζή", "λιας"],
-    "Ζήλιες" => &["Ζή", "λιες"],
-    "Ζίλια" => &["Ζί", "λια"],
-    "Ζιμπούλια" => &["Ζι", "μπού", "λια"],
-    "Ζίνια" => &["Ζί", "νια"],
-    "Ζίνιας" => &["Ζί", "νιας"],
-    "Ζίνιες" => &["Ζί", "νιες"],
-    "Ζίννια" => &["Ζίν", "νια"],
-    "Ζίννιας" => &["Ζίν", "νιας"],
-    "Ζίννιες" => &["Ζίν", "νιες"],
-    "Ζιπουνάκια" => &["Ζι", "που", "νά", "κια"],
-    "Ζιπούνια" => &["Ζι", "πού", "νια"],
-    "Ζόρια" => &["Ζό", "ρια"],
-    "Ζοριλίκια" => &["Ζο", "ρι", "λί", "κια"],
-    "Ζορμπαλίκια" => &["Ζορ", "μπα", "λί", "κια"],
-    "Ζούδια" => &["Ζού", "δια"],
-    "Ζουζούνια" => &["Ζου", "ζού", "νια"],
-    "Ζουλάπια" => &["Ζου", "λά", "πια"],
-    "Ζουμάκια" => &["Ζου", "μά", "κια"],
-    "Ζουμπουλάκια" => &["Ζου", "μπου", "λά", "κια"],
-    "Ζουμπούλια" => &["Ζου", "μπού", "λια"],
-    "Ζουνάρια" => &["Ζου", "νά", "ρια"],
-    "Ζούρλια" => &["Ζούρ", "λια"],
-    "Ζούρλιας" => &["Ζούρ", "λιας"],
-    "Ζούρλιες" => &["Ζούρ", "λιες"],
-    "Ζούφια" => &["Ζού", "φια"],
-    "Ζούφιας" => &["Ζού", "φιας"],
-    "Ζούφιε" => &["Ζού", "φιε"],
-    "Ζούφιες" => &["Ζού", "φιες"],
-    "Ζούφιο" => &["Ζού", "φιο"],
-    "Ζούφιοι" => &["Ζού", "φιοι"],
-    "Ζούφιος" => &["Ζού", "φιος"],
-    "Ζούφιου" => &["Ζού", "φιου"],
-    "Ζούφιους" => &["Ζού", "φιους"],
-    "Ζούφιων" => &["Ζού", "φιων"],
-    "Ζύγια" => &["Ζύ", "για"],
-    "Ζυγούρια" => &["Ζυ", "γού", "ρια"],
-    "Ζυμαράκια" => &["Ζυ", "μα", "ρά", "κια"],
-    "Ζυμάρια" => &["Ζυ", "μά", "ρια"],
-    "Ζυμωτηράκια" => &["Ζυ", "μω", "τη", "ρά", "κια"],
-    "Ζυμωτήρια" => &["Ζυ", "μω", "τή", "ρια"],
-    "Ζωάκια" => &["Ζω", "ά", "κια"],
-    "Ζωνάρια" => &["Ζω", "νά", "ρια"],
-    "Ζωντάνια" => &["Ζω", "ντά", "νια"],
-    "Ζωντάνιας" => &["Ζω", "ντά", "νιας"],
-    "Ζωντάνιες" => &["Ζω", "ντά", "νιες"],
-    "Ήλιε" => &["Ή", "λιε"],
-    "Ήλιοι" => &["Ή", "λιοι"],
-    "Ήλιος" => &["Ή", "λιος"],
-    "Ήλιους" => &["Ή", "λιους"],
-    "Ήλιων" => &["Ή", "λιων"],
-    "Ήπιαν" => &["Ή", "πιαν"],
-    "Ήσκιε" => &["Ή", "σκιε"],
-    "Ήσκιο" => &["Ή", "σκιο"],
-    "Ήσκιοι" => &["Ή", "σκιοι"],
-    "Ήσκιος" => &["Ή", "σκιος"],
-    "Ήσκιου" => &["Ή", "σκιου"],
-    "Ήσκιους" => &["Ή", "σκιους"],
-    "Ήσκιων" => &["Ή", "σκιων"],
-    "Θαλάμια" => &["Θα", "λά", "μια"],
-    "Θαλασσοπούλια" => &["Θα", "λασ", "σο", "πού", "λια"],
-    "Θεατράκια" => &["Θε", "α", "τρά", "κια"],
-    "Θεια" => &["Θεια"],
-    "Θειας" => &["Θειας"],
-    "Θειαφένια" => &["Θει", "α", "φέ", "νια"],
-    "Θειαφένιας" => &["Θει", "α", "φέ", "νιας"],
-    "Θειαφένιε" => &["Θει", "α", "φέ", "νιε"],
-    "Θειαφένιες" => &["Θει", "α", "φέ", "νιες"],
-    "Θειαφένιο" => &["Θει", "α", "φέ", "νιο"],
-    "Θειαφένιοι" => &["Θει", "α", "φέ", "νιοι"],
-    "Θειαφένιος" => &["Θει", "α", "φέ", "νιος"],
-    "Θειαφένιου" => &["Θει", "α", "φέ", "νιου"],
-    "Θειαφένιους" => &["Θει", "α", "φέ", "νιους"],
-    "Θειαφένιων" => &["Θει", "α", "φέ", "νιων"],
-    "Θειάφια" => &["Θει", "ά", "φια"],
-    "Θειαφιστήρια" => &["Θει", "α", "φι", "στή", "ρια"],
-    "Θειαφοκέρια" => &["Θει", "α", "φο", "κέ", "ρια"],
-    "Θειε" => &["Θειε"],
-    "Θειες" => &["Θειες"],
-    "Θειο" => &["Θειο"],
-    "Θειοι" => &["Θειοι"],
-    "Θειος" => &["Θειος"],
-    "Θειου" => &["Θειου"],
-    "Θειους" => &["Θειους"],
-    "Θειων" => &["Θειων"],
-    "Θεριακλίκια" => &["Θε", "ρι", "α", "κλί", "κια"],
-    "Θηκάρια" => &["Θη", "κά", "ρια"],
-    "Θηλύκια" => &["Θη", "λύ", "κια"],
-    "Θηλυκωτάρια" => &["Θη", "λυ", "κω", "τά", "ρια"],
-    "Θηλυκωτήρια" => &["Θη", "λυ", "κω", "τή", "ρια"],
-    "Θιος" => &["Θιος"],
-    "Θρασίμια" => &["Θρα", "σί", "μια"],
-    "Θρεφτάρια" => &["Θρε", "φτά", "ρια"],
-    "Θρούμπια" => &["Θρού", "μπια"],
-    "Θυμάρια" => &["Θυ", "μά", "ρια"],
-    "Θυμαρίσια" => &["Θυ", "μα", "ρί", "σια"],
-    "Θυμαρίσιας" => &["Θυ", "μα", "ρί", "σιας"],
-    "Θυμαρίσιε" => &["Θυ", "μα", "ρί", "σιε"],
-    "Θυμαρίσιες" => &["Θυ", "μα", "ρί", "σιες"],
-    "Θυμαρίσιο" => &["Θυ", "μα", "ρί", "σιο"],
-    "Θυμαρίσιοι" => &["Θυ", "μα", "ρί", "σιοι"],
-    "Θυμαρίσιος" => &["Θυ", "μα", "ρί", "σιος"],
-    "Θυμαρίσιου" => &["Θυ", "μα", "ρί", "σιου"],
-    "Θυμαρίσιους" => &["Θυ", "μα", "ρί", "σιους"],
-    "Θυμαρίσιων" => &["Θυ", "μα", "ρί", "σιων"],
-    "Θυμητάρια" => &["Θυ", "μη", "τά", "ρια"],
-    "Θυμιατήρια" => &["Θυ", "μι", "α", "τή", "ρια"],
-    "Ίδιου" => &["Ί", "διου"],
-    "Ίδιων" => &["Ί", "διων"],
-    "Ιδροκόπια" => &["Ι", "δρο", "κό", "πια"],
-    "Ιδρωτάρια" => &["Ι", "δρω", "τά", "ρια"],
-    "Ινάτια" => &["Ι", "νά", "τια"],
-    "Ιντερνετάκια" => &["Ι", "ντερ", "νε", "τά", "κια"],
-    "Ιντερνετάκιας" => &["Ι", "ντερ", "νε", "τά", "κιας"],
-    "Ιντερνετάκιες" => &["Ι", "ντερ", "νε", "τά", "κιες"],
-    "Ιπποδρομιάκια" => &["Ιπ", "πο", "δρο", "μι", "ά", "κια"],
-    "Ιπποδρομιάκιας" => &["Ιπ", "πο", "δρο", "μι", "ά", "κιας"],
-    "Ιπποδρομιάκιες" => &["Ιπ", "πο", "δρο", "μι", "ά", "κιες"],
-    "Ισαποστάκια" => &["Ι", "σα", "πο", "στά", "κια"],
-    "Ισαποστάκιας" => &["Ι", "σα", "πο", "στά", "κιας"],
-    "Ισαποστάκιες" => &["Ι", "σα", "πο", "στά", "κιες"],
-    "Ισαποστασάκια" => &["Ι", "σα", "πο", "στα", "σά", "κια"],
-    "Ισαποστασάκιας" => &["Ι", "σα", "πο", "στα", "σά", "κιας"],
-    "Ισαποστασάκιες" => &["Ι", "σα", "πο", "στα", "σά", "κιες"],
-    "Ίσια" => &["Ί", "σια"],
-    "Ίσιας" => &["Ί", "σιας"],
-    "Ίσιε" => &["Ί", "σιε"],
-    "Ίσιες" => &["Ί", "σιες"],
-    "Ίσιο" => &["Ί", "σιο"],
-    "Ίσιοι" => &["Ί", "σιοι"],
-    "Ίσιος" => &["Ί", "σιος"],
-    "Ίσιου" => &["Ί", "σιου"],
-    "Ίσιους" => &["Ί", "σιους"],
-    "Ίσιων" => &["Ί", "σιων"],
-    "Ίσκιε" => &["Ί", "σκιε"],
-    "Ίσκιο" => &["Ί", "σκιο"],
-    "Ίσκιοι" => &["Ί", "σκιοι"],
-    "Ίσκιος" => &["Ί", "σκιος"],
-    "Ίσκιου" => &["Ί", "σκιου"],
-    "Ίσκιους" => &["Ί", "σκιους"],
-    "Ίσκιων" => &["Ί", "σκιων"],
-    "Καβάκια" => &["Κα", "βά", "κια"],
-    "Καβγαδάκια" => &["Κα", "βγα", "δά", "κια"],
-    "Καβίλια" => &["Κα", "βί", "λια"],
-    "Καβίλιας" => &["Κα", "βί", "λιας"],
-    "Καβίλιες" => &["Κα", "βί", "λιες"],
-    "Καβούκια" => &["Κα", "βού", "κια"],
-    "Καβουράκια" => &["Κα", "βου", "ρά", "κια"],
-    "Καβούρια" => &["Κα", "βού", "ρια"],
-    "Καβουρντιστήρια" => &["Κα", "βουρ", "ντι", "στή", "ρια"],
-    "Καγκελένια" => &["Κα", "γκε", "λέ", "νια"],
-    "Καγκελένιας" => &["Κα", "γκε", "λέ", "νιας"],
-    "Καγκελένιε" => &["Κα", "γκε", "λέ", "νιε"],
-    "Καγκελένιες" => &["Κα", "γκε", "λέ", "νιες"],
-    "Καγκελένιο" => &["Κα", "γκε", "λέ", "νιο"],
-    "Καγκελένιοι" => &["Κα", "γκε", "λέ", "νιοι"],
-    "Καγκελένιος" => &["Κα", "γκε", "λέ", "νιος"],
-    "Καγκελένιου" => &["Κα", "γκε", "λέ", "νιου"],
-    "Καγκελένιους" => &["Κα", "γκε", "λέ", "νιους"],
-    "Καγκελένιων" => &["Κα", "γκε", "λέ", "νιων"],
-    "Καδρίλια" => &["Κα", "δρί", "λια"],
-    "Καδρίλιας" => &["Κα", "δρί", "λιας"],
-    "Καδρίλιες" => &["Κα", "δρί", "λιες"],
-    "Καδρόνια" => &["Κα", "δρό", "νια"],
-    "Καζανάκια" => &["Κα", "ζα", "νά", "κια"],
-    "Καζάνια" => &["Κα", "ζά", "νια"],
-    "Καζίκια" => &["Κα", "ζί", "κια"],
-    "Καημένα" => &["Καη", "μέ", "να"],
-    "Καημένας" => &["Καη", "μέ", "νας"],
-    "Καημένε" => &["Καη", "μέ", "νε"],
-    "Καημένες" => &["Καη", "μέ", "νες"],
-    "Καημένο" => &["Καη", "μέ", "νο"],
-    "Καημένοι" => &["Καη", "μέ", "νοι"],
-    "Καημένος" => &["Καη", "μέ", "νος"],
-    "Καημένου" => &["Καη", "μέ", "νου"],
-    "Καημένους" => &["Καη", "μέ", "νους"],
-    "Καημένων" => &["Καη", "μέ", "νων"],
-    "Καθάρια" => &["Κα", "θά", "ρια"],
-    "Καθάριας" => &["Κα", "θά", "ριας"],
-    "Καθάριε" => &["Κα", "θά", "ριε"],
-    "Καθάριες" => &["Κα", "θά", "ριες"],
-    "Καθάριο" => &["Κα", "θά", "ριο"],
-    "Καθάριοι" => &["Κα", "θά", "ριοι"],
-    "Καθάριος" => &["Κα", "θά", "ριος"],
-    "Καθάριου" => &["Κα", "θά", "ριου"],
-    "Καθάριους" => &["Κα", "θά", "ριους"],
-    "Καθάριων" => &["Κα", "θά", "ριων"],
-    "Καθικάκια" => &["Κα", "θι", "κά", "κια"],
-    "Καθίκια" => &["Κα", "θί", "κια"],
-    "Καΐκια" => &["Κα", "ΐ", "κια"],
-    "Καϊμάκια" => &["Κα", "ϊ", "μά", "κια"],
-    "Καινούργια" => &["Και", "νούρ", "για"],
-    "Καινούργιας" => &["Και", "νούρ", "γιας"],
-    "Καινούργιε" => &["Και", "νούρ", "γιε"],
-    "Καινούργιες" => &["Και", "νούρ", "γιες"],
-    "Καινούργιο" => &["Και", "νούρ", "γιο"],
-    "Καινούργιοι" => &["Και", "νούρ", "γιοι"],
-    "Καινούργιος" => &["Και", "νούρ", "γιος"],
-    "Καινούργιου" => &["Και", "νούρ", "γιου"],
-    "Καινούργιους" => &["Και", "νούρ", "γιους"],
-    "Καινούργιων" => &["Και", "νούρ", "γιων"],
-    "Καινούρια" => &["Και", "νού", "ρια"],
-    "Καινούριας" => &["Και", "νού", "ριας"],
-    "Καινούριε" => &["Και", "νού", "ριε"],
-    "Καινούριες" => &["Και", "νού", "ριες"],
-    "Καινούριο" => &["Και", "νού", "ριο"],
-    "Καινούριοι" => &["Και", "νού", "ριοι"],
-    "Καινούριος" => &["Και", "νού", "ριος"],
-    "Καινούριου" => &["Και", "νού", "ριου"],
-    "Καινούριους" => &["Και", "νού", "ριους"],
-    "Καινούριων" => &["Και", "νού", "ριων"],
-    "Κακάδια" => &["Κα", "κά", "δια"],
-    "Κακοπάθεια" => &["Κα", "κο", "πά", "θεια"],
-    "Κακοπάθειας" => &["Κα", "κο", "πά", "θειας"],
-    "Κακοπάθειες" => &["Κα", "κο", "πά", "θειες"],
-    "Κακοψύχια" => &["Κα", "κο", "ψύ", "χια"],
-    "Καλαγκάθια" => &["Κα", "λα", "γκά", "θια"],
-    "Καλαθάκια" => &["Κα", "λα", "θά", "κια"],
-    "Καλάθια" => &["Κα", "λά", "θια"],
-    "Καλαμάκια" => &["Κα", "λα", "μά", "κια"],
-    "Καλαμαράκια" => &["Κα", "λα", "μα", "ρά", "κια"],
-    "Καλαμάρια" => &["Κα", "λα", "μά", "ρια"],
-    "Καλαμένια" => &["Κα", "λα", "μέ", "νια"],
-    "Καλαμένιας" => &["Κα", "λα", "μέ", "νιας"],
-    "Καλαμένιε" => &["Κα", "λα", "μέ", "νιε"],
-    "Καλαμένιες" => &["Κα", "λα", "μέ", "νιες"],
-    "Καλαμένιο" => &["Κα", "λα", "μέ", "νιο"],
-    "Καλαμένιοι" => &["Κα", "λα", "μέ", "νιοι"],
-    "Καλαμένιος" => &["Κα", "λα", "μέ", "νιος"],
-    "Καλαμένιου" => &["Κα", "λα", "μέ", "νιου"],
-    "Καλαμένιους" => &["Κα", "λα", "μέ", "νιους"],
-    "Καλαμένιων" => &["Κα", "λα", "μέ", "νιων"],
-    "Καλάμια" => &["Κα", "λά", "μια"],
-    "Καλαμίδια" => &["Κα", "λα", "μί", "δια"],
-    "Καλαμποκένια" => &["Κα", "λα", "μπο", "κέ", "νια"],
-    "Καλαμποκένιας" => &["Κα", "λα", "μπο", "κέ", "νιας"],
-    "Καλαμποκένιε" => &["Κα", "λα", "μπο", "κέ", "νιε"],
-    "Καλαμποκένιες" => &["Κα", "λα", "μπο", "κέ", "νιες"],
-    "Καλαμποκένιο" => &["Κα", "λα", "μπο", "κέ", "νιο"],
-    "Καλαμποκένιοι" => &["Κα", "λα", "μπο", "κέ", "νιοι"],
-    "Καλαμποκένιος" => &["Κα", "λα", "μπο", "κέ", "νιος"],
-    "Καλαμποκένιου" => &["Κα", "λα", "μπο", "κέ", "νιου"],
-    "Καλαμποκένιους" => &["Κα", "λα", "μπο", "κέ", "νιους"],
-    "Καλαμποκένιων" => &["Κα", "λα", "μπο", "κέ", "νιων"],
-    "Καλαμπόκια" => &["Κα", "λα", "μπό", "κια"],
-    "Καλαμποκίσια" => &["Κα", "λα", "μπο", "κί", "σια"],
-    "Καλαμποκίσιας" => &["Κα", "λα", "μπο", "κί", "σιας"],
-    "Καλαμποκίσιε" => &["Κα", "λα", "μπο", "κί", "σιε"],
-    "Καλαμποκίσιες" => &["Κα", "λα", "μπο", "κί", "σιες"],
-    "Καλαμποκίσιο" => &["Κα", "λα", "μπο", "κί", "σιο"],
-    "Καλαμποκίσιοι" => &["Κα", "λα", "μπο", "κί", "σιοι"],
-    "Καλαμποκίσιος" => &["Κα", "λα", "μπο", "κί", "σιος"],
-    "Καλαμποκίσιου" => &["Κα", "λα", "μπο", "κί", "σιου"],
-    "Καλαμποκίσιους" => &["Κα", "λα", "μπο", "κί", "σιους"],
-    "Καλαμποκίσιων" => &["Κα", "λα", "μπο", "κί", "σιων"],
-    "Καλαμπούρια" => &["Κα", "λα", "μπού", "ρια"],
-    "Καλαντάρια" => &["Κα", "λα", "ντά", "ρια"],
-    "Καλαπόδια" => &["Κα", "λα", "πό", "δια"],
-    "Καλέμια" => &["Κα", "λέ", "μια"],
-    "Καλεντάρια" => &["Κα", "λε", "ντά", "ρια"],
-    "Καληνυχτάκια" => &["Κα", "λη", "νυ", "χτά", "κια"],
-    "Καληνυχτάκιας" => &["Κα", "λη", "νυ", "χτά", "κιας"],
-    "Καληνυχτάκιες" => &["Κα", "λη", "νυ", "χτά", "κιες"],
-    "Καλικαντζαράκια" => &["Κα", "λι", "κα", "ντζα", "ρά", "κια"],
-    "Καλικαντζαρούδια" => &["Κα", "λι", "κα", "ντζα", "ρού", "δια"],
-    "Καλικατζαράκια" => &["Κα", "λι", "κα", "τζα", "ρά", "κια"],
-    "Καλκάνια" => &["Καλ", "κά", "νια"],
-    "Καλντερίμια" => &["Καλ", "ντε", "ρί", "μια"],
-    "Καλογεράκια" => &["Κα", "λο", "γε", "ρά", "κια"],
-    "Καλογεροπαίδια" => &["Κα", "λο", "γε", "ρο", "παί", "δια"],
-    "Καλοκαιράκια" => &["Κα", "λο", "και", "ρά", "κια"],
-    "Καλοκαίρια" => &["Κα", "λο", "καί", "ρια"],
-    "Καλοπερασάκια" => &["Κα", "λο", "πε", "ρα", "σά", "κια"],
-    "Καλοπερασάκιας" => &["Κα", "λο", "πε", "ρα", "σά", "κιας"],
-    "Καλοπερασάκιες" => &["Κα", "λο", "πε", "ρα", "σά", "κιες"],
-    "Καλούδια" => &["Κα", "λού", "δια"],
-    "Καλούπια" => &["Κα", "λού", "πια"],
-    "Καλπάκια" => &["Καλ", "πά", "κια"],
-    "Καλυβάκια" => &["Κα", "λυ", "βά", "κια"],
-    "Καλύβια" => &["Κα", "λύ", "βια"],
-    "Καλύκια" => &["Κα", "λύ", "κια"],
-    "Καλυμμαύκια" => &["Κα", "λυμ", "μαύ", "κια"],
-    "Καλυμμαύχια" => &["Κα", "λυμ", "μαύ", "χια"],
-    "Καλφαλίκια" => &["Καλ", "φα", "λί", "κια"],
-    "Καμάκια" => &["Κα", "μά", "κια"],
-    "Καμαράκια" => &["Κα", "μα", "ρά", "κια"],
-    "Καμάρια" => &["Κα", "μά", "ρια"],
-    "Καμαρίνια" => &["Κα", "μα", "ρί", "νια"],
-    "Καμηλαύκια" => &["Κα", "μη", "λαύ", "κια"],
-    "Καμηλίσια" => &["Κα", "μη", "λί", "σια"],
-    "Καμηλίσιας" => &["Κα", "μη", "λί", "σιας"],
-    "Καμηλίσιε" => &["Κα", "μη", "λί", "σιε"],
-    "Καμηλίσιες" => &["Κα", "μη", "λί", "σιες"],
-    "Καμηλίσιο" => &["Κα", "μη", "λί", "σιο"],
-    "Καμηλίσιοι" => &["Κα", "μη", "λί", "σιοι"],
-    "Καμηλίσιος" => &["Κα", "μη", "λί", "σιος"],
-    "Καμηλίσιου" => &["Κα", "μη", "λί", "σιου"],
-    "Καμηλίσιους" => &["Κα", "μη", "λί", "σιους"],
-    "Καμηλίσιων" => &["Κα", "μη", "λί", "σιων"],
-    "Καμίνια" => &["Κα", "μί", "νια"],
-    "Καμιόνια" => &["Κα", "μι", "ό", "νια"],
-    "Καμιτσίκια" => &["Κα", "μι", "τσί", "κια"],
-    "Καμουτσίκια" => &["Κα", "μου", "τσί", "κια"],
-    "Καμπάγια" => &["Κα", "μπά", "για"],
-    "Καμπανάκια" => &["Κα", "μπα", "νά", "κια"],
-    "Καμπάνια" => &["Κα", "μπά", "νια"],
-    "Καμπάνιας" => &["Κα", "μπά", "νιας"],
-    "Καμπάνιες" => &["Κα", "μπά", "νιες"],
-    "Κάμπια" => &["Κά", "μπια"],
-    "Κάμπιας" => &["Κά", "μπιας"],
-    "Κάμπιες" => &["Κά", "μπιες"],
-    "Καμπίσια" => &["Κα", "μπί", "σια"],
-    "Καμπίσιας" => &["Κα", "μπί", "σιας"],
-    "Καμπίσιε" => &["Κα", "μπί", "σιε"],
-    "Καμπίσιες" => &["Κα", "μπί", "σιες"],
-    "Καμπίσιο" => &["Κα", "μπί", "σιο"],
-    "Καμπίσιοι" => &["Κα", "μπί", "σιοι"],
-    "Καμπίσιος" => &["Κα", "μπί", "σιος"],
-    "Καμπίσιου" => &["Κα", "μπί", "σιου"],
-    "Καμπίσιους" => &["Κα", "μπί", "σιους"],
-    "Καμπίσιων" => &["Κα", "μπί", "σιων"],
-    "Κάμπλια" => &["Κά", "μπλια"],
-    "Κάμπλιας" => &["Κά", "μπλιας"],
-    "Κάμπλιες" => &["Κά", "μπλιες"],
-    "Καμτσίκια" => &["Καμ", "τσί", "κια"],
-    "Κανάγια" => &["Κα", "νά", "για"],
-    "Κανάγιας" => &["Κα", "νά", "γιας"],
-    "Κανάγιες" => &["Κα", "νά", "γιες"],
-    "Κανάκια" => &["Κα", "νά", "κια"],
-    "Καναλάκια" => &["Κα", "να", "λά", "κια"],
-    "Κανάλια" => &["Κα", "νά", "λια"],
-    "Καναπεδάκια" => &["Κα", "να", "πε", "δά", "κια"],
-    "Κανάρια" => &["Κα", "νά", "ρια"],
-    "Καναρίνια" => &["Κα", "να", "ρί", "νια"],
-    "Κανατάκια" => &["Κα", "να", "τά", "κια"],
-    "Κανάτια" => &["Κα", "νά", "τια"],
-    "Κανδήλια" => &["Καν", "δή", "λια"],
-    "Κανίσκια" => &["Κα", "νί", "σκια"],
-    "Κανναβένια" => &["Καν", "να", "βέ", "νια"],
-    "Κανναβένιας" => &["Καν", "να", "βέ", "νιας"],
-    "Κανναβένιε" => &["Καν", "να", "βέ", "νιε"],
-    "Κανναβένιες" => &["Καν", "να", "βέ", "νιες"],
-    "Κανναβένιο" => &["Καν", "να", "βέ", "νιο"],
-    "Κανναβένιοι" => &["Καν", "να", "βέ", "νιοι"],
-    "Κανναβένιος" => &["Καν", "να", "βέ", "νιος"],
-    "Κανναβένιου" => &["Καν", "να", "βέ", "νιου"],
-    "Κανναβένιους" => &["Καν", "να", "βέ", "νιους"],
-    "Κανναβένιων" => &["Καν", "να", "βέ", "νιων"],
-    "Κανναβούρια" => &["Καν", "να", "βού", "ρια"],
-    "Κανοκιάλια" => &["Κα", "νο", "κι", "ά", "λια"],
-    "Κανονάκια" => &["Κα", "νο", "νά", "κια"],
-    "Κανόνια" => &["Κα", "νό", "νια"],
-    "Κανονίδια" => &["Κα", "νο", "νί", "δια"],
-    "Κανταΐφια" => &["Κα", "ντα", "ΐ", "φια"],
-    "Κανταράκια" => &["Κα", "ντα", "ρά", "κια"],
-    "Καντάρια" => &["Κα", "ντά", "ρια"],
-    "Καντηλάκια" => &["Κα", "ντη", "λά", "κια"],
-    "Καντηλέρια" => &["Κα", "ντη", "λέ", "ρια"],
-    "Καντήλια" => &["Κα", "ντή", "λια"],
-    "Καντηλιέρια" => &["Κα", "ντη", "λι", "έ", "ρια"],
-    "Καντίνια" => &["Κα", "ντί", "νια"],
-    "Καντόνια" => &["Κα", "ντό", "νια"],
-    "Καντούνια" => &["Κα", "ντού", "νια"],
-    "Καντρίλια" => &["Κα", "ντρί", "λια"],
-    "Καντρίλιας" => &["Κα", "ντρί", "λιας"],
-    "Καντρίλιες" => &["Κα", "ντρί", "λιες"],
-    "Καουτσουκένια" => &["Κα", "ου", "τσου", "κέ", "νια"],
-    "Καουτσουκένιας" => &["Κα", "ου", "τσου", "κέ", "νιας"],
-    "Καουτσουκένιε" => &["Κα", "ου", "τσου", "κέ", "νιε"],
-    "Καουτσουκένιες" => &["Κα", "ου", "τσου", "κέ", "νιες"],
-    "Καουτσουκένιο" => &["Κα", "ου", "τσου", "κέ", "νιο"],
-    "Καουτσουκένιοι" => &["Κα", "ου", "τσου", "κέ", "νιοι"],
-    "Καουτσουκένιος" => &["Κα", "ου", "τσου", "κέ", "νιος"],
-    "Καουτσουκένιου" => &["Κα", "ου", "τσου", "κέ", "νιου"],
-    "Καουτσουκένιους" => &["Κα", "ου", "τσου", "κέ", "νιους"],
-    "Καουτσουκένιων" => &["Κα", "ου", "τσου", "κέ", "νιων"],
-    "Καπάκια" => &["Κα", "πά", "κια"],
-    "Καπελάκια" => &["Κα", "πε", "λά", "κια"],
-    "Καπετάνιε" => &["Κα", "πε", "τά", "νιε"],
-    "Καπετάνιο" => &["Κα", "πε", "τά", "νιο"],
-    "Καπετάνιοι" => &["Κα", "πε", "τά", "νιοι"],
-    "Καπετάνιος" => &["Κα", "πε", "τά", "νιος"],
-    "Καπετάνιου" => &["Κα", "πε", "τά", "νιου"],
-    "Καπετάνιους" => &["Κα", "πε", "τά", "νιους"],
-    "Καπετάνιων" => &["Κα", "πε", "τά", "νιων"],
-    "Καπετανλίκια" => &["Κα", "πε", "ταν", "λί", "κια"],
-    "Καπίκια" => &["Κα", "πί", "κια"],
-    "Καπίστρια" => &["Κα", "πί", "στρια"],
-    "Καπιτάλια" => &["Κα", "πι", "τά", "λια"],
-    "Καπλάνια" => &["Κα", "πλά", "νια"],
-    "Καπνοτόπια" => &["Κα", "πνο", "τό", "πια"],
-    "Καπόνια" => &["Κα", "πό", "νια"],
-    "Καπούλια" => &["Κα", "πού", "λια"],
-    "Καπρίτσια" => &["Κα", "πρί", "τσια"],
-    "Καπρίτσιο" => &["Κα", "πρί", "τσιο"],
-    "Καπρίτσιου" => &["Κα", "πρί", "τσιου"],
-    "Καπρίτσιων" => &["Κα", "πρί", "τσιων"],
-    "Καραβάκια" => &["Κα", "ρα", "βά", "κια"],
-    "Καραβάνια" => &["Κα", "ρα", "βά", "νια"],
-    "Καράβια" => &["Κα", "ρά", "βια"],
-    "Καραβίσια" => &["Κα", "ρα", "βί", "σια"],
-    "Καραβίσιας" => &["Κα", "ρα", "βί", "σιας"],
-    "Καραβίσιε" => &["Κα", "ρα", "βί", "σιε"],
-    "Καραβίσιες" => &["Κα", "ρα", "βί", "σιες"],
-    "Καραβίσιο" => &["Κα", "ρα", "βί", "σιο"],
-    "Καραβίσιοι" => &["Κα", "ρα", "βί", "σιοι"],
-    "Καραβίσιος" => &["Κα", "ρα", "βί", "σιος"],
-    "Καραβίσιου" => &["Κα", "ρα", "βί", "σιου"],
-    "Καραβίσιους" => &["Κα", "ρα", "βί", "σιους"],
-    "Καραβίσιων" => &["Κα", "ρα", "βί", "σιων"],
-    "Καραβοστάσια" => &["Κα", "ρα", "βο", "στά", "σια"],
-    "Καραγάτσια" => &["Κα", "ρα", "γά", "τσια"],
-    "Καραγκιοζιλίκια" => &["Κα", "ρα", "γκι", "ο", "ζι", "λί", "κια"],
-    "Καραγκιοζλίκια" => &["Κα", "ρα", "γκι", "οζ", "λί", "κια"],
-    "Καρακόλια" => &["Κα", "ρα", "κό", "λια"],
-    "Καραμελένια" => &["Κα", "ρα", "με", "λέ", "νια"],
-    "Καραμελένιας" => &["Κα", "ρα", "με", "λέ", "νιας"],
-    "Καραμελένιε" => &["Κα", "ρα", "με", "λέ", "νιε"],
-    "Καραμελένιες" => &["Κα", "ρα", "με", "λέ", "νιες"],
-    "Καραμελένιο" => &["Κα", "ρα", "με", "λέ", "νιο"],
-    "Καραμελένιοι" => &["Κα", "ρα", "με", "λέ", "νιοι"],
-    "Καραμελένιος" => &["Κα", "ρα", "με", "λέ", "νιος"],
-    "Καραμελένιου" => &["Κα", "ρα", "με", "λέ", "νιου"],
-    "Καραμελένιους" => &["Κα", "ρα", "με", "λέ", "νιους"],
-    "Καραμελένιων" => &["Κα", "ρα", "με", "λέ", "νιων"],
-    "Καραούλια" => &["Κα", "ρα", "ού", "λια"],
-    "Καράτια" => &["Κα", "ρά", "τια"],
-    "Καραφάκια" => &["Κα", "ρα", "φά", "κια"],
-    "Καρβέλια" => &["Καρ", "βέ", "λια"],
-    "Καρβουνάκια" => &["Καρ", "βου", "νά", "κια"],
-    "Κάργια" => &["Κάρ", "για"],
-    "Κάργιας" => &["Κάρ", "γιας"],
-    "Κάργιες" => &["Κάρ", "γιες"],
-    "Καρδάρια" => &["Καρ", "δά", "ρια"],
-    "Καρδιοχτύπια" => &["Καρ", "δι", "ο", "χτύ", "πια"],
-    "Καρεκλάκια" => &["Κα", "ρε", "κλά", "κια"],
-    "Καρενάγια" => &["Κα", "ρε", "νά", "για"],
-    "Καρενάγιο" => &["Κα", "ρε", "νά", "γιο"],
-    "Καρενάγιου" => &["Κα", "ρε", "νά", "γιου"],
-    "Καρενάγιων" => &["Κα", "ρε", "νά", "γιων"],
-    "Καριοφίλια" => &["Κα", "ρι", "ο", "φί", "λια"],
-    "Καρμίνια" => &["Καρ", "μί", "νια"],
-    "Καρναβάλια" => &["Καρ", "να", "βά", "λια"],
-    "Καρνάγια" => &["Καρ", "νά", "για"],
-    "Καρνάγιο" => &["Καρ", "νά", "γιο"],
-    "Καρνάγιου" => &["Καρ", "νά", "γιου"],
-    "Καρνάγιων" => &["Καρ", "νά", "γιων"],
-    "Καροτσάκια" => &["Κα", "ρο", "τσά", "κια"],
-    "Καρότσια" => &["Κα", "ρό", "τσια"],
-    "Καρούλια" => &["Κα", "ρού", "λια"],
-    "Καρπούζια" => &["Καρ", "πού", "ζια"],
-    "Καρτάλια" => &["Καρ", "τά", "λια"],
-    "Καρτελάκια" => &["Καρ", "τε", "λά", "κια"],
-    "Καρτέρια" => &["Καρ", "τέ", "ρια"],
-    "Καρτόνια" => &["Καρ", "τό", "νια"],
-    "Καρυδάκια" => &["Κα", "ρυ", "δά", "κια"],
-    "Καρυδένια" => &["Κα", "ρυ", "δέ", "νια"],
-    "Καρυδένιας" => &["Κα", "ρυ", "δέ", "νιας"],
-    "Καρυδένιε" => &["Κα", "ρυ", "δέ", "νιε"],
-    "Καρυδένιες" => &["Κα", "ρυ", "δέ", "νιες"],
-    "Καρυδένιο" => &["Κα", "ρυ", "δέ", "νιο"],
-    "Καρυδένιοι" => &["Κα", "ρυ", "δέ", "νιοι"],
-    "Καρυδένιος" => &["Κα", "ρυ", "δέ", "νιος"],
-    "Καρυδένιου" => &["Κα", "ρυ", "δέ", "νιου"],
-    "Καρυδένιους" => &["Κα", "ρυ", "δέ", "νιους"],
-    "Καρυδένιων" => &["Κα", "ρυ", "δέ", "νιων"],
-    "Καρύδια" => &["Κα", "ρύ", "δια"],
-    "Καρυοφύλλια" => &["Κα", "ρυ", "ο", "φύλ", "λια"],
-    "Καρφάκια" => &["Καρ", "φά", "κια"],
-    "Κασελάκια" => &["Κα", "σε", "λά", "κια"],
-    "Κασέρια" => &["Κα", "σέ", "ρια"],
-    "Κασμίρια" => &["Κα", "σμί", "ρια"],
-    "Κασόνια" => &["Κα", "σό", "νια"],
-    "Καστάνια" => &["Κα", "στά", "νια"],
-    "Καστάνιας" => &["Κα", "στά", "νιας"],
-    "Καστάνιες" => &["Κα", "στά", "νιες"],
-    "Καστέλια" => &["Κα", "στέ", "λια"],
-    "Καστόρια" => &["Κα", "στό", "ρια"],
-    "Καταβρεχτήρια" => &["Κα", "τα", "βρε", "χτή", "ρια"],
-    "Κατακάθια" => &["Κα", "τα", "κά", "θια"],
-    "Κατάντια" => &["Κα", "τά", "ντια"],
-    "Κατάντιας" => &["Κα", "τά", "ντιας"],
-    "Κατάντιες" => &["Κα", "τά", "ντιες"],
-    "Καταπότια" => &["Κα", "τα", "πό", "τια"],
-    "Καταράχια" => &["Κα", "τα", "ρά", "χια"],
-    "Κατάρτια" => &["Κα", "τάρ", "τια"],
-    "Κατατόπια" => &["Κα", "τα", "τό", "πια"],
-    "Καταφρόνια" => &["Κα", "τα", "φρό", "νια"],
-    "Καταφρόνιας" => &["Κα", "τα", "φρό", "νιας"],
-    "Καταφρόνιες" => &["Κα", "τα", "φρό", "νιες"],
-    "Κατηγόρια" => &["Κα", "τη", "γό", "ρια"],
-    "Κατηγόριας" => &["Κα", "τη", "γό", "ριας"],
-    "Κατηγόριες" => &["Κα", "τη", "γό", "ριες"],
-    "Κατιμέρια" => &["Κα", "τι", "μέ", "ρια"],
-    "Κατοστάρια" => &["Κα", "το", "στά", "ρια"],
-    "Κατράμια" => &["Κα", "τρά", "μια"],
-    "Κατσαβίδια" => &["Κα", "τσα", "βί", "δια"],
-    "Κατσαμάκια" => &["Κα", "τσα", "μά", "κια"],
-    "Κατσαρόλια" => &["Κα", "τσα", "ρό", "λια"],
-    "Κατσικάκια" => &["Κα", "τσι", "κά", "κια"],
-    "Κατσίκια" => &["Κα", "τσί", "κια"],
-    "Κατσικίσια" => &["Κα", "τσι", "κί", "σια"],
-    "Κατσικίσιας" => &["Κα", "τσι", "κί", "σιας"],
-    "Κατσικίσιε" => &["Κα", "τσι", "κί", "σιε"],
-    "Κατσικίσιες" => &["Κα", "τσι", "κί", "σιες"],
-    "Κατσικίσιο" => &["Κα", "τσι", "κί", "σιο"],
-    "Κατσικίσιοι" => &["Κα", "τσι", "κί", "σιοι"],
-    "Κατσικίσιος" => &["Κα", "τσι", "κί", "σιος"],
-    "Κατσικίσιου" => &["Κα", "τσι", "κί", "σιου"],
-    "Κατσικίσιους" => &["Κα", "τσι", "κί", "σιους"],
-    "Κατσικίσιων" => &["Κα", "τσι", "κί", "σιων"],
-    "Κατώγια" => &["Κα", "τώ", "για"],
-    "Κατώφλια" => &["Κα", "τώ", "φλια"],
-    "Καφάσια" => &["Κα", "φά", "σια"],
-    "Καφεδάκια" => &["Κα", "φε", "δά", "κια"],
-    "Καφεκούτια" => &["Κα", "φε", "κού", "τια"],
-    "Καφενεδάκια" => &["Κα", "φε", "νε", "δά", "κια"],
-    "Καφτάνια" => &["Κα", "φτά", "νια"],
-    "Καψόνια" => &["Κα", "ψό", "νια"],
-    "Καψούλια" => &["Κα", "ψού", "λια"],
-    "Καψώνια" => &["Κα", "ψώ", "νια"],
-    "Κειμενάκια" => &["Κει", "με", "νά", "κια"],
-    "Κελάρια" => &["Κε", "λά", "ρια"],
-    "Κελεπούρια" => &["Κε", "λε", "πού", "ρια"],
-    "Κεμέρια" => &["Κε", "μέ", "ρια"],
-    "Κεντίδια" => &["Κε", "ντί", "δια"],
-    "Κεντράδια" => &["Κε", "ντρά", "δια"],
-    "Κεντράκια" => &["Κε", "ντρά", "κια"],
-    "Κεράκια" => &["Κε", "ρά", "κια"],
-    "Κεραμίδια" => &["Κε", "ρα", "μί", "δια"],
-    "Κερασάκια" => &["Κε", "ρα", "σά", "κια"],
-    "Κερασένια" => &["Κε", "ρα", "σέ", "νια"],
-    "Κερασένιας" => &["Κε", "ρα", "σέ", "νιας"],
-    "Κερασένιε" => &["Κε", "ρα", "σέ", "νιε"],
-    "Κερασένιες" => &["Κε", "ρα", "σέ", "νιες"],
-    "Κερασένιο" => &["Κε", "ρα", "σέ", "νιο"],
-    "Κερασένιοι" => &["Κε", "ρα", "σέ", "νιοι"],
-    "Κερασένιος" => &["Κε", "ρα", "σέ", "νιος"],
-    "Κερασένιου" => &["Κε", "ρα", "σέ", "νιου"],
-    "Κερασένιους" => &["Κε", "ρα", "σέ", "νιους"],
-    "Κερασένιων" => &["Κε", "ρα", "σέ", "νιων"],
-    "Κεράσια" => &["Κε", "ρά", "σια"],
-    "Κερατάκια" => &["Κε", "ρα", "τά", "κια"],
-    "Κερατένια" => &["Κε", "ρα", "τέ", "νια"],
-    "Κερατένιας" => &["Κε", "ρα", "τέ", "νιας"],
-    "Κερατένιε" => &["Κε", "ρα", "τέ", "νιε"],
-    "Κερατένιες" => &["Κε", "ρα", "τέ", "νιες"],
-    "Κερατένιο" => &["Κε", "ρα", "τέ", "νιο"],
-    "Κερατένιοι" => &["Κε", "ρα", "τέ", "νιοι"],
-    "Κερατένιος" => &["Κε", "ρα", "τέ", "νιος"],
-    "Κερατένιου" => &["Κε", "ρα", "τέ", "νιου"],
-    "Κερατένιους" => &["Κε", "ρα", "τέ", "νιους"],
-    "Κερατένιων" => &["Κε", "ρα", "τέ", "νιων"],
-    "Κερένια" => &["Κε", "ρέ", "νια"],
-    "Κερένιας" => &["Κε", "ρέ", "νιας"],
-    "Κερένιε" => &["Κε", "ρέ", "νιε"],
-    "Κερένιες" => &["Κε", "ρέ", "νιες"],
-    "Κερένιο" => &["Κε", "ρέ", "νιο"],
-    "Κερένιοι" => &["Κε", "ρέ", "νιοι"],
-    "Κερένιος" => &["Κε", "ρέ", "νιος"],
-    "Κερένιου" => &["Κε", "ρέ", "νιου"],
-    "Κερένιους" => &["Κε", "ρέ", "νιους"],
-    "Κερένιων" => &["Κε", "ρέ", "νιων"],
-    "Κεροπάνια" => &["Κε", "ρο", "πά", "νια"],
-    "Κεσάτια" => &["Κε", "σά", "τια"],
-    "Κεσεδάκια" => &["Κε", "σε", "δά", "κια"],
-    "Κεσέμια" => &["Κε", "σέ", "μια"],
-    "Κεφάκια" => &["Κε", "φά", "κια"],
-    "Κεφαλάκια" => &["Κε", "φα", "λά", "κια"],
-    "Κεφαλάρια" => &["Κε", "φα", "λά", "ρια"],
-    "Κεφάλια" => &["Κε", "φά", "λια"],
-    "Κεφαλίσια" => &["Κε", "φα", "λί", "σια"],
-    "Κεφαλίσιας" => &["Κε", "φα", "λί", "σιας"],
-    "Κεφαλίσιε" => &["Κε", "φα", "λί", "σιε"],
-    "Κεφαλίσιες" => &["Κε", "φα", "λί", "σιες"],
-    "Κεφαλίσιο" => &["Κε", "φα", "λί", "σιο"],
-    "Κεφαλίσιοι" => &["Κε", "φα", "λί", "σιοι"],
-    "Κεφαλίσιος" => &["Κε", "φα", "λί", "σιος"],
-    "Κεφαλίσιου" => &["Κε", "φα", "λί", "σιου"],
-    "Κεφαλίσιους" => &["Κε", "φα", "λί", "σιους"],
-    "Κεφαλίσιων" => &["Κε", "φα", "λί", "σιων"],
-    "Κεφαλοπάνια" => &["Κε", "φα", "λο", "πά", "νια"],
-    "Κεφαλοτύρια" => &["Κε", "φα", "λο", "τύ", "ρια"],
-    "Κεφαλοχώρια" => &["Κε", "φα", "λο", "χώ", "ρια"],
-    "Κέφια" => &["Κέ", "φια"],
-    "Κεχριμπαρένια" => &["Κε", "χρι", "μπα", "ρέ", "νια"],
-    "Κεχριμπαρένιας" => &["Κε", "χρι", "μπα", "ρέ", "νιας"],
-    "Κεχριμπαρένιε" => &["Κε", "χρι", "μπα", "ρέ", "νιε"],
-    "Κεχριμπαρένιες" => &["Κε", "χρι", "μπα", "ρέ", "νιες"],
-    "Κεχριμπαρένιο" => &["Κε", "χρι", "μπα", "ρέ", "νιο"],
-    "Κεχριμπαρένιοι" => &["Κε", "χρι", "μπα", "ρέ", "νιοι"],
-    "Κεχριμπαρένιος" => &["Κε", "χρι", "μπα", "ρέ", "νιος"],
-    "Κεχριμπαρένιου" => &["Κε", "χρι", "μπα", "ρέ", "νιου"],
-    "Κεχριμπαρένιους" => &["Κε", "χρι", "μπα", "ρέ", "νιους"],
-    "Κεχριμπαρένιων" => &["Κε", "χρι", "μπα", "ρέ", "νιων"],
-    "Κεχριμπάρια" => &["Κε", "χρι", "μπά", "ρια"],
-    "Κηπάκια" => &["Κη", "πά", "κια"],
-    "Κιάλια" => &["Κι", "ά", "λια"],
-    "Κιβούρια" => &["Κι", "βού", "ρια"],
-    "Κιλίμια" => &["Κι", "λί", "μια"],
-    "Κιόσκια" => &["Κι", "ό", "σκια"],
-    "Κιούγκια" => &["Κι", "ού", "γκια"],
-    "Κιούπια" => &["Κι", "ού", "πια"],
-    "Κιρκινέζια" => &["Κιρ", "κι", "νέ", "ζια"],
-    "Κιτάπια" => &["Κι", "τά", "πια"],
-    "Κιτρινάδια" => &["Κι", "τρι", "νά", "δια"],
-    "Κλαδάκια" => &["Κλα", "δά", "κια"],
-    "Κλαδευτήρια" => &["Κλα", "δευ", "τή", "ρια"],
-    "Κλαράκια" => &["Κλα", "ρά", "κια"],
-    "Κλειδάκια" => &["Κλει", "δά", "κια"],
-    "Κλεφτρόνια" => &["Κλε", "φτρό", "νια"],
-    "Κληματένια" => &["Κλη", "μα", "τέ", "νια"],
-    "Κληματένιας" => &["Κλη", "μα", "τέ", "νιας"],
-    "Κληματένιε" => &["Κλη", "μα", "τέ", "νιε"],
-    "Κληματένιες" => &["Κλη", "μα", "τέ", "νιες"],
-    "Κληματένιο" => &["Κλη", "μα", "τέ", "νιο"],
-    "Κληματένιοι" => &["Κλη", "μα", "τέ", "νιοι"],
-    "Κληματένιος" => &["Κλη", "μα", "τέ", "νιος"],
-    "Κληματένιου" => &["Κλη", "μα", "τέ", "νιου"],
-    "Κληματένιους" => &["Κλη", "μα", "τέ", "νιους"],
-    "Κληματένιων" => &["Κλη", "μα", "τέ", "νιων"],
-    "Κλινάρια" => &["Κλι", "νά", "ρια"],
-    "Κλοτσοσκούφια" => &["Κλο", "τσο", "σκού", "φια"],
-    "Κλουβάκια" => &["Κλου", "βά", "κια"],
-    "Κλωνάκια" => &["Κλω", "νά", "κια"],
-    "Κλωναράκια" => &["Κλω", "να", "ρά", "κια"],
-    "Κλωνάρια" => &["Κλω", "νά", "ρια"],
-    "Κλωσοπουλάκια" => &["Κλω", "σο", "που", "λά", "κια"],
-    "Κλωσοπούλια" => &["Κλω", "σο", "πού", "λια"],
-    "Κοιμητήρια" => &["Κοι", "μη", "τή", "ρια"],
-    "Κοκαλάκια" => &["Κο", "κα", "λά", "κια"],
-    "Κοκαλένια" => &["Κο", "κα", "λέ", "νια"],
-    "Κοκαλένιας" => &["Κο", "κα", "λέ", "νιας"],
-    "Κοκαλένιε" => &["Κο", "κα", "λέ", "νιε"],
-    "Κοκαλένιες" => &["Κο", "κα", "λέ", "νιες"],
-    "Κοκαλένιο" => &["Κο", "κα", "λέ", "νιο"],
-    "Κοκαλένιοι" => &["Κο", "κα", "λέ", "νιοι"],
-    "Κοκαλένιος" => &["Κο", "κα", "λέ", "νιος"],
-    "Κοκαλένιου" => &["Κο", "κα", "λέ", "νιου"],
-    "Κοκαλένιους" => &["Κο", "κα", "λέ", "νιους"],
-    "Κοκαλένιων" => &["Κο", "κα", "λέ", "νιων"],
-    "Κοκκάρια" => &["Κοκ", "κά", "ρια"],
-    "Κοκκινάδια" => &["Κοκ", "κι", "νά", "δια"],
-    "Κοκκινέλια" => &["Κοκ", "κι", "νέ", "λια"],
-    "Κοκκινογούλια" => &["Κοκ", "κι", "νο", "γού", "λια"],
-    "Κοκοράκια" => &["Κο", "κο", "ρά", "κια"],
-    "Κοκορετσάκια" => &["Κο", "κο", "ρε", "τσά", "κια"],
-    "Κοκορέτσια" => &["Κο", "κο", "ρέ", "τσια"],
-    "Κοκόρια" => &["Κο", "κό", "ρια"],
-    "Κολλητήρια" => &["Κολ", "λη", "τή", "ρια"],
-    "Κολοκυθάκια" => &["Κο", "λο", "κυ", "θά", "κια"],
-    "Κολοκυθένια" => &["Κο", "λο", "κυ", "θέ", "νια"],
-    "Κολοκυθένιας" => &["Κο", "λο", "κυ", "θέ", "νιας"],
-    "Κολοκυθένιε" => &["Κο", "λο", "κυ", "θέ", "νιε"],
-    "Κολοκυθένιες" => &["Κο", "λο", "κυ", "θέ", "νιες"],
-    "Κολοκυθένιο" => &["Κο", "λο", "κυ", "θέ", "νιο"],
-    "Κολοκυθένιοι" => &["Κο", "λο", "κυ", "θέ", "νιοι"],
-    "Κολοκυθένιος" => &["Κο", "λο", "κυ", "θέ", "νιος"],
-    "Κολοκυθένιου" => &["Κο", "λο", "κυ", "θέ", "νιου"],
-    "Κολοκυθένιους" => &["Κο", "λο", "κυ", "θέ", "νιους"],
-    "Κολοκυθένιων" => &["Κο", "λο", "κυ", "θέ", "νιων"],
-    "Κολοκύθια" => &["Κο", "λο", "κύ", "θια"],
-    "Κολονάκια" => &["Κο", "λο", "νά", "κια"],
-    "Κολόνια" => &["Κο", "λό", "νια"],
-    "Κολόνιας" => &["Κο", "λό", "νιας"],
-    "Κολόνιες" => &["Κο", "λό", "νιες"],
-    "Κολπάκια" => &["Κολ", "πά", "κια"],
-    "Κολύμπια" => &["Κο", "λύ", "μπια"],
-    "Κομματάκια" => &["Κομ", "μα", "τά", "κια"],
-    "Κομμάτια" => &["Κομ", "μά", "τια"],
-    "Κομπιουτεράκια" => &["Κο", "μπι", "ου", "τε", "ρά", "κια"],
-    "Κομπιουτεράκιας" => &["Κο", "μπι", "ου", "τε", "ρά", "κιας"],
-    "Κομπιουτεράκιες" => &["Κο", "μπι", "ου", "τε", "ρά", "κιες"],
-    "Κομπολογάκια" => &["Κο", "μπο", "λο", "γά", "κια"],
-    "Κομπολόγια" => &["Κο", "μπο", "λό", "για"],
-    "Κομποσκοίνια" => &["Κο", "μπο", "σκοί", "νια"],
-    "Κονάκια" => &["Κο", "νά", "κια"],
-    "Κονδύλια" => &["Κον", "δύ", "λια"],
-    "Κονσερβοκούτια" => &["Κον", "σερ", "βο", "κού", "τια"],
-    "Κοντάκια" => &["Κο", "ντά", "κια"],
-    "Κοντάρια" => &["Κο", "ντά", "ρια"],
-    "Κοντοβράκια" => &["Κο", "ντο", "βρά", "κια"],
-    "Κοντογούνια" => &["Κο", "ντο", "γού", "νια"],
-    "Κοντυλένια" => &["Κο", "ντυ", "λέ", "νια"],
-    "Κοντυλένιας" => &["Κο", "ντυ", "λέ", "νιας"],
-    "Κοντυλένιε" => &["Κο", "ντυ", "λέ", "νιε"],
-    "Κοντυλένιες" => &["Κο", "ντυ", "λέ", "νιες"],
-    "Κοντυλένιο" => &["Κο", "ντυ", "λέ", "νιο"],
-    "Κοντυλένιοι" => &["Κο", "ντυ", "λέ", "νιοι"],
-    "Κοντυλένιος" => &["Κο", "ντυ", "λέ", "νιος"],
-    "Κοντυλένιου" => &["Κο", "ντυ", "λέ", "νιου"],
-    "Κοντυλένιους" => &["Κο", "ντυ", "λέ", "νιους"],
-    "Κοντυλένιων" => &["Κο", "ντυ", "λέ", "νιων"],
-    "Κοντύλια" => &["Κο", "ντύ", "λια"],
-    "Κοπάδια" => &["Κο", "πά", "δια"],
-    "Κοπέλια" => &["Κο", "πέ", "λια"],
-    "Κόπια" => &["Κό", "πια"],
-    "Κόπιας" => &["Κό", "πιας"],
-    "Κοπίδια" => &["Κο", "πί", "δια"],
-    "Κόπιες" => &["Κό", "πιες"],
-    "Κοράκια" => &["Κο", "ρά", "κια"],
-    "Κοραλλένια" => &["Κο", "ραλ", "λέ", "νια"],
-    "Κοραλλένιας" => &["Κο", "ραλ", "λέ", "νιας"],
-    "Κοραλλένιε" => &["Κο", "ραλ", "λέ", "νιε"],
-    "Κοραλλένιες" => &["Κο", "ραλ", "λέ", "νιες"],
-    "Κοραλλένιο" => &["Κο", "ραλ", "λέ", "νιο"],
-    "Κοραλλένιοι" => &["Κο", "ραλ", "λέ", "νιοι"],
-    "Κοραλλένιος" => &["Κο", "ραλ", "λέ", "νιος"],
-    "Κοραλλένιου" => &["Κο", "ραλ", "λέ", "νιου"],
-    "Κοραλλένιους" => &["Κο", "ραλ", "λέ", "νιους"],
-    "Κοραλλένιων" => &["Κο", "ραλ", "λέ", "νιων"],
-    "Κοράλλια" => &["Κο", "ράλ", "λια"],
-    "Κοράσια" => &["Κο", "ρά", "σια"],
-    "Κορδελάκια" => &["Κορ", "δε", "λά", "κια"],
-    "Κορδονάκια" => &["Κορ", "δο", "νά", "κια"],
-    "Κορδόνια" => &["Κορ", "δό", "νια"],
-    "Κοριτσάκια" => &["Κο", "ρι", "τσά", "κια"],
-    "Κορίτσια" => &["Κο", "ρί", "τσια"],
-    "Κορμάκια" => &["Κορ", "μά", "κια"],
-    "Κορόδια" => &["Κο", "ρό", "δια"],
-    "Κορόδιο" => &["Κο", "ρό", "διο"],
-    "Κορόδιου" => &["Κο", "ρό", "διου"],
-    "Κορόδιων" => &["Κο", "ρό", "διων"],
-    "Κορόιδα" => &["Κο", "ρόι", "δα"],
-    "Κοροϊδάκια" => &["Κο", "ροϊ", "δά", "κια"],
-    "Κορόιδο" => &["Κο", "ρόι", "δο"],
-    "Κορόιδου" => &["Κο", "ρόι", "δου"],
-    "Κορόιδων" => &["Κο", "ρόι", "δων"],
-    "Κορτάκια" => &["Κορ", "τά", "κια"],
-    "Κορτάκιας" => &["Κορ", "τά", "κιας"],
-    "Κορτάκιες" => &["Κορ", "τά", "κιες"],
-    "Κορφοβούνια" => &["Κορ", "φο", "βού", "νια"],
-    "Κοστουμάκια" => &["Κο", "στου", "μά", "κια"],
-    "Κοστούμια" => &["Κο", "στού", "μια"],
-    "Κοτέτσια" => &["Κο", "τέ", "τσια"],
-    "Κοτίσια" => &["Κο", "τί", "σια"],
-    "Κοτίσιας" => &["Κο", "τί", "σιας"],
-    "Κοτίσιε" => &["Κο", "τί", "σιε"],
-    "Κοτίσιες" => &["Κο", "τί", "σιες"],
-    "Κοτίσιο" => &["Κο", "τί", "σιο"],
-    "Κοτίσιοι" => &["Κο", "τί", "σιοι"],
-    "Κοτίσιος" => &["Κο", "τί", "σιος"],
-    "Κοτίσιου" => &["Κο", "τί", "σιου"],
-    "Κοτίσιους" => &["Κο", "τί", "σιους"],
-    "Κοτίσιων" => &["Κο", "τί", "σιων"],
-    "Κοτοπουλάκια" => &["Κο", "το", "που", "λά", "κια"],
-    "Κοτρόνια" => &["Κο", "τρό", "νια"],
-    "Κοτσάκια" => &["Κο", "τσά", "κια"],
-    "Κοτσανάκια" => &["Κο", "τσα", "νά", "κια"],
-    "Κοτσάνια" => &["Κο", "τσά", "νια"],
-    "Κότσια" => &["Κό", "τσια"],
-    "Κοτσιδάκια" => &["Κο", "τσι", "δά", "κια"],
-    "Κοτσίδια" => &["Κο", "τσί", "δια"],
-    "Κοτσύφια" => &["Κο", "τσύ", "φια"],
-    "Κουβαράκια" => &["Κου", "βα", "ρά", "κια"],
-    "Κουβάρια" => &["Κου", "βά", "ρια"],
-    "Κουβαρνταλίκια" => &["Κου", "βαρ", "ντα", "λί", "κια"],
-    "Κουβέλια" => &["Κου", "βέ", "λια"],
-    "Κουβεντολόγια" => &["Κου", "βε", "ντο", "λό", "για"],
-    "Κουβεντολόια" => &["Κου", "βε", "ντο", "λό", "ια"],
-    "Κουδουνάκια" => &["Κου", "δου", "νά", "κια"],
-    "Κουδούνια" => &["Κου", "δού", "νια"],
-    "Κουκλάκια" => &["Κου", "κλά", "κια"],
-    "Κουκουβάγια" => &["Κου", "κου", "βά", "για"],
-    "Κουκουβάγιας" => &["Κου", "κου", "βά", "γιας"],
-    "Κουκουβάγιες" => &["Κου", "κου", "βά", "γιες"],
-    "Κουκούλια" => &["Κου", "κού", "λια"],
-    "Κουκουνάρια" => &["Κου", "κου", "νά", "ρια"],
-    "Κουκουτσάκια" => &["Κου", "κου", "τσά", "κια"],
-    "Κουκούτσια" => &["Κου", "κού", "τσια"],
-    "Κουλουράκια" => &["Κου", "λου", "ρά", "κια"],
-    "Κουλούρια" => &["Κου", "λού", "ρια"],
-    "Κουμάρια" => &["Κου", "μά", "ρια"],
-    "Κουμάσια" => &["Κου", "μά", "σια"],
-    "Κουμπάκια" => &["Κου", "μπά", "κια"],
-    "Κουμπάνια" => &["Κου", "μπά", "νια"],
-    "Κουμπάνιας" => &["Κου", "μπά", "νιας"],
-    "Κουμπάνιες" => &["Κου", "μπά", "νιες"],
-    "Κουμπούρια" => &["Κου", "μπού", "ρια"],
-    "Κουνάβια" => &["Κου", "νά", "βια"],
-    "Κουνάδια" => &["Κου", "νά", "δια"],
-    "Κουνελάκια" => &["Κου", "νε", "λά", "κια"],
-    "Κουνέλια" => &["Κου", "νέ", "λια"],
-    "Κούνια" => &["Κού", "νια"],
-    "Κούνιας" => &["Κού", "νιας"],
-    "Κούνιες" => &["Κού", "νιες"],
-    "Κουνουπάκια" => &["Κου", "νου", "πά", "κια"],
-    "Κουνούπια" => &["Κου", "νού", "πια"],
-    "Κουνουπίδια" => &["Κου", "νου", "πί", "δια"],
-    "Κουπάκια" => &["Κου", "πά", "κια"],
-    "Κουπονάκια" => &["Κου", "πο", "νά", "κια"],
-    "Κουπόνια" => &["Κου", "πό", "νια"],
-    "Κουράγια" => &["Κου", "ρά", "για"],
-    "Κουράγιο" => &["Κου", "ρά", "γιο"],
-    "Κουράγιου" => &["Κου", "ρά", "γιου"],
-    "Κουράγιων" => &["Κου", "ρά", "γιων"],
-    "Κουράδια" => &["Κου", "ρά", "δια"],
-    "Κουρασάνια" => &["Κου", "ρα", "σά", "νια"],
-    "Κουρδιστήρια" => &["Κουρ", "δι", "στή", "ρια"],
-    "Κουρελάκια" => &["Κου", "ρε", "λά", "κια"],
-    "Κουρέλια" => &["Κου", "ρέ", "λια"],
-    "Κουρκούτια" => &["Κουρ", "κού", "τια"],
-    "Κουρμπάνια" => &["Κουρ", "μπά", "νια"],
-    "Κουρμπάτσια" => &["Κουρ", "μπά", "τσια"],
-    "Κουρμπέτια" => &["Κουρ", "μπέ", "τια"],
-    "Κούρνια" => &["Κούρ", "νια"],
-    "Κούρνιας" => &["Κούρ", "νιας"],
-    "Κούρνιες" => &["Κούρ", "νιες"],
-    "Κουρντιστήρια" => &["Κουρ", "ντι", "στή", "ρια"],
-    "Κουρούπια" => &["Κου", "ρού", "πια"],
-    "Κουρτινάκια" => &["Κουρ", "τι", "νά", "κια"],
-    "Κουσέλια" => &["Κου", "σέ", "λια"],
-    "Κουσκούσια" => &["Κου", "σκού", "σια"],
-    "Κουσούρια" => &["Κου", "σού", "ρια"],
-    "Κουστουμάκια" => &["Κου", "στου", "μά", "κια"],
-    "Κουστούμια" => &["Κου", "στού", "μια"],
-    "Κουταβάκια" => &["Κου", "τα", "βά", "κια"],
-    "Κουτάβια" => &["Κου", "τά", "βια"],
-    "Κουτάκια" => &["Κου", "τά", "κια"],
-    "Κουταλάκια" => &["Κου", "τα", "λά", "κια"],
-    "Κουτάλια" => &["Κου", "τά", "λια"],
-    "Κουτορνίθια" => &["Κου", "τορ", "νί", "θια"],
-    "Κουτουκάκια" => &["Κου", "του", "κά", "κια"],
-    "Κουτούκια" => &["Κου", "τού", "κια"],
-    "Κουφάρια" => &["Κου", "φά", "ρια"],
-    "Κουφετάκια" => &["Κου", "φε", "τά", "κια"],
-    "Κοφινάκια" => &["Κο", "φι", "νά", "κια"],
-    "Κοφίνια" => &["Κο", "φί", "νια"],
-    "Κοφτήρια" => &["Κο", "φτή", "ρια"],
-    "Κοχλάδια" => &["Κο", "χλά", "δια"],
-    "Κοχλίδια" => &["Κο", "χλί", "δια"],
-    "Κοχυλάκια" => &["Κο", "χυ", "λά", "κια"],
-    "Κοχύλια" => &["Κο", "χύ", "λια"],
-    "Κοψίδια" => &["Κο", "ψί", "δια"],
-    "Κραγιόνια" => &["Κρα", "γι", "ό", "νια"],
-    "Κρανένια" => &["Κρα", "νέ", "νια"],
-    "Κρανένιας" => &["Κρα", "νέ", "νιας"],
-    "Κρανένιε" => &["Κρα", "νέ", "νιε"],
-    "Κρανένιες" => &["Κρα", "νέ", "νιες"],
-    "Κρανένιο" => &["Κρα", "νέ", "νιο"],
-    "Κρανένιοι" => &["Κρα", "νέ", "νιοι"],
-    "Κρανένιος" => &["Κρα", "νέ", "νιος"],
-    "Κρανένιου" => &["Κρα", "νέ", "νιου"],
-    "Κρανένιους" => &["Κρα", "νέ", "νιους"],
-    "Κρανένιων" => &["Κρα", "νέ", "νιων"],
-    "Κρανίσια" => &["Κρα", "νί", "σια"],
-    "Κρανίσιας" => &["Κρα", "νί", "σιας"],
-    "Κρανίσιε" => &["Κρα", "νί", "σιε"],
-    "Κρανίσιες" => &["Κρα", "νί", "σιες"],
-    "Κρανίσιο" => &["Κρα", "νί", "σιο"],
-    "Κρανίσιοι" => &["Κρα", "νί", "σιοι"],
-    "Κρανίσιος" => &["Κρα", "νί", "σιος"],
-    "Κρανίσιου" => &["Κρα", "νί", "σιου"],
-    "Κρανίσιους" => &["Κρα", "νί", "σιους"],
-    "Κρανίσιων" => &["Κρα", "νί", "σιων"],
-    "Κρασάκια" => &["Κρα", "σά", "κια"],
-    "Κρασοπότια" => &["Κρα", "σο", "πό", "τια"],
-    "Κρεατάκια" => &["Κρε", "α", "τά", "κια"],
-    "Κρεβατάκια" => &["Κρε", "βα", "τά", "κια"],
-    "Κρεβάτια" => &["Κρε", "βά", "τια"],
-    "Κρεμαστάρια" => &["Κρε", "μα", "στά", "ρια"],
-    "Κρεμέζια" => &["Κρε", "μέ", "ζια"],
-    "Κρεμμυδάκια" => &["Κρεμ", "μυ", "δά", "κια"],
-    "Κρεμμύδια" => &["Κρεμ", "μύ", "δια"],
-    "Κρέπια" => &["Κρέ", "πια"],
-    "Κριαράκια" => &["Κρι", "α", "ρά", "κια"],
-    "Κριάρια" => &["Κρι", "ά", "ρια"],
-    "Κριθαράκια" => &["Κρι", "θα", "ρά", "κια"],
-    "Κριθαρένια" => &["Κρι", "θα", "ρέ", "νια"],
-    "Κριθαρένιας" => &["Κρι", "θα", "ρέ", "νιας"],
-    "Κριθαρένιε" => &["Κρι", "θα", "ρέ", "νιε"],
-    "Κριθαρένιες" => &["Κρι", "θα", "ρέ", "νιες"],
-    "Κριθαρένιο" => &["Κρι", "θα", "ρέ", "νιο"],
-    "Κριθαρένιοι" => &["Κρι", "θα", "ρέ", "νιοι"],
-    "Κριθαρένιος" => &["Κρι", "θα", "ρέ", "νιος"],
-    "Κριθαρένιου" => &["Κρι", "θα", "ρέ", "νιου"],
-    "Κριθαρένιους" => &["Κρι", "θα", "ρέ", "νιους"],
-    "Κριθαρένιων" => &["Κρι", "θα", "ρέ", "νιων"],
-    "Κριθάρια" => &["Κρι", "θά", "ρια"],
-    "Κριθαρίσια" => &["Κρι", "θα", "ρί", "σια"],
-    "Κριθαρίσιας" => &["Κρι", "θα", "ρί", "σιας"],
-    "Κριθαρίσιε" => &["Κρι", "θα", "ρί", "σιε"],
-    "Κριθαρίσιες" => &["Κρι", "θα", "ρί", "σιες"],
-    "Κριθαρίσιο" => &["Κρι", "θα", "ρί", "σιο"],
-    "Κριθαρίσιοι" => &["Κρι", "θα", "ρί", "σιοι"],
-    "Κριθαρίσιος" => &["Κρι", "θα", "ρί", "σιος"],
-    "Κριθαρίσιου" => &["Κρι", "θα", "ρί", "σιου"],
-    "Κριθαρίσιους" => &["Κρι", "θα", "ρί", "σιους"],
-    "Κριθαρίσιων" => &["Κρι", "θα", "ρί", "σιων"],
-    "Κρικέλια" => &["Κρι", "κέ", "λια"],
-    "Κρινάκια" => &["Κρι", "νά", "κια"],
-    "Κρινένια" => &["Κρι", "νέ", "νια"],
-    "Κρινένιας" => &["Κρι", "νέ", "νιας"],
-    "Κρινένιε" => &["Κρι", "νέ", "νιε"],
-    "Κρινένιες" => &["Κρι", "νέ", "νιες"],
-    "Κρινένιο" => &["Κρι", "νέ", "νιο"],
-    "Κρινένιοι" => &["Κρι", "νέ", "νιοι"],
-    "Κρινένιος" => &["Κρι", "νέ", "νιος"],
-    "Κρινένιου" => &["Κρι", "νέ", "νιου"],
-    "Κρινένιους" => &["Κρι", "νέ", "νιους"],
-    "Κρινένιων" => &["Κρι", "νέ", "νιων"],
-    "Κροκοδειλίσια" => &["Κρο", "κο", "δει", "λί", "σια"],
-    "Κροκοδειλίσιας" => &["Κρο", "κο", "δει", "λί", "σιας"],
-    "Κροκοδειλίσιε" => &["Κρο", "κο", "δει", "λί", "σιε"],
-    "Κροκοδειλίσιες" => &["Κρο", "κο", "δει", "λί", "σιες"],
-    "Κροκοδειλίσιο" => &["Κρο", "κο", "δει", "λί", "σιο"],
-    "Κροκοδειλίσιοι" => &["Κρο", "κο", "δει", "λί", "σιοι"],
-    "Κροκοδειλίσιος" => &["Κρο", "κο", "δει", "λί", "σιος"],
-    "Κροκοδειλίσιου" => &["Κρο", "κο", "δει", "λί", "σιου"],
-    "Κροκοδειλίσιους" => &["Κρο", "κο", "δει", "λί", "σιους"],
-    "Κροκοδειλίσιων" => &["Κρο", "κο", "δει", "λί", "σιων"],
-    "Κρομμύδια" => &["Κρομ", "μύ", "δια"],
-    "Κροντήρια" => &["Κρο", "ντή", "ρια"],
-    "Κρόσσια" => &["Κρόσ", "σια"],
-    "Κρουσταλλάκια" => &["Κρου", "σταλ", "λά", "κια"],
-    "Κρουσταλλένια" => &["Κρου", "σταλ", "λέ", "νια"],
-    "Κρουσταλλένιας" => &["Κρου", "σταλ", "λέ", "νιας"],
-    "Κρουσταλλένιε" => &["Κρου", "σταλ", "λέ", "νιε"],
-    "Κρουσταλλένιες" => &["Κρου", "σταλ", "λέ", "νιες"],
-    "Κρουσταλλένιο" => &["Κρου", "σταλ", "λέ", "νιο"],
-    "Κρουσταλλένιοι" => &["Κρου", "σταλ", "λέ", "νιοι"],
-    "Κρουσταλλένιος" => &["Κρου", "σταλ", "λέ", "νιος"],
-    "Κρουσταλλένιου" => &["Κρου", "σταλ", "λέ", "νιου"],
-    "Κρουσταλλένιους" => &["Κρου", "σταλ", "λέ", "νιους"],
-    "Κρουσταλλένιων" => &["Κρου", "σταλ", "λέ", "νιων"],
-    "Κρουστάλλια" => &["Κρου", "στάλ", "λια"],
-    "Κρυπτούλια" => &["Κρυ", "πτού", "λια"],
-    "Κρυσταλλάκια" => &["Κρυ", "σταλ", "λά", "κια"],
-    "Κρυσταλλένια" => &["Κρυ", "σταλ", "λέ", "νια"],
-    "Κρυσταλλένιας" => &["Κρυ", "σταλ", "λέ", "νιας"],
-    "Κρυσταλλένιε" => &["Κρυ", "σταλ", "λέ", "νιε"],
-    "Κρυσταλλένιες" => &["Κρυ", "σταλ", "λέ", "νιες"],
-    "Κρυσταλλένιο" => &["Κρυ", "σταλ", "λέ", "νιο"],
-    "Κρυσταλλένιοι" => &["Κρυ", "σταλ", "λέ", "νιοι"],
-    "Κρυσταλλένιος" => &["Κρυ", "σταλ", "λέ", "νιος"],
-    "Κρυσταλλένιου" => &["Κρυ", "σταλ", "λέ", "νιου"],
-    "Κρυσταλλένιους" => &["Κρυ", "σταλ", "λέ", "νιους"],
-    "Κρυσταλλένιων" => &["Κρυ", "σταλ", "λέ", "νιων"],
-    "Κρυστάλλια" => &["Κρυ", "στάλ", "λια"],
-    "Κρυφτούλια" => &["Κρυ", "φτού", "λια"],
-    "Κρυωματάκια" => &["Κρυ", "ω", "μα", "τά", "κια"],
-    "Κτενάκια" => &["Κτε", "νά", "κια"],
-    "Κτένια" => &["Κτέ", "νια"],
-    "Κτυπηματάκια" => &["Κτυ", "πη", "μα", "τά", "κια"],
-    "Κτυπητήρια" => &["Κτυ", "πη", "τή", "ρια"],
-    "Κτυποκάρδια" => &["Κτυ", "πο", "κάρ", "δια"],
-    "Κυβέρνια" => &["Κυ", "βέρ", "νια"],
-    "Κυβέρνιας" => &["Κυ", "βέρ", "νιας"],
-    "Κυβέρνιες" => &["Κυ", "βέρ", "νιες"],
-    "Κυδώνια" => &["Κυ", "δώ", "νια"],
-    "Κυματάκια" => &["Κυ", "μα", "τά", "κια"],
-    "Κυνήγια" => &["Κυ", "νή", "για"],
-    "Κυπαρισσάκια" => &["Κυ", "πα", "ρισ", "σά", "κια"],
-    "Κυπαρισσένια" => &["Κυ", "πα", "ρισ", "σέ", "νια"],
-    "Κυπαρισσένιας" => &["Κυ", "πα", "ρισ", "σέ", "νιας"],
-    "Κυπαρισσένιε" => &["Κυ", "πα", "ρισ", "σέ", "νιε"],
-    "Κυπαρισσένιες" => &["Κυ", "πα", "ρισ", "σέ", "νιες"],
-    "Κυπαρισσένιο" => &["Κυ", "πα", "ρισ", "σέ", "νιο"],
-    "Κυπαρισσένιοι" => &["Κυ", "πα", "ρισ", "σέ", "νιοι"],
-    "Κυπαρισσένιος" => &["Κυ", "πα", "ρισ", "σέ", "νιος"],
-    "Κυπαρισσένιου" => &["Κυ", "πα", "ρισ", "σέ", "νιου"],
-    "Κυπαρισσένιους" => &["Κυ", "πα", "ρισ", "σέ", "νιους"],
-    "Κυπαρισσένιων" => &["Κυ", "πα", "ρισ", "σέ", "νιων"],
-    "Κυπαρίσσια" => &["Κυ", "πα", "ρίσ", "σια"],
-    "Κυπελάκια" => &["Κυ", "πε", "λά", "κια"],
-    "Κυπελλάκια" => &["Κυ", "πελ", "λά", "κια"],
-    "Κυριλίκια" => &["Κυ", "ρι", "λί", "κια"],
-    "Κωθώνια" => &["Κω", "θώ", "νια"],
-    "Κωλομέρια" => &["Κω", "λο", "μέ", "ρια"],
-    "Κωλονούρια" => &["Κω", "λο", "νού", "ρια"],
-    "Λαβράκια" => &["Λα", "βρά", "κια"],
-    "Λαγάκια" => &["Λα", "γά", "κια"],
-    "Λαγήνια" => &["Λα", "γή", "νια"],
-    "Λάγια" => &["Λά", "για"],
-    "Λάγιας" => &["Λά", "γιας"],
-    "Λάγιε" => &["Λά", "γιε"],
-    "Λάγιες" => &["Λά", "γιες"],
-    "Λάγιο" => &["Λά", "γιο"],
-    "Λάγιοι" => &["Λά", "γιοι"],
-    "Λάγιος" => &["Λά", "γιος"],
-    "Λάγιου" => &["Λά", "γιου"],
-    "Λάγιους" => &["Λά", "γιους"],
-    "Λαγίσια" => &["Λα", "γί", "σια"],
-    "Λαγίσιας" => &["Λα", "γί", "σιας"],
-    "Λαγίσιε" => &["Λα", "γί", "σιε"],
-    "Λαγίσιες" => &["Λα", "γί", "σιες"],
-    "Λαγίσιο" => &["Λα", "γί", "σιο"],
-    "Λαγίσιοι" => &["Λα", "γί", "σιοι"],
-    "Λαγίσιος" => &["Λα", "γί", "σιος"],
-    "Λαγίσιου" => &["Λα", "γί", "σιου"],
-    "Λαγίσιους" => &["Λα", "γί", "σιους"],
-    "Λαγίσιων" => &["Λα", "γί", "σιων"],
-    "Λάγιων" => &["Λά", "γιων"],
-    "Λαγκάδια" => &["Λα", "γκά", "δια"],
-    "Λαγοκυνήγια" => &["Λα", "γο", "κυ", "νή", "για"],
-    "Λαγόνια" => &["Λα", "γό", "νια"],
-    "Λαγούμια" => &["Λα", "γού", "μια"],
-    "Λαδάκια" => &["Λα", "δά", "κια"],
-    "Λάδια" => &["Λά", "δια"],
-    "Λαδωτήρια" => &["Λα", "δω", "τή", "ρια"],
-    "Λαζάνια" => &["Λα", "ζά", "νια"],
-    "Λαήνια" => &["Λα", "ή", "νια"],
-    "Λαθάκια" => &["Λα", "θά", "κια"],
-    "Λαθούρια" => &["Λα", "θού", "ρια"],
-    "Λαΐσιε" => &["Λα", "ΐ", "σιε"],
-    "Λαΐσιο" => &["Λα", "ΐ", "σιο"],
-    "Λαΐσιοι" => &["Λα", "ΐ", "σιοι"],
-    "Λαΐσιος" => &["Λα", "ΐ", "σιος"],
-    "Λαΐσιου" => &["Λα", "ΐ", "σιου"],
-    "Λαΐσιους" => &["Λα", "ΐ", "σιους"],
-    "Λαΐσιων" => &["Λα", "ΐ", "σιων"],
-    "Λακκάκια" => &["Λακ", "κά", "κια"],
-    "Λαμαρινένια" => &["Λα", "μα", "ρι", "νέ", "νια"],
-    "Λαμαρινένιας" => &["Λα", "μα", "ρι", "νέ", "νιας"],
-    "Λαμαρινένιε" => &["Λα", "μα", "ρι", "νέ", "νιε"],
-    "Λαμαρινένιες" => &["Λα", "μα", "ρι", "νέ", "νιες"],
-    "Λαμαρινένιο" => &["Λα", "μα", "ρι", "νέ", "νιο"],
-    "Λαμαρινένιοι" => &["Λα", "μα", "ρι", "νέ", "νιοι"],
-    "Λαμαρινένιος" => &["Λα", "μα", "ρι", "νέ", "νιος"],
-    "Λαμαρινένιου" => &["Λα", "μα", "ρι", "νέ", "νιου"],
-    "Λαμαρινένιους" => &["Λα", "μα", "ρι", "νέ", "νιους"],
-    "Λαμαρινένιων" => &["Λα", "μα", "ρι", "νέ", "νιων"],
-    "Λάμια" => &["Λά", "μια"],
-    "Λάμιας" => &["Λά", "μιας"],
-    "Λάμιες" => &["Λά", "μιες"],
-    "Λαμνοκόπια" => &["Λα", "μνο", "κό", "πια"],
-    "Λαμόγια" => &["Λα", "μό", "για"],
-    "Λαμόγιο" => &["Λα", "μό", "γιο"],
-    "Λαμόγιου" => &["Λα", "μό", "γιου"],
-    "Λαμόγιων" => &["Λα", "μό", "γιων"],
-    "Λαμπάκια" => &["Λα", "μπά", "κια"],
-    "Λαμπιόνια" => &["Λα", "μπι", "ό", "νια"],
-    "Λαμποκόπια" => &["Λα", "μπο", "κό", "πια"],
-    "Λανάρια" => &["Λα", "νά", "ρια"],
-    "Λαούμια" => &["Λα", "ού", "μια"],
-    "Λαρύγγια" => &["Λα", "ρύγ", "για"],
-    "Λασπονέρια" => &["Λα", "σπο", "νέ", "ρια"],
-    "Λασποτόπια" => &["Λα", "σπο", "τό", "πια"],
-    "Λαστιχάκια" => &["Λα", "στι", "χά", "κια"],
-    "Λαστιχένια" => &["Λα", "στι", "χέ", "νια"],
-    "Λαστιχένιας" => &["Λα", "στι", "χέ", "νιας"],
-    "Λαστιχένιε" => &["Λα", "στι", "χέ", "νιε"],
-    "Λαστιχένιες" => &["Λα", "στι", "χέ", "νιες"],
-    "Λαστιχένιο" => &["Λα", "στι", "χέ", "νιο"],
-    "Λαστιχένιοι" => &["Λα", "στι", "χέ", "νιοι"],
-    "Λαστιχένιος" => &["Λα", "στι", "χέ", "νιος"],
-    "Λαστιχένιου" => &["Λα", "στι", "χέ", "νιου"],
-    "Λαστιχένιους" => &["Λα", "στι", "χέ", "νιους"],
-    "Λαστιχένιων" => &["Λα", "στι", "χέ", "νιων"],
-    "Λατίνια" => &["Λα", "τί", "νια"],
-    "Λατόμια" => &["Λα", "τό", "μια"],
-    "Λάφια" => &["Λά", "φια"],
-    "Λαχανάκια" => &["Λα", "χα", "νά", "κια"],
-    "Λαχούρια" => &["Λα", "χού", "ρια"],
-    "Λεβέτια" => &["Λε", "βέ", "τια"],
-    "Λεγένια" => &["Λε", "γέ", "νια"],
-    "Λελέκια" => &["Λε", "λέ", "κια"],
-    "Λελούδια" => &["Λε", "λού", "δια"],
-    "Λεμονάκια" => &["Λε", "μο", "νά", "κια"],
-    "Λεμονάνθια" => &["Λε", "μο", "νάν", "θια"],
-    "Λεμόνια" => &["Λε", "μό", "νια"],
-    "Λέπια" => &["Λέ", "πια"],
-    "Λεπίδια" => &["Λε", "πί", "δια"],
-    "Λέσια" => &["Λέ", "σια"],
-    "Λεφτοκάρια" => &["Λε", "φτο", "κά", "ρια"],
-    "Λεχούδια" => &["Λε", "χού", "δια"],
-    "Λημέρια" => &["Λη", "μέ", "ρια"],
-    "Λιανοκέρια" => &["Λι", "α", "νο", "κέ", "ρια"],
-    "Λιβαδάκια" => &["Λι", "βα", "δά", "κια"],
-    "Λιβάδια" => &["Λι", "βά", "δια"],
-    "Λιβαδίσια" => &["Λι", "βα", "δί", "σια"],
-    "Λιβαδίσιας" => &["Λι", "βα", "δί", "σιας"],
-    "Λιβαδίσιε" => &["Λι", "βα", "δί", "σιε"],
-    "Λιβαδίσιες" => &["Λι", "βα", "δί", "σιες"],
-    "Λιβαδίσιο" => &["Λι", "βα", "δί", "σιο"],
-    "Λιβαδίσιοι" => &["Λι", "βα", "δί", "σιοι"],
-    "Λιβαδίσιος" => &["Λι", "βα", "δί", "σιος"],
-    "Λιβαδίσιου" => &["Λι", "βα", "δί", "σιου"],
-    "Λιβαδίσιους" => &["Λι", "βα", "δί", "σιους"],
-    "Λιβαδίσιων" => &["Λι", "βα", "δί", "σιων"],
-    "Λιβάνια" => &["Λι", "βά", "νια"],
-    "Λιβανιστήρια" => &["Λι", "βα", "νι", "στή", "ρια"],
-    "Λιθαράκια" => &["Λι", "θα", "ρά", "κια"],
-    "Λιθάρια" => &["Λι", "θά", "ρια"],
-    "Λιμανάκια" => &["Λι", "μα", "νά", "κια"],
-    "Λιμάνια" => &["Λι", "μά", "νια"],
-    "Λιμνίσια" => &["Λι", "μνί", "σια"],
-    "Λιμνίσιας" => &["Λι", "μνί", "σιας"],
-    "Λιμνίσιε" => &["Λι", "μνί", "σιε"],
-    "Λιμνίσιες" => &["Λι", "μνί", "σιες"],
-    "Λιμνίσιο" => &["Λι", "μνί", "σιο"],
-    "Λιμνίσιοι" => &["Λι", "μνί", "σιοι"],
-    "Λιμνίσιος" => &["Λι", "μνί", "σιος"],
-    "Λιμνίσιου" => &["Λι", "μνί", "σιου"],
-    "Λιμνίσιους" => &["Λι", "μνί", "σιους"],
-    "Λιμνίσιων" => &["Λι", "μνί", "σιων"],
-    "Λινάρια" => &["Λι", "νά", "ρια"],
-    "Λιοκόκκια" => &["Λι", "ο", "κόκ", "κια"],
-    "Λιονταράκια" => &["Λι", "ο", "ντα", "ρά", "κια"],
-    "Λιοντάρια" => &["Λι", "ο", "ντά", "ρια"],
-    "Λιονταρίσια" => &["Λι", "ο", "ντα", "ρί", "σια"],
-    "Λιονταρίσιας" => &["Λι", "ο", "ντα", "ρί", "σιας"],
-    "Λιονταρίσιε" => &["Λι", "ο", "ντα", "ρί", "σιε"],
-    "Λιονταρίσιες" => &["Λι", "ο", "ντα", "ρί", "σιες"],
-    "Λιονταρίσιο" => &["Λι", "ο", "ντα", "ρί", "σιο"],
-    "Λιονταρίσιοι" => &["Λι", "ο", "ντα", "ρί", "σιοι"],
-    "Λιονταρίσιος" => &["Λι", "ο", "ντα", "ρί", "σιος"],
-    "Λιονταρίσιου" => &["Λι", "ο", "ντα", "ρί", "σιου"],
-    "Λιονταρίσιους" => &["Λι", "ο", "ντα", "ρί", "σιους"],
-    "Λιονταρίσιων" => &["Λι", "ο", "ντα", "ρί", "σιων"],
-    "Λιοπύρια" => &["Λι", "ο", "πύ", "ρια"],
-    "Λιοστάσια" => &["Λι", "ο", "στά", "σια"],
-    "Λιοτρίβια" => &["Λι", "ο", "τρί", "βια"],
-    "Λισγάρια" => &["Λισ", "γά", "ρια"],
-    "Λιχνιστήρια" => &["Λι", "χνι", "στή", "ρια"],
-    "Λογάκια" => &["Λο", "γά", "κια"],
-    "Λογάρια" => &["Λο", "γά", "ρια"],
-    "Λόγια" => &["Λό", "για"],
-    "Λουκανικάκια" => &["Λου", "κα", "νι", "κά", "κια"],
-    "Λούκια" => &["Λού", "κια"],
-    "Λουκουμάκια" => &["Λου", "κου", "μά", "κια"],
-    "Λουκούμια" => &["Λου", "κού", "μια"],
-    "Λουλάκια" => &["Λου", "λά", "κια"],
-    "Λουλουδάκια" => &["Λου", "λου", "δά", "κια"],
-    "Λουλουδένια" => &["Λου", "λου", "δέ", "νια"],
-    "Λουλουδένιας" => &["Λου", "λου", "δέ", "νιας"],
-    "Λουλουδένιε" => &["Λου", "λου", "δέ", "νιε"],
-    "Λουλουδένιες" => &["Λου", "λου", "δέ", "νιες"],
-    "Λουλουδένιο" => &["Λου", "λου", "δέ", "νιο"],
-    "Λουλουδένιοι" => &["Λου", "λου", "δέ", "νιοι"],
-    "Λουλουδένιος" => &["Λου", "λου", "δέ", "νιος"],
-    "Λουλουδένιου" => &["Λου", "λου", "δέ", "νιου"],
-    "Λουλουδένιους" => &["Λου", "λου", "δέ", "νιους"],
-    "Λουλουδένιων" => &["Λου", "λου", "δέ", "νιων"],
-    "Λουλούδια" => &["Λου", "λού", "δια"],
-    "Λουμίνια" => &["Λου", "μί", "νια"],
-    "Λουράκια" => &["Λου", "ρά", "κια"],
-    "Λουστρίνια" => &["Λου", "στρί", "νια"],
-    "Λυθρίνια" => &["Λυ", "θρί", "νια"],
-    "Λυτάρια" => &["Λυ", "τά", "ρια"],
-    "Λυχναράκια" => &["Λυ", "χνα", "ρά", "κια"],
-    "Λυχνάρια" => &["Λυ", "χνά", "ρια"],
-    "Μαγαζάκια" => &["Μα", "γα", "ζά", "κια"],
-    "Μαγδαλένια" => &["Μαγ", "δα", "λέ", "νια"],
-    "Μαγδαλένιας" => &["Μαγ", "δα", "λέ", "νιας"],
-    "Μαγδαλένιε" => &["Μαγ", "δα", "λέ", "νιε"],
-    "Μαγδαλένιες" => &["Μαγ", "δα", "λέ", "νιες"],
-    "Μαγδαλένιο" => &["Μαγ", "δα", "λέ", "νιο"],
-    "Μαγδαλένιοι" => &["Μαγ", "δα", "λέ", "νιοι"],
-    "Μαγδαλένιος" => &["Μαγ", "δα", "λέ", "νιος"],
-    "Μαγδαλένιου" => &["Μαγ", "δα", "λέ", "νιου"],
-    "Μαγδαλένιους" => &["Μαγ", "δα", "λέ", "νιους"],
-    "Μαγδαλένιων" => &["Μαγ", "δα", "λέ", "νιων"],
-    "Μάγια" => &["Μά", "για"],
-    "Μάγιας" => &["Μά", "γιας"],
-    "Μάγιες" => &["Μά", "γιες"],
-    "Μαγκάκια" => &["Μα", "γκά", "κια"],
-    "Μαγκάλια" => &["Μα", "γκά", "λια"],
-    "Μαγκάνια" => &["Μα", "γκά", "νια"],
-    "Μαγνάδια" => &["Μα", "γνά", "δια"],
-    "Μαγνητοφωνάκια" => &["Μα", "γνη", "το", "φω", "νά", "κια"],
-    "Μαγουλάκια" => &["Μα", "γου", "λά", "κια"],
-    "Μαδέρια" => &["Μα", "δέ", "ρια"],
-    "Μαθητούδια" => &["Μα", "θη", "τού", "δια"],
-    "Μαϊμού" => &["Μαϊ", "μού"],
-    "Μαϊμούδες" => &["Μαϊ", "μού", "δες"],
-    "Μαϊμουδίσια" => &["Μαϊ", "μου", "δί", "σια"],
-    "Μαϊμουδίσιας" => &["Μαϊ", "μου", "δί", "σιας"],
-    "Μαϊμουδίσιε" => &["Μαϊ", "μου", "δί", "σιε"],
-    "Μαϊμουδίσιες" => &["Μαϊ", "μου", "δί", "σιες"],
-    "Μαϊμουδίσιο" => &["Μαϊ", "μου", "δί", "σιο"],
-    "Μαϊμουδίσιοι" => &["Μαϊ", "μου", "δί", "σιοι"],
-    "Μαϊμουδίσιος" => &["Μαϊ", "μου", "δί", "σιος"],
-    "Μαϊμουδίσιου" => &["Μαϊ", "μου", "δί", "σιου"],
-    "Μαϊμουδίσιους" => &["Μαϊ", "μου", "δί", "σιους"],
-    "Μαϊμουδίσιων" => &["Μαϊ", "μου", "δί", "σιων"],
-    "Μαϊμούδων" => &["Μαϊ", "μού", "δων"],
-    "Μαϊμούς" => &["Μαϊ", "μούς"],
-    "Μαϊστράλι" => &["Μαϊ", "στρά", "λι"],
-    "Μαϊστράλια" => &["Μαϊ", "στρά", "λια"],
-    "Μακάρια" => &["Μα", "κά", "ρια"],
-    "Μακαρονάκια" => &["Μα", "κα", "ρο", "νά", "κια"],
-    "Μακαρόνια" => &["Μα", "κα", "ρό", "νια"],
-    "Μακεδονήσια" => &["Μα", "κε", "δο", "νή", "σια"],
-    "Μακρινάρια" => &["Μα", "κρι", "νά", "ρια"],
-    "Μακροβούτια" => &["Μα", "κρο", "βού", "τια"],
-    "Μαλαματένια" => &["Μα", "λα", "μα", "τέ", "νια"],
-    "Μαλαματένιας" => &["Μα", "λα", "μα", "τέ", "νιας"],
-    "Μαλαματένιε" => &["Μα", "λα", "μα", "τέ", "νιε"],
-    "Μαλαματένιες" => &["Μα", "λα", "μα", "τέ", "νιες"],
-    "Μαλαματένιο" => &["Μα", "λα", "μα", "τέ", "νιο"],
-    "Μαλαματένιοι" => &["Μα", "λα", "μα", "τέ", "νιοι"],
-    "Μαλαματένιος" => &["Μα", "λα", "μα", "τέ", "νιος"],
-    "Μαλαματένιου" => &["Μα", "λα", "μα", "τέ", "νιου"],
-    "Μαλαματένιους" => &["Μα", "λα", "μα", "τέ", "νιους"],
-    "Μαλαματένιων" => &["Μα", "λα", "μα", "τέ", "νιων"],
-    "Μαλλάκια" => &["Μαλ", "λά", "κια"],
-    "Μαμουδάκια" => &["Μα", "μου", "δά", "κια"],
-    "Μαμούδια" => &["Μα", "μού", "δια"],
-    "Μαμουνάκια" => &["Μα", "μου", "νά", "κια"],
-    "Μαμούνια" => &["Μα", "μού", "νια"],
-    "Μαναράκια" => &["Μα", "να", "ρά", "κια"],
-    "Μανάρια" => &["Μα", "νά", "ρια"],
-    "Μανικέτια" => &["Μα", "νι", "κέ", "τια"],
-    "Μανίκια" => &["Μα", "νί", "κια"],
-    "Μανίσια" => &["Μα", "νί", "σια"],
-    "Μανίσιας" => &["Μα", "νί", "σιας"],
-    "Μανίσιε" => &["Μα", "νί", "σιε"],
-    "Μανίσιες" => &["Μα", "νί", "σιες"],
-    "Μανίσιο" => &["Μα", "νί", "σιο"],
-    "Μανίσιοι" => &["Μα", "νί", "σιοι"],
-    "Μανίσιος" => &["Μα", "νί", "σιος"],
-    "Μανίσιου" => &["Μα", "νί", "σιου"],
-    "Μανίσιους" => &["Μα", "νί", "σιους"],
-    "Μανίσιων" => &["Μα", "νί", "σιων"],
-    "Μανιτάρια" => &["Μα", "νι", "τά", "ρια"],
-    "Μανόλια" => &["Μα", "νό", "λια"],
-    "Μανόλιας" => &["Μα", "νό", "λιας"],
-    "Μανόλιες" => &["Μα", "νό", "λιες"],
-    "Μανουάλια" => &["Μα", "νου", "ά", "λια"],
-    "Μανούλια" => &["Μα", "νού", "λια"],
-    "Μανούρια" => &["Μα", "νού", "ρια"],
-    "Μανταλάκια" => &["Μα", "ντα", "λά", "κια"],
-    "Μανταρινάκια" => &["Μα", "ντα", "ρι", "νά", "κια"],
-    "Μανταρίνια" => &["Μα", "ντα", "ρί", "νια"],
-    "Μαντεμένια" => &["Μα", "ντε", "μέ", "νια"],
-    "Μαντεμένιας" => &["Μα", "ντε", "μέ", "νιας"],
-    "Μαντεμένιε" => &["Μα", "ντε", "μέ", "νιε"],
-    "Μαντεμένιες" => &["Μα", "ντε", "μέ", "νιες"],
-    "Μαντεμένιο" => &["Μα", "ντε", "μέ", "νιο"],
-    "Μαντεμένιοι" => &["Μα", "ντε", "μέ", "νιοι"],
-    "Μαντεμένιος" => &["Μα", "ντε", "μέ", "νιος"],
-    "Μαντεμένιου" => &["Μα", "ντε", "μέ", "νιου"],
-    "Μαντεμένιους" => &["Μα", "ντε", "μέ", "νιους"],
-    "Μαντεμένιων" => &["Μα", "ντε", "μέ", "νιων"],
-    "Μαντέμια" => &["Μα", "ντέ", "μια"],
-    "Μαντζούνια" => &["Μα", "ντζού", "νια"],
-    "Μαντηλάκια" => &["Μα", "ντη", "λά", "κια"],
-    "Μαντήλια" => &["Μα", "ντή", "λια"],
-    "Μαντιλάκια" => &["Μα", "ντι", "λά", "κια"],
-    "Μαντίλια" => &["Μα", "ντί", "λια"],
-    "Μαντίλιας" => &["Μα", "ντί", "λιας"],
-    "Μαντίλιες" => &["Μα", "ντί", "λιες"],
-    "Μαξιλαράκια" => &["Μα", "ξι", "λα", "ρά", "κια"],
-    "Μαξιλάρια" => &["Μα", "ξι", "λά", "ρια"],
-    "Μαξούλια" => &["Μα", "ξού", "λια"],
-    "Μαόνια" => &["Μα", "ό", "νια"],
-    "Μαράζια" => &["Μα", "ρά", "ζια"],
-    "Μαραφέτια" => &["Μα", "ρα", "φέ", "τια"],
-    "Μαργαριταράκια" => &["Μαρ", "γα", "ρι", "τα", "ρά", "κια"],
-    "Μαργαριταρένια" => &["Μαρ", "γα", "ρι", "τα", "ρέ", "νια"],
-    "Μαργαριταρένιας" => &["Μαρ", "γα", "ρι", "τα", "ρέ", "νιας"],
-    "Μαργαριταρένιε" => &["Μαρ", "γα", "ρι", "τα", "ρέ", "νιε"],
-    "Μαργαριταρένιες" => &["Μαρ", "γα", "ρι", "τα", "ρέ", "νιες"],
-    "Μαργαριταρένιο" => &["Μαρ", "γα", "ρι", "τα", "ρέ", "νιο"],
-    "Μαργαριταρένιοι" => &["Μαρ", "γα", "ρι", "τα", "ρέ", "νιοι"],
-    "Μαργαριταρένιος" => &["Μαρ", "γα", "ρι", "τα", "ρέ", "νιος"],
-    "Μαργαριταρένιου" => &["Μαρ", "γα", "ρι", "τα", "ρέ", "νιου"],
-    "Μαργαριταρένιους" => &["Μαρ", "γα", "ρι", "τα", "ρέ", "νιους"],
-    "Μαργαριταρένιων" => &["Μαρ", "γα", "ρι", "τα", "ρέ", "νιων"],
-    "Μαργαριτάρια" => &["Μαρ", "γα", "ρι", "τά", "ρια"],
-    "Μαργέλια" => &["Μαρ", "γέ", "λια"],
-    "Μαριδάκια" => &["Μα", "ρι", "δά", "κια"],
-    "Μαρκούτσια" => &["Μαρ", "κού", "τσια"],
-    "Μαρμαράκια" => &["Μαρ", "μα", "ρά", "κια"],
-    "Μαρμαρένια" => &["Μαρ", "μα", "ρέ", "νια"],
-    "Μαρμαρένιας" => &["Μαρ", "μα", "ρέ", "νιας"],
-    "Μαρμαρένιε" => &["Μαρ", "μα", "ρέ", "νιε"],
-    "Μαρμαρένιες" => &["Μαρ", "μα", "ρέ", "νιες"],
-    "Μαρμαρένιο" => &["Μαρ", "μα", "ρέ", "νιο"],
-    "Μαρμαρένιοι" => &["Μαρ", "μα", "ρέ", "νιοι"],
-    "Μαρμαρένιος" => &["Μαρ", "μα", "ρέ", "νιος"],
-    "Μαρμαρένιου" => &["Μαρ", "μα", "ρέ", "νιου"],
-    "Μαρμαρένιους" => &["Μαρ", "μα", "ρέ", "νιους"],
-    "Μαρμαρένιων" => &["Μαρ", "μα", "ρέ", "νιων"],
-    "Μαρόνια" => &["Μα", "ρό", "νια"],
-    "Μαρουλάκια" => &["Μα", "ρου", "λά", "κια"],
-    "Μαρούλια" => &["Μα", "ρού", "λια"],
-    "Μαρτίνια" => &["Μαρ", "τί", "νια"],
-    "Μαρτυρίκια" => &["Μαρ", "τυ", "ρί", "κια"],
-    "Μασκαραλίκια" => &["Μα", "σκα", "ρα", "λί", "κια"],
-    "Μασούρια" => &["Μα", "σού", "ρια"],
-    "Μαστάρια" => &["Μα", "στά", "ρια"],
-    "Ματάκια" => &["Μα", "τά", "κια"],
-    "Ματάκιας" => &["Μα", "τά", "κιας"],
-    "Ματάκιες" => &["Μα", "τά", "κιες"],
-    "Μάτια" => &["Μά", "τια"],
-    "Ματογυάλια" => &["Μα", "το", "γυ", "ά", "λια"],
-    "Ματοκλάδια" => &["Μα", "το", "κλά", "δια"],
-    "Ματσάκια" => &["Μα", "τσά", "κια"],
-    "Ματσούκια" => &["Μα", "τσού", "κια"],
-    "Μαυραγάνια" => &["Μαυ", "ρα", "γά", "νια"],
-    "Μαυράδια" => &["Μαυ", "ρά", "δια"],
-    "Μαυράκια" => &["Μαυ", "ρά", "κια"],
-    "Μαυροπούλια" => &["Μαυ", "ρο", "πού", "λια"],
-    "Μαχαιράκια" => &["Μα", "χαι", "ρά", "κια"],
-    "Μαχαίρια" => &["Μα", "χαί", "ρια"],
-    "Μαχμουρλίκια" => &["Μαχ", "μουρ", "λί", "κια"],
-    "Μεδούλια" => &["Με", "δού", "λια"],
-    "Μεζεδάκια" => &["Με", "ζε", "δά", "κια"],
-    "Μεζελίκια" => &["Με", "ζε", "λί", "κια"],
-    "Μεθοκόπια" => &["Με", "θο", "κό", "πια"],
-    "Μεθύσια" => &["Με", "θύ", "σια"],
-    "Μεϊντάνια" => &["Με", "ϊ", "ντά", "νια"],
-    "Μελάνια" => &["Με", "λά", "νια"],
-    "Μελανούρια" => &["Με", "λα", "νού", "ρια"],
-    "Μελένια" => &["Με", "λέ", "νια"],
-    "Μελένιας" => &["Με", "λέ", "νιας"],
-    "Μελένιε" => &["Με", "λέ", "νιε"],
-    "Μελένιες" => &["Με", "λέ", "νιες"],
-    "Μελένιο" => &["Με", "λέ", "νιο"],
-    "Μελένιοι" => &["Με", "λέ", "νιοι"],
-    "Μελένιος" => &["Με", "λέ", "νιος"],
-    "Μελένιου" => &["Με", "λέ", "νιου"],
-    "Μελένιους" => &["Με", "λέ", "νιους"],
-    "Μελένιων" => &["Με", "λέ", "νιων"],
-    "Μέλια" => &["Μέ", "λια"],
-    "Μελίγγια" => &["Με", "λίγ", "για"],
-    "Μελίσσια" => &["Με", "λίσ", "σια"],
-    "Μελισσολόια" => &["Με", "λισ", "σο", "λό", "ια"],
-    "Μελιτζανάκια" => &["Με", "λι", "τζα", "νά", "κια"],
-    "Μελτεμάκια" => &["Μελ", "τε", "μά", "κια"],
-    "Μελτέμια" => &["Μελ", "τέ", "μια"],
-    "Μενεξεδένια" => &["Με", "νε", "ξε", "δέ", "νια"],
-    "Μενεξεδένιας" => &["Με", "νε", "ξε", "δέ", "νιας"],
-    "Μενεξεδένιε" => &["Με", "νε", "ξε", "δέ", "νιε"],
-    "Μενεξεδένιες" => &["Με", "νε", "ξε", "δέ", "νιες"],
-    "Μενεξεδένιο" => &["Με", "νε", "ξε", "δέ", "νιο"],
-    "Μενεξεδένιοι" => &["Με", "νε", "ξε", "δέ", "νιοι"],
-    "Μενεξεδένιος" => &["Με", "νε", "ξε", "δέ", "νιος"],
-    "Μενεξεδένιου" => &["Με", "νε", "ξε", "δέ", "νιου"],
-    "Μενεξεδένιους" => &["Με", "νε", "ξε", "δέ", "νιους"],
-    "Μενεξεδένιων" => &["Με", "νε", "ξε", "δέ", "νιων"],
-    "Μεντέρια" => &["Με", "ντέ", "ρια"],
-    "Μεράδια" => &["Με", "ρά", "δια"],
-    "Μεράκια" => &["Με", "ρά", "κια"],
-    "Μερεμέτια" => &["Με", "ρε", "μέ", "τια"],
-    "Μερμηγκάκια" => &["Μερ", "μη", "γκά", "κια"],
-    "Μερμήγκια" => &["Μερ", "μή", "γκια"],
-    "Μεροδούλια" => &["Με", "ρο", "δού", "λια"],
-    "Μεροφάγια" => &["Με", "ρο", "φά", "για"],
-    "Μεσάλια" => &["Με", "σά", "λια"],
-    "Μεσαύλια" => &["Με", "σαύ", "λια"],
-    "Μεσημεράκια" => &["Με", "ση", "με", "ρά", "κια"],
-    "Μεσημέρια" => &["Με", "ση", "μέ", "ρια"],
-    "Μεσοδόκια" => &["Με", "σο", "δό", "κια"],
-    "Μεσονύχτια" => &["Με", "σο", "νύ", "χτια"],
-    "Μεσοφόρια" => &["Με", "σο", "φό", "ρια"],
-    "Μεσοχώρια" => &["Με", "σο", "χώ", "ρια"],
-    "Μεταλίκια" => &["Με", "τα", "λί", "κια"],
-    "Μεταλλένια" => &["Με", "ταλ", "λέ", "νια"],
-    "Μεταλλένιας" => &["Με", "ταλ", "λέ", "νιας"],
-    "Μεταλλένιε" => &["Με", "ταλ", "λέ", "νιε"],
-    "Μεταλλένιες" => &["Με", "ταλ", "λέ", "νιες"],
-    "Μεταλλένιο" => &["Με", "ταλ", "λέ", "νιο"],
-    "Μεταλλένιοι" => &["Με", "ταλ", "λέ", "νιοι"],
-    "Μεταλλένιος" => &["Με", "ταλ", "λέ", "νιος"],
-    "Μεταλλένιου" => &["Με", "ταλ", "λέ", "νιου"],
-    "Μεταλλένιους" => &["Με", "ταλ", "λέ", "νιους"],
-    "Μεταλλένιων" => &["Με", "ταλ", "λέ", "νιων"],
-    "Μετάνοια" => &["Με", "τά", "νοια"],
-    "Μετάνοιας" => &["Με", "τά", "νοιας"],
-    "Μετάνοιες" => &["Με", "τά", "νοιες"],
-    "Μεταξένια" => &["Με", "τα", "ξέ", "νια"],
-    "Μεταξένιας" => &["Με", "τα", "ξέ", "νιας"],
-    "Μεταξένιε" => &["Με", "τα", "ξέ", "νιε"],
-    "Μεταξένιες" => &["Με", "τα", "ξέ", "νιες"],
-    "Μεταξένιο" => &["Με", "τα", "ξέ", "νιο"],
-    "Μεταξένιοι" => &["Με", "τα", "ξέ", "νιοι"],
-    "Μεταξένιος" => &["Με", "τα", "ξέ", "νιος"],
-    "Μεταξένιου" => &["Με", "τα", "ξέ", "νιου"],
-    "Μεταξένιους" => &["Με", "τα", "ξέ", "νιους"],
-    "Μεταξένιων" => &["Με", "τα", "ξέ", "νιων"],
-    "Μετάξια" => &["Με", "τά", "ξια"],
-    "Μετερίζια" => &["Με", "τε", "ρί", "ζια"],
-    "Μετζίτια" => &["Με", "τζί", "τια"],
-    "Μετόχια" => &["Με", "τό", "χια"],
-    "Μηλαδέλφια" => &["Μη", "λα", "δέλ", "φια"],
-    "Μηλαδέρφια" => &["Μη", "λα", "δέρ", "φια"],
-    "Μηλίγγια" => &["Μη", "λίγ", "για"],
-    "Μηνίγγια" => &["Μη", "νίγ", "για"],
-    "Μηχανάκια" => &["Μη", "χα", "νά", "κια"],
-    "Μηχανάκιας" => &["Μη", "χα", "νά", "κιας"],
-    "Μηχανάκιες" => &["Μη", "χα", "νά", "κιες"],
-    "Μηχανηματάκια" => &["Μη", "χα", "νη", "μα", "τά", "κια"],
-    "Μια" => &["Μια"],
-    "Μιαν" => &["Μιαν"],
-    "Μιας" => &["Μιας"],
-    "Μιζέρια" => &["Μι", "ζέ", "ρια"],
-    "Μιζέριας" => &["Μι", "ζέ", "ριας"],
-    "Μιζέριες" => &["Μι", "ζέ", "ριες"],
-    "Μικρουλάκια" => &["Μι", "κρου", "λά", "κια"],
-    "Μικρούλια" => &["Μι", "κρού", "λια"],
-    "Μίλια" => &["Μί", "λια"],
-    "Μιλιούνια" => &["Μι", "λι", "ού", "νια"],
-    "Μίνια" => &["Μί", "νια"],
-    "Μιντέρια" => &["Μι", "ντέ", "ρια"],
-    "Μιξεράκια" => &["Μι", "ξε", "ρά", "κια"],
-    "Μισοφόρια" => &["Μι", "σο", "φό", "ρια"],
-    "Μιτάρια" => &["Μι", "τά", "ρια"],
-    "Μνημονιάκια" => &["Μνη", "μο", "νι", "ά", "κια"],
-    "Μνημονιάκιας" => &["Μνη", "μο", "νι", "ά", "κιας"],
-    "Μνημονιάκιες" => &["Μνη", "μο", "νι", "ά", "κιες"],
-    "Μνημούρια" => &["Μνη", "μού", "ρια"],
-    "Μόδια" => &["Μό", "δια"],
-    "Μοιράδια" => &["Μοι", "ρά", "δια"],
-    "Μοιρολόγια" => &["Μοι", "ρο", "λό", "για"],
-    "Μοιρολόια" => &["Μοι", "ρο", "λό", "ια"],
-    "Μολυβάκια" => &["Μο", "λυ", "βά", "κια"],
-    "Μολυβένια" => &["Μο", "λυ", "βέ", "νια"],
-    "Μολυβένιας" => &["Μο", "λυ", "βέ", "νιας"],
-    "Μολυβένιε" => &["Μο", "λυ", "βέ", "νιε"],
-    "Μολυβένιες" => &["Μο", "λυ", "βέ", "νιες"],
-    "Μολυβένιο" => &["Μο", "λυ", "βέ", "νιο"],
-    "Μολυβένιοι" => &["Μο", "λυ", "βέ", "νιοι"],
-    "Μολυβένιος" => &["Μο", "λυ", "βέ", "νιος"],
-    "Μολυβένιου" => &["Μο", "λυ", "βέ", "νιου"],
-    "Μολυβένιους" => &["Μο", "λυ", "βέ", "νιους"],
-    "Μολυβένιων" => &["Μο", "λυ", "βέ", "νιων"],
-    "Μολύβια" => &["Μο", "λύ", "βια"],
-    "Μολυντήρια" => &["Μο", "λυ", "ντή", "ρια"],
-    "Μοναστηράκια" => &["Μο", "να", "στη", "ρά", "κια"],
-    "Μοναστήρια" => &["Μο", "να", "στή", "ρια"],
-    "Μοναστηρίσια" => &["Μο", "να", "στη", "ρί", "σια"],
-    "Μοναστηρίσιας" => &["Μο", "να", "στη", "ρί", "σιας"],
-    "Μοναστηρίσιε" => &["Μο", "να", "στη", "ρί", "σιε"],
-    "Μοναστηρίσιες" => &["Μο", "να", "στη", "ρί", "σιες"],
-    "Μοναστηρίσιο" => &["Μο", "να", "στη", "ρί", "σιο"],
-    "Μοναστηρίσιοι" => &["Μο", "να", "στη", "ρί", "σιοι"],
-    "Μοναστηρίσιος" => &["Μο", "να", "στη", "ρί", "σιος"],
-    "Μοναστηρίσιου" => &["Μο", "να", "στη", "ρί", "σιου"],
-    "Μοναστηρίσιους" => &["Μο", "να", "στη", "ρί", "σιους"],
-    "Μοναστηρίσιων" => &["Μο", "να", "στη", "ρί", "σιων"],
-    "Μοναχοπαίδια" => &["Μο", "να", "χο", "παί", "δια"],
-    "Μονοπατάκια" => &["Μο", "νο", "πα", "τά", "κια"],
-    "Μονοπάτια" => &["Μο", "νο", "πά", "τια"],
-    "Μοντελάκια" => &["Μο", "ντε", "λά", "κια"],
-    "Μορτάκια" => &["Μορ", "τά", "κια"],
-    "Μοσκαρίσια" => &["Μο", "σκα", "ρί", "σια"],
-    "Μοσκαρίσιας" => &["Μο", "σκα", "ρί", "σιας"],
-    "Μοσκαρίσιε" => &["Μο", "σκα", "ρί", "σιε"],
-    "Μοσκαρίσιες" => &["Μο", "σκα", "ρί", "σιες"],
-    "Μοσκαρίσιο" => &["Μο", "σκα", "ρί", "σιο"],
-    "Μοσκαρίσιοι" => &["Μο", "σκα", "ρί", "σιοι"],
-    "Μοσκαρίσιος" => &["Μο", "σκα", "ρί", "σιος"],
-    "Μοσκαρίσιου" => &["Μο", "σκα", "ρί", "σιου"],
-    "Μοσκαρίσιους" => &["Μο", "σκα", "ρί", "σιους"],
-    "Μοσκαρίσιων" => &["Μο", "σκα", "ρί", "σιων"],
-    "Μοσκοκάρφια" => &["Μο", "σκο", "κάρ", "φια"],
-    "Μοσχαράκια" => &["Μο", "σχα", "ρά", "κια"],
-    "Μοσχάρια" => &["Μο", "σχά", "ρια"],
-    "Μοσχαρίσια" => &["Μο", "σχα", "ρί", "σια"],
-    "Μοσχαρίσιας" => &["Μο", "σχα", "ρί", "σιας"],
-    "Μοσχαρίσιε" => &["Μο", "σχα", "ρί", "σιε"],
-    "Μοσχαρίσιες" => &["Μο", "σχα", "ρί", "σιες"],
-    "Μοσχαρίσιο" => &["Μο", "σχα", "ρί", "σιο"],
-    "Μοσχαρίσιοι" => &["Μο", "σχα", "ρί", "σιοι"],
-    "Μοσχαρίσιος" => &["Μο", "σχα", "ρί", "σιος"],
-    "Μοσχαρίσιου" => &["Μο", "σχα", "ρί", "σιου"],
-    "Μοσχαρίσιους" => &["Μο", "σχα", "ρί", "σιους"],
-    "Μοσχαρίσιων" => &["Μο", "σχα", "ρί", "σιων"],
-    "Μοσχοκάρφια" => &["Μο", "σχο", "κάρ", "φια"],
-    "Μουλαράκια" => &["Μου", "λα", "ρά", "κια"],
-    "Μουλάρια" => &["Μου", "λά", "ρια"],
-    "Μουλαρίσια" => &["Μου", "λα", "ρί", "σια"],
-    "Μουλαρίσιας" => &["Μου", "λα", "ρί", "σιας"],
-    "Μουλαρίσιε" => &["Μου", "λα", "ρί", "σιε"],
-    "Μουλαρίσιες" => &["Μου", "λα", "ρί", "σιες"],
-    "Μουλαρίσιο" => &["Μου", "λα", "ρί", "σιο"],
-    "Μουλαρίσιοι" => &["Μου", "λα", "ρί", "σιοι"],
-    "Μουλαρίσιος" => &["Μου", "λα", "ρί", "σιος"],
-    "Μουλαρίσιου" => &["Μου", "λα", "ρί", "σιου"],
-    "Μουλαρίσιους" => &["Μου", "λα", "ρί", "σιους"],
-    "Μουλαρίσιων" => &["Μου", "λα", "ρί", "σιων"],
-    "Μούλκια" => &["Μούλ", "κια"],
-    "Μούμια" => &["Μού", "μια"],
-    "Μούμιας" => &["Μού", "μιας"],
-    "Μούμιες" => &["Μού", "μιες"],
-    "Μουνάκια" => &["Μου", "νά", "κια"],
-    "Μουνάκιας" => &["Μου", "νά", "κιας"],
-    "Μουνάκιες" => &["Μου", "νά", "κιες"],
-    "Μουράγια" => &["Μου", "ρά", "για"],
-    "Μουράγιο" => &["Μου", "ρά", "γιο"],
-    "Μουράγιου" => &["Μου", "ρά", "γιου"],
-    "Μουράγιων" => &["Μου", "ρά", "γιων"],
-    "Μούρλια" => &["Μούρ", "λια"],
-    "Μούρλιας" => &["Μούρ", "λιας"],
-    "Μούρλιες" => &["Μούρ", "λιες"],
-    "Μουσάκια" => &["Μου", "σά", "κια"],
-    "Μουσαμαδένια" => &["Μου", "σα", "μα", "δέ", "νια"],
-    "Μουσαμαδένιας" => &["Μου", "σα", "μα", "δέ", "νιας"],
-    "Μουσαμαδένιε" => &["Μου", "σα", "μα", "δέ", "νιε"],
-    "Μουσαμαδένιες" => &["Μου", "σα", "μα", "δέ", "νιες"],
-    "Μουσαμαδένιο" => &["Μου", "σα", "μα", "δέ", "νιο"],
-    "Μουσαμαδένιοι" => &["Μου", "σα", "μα", "δέ", "νιοι"],
-    "Μουσαμαδένιος" => &["Μου", "σα", "μα", "δέ", "νιος"],
-    "Μουσαμαδένιου" => &["Μου", "σα", "μα", "δέ", "νιου"],
-    "Μουσαμαδένιους" => &["Μου", "σα", "μα", "δέ", "νιους"],
-    "Μουσαμαδένιων" => &["Μου", "σα", "μα", "δέ", "νιων"],
-    "Μουσαντένια" => &["Μου", "σα", "ντέ", "νια"],
-    "Μουσαντένιας" => &["Μου", "σα", "ντέ", "νιας"],
-    "Μουσαντένιε" => &["Μου", "σα", "ντέ", "νιε"],
-    "Μουσαντένιες" => &["Μου", "σα", "ντέ", "νιες"],
-    "Μουσαντένιο" => &["Μου", "σα", "ντέ", "νιο"],
-    "Μουσαντένιοι" => &["Μου", "σα", "ντέ", "νιοι"],
-    "Μουσαντένιος" => &["Μου", "σα", "ντέ", "νιος"],
-    "Μουσαντένιου" => &["Μου", "σα", "ντέ", "νιου"],
-    "Μουσαντένιους" => &["Μου", "σα", "ντέ", "νιους"],
-    "Μουσαντένιων" => &["Μου", "σα", "ντέ", "νιων"],
-    "Μουσαφιρλίκια" => &["Μου", "σα", "φιρ", "λί", "κια"],
-    "Μούσια" => &["Μού", "σια"],
-    "Μούσκια" => &["Μού", "σκια"],
-    "Μουσκίδια" => &["Μου", "σκί", "δια"],
-    "Μούσκιο" => &["Μού", "σκιο"],
-    "Μούσκιου" => &["Μού", "σκιου"],
-    "Μούσκιων" => &["Μού", "σκιων"],
-    "Μούσκλια" => &["Μού", "σκλια"],
-    "Μουσουδάκια" => &["Μου", "σου", "δά", "κια"],
-    "Μουσούδια" => &["Μου", "σού", "δια"],
-    "Μουστακάκια" => &["Μου", "στα", "κά", "κια"],
-    "Μουστάκια" => &["Μου", "στά", "κια"],
-    "Μουστάκιας" => &["Μου", "στά", "κιας"],
-    "Μουστάκιες" => &["Μου", "στά", "κιες"],
-    "Μουτράκια" => &["Μου", "τρά", "κια"],
-    "Μπαγκάκια" => &["Μπα", "γκά", "κια"],
-    "Μπαϊράκια" => &["Μπα", "ϊ", "ρά", "κια"],
-    "Μπαϊράμια" => &["Μπα", "ϊ", "ρά", "μια"],
-    "Μπακαλιαράκια" => &["Μπα", "κα", "λι", "α", "ρά", "κια"],
-    "Μπακιρένια" => &["Μπα", "κι", "ρέ", "νια"],
-    "Μπακιρένιας" => &["Μπα", "κι", "ρέ", "νιας"],
-    "Μπακιρένιε" => &["Μπα", "κι", "ρέ", "νιε"],
-    "Μπακιρένιες" => &["Μπα", "κι", "ρέ", "νιες"],
-    "Μπακιρένιο" => &["Μπα", "κι", "ρέ", "νιο"],
-    "Μπακιρένιοι" => &["Μπα", "κι", "ρέ", "νιοι"],
-    "Μπακιρένιος" => &["Μπα", "κι", "ρέ", "νιος"],
-    "Μπακιρένιου" => &["Μπα", "κι", "ρέ", "νιου"],
-    "Μπακιρένιους" => &["Μπα", "κι", "ρέ", "νιους"],
-    "Μπακιρένιων" => &["Μπα", "κι", "ρέ", "νιων"],
-    "Μπακίρια" => &["Μπα", "κί", "ρια"],
-    "Μπαλάκια" => &["Μπα", "λά", "κια"],
-    "Μπαλαμούτια" => &["Μπα", "λα", "μού", "τια"],
-    "Μπαλκονάκια" => &["Μπαλ", "κο", "νά", "κια"],
-    "Μπαλκόνια" => &["Μπαλ", "κό", "νια"],
-    "Μπαλονάκια" => &["Μπα", "λο", "νά", "κια"],
-    "Μπαλόνια" => &["Μπα", "λό", "νια"],
-    "Μπάμια" => &["Μπά", "μια"],
-    "Μπάμιας" => &["Μπά", "μιας"],
-    "Μπάμιες" => &["Μπά", "μιες"],
-    "Μπαμπακάκια" => &["Μπα", "μπα", "κά", "κια"],
-    "Μπαμπακένια" => &["Μπα", "μπα", "κέ", "νια"],
-    "Μπαμπακένιας" => &["Μπα", "μπα", "κέ", "νιας"],
-    "Μπαμπακένιε" => &["Μπα", "μπα", "κέ", "νιε"],
-    "Μπαμπακένιες" => &["Μπα", "μπα", "κέ", "νιες"],
-    "Μπαμπακένιο" => &["Μπα", "μπα", "κέ", "νιο"],
-    "Μπαμπακένιοι" => &["Μπα", "μπα", "κέ", "νιοι"],
-    "Μπαμπακένιος" => &["Μπα", "μπα", "κέ", "νιος"],
-    "Μπαμπακένιου" => &["Μπα", "μπα", "κέ", "νιου"],
-    "Μπαμπακένιους" => &["Μπα", "μπα", "κέ", "νιους"],
-    "Μπαμπακένιων" => &["Μπα", "μπα", "κέ", "νιων"],
-    "Μπαμπάκια" => &["Μπα", "μπά", "κια"],
-    "Μπάνια" => &["Μπά", "νια"],
-    "Μπάνιο" => &["Μπά", "νιο"],
-    "Μπάνιου" => &["Μπά", "νιου"],
-    "Μπανιστήρια" => &["Μπα", "νι", "στή", "ρια"],
-    "Μπάνιων" => &["Μπά", "νιων"],
-    "Μπαξίσια" => &["Μπα", "ξί", "σια"],
-    "Μπαράκια" => &["Μπα", "ρά", "κια"],
-    "Μπαράκιας" => &["Μπα", "ρά", "κιας"],
-    "Μπαράκιες" => &["Μπα", "ρά", "κιες"],
-    "Μπαρμπουνάκια" => &["Μπαρ", "μπου", "νά", "κια"],
-    "Μπαρμπούνια" => &["Μπαρ", "μπού", "νια"],
-    "Μπαρμπούτια" => &["Μπαρ", "μπού", "τια"],
-    "Μπαρούτια" => &["Μπα", "ρού", "τια"],
-    "Μπαστουνάκια" => &["Μπα", "στου", "νά", "κια"],
-    "Μπαστούνια" => &["Μπα", "στού", "νια"],
-    "Μπατζάκια" => &["Μπα", "τζά", "κια"],
-    "Μπαχαλάκια" => &["Μπα", "χα", "λά", "κια"],
-    "Μπαχαλάκιας" => &["Μπα", "χα", "λά", "κιας"],
-    "Μπαχαλάκιες" => &["Μπα", "χα", "λά", "κιες"],
-    "Μπεγλέρια" => &["Μπε", "γλέ", "ρια"],
-    "Μπεζεστένια" => &["Μπε", "ζε", "στέ", "νια"],
-    "Μπεηλίκια" => &["Μπε", "η", "λί", "κια"],
-    "Μπεκατσίνια" => &["Μπε", "κα", "τσί", "νια"],
-    "Μπεκατσόνια" => &["Μπε", "κα", "τσό", "νια"],
-    "Μπεκριλίκια" => &["Μπε", "κρι", "λί", "κια"],
-    "Μπεκρολόγια" => &["Μπε", "κρο", "λό", "για"],
-    "Μπεντένια" => &["Μπε", "ντέ", "νια"],
-    "Μπερεκέτια" => &["Μπε", "ρε", "κέ", "τια"],
-    "Μπερκέτια" => &["Μπερ", "κέ", "τια"],
-    "Μπερντάκια" => &["Μπερ", "ντά", "κια"],
-    "Μπερντάχια" => &["Μπερ", "ντά", "χια"],
-    "Μπετονένια" => &["Μπε", "το", "νέ", "νια"],
-    "Μπετονένιας" => &["Μπε", "το", "νέ", "νιας"],
-    "Μπετονένιε" => &["Μπε", "το", "νέ", "νιε"],
-    "Μπετονένιες" => &["Μπε", "το", "νέ", "νιες"],
-    "Μπετονένιο" => &["Μπε", "το", "νέ", "νιο"],
-    "Μπετονένιοι" => &["Μπε", "το", "νέ", "νιοι"],
-    "Μπετονένιος" => &["Μπε", "το", "νέ", "νιος"],
-    "Μπετονένιου" => &["Μπε", "το", "νέ", "νιου"],
-    "Μπετονένιους" => &["Μπε", "το", "νέ", "νιους"],
-    "Μπετονένιων" => &["Μπε", "το", "νέ", "νιων"],
-    "Μπετούγια" => &["Μπε", "τού", "για"],
-    "Μπετούγιας" => &["Μπε", "τού", "γιας"],
-    "Μπετούγιες" => &["Μπε", "τού", "γιες"],
-    "Μπιγόνια" => &["Μπι", "γό", "νια"],
-    "Μπιγόνιας" => &["Μπι", "γό", "νιας"],
-    "Μπιγόνιες" => &["Μπι", "γό", "νιες"],
-    "Μπιζέλια" => &["Μπι", "ζέ", "λια"],
-    "Μπικινάκια" => &["Μπι", "κι", "νά", "κια"],
-    "Μπικίνια" => &["Μπι", "κί", "νια"],
-    "Μπίλια" => &["Μπί", "λια"],
-    "Μπίλιας" => &["Μπί", "λιας"],
-    "Μπίλιες" => &["Μπί", "λιες"],
-    "Μπιλιετάκια" => &["Μπι", "λι", "ε", "τά", "κια"],
-    "Μπιμπίκια" => &["Μπι", "μπί", "κια"],
-    "Μπιμπλουδάκια" => &["Μπι", "μπλου", "δά", "κια"],
-    "Μπινελίκια" => &["Μπι", "νε", "λί", "κια"],
-    "Μπιρμπίλια" => &["Μπιρ", "μπί", "λια"],
-    "Μπισκοτάκια" => &["Μπι", "σκο", "τά", "κια"],
-    "Μπιστολάκια" => &["Μπι", "στο", "λά", "κια"],
-    "Μπιστόλια" => &["Μπι", "στό", "λια"],
-    "Μπιτόνια" => &["Μπι", "τό", "νια"],
-    "Μπιφτεκάκια" => &["Μπι", "φτε", "κά", "κια"],
-    "Μπιφτέκια" => &["Μπι", "φτέ", "κια"],
-    "Μπιχλιμπιδάκια" => &["Μπι", "χλι", "μπι", "δά", "κια"],
-    "Μπιχλιμπίδια" => &["Μπι", "χλι", "μπί", "δια"],
-    "Μπλάστρια" => &["Μπλά", "στρια"],
-    "Μπλια" => &["Μπλια"],
-    "Μπλιο" => &["Μπλιο"],
-    "Μπλοκάκια" => &["Μπλο", "κά", "κια"],
-    "Μπλουζάκια" => &["Μπλου", "ζά", "κια"],
-    "Μπογαλάκια" => &["Μπο", "γα", "λά", "κια"],
-    "Μπόγια" => &["Μπό", "για"],
-    "Μπολάκια" => &["Μπο", "λά", "κια"],
-    "Μπόλια" => &["Μπό", "λια"],
-    "Μπόλιας" => &["Μπό", "λιας"],
-    "Μπόλιες" => &["Μπό", "λιες"],
-    "Μπομπόνια" => &["Μπο", "μπό", "νια"],
-    "Μποστάνια" => &["Μπο", "στά", "νια"],
-    "Μπότζια" => &["Μπό", "τζια"],
-    "Μποτίλια" => &["Μπο", "τί", "λια"],
-    "Μποτίλιας" => &["Μπο", "τί", "λιας"],
-    "Μποτίλιες" => &["Μπο", "τί", "λιες"],
-    "Μποτίνια" => &["Μπο", "τί", "νια"],
-    "Μπουγάζια" => &["Μπου", "γά", "ζια"],
-    "Μπουγαρίνια" => &["Μπου", "γα", "ρί", "νια"],
-    "Μπούγια" => &["Μπού", "για"],
-    "Μπούγιο" => &["Μπού", "γιο"],
-    "Μπούγιου" => &["Μπού", "γιου"],
-    "Μπούγιων" => &["Μπού", "γιων"],
-    "Μπουζουκάκια" => &["Μπου", "ζου", "κά", "κια"],
-    "Μπουζούκια" => &["Μπου", "ζού", "κια"],
-    "Μπουκαλάκια" => &["Μπου", "κα", "λά", "κια"],
-    "Μπουκάλια" => &["Μπου", "κά", "λια"],
-    "Μπουκαμβίλια" => &["Μπου", "καμ", "βί", "λια"],
-    "Μπουκαμβίλιας" => &["Μπου", "καμ", "βί", "λιας"],
-    "Μπουκαμβίλιες" => &["Μπου", "καμ", "βί", "λιες"],
-    "Μπουκετάκια" => &["Μπου", "κε", "τά", "κια"],
-    "Μπουκλάκια" => &["Μπου", "κλά", "κια"],
-    "Μπουλόνια" => &["Μπου", "λό", "νια"],
-    "Μπουλούκια" => &["Μπου", "λού", "κια"],
-    "Μπουμπάρια" => &["Μπου", "μπά", "ρια"],
-    "Μπουμπουκάκια" => &["Μπου", "μπου", "κά", "κια"],
-    "Μπουμπούκια" => &["Μπου", "μπού", "κια"],
-    "Μπουντρούμια" => &["Μπου", "ντρού", "μια"],
-    "Μπουρεκάκια" => &["Μπου", "ρε", "κά", "κια"],
-    "Μπουρέκια" => &["Μπου", "ρέ", "κια"],
-    "Μπουρίνια" => &["Μπου", "ρί", "νια"],
-    "Μπουρνούζια" => &["Μπουρ", "νού", "ζια"],
-    "Μπούρτζια" => &["Μπούρ", "τζια"],
-    "Μπουτάκια" => &["Μπου", "τά", "κια"],
-    "Μπούτια" => &["Μπού", "τια"],
-    "Μπριάμια" => &["Μπρι", "ά", "μια"],
-    "Μπριγιάντια" => &["Μπρι", "γι", "ά", "ντια"],
-    "Μπριζολάκια" => &["Μπρι", "ζο", "λά", "κια"],
-    "Μπρικάκια" => &["Μπρι", "κά", "κια"],
-    "Μπρίκια" => &["Μπρί", "κια"],
-    "Μπριλάντια" => &["Μπρι", "λά", "ντια"],
-    "Μπρισίμια" => &["Μπρι", "σί", "μια"],
-    "Μυγάκια" => &["Μυ", "γά", "κια"],
-    "Μύδια" => &["Μύ", "δια"],
-    "Μυρμηγκάκια" => &["Μυρ", "μη", "γκά", "κια"],
-    "Μυρμήγκια" => &["Μυρ", "μή", "γκια"],
-    "Μυτάκια" => &["Μυ", "τά", "κια"],
-    "Μωράκια" => &["Μω", "ρά", "κια"],
-    "Μωρουδελάκια" => &["Μω", "ρου", "δε", "λά", "κια"],
-    "Μωρουδέλια" => &["Μω", "ρου", "δέ", "λια"],
-    "Νάγια" => &["Νά", "για"],
-    "Νάγιας" => &["Νά", "γιας"],
-    "Νάγιες" => &["Νά", "γιες"],
-    "Ναζάκια" => &["Να", "ζά", "κια"],
-    "Νάζια" => &["Νά", "ζια"],
-    "Νανάκια" => &["Να", "νά", "κια"],
-    "Ναυτάκια" => &["Ναυ", "τά", "κια"],
-    "Νεκροκέρια" => &["Νε", "κρο", "κέ", "ρια"],
-    "Νεκροπούλια" => &["Νε", "κρο", "πού", "λια"],
-    "Νεκταρίνια" => &["Νε", "κτα", "ρί", "νια"],
-    "Νεράιδα" => &["Νε", "ράι", "δα"],
-    "Νεράιδας" => &["Νε", "ράι", "δας"],
-    "Νεράιδες" => &["Νε", "ράι", "δες"],
-    "Νεράιδων" => &["Νε", "ράι", "δων"],
-    "Νεράκια" => &["Νε", "ρά", "κια"],
-    "Νεραντζάκια" => &["Νε", "ρα", "ντζά", "κια"],
-    "Νεραντζάνθια" => &["Νε", "ρα", "ντζάν", "θια"],
-    "Νεράντζια" => &["Νε", "ρά", "ντζια"],
-    "Νεροζούμια" => &["Νε", "ρο", "ζού", "μια"],
-    "Νεροζύγια" => &["Νε", "ρο", "ζύ", "για"],
-    "Νερομπούλια" => &["Νε", "ρο", "μπού", "λια"],
-    "Νεροπούλια" => &["Νε", "ρο", "πού", "λια"],
-    "Νευράκια" => &["Νευ", "ρά", "κια"],
-    "Νέφτια" => &["Νέ", "φτια"],
-    "Νησάκια" => &["Νη", "σά", "κια"],
-    "Νια" => &["Νια"],
-    "Νιας" => &["Νιας"],
-    "Νιε" => &["Νιε"],
-    "Νιες" => &["Νιες"],
-    "Νικελένια" => &["Νι", "κε", "λέ", "νια"],
-    "Νικελένιας" => &["Νι", "κε", "λέ", "νιας"],
-    "Νικελένιε" => &["Νι", "κε", "λέ", "νιε"],
-    "Νικελένιες" => &["Νι", "κε", "λέ", "νιες"],
-    "Νικελένιο" => &["Νι", "κε", "λέ", "νιο"],
-    "Νικελένιοι" => &["Νι", "κε", "λέ", "νιοι"],
-    "Νικελένιος" => &["Νι", "κε", "λέ", "νιος"],
-    "Νικελένιου" => &["Νι", "κε", "λέ", "νιου"],
-    "Νικελένιους" => &["Νι", "κε", "λέ", "νιους"],
-    "Νικελένιων" => &["Νι", "κε", "λέ", "νιων"],
-    "Νινάκια" => &["Νι", "νά", "κια"],
-    "Νιο" => &["Νιο"],
-    "Νιοι" => &["Νιοι"],
-    "Νιος" => &["Νιος"],
-    "Νιου" => &["Νιου"],
-    "Νιους" => &["Νιους"],
-    "Νιων" => &["Νιων"],
-    "Νοίκια" => &["Νοί", "κια"],
-    "Νταβαντούρια" => &["Ντα", "βα", "ντού", "ρια"],
-    "Νταβατζιλίκια" => &["Ντα", "βα", "τζι", "λί", "κια"],
-    "Νταβατούρια" => &["Ντα", "βα", "τού", "ρια"],
-    "Νταβούλια" => &["Ντα", "βού", "λια"],
-    "Νταηλίκια" => &["Ντα", "η", "λί", "κια"],
-    "Νταλαβέρια" => &["Ντα", "λα", "βέ", "ρια"],
-    "Ντάλια" => &["Ντά", "λια"],
-    "Ντάλιας" => &["Ντά", "λιας"],
-    "Ντάλιες" => &["Ντά", "λιες"],
-    "Νταμαζλούκια" => &["Ντα", "μαζ", "λού", "κια"],
-    "Νταμάρια" => &["Ντα", "μά", "ρια"],
-    "Νταμαρίσια" => &["Ντα", "μα", "ρί", "σια"],
-    "Νταμαρίσιας" => &["Ντα", "μα", "ρί", "σιας"],
-    "Νταμαρίσιε" => &["Ντα", "μα", "ρί", "σιε"],
-    "Νταμαρίσιες" => &["Ντα", "μα", "ρί", "σιες"],
-    "Νταμαρίσιο" => &["Ντα", "μα", "ρί", "σιο"],
-    "Νταμαρίσιοι" => &["Ντα", "μα", "ρί", "σιοι"],
-    "Νταμαρίσιος" => &["Ντα", "μα", "ρί", "σιος"],
-    "Νταμαρίσιου" => &["Ντα", "μα", "ρί", "σιου"],
-    "Νταμαρίσιους" => &["Ντα", "μα", "ρί", "σιους"],
-    "Νταμαρίσιων" => &["Ντα", "μα", "ρί", "σιων"],
-    "Νταμουζλούκια" => &["Ντα", "μουζ", "λού", "κια"],
-    "Νταντελένια" => &["Ντα", "ντε", "λέ", "νια"],
-    "Νταντελένιας" => &["Ντα", "ντε", "λέ", "νιας"],
-    "Νταντελένιε" => &["Ντα", "ντε", "λέ", "νιε"],
-    "Νταντελένιες" => &["Ντα", "ντε", "λέ", "νιες"],
-    "Νταντελένιο" => &["Ντα", "ντε", "λέ", "νιο"],
-    "Νταντελένιοι" => &["Ντα", "ντε", "λέ", "νιοι"],
-    "Νταντελένιος" => &["Ντα", "ντε", "λέ", "νιος"],
-    "Νταντελένιου" => &["Ντα", "ντε", "λέ", "νιου"],
-    "Νταντελένιους" => &["Ντα", "ντε", "λέ", "νιους"],
-    "Νταντελένιων" => &["Ντα", "ντε", "λέ", "νιων"],
-    "Νταούλια" => &["Ντα", "ού", "λια"],
-    "Ντάπια" => &["Ντά", "πια"],
-    "Ντάπιας" => &["Ντά", "πιας"],
-    "Ντάπιες" => &["Ντά", "πιες"],
-    "Νταραβέρια" => &["Ντα", "ρα", "βέ", "ρια"],
-    "Ντενεκεδάκια" => &["Ντε", "νε", "κε", "δά", "κια"],
-    "Ντενεκεδένια" => &["Ντε", "νε", "κε", "δέ", "νια"],
-    "Ντενεκεδένιας" => &["Ντε", "νε", "κε", "δέ", "νιας"],
-    "Ντενεκεδένιε" => &["Ντε", "νε", "κε", "δέ", "νιε"],
-    "Ντενεκεδένιες" => &["Ντε", "νε", "κε", "δέ", "νιες"],
-    "Ντενεκεδένιο" => &["Ντε", "νε", "κε", "δέ", "νιο"],
-    "Ντενεκεδένιοι" => &["Ντε", "νε", "κε", "δέ", "νιοι"],
-    "Ντενεκεδένιος" => &["Ντε", "νε", "κε", "δέ", "νιος"],
-    "Ντενεκεδένιου" => &["Ντε", "νε", "κε", "δέ", "νιου"],
-    "Ντενεκεδένιους" => &["Ντε", "νε", "κε", "δέ", "νιους"],
-    "Ντενεκεδένιων" => &["Ντε", "νε", "κε", "δέ", "νιων"],
-    "Ντερβένια" => &["Ντερ", "βέ", "νια"],
-    "Ντέρτια" => &["Ντέρ", "τια"],
-    "Ντέφια" => &["Ντέ", "φια"],
-    "Ντιβάνια" => &["Ντι", "βά", "νια"],
-    "Ντοβλέτια" => &["Ντο", "βλέ", "τια"],
-    "Ντόπια" => &["Ντό", "πια"],
-    "Ντόπιας" => &["Ντό", "πιας"],
-    "Ντόπιε" => &["Ντό", "πιε"],
-    "Ντόπιες" => &["Ντό", "πιες"],
-    "Ντόπιο" => &["Ντό", "πιο"],
-    "Ντόπιοι" => &["Ντό", "πιοι"],
-    "Ντόπιος" => &["Ντό", "πιος"],
-    "Ντόπιου" => &["Ντό", "πιου"],
-    "Ντόπιους" => &["Ντό", "πιους"],
-    "Ντόπιων" => &["Ντό", "πιων"],
-    "Ντουβάρια" => &["Ντου", "βά", "ρια"],
-    "Ντουλαπάκια" => &["Ντου", "λα", "πά", "κια"],
-    "Ντουλάπια" => &["Ντου", "λά", "πια"],
-    "Ντουμάνια" => &["Ντου", "μά", "νια"],
-    "Ντουφέκια" => &["Ντου", "φέ", "κια"],
-    "Ντρίλια" => &["Ντρί", "λια"],
-    "Νυστέρια" => &["Νυ", "στέ", "ρια"],
-    "Νυφοστόλια" => &["Νυ", "φο", "στό", "λια"],
-    "Νυχάκια" => &["Νυ", "χά", "κια"],
-    "Νύχια" => &["Νύ", "χια"],
-    "Νυχτέρια" => &["Νυ", "χτέ", "ρια"],
-    "Νυχτοπούλια" => &["Νυ", "χτο", "πού", "λια"],
-    "Ξάγια" => &["Ξά", "για"],
-    "Ξαγρύπνια" => &["Ξα", "γρύ", "πνια"],
-    "Ξαγρύπνιας" => &["Ξα", "γρύ", "πνιας"],
-    "Ξαγρύπνιες" => &["Ξα", "γρύ", "πνιες"],
-    "Ξαδέλφια" => &["Ξα", "δέλ", "φια"],
-    "Ξαδέρφια" => &["Ξα", "δέρ", "φια"],
-    "Ξακρίδια" => &["Ξα", "κρί", "δια"],
-    "Ξάρτια" => &["Ξάρ", "τια"],
-    "Ξεμασκαλίδια" => &["Ξε", "μα", "σκα", "λί", "δια"],
-    "Ξενύχτια" => &["Ξε", "νύ", "χτια"],
-    "Ξεπεταρόνια" => &["Ξε", "πε", "τα", "ρό", "νια"],
-    "Ξεπεταρούδια" => &["Ξε", "πε", "τα", "ρού", "δια"],
-    "Ξεράδια" => &["Ξε", "ρά", "δια"],
-    "Ξεροβόρια" => &["Ξε", "ρο", "βό", "ρια"],
-    "Ξεροβούνια" => &["Ξε", "ρο", "βού", "νια"],
-    "Ξερολίθια" => &["Ξε", "ρο", "λί", "θια"],
-    "Ξερονήσια" => &["Ξε", "ρο", "νή", "σια"],
-    "Ξεροσφύρια" => &["Ξε", "ρο", "σφύ", "ρια"],
-    "Ξεροτηγανίδια" => &["Ξε", "ρο", "τη", "γα", "νί", "δια"],
-    "Ξεσκονιστήρια" => &["Ξε", "σκο", "νι", "στή", "ρια"],
-    "Ξεσυνέρια" => &["Ξε", "συ", "νέ", "ρια"],
-    "Ξεσυνέριο" => &["Ξε", "συ", "νέ", "ριο"],
-    "Ξεσυνέριου" => &["Ξε", "συ", "νέ", "ριου"],
-    "Ξεσυνέριων" => &["Ξε", "συ", "νέ", "ριων"],
-    "Ξετυλιγάδια" => &["Ξε", "τυ", "λι", "γά", "δια"],
-    "Ξεφτέρια" => &["Ξε", "φτέ", "ρια"],
-    "Ξέφτια" => &["Ξέ", "φτια"],
-    "Ξεφτίδια" => &["Ξε", "φτί", "δια"],
-    "Ξίγκια" => &["Ξί", "γκια"],
-    "Ξίδια" => &["Ξί", "δια"],
-    "Ξόδια" => &["Ξό", "δια"],
-    "Ξόμπλια" => &["Ξό", "μπλια"],
-    "Ξόρκια" => &["Ξόρ", "κια"],
-    "Ξουράφια" => &["Ξου", "ρά", "φια"],
-    "Ξύγκια" => &["Ξύ", "γκια"],
-    "Ξύδια" => &["Ξύ", "δια"],
-    "Ξυλάκια" => &["Ξυ", "λά", "κια"],
-    "Ξυλαράκια" => &["Ξυ", "λα", "ρά", "κια"],
-    "Ξυλένια" => &["Ξυ", "λέ", "νια"],
-    "Ξυλένιας" => &["Ξυ", "λέ", "νιας"],
-    "Ξυλένιε" => &["Ξυ", "λέ", "νιε"],
-    "Ξυλένιες" => &["Ξυ", "λέ", "νιες"],
-    "Ξυλένιο" => &["Ξυ", "λέ", "νιο"],
-    "Ξυλένιοι" => &["Ξυ", "λέ", "νιοι"],
-    "Ξυλένιος" => &["Ξυ", "λέ", "νιος"],
-    "Ξυλένιου" => &["Ξυ", "λέ", "νιου"],
-    "Ξυλένιους" => &["Ξυ", "λέ", "νιους"],
-    "Ξυλένιων" => &["Ξυ", "λέ", "νιων"],
-    "Ξυλίκια" => &["Ξυ", "λί", "κια"],
-    "Ξυλοκάρφια" => &["Ξυ", "λο", "κάρ", "φια"],
-    "Ξυπνητήρια" => &["Ξυ", "πνη", "τή", "ρια"],
-    "Ξυπνητούρια" => &["Ξυ", "πνη", "τού", "ρια"],
-    "Ξυπνοπούλια" => &["Ξυ", "πνο", "πού", "λια"],
-    "Ξυραφάκια" => &["Ξυ", "ρα", "φά", "κια"],
-    "Ξυράφια" => &["Ξυ", "ρά", "φια"],
-    "Ξυστήρια" => &["Ξυ", "στή", "ρια"],
-    "Ξωκλήσια" => &["Ξω", "κλή", "σια"],
-    "Ολυμπιακάκια" => &["Ο", "λυ", "μπι", "α", "κά", "κια"],
-    "Ολυμπιακάκιας" => &["Ο", "λυ", "μπι", "α", "κά", "κιας"],
-    "Ολυμπιακάκιες" => &["Ο", "λυ", "μπι", "α", "κά", "κιες"],
-    "Ονοματάκια" => &["Ο", "νο", "μα", "τά", "κια"],
-    "Οξένια" => &["Ο", "ξέ", "νια"],
-    "Οξένιας" => &["Ο", "ξέ", "νιας"],
-    "Οξένιε" => &["Ο", "ξέ", "νιε"],
-    "Οξένιες" => &["Ο", "ξέ", "νιες"],
-    "Οξένιο" => &["Ο", "ξέ", "νιο"],
-    "Οξένιοι" => &["Ο", "ξέ", "νιοι"],
-    "Οξένιος" => &["Ο", "ξέ", "νιος"],
-    "Οξένιου" => &["Ο", "ξέ", "νιου"],
-    "Οξένιους" => &["Ο", "ξέ", "νιους"],
-    "Οξένιων" => &["Ο", "ξέ", "νιων"],
-    "Οργανάκια" => &["Ορ", "γα", "νά", "κια"],
-    "Ορμάνια" => &["Ορ", "μά", "νια"],
-    "Ορμήνια" => &["Ορ", "μή", "νια"],
-    "Ορμήνιας" => &["Ορ", "μή", "νιας"],
-    "Ορμήνιες" => &["Ορ", "μή", "νιες"],
-    "Ορμίδια" => &["Ορ", "μί", "δια"],
-    "Όρνια" => &["Όρ", "νια"],
-    "Ορνίθια" => &["Ορ", "νί", "θια"],
-    "Όρνιο" => &["Όρ", "νιο"],
-    "Όρνιου" => &["Όρ", "νιου"],
-    "Όρνιων" => &["Όρ", "νιων"],
-    "Ορτύκια" => &["Ορ", "τύ", "κια"],
-    "Ορφάνια" => &["Ορ", "φά", "νια"],
-    "Ορφάνιας" => &["Ορ", "φά", "νιας"],
-    "Ορφάνιες" => &["Ορ", "φά", "νιες"],
-    "Ούγια" => &["Ού", "για"],
-    "Ούγιας" => &["Ού", "γιας"],
-    "Ούγιες" => &["Ού", "γιες"],
-    "Ουζάκια" => &["Ου", "ζά", "κια"],
-    "Οχτάρια" => &["Ο", "χτά", "ρια"],
-    "Παγάκια" => &["Πα", "γά", "κια"],
-    "Παγίδια" => &["Πα", "γί", "δια"],
-    "Παγκάκια" => &["Πα", "γκά", "κια"],
-    "Παγκάρια" => &["Πα", "γκά", "ρια"],
-    "Παγόνια" => &["Πα", "γό", "νια"],
-    "Παγουράκια" => &["Πα", "γου", "ρά", "κια"],
-    "Παγούρια" => &["Πα", "γού", "ρια"],
-    "Παγωτάκια" => &["Πα", "γω", "τά", "κια"],
-    "Παζάρια" => &["Πα", "ζά", "ρια"],
-    "Παζαρίσια" => &["Πα", "ζα", "ρί", "σια"],
-    "Παζαρίσιας" => &["Πα", "ζα", "ρί", "σιας"],
-    "Παζαρίσιε" => &["Πα", "ζα", "ρί", "σιε"],
-    "Παζαρίσιες" => &["Πα", "ζα", "ρί", "σιες"],
-    "Παζαρίσιο" => &["Πα", "ζα", "ρί", "σιο"],
-    "Παζαρίσιοι" => &["Πα", "ζα", "ρί", "σιοι"],
-    "Παζαρίσιος" => &["Πα", "ζα", "ρί", "σιος"],
-    "Παζαρίσιου" => &["Πα", "ζα", "ρί", "σιου"],
-    "Παζαρίσιους" => &["Πα", "ζα", "ρί", "σιους"],
-    "Παζαρίσιων" => &["Πα", "ζα", "ρί", "σιων"],
-    "Παζαρλίκια" => &["Πα", "ζαρ", "λί", "κια"],
-    "Παιγνιδάκια" => &["Παι", "γνι", "δά", "κια"],
-    "Παιγνίδια" => &["Παι", "γνί", "δια"],
-    "Παιδάκια" => &["Παι", "δά", "κια"],
-    "Παϊδάκια" => &["Πα", "ϊ", "δά", "κια"],
-    "Παιδαρέλια" => &["Παι", "δα", "ρέ", "λια"],
-    "Παΐδια" => &["Πα", "ΐ", "δια"],
-    "Παιδιακίσια" => &["Παι", "δι", "α", "κί", "σια"],
-    "Παιδιακίσιας" => &["Παι", "δι", "α", "κί", "σιας"],
-    "Παιδιακίσιε" => &["Παι", "δι", "α", "κί", "σιε"],
-    "Παιδιακίσιες" => &["Παι", "δι", "α", "κί", "σιες"],
-    "Παιδιακίσιο" => &["Παι", "δι", "α", "κί", "σιο"],
-    "Παιδιακίσιοι" => &["Παι", "δι", "α", "κί", "σιοι"],
-    "Παιδιακίσιος" => &["Παι", "δι", "α", "κί", "σιος"],
-    "Παιδιακίσιου" => &["Παι", "δι", "α", "κί", "σιου"],
-    "Παιδιακίσιους" => &["Παι", "δι", "α", "κί", "σιους"],
-    "Παιδιακίσιων" => &["Παι", "δι", "α", "κί", "σιων"],
-    "Παιδολόγια" => &["Παι", "δο", "λό", "για"],
-    "Παιδομάνια" => &["Παι", "δο", "μά", "νια"],
-    "Παιχνιδάκια" => &["Παι", "χνι", "δά", "κια"],
-    "Παιχνίδια" => &["Παι", "χνί", "δια"],
-    "Πακετάκια" => &["Πα", "κε", "τά", "κια"],
-    "Παλαμάκια" => &["Πα", "λα", "μά", "κια"],
-    "Παλαμάρια" => &["Πα", "λα", "μά", "ρια"],
-    "Παλατάκια" => &["Πα", "λα", "τά", "κια"],
-    "Παλάτια" => &["Πα", "λά", "τια"],
-    "Παλικαράκια" => &["Πα", "λι", "κα", "ρά", "κια"],
-    "Παλικάρια" => &["Πα", "λι", "κά", "ρια"],
-    "Παλικαρίσια" => &["Πα", "λι", "κα", "ρί", "σια"],
-    "Παλικαρίσιας" => &["Πα", "λι", "κα", "ρί", "σιας"],
-    "Παλικαρίσιε" => &["Πα", "λι", "κα", "ρί", "σιε"],
-    "Παλικαρίσιες" => &["Πα", "λι", "κα", "ρί", "σιες"],
-    "Παλικαρίσιο" => &["Πα", "λι", "κα", "ρί", "σιο"],
-    "Παλικαρίσιοι" => &["Πα", "λι", "κα", "ρί", "σιοι"],
-    "Παλικαρίσιος" => &["Πα", "λι", "κα", "ρί", "σιος"],
-    "Παλικαρίσιου" => &["Πα", "λι", "κα", "ρί", "σιου"],
-    "Παλικαρίσιους" => &["Πα", "λι", "κα", "ρί", "σιους"],
-    "Παλικαρίσιων" => &["Πα", "λι", "κα", "ρί", "σιων"],
-    "Παλιούρια" => &["Πα", "λι", "ού", "ρια"],
-    "Παλληκαρίσια" => &["Παλ", "λη", "κα", "ρί", "σια"],
-    "Παλληκαρίσιας" => &["Παλ", "λη", "κα", "ρί", "σιας"],
-    "Παλληκαρίσιε" => &["Παλ", "λη", "κα", "ρί", "σιε"],
-    "Παλληκαρίσιες" => &["Παλ", "λη", "κα", "ρί", "σιες"],
-    "Παλληκαρίσιο" => &["Παλ", "λη", "κα", "ρί", "σιο"],
-    "Παλληκαρίσιοι" => &["Παλ", "λη", "κα", "ρί", "σιοι"],
-    "Παλληκαρίσιος" => &["Παλ", "λη", "κα", "ρί", "σιος"],
-    "Παλληκαρίσιου" => &["Παλ", "λη", "κα", "ρί", "σιου"],
-    "Παλληκαρίσιους" => &["Παλ", "λη", "κα", "ρί", "σιους"],
-    "Παλληκαρίσιων" => &["Παλ", "λη", "κα", "ρί", "σιων"],
-    "Παλλικάρια" => &["Παλ", "λι", "κά", "ρια"],
-    "Παλούκια" => &["Πα", "λού", "κια"],
-    "Παλτουδάκια" => &["Παλ", "του", "δά", "κια"],
-    "Παναθηναϊκάκια" => &["Πα", "να", "θη", "να", "ϊ", "κά", "κια"],
-    "Παναθηναϊκάκιας" => &["Πα", "να", "θη", "να", "ϊ", "κά", "κιας"],
-    "Παναθηναϊκάκιες" => &["Πα", "να", "θη", "να", "ϊ", "κά", "κιες"],
-    "Πανάκια" => &["Πα", "νά", "κια"],
-    "Πανεράκια" => &["Πα", "νε", "ρά", "κια"],
-    "Πανέρια" => &["Πα", "νέ", "ρια"],
-    "Πανηγύρια" => &["Πα", "νη", "γύ", "ρια"],
-    "Πανταλονάκια" => &["Πα", "ντα", "λο", "νά", "κια"],
-    "Πανταλόνια" => &["Πα", "ντα", "λό", "νια"],
-    "Παντελονάκια" => &["Πα", "ντε", "λο", "νά", "κια"],
-    "Παντελόνια" => &["Πα", "ντε", "λό", "νια"],
-    "Παντεσπάνια" => &["Πα", "ντε", "σπά", "νια"],
-    "Παντζάρια" => &["Πα", "ντζά", "ρια"],
-    "Παντζούρια" => &["Πα", "ντζού", "ρια"],
-    "Πανωπροίκια" => &["Πα", "νω", "προί", "κια"],
-    "Πανωτόκια" => &["Πα", "νω", "τό", "κια"],
-    "Πανωφόρια" => &["Πα", "νω", "φό", "ρια"],
-    "Παξιμάδια" => &["Πα", "ξι", "μά", "δια"],
-    "Παπαγαλάκια" => &["Πα", "πα", "γα", "λά", "κια"],
-    "Παπάγια" => &["Πα", "πά", "για"],
-    "Παπάγιας" => &["Πα", "πά", "γιας"],
-    "Παπάγιες" => &["Πα", "πά", "γιες"],
-    "Παπαδάκια" => &["Πα", "πα", "δά", "κια"],
-    "Παπαδολόγια" => &["Πα", "πα", "δο", "λό", "για"],
-    "Παπαδομάνια" => &["Πα", "πα", "δο", "μά", "νια"],
-    "Παπαδοπαίδια" => &["Πα", "πα", "δο", "παί", "δια"],
-    "Παπάζια" => &["Πα", "πά", "ζια"],
-    "Παπάκια" => &["Πα", "πά", "κια"],
-    "Πάπια" => &["Πά", "πια"],
-    "Πάπιας" => &["Πά", "πιας"],
-    "Παπιγιονάκια" => &["Πα", "πι", "γι", "ο", "νά", "κια"],
-    "Παπιγιονάκιας" => &["Πα", "πι", "γι", "ο", "νά", "κιας"],
-    "Παπιγιονάκιες" => &["Πα", "πι", "γι", "ο", "νά", "κιες"],
-    "Πάπιες" => &["Πά", "πιες"],
-    "Παπίσια" => &["Πα", "πί", "σια"],
-    "Παπίσιας" => &["Πα", "πί", "σιας"],
-    "Παπίσιε" => &["Πα", "πί", "σιε"],
-    "Παπίσιες" => &["Πα", "πί", "σιες"],
-    "Παπίσιο" => &["Πα", "πί", "σιο"],
-    "Παπίσιοι" => &["Πα", "πί", "σιοι"],
-    "Παπίσιος" => &["Πα", "πί", "σιος"],
-    "Παπίσιου" => &["Πα", "πί", "σιου"],
-    "Παπίσιους" => &["Πα", "πί", "σιους"],
-    "Παπίσιων" => &["Πα", "πί", "σιων"],
-    "Παποράκια" => &["Πα", "πο", "ρά", "κια"],
-    "Παπόρια" => &["Πα", "πό", "ρια"],
-    "Παπουτσάκια" => &["Πα", "που", "τσά", "κια"],
-    "Παπούτσια" => &["Πα", "πού", "τσια"],
-    "Παραγάδια" => &["Πα", "ρα", "γά", "δια"],
-    "Παραγκώμια" => &["Πα", "ρα", "γκώ", "μια"],
-    "Παραγώνια" => &["Πα", "ρα", "γώ", "νια"],
-    "Παραδάκια" => &["Πα", "ρα", "δά", "κια"],
-    "Παραδειγματάκια" => &["Πα", "ρα", "δειγ", "μα", "τά", "κια"],
-    "Παραδεισένια" => &["Πα", "ρα", "δει", "σέ", "νια"],
-    "Παραδεισένιας" => &["Πα", "ρα", "δει", "σέ", "νιας"],
-    "Παραδεισένιε" => &["Πα", "ρα", "δει", "σέ", "νιε"],
-    "Παραδεισένιες" => &["Πα", "ρα", "δει", "σέ", "νιες"],
-    "Παραδεισένιο" => &["Πα", "ρα", "δει", "σέ", "νιο"],
-    "Παραδεισένιοι" => &["Πα", "ρα", "δει", "σέ", "νιοι"],
-    "Παραδεισένιος" => &["Πα", "ρα", "δει", "σέ", "νιος"],
-    "Παραδεισένιου" => &["Πα", "ρα", "δει", "σέ", "νιου"],
-    "Παραδεισένιους" => &["Πα", "ρα", "δει", "σέ", "νιους"],
-    "Παραδεισένιων" => &["Πα", "ρα", "δει", "σέ", "νιων"],
-    "Παραθυράκια" => &["Πα", "ρα", "θυ", "ρά", "κια"],
-    "Παραθύρια" => &["Πα", "ρα", "θύ", "ρια"],
-    "Παρακάλια" => &["Πα", "ρα", "κά", "λια"],
-    "Παρακλάδια" => &["Πα", "ρα", "κλά", "δια"],
-    "Παραμυθάκια" => &["Πα", "ρα", "μυ", "θά", "κια"],
-    "Παραμυθένια" => &["Πα", "ρα", "μυ", "θέ", "νια"],
-    "Παραμυθένιας" => &["Πα", "ρα", "μυ", "θέ", "νιας"],
-    "Παραμυθένιε" => &["Πα", "ρα", "μυ", "θέ", "νιε"],
-    "Παραμυθένιες" => &["Πα", "ρα", "μυ", "θέ", "νιες"],
-    "Παραμυθένιο" => &["Πα", "ρα", "μυ", "θέ", "νιο"],
-    "Παραμυθένιοι" => &["Πα", "ρα", "μυ", "θέ", "νιοι"],
-    "Παραμυθένιος" => &["Πα", "ρα", "μυ", "θέ", "νιος"],
-    "Παραμυθένιου" => &["Πα", "ρα", "μυ", "θέ", "νιου"],
-    "Παραμυθένιους" => &["Πα", "ρα", "μυ", "θέ", "νιους"],
-    "Παραμυθένιων" => &["Πα", "ρα", "μυ", "θέ", "νιων"],
-    "Παραμύθια" => &["Πα", "ρα", "μύ", "θια"],
-    "Παρανόμια" => &["Πα", "ρα", "νό", "μια"],
-    "Παραπαίδια" => &["Πα", "ρα", "παί", "δια"],
-    "Παραπανίσια" => &["Πα", "ρα", "πα", "νί", "σια"],
-    "Παραπανίσιας" => &["Πα", "ρα", "πα", "νί", "σιας"],
-    "Παραπανίσιε" => &["Πα", "ρα", "πα", "νί", "σιε"],
-    "Παραπανίσιες" => &["Πα", "ρα", "πα", "νί", "σιες"],
-    "Παραπανίσιο" => &["Πα", "ρα", "πα", "νί", "σιο"],
-    "Παραπανίσιοι" => &["Πα", "ρα", "πα", "νί", "σιοι"],
-    "Παραπανίσιος" => &["Πα", "ρα", "πα", "νί", "σιος"],
-    "Παραπανίσιου" => &["Πα", "ρα", "πα", "νί", "σιου"],
-    "Παραπανίσιους" => &["Πα", "ρα", "πα", "νί", "σιους"],
-    "Παραπανίσιων" => &["Πα", "ρα", "πα", "νί", "σιων"],
-    "Παραπόρτια" => &["Πα", "ρα", "πόρ", "τια"],
-    "Παραπούλια" => &["Πα", "ρα", "πού", "λια"],
-    "Παραπροίκια" => &["Πα", "ρα", "προί", "κια"],
-    "Παρασόλια" => &["Πα", "ρα", "σό", "λια"],
-    "Παρασπόρια" => &["Πα", "ρα", "σπό", "ρια"],
-    "Παρατσούκλια" => &["Πα", "ρα", "τσού", "κλια"],
-    "Παρεκκλήσια" => &["Πα", "ρεκ", "κλή", "σια"],
-    "Παρηγόρια" => &["Πα", "ρη", "γό", "ρια"],
-    "Παρηγόριας" => &["Πα", "ρη", "γό", "ριας"],
-    "Παρηγόριες" => &["Πα", "ρη", "γό", "ριες"],
-    "Παρτάκια" => &["Παρ", "τά", "κια"],
-    "Παρτάκιας" => &["Παρ", "τά", "κιας"],
-    "Παρτάκιες" => &["Παρ", "τά", "κιες"],
-    "Παρτάλια" => &["Παρ", "τά", "λια"],
-    "Παρτέρια" => &["Παρ", "τέ", "ρια"],
-    "Πασαλίκια" => &["Πα", "σα", "λί", "κια"],
-    "Πασαπόρτια" => &["Πα", "σα", "πόρ", "τια"],
-    "Πασουμάκια" => &["Πα", "σου", "μά", "κια"],
-    "Πασούμια" => &["Πα", "σού", "μια"],
-    "Παστέλια" => &["Πα", "στέ", "λια"],
-    "Παστίλια" => &["Πα", "στί", "λια"],
-    "Παστίλιας" => &["Πα", "στί", "λιας"],
-    "Παστίλιες" => &["Πα", "στί", "λιες"],
-    "Πατάκια" => &["Πα", "τά", "κια"],
-    "Πατάρια" => &["Πα", "τά", "ρια"],
-    "Πατατάκια" => &["Πα", "τα", "τά", "κια"],
-    "Πατητήρια" => &["Πα", "τη", "τή", "ρια"],
-    "Πατίκια" => &["Πα", "τί", "κια"],
-    "Πατίνια" => &["Πα", "τί", "νια"],
-    "Πατριωτάκια" => &["Πα", "τρι", "ω", "τά", "κια"],
-    "Πατσαβούρια" => &["Πα", "τσα", "βού", "ρια"],
-    "Παφιλένια" => &["Πα", "φι", "λέ", "νια"],
-    "Παφιλένιας" => &["Πα", "φι", "λέ", "νιας"],
-    "Παφιλένιε" => &["Πα", "φι", "λέ", "νιε"],
-    "Παφιλένιες" => &["Πα", "φι", "λέ", "νιες"],
-    "Παφιλένιο" => &["Πα", "φι", "λέ", "νιο"],
-    "Παφιλένιοι" => &["Πα", "φι", "λέ", "νιοι"],
-    "Παφιλένιος" => &["Πα", "φι", "λέ", "νιος"],
-    "Παφιλένιου" => &["Πα", "φι", "λέ", "νιου"],
-    "Παφιλένιους" => &["Πα", "φι", "λέ", "νιους"],
-    "Παφιλένιων" => &["Πα", "φι", "λέ", "νιων"],
-    "Παχάκια" => &["Πα", "χά", "κια"],
-    "Πεδούκλια" => &["Πε", "δού", "κλια"],
-    "Πεζούλια" => &["Πε", "ζού", "λια"],
-    "Πεια" => &["Πεια"],
-    "Πειο" => &["Πειο"],
-    "Πειρακτήρια" => &["Πει", "ρα", "κτή", "ρια"],
-    "Πειραχτήρια" => &["Πει", "ρα", "χτή", "ρια"],
-    "Πελαγίσια" => &["Πε", "λα", "γί", "σια"],
-    "Πελαγίσιας" => &["Πε", "λα", "γί", "σιας"],
-    "Πελαγίσιε" => &["Πε", "λα", "γί", "σιε"],
-    "Πελαγίσιες" => &["Πε", "λα", "γί", "σιες"],
-    "Πελαγίσιο" => &["Πε", "λα", "γί", "σιο"],
-    "Πελαγίσιοι" => &["Πε", "λα", "γί", "σιοι"],
-    "Πελαγίσιος" => &["Πε", "λα", "γί", "σιος"],
-    "Πελαγίσιου" => &["Πε", "λα", "γί", "σιου"],
-    "Πελαγίσιους" => &["Πε", "λα", "γί", "σιους"],
-    "Πελαγίσιων" => &["Πε", "λα", "γί", "σιων"],
-    "Πελαργόνια" => &["Πε", "λαρ", "γό", "νια"],
-    "Πελέκια" => &["Πε", "λέ", "κια"],
-    "Πελεκούδια" => &["Πε", "λε", "κού", "δια"],
-    "Πεναλτάκια" => &["Πε", "ναλ", "τά", "κια"],
-    "Πεναλτάκιας" => &["Πε", "ναλ", "τά", "κιας"],
-    "Πεναλτάκιες" => &["Πε", "ναλ", "τά", "κιες"],
-    "Πενηνταράκια" => &["Πε", "νη", "ντα", "ρά", "κια"],
-    "Πενηντάρια" => &["Πε", "νη", "ντά", "ρια"],
-    "Πεντακοσάρια" => &["Πε", "ντα", "κο", "σά", "ρια"],
-    "Πενταράκια" => &["Πε", "ντα", "ρά", "κια"],
-    "Πεντάρια" => &["Πε", "ντά", "ρια"],
-    "Πεντελίσια" => &["Πε", "ντε", "λί", "σια"],
-    "Πεντελίσιας" => &["Πε", "ντε", "λί", "σιας"],
-    "Πεντελίσιε" => &["Πε", "ντε", "λί", "σιε"],
-    "Πεντελίσιες" => &["Πε", "ντε", "λί", "σιες"],
-    "Πεντελίσιο" => &["Πε", "ντε", "λί", "σιο"],
-    "Πεντελίσιοι" => &["Πε", "ντε", "λί", "σιοι"],
-    "Πεντελίσιος" => &["Πε", "ντε", "λί", "σιος"],
-    "Πεντελίσιου" => &["Πε", "ντε", "λί", "σιου"],
-    "Πεντελίσιους" => &["Πε", "ντε", "λί", "σιους"],
-    "Πεντελίσιων" => &["Πε", "ντε", "λί", "σιων"],
-    "Πεπόνια" => &["Πε", "πό", "νια"],
-    "Περβάζια" => &["Περ", "βά", "ζια"],
-    "Περβολάκια" => &["Περ", "βο", "λά", "κια"],
-    "Περβολαρίσια" => &["Περ", "βο", "λα", "ρί", "σια"],
-    "Περβολαρίσιας" => &["Περ", "βο", "λα", "ρί", "σιας"],
-    "Περβολαρίσιε" => &["Περ", "βο", "λα", "ρί", "σιε"],
-    "Περβολαρίσιες" => &["Περ", "βο", "λα", "ρί", "σιες"],
-    "Περβολαρίσιο" => &["Περ", "βο", "λα", "ρί", "σιο"],
-    "Περβολαρίσιοι" => &["Περ", "βο", "λα", "ρί", "σιοι"],
-    "Περβολαρίσιος" => &["Περ", "βο", "λα", "ρί", "σιος"],
-    "Περβολαρίσιου" => &["Περ", "βο", "λα", "ρί", "σιου"],
-    "Περβολαρίσιους" => &["Περ", "βο", "λα", "ρί", "σιους"],
-    "Περβολαρίσιων" => &["Περ", "βο", "λα", "ρί", "σιων"],
-    "Περβόλια" => &["Περ", "βό", "λια"],
-    "Περγέλια" => &["Περ", "γέ", "λια"],
-    "Περγέλιο" => &["Περ", "γέ", "λιο"],
-    "Περγέλιου" => &["Περ", "γέ", "λιου"],
-    "Περγέλιων" => &["Περ", "γέ", "λιων"],
-    "Περδικάκια" => &["Περ", "δι", "κά", "κια"],
-    "Περδίκια" => &["Περ", "δί", "κια"],
-    "Περδικίσια" => &["Περ", "δι", "κί", "σια"],
-    "Περδικίσιας" => &["Περ", "δι", "κί", "σιας"],
-    "Περδικίσιε" => &["Περ", "δι", "κί", "σιε"],
-    "Περδικίσιες" => &["Περ", "δι", "κί", "σιες"],
-    "Περδικίσιο" => &["Περ", "δι", "κί", "σιο"],
-    "Περδικίσιοι" => &["Περ", "δι", "κί", "σιοι"],
-    "Περδικίσιος" => &["Περ", "δι", "κί", "σιος"],
-    "Περδικίσιου" => &["Περ", "δι", "κί", "σιου"],
-    "Περδικίσιους" => &["Περ", "δι", "κί", "σιους"],
-    "Περδικίσιων" => &["Περ", "δι", "κί", "σιων"],
-    "Περδικοπάνια" => &["Περ", "δι", "κο", "πά", "νια"],
-    "Περδικοπούλια" => &["Περ", "δι", "κο", "πού", "λια"],
-    "Περδικούλια" => &["Περ", "δι", "κού", "λια"],
-    "Περδούκλια" => &["Περ", "δού", "κλια"],
-    "Περηφάνεια" => &["Πε", "ρη", "φά", "νεια"],
-    "Περηφάνειας" => &["Πε", "ρη", "φά", "νειας"],
-    "Περηφάνειες" => &["Πε", "ρη", "φά", "νειες"],
-    "Περηφάνια" => &["Πε", "ρη", "φά", "νια"],
-    "Περηφάνιας" => &["Πε", "ρη", "φά", "νιας"],
-    "Περηφάνιες" => &["Πε", "ρη", "φά", "νιες"],
-    "Περιβολάκια" => &["Πε", "ρι", "βο", "λά", "κια"],
-    "Περιβολαρίσια" => &["Πε", "ρι", "βο", "λα", "ρί", "σια"],
-    "Περιβολαρίσιας" => &["Πε", "ρι", "βο", "λα", "ρί", "σιας"],
-    "Περιβολαρίσιε" => &["Πε", "ρι", "βο", "λα", "ρί", "σιε"],
-    "Περιβολαρίσιες" => &["Πε", "ρι", "βο", "λα", "ρί", "σιες"],
-    "Περιβολαρίσιο" => &["Πε", "ρι", "βο", "λα", "ρί", "σιο"],
-    "Περιβολαρίσιοι" => &["Πε", "ρι", "βο", "λα", "ρί", "σιοι"],
-    "Περιβολαρίσιος" => &["Πε", "ρι", "βο", "λα", "ρί", "σιος"],
-    "Περιβολαρίσιου" => &["Πε", "ρι", "βο", "λα", "ρί", "σιου"],
-    "Περιβολαρίσιους" => &["Πε", "ρι", "βο", "λα", "ρί", "σιους"],
-    "Περιβολαρίσιων" => &["Πε", "ρι", "βο", "λα", "ρί", "σιων"],
-    "Περιβόλια" => &["Πε", "ρι", "βό", "λια"],
-    "Περιβολίσια" => &["Πε", "ρι", "βο", "λί", "σια"],
-    "Περιβολίσιας" => &["Πε", "ρι", "βο", "λί", "σιας"],
-    "Περιβολίσιε" => &["Πε", "ρι", "βο", "λί", "σιε"],
-    "Περιβολίσιες" => &["Πε", "ρι", "βο", "λί", "σιες"],
-    "Περιβολίσιο" => &["Πε", "ρι", "βο", "λί", "σιο"],
-    "Περιβολίσιοι" => &["Πε", "ρι", "βο", "λί", "σιοι"],
-    "Περιβολίσιος" => &["Πε", "ρι", "βο", "λί", "σιος"],
-    "Περιβολίσιου" => &["Πε", "ρι", "βο", "λί", "σιου"],
-    "Περιβολίσιους" => &["Πε", "ρι", "βο", "λί", "σιους"],
-    "Περιβολίσιων" => &["Πε", "ρι", "βο", "λί", "σιων"],
-    "Περιγιάλια" => &["Πε", "ρι", "γι", "ά", "λια"],
-    "Περιμενάκια" => &["Πε", "ρι", "με", "νά", "κια"],
-    "Περιμενάκιας" => &["Πε", "ρι", "με", "νά", "κιας"],
-    "Περιμενάκιες" => &["Πε", "ρι", "με", "νά", "κιες"],
-    "Περιπλοκάδια" => &["Πε", "ρι", "πλο", "κά", "δια"],
-    "Περιστεράκια" => &["Πε", "ρι", "στε", "ρά", "κια"],
-    "Περιστέρια" => &["Πε", "ρι", "στέ", "ρια"],
-    "Περιστερίσια" => &["Πε", "ρι", "στε", "ρί", "σια"],
-    "Περιστερίσιας" => &["Πε", "ρι", "στε", "ρί", "σιας"],
-    "Περιστερίσιε" => &["Πε", "ρι", "στε", "ρί", "σιε"],
-    "Περιστερίσιες" => &["Πε", "ρι", "στε", "ρί", "σιες"],
-    "Περιστερίσιο" => &["Πε", "ρι", "στε", "ρί", "σιο"],
-    "Περιστερίσιοι" => &["Πε", "ρι", "στε", "ρί", "σιοι"],
-    "Περιστερίσιος" => &["Πε", "ρι", "στε", "ρί", "σιος"],
-    "Περιστερίσιου" => &["Πε", "ρι", "στε", "ρί", "σιου"],
-    "Περιστερίσιους" => &["Πε", "ρι", "στε", "ρί", "σιους"],
-    "Περιστερίσιων" => &["Πε", "ρι", "στε", "ρί", "σιων"],
-    "Περιφρόνια" => &["Πε", "ρι", "φρό", "νια"],
-    "Περιφρόνιας" => &["Πε", "ρι", "φρό", "νιας"],
-    "Περιφρόνιες" => &["Πε", "ρι", "φρό", "νιες"],
-    "Περντάχια" => &["Περ", "ντά", "χια"],
-    "Περουζένια" => &["Πε", "ρου", "ζέ", "νια"],
-    "Περουζένιας" => &["Πε", "ρου", "ζέ", "νιας"],
-    "Περουζένιε" => &["Πε", "ρου", "ζέ", "νιε"],
-    "Περουζένιες" => &["Πε", "ρου", "ζέ", "νιες"],
-    "Περουζένιο" => &["Πε", "ρου", "ζέ", "νιο"],
-    "Περουζένιοι" => &["Πε", "ρου", "ζέ", "νιοι"],
-    "Περουζένιος" => &["Πε", "ρου", "ζέ", "νιος"],
-    "Περουζένιου" => &["Πε", "ρου", "ζέ", "νιου"],
-    "Περουζένιους" => &["Πε", "ρου", "ζέ", "νιους"],
-    "Περουζένιων" => &["Πε", "ρου", "ζέ", "νιων"],
-    "Πεσκέσια" => &["Πε", "σκέ", "σια"],
-    "Πεσκίρια" => &["Πε", "σκί", "ρια"],
-    "Πετάλια" => &["Πε", "τά", "λια"],
-    "Πεταλούδια" => &["Πε", "τα", "λού", "δια"],
-    "Πεταρούδια" => &["Πε", "τα", "ρού", "δια"],
-    "Πεταχτάρια" => &["Πε", "τα", "χτά", "ρια"],
-    "Πετειναράκια" => &["Πε", "τει", "να", "ρά", "κια"],
-    "Πετεινάρια" => &["Πε", "τει", "νά", "ρια"],
-    "Πετιμέζια" => &["Πε", "τι", "μέ", "ζια"],
-    "Πετμέζια" => &["Πε", "τμέ", "ζια"],
-    "Πετούγια" => &["Πε", "τού", "για"],
-    "Πετούγιας" => &["Πε", "τού", "γιας"],
-    "Πετούγιες" => &["Πε", "τού", "γιες"],
-    "Πετραδάκια" => &["Πε", "τρα", "δά", "κια"],
-    "Πετράδια" => &["Πε", "τρά", "δια"],
-    "Πετραχήλια" => &["Πε", "τρα", "χή", "λια"],
-    "Πετρένια" => &["Πε", "τρέ", "νια"],
-    "Πετρένιας" => &["Πε", "τρέ", "νιας"],
-    "Πετρένιε" => &["Πε", "τρέ", "νιε"],
-    "Πετρένιες" => &["Πε", "τρέ", "νιες"],
-    "Πετρένιο" => &["Πε", "τρέ", "νιο"],
-    "Πετρένιοι" => &["Πε", "τρέ", "νιοι"],
-    "Πετρένιος" => &["Πε", "τρέ", "νιος"],
-    "Πετρένιου" => &["Πε", "τρέ", "νιου"],
-    "Πετρένιους" => &["Πε", "τρέ", "νιους"],
-    "Πετρένιων" => &["Πε", "τρέ", "νιων"],
-    "Πετροτόπια" => &["Πε", "τρο", "τό", "πια"],
-    "Πετσάκια" => &["Πε", "τσά", "κια"],
-    "Πετσένια" => &["Πε", "τσέ", "νια"],
-    "Πετσένιας" => &["Πε", "τσέ", "νιας"],
-    "Πετσένιε" => &["Πε", "τσέ", "νιε"],
-    "Πετσένιες" => &["Πε", "τσέ", "νιες"],
-    "Πετσένιο" => &["Πε", "τσέ", "νιο"],
-    "Πετσένιοι" => &["Πε", "τσέ", "νιοι"],
-    "Πετσένιος" => &["Πε", "τσέ", "νιος"],
-    "Πετσένιου" => &["Πε", "τσέ", "νιου"],
-    "Πετσένιους" => &["Πε", "τσέ", "νιους"],
-    "Πετσένιων" => &["Πε", "τσέ", "νιων"],
-    "Πετσετάκια" => &["Πε", "τσε", "τά", "κια"],
-    "Πετσετένια" => &["Πε", "τσε", "τέ", "νια"],
-    "Πετσετένιας" => &["Πε", "τσε", "τέ", "νιας"],
-    "Πετσετένιε" => &["Πε", "τσε", "τέ", "νιε"],
-    "Πετσετένιες" => &["Πε", "τσε", "τέ", "νιες"],
-    "Πετσετένιο" => &["Πε", "τσε", "τέ", "νιο"],
-    "Πετσετένιοι" => &["Πε", "τσε", "τέ", "νιοι"],
-    "Πετσετένιος" => &["Πε", "τσε", "τέ", "νιος"],
-    "Πετσετένιου" => &["Πε", "τσε", "τέ", "νιου"],
-    "Πετσετένιους" => &["Πε", "τσε", "τέ", "νιους"],
-    "Πετσετένιων" => &["Πε", "τσε", "τέ", "νιων"],
-    "Πευκάκια" => &["Πευ", "κά", "κια"],
-    "Πεφταστέρια" => &["Πε", "φτα", "στέ", "ρια"],
-    "Πηγαδάκια" => &["Πη", "γα", "δά", "κια"],
-    "Πηγάδια" => &["Πη", "γά", "δια"],
-    "Πηγαδίσια" => &["Πη", "γα", "δί", "σια"],
-    "Πηγαδίσιας" => &["Πη", "γα", "δί", "σιας"],
-    "Πηγαδίσιε" => &["Πη", "γα", "δί", "σιε"],
-    "Πηγαδίσιες" => &["Πη", "γα", "δί", "σιες"],
-    "Πηγαδίσιο" => &["Πη", "γα", "δί", "σιο"],
-    "Πηγαδίσιοι" => &["Πη", "γα", "δί", "σιοι"],
-    "Πηγαδίσιος" => &["Πη", "γα", "δί", "σιος"],
-    "Πηγαδίσιου" => &["Πη", "γα", "δί", "σιου"],
-    "Πηγαδίσιους" => &["Πη", "γα", "δί", "σιους"],
-    "Πηγαδίσιων" => &["Πη", "γα", "δί", "σιων"],
-    "Πηγούνια" => &["Πη", "γού", "νια"],
-    "Πηδηματάκια" => &["Πη", "δη", "μα", "τά", "κια"],
-    "Πηλένια" => &["Πη", "λέ", "νια"],
-    "Πηλένιας" => &["Πη", "λέ", "νιας"],
-    "Πηλένιε" => &["Πη", "λέ", "νιε"],
-    "Πηλένιες" => &["Πη", "λέ", "νιες"],
-    "Πηλένιο" => &["Πη", "λέ", "νιο"],
-    "Πηλένιοι" => &["Πη", "λέ", "νιοι"],
-    "Πηλένιος" => &["Πη", "λέ", "νιος"],
-    "Πηλένιου" => &["Πη", "λέ", "νιου"],
-    "Πηλένιους" => &["Πη", "λέ", "νιους"],
-    "Πηλένιων" => &["Πη", "λέ", "νιων"],
-    "Πηλοφόρια" => &["Πη", "λο", "φό", "ρια"],
-    "Πηρούνια" => &["Πη", "ρού", "νια"],
-    "Πηχάκια" => &["Πη", "χά", "κια"],
-    "Πια" => &["Πια"],
-    "Πιαστράκια" => &["Πι", "α", "στρά", "κια"],
-    "Πιατάκια" => &["Πι", "α", "τά", "κια"],
-    "Πιατελάκια" => &["Πι", "α", "τε", "λά", "κια"],
-    "Πιγούνια" => &["Πι", "γού", "νια"],
-    "Πιει" => &["Πιει"],
-    "Πιεις" => &["Πιεις"],
-    "Πιες" => &["Πιες"],
-    "Πιη" => &["Πιη"],
-    "Πιης" => &["Πιης"],
-    "Πιθάρια" => &["Πι", "θά", "ρια"],
-    "Πιθηκάκια" => &["Πι", "θη", "κά", "κια"],
-    "Πιθηκίσια" => &["Πι", "θη", "κί", "σια"],
-    "Πιθηκίσιας" => &["Πι", "θη", "κί", "σιας"],
-    "Πιθηκίσιε" => &["Πι", "θη", "κί", "σιε"],
-    "Πιθηκίσιες" => &["Πι", "θη", "κί", "σιες"],
-    "Πιθηκίσιο" => &["Πι", "θη", "κί", "σιο"],
-    "Πιθηκίσιοι" => &["Πι", "θη", "κί", "σιοι"],
-    "Πιθηκίσιος" => &["Πι", "θη", "κί", "σιος"],
-    "Πιθηκίσιου" => &["Πι", "θη", "κί", "σιου"],
-    "Πιθηκίσιους" => &["Πι", "θη", "κί", "σιους"],
-    "Πιθηκίσιων" => &["Πι", "θη", "κί", "σιων"],
-    "Πιλάφια" => &["Πι", "λά", "φια"],
-    "Πινακάκια" => &["Πι", "να", "κά", "κια"],
-    "Πινάκια" => &["Πι", "νά", "κια"],
-    "Πινελάκια" => &["Πι", "νε", "λά", "κια"],
-    "Πιο" => &["Πιο"],
-    "Πιόνια" => &["Πι", "ό", "νια"],
-    "Πιουν" => &["Πιουν"],
-    "Πιπέρια" => &["Πι", "πέ", "ρια"],
-    "Πιπίνια" => &["Πι", "πί", "νια"],
-    "Πιρουνάκια" => &["Πι", "ρου", "νά", "κια"],
-    "Πιρούνια" => &["Πι", "ρού", "νια"],
-    "Πιστολάκια" => &["Πι", "στο", "λά", "κια"],
-    "Πιστόλια" => &["Πι", "στό", "λια"],
-    "Πιστολίδια" => &["Πι", "στο", "λί", "δια"],
-    "Πιστόνια" => &["Πι", "στό", "νια"],
-    "Πισωγάζια" => &["Πι", "σω", "γά", "ζια"],
-    "Πιτσιρίκια" => &["Πι", "τσι", "ρί", "κια"],
-    "Πιτσουνάκια" => &["Πι", "τσου", "νά", "κια"],
-    "Πιτσούνια" => &["Πι", "τσού", "νια"],
-    "Πιω" => &["Πιω"],
-    "Πλακάκια" => &["Πλα", "κά", "κια"],
-    "Πλακομούνια" => &["Πλα", "κο", "μού", "νια"],
-    "Πλανίδια" => &["Πλα", "νί", "δια"],
-    "Πλαστήρια" => &["Πλα", "στή", "ρια"],
-    "Πλατάνια" => &["Πλα", "τά", "νια"],
-    "Πλατινένια" => &["Πλα", "τι", "νέ", "νια"],
-    "Πλατινένιας" => &["Πλα", "τι", "νέ", "νιας"],
-    "Πλατινένιε" => &["Πλα", "τι", "νέ", "νιε"],
-    "Πλατινένιες" => &["Πλα", "τι", "νέ", "νιες"],
-    "Πλατινένιο" => &["Πλα", "τι", "νέ", "νιο"],
-    "Πλατινένιοι" => &["Πλα", "τι", "νέ", "νιοι"],
-    "Πλατινένιος" => &["Πλα", "τι", "νέ", "νιος"],
-    "Πλατινένιου" => &["Πλα", "τι", "νέ", "νιου"],
-    "Πλατινένιους" => &["Πλα", "τι", "νέ", "νιους"],
-    "Πλατινένιων" => &["Πλα", "τι", "νέ", "νιων"],
-    "Πλεια" => &["Πλεια"],
-    "Πλειο" => &["Πλειο"],
-    "Πλεμάτια" => &["Πλε", "μά", "τια"],
-    "Πλεμόνια" => &["Πλε", "μό", "νια"],
-    "Πλεμπάγια" => &["Πλε", "μπά", "για"],
-    "Πλεμπάγιας" => &["Πλε", "μπά", "γιας"],
-    "Πλεμπάγιες" => &["Πλε", "μπά", "γιες"],
-    "Πλέρια" => &["Πλέ", "ρια"],
-    "Πλέριας" => &["Πλέ", "ριας"],
-    "Πλέριε" => &["Πλέ", "ριε"],
-    "Πλέριες" => &["Πλέ", "ριες"],
-    "Πλέριο" => &["Πλέ", "ριο"],
-    "Πλέριοι" => &["Πλέ", "ριοι"],
-    "Πλέριος" => &["Πλέ", "ριος"],
-    "Πλέριου" => &["Πλέ", "ριου"],
-    "Πλέριους" => &["Πλέ", "ριους"],
-    "Πλέριων" => &["Πλέ", "ριων"],
-    "Πλια" => &["Πλια"],
-    "Πλιγούρια" => &["Πλι", "γού", "ρια"],
-    "Πλιο" => &["Πλιο"],
-    "Πλοκάμια" => &["Πλο", "κά", "μια"],
-    "Πλουμίδια" => &["Πλου", "μί", "δια"],
-    "Πνευμόνια" => &["Πνευ", "μό", "νια"],
-    "Ποδαράκια" => &["Πο", "δα", "ρά", "κια"],
-    "Ποδάρια" => &["Πο", "δά", "ρια"],
-    "Ποδηλατάκια" => &["Πο", "δη", "λα", "τά", "κια"],
-    "Πόδια" => &["Πό", "δια"],
-    "Ποδοκόπια" => &["Πο", "δο", "κό", "πια"],
-    "Ποια" => &["Ποια"],
-    "Ποιαν" => &["Ποιαν"],
-    "Ποιας" => &["Ποιας"],
-    "Ποιε" => &["Ποιε"],
-    "Ποιες" => &["Ποιες"],
-    "Ποιηματάκια" => &["Ποι", "η", "μα", "τά", "κια"],
-    "Ποιο" => &["Ποιο"],
-    "Ποιοι" => &["Ποιοι"],
-    "Ποιον" => &["Ποιον"],
-    "Ποιος" => &["Ποιος"],
-    "Ποιου" => &["Ποιου"],
-    "Ποιους" => &["Ποιους"],
-    "Ποιων" => &["Ποιων"],
-    "Ποκάρια" => &["Πο", "κά", "ρια"],
-    "Πολυτρίχια" => &["Πο", "λυ", "τρί", "χια"],
-    "Πονάκια" => &["Πο", "νά", "κια"],
-    "Ποντικάκια" => &["Πο", "ντι", "κά", "κια"],
-    "Ποντίκια" => &["Πο", "ντί", "κια"],
-    "Ποντικίσια" => &["Πο", "ντι", "κί", "σια"],
-    "Ποντικίσιας" => &["Πο", "ντι", "κί", "σιας"],
-    "Ποντικίσιε" => &["Πο", "ντι", "κί", "σιε"],
-    "Ποντικίσιες" => &["Πο", "ντι", "κί", "σιες"],
-    "Ποντικίσιο" => &["Πο", "ντι", "κί", "σιο"],
-    "Ποντικίσιοι" => &["Πο", "ντι", "κί", "σιοι"],
-    "Ποντικίσιος" => &["Πο", "ντι", "κί", "σιος"],
-    "Ποντικίσιου" => &["Πο", "ντι", "κί", "σιου"],
-    "Ποντικίσιους" => &["Πο", "ντι", "κί", "σιους"],
-    "Ποντικίσιων" => &["Πο", "ντι", "κί", "σιων"],
-    "Πορτάκια" => &["Πορ", "τά", "κια"],
-    "Πορτοκαλάκια" => &["Πορ", "το", "κα", "λά", "κια"],
-    "Πορτοκάλια" => &["Πορ", "το", "κά", "λια"],
-    "Πορτοφολάκια" => &["Πορ", "το", "φο", "λά", "κια"],
-    "Πορτοφόλια" => &["Πορ", "το", "φό", "λια"],
-    "Πορφυρένια" => &["Πορ", "φυ", "ρέ", "νια"],
-    "Πορφυρένιας" => &["Πορ", "φυ", "ρέ", "νιας"],
-    "Πορφυρένιε" => &["Πορ", "φυ", "ρέ", "νιε"],
-    "Πορφυρένιες" => &["Πορ", "φυ", "ρέ", "νιες"],
-    "Πορφυρένιο" => &["Πορ", "φυ", "ρέ", "νιο"],
-    "Πορφυρένιοι" => &["Πορ", "φυ", "ρέ", "νιοι"],
-    "Πορφυρένιος" => &["Πορ", "φυ", "ρέ", "νιος"],
-    "Πορφυρένιου" => &["Πορ", "φυ", "ρέ", "νιου"],
-    "Πορφυρένιους" => &["Πορ", "φυ", "ρέ", "νιους"],
-    "Πορφυρένιων" => &["Πορ", "φυ", "ρέ", "νιων"],
-    "Ποτάκια" => &["Πο", "τά", "κια"],
-    "Ποταμάκια" => &["Πο", "τα", "μά", "κια"],
-    "Ποτάμια" => &["Πο", "τά", "μια"],
-    "Ποταμίσια" => &["Πο", "τα", "μί", "σια"],
-    "Ποταμίσιας" => &["Πο", "τα", "μί", "σιας"],
-    "Ποταμίσιε" => &["Πο", "τα", "μί", "σιε"],
-    "Ποταμίσιες" => &["Πο", "τα", "μί", "σιες"],
-    "Ποταμίσιο" => &["Πο", "τα", "μί", "σιο"],
-    "Ποταμίσιοι" => &["Πο", "τα", "μί", "σιοι"],
-    "Ποταμίσιος" => &["Πο", "τα", "μί", "σιος"],
-    "Ποταμίσιου" => &["Πο", "τα", "μί", "σιου"],
-    "Ποταμίσιους" => &["Πο", "τα", "μί", "σιους"],
-    "Ποταμίσιων" => &["Πο", "τα", "μί", "σιων"],
-    "Ποτηράκια" => &["Πο", "τη", "ρά", "κια"],
-    "Ποτήρια" => &["Πο", "τή", "ρια"],
-    "Ποτιστήρια" => &["Πο", "τι", "στή", "ρια"],
-    "Πουκαμισάκια" => &["Που", "κα", "μι", "σά", "κια"],
-    "Πουλάκια" => &["Που", "λά", "κια"],
-    "Πουλάκιας" => &["Που", "λά", "κιας"],
-    "Πουλάκιες" => &["Που", "λά", "κιες"],
-    "Πουλαράκια" => &["Που", "λα", "ρά", "κια"],
-    "Πουλάρια" => &["Που", "λά", "ρια"],
-    "Πούλια" => &["Πού", "λια"],
-    "Πούλιας" => &["Πού", "λιας"],
-    "Πούλιες" => &["Πού", "λιες"],
-    "Πουλοβεράκια" => &["Που", "λο", "βε", "ρά", "κια"],
-    "Πουπουλένια" => &["Που", "που", "λέ", "νια"],
-    "Πουπουλένιας" => &["Που", "που", "λέ", "νιας"],
-    "Πουπουλένιε" => &["Που", "που", "λέ", "νιε"],
-    "Πουπουλένιες" => &["Που", "που", "λέ", "νιες"],
-    "Πουπουλένιο" => &["Που", "που", "λέ", "νιο"],
-    "Πουπουλένιοι" => &["Που", "που", "λέ", "νιοι"],
-    "Πουπουλένιος" => &["Που", "που", "λέ", "νιος"],
-    "Πουπουλένιου" => &["Που", "που", "λέ", "νιου"],
-    "Πουπουλένιους" => &["Που", "που", "λέ", "νιους"],
-    "Πουπουλένιων" => &["Που", "που", "λέ", "νιων"],
-    "Πουράκια" => &["Που", "ρά", "κια"],
-    "Πουρνάρια" => &["Πουρ", "νά", "ρια"],
-    "Πούσια" => &["Πού", "σια"],
-    "Πουσταρέλια" => &["Που", "στα", "ρέ", "λια"],
-    "Πραγματάκια" => &["Πραγ", "μα", "τά", "κια"],
-    "Πραμάτεια" => &["Πρα", "μά", "τεια"],
-    "Πραμάτειας" => &["Πρα", "μά", "τειας"],
-    "Πραμάτειες" => &["Πρα", "μά", "τειες"],
-    "Πρεβάζια" => &["Πρε", "βά", "ζια"],
-    "Πρεζάκια" => &["Πρε", "ζά", "κια"],
-    "Πρεζάκιας" => &["Πρε", "ζά", "κιας"],
-    "Πρεζάκιες" => &["Πρε", "ζά", "κιες"],
-    "Πρεζόνια" => &["Πρε", "ζό", "νια"],
-    "Πρέκια" => &["Πρέ", "κια"],
-    "Πρινάρια" => &["Πρι", "νά", "ρια"],
-    "Πρινένια" => &["Πρι", "νέ", "νια"],
-    "Πρινένιας" => &["Πρι", "νέ", "νιας"],
-    "Πρινένιε" => &["Πρι", "νέ", "νιε"],
-    "Πρινένιες" => &["Πρι", "νέ", "νιες"],
-    "Πρινένιο" => &["Πρι", "νέ", "νιο"],
-    "Πρινένιοι" => &["Πρι", "νέ", "νιοι"],
-    "Πρινένιος" => &["Πρι", "νέ", "νιος"],
-    "Πρινένιου" => &["Πρι", "νέ", "νιου"],
-    "Πρινένιους" => &["Πρι", "νέ", "νιους"],
-    "Πρινένιων" => &["Πρι", "νέ", "νιων"],
-    "Πριονάκια" => &["Πρι", "ο", "νά", "κια"],
-    "Πριόνια" => &["Πρι", "ό", "νια"],
-    "Πριονίδια" => &["Πρι", "ο", "νί", "δια"],
-    "Προβασκάνια" => &["Προ", "βα", "σκά", "νια"],
-    "Προβατάκια" => &["Προ", "βα", "τά", "κια"],
-    "Προβατένια" => &["Προ", "βα", "τέ", "νια"],
-    "Προβατένιας" => &["Προ", "βα", "τέ", "νιας"],
-    "Προβατένιε" => &["Προ", "βα", "τέ", "νιε"],
-    "Προβατένιες" => &["Προ", "βα", "τέ", "νιες"],
-    "Προβατένιο" => &["Προ", "βα", "τέ", "νιο"],
-    "Προβατένιοι" => &["Προ", "βα", "τέ", "νιοι"],
-    "Προβατένιος" => &["Προ", "βα", "τέ", "νιος"],
-    "Προβατένιου" => &["Προ", "βα", "τέ", "νιου"],
-    "Προβατένιους" => &["Προ", "βα", "τέ", "νιους"],
-    "Προβατένιων" => &["Προ", "βα", "τέ", "νιων"],
-    "Προβατίσια" => &["Προ", "βα", "τί", "σια"],
-    "Προβατίσιας" => &["Προ", "βα", "τί", "σιας"],
-    "Προβατίσιε" => &["Προ", "βα", "τί", "σιε"],
-    "Προβατίσιες" => &["Προ", "βα", "τί", "σιες"],
-    "Προβατίσιο" => &["Προ", "βα", "τί", "σιο"],
-    "Προβατίσιοι" => &["Προ", "βα", "τί", "σιοι"],
-    "Προβατίσιος" => &["Προ", "βα", "τί", "σιος"],
-    "Προβατίσιου" => &["Προ", "βα", "τί", "σιου"],
-    "Προβατίσιους" => &["Προ", "βα", "τί", "σιους"],
-    "Προβατίσιων" => &["Προ", "βα", "τί", "σιων"],
-    "Πρόβεια" => &["Πρό", "βεια"],
-    "Πρόβειας" => &["Πρό", "βειας"],
-    "Πρόβειε" => &["Πρό", "βειε"],
-    "Πρόβειες" => &["Πρό", "βειες"],
-    "Πρόβειο" => &["Πρό", "βειο"],
-    "Πρόβειοι" => &["Πρό", "βειοι"],
-    "Πρόβειος" => &["Πρό", "βειος"],
-    "Πρόβειου" => &["Πρό", "βειου"],
-    "Πρόβειους" => &["Πρό", "βειους"],
-    "Πρόβειων" => &["Πρό", "βειων"],
-    "Προβληματάκια" => &["Προ", "βλη", "μα", "τά", "κια"],
-    "Προβοκάτσια" => &["Προ", "βο", "κά", "τσια"],
-    "Προβοκάτσιας" => &["Προ", "βο", "κά", "τσιας"],
-    "Προβοκάτσιες" => &["Προ", "βο", "κά", "τσιες"],
-    "Προγόνια" => &["Προ", "γό", "νια"],
-    "Προγούλια" => &["Προ", "γού", "λια"],
-    "Προεδριλίκια" => &["Προ", "ε", "δρι", "λί", "κια"],
-    "Προζύμια" => &["Προ", "ζύ", "μια"],
-    "Προκάκια" => &["Προ", "κά", "κια"],
-    "Προκοίλια" => &["Προ", "κοί", "λια"],
-    "Προλετάρια" => &["Προ", "λε", "τά", "ρια"],
-    "Προλετάριας" => &["Προ", "λε", "τά", "ριας"],
-    "Προλετάριες" => &["Προ", "λε", "τά", "ριες"],
-    "Προσευχητάρια" => &["Προ", "σευ", "χη", "τά", "ρια"],
-    "Προσκεφαλάδια" => &["Προ", "σκε", "φα", "λά", "δια"],
-    "Προσκεφάλια" => &["Προ", "σκε", "φά", "λια"],
-    "Προσκυνητάρια" => &["Προ", "σκυ", "νη", "τά", "ρια"],
-    "Προσκυνοχάρτια" => &["Προ", "σκυ", "νο", "χάρ", "τια"],
-    "Προσόψια" => &["Προ", "σό", "ψια"],
-    "Προσφάγια" => &["Προ", "σφά", "για"],
-    "Προσφυγάκια" => &["Προ", "σφυ", "γά", "κια"],
-    "Προσωπάκια" => &["Προ", "σω", "πά", "κια"],
-    "Πρωιμάδια" => &["Πρω", "ι", "μά", "δια"],
-    "Πρωτάκια" => &["Πρω", "τά", "κια"],
-    "Πρωτοβρόχια" => &["Πρω", "το", "βρό", "χια"],
-    "Πρωτοκαπετάνιε" => &["Πρω", "το", "κα", "πε", "τά", "νιε"],
-    "Πρωτοκαπετάνιο" => &["Πρω", "το", "κα", "πε", "τά", "νιο"],
-    "Πρωτοκαπετάνιοι" => &["Πρω", "το", "κα", "πε", "τά", "νιοι"],
-    "Πρωτοκαπετάνιος" => &["Πρω", "το", "κα", "πε", "τά", "νιος"],
-    "Πρωτοκαπετάνιου" => &["Πρω", "το", "κα", "πε", "τά", "νιου"],
-    "Πρωτοκαπετάνιους" => &["Πρω", "το", "κα", "πε", "τά", "νιους"],
-    "Πρωτοκαπετάνιων" => &["Πρω", "το", "κα", "πε", "τά", "νιων"],
-    "Πυξάρια" => &["Πυ", "ξά", "ρια"],
-    "Πυροφάνια" => &["Πυ", "ρο", "φά", "νια"],
-    "Ραβαΐσια" => &["Ρα", "βα", "ΐ", "σια"],
-    "Ραβασάκια" => &["Ρα", "βα", "σά", "κια"],
-    "Ραβδάκια" => &["Ρα", "βδά", "κια"],
-    "Ραβδιστήρια" => &["Ρα", "βδι", "στή", "ρια"],
-    "Ραδίκια" => &["Ρα", "δί", "κια"],
-    "Ραμαζάνια" => &["Ρα", "μα", "ζά", "νια"],
-    "Ραντεβουδάκια" => &["Ρα", "ντε", "βου", "δά", "κια"],
-    "Ραντιστήρια" => &["Ρα", "ντι", "στή", "ρια"],
-    "Ραπανάκια" => &["Ρα", "πα", "νά", "κια"],
-    "Ραπάνια" => &["Ρα", "πά", "νια"],
-    "Ράφια" => &["Ρά", "φια"],
-    "Ραχάτια" => &["Ρα", "χά", "τια"],
-    "Ραχατλίκια" => &["Ρα", "χα", "τλί", "κια"],
-    "Ρεβένια" => &["Ρε", "βέ", "νια"],
-    "Ρεβιθάκια" => &["Ρε", "βι", "θά", "κια"],
-    "Ρεβίθια" => &["Ρε", "βί", "θια"],
-    "Ρεζίλια" => &["Ρε", "ζί", "λια"],
-    "Ρεζιλίκια" => &["Ρε", "ζι", "λί", "κια"],
-    "Ρείκια" => &["Ρεί", "κια"],
-    "Ρέλια" => &["Ρέ", "λια"],
-    "Ρεμάλια" => &["Ρε", "μά", "λια"],
-    "Ρεματάκια" => &["Ρε", "μα", "τά", "κια"],
-    "Ρεπάνια" => &["Ρε", "πά", "νια"],
-    "Ρέπια" => &["Ρέ", "πια"],
-    "Ρετάλια" => &["Ρε", "τά", "λια"],
-    "Ρετσέλια" => &["Ρε", "τσέ", "λια"],
-    "Ρετσίνια" => &["Ρε", "τσί", "νια"],
-    "Ρημάδια" => &["Ρη", "μά", "δια"],
-    "Ρημοκλήσια" => &["Ρη", "μο", "κλή", "σια"],
-    "Ριάλια" => &["Ρι", "ά", "λια"],
-    "Ριζάφτια" => &["Ρι", "ζά", "φτια"],
-    "Ριζικάρια" => &["Ρι", "ζι", "κά", "ρια"],
-    "Ριζοβούνια" => &["Ρι", "ζο", "βού", "νια"],
-    "Ριζοβράχια" => &["Ρι", "ζο", "βρά", "χια"],
-    "Ριζοδόντια" => &["Ρι", "ζο", "δό", "ντια"],
-    "Ριζοχώρια" => &["Ρι", "ζο", "χώ", "ρια"],
-    "Ρινίδια" => &["Ρι", "νί", "δια"],
-    "Ρινιστήρια" => &["Ρι", "νι", "στή", "ρια"],
-    "Ριπίδια" => &["Ρι", "πί", "δια"],
-    "Ρίφια" => &["Ρί", "φια"],
-    "Ροβίθια" => &["Ρο", "βί", "θια"],
-    "Ροδάμια" => &["Ρο", "δά", "μια"],
-    "Ροδάνια" => &["Ρο", "δά", "νια"],
-    "Ροδένια" => &["Ρο", "δέ", "νια"],
-    "Ροδένιας" => &["Ρο", "δέ", "νιας"],
-    "Ροδένιε" => &["Ρο", "δέ", "νιε"],
-    "Ροδένιες" => &["Ρο", "δέ", "νιες"],
-    "Ροδένιο" => &["Ρο", "δέ", "νιο"],
-    "Ροδένιοι" => &["Ρο", "δέ", "νιοι"],
-    "Ροδένιος" => &["Ρο", "δέ", "νιος"],
-    "Ροδένιου" => &["Ρο", "δέ", "νιου"],
-    "Ροδένιους" => &["Ρο", "δέ", "νιους"],
-    "Ροδένιων" => &["Ρο", "δέ", "νιων"],
-    "Ρόδια" => &["Ρό", "δια"],
-    "Ρόιδι" => &["Ρόι", "δι"],
-    "Ρόιδια" => &["Ρόι", "δια"],
-    "Ροϊδιού" => &["Ροϊ", "διού"],
-    "Ροϊδιών" => &["Ροϊ", "διών"],
-    "Ροκάνια" => &["Ρο", "κά", "νια"],
-    "Ροκανίδια" => &["Ρο", "κα", "νί", "δια"],
-    "Ρολόγια" => &["Ρο", "λό", "για"],
-    "Ροσόλια" => &["Ρο", "σό", "λια"],
-    "Ρούβλια" => &["Ρού", "βλια"],
-    "Ρουθούνια" => &["Ρου", "θού", "νια"],
-    "Ρουμάνια" => &["Ρου", "μά", "νια"],
-    "Ρούμια" => &["Ρού", "μια"],
-    "Ρουμπινένια" => &["Ρου", "μπι", "νέ", "νια"],
-    "Ρουμπινένιας" => &["Ρου", "μπι", "νέ", "νιας"],
-    "Ρουμπινένιε" => &["Ρου", "μπι", "νέ", "νιε"],
-    "Ρουμπινένιες" => &["Ρου", "μπι", "νέ", "νιες"],
-    "Ρουμπινένιο" => &["Ρου", "μπι", "νέ", "νιο"],
-    "Ρουμπινένιοι" => &["Ρου", "μπι", "νέ", "νιοι"],
-    "Ρουμπινένιος" => &["Ρου", "μπι", "νέ", "νιος"],
-    "Ρουμπινένιου" => &["Ρου", "μπι", "νέ", "νιου"],
-    "Ρουμπινένιους" => &["Ρου", "μπι", "νέ", "νιους"],
-    "Ρουμπινένιων" => &["Ρου", "μπι", "νέ", "νιων"],
-    "Ρουμπίνια" => &["Ρου", "μπί", "νια"],
-    "Ρουπάκια" => &["Ρου", "πά", "κια"],
-    "Ρούπια" => &["Ρού", "πια"],
-    "Ρουσφέτια" => &["Ρου", "σφέ", "τια"],
-    "Ρουχαλάκια" => &["Ρου", "χα", "λά", "κια"],
-    "Ρυάκια" => &["Ρυ", "ά", "κια"],
-    "Ρυζάκια" => &["Ρυ", "ζά", "κια"],
-    "Ρύζια" => &["Ρύ", "ζια"],
-    "Ρωγοβύζια" => &["Ρω", "γο", "βύ", "ζια"],
-    "Σαγανάκια" => &["Σα", "γα", "νά", "κια"],
-    "Σαγάνια" => &["Σα", "γά", "νια"],
-    "Σαγιάκια" => &["Σα", "γι", "ά", "κια"],
-    "Σαγκουίνια" => &["Σα", "γκου", "ί", "νια"],
-    "Σαγόνια" => &["Σα", "γό", "νια"],
-    "Σαγούλια" => &["Σα", "γού", "λια"],
-    "Σαζάνια" => &["Σα", "ζά", "νια"],
-    "Σαΐνια" => &["Σα", "ΐ", "νια"],
-    "Σακάκια" => &["Σα", "κά", "κια"],
-    "Σακατιλίκια" => &["Σα", "κα", "τι", "λί", "κια"],
-    "Σακουλάκια" => &["Σα", "κου", "λά", "κια"],
-    "Σακούλια" => &["Σα", "κού", "λια"],
-    "Σακουλίσια" => &["Σα", "κου", "λί", "σια"],
-    "Σακουλίσιας" => &["Σα", "κου", "λί", "σιας"],
-    "Σακουλίσιε" => &["Σα", "κου", "λί", "σιε"],
-    "Σακουλίσιες" => &["Σα", "κου", "λί", "σιες"],
-    "Σακουλίσιο" => &["Σα", "κου", "λί", "σιο"],
-    "Σακουλίσιοι" => &["Σα", "κου", "λί", "σιοι"],
-    "Σακουλίσιος" => &["Σα", "κου", "λί", "σιος"],
-    "Σακουλίσιου" => &["Σα", "κου", "λί", "σιου"],
-    "Σακουλίσιους" => &["Σα", "κου", "λί", "σιους"],
-    "Σακουλίσιων" => &["Σα", "κου", "λί", "σιων"],
-    "Σαλάμια" => &["Σα", "λά", "μια"],
-    "Σαλάχια" => &["Σα", "λά", "χια"],
-    "Σαλβάρια" => &["Σαλ", "βά", "ρια"],
-    "Σαλέπια" => &["Σα", "λέ", "πια"],
-    "Σάλια" => &["Σά", "λια"],
-    "Σαλιγκαράκια" => &["Σα", "λι", "γκα", "ρά", "κια"],
-    "Σαλιγκάρια" => &["Σα", "λι", "γκά", "ρια"],
-    "Σάλιο" => &["Σά", "λιο"],
-    "Σάλιου" => &["Σά", "λιου"],
-    "Σάλιων" => &["Σά", "λιων"],
-    "Σαλονάκια" => &["Σα", "λο", "νά", "κια"],
-    "Σαλόνια" => &["Σα", "λό", "νια"],
-    "Σαμάρια" => &["Σα", "μά", "ρια"],
-    "Σαμαροσκούτια" => &["Σα", "μα", "ρο", "σκού", "τια"],
-    "Σαμιαμίδια" => &["Σα", "μι", "α", "μί", "δια"],
-    "Σαμιαμίθια" => &["Σα", "μι", "α", "μί", "θια"],
-    "Σαμντάνια" => &["Σα", "μντά", "νια"],
-    "Σαμοβάρια" => &["Σα", "μο", "βά", "ρια"],
-    "Σαμούρια" => &["Σα", "μού", "ρια"],
-    "Σαμπάνια" => &["Σα", "μπά", "νια"],
-    "Σαμπάνιας" => &["Σα", "μπά", "νιας"],
-    "Σαμπάνιες" => &["Σα", "μπά", "νιες"],
-    "Σανδάλια" => &["Σαν", "δά", "λια"],
-    "Σανιδάκια" => &["Σα", "νι", "δά", "κια"],
-    "Σανιδένια" => &["Σα", "νι", "δέ", "νια"],
-    "Σανιδένιας" => &["Σα", "νι", "δέ", "νιας"],
-    "Σανιδένιε" => &["Σα", "νι", "δέ", "νιε"],
-    "Σανιδένιες" => &["Σα", "νι", "δέ", "νιες"],
-    "Σανιδένιο" => &["Σα", "νι", "δέ", "νιο"],
-    "Σανιδένιοι" => &["Σα", "νι", "δέ", "νιοι"],
-    "Σανιδένιος" => &["Σα", "νι", "δέ", "νιος"],
-    "Σανιδένιου" => &["Σα", "νι", "δέ", "νιου"],
-    "Σανιδένιους" => &["Σα", "νι", "δέ", "νιους"],
-    "Σανιδένιων" => &["Σα", "νι", "δέ", "νιων"],
-    "Σανίδια" => &["Σα", "νί", "δια"],
-    "Σαντάλια" => &["Σα", "ντά", "λια"],
-    "Σαντζάκια" => &["Σα", "ντζά", "κια"],
-    "Σαντούρια" => &["Σα", "ντού", "ρια"],
-    "Σάπια" => &["Σά", "πια"],
-    "Σάπιας" => &["Σά", "πιας"],
-    "Σάπιε" => &["Σά", "πιε"],
-    "Σάπιες" => &["Σά", "πιες"],
-    "Σάπιο" => &["Σά", "πιο"],
-    "Σάπιοι" => &["Σά", "πιοι"],
-    "Σάπιος" => &["Σά", "πιος"],
-    "Σάπιου" => &["Σά", "πιου"],
-    "Σάπιους" => &["Σά", "πιους"],
-    "Σάπιων" => &["Σά", "πιων"],
-    "Σαπούνια" => &["Σα", "πού", "νια"],
-    "Σαραβαλάκια" => &["Σα", "ρα", "βα", "λά", "κια"],
-    "Σαράγια" => &["Σα", "ρά", "για"],
-    "Σαράια" => &["Σα", "ρά", "ια"],
-    "Σαράκια" => &["Σα", "ρά", "κια"],
-    "Σαραφλίκια" => &["Σα", "ρα", "φλί", "κια"],
-    "Σαρδελοκούτια" => &["Σαρ", "δε", "λο", "κού", "τια"],
-    "Σαρίδια" => &["Σα", "ρί", "δια"],
-    "Σαρίκια" => &["Σα", "ρί", "κια"],
-    "Σαυρίδια" => &["Σαυ", "ρί", "δια"],
-    "Σαχάνια" => &["Σα", "χά", "νια"],
-    "Σβηστήρια" => &["Σβη", "στή", "ρια"],
-    "Σβουράκια" => &["Σβου", "ρά", "κια"],
-    "Σεγκούνια" => &["Σε", "γκού", "νια"],
-    "Σεκλέτια" => &["Σε", "κλέ", "τια"],
-    "Σεκόγια" => &["Σε", "κό", "για"],
-    "Σεκόγιας" => &["Σε", "κό", "γιας"],
-    "Σεκόγιες" => &["Σε", "κό", "γιες"],
-    "Σελάχια" => &["Σε", "λά", "χια"],
-    "Σελίνια" => &["Σε", "λί", "νια"],
-    "Σεμιγδαλένια" => &["Σε", "μιγ", "δα", "λέ", "νια"],
-    "Σεμιγδαλένιας" => &["Σε", "μιγ", "δα", "λέ", "νιας"],
-    "Σεμιγδαλένιε" => &["Σε", "μιγ", "δα", "λέ", "νιε"],
-    "Σεμιγδαλένιες" => &["Σε", "μιγ", "δα", "λέ", "νιες"],
-    "Σεμιγδαλένιο" => &["Σε", "μιγ", "δα", "λέ", "νιο"],
-    "Σεμιγδαλένιοι" => &["Σε", "μιγ", "δα", "λέ", "νιοι"],
-    "Σεμιγδαλένιος" => &["Σε", "μιγ", "δα", "λέ", "νιος"],
-    "Σεμιγδαλένιου" => &["Σε", "μιγ", "δα", "λέ", "νιου"],
-    "Σεμιγδαλένιους" => &["Σε", "μιγ", "δα", "λέ", "νιους"],
-    "Σεμιγδαλένιων" => &["Σε", "μιγ", "δα", "λέ", "νιων"],
-    "Σεμιγδάλια" => &["Σε", "μιγ", "δά", "λια"],
-    "Σένια" => &["Σέ", "νια"],
-    "Σένιας" => &["Σέ", "νιας"],
-    "Σένιε" => &["Σέ", "νιε"],
-    "Σένιες" => &["Σέ", "νιες"],
-    "Σένιο" => &["Σέ", "νιο"],
-    "Σένιοι" => &["Σέ", "νιοι"],
-    "Σένιος" => &["Σέ", "νιος"],
-    "Σένιου" => &["Σέ", "νιου"],
-    "Σένιους" => &["Σέ", "νιους"],
-    "Σένιων" => &["Σέ", "νιων"],
-    "Σεντεφένια" => &["Σε", "ντε", "φέ", "νια"],
-    "Σεντεφένιας" => &["Σε", "ντε", "φέ", "νιας"],
-    "Σεντεφένιε" => &["Σε", "ντε", "φέ", "νιε"],
-    "Σεντεφένιες" => &["Σε", "ντε", "φέ", "νιες"],
-    "Σεντεφένιο" => &["Σε", "ντε", "φέ", "νιο"],
-    "Σεντεφένιοι" => &["Σε", "ντε", "φέ", "νιοι"],
-    "Σεντεφένιος" => &["Σε", "ντε", "φέ", "νιος"],
-    "Σεντεφένιου" => &["Σε", "ντε", "φέ", "νιου"],
-    "Σεντεφένιους" => &["Σε", "ντε", "φέ", "νιους"],
-    "Σεντεφένιων" => &["Σε", "ντε", "φέ", "νιων"],
-    "Σεντέφια" => &["Σε", "ντέ", "φια"],
-    "Σεντόνια" => &["Σε", "ντό", "νια"],
-    "Σεντούκια" => &["Σε", "ντού", "κια"],
-    "Σέντσια" => &["Σέ", "ντσια"],
-    "Σεράγια" => &["Σε", "ρά", "για"],
-    "Σεργιάνια" => &["Σερ", "γι", "ά", "νια"],
-    "Σερμπέτια" => &["Σερ", "μπέ", "τια"],
-    "Σεφέρια" => &["Σε", "φέ", "ρια"],
-    "Σημαδάκια" => &["Ση", "μα", "δά", "κια"],
-    "Σημάδια" => &["Ση", "μά", "δια"],
-    "Σημαιάκια" => &["Ση", "μαι", "ά", "κια"],
-    "Σηματάκια" => &["Ση", "μα", "τά", "κια"],
-    "Σησάμια" => &["Ση", "σά", "μια"],
-    "Σιγκούνια" => &["Σι", "γκού", "νια"],
-    "Σιδεράκια" => &["Σι", "δε", "ρά", "κια"],
-    "Σιδερένια" => &["Σι", "δε", "ρέ", "νια"],
-    "Σιδερένιας" => &["Σι", "δε", "ρέ", "νιας"],
-    "Σιδερένιε" => &["Σι", "δε", "ρέ", "νιε"],
-    "Σιδερένιες" => &["Σι", "δε", "ρέ", "νιες"],
-    "Σιδερένιο" => &["Σι", "δε", "ρέ", "νιο"],
-    "Σιδερένιοι" => &["Σι", "δε", "ρέ", "νιοι"],
-    "Σιδερένιος" => &["Σι", "δε", "ρέ", "νιος"],
-    "Σιδερένιου" => &["Σι", "δε", "ρέ", "νιου"],
-    "Σιδερένιους" => &["Σι", "δε", "ρέ", "νιους"],
-    "Σιδερένιων" => &["Σι", "δε", "ρέ", "νιων"],
-    "Σικλέτια" => &["Σι", "κλέ", "τια"],
-    "Σιμιγδαλένια" => &["Σι", "μιγ", "δα", "λέ", "νια"],
-    "Σιμιγδαλένιας" => &["Σι", "μιγ", "δα", "λέ", "νιας"],
-    "Σιμιγδαλένιε" => &["Σι", "μιγ", "δα", "λέ", "νιε"],
-    "Σιμιγδαλένιες" => &["Σι", "μιγ", "δα", "λέ", "νιες"],
-    "Σιμιγδαλένιο" => &["Σι", "μιγ", "δα", "λέ", "νιο"],
-    "Σιμιγδαλένιοι" => &["Σι", "μιγ", "δα", "λέ", "νιοι"],
-    "Σιμιγδαλένιος" => &["Σι", "μιγ", "δα", "λέ", "νιος"],
-    "Σιμιγδαλένιου" => &["Σι", "μιγ", "δα", "λέ", "νιου"],
-    "Σιμιγδαλένιους" => &["Σι", "μιγ", "δα", "λέ", "νιους"],
-    "Σιμιγδαλένιων" => &["Σι", "μιγ", "δα", "λέ", "νιων"],
-    "Σιμιγδάλια" => &["Σι", "μιγ", "δά", "λια"],
-    "Σιμίτια" => &["Σι", "μί", "τια"],
-    "Σινάπια" => &["Σι", "νά", "πια"],
-    "Σινάφια" => &["Σι", "νά", "φια"],
-    "Σιντεφένια" => &["Σι", "ντε", "φέ", "νια"],
-    "Σιντεφένιας" => &["Σι", "ντε", "φέ", "νιας"],
-    "Σιντεφένιε" => &["Σι", "ντε", "φέ", "νιε"],
-    "Σιντεφένιες" => &["Σι", "ντε", "φέ", "νιες"],
-    "Σιντεφένιο" => &["Σι", "ντε", "φέ", "νιο"],
-    "Σιντεφένιοι" => &["Σι", "ντε", "φέ", "νιοι"],
-    "Σιντεφένιος" => &["Σι", "ντε", "φέ", "νιος"],
-    "Σιντεφένιου" => &["Σι", "ντε", "φέ", "νιου"],
-    "Σιντεφένιους" => &["Σι", "ντε", "φέ", "νιους"],
-    "Σιντεφένιων" => &["Σι", "ντε", "φέ", "νιων"],
-    "Σιντέφια" => &["Σι", "ντέ", "φια"],
-    "Σιντριβάνια" => &["Σι", "ντρι", "βά", "νια"],
-    "Σιορ" => &["Σιορ"],
-    "Σιρίτια" => &["Σι", "ρί", "τια"],
-    "Σιρόπια" => &["Σι", "ρό", "πια"],
-    "Σιταρένια" => &["Σι", "τα", "ρέ", "νια"],
-    "Σιταρένιας" => &["Σι", "τα", "ρέ", "νιας"],
-    "Σιταρένιε" => &["Σι", "τα", "ρέ", "νιε"],
-    "Σιταρένιες" => &["Σι", "τα", "ρέ", "νιες"],
-    "Σιταρένιο" => &["Σι", "τα", "ρέ", "νιο"],
-    "Σιταρένιοι" => &["Σι", "τα", "ρέ", "νιοι"],
-    "Σιταρένιος" => &["Σι", "τα", "ρέ", "νιος"],
-    "Σιταρένιου" => &["Σι", "τα", "ρέ", "νιου"],
-    "Σιταρένιους" => &["Σι", "τα", "ρέ", "νιους"],
-    "Σιταρένιων" => &["Σι", "τα", "ρέ", "νιων"],
-    "Σιτάρια" => &["Σι", "τά", "ρια"],
-    "Σιταρίσια" => &["Σι", "τα", "ρί", "σια"],
-    "Σιταρίσιας" => &["Σι", "τα", "ρί", "σιας"],
-    "Σιταρίσιε" => &["Σι", "τα", "ρί", "σιε"],
-    "Σιταρίσιες" => &["Σι", "τα", "ρί", "σιες"],
-    "Σιταρίσιο" => &["Σι", "τα", "ρί", "σιο"],
-    "Σιταρίσιοι" => &["Σι", "τα", "ρί", "σιοι"],
-    "Σιταρίσιος" => &["Σι", "τα", "ρί", "σιος"],
-    "Σιταρίσιου" => &["Σι", "τα", "ρί", "σιου"],
-    "Σιταρίσιους" => &["Σι", "τα", "ρί", "σιους"],
-    "Σιταρίσιων" => &["Σι", "τα", "ρί", "σιων"],
-    "Σιτζίμια" => &["Σι", "τζί", "μια"],
-    "Σιφόνια" => &["Σι", "φό", "νια"],
-    "Σιφούνια" => &["Σι", "φού", "νια"],
-    "Σκάγια" => &["Σκά", "για"],
-    "Σκαθάρια" => &["Σκα", "θά", "ρια"],
-    "Σκάκια" => &["Σκά", "κια"],
-    "Σκαλάκια" => &["Σκα", "λά", "κια"],
-    "Σκαλιστήρια" => &["Σκα", "λι", "στή", "ρια"],
-    "Σκαλοπάτια" => &["Σκα", "λο", "πά", "τια"],
-    "Σκαλτσούνια" => &["Σκαλ", "τσού", "νια"],
-    "Σκαμνάκια" => &["Σκα", "μνά", "κια"],
-    "Σκαμπίλια" => &["Σκα", "μπί", "λια"],
-    "Σκαρπίνια" => &["Σκαρ", "πί", "νια"],
-    "Σκατένια" => &["Σκα", "τέ", "νια"],
-    "Σκατένιας" => &["Σκα", "τέ", "νιας"],
-    "Σκατένιε" => &["Σκα", "τέ", "νιε"],
-    "Σκατένιες" => &["Σκα", "τέ", "νιες"],
-    "Σκατένιο" => &["Σκα", "τέ", "νιο"],
-    "Σκατένιοι" => &["Σκα", "τέ", "νιοι"],
-    "Σκατένιος" => &["Σκα", "τέ", "νιος"],
-    "Σκατένιου" => &["Σκα", "τέ", "νιου"],
-    "Σκατένιους" => &["Σκα", "τέ", "νιους"],
-    "Σκατένιων" => &["Σκα", "τέ", "νιων"],
-    "Σκαφίδια" => &["Σκα", "φί", "δια"],
-    "Σκέλια" => &["Σκέ", "λια"],
-    "Σκελίδια" => &["Σκε", "λί", "δια"],
-    "Σκεπάρνια" => &["Σκε", "πάρ", "νια"],
-    "Σκεπαστήρια" => &["Σκε", "πα", "στή", "ρια"],
-    "Σκιάδια" => &["Σκι", "ά", "δια"],
-    "Σκιουράκια" => &["Σκι", "ου", "ρά", "κια"],
-    "Σκιτσάκια" => &["Σκι", "τσά", "κια"],
-    "Σκλαβάκια" => &["Σκλα", "βά", "κια"],
-    "Σκοινάκια" => &["Σκοι", "νά", "κια"],
-    "Σκοινένια" => &["Σκοι", "νέ", "νια"],
-    "Σκοινένιας" => &["Σκοι", "νέ", "νιας"],
-    "Σκοινένιε" => &["Σκοι", "νέ", "νιε"],
-    "Σκοινένιες" => &["Σκοι", "νέ", "νιες"],
-    "Σκοινένιο" => &["Σκοι", "νέ", "νιο"],
-    "Σκοινένιοι" => &["Σκοι", "νέ", "νιοι"],
-    "Σκοινένιος" => &["Σκοι", "νέ", "νιος"],
-    "Σκοινένιου" => &["Σκοι", "νέ", "νιου"],
-    "Σκοινένιους" => &["Σκοι", "νέ", "νιους"],
-    "Σκοινένιων" => &["Σκοι", "νέ", "νιων"],
-    "Σκολιαρούδια" => &["Σκο", "λι", "α", "ρού", "δια"],
-    "Σκολύμπρια" => &["Σκο", "λύ", "μπρια"],
-    "Σκονάκια" => &["Σκο", "νά", "κια"],
-    "Σκορδάκια" => &["Σκορ", "δά", "κια"],
-    "Σκορδοστούμπια" => &["Σκορ", "δο", "στού", "μπια"],
-    "Σκορπίδια" => &["Σκορ", "πί", "δια"],
-    "Σκορποχώρια" => &["Σκορ", "πο", "χώ", "ρια"],
-    "Σκοτάδια" => &["Σκο", "τά", "δια"],
-    "Σκοτίδια" => &["Σκο", "τί", "δια"],
-    "Σκουλαρικάκια" => &["Σκου", "λα", "ρι", "κά", "κια"],
-    "Σκουλαρίκια" => &["Σκου", "λα", "ρί", "κια"],
-    "Σκουληκάκια" => &["Σκου", "λη", "κά", "κια"],
-    "Σκουλήκια" => &["Σκου", "λή", "κια"],
-    "Σκουπίδια" => &["Σκου", "πί", "δια"],
-    "Σκουτάρια" => &["Σκου", "τά", "ρια"],
-    "Σκουτέλια" => &["Σκου", "τέ", "λια"],
-    "Σκουφάκια" => &["Σκου", "φά", "κια"],
-    "Σκούφια" => &["Σκού", "φια"],
-    "Σκούφιας" => &["Σκού", "φιας"],
-    "Σκούφιες" => &["Σκού", "φιες"],
-    "Σκρίνια" => &["Σκρί", "νια"],
-    "Σκρίνιο" => &["Σκρί", "νιο"],
-    "Σκρίνιου" => &["Σκρί", "νιου"],
-    "Σκρίνιων" => &["Σκρί", "νιων"],
-    "Σκυλάκια" => &["Σκυ", "λά", "κια"],
-    "Σκυλίσια" => &["Σκυ", "λί", "σια"],
-    "Σκυλίσιας" => &["Σκυ", "λί", "σιας"],
-    "Σκυλίσιε" => &["Σκυ", "λί", "σιε"],
-    "Σκυλίσιες" => &["Σκυ", "λί", "σιες"],
-    "Σκυλίσιο" => &["Σκυ", "λί", "σιο"],
-    "Σκυλίσιοι" => &["Σκυ", "λί", "σιοι"],
-    "Σκυλίσιος" => &["Σκυ", "λί", "σιος"],
-    "Σκυλίσιου" => &["Σκυ", "λί", "σιου"],
-    "Σκυλίσιους" => &["Σκυ", "λί", "σιους"],
-    "Σκυλίσιων" => &["Σκυ", "λί", "σιων"],
-    "Σκυλολόια" => &["Σκυ", "λο", "λό", "ια"],
-    "Σλέπια" => &["Σ", "λέ", "πια"],
-    "Σλιπάκια" => &["Σ", "λι", "πά", "κια"],
-    "Σμαραγδένια" => &["Σμα", "ραγ", "δέ", "νια"],
-    "Σμαραγδένιας" => &["Σμα", "ραγ", "δέ", "νιας"],
-    "Σμαραγδένιε" => &["Σμα", "ραγ", "δέ", "νιε"],
-    "Σμαραγδένιες" => &["Σμα", "ραγ", "δέ", "νιες"],
-    "Σμαραγδένιο" => &["Σμα", "ραγ", "δέ", "νιο"],
-    "Σμαραγδένιοι" => &["Σμα", "ραγ", "δέ", "νιοι"],
-    "Σμαραγδένιος" => &["Σμα", "ραγ", "δέ", "νιος"],
-    "Σμαραγδένιου" => &["Σμα", "ραγ", "δέ", "νιου"],
-    "Σμαραγδένιους" => &["Σμα", "ραγ", "δέ", "νιους"],
-    "Σμαραγδένιων" => &["Σμα", "ραγ", "δέ", "νιων"],
-    "Σμαράγδια" => &["Σμα", "ράγ", "δια"],
-    "Σμάρια" => &["Σμά", "ρια"],
-    "Σμιγάδια" => &["Σμι", "γά", "δια"],
-    "Σμιλάρια" => &["Σμι", "λά", "ρια"],
-    "Σμυρίγλια" => &["Σμυ", "ρί", "γλια"],
-    "Σόγια" => &["Σό", "για"],
-    "Σοκάκια" => &["Σο", "κά", "κια"],
-    "Σοκολατένια" => &["Σο", "κο", "λα", "τέ", "νια"],
-    "Σοκολατένιας" => &["Σο", "κο", "λα", "τέ", "νιας"],
-    "Σοκολατένιε" => &["Σο", "κο", "λα", "τέ", "νιε"],
-    "Σοκολατένιες" => &["Σο", "κο", "λα", "τέ", "νιες"],
-    "Σοκολατένιο" => &["Σο", "κο", "λα", "τέ", "νιο"],
-    "Σοκολατένιοι" => &["Σο", "κο", "λα", "τέ", "νιοι"],
-    "Σοκολατένιος" => &["Σο", "κο", "λα", "τέ", "νιος"],
-    "Σοκολατένιου" => &["Σο", "κο", "λα", "τέ", "νιου"],
-    "Σοκολατένιους" => &["Σο", "κο", "λα", "τέ", "νιους"],
-    "Σοκολατένιων" => &["Σο", "κο", "λα", "τέ", "νιων"],
-    "Σορόπια" => &["Σο", "ρό", "πια"],
-    "Σορτσάκια" => &["Σορ", "τσά", "κια"],
-    "Σοσόνια" => &["Σο", "σό", "νια"],
-    "Σουβλάκια" => &["Σου", "βλά", "κια"],
-    "Σουλαντιστήρια" => &["Σου", "λα", "ντι", "στή", "ρια"],
-    "Σουλούπια" => &["Σου", "λού", "πια"],
-    "Σουραύλια" => &["Σου", "ραύ", "λια"],
-    "Σουρωτήρια" => &["Σου", "ρω", "τή", "ρια"],
-    "Σουσαμένια" => &["Σου", "σα", "μέ", "νια"],
-    "Σουσαμένιας" => &["Σου", "σα", "μέ", "νιας"],
-    "Σουσαμένιε" => &["Σου", "σα", "μέ", "νιε"],
-    "Σουσαμένιες" => &["Σου", "σα", "μέ", "νιες"],
-    "Σουσαμένιο" => &["Σου", "σα", "μέ", "νιο"],
-    "Σουσαμένιοι" => &["Σου", "σα", "μέ", "νιοι"],
-    "Σουσαμένιος" => &["Σου", "σα", "μέ", "νιος"],
-    "Σουσαμένιου" => &["Σου", "σα", "μέ", "νιου"],
-    "Σουσαμένιους" => &["Σου", "σα", "μέ", "νιους"],
-    "Σουσαμένιων" => &["Σου", "σα", "μέ", "νιων"],
-    "Σουσάμια" => &["Σου", "σά", "μια"],
-    "Σουσούμια" => &["Σου", "σού", "μια"],
-    "Σουτζουκάκια" => &["Σου", "τζου", "κά", "κια"],
-    "Σουτζούκια" => &["Σου", "τζού", "κια"],
-    "Σοφεράκια" => &["Σο", "φε", "ρά", "κια"],
-    "Σπανάκια" => &["Σπα", "νά", "κια"],
-    "Σπαράγγια" => &["Σπα", "ράγ", "για"],
-    "Σπερδούκλια" => &["Σπερ", "δού", "κλια"],
-    "Σπήλια" => &["Σπή", "λια"],
-    "Σπήλιο" => &["Σπή", "λιο"],
-    "Σπήλιου" => &["Σπή", "λιου"],
-    "Σπήλιων" => &["Σπή", "λιων"],
-    "Σπηρούνια" => &["Σπη", "ρού", "νια"],
-    "Σπιθουράκια" => &["Σπι", "θου", "ρά", "κια"],
-    "Σπιθούρια" => &["Σπι", "θού", "ρια"],
-    "Σπιράγια" => &["Σπι", "ρά", "για"],
-    "Σπιράγιο" => &["Σπι", "ρά", "γιο"],
-    "Σπιράγιου" => &["Σπι", "ρά", "γιου"],
-    "Σπιράγιων" => &["Σπι", "ρά", "γιων"],
-    "Σπιρούνια" => &["Σπι", "ρού", "νια"],
-    "Σπιρτοκούτια" => &["Σπιρ", "το", "κού", "τια"],
-    "Σπιτάκια" => &["Σπι", "τά", "κια"],
-    "Σπιτάλια" => &["Σπι", "τά", "λια"],
-    "Σπίτια" => &["Σπί", "τια"],
-    "Σπιτίσια" => &["Σπι", "τί", "σια"],
-    "Σπιτίσιας" => &["Σπι", "τί", "σιας"],
-    "Σπιτίσιε" => &["Σπι", "τί", "σιε"],
-    "Σπιτίσιες" => &["Σπι", "τί", "σιες"],
-    "Σπιτίσιο" => &["Σπι", "τί", "σιο"],
-    "Σπιτίσιοι" => &["Σπι", "τί", "σιοι"],
-    "Σπιτίσιος" => &["Σπι", "τί", "σιος"],
-    "Σπιτίσιου" => &["Σπι", "τί", "σιου"],
-    "Σπιτίσιους" => &["Σπι", "τί", "σιους"],
-    "Σπιτίσιων" => &["Σπι", "τί", "σιων"],
-    "Σποράκια" => &["Σπο", "ρά", "κια"],
-    "Σπόρια" => &["Σπό", "ρια"],
-    "Σποτάκια" => &["Σπο", "τά", "κια"],
-    "Σπουργίτια" => &["Σπουρ", "γί", "τια"],
-    "Σπυράκια" => &["Σπυ", "ρά", "κια"],
-    "Σταβάρια" => &["Στα", "βά", "ρια"],
-    "Σταλίκια" => &["Στα", "λί", "κια"],
-    "Σταμνάκια" => &["Στα", "μνά", "κια"],
-    "Σταρένια" => &["Στα", "ρέ", "νια"],
-    "Σταρένιας" => &["Στα", "ρέ", "νιας"],
-    "Σταρένιε" => &["Στα", "ρέ", "νιε"],
-    "Σταρένιες" => &["Στα", "ρέ", "νιες"],
-    "Σταρένιο" => &["Στα", "ρέ", "νιο"],
-    "Σταρένιοι" => &["Στα", "ρέ", "νιοι"],
-    "Σταρένιος" => &["Στα", "ρέ", "νιος"],
-    "Σταρένιου" => &["Στα", "ρέ", "νιου"],
-    "Σταρένιους" => &["Στα", "ρέ", "νιους"],
-    "Σταρένιων" => &["Στα", "ρέ", "νιων"],
-    "Στάρια" => &["Στά", "ρια"],
-    "Σταρίσια" => &["Στα", "ρί", "σια"],
-    "Σταρίσιας" => &["Στα", "ρί", "σιας"],
-    "Σταρίσιε" => &["Στα", "ρί", "σιε"],
-    "Σταρίσιες" => &["Στα", "ρί", "σιες"],
-    "Σταρίσιο" => &["Στα", "ρί", "σιο"],
-    "Σταρίσιοι" => &["Στα", "ρί", "σιοι"],
-    "Σταρίσιος" => &["Στα", "ρί", "σιος"],
-    "Σταρίσιου" => &["Στα", "ρί", "σιου"],
-    "Σταρίσιους" => &["Στα", "ρί", "σιους"],
-    "Σταρίσιων" => &["Στα", "ρί", "σιων"],
-    "Στασίδια" => &["Στα", "σί", "δια"],
-    "Σταυραδέλφια" => &["Σταυ", "ρα", "δέλ", "φια"],
-    "Σταυραδέρφια" => &["Σταυ", "ρα", "δέρ", "φια"],
-    "Σταυροδρόμια" => &["Σταυ", "ρο", "δρό", "μια"],
-    "Σταυροκόπια" => &["Σταυ", "ρο", "κό", "πια"],
-    "Σταυρουδάκια" => &["Σταυ", "ρου", "δά", "κια"],
-    "Σταφύλια" => &["Στα", "φύ", "λια"],
-    "Στάχυα" => &["Στά", "χυα"],
-    "Στειλιάρια" => &["Στει", "λι", "ά", "ρια"],
-    "Στέκια" => &["Στέ", "κια"],
-    "Στενάκια" => &["Στε", "νά", "κια"],
-    "Στεναχώρια" => &["Στε", "να", "χώ", "ρια"],
-    "Στεναχώριας" => &["Στε", "να", "χώ", "ριας"],
-    "Στεναχώριες" => &["Στε", "να", "χώ", "ριες"],
-    "Στενορύμια" => &["Στε", "νο", "ρύ", "μια"],
-    "Στενοχώρια" => &["Στε", "νο", "χώ", "ρια"],
-    "Στενοχώριας" => &["Στε", "νο", "χώ", "ριας"],
-    "Στενοχώριες" => &["Στε", "νο", "χώ", "ριες"],
-    "Στερνίσια" => &["Στερ", "νί", "σια"],
-    "Στερνίσιας" => &["Στερ", "νί", "σιας"],
-    "Στερνίσιε" => &["Στερ", "νί", "σιε"],
-    "Στερνίσιες" => &["Στερ", "νί", "σιες"],
-    "Στερνίσιο" => &["Στερ", "νί", "σιο"],
-    "Στερνίσιοι" => &["Στερ", "νί", "σιοι"],
-    "Στερνίσιος" => &["Στερ", "νί", "σιος"],
-    "Στερνίσιου" => &["Στερ", "νί", "σιου"],
-    "Στερνίσιους" => &["Στερ", "νί", "σιους"],
-    "Στερνίσιων" => &["Στερ", "νί", "σιων"],
-    "Στερνοπαίδια" => &["Στερ", "νο", "παί", "δια"],
-    "Στερνοπούλια" => &["Στερ", "νο", "πού", "λια"],
-    "Στεφανάκια" => &["Στε", "φα", "νά", "κια"],
-    "Στεφάνια" => &["Στε", "φά", "νια"],
-    "Στήθια" => &["Στή", "θια"],
-    "Στηθούρια" => &["Στη", "θού", "ρια"],
-    "Στημόνια" => &["Στη", "μό", "νια"],
-    "Στια" => &["Στια"],
-    "Στιας" => &["Στιας"],
-    "Στιβάλια" => &["Στι", "βά", "λια"],
-    "Στιβάνια" => &["Στι", "βά", "νια"],
-    "Στιες" => &["Στιες"],
-    "Στιχάκια" => &["Στι", "χά", "κια"],
-    "Στολίδια" => &["Στο", "λί", "δια"],
-    "Στοματάκια" => &["Στο", "μα", "τά", "κια"],
-    "Στομαχάκια" => &["Στο", "μα", "χά", "κια"],
-    "Στομάχια" => &["Στο", "μά", "χια"],
-    "Στόρια" => &["Στό", "ρια"],
-    "Στουπένια" => &["Στου", "πέ", "νια"],
-    "Στουπένιας" => &["Στου", "πέ", "νιας"],
-    "Στουπένιε" => &["Στου", "πέ", "νιε"],
-    "Στουπένιες" => &["Στου", "πέ", "νιες"],
-    "Στουπένιο" => &["Στου", "πέ", "νιο"],
-    "Στουπένιοι" => &["Στου", "πέ", "νιοι"],
-    "Στουπένιος" => &["Στου", "πέ", "νιος"],
-    "Στουπένιου" => &["Στου", "πέ", "νιου"],
-    "Στουπένιους" => &["Στου", "πέ", "νιους"],
-    "Στουπένιων" => &["Στου", "πέ", "νιων"],
-    "Στουπέτσια" => &["Στου", "πέ", "τσια"],
-    "Στουρνάρια" => &["Στουρ", "νά", "ρια"],
-    "Στραβάδια" => &["Στρα", "βά", "δια"],
-    "Στραγάλια" => &["Στρα", "γά", "λια"],
-    "Στραγγιστήρια" => &["Στραγ", "γι", "στή", "ρια"],
-    "Στρατιωτάκια" => &["Στρα", "τι", "ω", "τά", "κια"],
-    "Στρείδια" => &["Στρεί", "δια"],
-    "Στριμωξίδια" => &["Στρι", "μω", "ξί", "δια"],
-    "Στριφτάρια" => &["Στρι", "φτά", "ρια"],
-    "Στριφτήρια" => &["Στρι", "φτή", "ρια"],
-    "Στροφίλια" => &["Στρο", "φί", "λια"],
-    "Στρωσίδια" => &["Στρω", "σί", "δια"],
-    "Στυλοπάτια" => &["Στυ", "λο", "πά", "τια"],
-    "Συγγενάδια" => &["Συγ", "γε", "νά", "δια"],
-    "Συγγενολόια" => &["Συγ", "γε", "νο", "λό", "ια"],
-    "Συγνεφάκια" => &["Συ", "γνε", "φά", "κια"],
-    "Συγχωροχάρτια" => &["Συγ", "χω", "ρο", "χάρ", "τια"],
-    "Συκωτάκια" => &["Συ", "κω", "τά", "κια"],
-    "Συκώτια" => &["Συ", "κώ", "τια"],
-    "Συμπόνια" => &["Συ", "μπό", "νια"],
-    "Συμπόνιας" => &["Συ", "μπό", "νιας"],
-    "Συμπόνιες" => &["Συ", "μπό", "νιες"],
-    "Συναξάρια" => &["Συ", "να", "ξά", "ρια"],
-    "Συνάχια" => &["Συ", "νά", "χια"],
-    "Συνέρια" => &["Συ", "νέ", "ρια"],
-    "Συνέριο" => &["Συ", "νέ", "ριο"],
-    "Συνέριου" => &["Συ", "νέ", "ριου"],
-    "Συνέριων" => &["Συ", "νέ", "ριων"],
-    "Συννεφάκια" => &["Συν", "νε", "φά", "κια"],
-    "Συννεφένια" => &["Συν", "νε", "φέ", "νια"],
-    "Συννεφένιας" => &["Συν", "νε", "φέ", "νιας"],
-    "Συννεφένιε" => &["Συν", "νε", "φέ", "νιε"],
-    "Συννεφένιες" => &["Συν", "νε", "φέ", "νιες"],
-    "Συννεφένιο" => &["Συν", "νε", "φέ", "νιο"],
-    "Συννεφένιοι" => &["Συν", "νε", "φέ", "νιοι"],
-    "Συννεφένιος" => &["Συν", "νε", "φέ", "νιος"],
-    "Συννεφένιου" => &["Συν", "νε", "φέ", "νιου"],
-    "Συννεφένιους" => &["Συν", "νε", "φέ", "νιους"],
-    "Συννεφένιων" => &["Συν", "νε", "φέ", "νιων"],
-    "Συντριβάνια" => &["Συ", "ντρι", "βά", "νια"],
-    "Συντρίμμια" => &["Συ", "ντρίμ", "μια"],
-    "Συντρόφια" => &["Συ", "ντρό", "φια"],
-    "Συρμάκια" => &["Συρ", "μά", "κια"],
-    "Συρματένια" => &["Συρ", "μα", "τέ", "νια"],
-    "Συρματένιας" => &["Συρ", "μα", "τέ", "νιας"],
-    "Συρματένιε" => &["Συρ", "μα", "τέ", "νιε"],
-    "Συρματένιες" => &["Συρ", "μα", "τέ", "νιες"],
-    "Συρματένιο" => &["Συρ", "μα", "τέ", "νιο"],
-    "Συρματένιοι" => &["Συρ", "μα", "τέ", "νιοι"],
-    "Συρματένιος" => &["Συρ", "μα", "τέ", "νιος"],
-    "Συρματένιου" => &["Συρ", "μα", "τέ", "νιου"],
-    "Συρματένιους" => &["Συρ", "μα", "τέ", "νιους"],
-    "Συρματένιων" => &["Συρ", "μα", "τέ", "νιων"],
-    "Συρτάκια" => &["Συρ", "τά", "κια"],
-    "Συρτάρια" => &["Συρ", "τά", "ρια"],
-    "Σύσκια" => &["Σύ", "σκια"],
-    "Σύσκιο" => &["Σύ", "σκιο"],
-    "Σύσκιου" => &["Σύ", "σκιου"],
-    "Σύσκιων" => &["Σύ", "σκιων"],
-    "Συχώρια" => &["Συ", "χώ", "ρια"],
-    "Συχώριο" => &["Συ", "χώ", "ριο"],
-    "Συχώριου" => &["Συ", "χώ", "ριου"],
-    "Συχώριων" => &["Συ", "χώ", "ριων"],
-    "Συχωροχάρτια" => &["Συ", "χω", "ρο", "χάρ", "τια"],
-    "Σφαγάδια" => &["Σφα", "γά", "δια"],
-    "Σφαγάρια" => &["Σφα", "γά", "ρια"],
-    "Σφαλάγγια" => &["Σφα", "λάγ", "για"],
-    "Σφαχτάρια" => &["Σφα", "χτά", "ρια"],
-    "Σφεντάμια" => &["Σφε", "ντά", "μια"],
-    "Σφερδούκλια" => &["Σφερ", "δού", "κλια"],
-    "Σφηνάκια" => &["Σφη", "νά", "κια"],
-    "Σφοντύλια" => &["Σφο", "ντύ", "λια"],
-    "Σφουγγαράκια" => &["Σφουγ", "γα", "ρά", "κια"],
-    "Σφουγγάρια" => &["Σφουγ", "γά", "ρια"],
-    "Σφυράκια" => &["Σφυ", "ρά", "κια"],
-    "Σχοινάκια" => &["Σχοι", "νά", "κια"],
-    "Σχολιαρούδια" => &["Σχο", "λι", "α", "ρού", "δια"],
-    "Σωληνάκια" => &["Σω", "λη", "νά", "κια"],
-    "Σωματάκια" => &["Σω", "μα", "τά", "κια"],
-    "Ταβάνια" => &["Τα", "βά", "νια"],
-    "Ταβατούρια" => &["Τα", "βα", "τού", "ρια"],
-    "Ταβερνάκια" => &["Τα", "βερ", "νά", "κια"],
-    "Ταβλάκια" => &["Τα", "βλά", "κια"],
-    "Τάβλια" => &["Τά", "βλια"],
-    "Ταγάρια" => &["Τα", "γά", "ρια"],
-    "Τάγια" => &["Τά", "για"],
-    "Τάγιας" => &["Τά", "γιας"],
-    "Τάγιες" => &["Τά", "γιες"],
-    "Ταίρια" => &["Ταί", "ρια"],
-    "Τακάκια" => &["Τα", "κά", "κια"],
-    "Τακίμια" => &["Τα", "κί", "μια"],
-    "Τακουνάκια" => &["Τα", "κου", "νά", "κια"],
-    "Τακούνια" => &["Τα", "κού", "νια"],
-    "Ταλαγάνια" => &["Τα", "λα", "γά", "νια"],
-    "Τάλια" => &["Τά", "λια"],
-    "Τάλιας" => &["Τά", "λιας"],
-    "Τάλιες" => &["Τά", "λιες"],
-    "Ταμάχια" => &["Τα", "μά", "χια"],
-    "Ταμπούρια" => &["Τα", "μπού", "ρια"],
-    "Ταξιδάκια" => &["Τα", "ξι", "δά", "κια"],
-    "Ταξίδια" => &["Τα", "ξί", "δια"],
-    "Τάπια" => &["Τά", "πια"],
-    "Τάπιας" => &["Τά", "πιας"],
-    "Τάπιες" => &["Τά", "πιες"],
-    "Τασάκια" => &["Τα", "σά", "κια"],
-    "Τάσια" => &["Τά", "σια"],
-    "Ταφταδένια" => &["Τα", "φτα", "δέ", "νια"],
-    "Ταφταδένιας" => &["Τα", "φτα", "δέ", "νιας"],
-    "Ταφταδένιε" => &["Τα", "φτα", "δέ", "νιε"],
-    "Ταφταδένιες" => &["Τα", "φτα", "δέ", "νιες"],
-    "Ταφταδένιο" => &["Τα", "φτα", "δέ", "νιο"],
-    "Ταφταδένιοι" => &["Τα", "φτα", "δέ", "νιοι"],
-    "Ταφταδένιος" => &["Τα", "φτα", "δέ", "νιος"],
-    "Ταφταδένιου" => &["Τα", "φτα", "δέ", "νιου"],
-    "Ταφταδένιους" => &["Τα", "φτα", "δέ", "νιους"],
-    "Ταφταδένιων" => &["Τα", "φτα", "δέ", "νιων"],
-    "Ταχίνια" => &["Τα", "χί", "νια"],
-    "Ταψάκια" => &["Τα", "ψά", "κια"],
-    "Τελατίνια" => &["Τε", "λα", "τί", "νια"],
-    "Τέλια" => &["Τέ", "λια"],
-    "Τεμπεσίρια" => &["Τε", "μπε", "σί", "ρια"],
-    "Τενεκεδάκια" => &["Τε", "νε", "κε", "δά", "κια"],
-    "Τενεκεδένια" => &["Τε", "νε", "κε", "δέ", "νια"],
-    "Τενεκεδένιας" => &["Τε", "νε", "κε", "δέ", "νιας"],
-    "Τενεκεδένιε" => &["Τε", "νε", "κε", "δέ", "νιε"],
-    "Τενεκεδένιες" => &["Τε", "νε", "κε", "δέ", "νιες"],
-    "Τενεκεδένιο" => &["Τε", "νε", "κε", "δέ", "νιο"],
-    "Τενεκεδένιοι" => &["Τε", "νε", "κε", "δέ", "νιοι"],
-    "Τενεκεδένιος" => &["Τε", "νε", "κε", "δέ", "νιος"],
-    "Τενεκεδένιου" => &["Τε", "νε", "κε", "δέ", "νιου"],
-    "Τενεκεδένιους" => &["Τε", "νε", "κε", "δέ", "νιους"],
-    "Τενεκεδένιων" => &["Τε", "νε", "κε", "δέ", "νιων"],
-    "Τερλίκια" => &["Τερ", "λί", "κια"],
-    "Τερτίπια" => &["Τερ", "τί", "πια"],
-    "Τεσσάρια" => &["Τεσ", "σά", "ρια"],
-    "Τέτοια" => &["Τέ", "τοια"],
-    "Τέτοιαν" => &["Τέ", "τοιαν"],
-    "Τέτοιας" => &["Τέ", "τοιας"],
-    "Τέτοιε" => &["Τέ", "τοιε"],
-    "Τέτοιες" => &["Τέ", "τοιες"],
-    "Τέτοιο" => &["Τέ", "τοιο"],
-    "Τέτοιοι" => &["Τέ", "τοιοι"],
-    "Τέτοιον" => &["Τέ", "τοιον"],
-    "Τέτοιος" => &["Τέ", "τοιος"],
-    "Τέτοιου" => &["Τέ", "τοιου"],
-    "Τέτοιους" => &["Τέ", "τοιους"],
-    "Τέτοιων" => &["Τέ", "τοιων"],
-    "Τετραγωνάκια" => &["Τε", "τρα", "γω", "νά", "κια"],
-    "Τεφαρίκια" => &["Τε", "φα", "ρί", "κια"],
-    "Τεφτέρια" => &["Τε", "φτέ", "ρια"],
-    "Τζάκια" => &["Τζά", "κια"],
-    "Τζαμένια" => &["Τζα", "μέ", "νια"],
-    "Τζαμένιας" => &["Τζα", "μέ", "νιας"],
-    "Τζαμένιε" => &["Τζα", "μέ", "νιε"],
-    "Τζαμένιες" => &["Τζα", "μέ", "νιες"],
-    "Τζαμένιο" => &["Τζα", "μέ", "νιο"],
-    "Τζαμένιοι" => &["Τζα", "μέ", "νιοι"],
-    "Τζαμένιος" => &["Τζα", "μέ", "νιος"],
-    "Τζαμένιου" => &["Τζα", "μέ", "νιου"],
-    "Τζαμένιους" => &["Τζα", "μέ", "νιους"],
-    "Τζαμένιων" => &["Τζα", "μέ", "νιων"],
-    "Τζάμια" => &["Τζά", "μια"],
-    "Τζαμιλίκια" => &["Τζα", "μι", "λί", "κια"],
-    "Τζαμλίκια" => &["Τζαμ", "λί", "κια"],
-    "Τζατζίκια" => &["Τζα", "τζί", "κια"],
-    "Τζια" => &["Τζια"],
-    "Τζιαντούγια" => &["Τζι", "α", "ντού", "για"],
-    "Τζιαντούγιας" => &["Τζι", "α", "ντού", "γιας"],
-    "Τζιαντούγιες" => &["Τζι", "α", "ντού", "γιες"],
-    "Τζιγέρια" => &["Τζι", "γέ", "ρια"],
-    "Τζιέρια" => &["Τζι", "έ", "ρια"],
-    "Τζιμάνια" => &["Τζι", "μά", "νια"],
-    "Τζίνια" => &["Τζί", "νια"],
-    "Τζιπάκια" => &["Τζι", "πά", "κια"],
-    "Τζιτζικάκια" => &["Τζι", "τζι", "κά", "κια"],
-    "Τζιτζίκια" => &["Τζι", "τζί", "κια"],
-    "Τζοβαΐρια" => &["Τζο", "βα", "ΐ", "ρια"],
-    "Τζόγια" => &["Τζό", "για"],
-    "Τζόγιας" => &["Τζό", "γιας"],
-    "Τζόγιες" => &["Τζό", "γιες"],
-    "Τζούφια" => &["Τζού", "φια"],
-    "Τζούφιας" => &["Τζού", "φιας"],
-    "Τζούφιε" => &["Τζού", "φιε"],
-    "Τζούφιες" => &["Τζού", "φιες"],
-    "Τζούφιο" => &["Τζού", "φιο"],
-    "Τζούφιοι" => &["Τζού", "φιοι"],
-    "Τζούφιος" => &["Τζού", "φιος"],
-    "Τζούφιου" => &["Τζού", "φιου"],
-    "Τζούφιους" => &["Τζού", "φιους"],
-    "Τζούφιων" => &["Τζού", "φιων"],
-    "Τηγανάκια" => &["Τη", "γα", "νά", "κια"],
-    "Τηγάνια" => &["Τη", "γά", "νια"],
-    "Τηλεορασάκια" => &["Τη", "λε", "ο", "ρα", "σά", "κια"],
-    "Τηλεορασάκιας" => &["Τη", "λε", "ο", "ρα", "σά", "κιας"],
-    "Τηλεορασάκιες" => &["Τη", "λε", "ο", "ρα", "σά", "κιες"],
-    "Τηλεπαιχνίδια" => &["Τη", "λε", "παι", "χνί", "δια"],
-    "Τιγράκια" => &["Τι", "γρά", "κια"],
-    "Τίλια" => &["Τί", "λια"],
-    "Τίλιο" => &["Τί", "λιο"],
-    "Τίλιου" => &["Τί", "λιου"],
-    "Τίλιων" => &["Τί", "λιων"],
-    "Τιμάρια" => &["Τι", "μά", "ρια"],
-    "Τιμόνια" => &["Τι", "μό", "νια"],
-    "Τιποτένια" => &["Τι", "πο", "τέ", "νια"],
-    "Τιποτένιας" => &["Τι", "πο", "τέ", "νιας"],
-    "Τιποτένιε" => &["Τι", "πο", "τέ", "νιε"],
-    "Τιποτένιες" => &["Τι", "πο", "τέ", "νιες"],
-    "Τιποτένιο" => &["Τι", "πο", "τέ", "νιο"],
-    "Τιποτένιοι" => &["Τι", "πο", "τέ", "νιοι"],
-    "Τιποτένιος" => &["Τι", "πο", "τέ", "νιος"],
-    "Τιποτένιου" => &["Τι", "πο", "τέ", "νιου"],
-    "Τιποτένιους" => &["Τι", "πο", "τέ", "νιους"],
-    "Τιποτένιων" => &["Τι", "πο", "τέ", "νιων"],
-    "Τιράγια" => &["Τι", "ρά", "για"],
-    "Τιράγιο" => &["Τι", "ρά", "γιο"],
-    "Τιράγιου" => &["Τι", "ρά", "γιου"],
-    "Τιράγιων" => &["Τι", "ρά", "γιων"],
-    "Τιρτίρια" => &["Τιρ", "τί", "ρια"],
-    "Τιτλάκια" => &["Τι", "τλά", "κια"],
-    "Τμηματάκια" => &["Τμη", "μα", "τά", "κια"],
-    "Τοκμάκια" => &["Τοκ", "μά", "κια"],
-    "Τομαρένια" => &["Το", "μα", "ρέ", "νια"],
-    "Τομαρένιας" => &["Το", "μα", "ρέ", "νιας"],
-    "Τομαρένιε" => &["Το", "μα", "ρέ", "νιε"],
-    "Τομαρένιες" => &["Το", "μα", "ρέ", "νιες"],
-    "Τομαρένιο" => &["Το", "μα", "ρέ", "νιο"],
-    "Τομαρένιοι" => &["Το", "μα", "ρέ", "νιοι"],
-    "Τομαρένιος" => &["Το", "μα", "ρέ", "νιος"],
-    "Τομαρένιου" => &["Το", "μα", "ρέ", "νιου"],
-    "Τομαρένιους" => &["Το", "μα", "ρέ", "νιους"],
-    "Τομαρένιων" => &["Το", "μα", "ρέ", "νιων"],
-    "Τομάρια" => &["Το", "μά", "ρια"],
-    "Τοξάκια" => &["Το", "ξά", "κια"],
-    "Τοπάζια" => &["Το", "πά", "ζια"],
-    "Τοπάκια" => &["Το", "πά", "κια"],
-    "Τόπια" => &["Τό", "πια"],
-    "Τορτίγια" => &["Τορ", "τί", "για"],
-    "Τορτίγιας" => &["Τορ", "τί", "γιας"],
-    "Τορτίγιες" => &["Τορ", "τί", "γιες"],
-    "Τουβλάκια" => &["Του", "βλά", "κια"],
-    "Τούγια" => &["Τού", "για"],
-    "Τούγιας" => &["Τού", "γιας"],
-    "Τούγιες" => &["Τού", "γιες"],
-    "Τουζλούκια" => &["Τουζ", "λού", "κια"],
-    "Τούλια" => &["Τού", "λια"],
-    "Τουλούμια" => &["Του", "λού", "μια"],
-    "Τουλουμίσια" => &["Του", "λου", "μί", "σια"],
-    "Τουλουμίσιας" => &["Του", "λου", "μί", "σιας"],
-    "Τουλουμίσιε" => &["Του", "λου", "μί", "σιε"],
-    "Τουλουμίσιες" => &["Του", "λου", "μί", "σιες"],
-    "Τουλουμίσιο" => &["Του", "λου", "μί", "σιο"],
-    "Τουλουμίσιοι" => &["Του", "λου", "μί", "σιοι"],
-    "Τουλουμίσιος" => &["Του", "λου", "μί", "σιος"],
-    "Τουλουμίσιου" => &["Του", "λου", "μί", "σιου"],
-    "Τουλουμίσιους" => &["Του", "λου", "μί", "σιους"],
-    "Τουλουμίσιων" => &["Του", "λου", "μί", "σιων"],
-    "Τουλουμοτύρια" => &["Του", "λου", "μο", "τύ", "ρια"],
-    "Τουλουπάνια" => &["Του", "λου", "πά", "νια"],
-    "Τουλπάνια" => &["Τουλ", "πά", "νια"],
-    "Τουμπελέκια" => &["Του", "μπε", "λέ", "κια"],
-    "Τουρκολόια" => &["Τουρ", "κο", "λό", "ια"],
-    "Τουρμπάνια" => &["Τουρ", "μπά", "νια"],
-    "Τουφέκια" => &["Του", "φέ", "κια"],
-    "Τουφεκίδια" => &["Του", "φε", "κί", "δια"],
-    "Τραγάκια" => &["Τρα", "γά", "κια"],
-    "Τραγίσια" => &["Τρα", "γί", "σια"],
-    "Τραγίσιας" => &["Τρα", "γί", "σιας"],
-    "Τραγίσιε" => &["Τρα", "γί", "σιε"],
-    "Τραγίσιες" => &["Τρα", "γί", "σιες"],
-    "Τραγίσιο" => &["Τρα", "γί", "σιο"],
-    "Τραγίσιοι" => &["Τρα", "γί", "σιοι"],
-    "Τραγίσιος" => &["Τρα", "γί", "σιος"],
-    "Τραγίσιου" => &["Τρα", "γί", "σιου"],
-    "Τραγίσιους" => &["Τρα", "γί", "σιους"],
-    "Τραγίσιων" => &["Τρα", "γί", "σιων"],
-    "Τραγουδάκια" => &["Τρα", "γου", "δά", "κια"],
-    "Τραγούδια" => &["Τρα", "γού", "δια"],
-    "Τραινάκια" => &["Τραι", "νά", "κια"],
-    "Τραπεζάκια" => &["Τρα", "πε", "ζά", "κια"],
-    "Τραπέζια" => &["Τρα", "πέ", "ζια"],
-    "Τρενάκια" => &["Τρε", "νά", "κια"],
-    "Τρεχαντήρια" => &["Τρε", "χα", "ντή", "ρια"],
-    "Τριαντάρια" => &["Τρι", "α", "ντά", "ρια"],
-    "Τριανταφυλλένια" => &["Τρι", "α", "ντα", "φυλ", "λέ", "νια"],
-    "Τριανταφυλλένιας" => &["Τρι", "α", "ντα", "φυλ", "λέ", "νιας"],
-    "Τριανταφυλλένιε" => &["Τρι", "α", "ντα", "φυλ", "λέ", "νιε"],
-    "Τριανταφυλλένιες" => &["Τρι", "α", "ντα", "φυλ", "λέ", "νιες"],
-    "Τριανταφυλλένιο" => &["Τρι", "α", "ντα", "φυλ", "λέ", "νιο"],
-    "Τριανταφυλλένιοι" => &["Τρι", "α", "ντα", "φυλ", "λέ", "νιοι"],
-    "Τριανταφυλλένιος" => &["Τρι", "α", "ντα", "φυλ", "λέ", "νιος"],
-    "Τριανταφυλλένιου" => &["Τρι", "α", "ντα", "φυλ", "λέ", "νιου"],
-    "Τριανταφυλλένιους" => &["Τρι", "α", "ντα", "φυλ", "λέ", "νιους"],
-    "Τριανταφυλλένιων" => &["Τρι", "α", "ντα", "φυλ", "λέ", "νιων"],
-    "Τριάρια" => &["Τρι", "ά", "ρια"],
-    "Τριβέλια" => &["Τρι", "βέ", "λια"],
-    "Τριβόλια" => &["Τρι", "βό", "λια"],
-    "Τριζόνια" => &["Τρι", "ζό", "νια"],
-    "Τρικέρια" => &["Τρι", "κέ", "ρια"],
-    "Τρικράνια" => &["Τρι", "κρά", "νια"],
-    "Τρίλια" => &["Τρί", "λια"],
-    "Τρίλιας" => &["Τρί", "λιας"],
-    "Τρίλιες" => &["Τρί", "λιες"],
-    "Τριόδια" => &["Τρι", "ό", "δια"],
-    "Τριποντάκια" => &["Τρι", "πο", "ντά", "κια"],
-    "Τριποντάκιας" => &["Τρι", "πο", "ντά", "κιας"],
-    "Τριποντάκιες" => &["Τρι", "πο", "ντά", "κιες"],
-    "Τριφυλλάκια" => &["Τρι", "φυλ", "λά", "κια"],
-    "Τριφύλλια" => &["Τρι", "φύλ", "λια"],
-    "Τροκάνια" => &["Τρο", "κά", "νια"],
-    "Τρομπόνια" => &["Τρο", "μπό", "νια"],
-    "Τροπάρια" => &["Τρο", "πά", "ρια"],
-    "Τρουφάκια" => &["Τρου", "φά", "κια"],
-    "Τρυγονάκια" => &["Τρυ", "γο", "νά", "κια"],
-    "Τρυγόνια" => &["Τρυ", "γό", "νια"],
-    "Τρυπάνια" => &["Τρυ", "πά", "νια"],
-    "Τρυπητήρια" => &["Τρυ", "πη", "τή", "ρια"],
-    "Τρύπια" => &["Τρύ", "πια"],
-    "Τρύπιας" => &["Τρύ", "πιας"],
-    "Τρύπιε" => &["Τρύ", "πιε"],
-    "Τρύπιες" => &["Τρύ", "πιες"],
-    "Τρύπιο" => &["Τρύ", "πιο"],
-    "Τρύπιοι" => &["Τρύ", "πιοι"],
-    "Τρύπιος" => &["Τρύ", "πιος"],
-    "Τρύπιου" => &["Τρύ", "πιου"],
-    "Τρύπιους" => &["Τρύ", "πιους"],
-    "Τρύπιων" => &["Τρύ", "πιων"],
-    "Τρυπογάζια" => &["Τρυ", "πο", "γά", "ζια"],
-    "Τσάγια" => &["Τσά", "για"],
-    "Τσαΐρια" => &["Τσα", "ΐ", "ρια"],
-    "Τσακάλια" => &["Τσα", "κά", "λια"],
-    "Τσακίδια" => &["Τσα", "κί", "δια"],
-    "Τσακμάκια" => &["Τσακ", "μά", "κια"],
-    "Τσαλιμάκια" => &["Τσα", "λι", "μά", "κια"],
-    "Τσαλίμια" => &["Τσα", "λί", "μια"],
-    "Τσάμια" => &["Τσά", "μια"],
-    "Τσαμπάκια" => &["Τσα", "μπά", "κια"],
-    "Τσαμπουκαλίκια" => &["Τσα", "μπου", "κα", "λί", "κια"],
-    "Τσανάκια" => &["Τσα", "νά", "κια"],
-    "Τσαντάκια" => &["Τσα", "ντά", "κια"],
-    "Τσαντάκιας" => &["Τσα", "ντά", "κιας"],
-    "Τσαντάκιες" => &["Τσα", "ντά", "κιες"],
-    "Τσαντίρια" => &["Τσα", "ντί", "ρια"],
-    "Τσαρδάκια" => &["Τσαρ", "δά", "κια"],
-    "Τσαρουχάκια" => &["Τσα", "ρου", "χά", "κια"],
-    "Τσαρούχια" => &["Τσα", "ρού", "χια"],
-    "Τσατάλια" => &["Τσα", "τά", "λια"],
-    "Τσεκουράκια" => &["Τσε", "κου", "ρά", "κια"],
-    "Τσεκούρια" => &["Τσε", "κού", "ρια"],
-    "Τσελίκια" => &["Τσε", "λί", "κια"],
-    "Τσεμπέρια" => &["Τσε", "μπέ", "ρια"],
-    "Τσεπάκια" => &["Τσε", "πά", "κια"],
-    "Τσέρκια" => &["Τσέρ", "κια"],
-    "Τσιγαράκια" => &["Τσι", "γα", "ρά", "κια"],
-    "Τσιγαριλίκια" => &["Τσι", "γα", "ρι", "λί", "κια"],
-    "Τσιγκελάκια" => &["Τσι", "γκε", "λά", "κια"],
-    "Τσιγκέλια" => &["Τσι", "γκέ", "λια"],
-    "Τσικρίκια" => &["Τσι", "κρί", "κια"],
-    "Τσίλια" => &["Τσί", "λια"],
-    "Τσίλιας" => &["Τσί", "λιας"],
-    "Τσίλιες" => &["Τσί", "λιες"],
-    "Τσιμεντένια" => &["Τσι", "με", "ντέ", "νια"],
-    "Τσιμεντένιας" => &["Τσι", "με", "ντέ", "νιας"],
-    "Τσιμεντένιε" => &["Τσι", "με", "ντέ", "νιε"],
-    "Τσιμεντένιες" => &["Τσι", "με", "ντέ", "νιες"],
-    "Τσιμεντένιο" => &["Τσι", "με", "ντέ", "νιο"],
-    "Τσιμεντένιοι" => &["Τσι", "με", "ντέ", "νιοι"],
-    "Τσιμεντένιος" => &["Τσι", "με", "ντέ", "νιος"],
-    "Τσιμεντένιου" => &["Τσι", "με", "ντέ", "νιου"],
-    "Τσιμεντένιους" => &["Τσι", "με", "ντέ", "νιους"],
-    "Τσιμεντένιων" => &["Τσι", "με", "ντέ", "νιων"],
-    "Τσιμπιδάκια" => &["Τσι", "μπι", "δά", "κια"],
-    "Τσιμπίδια" => &["Τσι", "μπί", "δια"],
-    "Τσιμπούκια" => &["Τσι", "μπού", "κια"],
-    "Τσιμπούρια" => &["Τσι", "μπού", "ρια"],
-    "Τσιμπουσάκια" => &["Τσι", "μπου", "σά", "κια"],
-    "Τσιμπούσια" => &["Τσι", "μπού", "σια"],
-    "Τσιράκια" => &["Τσι", "ρά", "κια"],
-    "Τσιριμόνια" => &["Τσι", "ρι", "μό", "νια"],
-    "Τσιριμόνιας" => &["Τσι", "ρι", "μό", "νιας"],
-    "Τσιριμόνιες" => &["Τσι", "ρι", "μό", "νιες"],
-    "Τσιρίσια" => &["Τσι", "ρί", "σια"],
-    "Τσίτια" => &["Τσί", "τια"],
-    "Τσιφλίκια" => &["Τσι", "φλί", "κια"],
-    "Τσιφτετέλια" => &["Τσι", "φτε", "τέ", "λια"],
-    "Τσογλάνια" => &["Τσο", "γλά", "νια"],
-    "Τσόλια" => &["Τσό", "λια"],
-    "Τσουβαλάκια" => &["Τσου", "βα", "λά", "κια"],
-    "Τσουβάλια" => &["Τσου", "βά", "λια"],
-    "Τσουκαλάκια" => &["Τσου", "κα", "λά", "κια"],
-    "Τσουκάλια" => &["Τσου", "κά", "λια"],
-    "Τσουλάκια" => &["Τσου", "λά", "κια"],
-    "Τσούλια" => &["Τσού", "λια"],
-    "Τσουλούφια" => &["Τσου", "λού", "φια"],
-    "Τσουράπια" => &["Τσου", "ρά", "πια"],
-    "Τσουρεκάκια" => &["Τσου", "ρε", "κά", "κια"],
-    "Τσουρέκια" => &["Τσου", "ρέ", "κια"],
-    "Τσουτσέκια" => &["Τσου", "τσέ", "κια"],
-    "Τσόφλια" => &["Τσό", "φλια"],
-    "Τυλιγάδια" => &["Τυ", "λι", "γά", "δια"],
-    "Τυλιχτάρια" => &["Τυ", "λι", "χτά", "ρια"],
-    "Τυράκια" => &["Τυ", "ρά", "κια"],
-    "Τυχεράκια" => &["Τυ", "χε", "ρά", "κια"],
-    "Τυχεράκιας" => &["Τυ", "χε", "ρά", "κιας"],
-    "Τυχεράκιες" => &["Τυ", "χε", "ρά", "κιες"],
-    "Υιε" => &["Υιε"],
-    "Υιο" => &["Υιο"],
-    "Υιοι" => &["Υιοι"],
-    "Υιος" => &["Υιος"],
-    "Υιου" => &["Υιου"],
-    "Υιους" => &["Υιους"],
-    "Υιων" => &["Υιων"],
-    "Υπαλληλίκια" => &["Υ", "παλ", "λη", "λί", "κια"],
-    "Υφάδια" => &["Υ", "φά", "δια"],
-    "Υφασματένια" => &["Υ", "φα", "σμα", "τέ", "νια"],
-    "Υφασματένιας" => &["Υ", "φα", "σμα", "τέ", "νιας"],
-    "Υφασματένιε" => &["Υ", "φα", "σμα", "τέ", "νιε"],
-    "Υφασματένιες" => &["Υ", "φα", "σμα", "τέ", "νιες"],
-    "Υφασματένιο" => &["Υ", "φα", "σμα", "τέ", "νιο"],
-    "Υφασματένιοι" => &["Υ", "φα", "σμα", "τέ", "νιοι"],
-    "Υφασματένιος" => &["Υ", "φα", "σμα", "τέ", "νιος"],
-    "Υφασματένιου" => &["Υ", "φα", "σμα", "τέ", "νιου"],
-    "Υφασματένιους" => &["Υ", "φα", "σμα", "τέ", "νιους"],
-    "Υφασματένιων" => &["Υ", "φα", "σμα", "τέ", "νιων"],
-    "Υψωματάκια" => &["Υ", "ψω", "μα", "τά", "κια"],
-    "Φαγοπότια" => &["Φα", "γο", "πό", "τια"],
-    "Φάδια" => &["Φά", "δια"],
-    "Φακελάκια" => &["Φα", "κε", "λά", "κια"],
-    "Φακιόλια" => &["Φα", "κι", "ό", "λια"],
-    "Φαλάγγια" => &["Φα", "λάγ", "για"],
-    "Φαμίλια" => &["Φα", "μί", "λια"],
-    "Φαμίλιας" => &["Φα", "μί", "λιας"],
-    "Φαμίλιες" => &["Φα", "μί", "λιες"],
-    "Φαναράκια" => &["Φα", "να", "ρά", "κια"],
-    "Φανάρια" => &["Φα", "νά", "ρια"],
-    "Φανελάκια" => &["Φα", "νε", "λά", "κια"],
-    "Φανελένια" => &["Φα", "νε", "λέ", "νια"],
-    "Φανελένιας" => &["Φα", "νε", "λέ", "νιας"],
-    "Φανελένιε" => &["Φα", "νε", "λέ", "νιε"],
-    "Φανελένιες" => &["Φα", "νε", "λέ", "νιες"],
-    "Φανελένιο" => &["Φα", "νε", "λέ", "νιο"],
-    "Φανελένιοι" => &["Φα", "νε", "λέ", "νιοι"],
-    "Φανελένιος" => &["Φα", "νε", "λέ", "νιος"],
-    "Φανελένιου" => &["Φα", "νε", "λέ", "νιου"],
-    "Φανελένιους" => &["Φα", "νε", "λέ", "νιους"],
-    "Φανελένιων" => &["Φα", "νε", "λέ", "νιων"],
-    "Φανελλένια" => &["Φα", "νελ", "λέ", "νια"],
-    "Φανελλένιας" => &["Φα", "νελ", "λέ", "νιας"],
-    "Φανελλένιε" => &["Φα", "νελ", "λέ", "νιε"],
-    "Φανελλένιες" => &["Φα", "νελ", "λέ", "νιες"],
-    "Φανελλένιο" => &["Φα", "νελ", "λέ", "νιο"],
-    "Φανελλένιοι" => &["Φα", "νελ", "λέ", "νιοι"],
-    "Φανελλένιος" => &["Φα", "νελ", "λέ", "νιος"],
-    "Φανελλένιου" => &["Φα", "νελ", "λέ", "νιου"],
-    "Φανελλένιους" => &["Φα", "νελ", "λέ", "νιους"],
-    "Φανελλένιων" => &["Φα", "νελ", "λέ", "νιων"],
-    "Φαράγγια" => &["Φα", "ράγ", "για"],
-    "Φαράσια" => &["Φα", "ρά", "σια"],
-    "Φαρμάκια" => &["Φαρ", "μά", "κια"],
-    "Φαρφουρένια" => &["Φαρ", "φου", "ρέ", "νια"],
-    "Φαρφουρένιας" => &["Φαρ", "φου", "ρέ", "νιας"],
-    "Φαρφουρένιε" => &["Φαρ", "φου", "ρέ", "νιε"],
-    "Φαρφουρένιες" => &["Φαρ", "φου", "ρέ", "νιες"],
-    "Φαρφουρένιο" => &["Φαρ", "φου", "ρέ", "νιο"],
-    "Φαρφουρένιοι" => &["Φαρ", "φου", "ρέ", "νιοι"],
-    "Φαρφουρένιος" => &["Φαρ", "φου", "ρέ", "νιος"],
-    "Φαρφουρένιου" => &["Φαρ", "φου", "ρέ", "νιου"],
-    "Φαρφουρένιους" => &["Φαρ", "φου", "ρέ", "νιους"],
-    "Φαρφουρένιων" => &["Φαρ", "φου", "ρέ", "νιων"],
-    "Φασολάκια" => &["Φα", "σο", "λά", "κια"],
-    "Φασόλια" => &["Φα", "σό", "λια"],
-    "Φασούλια" => &["Φα", "σού", "λια"],
-    "Φεγγαράκια" => &["Φεγ", "γα", "ρά", "κια"],
-    "Φεγγαρένια" => &["Φεγ", "γα", "ρέ", "νια"],
-    "Φεγγαρένιας" => &["Φεγ", "γα", "ρέ", "νιας"],
-    "Φεγγαρένιε" => &["Φεγ", "γα", "ρέ", "νιε"],
-    "Φεγγαρένιες" => &["Φεγ", "γα", "ρέ", "νιες"],
-    "Φεγγαρένιο" => &["Φεγ", "γα", "ρέ", "νιο"],
-    "Φεγγαρένιοι" => &["Φεγ", "γα", "ρέ", "νιοι"],
-    "Φεγγαρένιος" => &["Φεγ", "γα", "ρέ", "νιος"],
-    "Φεγγαρένιου" => &["Φεγ", "γα", "ρέ", "νιου"],
-    "Φεγγαρένιους" => &["Φεγ", "γα", "ρέ", "νιους"],
-    "Φεγγαρένιων" => &["Φεγ", "γα", "ρέ", "νιων"],
-    "Φεγγάρια" => &["Φεγ", "γά", "ρια"],
-    "Φεγγαρίσια" => &["Φεγ", "γα", "ρί", "σια"],
-    "Φεγγαρίσιας" => &["Φεγ", "γα", "ρί", "σιας"],
-    "Φεγγαρίσιε" => &["Φεγ", "γα", "ρί", "σιε"],
-    "Φεγγαρίσιες" => &["Φεγ", "γα", "ρί", "σιες"],
-    "Φεγγαρίσιο" => &["Φεγ", "γα", "ρί", "σιο"],
-    "Φεγγαρίσιοι" => &["Φεγ", "γα", "ρί", "σιοι"],
-    "Φεγγαρίσιος" => &["Φεγ", "γα", "ρί", "σιος"],
-    "Φεγγαρίσιου" => &["Φεγ", "γα", "ρί", "σιου"],
-    "Φεγγαρίσιους" => &["Φεγ", "γα", "ρί", "σιους"],
-    "Φεγγαρίσιων" => &["Φεγ", "γα", "ρί", "σιων"],
-    "Φελόνια" => &["Φε", "λό", "νια"],
-    "Φελπεδένια" => &["Φελ", "πε", "δέ", "νια"],
-    "Φελπεδένιας" => &["Φελ", "πε", "δέ", "νιας"],
-    "Φελπεδένιε" => &["Φελ", "πε", "δέ", "νιε"],
-    "Φελπεδένιες" => &["Φελ", "πε", "δέ", "νιες"],
-    "Φελπεδένιο" => &["Φελ", "πε", "δέ", "νιο"],
-    "Φελπεδένιοι" => &["Φελ", "πε", "δέ", "νιοι"],
-    "Φελπεδένιος" => &["Φελ", "πε", "δέ", "νιος"],
-    "Φελπεδένιου" => &["Φελ", "πε", "δέ", "νιου"],
-    "Φελπεδένιους" => &["Φελ", "πε", "δέ", "νιους"],
-    "Φελπεδένιων" => &["Φελ", "πε", "δέ", "νιων"],
-    "Φελπένια" => &["Φελ", "πέ", "νια"],
-    "Φελπένιας" => &["Φελ", "πέ", "νιας"],
-    "Φελπένιε" => &["Φελ", "πέ", "νιε"],
-    "Φελπένιες" => &["Φελ", "πέ", "νιες"],
-    "Φελπένιο" => &["Φελ", "πέ", "νιο"],
-    "Φελπένιοι" => &["Φελ", "πέ", "νιοι"],
-    "Φελπένιος" => &["Φελ", "πέ", "νιος"],
-    "Φελπένιου" => &["Φελ", "πέ", "νιου"],
-    "Φελπένιους" => &["Φελ", "πέ", "νιους"],
-    "Φελπένιων" => &["Φελ", "πέ", "νιων"],
-    "Φερμάνια" => &["Φερ", "μά", "νια"],
-    "Φερτάκια" => &["Φερ", "τά", "κια"],
-    "Φερτάκιας" => &["Φερ", "τά", "κιας"],
-    "Φερτάκιες" => &["Φερ", "τά", "κιες"],
-    "Φέσια" => &["Φέ", "σια"],
-    "Φεστόνια" => &["Φε", "στό", "νια"],
-    "Φθήνια" => &["Φθή", "νια"],
-    "Φθήνιας" => &["Φθή", "νιας"],
-    "Φθήνιες" => &["Φθή", "νιες"],
-    "Φιγουρίνια" => &["Φι", "γου", "ρί", "νια"],
-    "Φιδάκια" => &["Φι", "δά", "κια"],
-    "Φιδένια" => &["Φι", "δέ", "νια"],
-    "Φιδένιας" => &["Φι", "δέ", "νιας"],
-    "Φιδένιε" => &["Φι", "δέ", "νιε"],
-    "Φιδένιες" => &["Φι", "δέ", "νιες"],
-    "Φιδένιο" => &["Φι", "δέ", "νιο"],
-    "Φιδένιοι" => &["Φι", "δέ", "νιοι"],
-    "Φιδένιος" => &["Φι", "δέ", "νιος"],
-    "Φιδένιου" => &["Φι", "δέ", "νιου"],
-    "Φιδένιους" => &["Φι", "δέ", "νιους"],
-    "Φιδένιων" => &["Φι", "δέ", "νιων"],
-    "Φίδια" => &["Φί", "δια"],
-    "Φιδίσια" => &["Φι", "δί", "σια"],
-    "Φιδίσιας" => &["Φι", "δί", "σιας"],
-    "Φιδίσιε" => &["Φι", "δί", "σιε"],
-    "Φιδίσιες" => &["Φι", "δί", "σιες"],
-    "Φιδίσιο" => &["Φι", "δί", "σιο"],
-    "Φιδίσιοι" => &["Φι", "δί", "σιοι"],
-    "Φιδίσιος" => &["Φι", "δί", "σιος"],
-    "Φιδίσιου" => &["Φι", "δί", "σιου"],
-    "Φιδίσιους" => &["Φι", "δί", "σιους"],
-    "Φιδίσιων" => &["Φι", "δί", "σιων"],
-    "Φιλάκια" => &["Φι", "λά", "κια"],
-    "Φιλαράκια" => &["Φι", "λα", "ρά", "κια"],
-    "Φιλέρια" => &["Φι", "λέ", "ρια"],
-    "Φιλετάκια" => &["Φι", "λε", "τά", "κια"],
-    "Φιλιστρίνια" => &["Φι", "λι", "στρί", "νια"],
-    "Φιλμάκια" => &["Φιλ", "μά", "κια"],
-    "Φιλντισένια" => &["Φιλ", "ντι", "σέ", "νια"],
-    "Φιλντισένιας" => &["Φιλ", "ντι", "σέ", "νιας"],
-    "Φιλντισένιε" => &["Φιλ", "ντι", "σέ", "νιε"],
-    "Φιλντισένιες" => &["Φιλ", "ντι", "σέ", "νιες"],
-    "Φιλντισένιο" => &["Φιλ", "ντι", "σέ", "νιο"],
-    "Φιλντισένιοι" => &["Φιλ", "ντι", "σέ", "νιοι"],
-    "Φιλντισένιος" => &["Φιλ", "ντι", "σέ", "νιος"],
-    "Φιλντισένιου" => &["Φιλ", "ντι", "σέ", "νιου"],
-    "Φιλντισένιους" => &["Φιλ", "ντι", "σέ", "νιους"],
-    "Φιλντισένιων" => &["Φιλ", "ντι", "σέ", "νιων"],
-    "Φινεστρίνια" => &["Φι", "νε", "στρί", "νια"],
-    "Φινιστρίνια" => &["Φι", "νι", "στρί", "νια"],
-    "Φιντανάκια" => &["Φι", "ντα", "νά", "κια"],
-    "Φιντάνια" => &["Φι", "ντά", "νια"],
-    "Φιογκάκια" => &["Φι", "ο", "γκά", "κια"],
-    "Φιορίνια" => &["Φι", "ο", "ρί", "νια"],
-    "Φιρίκια" => &["Φι", "ρί", "κια"],
-    "Φιρμάνια" => &["Φιρ", "μά", "νια"],
-    "Φιρφιρένια" => &["Φιρ", "φι", "ρέ", "νια"],
-    "Φιρφιρένιας" => &["Φιρ", "φι", "ρέ", "νιας"],
-    "Φιρφιρένιε" => &["Φιρ", "φι", "ρέ", "νιε"],
-    "Φιρφιρένιες" => &["Φιρ", "φι", "ρέ", "νιες"],
-    "Φιρφιρένιο" => &["Φιρ", "φι", "ρέ", "νιο"],
-    "Φιρφιρένιοι" => &["Φιρ", "φι", "ρέ", "νιοι"],
-    "Φιρφιρένιος" => &["Φιρ", "φι", "ρέ", "νιος"],
-    "Φιρφιρένιου" => &["Φιρ", "φι", "ρέ", "νιου"],
-    "Φιρφιρένιους" => &["Φιρ", "φι", "ρέ", "νιους"],
-    "Φιρφιρένιων" => &["Φιρ", "φι", "ρέ", "νιων"],
-    "Φισέκια" => &["Φι", "σέ", "κια"],
-    "Φισεκλίκια" => &["Φι", "σε", "κλί", "κια"],
-    "Φιστίκια" => &["Φι", "στί", "κια"],
-    "Φιτίλια" => &["Φι", "τί", "λια"],
-    "Φκιασίδια" => &["Φ", "κι", "α", "σί", "δια"],
-    "Φλαμούρια" => &["Φλα", "μού", "ρια"],
-    "Φλιμπεράκια" => &["Φλι", "μπε", "ρά", "κια"],
-    "Φλιπεράκια" => &["Φλι", "πε", "ρά", "κια"],
-    "Φλισκούνια" => &["Φλι", "σκού", "νια"],
-    "Φλιτζανάκια" => &["Φλι", "τζα", "νά", "κια"],
-    "Φλιτζάνια" => &["Φλι", "τζά", "νια"],
-    "Φλόκια" => &["Φλό", "κια"],
-    "Φλούδια" => &["Φλού", "δια"],
-    "Φλώρια" => &["Φλώ", "ρια"],
-    "Φοινίκια" => &["Φοι", "νί", "κια"],
-    "Φορεματάκια" => &["Φο", "ρε", "μα", "τά", "κια"],
-    "Φορτηγάκια" => &["Φορ", "τη", "γά", "κια"],
-    "Φόρτια" => &["Φόρ", "τια"],
-    "Φορτσέρια" => &["Φορ", "τσέ", "ρια"],
-    "Φουλάρια" => &["Φου", "λά", "ρια"],
-    "Φούλια" => &["Φού", "λια"],
-    "Φουντούκια" => &["Φου", "ντού", "κια"],
-    "Φούρια" => &["Φού", "ρια"],
-    "Φούριας" => &["Φού", "ριας"],
-    "Φούριες" => &["Φού", "ριες"],
-    "Φουστανάκια" => &["Φου", "στα", "νά", "κια"],
-    "Φουστάνια" => &["Φου", "στά", "νια"],
-    "Φραντζολάκια" => &["Φρα", "ντζο", "λά", "κια"],
-    "Φράξια" => &["Φρά", "ξια"],
-    "Φράξιας" => &["Φρά", "ξιας"],
-    "Φράξιες" => &["Φρά", "ξιες"],
-    "Φροκάλια" => &["Φρο", "κά", "λια"],
-    "Φροκαλίδια" => &["Φρο", "κα", "λί", "δια"],
-    "Φρουτένια" => &["Φρου", "τέ", "νια"],
-    "Φρουτένιας" => &["Φρου", "τέ", "νιας"],
-    "Φρουτένιε" => &["Φρου", "τέ", "νιε"],
-    "Φρουτένιες" => &["Φρου", "τέ", "νιες"],
-    "Φρουτένιο" => &["Φρου", "τέ", "νιο"],
-    "Φρουτένιοι" => &["Φρου", "τέ", "νιοι"],
-    "Φρουτένιος" => &["Φρου", "τέ", "νιος"],
-    "Φρουτένιου" => &["Φρου", "τέ", "νιου"],
-    "Φρουτένιους" => &["Φρου", "τέ", "νιους"],
-    "Φρουτένιων" => &["Φρου", "τέ", "νιων"],
-    "Φρύδια" => &["Φρύ", "δια"],
-    "Φτερνιστήρια" => &["Φτερ", "νι", "στή", "ρια"],
-    "Φτήνια" => &["Φτή", "νια"],
-    "Φτήνιας" => &["Φτή", "νιας"],
-    "Φτήνιες" => &["Φτή", "νιες"],
-    "Φτιασίδια" => &["Φτι", "α", "σί", "δια"],
-    "Φτυαράκια" => &["Φτυ", "α", "ρά", "κια"],
-    "Φτυάρια" => &["Φτυ", "ά", "ρια"],
-    "Φτωχαδάκια" => &["Φτω", "χα", "δά", "κια"],
-    "Φτώχεια" => &["Φτώ", "χεια"],
-    "Φτώχειας" => &["Φτώ", "χειας"],
-    "Φτώχειες" => &["Φτώ", "χειες"],
-    "Φτώχια" => &["Φτώ", "χια"],
-    "Φτώχιας" => &["Φτώ", "χιας"],
-    "Φτώχιες" => &["Φτώ", "χιες"],
-    "Φύκια" => &["Φύ", "κια"],
-    "Φυλαχτάρια" => &["Φυ", "λα", "χτά", "ρια"],
-    "Φυλλαράκια" => &["Φυλ", "λα", "ρά", "κια"],
-    "Φυλλοκάρδια" => &["Φυλ", "λο", "κάρ", "δια"],
-    "Φυντάνια" => &["Φυ", "ντά", "νια"],
-    "Φυσέκια" => &["Φυ", "σέ", "κια"],
-    "Φυσεκλίκια" => &["Φυ", "σε", "κλί", "κια"],
-    "Φυσίγγια" => &["Φυ", "σίγ", "για"],
-    "Φυτευτήρια" => &["Φυ", "τευ", "τή", "ρια"],
-    "Φυτίλια" => &["Φυ", "τί", "λια"],
-    "Φώκια" => &["Φώ", "κια"],
-    "Φώκιας" => &["Φώ", "κιας"],
-    "Φώκιες" => &["Φώ", "κιες"],
-    "Φώλια" => &["Φώ", "λια"],
-    "Φωτάκια" => &["Φω", "τά", "κια"],
-    "Φωτοκόπια" => &["Φω", "το", "κό", "πια"],
-    "Φωτοκόπιας" => &["Φω", "το", "κό", "πιας"],
-    "Φωτοκόπιες" => &["Φω", "το", "κό", "πιες"],
-    "Χαβαδάκια" => &["Χα", "βα", "δά", "κια"],
-    "Χαβάνια" => &["Χα", "βά", "νια"],
-    "Χαβιάρια" => &["Χα", "βι", "ά", "ρια"],
-    "Χαγιάτια" => &["Χα", "γι", "ά", "τια"],
-    "Χαδάκια" => &["Χα", "δά", "κια"],
-    "Χάδια" => &["Χά", "δια"],
-    "Χάζια" => &["Χά", "ζια"],
-    "Χαζοκούτια" => &["Χα", "ζο", "κού", "τια"],
-    "Χαζοπούλια" => &["Χα", "ζο", "πού", "λια"],
-    "Χαϊβάνι" => &["Χαϊ", "βά", "νι"],
-    "Χαϊβάνια" => &["Χαϊ", "βά", "νια"],
-    "Χαϊβανιού" => &["Χαϊ", "βα", "νιού"],
-    "Χαϊβανιών" => &["Χαϊ", "βα", "νιών"],
-    "Χαϊδάκια" => &["Χα", "ϊ", "δά", "κια"],
-    "Χάιδι" => &["Χάι", "δι"],
-    "Χάιδια" => &["Χάι", "δια"],
-    "Χαϊδιού" => &["Χαϊ", "διού"],
-    "Χαϊδιών" => &["Χαϊ", "διών"],
-    "Χαΐρια" => &["Χα", "ΐ", "ρια"],
-    "Χαλάζια" => &["Χα", "λά", "ζια"],
-    "Χαλαζοβρόχια" => &["Χα", "λα", "ζο", "βρό", "χια"],
-    "Χαλάκια" => &["Χα", "λά", "κια"],
-    "Χάλια" => &["Χά", "λια"],
-    "Χαλικάκια" => &["Χα", "λι", "κά", "κια"],
-    "Χαλίκια" => &["Χα", "λί", "κια"],
-    "Χαλινάρια" => &["Χα", "λι", "νά", "ρια"],
-    "Χαλκαδάκια" => &["Χαλ", "κα", "δά", "κια"],
-    "Χαλούμια" => &["Χα", "λού", "μια"],
-    "Χαλυβένια" => &["Χα", "λυ", "βέ", "νια"],
-    "Χαλυβένιας" => &["Χα", "λυ", "βέ", "νιας"],
-    "Χαλυβένιε" => &["Χα", "λυ", "βέ", "νιε"],
-    "Χαλυβένιες" => &["Χα", "λυ", "βέ", "νιες"],
-    "Χαλυβένιο" => &["Χα", "λυ", "βέ", "νιο"],
-    "Χαλυβένιοι" => &["Χα", "λυ", "βέ", "νιοι"],
-    "Χαλυβένιος" => &["Χα", "λυ", "βέ", "νιος"],
-    "Χαλυβένιου" => &["Χα", "λυ", "βέ", "νιου"],
-    "Χαλυβένιους" => &["Χα", "λυ", "βέ", "νιους"],
-    "Χαλυβένιων" => &["Χα", "λυ", "βέ", "νιων"],
-    "Χαμαλίκια" => &["Χα", "μα", "λί", "κια"],
-    "Χαμίνια" => &["Χα", "μί", "νια"],
-    "Χαμογέλια" => &["Χα", "μο", "γέ", "λια"],
-    "Χαμογέλιο" => &["Χα", "μο", "γέ", "λιο"],
-    "Χαμογέλιου" => &["Χα", "μο", "γέ", "λιου"],
-    "Χαμογέλιων" => &["Χα", "μο", "γέ", "λιων"],
-    "Χαμόγια" => &["Χα", "μό", "για"],
-    "Χαμοδράκια" => &["Χα", "μο", "δρά", "κια"],
-    "Χαμολόγια" => &["Χα", "μο", "λό", "για"],
-    "Χαμολόια" => &["Χα", "μο", "λό", "ια"],
-    "Χαμομηλάκια" => &["Χα", "μο", "μη", "λά", "κια"],
-    "Χαμομήλια" => &["Χα", "μο", "μή", "λια"],
-    "Χαμπάρια" => &["Χα", "μπά", "ρια"],
-    "Χαμπέρια" => &["Χα", "μπέ", "ρια"],
-    "Χαμσίνια" => &["Χαμ", "σί", "νια"],
-    "Χαμώγια" => &["Χα", "μώ", "για"],
-    "Χάνια" => &["Χά", "νια"],
-    "Χανουμάκια" => &["Χα", "νου", "μά", "κια"],
-    "Χαντάκια" => &["Χα", "ντά", "κια"],
-    "Χαντζάρια" => &["Χα", "ντζά", "ρια"],
-    "Χαπάκια" => &["Χα", "πά", "κια"],
-    "Χάπια" => &["Χά", "πια"],
-    "Χαράκια" => &["Χα", "ρά", "κια"],
-    "Χαρακίρια" => &["Χα", "ρα", "κί", "ρια"],
-    "Χαράμια" => &["Χα", "ρά", "μια"],
-    "Χαράτσια" => &["Χα", "ρά", "τσια"],
-    "Χαρέμια" => &["Χα", "ρέ", "μια"],
-    "Χαρμάνια" => &["Χαρ", "μά", "νια"],
-    "Χαροκόπια" => &["Χα", "ρο", "κό", "πια"],
-    "Χαρούπια" => &["Χα", "ρού", "πια"],
-    "Χαρτάκια" => &["Χαρ", "τά", "κια"],
-    "Χαρτένια" => &["Χαρ", "τέ", "νια"],
-    "Χαρτένιας" => &["Χαρ", "τέ", "νιας"],
-    "Χαρτένιε" => &["Χαρ", "τέ", "νιε"],
-    "Χαρτένιες" => &["Χαρ", "τέ", "νιες"],
-    "Χαρτένιο" => &["Χαρ", "τέ", "νιο"],
-    "Χαρτένιοι" => &["Χαρ", "τέ", "νιοι"],
-    "Χαρτένιος" => &["Χαρ", "τέ", "νιος"],
-    "Χαρτένιου" => &["Χαρ", "τέ", "νιου"],
-    "Χαρτένιους" => &["Χαρ", "τέ", "νιους"],
-    "Χαρτένιων" => &["Χαρ", "τέ", "νιων"],
-    "Χαρτζιλίκια" => &["Χαρ", "τζι", "λί", "κια"],
-    "Χαρτονένια" => &["Χαρ", "το", "νέ", "νια"],
-    "Χαρτονένιας" => &["Χαρ", "το", "νέ", "νιας"],
-    "Χαρτονένιε" => &["Χαρ", "το", "νέ", "νιε"],
-    "Χαρτονένιες" => &["Χαρ", "το", "νέ", "νιες"],
-    "Χαρτονένιο" => &["Χαρ", "το", "νέ", "νιο"],
-    "Χαρτονένιοι" => &["Χαρ", "το", "νέ", "νιοι"],
-    "Χαρτονένιος" => &["Χαρ", "το", "νέ", "νιος"],
-    "Χαρτονένιου" => &["Χαρ", "το", "νέ", "νιου"],
-    "Χαρτονένιους" => &["Χαρ", "το", "νέ", "νιους"],
-    "Χαρτονένιων" => &["Χαρ", "το", "νέ", "νιων"],
-    "Χαρτόνια" => &["Χαρ", "τό", "νια"],
-    "Χαρχάλια" => &["Χαρ", "χά", "λια"],
-    "Χασεδένια" => &["Χα", "σε", "δέ", "νια"],
-    "Χασεδένιας" => &["Χα", "σε", "δέ", "νιας"],
-    "Χασεδένιε" => &["Χα", "σε", "δέ", "νιε"],
-    "Χασεδένιες" => &["Χα", "σε", "δέ", "νιες"],
-    "Χασεδένιο" => &["Χα", "σε", "δέ", "νιο"],
-    "Χασεδένιοι" => &["Χα", "σε", "δέ", "νιοι"],
-    "Χασεδένιος" => &["Χα", "σε", "δέ", "νιος"],
-    "Χασεδένιου" => &["Χα", "σε", "δέ", "νιου"],
-    "Χασεδένιους" => &["Χα", "σε", "δέ", "νιους"],
-    "Χασεδένιων" => &["Χα", "σε", "δέ", "νιων"],
-    "Χασίσια" => &["Χα", "σί", "σια"],
-    "Χασομέρια" => &["Χα", "σο", "μέ", "ρια"],
-    "Χαστούκια" => &["Χα", "στού", "κια"],
-    "Χατζάρια" => &["Χα", "τζά", "ρια"],
-    "Χατίρια" => &["Χα", "τί", "ρια"],
-    "Χειλάκια" => &["Χει", "λά", "κια"],
-    "Χείλια" => &["Χεί", "λια"],
-    "Χειμάδια" => &["Χει", "μά", "δια"],
-    "Χειραμάξια" => &["Χει", "ρα", "μά", "ξια"],
-    "Χέλια" => &["Χέ", "λια"],
-    "Χελιδόνια" => &["Χε", "λι", "δό", "νια"],
-    "Χελίσια" => &["Χε", "λί", "σια"],
-    "Χελίσιας" => &["Χε", "λί", "σιας"],
-    "Χελίσιε" => &["Χε", "λί", "σιε"],
-    "Χελίσιες" => &["Χε", "λί", "σιες"],
-    "Χελίσιο" => &["Χε", "λί", "σιο"],
-    "Χελίσιοι" => &["Χε", "λί", "σιοι"],
-    "Χελίσιος" => &["Χε", "λί", "σιος"],
-    "Χελίσιου" => &["Χε", "λί", "σιου"],
-    "Χελίσιους" => &["Χε", "λί", "σιους"],
-    "Χελίσιων" => &["Χε", "λί", "σιων"],
-    "Χελωνάκια" => &["Χε", "λω", "νά", "κια"],
-    "Χελώνια" => &["Χε", "λώ", "νια"],
-    "Χελωνίσια" => &["Χε", "λω", "νί", "σια"],
-    "Χελωνίσιας" => &["Χε", "λω", "νί", "σιας"],
-    "Χελωνίσιε" => &["Χε", "λω", "νί", "σιε"],
-    "Χελωνίσιες" => &["Χε", "λω", "νί", "σιες"],
-    "Χελωνίσιο" => &["Χε", "λω", "νί", "σιο"],
-    "Χελωνίσιοι" => &["Χε", "λω", "νί", "σιοι"],
-    "Χελωνίσιος" => &["Χε", "λω", "νί", "σιος"],
-    "Χελωνίσιου" => &["Χε", "λω", "νί", "σιου"],
-    "Χελωνίσιους" => &["Χε", "λω", "νί", "σιους"],
-    "Χελωνίσιων" => &["Χε", "λω", "νί", "σιων"],
-    "Χεράκια" => &["Χε", "ρά", "κια"],
-    "Χέρια" => &["Χέ", "ρια"],
-    "Χερούλια" => &["Χε", "ρού", "λια"],
-    "Χερσοτόπια" => &["Χερ", "σο", "τό", "πια"],
-    "Χηνάκια" => &["Χη", "νά", "κια"],
-    "Χηνάρια" => &["Χη", "νά", "ρια"],
-    "Χηνίσια" => &["Χη", "νί", "σια"],
-    "Χηνίσιας" => &["Χη", "νί", "σιας"],
-    "Χηνίσιε" => &["Χη", "νί", "σιε"],
-    "Χηνίσιες" => &["Χη", "νί", "σιες"],
-    "Χηνίσιο" => &["Χη", "νί", "σιο"],
-    "Χηνίσιοι" => &["Χη", "νί", "σιοι"],
-    "Χηνίσιος" => &["Χη", "νί", "σιος"],
-    "Χηνίσιου" => &["Χη", "νί", "σιου"],
-    "Χηνίσιους" => &["Χη", "νί", "σιους"],
-    "Χηνίσιων" => &["Χη", "νί", "σιων"],
-    "Χιονένια" => &["Χι", "ο", "νέ", "νια"],
-    "Χιονένιας" => &["Χι", "ο", "νέ", "νιας"],
-    "Χιονένιε" => &["Χι", "ο", "νέ", "νιε"],
-    "Χιονένιες" => &["Χι", "ο", "νέ", "νιες"],
-    "Χιονένιο" => &["Χι", "ο", "νέ", "νιο"],
-    "Χιονένιοι" => &["Χι", "ο", "νέ", "νιοι"],
-    "Χιονένιος" => &["Χι", "ο", "νέ", "νιος"],
-    "Χιονένιου" => &["Χι", "ο", "νέ", "νιου"],
-    "Χιονένιους" => &["Χι", "ο", "νέ", "νιους"],
-    "Χιονένιων" => &["Χι", "ο", "νέ", "νιων"],
-    "Χιόνια" => &["Χι", "ό", "νια"],
-    "Χλια" => &["Χλια"],
-    "Χλιας" => &["Χλιας"],
-    "Χλιε" => &["Χλιε"],
-    "Χλιες" => &["Χλιες"],
-    "Χλιο" => &["Χλιο"],
-    "Χλιοι" => &["Χλιοι"],
-    "Χλιος" => &["Χλιος"],
-    "Χλιου" => &["Χλιου"],
-    "Χλιους" => &["Χλιους"],
-    "Χλιων" => &["Χλιων"],
-    "Χλωροτύρια" => &["Χλω", "ρο", "τύ", "ρια"],
-    "Χνάρια" => &["Χνά", "ρια"],
-    "Χνουδένια" => &["Χνου", "δέ", "νια"],
-    "Χνουδένιας" => &["Χνου", "δέ", "νιας"],
-    "Χνουδένιε" => &["Χνου", "δέ", "νιε"],
-    "Χνουδένιες" => &["Χνου", "δέ", "νιες"],
-    "Χνουδένιο" => &["Χνου", "δέ", "νιο"],
-    "Χνουδένιοι" => &["Χνου", "δέ", "νιοι"],
-    "Χνουδένιος" => &["Χνου", "δέ", "νιος"],
-    "Χνουδένιου" => &["Χνου", "δέ", "νιου"],
-    "Χνουδένιους" => &["Χνου", "δέ", "νιους"],
-    "Χνουδένιων" => &["Χνου", "δέ", "νιων"],
-    "Χνούδια" => &["Χνού", "δια"],
-    "Χοιρομέρια" => &["Χοι", "ρο", "μέ", "ρια"],
-    "Χοντράδια" => &["Χο", "ντρά", "δια"],
-    "Χοροστάσια" => &["Χο", "ρο", "στά", "σια"],
-    "Χορταράκια" => &["Χορ", "τα", "ρά", "κια"],
-    "Χορταρένια" => &["Χορ", "τα", "ρέ", "νια"],
-    "Χορταρένιας" => &["Χορ", "τα", "ρέ", "νιας"],
-    "Χορταρένιε" => &["Χορ", "τα", "ρέ", "νιε"],
-    "Χορταρένιες" => &["Χορ", "τα", "ρέ", "νιες"],
-    "Χορταρένιο" => &["Χορ", "τα", "ρέ", "νιο"],
-    "Χορταρένιοι" => &["Χορ", "τα", "ρέ", "νιοι"],
-    "Χορταρένιος" => &["Χορ", "τα", "ρέ", "νιος"],
-    "Χορταρένιου" => &["Χορ", "τα", "ρέ", "νιου"],
-    "Χορταρένιους" => &["Χορ", "τα", "ρέ", "νιους"],
-    "Χορταρένιων" => &["Χορ", "τα", "ρέ", "νιων"],
-    "Χορτάρια" => &["Χορ", "τά", "ρια"],
-    "Χουβαρνταλίκια" => &["Χου", "βαρ", "ντα", "λί", "κια"],
-    "Χούγια" => &["Χού", "για"],
-    "Χουζούρια" => &["Χου", "ζού", "ρια"],
-    "Χουλιάρια" => &["Χου", "λι", "ά", "ρια"],
-    "Χουνέρια" => &["Χου", "νέ", "ρια"],
-    "Χοχλίδια" => &["Χο", "χλί", "δια"],
-    "Χράμια" => &["Χρά", "μια"],
-    "Χρόνια" => &["Χρό", "νια"],
-    "Χρυσαφένια" => &["Χρυ", "σα", "φέ", "νια"],
-    "Χρυσαφένιας" => &["Χρυ", "σα", "φέ", "νιας"],
-    "Χρυσαφένιε" => &["Χρυ", "σα", "φέ", "νιε"],
-    "Χρυσαφένιες" => &["Χρυ", "σα", "φέ", "νιες"],
-    "Χρυσαφένιο" => &["Χρυ", "σα", "φέ", "νιο"],
-    "Χρυσαφένιοι" => &["Χρυ", "σα", "φέ", "νιοι"],
-    "Χρυσαφένιος" => &["Χρυ", "σα", "φέ", "νιος"],
-    "Χρυσαφένιου" => &["Χρυ", "σα", "φέ", "νιου"],
-    "Χρυσαφένιους" => &["Χρυ", "σα", "φέ", "νιους"],
-    "Χρυσαφένιων" => &["Χρυ", "σα", "φέ", "νιων"],
-    "Χρυσάφια" => &["Χρυ", "σά", "φια"],
-    "Χταπόδια" => &["Χτα", "πό", "δια"],
-    "Χτενάκια" => &["Χτε", "νά", "κια"],
-    "Χτένια" => &["Χτέ", "νια"],
-    "Χτυπηματάκια" => &["Χτυ", "πη", "μα", "τά", "κια"],
-    "Χτυπητήρια" => &["Χτυ", "πη", "τή", "ρια"],
-    "Χτυποκάρδια" => &["Χτυ", "πο", "κάρ", "δια"],
-    "Χύσια" => &["Χύ", "σια"],
-    "Χωματένια" => &["Χω", "μα", "τέ", "νια"],
-    "Χωματένιας" => &["Χω", "μα", "τέ", "νιας"],
-    "Χωματένιε" => &["Χω", "μα", "τέ", "νιε"],
-    "Χωματένιες" => &["Χω", "μα", "τέ", "νιες"],
-    "Χωματένιο" => &["Χω", "μα", "τέ", "νιο"],
-    "Χωματένιοι" => &["Χω", "μα", "τέ", "νιοι"],
-    "Χωματένιος" => &["Χω", "μα", "τέ", "νιος"],
-    "Χωματένιου" => &["Χω", "μα", "τέ", "νιου"],
-    "Χωματένιους" => &["Χω", "μα", "τέ", "νιους"],
-    "Χωματένιων" => &["Χω", "μα", "τέ", "νιων"],
-    "Χωνάκια" => &["Χω", "νά", "κια"],
-    "Χωνευτήρια" => &["Χω", "νευ", "τή", "ρια"],
-    "Χωραφάκια" => &["Χω", "ρα", "φά", "κια"],
-    "Χωράφια" => &["Χω", "ρά", "φια"],
-    "Χωριουδάκια" => &["Χω", "ρι", "ου", "δά", "κια"],
-    "Ψαθάκια" => &["Ψα", "θά", "κια"],
-    "Ψαθένια" => &["Ψα", "θέ", "νια"],
-    "Ψαθένιας" => &["Ψα", "θέ", "νιας"],
-    "Ψαθένιε" => &["Ψα", "θέ", "νιε"],
-    "Ψαθένιες" => &["Ψα", "θέ", "νιες"],
-    "Ψαθένιο" => &["Ψα", "θέ", "νιο"],
-    "Ψαθένιοι" => &["Ψα", "θέ", "νιοι"],
-    "Ψαθένιος" => &["Ψα", "θέ", "νιος"],
-    "Ψαθένιου" => &["Ψα", "θέ", "νιου"],
-    "Ψαθένιους" => &["Ψα", "θέ", "νιους"],
-    "Ψαθένιων" => &["Ψα", "θέ", "νιων"],
-    "Ψαλιδάκια" => &["Ψα", "λι", "δά", "κια"],
-    "Ψαλίδια" => &["Ψα", "λί", "δια"],
-    "Ψαλτήρια" => &["Ψαλ", "τή", "ρια"],
-    "Ψαράκια" => &["Ψα", "ρά", "κια"],
-    "Ψάρια" => &["Ψά", "ρια"],
-    "Ψαρίσια" => &["Ψα", "ρί", "σια"],
-    "Ψαρίσιας" => &["Ψα", "ρί", "σιας"],
-    "Ψαρίσιε" => &["Ψα", "ρί", "σιε"],
-    "Ψαρίσιες" => &["Ψα", "ρί", "σιες"],
-    "Ψαρίσιο" => &["Ψα", "ρί", "σιο"],
-    "Ψαρίσιοι" => &["Ψα", "ρί", "σιοι"],
-    "Ψαρίσιος" => &["Ψα", "ρί", "σιος"],
-    "Ψαρίσιου" => &["Ψα", "ρί", "σιου"],
-    "Ψαρίσιους" => &["Ψα", "ρί", "σιους"],
-    "Ψαρίσιων" => &["Ψα", "ρί", "σιων"],
-    "Ψαρονέφρια" => &["Ψα", "ρο", "νέ", "φρια"],
-    "Ψαρόνια" => &["Ψα", "ρό", "νια"],
-    "Ψαροπούλια" => &["Ψα", "ρο", "πού", "λια"],
-    "Ψαροταβερνάκια" => &["Ψα", "ρο", "τα", "βερ", "νά", "κια"],
-    "Ψεγάδια" => &["Ψε", "γά", "δια"],
-    "Ψεματάκια" => &["Ψε", "μα", "τά", "κια"],
-    "Ψίδια" => &["Ψί", "δια"],
-    "Ψιλοβρόχια" => &["Ψι", "λο", "βρό", "χια"],
-    "Ψιμάρια" => &["Ψι", "μά", "ρια"],
-    "Ψόφια" => &["Ψό", "φια"],
-    "Ψόφιας" => &["Ψό", "φιας"],
-    "Ψόφιε" => &["Ψό", "φιε"],
-    "Ψόφιες" => &["Ψό", "φιες"],
-    "Ψοφίμια" => &["Ψο", "φί", "μια"],
-    "Ψόφιο" => &["Ψό", "φιο"],
-    "Ψόφιοι" => &["Ψό", "φιοι"],
-    "Ψόφιος" => &["Ψό", "φιος"],
-    "Ψόφιου" => &["Ψό", "φιου"],
-    "Ψόφιους" => &["Ψό", "φιους"],
-    "Ψόφιων" => &["Ψό", "φιων"],
-    "Ψυχάκια" => &["Ψυ", "χά", "κια"],
-    "Ψυχάκιας" => &["Ψυ", "χά", "κιας"],
-    "Ψυχάκιες" => &["Ψυ", "χά", "κιες"],
-    "Ψυχάρια" => &["Ψυ", "χά", "ρια"],
-    "Ψυχομέτρια" => &["Ψυ", "χο", "μέ", "τρια"],
-    "Ψυχοπαίδια" => &["Ψυ", "χο", "παί", "δια"],
-    "Ψυχοπόνια" => &["Ψυ", "χο", "πό", "νια"],
-    "Ψυχοπόνιας" => &["Ψυ", "χο", "πό", "νιας"],
-    "Ψυχοπόνιες" => &["Ψυ", "χο", "πό", "νιες"],
-    "Ψυχοχάρτια" => &["Ψυ", "χο", "χάρ", "τια"],
-    "Ψωμάκια" => &["Ψω", "μά", "κια"],
-    "Ψωμοτύρια" => &["Ψω", "μο", "τύ", "ρια"],
-    "Ψώνια" => &["Ψώ", "νια"],
-    "Ψώνιο" => &["Ψώ", "νιο"],
-    "Ψώνιου" => &["Ψώ", "νιου"],
-    "Ψώνιων" => &["Ψώ", "νιων"],
-    "Ψωροπερηφάνια" => &["Ψω", "ρο", "πε", "ρη", "φά", "νια"],
-    "Ψωροπερηφάνιας" => &["Ψω", "ρο", "πε", "ρη", "φά", "νιας"],
-    "Ψωροπερηφάνιες" => &["Ψω", "ρο", "πε", "ρη", "φά", "νιες"],
     "αγάλια" => &["α", "γά", "λια"],
     "αγαλματάκια" => &["α", "γαλ", "μα", "τά", "κια"],
     "αγαλματένια" => &["α", "γαλ", "μα", "τέ", "νια"],
@@ -0,0 +1,240 @@
+//! Gold files of syllabifications, to evaluate grac, or any other
+//! syllabifier, against them.
+//!
+//! A gold file has a word and its expected syllables joined with hyphens per
+//! line, separated by a comma:
+//!
+//! ```text
+//! # Comments and empty lines are skipped
+//! γυναικός, γυ-ναι-κός
+//! ["άνδρας", "άν-δρας"],
+//! ```
+//!
+//! The second form is the one of the test macros of grac, so that their cases
+//! can be copied as they are.
+
+use std::fmt;
+use std::path::Path;
+
+use crate::syllabify::syllabify;
+
+/// A word with its expected syllables.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GoldEntry {
+    pub word: String,
+    pub expected: Vec<String>,
+}
+
+/// Errors when loading a gold file.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum GoldError {
+    Io(std::io::Error),
+    /// Malformed line (line numbers start at one).
+    Parse {
+        line: usize,
+        message: String,
+    },
+}
+
+impl fmt::Display for GoldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "{err}"),
+            Self::Parse { line, message } => write!(f, "line {line}: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for GoldError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            Self::Parse { .. } => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for GoldError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+/// Parse a gold file (cf. the [module documentation](self)).
+///
+/// # Errors
+///
+/// Returns [`GoldError::Parse`] for a malformed line.
+///
+/// # Example
+///
+/// ```
+/// use grac::testing::parse_gold;
+///
+/// let entries = parse_gold("γυναικός, γυ-ναι-κός\n[\"άνδρας\", \"άν-δρας\"],").unwrap();
+/// assert_eq!(entries[0].word, "γυναικός");
+/// assert_eq!(entries[1].expected, ["άν", "δρας"]);
+///
+/// assert!(parse_gold("γυναικός, γυ-ναι").is_err());
+/// ```
+pub fn parse_gold(s: &str) -> Result<Vec<GoldEntry>, GoldError> {
+    let mut entries = Vec::new();
+    for (idx, line) in s.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let parse_error = |message: &str| GoldError::Parse {
+            line: idx + 1,
+            message: message.to_string(),
+        };
+        let line = line.trim_end_matches(',').trim_end_matches(']');
+        let line = line.strip_prefix('[').unwrap_or(line);
+        let (word, expected) = line
+            .split_once(',')
+            .ok_or_else(|| parse_error("expected a comma"))?;
+        let (word, expected) = (unquote(word), unquote(expected));
+        if word.is_empty() {
+            return Err(parse_error("empty word"));
+        }
+        if expected.replace('-', "") != word {
+            return Err(parse_error("the syllables do not spell the word"));
+        }
+        entries.push(GoldEntry {
+            word: word.to_string(),
+            expected: expected.split('-').map(str::to_string).collect(),
+        });
+    }
+    Ok(entries)
+}
+
+fn unquote(s: &str) -> &str {
+    let s = s.trim();
+    s.strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .unwrap_or(s)
+}
+
+/// Same as [`parse_gold`], reading from a file.
+///
+/// # Errors
+///
+/// Returns [`GoldError::Io`] if the file can not be read, and the errors of
+/// [`parse_gold`] otherwise.
+pub fn load_gold(path: impl AsRef<Path>) -> Result<Vec<GoldEntry>, GoldError> {
+    parse_gold(&std::fs::read_to_string(path)?)
+}
+
+/// A word whose syllables are not the expected ones.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    pub word: String,
+    pub expected: Vec<String>,
+    pub got: Vec<String>,
+}
+
+/// Result of [`evaluate`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Evaluation {
+    /// Number of evaluated words.
+    pub total: usize,
+    /// In the order of the entries.
+    pub mismatches: Vec<Mismatch>,
+}
+
+impl Evaluation {
+    /// Ratio of words with the expected syllables, or one if there are none.
+    pub fn accuracy(&self) -> f64 {
+        if self.total == 0 {
+            return 1.0;
+        }
+        (self.total - self.mismatches.len()) as f64 / self.total as f64
+    }
+}
+
+/// Compare the syllables given by `syllabify` to the expected ones.
+///
+/// # Example
+///
+/// ```
+/// use grac::syllabify;
+/// use grac::testing::{evaluate, parse_gold};
+///
+/// let entries = parse_gold("πάντα, πά-ντα\nποιος, πο-ιος").unwrap();
+/// let evaluation = evaluate(&entries, |word| {
+///     syllabify(word).iter().map(|s| s.to_string()).collect()
+/// });
+/// assert_eq!(evaluation.total, 2);
+/// assert_eq!(evaluation.mismatches[0].got, ["ποιος"]);
+/// assert_eq!(evaluation.accuracy(), 0.5);
+/// ```
+pub fn evaluate(
+    entries: &[GoldEntry],
+    mut syllabify: impl FnMut(&str) -> Vec<String>,
+) -> Evaluation {
+    let mismatches = entries
+        .iter()
+        .filter_map(|entry| {
+            let got = syllabify(&entry.word);
+            (got != entry.expected).then(|| Mismatch {
+                word: entry.word.clone(),
+                expected: entry.expected.clone(),
+                got,
+            })
+        })
+        .collect();
+    Evaluation {
+        total: entries.len(),
+        mismatches,
+    }
+}
+
+/// Same as [`evaluate`], with [`syllabify`].
+pub fn evaluate_grac(entries: &[GoldEntry]) -> Evaluation {
+    evaluate(entries, |word| {
+        syllabify(word).iter().map(|s| (*s).to_string()).collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_gold() {
+        let gold = "# Ancient\n\n  γυναικός , \"γυ-ναι-κός\"\r\n[\"άνδρας\", \"άν-δρας\"],\n";
+        let entries = parse_gold(gold).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].expected, ["γυ", "ναι", "κός"]);
+        assert_eq!(entries[1].word, "άνδρας");
+    }
+
+    #[test]
+    fn test_parse_gold_errors() {
+        for (gold, expected) in [
+            ("γυναικός", 1),
+            ("\nγυναικός, γυ-ναι-κος", 2),
+            ("άνδρας, άν-δρας\n, ", 2),
+        ] {
+            let err = parse_gold(gold).unwrap_err();
+            assert!(
+                matches!(err, GoldError::Parse { line, .. } if line == expected),
+                "{gold}"
+            );
+        }
+        assert!(matches!(
+            load_gold("does/not/exist.txt"),
+            Err(GoldError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn test_evaluate_grac() {
+        let entries = parse_gold("πάντα, πά-ντα\nκόσμος, κό-σμος").unwrap();
+        let evaluation = evaluate_grac(&entries);
+        assert_eq!(evaluation.total, 2);
+        assert!(evaluation.mismatches.is_empty());
+        assert!((evaluate_grac(&[]).accuracy() - 1.0).abs() < f64::EPSILON);
+    }
+}
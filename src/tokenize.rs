@@ -0,0 +1,318 @@
+//! Tokenization.
+//!
+//! Text is split at whitespace, and every resulting chunk is further split into
+//! (left punctuation, word, right punctuation) with [`split_punctuation`].
+
+use std::ops::Range;
+
+use crate::monotonic::split_punctuation;
+use crate::protect::ProtectedRegions;
+use crate::span::{SpanEncoding, encode_spans};
+use crate::syllabify::{SyllabifyOptions, Syllables, syllabify, syllabify_with_options};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    /// Contains at least one alphabetic char. May contain inner punctuation: ό,τι
+    Word,
+    /// Anything else that is not whitespace, numbers included.
+    Punctuation,
+    Whitespace,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token<'a> {
+    pub kind: TokenKind,
+    pub text: &'a str,
+    /// Byte range of the token in the text.
+    pub span: Range<usize>,
+}
+
+/// Maximal runs of either whitespace or non-whitespace chars.
+struct Runs<'a> {
+    s: &'a str,
+    start: usize,
+}
+
+impl<'a> Iterator for Runs<'a> {
+    // (start byte, run, is_whitespace)
+    type Item = (usize, &'a str, bool);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = &self.s[self.start..];
+        let is_whitespace = rest.chars().next()?.is_whitespace();
+        let len = rest
+            .char_indices()
+            .find_map(|(i, ch)| (ch.is_whitespace() != is_whitespace).then_some(i))
+            .unwrap_or(rest.len());
+        let start = self.start;
+        self.start += len;
+        Some((start, &rest[..len], is_whitespace))
+    }
+}
+
+/// Split text into tokens, preserving every byte.
+///
+/// # Example
+///
+/// ```
+/// use grac::{TokenKind, tokenize};
+///
+/// let tokens: Vec<_> = tokenize("«Καλημέρα», είπε.").collect();
+/// let kinds: Vec<_> = tokens.iter().map(|token| token.kind).collect();
+/// let texts: Vec<_> = tokens.iter().map(|token| token.text).collect();
+/// assert_eq!(texts, ["«", "Καλημέρα", "»,", " ", "είπε", "."]);
+/// assert_eq!(kinds[..2], [TokenKind::Punctuation, TokenKind::Word]);
+/// assert_eq!(tokens[1].span, 2..18);
+/// ```
+pub fn tokenize(s: &str) -> impl Iterator<Item = Token<'_>> {
+    Runs { s, start: 0 }.flat_map(|(start, run, is_whitespace)| {
+        let (left, core, right) = if is_whitespace {
+            ("", run, "")
+        } else {
+            split_punctuation(run)
+        };
+        let core_kind = if is_whitespace {
+            TokenKind::Whitespace
+        } else if core.is_empty() {
+            TokenKind::Punctuation
+        } else {
+            TokenKind::Word
+        };
+        let core_start = start + left.len();
+        let right_start = core_start + core.len();
+        [
+            (TokenKind::Punctuation, left, start),
+            (core_kind, core, core_start),
+            (TokenKind::Punctuation, right, right_start),
+        ]
+        .into_iter()
+        .filter(|(_, text, _)| !text.is_empty())
+        .map(|(kind, text, start)| Token {
+            kind,
+            text,
+            span: start..start + text.len(),
+        })
+    })
+}
+
+/// Same as [`tokenize`], with spans in UTF-16 code units.
+pub fn tokenize_utf16(s: &str) -> Vec<Token<'_>> {
+    let tokens: Vec<_> = tokenize(s).collect();
+    let spans = encode_spans(
+        s,
+        tokens.iter().map(|t| t.span.clone()),
+        SpanEncoding::Utf16,
+    );
+    tokens
+        .into_iter()
+        .zip(spans)
+        .map(|(token, span)| Token { span, ..token })
+        .collect()
+}
+
+/// Rewrite the words of a text, seeing the words around them.
+///
+/// Words are the [`TokenKind::Word`] tokens of [`tokenize`]. For every word,
+/// `f` receives up to `window` words before and after it, in text order, and
+/// returns its replacement, or `None` to keep it. Whitespace, punctuation and
+/// the words that are kept are copied as they are.
+///
+/// Context words are those of the input text, not their replacements. What
+/// separates two words, like a comma, can be read from their spans.
+///
+/// # Example
+///
+/// ```
+/// use grac::for_each_word_with_context;
+///
+/// let text = "θα πάω, θα δω";
+/// // Uppercase the words after «θα», unless a comma separates them.
+/// let result = for_each_word_with_context(text, 1, |prev, word, _| {
+///     let prev = prev.last()?;
+///     let gap = &text[prev.span.end..word.span.start];
+///     (prev.text == "θα" && gap.trim().is_empty()).then(|| word.text.to_uppercase())
+/// });
+/// assert_eq!(result, "θα ΠΆΩ, θα ΔΩ");
+/// ```
+pub fn for_each_word_with_context<'a>(
+    text: &'a str,
+    window: usize,
+    mut f: impl FnMut(&[Token<'a>], &Token<'a>, &[Token<'a>]) -> Option<String>,
+) -> String {
+    let words: Vec<Token<'a>> = tokenize(text)
+        .filter(|token| token.kind == TokenKind::Word)
+        .collect();
+    let mut out = String::with_capacity(text.len());
+    let mut copied = 0;
+    for (idx, word) in words.iter().enumerate() {
+        let prev = &words[idx.saturating_sub(window)..idx];
+        let next = &words[idx + 1
+            ..words
+                .len()
+                .min(idx.saturating_add(1).saturating_add(window))];
+        if let Some(replacement) = f(prev, word, next) {
+            out.push_str(&text[copied..word.span.start]);
+            out.push_str(&replacement);
+            copied = word.span.end;
+        }
+    }
+    out.push_str(&text[copied..]);
+    out
+}
+
+/// Byte span and text of every word, without its punctuation.
+pub(crate) fn words(s: &str) -> impl Iterator<Item = (Range<usize>, &str)> {
+    Runs { s, start: 0 }
+        .filter(|&(_, _, is_whitespace)| !is_whitespace)
+        .filter_map(|(start, run, _)| {
+            let (left, core, _) = split_punctuation(run);
+            let start = start + left.len();
+            (!core.is_empty()).then_some((start..start + core.len(), core))
+        })
+}
+
+/// Syllabify every word of a text with [`syllabify`].
+///
+/// Words are the [`TokenKind::Word`] tokens of [`tokenize`], so punctuation is
+/// not part of their syllables. Each word comes with its byte span in the text.
+///
+/// # Example
+///
+/// ```
+/// use grac::syllabify_words;
+///
+/// let text = "«Καλημέρα», είπε.";
+/// let words: Vec<_> = syllabify_words(text)
+///     .map(|(span, syllables)| (span, syllables.join("-")))
+///     .collect();
+/// assert_eq!(words, [(2..18, "Κα-λη-μέ-ρα".to_string()), (22..30, "εί-πε".to_string())]);
+/// ```
+pub fn syllabify_words(s: &str) -> impl Iterator<Item = (Range<usize>, Syllables<'_>)> {
+    words(s).map(|(span, word)| (span, syllabify(word)))
+}
+
+/// Same as [`syllabify_words`], with [`SyllabifyOptions`].
+///
+/// As in [`syllabify_with_options`], synizesis is not looked up.
+pub fn syllabify_words_with_options<'a>(
+    s: &'a str,
+    options: &SyllabifyOptions,
+) -> impl Iterator<Item = (Range<usize>, Syllables<'a>)> {
+    words(s).map(|(span, word)| (span, syllabify_with_options(word, options)))
+}
+
+/// Same as [`syllabify_words`], skipping the words in protected regions.
+///
+/// Words split by a protected region are syllabified in parts.
+///
+/// # Example
+///
+/// ```
+/// use grac::{ProtectedRegions, syllabify_words_outside};
+///
+/// let text = "<p title=\"τίτλος\">Καλημέρα</p>";
+/// let words: Vec<_> = syllabify_words_outside(text, &ProtectedRegions::xml())
+///     .map(|(span, syllables)| (span, syllables.join("-")))
+///     .collect();
+/// assert_eq!(words, [(24..40, "Κα-λη-μέ-ρα".to_string())]);
+/// ```
+pub fn syllabify_words_outside<'a>(
+    s: &'a str,
+    protected: &ProtectedRegions,
+) -> impl Iterator<Item = (Range<usize>, Syllables<'a>)> {
+    protected
+        .segments(s)
+        .into_iter()
+        .filter(|(_, is_protected)| !is_protected)
+        .flat_map(move |(range, _)| {
+            let offset = range.start;
+            syllabify_words(&s[range])
+                .map(move |(span, syllables)| (span.start + offset..span.end + offset, syllables))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokenize_lossless() {
+        let text = "  ὅ,τι\u{2009}τοῦ τύχῃ...\n\n―Τὴν 1808· ";
+        let joined: String = tokenize(text).map(|token| token.text).collect();
+        assert_eq!(joined, text);
+        for token in tokenize(text) {
+            assert_eq!(&text[token.span], token.text);
+        }
+    }
+
+    #[test]
+    fn test_tokenize_kinds() {
+        let kinds: Vec<_> = tokenize("ό,τι 1808·").map(|token| token.kind).collect();
+        assert_eq!(
+            kinds,
+            [
+                TokenKind::Word,
+                TokenKind::Whitespace,
+                TokenKind::Punctuation
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tokenize_empty() {
+        assert_eq!(tokenize("").count(), 0);
+    }
+
+    #[test]
+    fn test_for_each_word_with_context() {
+        let text = "  ὅ,τι\u{2009}τοῦ τύχῃ...\n\n―Τὴν 1808· ";
+        assert_eq!(for_each_word_with_context(text, 2, |_, _, _| None), text);
+        let same = for_each_word_with_context(text, 2, |_, word, _| Some(word.text.to_string()));
+        assert_eq!(same, text);
+
+        let mut windows = Vec::new();
+        let result = for_each_word_with_context("α β γ δ", 2, |prev, word, next| {
+            let texts = |tokens: &[Token]| tokens.iter().map(|t| t.text).collect::<String>();
+            windows.push(format!("{}|{}|{}", texts(prev), word.text, texts(next)));
+            Some(word.text.to_uppercase())
+        });
+        assert_eq!(result, "Α Β Γ Δ");
+        assert_eq!(windows, ["|α|βγ", "α|β|γδ", "αβ|γ|δ", "βγ|δ|"]);
+
+        let result = for_each_word_with_context("α β", 0, |prev, _, next| {
+            assert!(prev.is_empty() && next.is_empty());
+            Some(String::new())
+        });
+        assert_eq!(result, " ");
+
+        // Every word sees the whole text
+        let result = for_each_word_with_context("α β γ", usize::MAX, |prev, _, next| {
+            Some((prev.len() + next.len()).to_string())
+        });
+        assert_eq!(result, "2 2 2");
+    }
+
+    #[test]
+    fn test_syllabify_words() {
+        let text = "  ὅ,τι\u{2009}τοῦ τύχῃ...\n\n―Μάγια 1808· ";
+        let words: Vec<_> = tokenize(text)
+            .filter(|token| token.kind == TokenKind::Word)
+            .map(|token| token.span)
+            .collect();
+        let spans: Vec<_> = syllabify_words(text).map(|(span, _)| span).collect();
+        assert_eq!(spans, words);
+        for (span, syllables) in syllabify_words(text) {
+            assert_eq!(syllables, syllabify(&text[span]));
+        }
+        assert_eq!(syllabify_words(";... ").count(), 0);
+    }
+
+    #[test]
+    fn test_syllabify_words_with_options() {
+        let options = SyllabifyOptions::new();
+        let syllables: Vec<_> = syllabify_words_with_options("Μάγια, ναι", &options)
+            .map(|(_, syllables)| syllables.join("-"))
+            .collect();
+        assert_eq!(syllables, ["Μά-γι-α", "ναι"]);
+    }
+}
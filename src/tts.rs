@@ -0,0 +1,699 @@
+//! Text normalization for text-to-speech engines.
+//!
+//! Numbers and abbreviations are expanded to words, and every word is
+//! syllabified and transcribed to IPA, with its stressed syllable.
+
+use std::ops::Range;
+
+use crate::accents::{Diacritic, has_diacritics, has_diaeresis_char};
+use crate::chars::{base_lower, is_combining_mark, is_greek_letter};
+use crate::constants::is_stopword;
+use crate::syllabify::{is_vowel, syllabify};
+use crate::tokenize::{TokenKind, tokenize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TtsTokenKind {
+    Word,
+    /// A word of the expansion of a number.
+    Number,
+    /// A word of the expansion of an abbreviation.
+    Abbreviation,
+    /// A short pause: comma, ano teleia, colon.
+    Pause,
+    /// A long pause: full stop, question or exclamation mark.
+    SentenceEnd,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TtsSyllable {
+    pub text: String,
+    /// Broad IPA transcription.
+    pub phonemes: String,
+    pub stressed: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TtsToken<'a> {
+    pub kind: TtsTokenKind,
+    /// Text of the token in the input.
+    ///
+    /// Numbers and abbreviations expand to a token per word, all with the same source.
+    pub source: &'a str,
+    /// Byte range of the source in the text.
+    pub span: Range<usize>,
+    /// The word to be spoken. Empty for pauses.
+    pub text: String,
+    pub syllables: Vec<TtsSyllable>,
+}
+
+/// Abbreviations, without their final dot, and their expansions.
+const ABBREVIATIONS: [(&str, &str); 24] = [
+    ("αι", "αιώνας"),
+    ("βλ", "βλέπε"),
+    ("δηλ", "δηλαδή"),
+    ("εκ", "εκατοστά"),
+    ("κ", "κύριος"),
+    ("κ.ά", "και άλλα"),
+    ("κ.λπ", "και λοιπά"),
+    ("κα", "κυρία"),
+    ("κλπ", "και λοιπά"),
+    ("κτλ", "και τα λοιπά"),
+    ("λ.χ", "λόγου χάρη"),
+    ("μ.Χ", "μετά Χριστόν"),
+    ("μ.μ", "μετά μεσημβρίας"),
+    ("ο.π", "όπως παραπάνω"),
+    ("οδ", "οδός"),
+    ("π.Χ", "προ Χριστού"),
+    ("π.μ", "προ μεσημβρίας"),
+    ("π.χ", "παραδείγματος χάρη"),
+    ("σελ", "σελίδα"),
+    ("σημ", "σημείωση"),
+    ("τηλ", "τηλέφωνο"),
+    ("χλμ", "χιλιόμετρα"),
+    ("Α.Ε", "ανώνυμη εταιρεία"),
+    ("Ο.Ε", "ομόρρυθμη εταιρεία"),
+];
+
+const SENTENCE_ENDS: [char; 5] = ['.', '!', ';', '\u{037E}', '…'];
+
+const PAUSES: [char; 4] = [',', '\u{0387}', ':', '—'];
+
+/// Prepare text for a text-to-speech engine.
+///
+/// * Words are kept as they are.
+/// * Numbers are read as cardinals (cf. [`number_to_words`]), with `.` as
+///   thousands separator and `,` as decimal separator.
+/// * Common abbreviations followed by a dot are expanded (κλπ. → και λοιπά).
+/// * Punctuation becomes pauses, and is otherwise dropped with the whitespace.
+///
+/// Every spoken word is split into syllables (cf. [`syllabify`]), transcribed
+/// to broad IPA, and marked with its stressed syllable. Monosyllables without
+/// an accent are stressed unless they are stopwords (το, και...).
+///
+/// # Example
+///
+/// ```
+/// use grac::{TtsTokenKind, tts_tokens};
+///
+/// let tokens = tts_tokens("Πέρασαν 25 χρόνια, π.χ. ο Νίκος.");
+/// let texts: Vec<_> = tokens.iter().map(|token| token.text.as_str()).collect();
+/// assert_eq!(
+///     texts,
+///     ["Πέρασαν", "είκοσι", "πέντε", "χρόνια", "", "παραδείγματος", "χάρη", "ο", "Νίκος", ""]
+/// );
+/// assert_eq!(tokens[1].kind, TtsTokenKind::Number);
+/// assert_eq!(tokens[1].source, "25");
+/// assert_eq!(tokens[4].kind, TtsTokenKind::Pause);
+/// assert_eq!(tokens[9].kind, TtsTokenKind::SentenceEnd);
+///
+/// let phonemes: Vec<_> = tokens[3].syllables.iter().map(|s| s.phonemes.as_str()).collect();
+/// assert_eq!(phonemes, ["xro", "ɲa"]);
+/// assert!(tokens[3].syllables[0].stressed);
+/// ```
+pub fn tts_tokens(text: &str) -> Vec<TtsToken<'_>> {
+    let mut out = Vec::new();
+    let mut tokens = tokenize(text).peekable();
+    while let Some(token) = tokens.next() {
+        match token.kind {
+            TokenKind::Whitespace => (),
+            TokenKind::Word => {
+                let expansion = lookup_abbreviation(token.text);
+                // The dot belongs to the abbreviation.
+                let dot = expansion.and_then(|_| tokens.next_if(|next| next.text.starts_with('.')));
+                if let (Some(expansion), Some(dot)) = (expansion, dot) {
+                    let span = token.span.start..dot.span.start + 1;
+                    for word in expansion.split(' ') {
+                        out.push(word_token(
+                            &text[span.clone()],
+                            span.clone(),
+                            word,
+                            TtsTokenKind::Abbreviation,
+                        ));
+                    }
+                    let rest = &dot.text[1..];
+                    punctuation_tokens(rest, dot.span.start + 1, &mut out);
+                } else {
+                    out.push(word_token(
+                        token.text,
+                        token.span,
+                        token.text,
+                        TtsTokenKind::Word,
+                    ));
+                }
+            }
+            TokenKind::Punctuation => punctuation_tokens(token.text, token.span.start, &mut out),
+        }
+    }
+    out
+}
+
+fn lookup_abbreviation(word: &str) -> Option<&'static str> {
+    ABBREVIATIONS
+        .iter()
+        .find(|(abbreviation, _)| *abbreviation == word)
+        .map(|(_, expansion)| *expansion)
+}
+
+fn word_token<'a>(
+    source: &'a str,
+    span: Range<usize>,
+    word: &str,
+    kind: TtsTokenKind,
+) -> TtsToken<'a> {
+    TtsToken {
+        kind,
+        source,
+        span,
+        text: word.to_string(),
+        syllables: tts_syllables(word),
+    }
+}
+
+/// Numbers and pauses of a punctuation token starting at `offset`.
+fn punctuation_tokens<'a>(s: &'a str, offset: usize, out: &mut Vec<TtsToken<'a>>) {
+    let mut rest = s;
+    let mut start = offset;
+    while let Some(ch) = rest.chars().next() {
+        let len = if ch.is_ascii_digit() {
+            let len = number_len(rest);
+            for word in read_number(&rest[..len]).split(' ') {
+                out.push(word_token(
+                    &rest[..len],
+                    start..start + len,
+                    word,
+                    TtsTokenKind::Number,
+                ));
+            }
+            len
+        } else {
+            let kind = pause_kind(ch);
+            // A single pause for runs like ... or ;!
+            let len = match kind {
+                Some(_) => rest
+                    .char_indices()
+                    .find(|(_, other)| pause_kind(*other) != kind)
+                    .map_or(rest.len(), |(idx, _)| idx),
+                None => ch.len_utf8(),
+            };
+            if let Some(kind) = kind {
+                out.push(TtsToken {
+                    kind,
+                    source: &rest[..len],
+                    span: start..start + len,
+                    text: String::new(),
+                    syllables: Vec::new(),
+                });
+            }
+            len
+        };
+        rest = &rest[len..];
+        start += len;
+    }
+}
+
+fn pause_kind(ch: char) -> Option<TtsTokenKind> {
+    if SENTENCE_ENDS.contains(&ch) {
+        Some(TtsTokenKind::SentenceEnd)
+    } else if PAUSES.contains(&ch) {
+        Some(TtsTokenKind::Pause)
+    } else {
+        None
+    }
+}
+
+/// Length of the number at the start of `s`: digits, with inner dots and commas.
+fn number_len(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    let mut len = 0;
+    while len < bytes.len() {
+        let is_separator =
+            matches!(bytes[len], b'.' | b',') && bytes.get(len + 1).is_some_and(u8::is_ascii_digit);
+        if !bytes[len].is_ascii_digit() && !is_separator {
+            break;
+        }
+        len += 1;
+    }
+    len
+}
+
+/// Read a number such as 1.500 or 3,14.
+fn read_number(s: &str) -> String {
+    let (integer, decimals) = s.split_once(',').unwrap_or((s, ""));
+    let integer: String = integer.chars().filter(char::is_ascii_digit).collect();
+    let mut words = read_digits(&integer);
+    if !decimals.is_empty() {
+        words.push_str(" κόμμα ");
+        words.push_str(&read_digits(decimals));
+    }
+    words
+}
+
+/// Leading zeros are read one by one (05 → μηδέν πέντε).
+fn read_digits(digits: &str) -> String {
+    let significant = digits.trim_start_matches('0');
+    let mut words: Vec<String> = vec!["μηδέν".to_string(); digits.len() - significant.len()];
+    if !significant.is_empty() {
+        match significant.parse() {
+            Ok(n) => words.push(number_to_words(n)),
+            Err(_) => words.extend(
+                significant
+                    .chars()
+                    .map(|d| UNITS[d as usize - '0' as usize].to_string()),
+            ),
+        }
+    } else if words.len() > 1 {
+        words.truncate(1);
+    }
+    words.join(" ")
+}
+
+const UNITS: [&str; 10] = [
+    "μηδέν",
+    "ένα",
+    "δύο",
+    "τρία",
+    "τέσσερα",
+    "πέντε",
+    "έξι",
+    "επτά",
+    "οκτώ",
+    "εννέα",
+];
+
+const TEENS: [&str; 10] = [
+    "δέκα",
+    "έντεκα",
+    "δώδεκα",
+    "δεκατρία",
+    "δεκατέσσερα",
+    "δεκαπέντε",
+    "δεκαέξι",
+    "δεκαεπτά",
+    "δεκαοκτώ",
+    "δεκαεννέα",
+];
+
+const TENS: [&str; 10] = [
+    "",
+    "",
+    "είκοσι",
+    "τριάντα",
+    "σαράντα",
+    "πενήντα",
+    "εξήντα",
+    "εβδομήντα",
+    "ογδόντα",
+    "ενενήντα",
+];
+
+const HUNDREDS: [&str; 10] = [
+    "",
+    "εκατό",
+    "διακόσια",
+    "τριακόσια",
+    "τετρακόσια",
+    "πεντακόσια",
+    "εξακόσια",
+    "επτακόσια",
+    "οκτακόσια",
+    "εννιακόσια",
+];
+
+/// Large powers of a thousand: (value, singular, plural).
+const SCALES: [(u64, &str, &str); 4] = [
+    (
+        1_000_000_000_000_000,
+        "τετράκις εκατομμύριο",
+        "τετράκις εκατομμύρια",
+    ),
+    (1_000_000_000_000, "τρισεκατομμύριο", "τρισεκατομμύρια"),
+    (1_000_000_000, "δισεκατομμύριο", "δισεκατομμύρια"),
+    (1_000_000, "εκατομμύριο", "εκατομμύρια"),
+];
+
+/// Read a number as a cardinal, in the neuter.
+///
+/// # Example
+///
+/// ```
+/// use grac::number_to_words;
+///
+/// assert_eq!(number_to_words(0), "μηδέν");
+/// assert_eq!(number_to_words(115), "εκατόν δεκαπέντε");
+/// assert_eq!(number_to_words(1821), "χίλια οκτακόσια είκοσι ένα");
+/// assert_eq!(number_to_words(3_400), "τρεις χιλιάδες τετρακόσια");
+/// assert_eq!(number_to_words(2_000_000), "δύο εκατομμύρια");
+/// ```
+pub fn number_to_words(n: u64) -> String {
+    if n == 0 {
+        return UNITS[0].to_string();
+    }
+    let mut words: Vec<String> = Vec::new();
+    let mut rest = n;
+    for (value, singular, plural) in SCALES {
+        let count = rest / value;
+        rest %= value;
+        if count == 1 {
+            words.push(format!("ένα {singular}"));
+        } else if count > 1 {
+            words.push(format!("{} {plural}", number_to_words(count)));
+        }
+    }
+    let thousands = rest / 1000;
+    if thousands == 1 {
+        words.push("χίλια".to_string());
+    } else if thousands > 1 {
+        words.push(format!("{} χιλιάδες", feminine(&below_thousand(thousands))));
+    }
+    if !rest.is_multiple_of(1000) {
+        words.push(below_thousand(rest % 1000));
+    }
+    words.join(" ")
+}
+
+fn below_thousand(n: u64) -> String {
+    let (hundreds, rest) = ((n / 100) as usize, (n % 100) as usize);
+    let mut words: Vec<&str> = Vec::new();
+    if hundreds == 1 && rest > 0 {
+        words.push("εκατόν");
+    } else if hundreds > 0 {
+        words.push(HUNDREDS[hundreds]);
+    }
+    match rest {
+        0 => (),
+        1..=9 => words.push(UNITS[rest]),
+        10..=19 => words.push(TEENS[rest - 10]),
+        _ => {
+            words.push(TENS[rest / 10]);
+            if rest % 10 > 0 {
+                words.push(UNITS[rest % 10]);
+            }
+        }
+    }
+    words.join(" ")
+}
+
+/// Feminine forms, to agree with χιλιάδες.
+fn feminine(words: &str) -> String {
+    words
+        .split(' ')
+        .map(|word| match word {
+            "ένα" => "μία".to_string(),
+            "τρία" => "τρεις".to_string(),
+            "τέσσερα" => "τέσσερις".to_string(),
+            "δεκατρία" => "δεκατρείς".to_string(),
+            "δεκατέσσερα" => "δεκατέσσερις".to_string(),
+            _ => match word.strip_suffix("όσια") {
+                Some(stem) => format!("{stem}όσιες"),
+                None => word.to_string(),
+            },
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn tts_syllables(word: &str) -> Vec<TtsSyllable> {
+    let syllables = syllabify(word);
+    let phonemes = phonemes_by_syllable(&syllables);
+    let accents = [Diacritic::ACUTE, Diacritic::GRAVE, Diacritic::CIRCUMFLEX];
+    let is_accented: Vec<bool> = syllables
+        .iter()
+        .map(|syllable| has_diacritics(syllable.chars(), &accents))
+        .collect();
+    let stress_monosyllable = syllables.len() == 1 && !is_stopword(word);
+    syllables
+        .iter()
+        .zip(phonemes)
+        .zip(is_accented)
+        .map(|((text, phonemes), is_accented)| TtsSyllable {
+            text: (*text).to_string(),
+            phonemes,
+            stressed: is_accented || stress_monosyllable,
+        })
+        .collect()
+}
+
+/// Vowels that palatalize the previous consonant.
+fn is_front(letters: &[Letter], idx: usize) -> bool {
+    match letters.get(idx) {
+        Some(letter) => match letter.base {
+            'ε' | 'η' | 'ι' | 'υ' => true,
+            'α' | 'ο' => letters
+                .get(idx + 1)
+                .is_some_and(|next| next.base == 'ι' && !next.diaeresis),
+            _ => false,
+        },
+        None => false,
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Letter {
+    base: char,
+    diaeresis: bool,
+    syllable: usize,
+}
+
+/// The letters of a word, with the index of their syllable.
+fn letters(syllables: &[&str]) -> Vec<Letter> {
+    syllables
+        .iter()
+        .enumerate()
+        .flat_map(|(syllable, text)| {
+            text.chars().map(move |ch| Letter {
+                base: base_lower(ch),
+                diaeresis: has_diaeresis_char(ch),
+                syllable,
+            })
+        })
+        .filter(|letter| !is_combining_mark(letter.base))
+        .collect()
+}
+
+/// The palatal consonant of a consonant before a glide, if it has one.
+const fn palatal(ch: char) -> Option<&'static str> {
+    match ch {
+        'γ' => Some("ʝ"),
+        'κ' => Some("c"),
+        'χ' => Some("ç"),
+        'λ' => Some("ʎ"),
+        'ν' => Some("ɲ"),
+        _ => None,
+    }
+}
+
+/// Broad IPA transcription of every syllable of a word.
+fn phonemes_by_syllable(syllables: &[&str]) -> Vec<String> {
+    let letters = letters(syllables);
+    let mut out = vec![String::new(); syllables.len()];
+    let base = |idx: usize| letters.get(idx).map(|letter| letter.base);
+    // A plain vowel, that forms a digraph with the previous letter of the syllable.
+    let plain = |idx: usize, ch: char| {
+        letters.get(idx).is_some_and(|l| {
+            l.base == ch && !l.diaeresis && l.syllable == letters[idx - 1].syllable
+        })
+    };
+    let glide_len = |idx: usize| {
+        let len = if plain(idx + 1, 'ι') {
+            1
+        } else if plain(idx + 1, 'ε') && plain(idx + 2, 'ι') {
+            2
+        } else {
+            return 0;
+        };
+        let before_vowel = letters
+            .get(idx + 1 + len)
+            .is_some_and(|next| is_vowel(next.base) && next.syllable == letters[idx].syllable);
+        if before_vowel { len } else { 0 }
+    };
+    // Voiced after αυ, ευ, ηυ, and before σ.
+    let voiced_next =
+        |idx: usize| base(idx).is_some_and(|ch| is_vowel(ch) || "βγδζλμνρ".contains(ch));
+
+    let mut idx = 0;
+    while idx < letters.len() {
+        let letter = letters[idx];
+        let syllable = letter.syllable;
+        let (phonemes, len): (&str, usize) = match letter.base {
+            'α' if plain(idx + 1, 'ι') => ("e", 2),
+            'ε' | 'ο' | 'υ' if plain(idx + 1, 'ι') => ("i", 2),
+            'ο' if plain(idx + 1, 'υ') => ("u", 2),
+            'α' | 'ε' | 'η' if plain(idx + 1, 'υ') => {
+                let vowel = match letter.base {
+                    'α' => "a",
+                    'ε' => "e",
+                    _ => "i",
+                };
+                out[syllable].push_str(vowel);
+                let consonant = if voiced_next(idx + 2) { "v" } else { "f" };
+                out[letters[idx + 1].syllable].push_str(consonant);
+                idx += 2;
+                continue;
+            }
+            'α' => ("a", 1),
+            'ε' => ("e", 1),
+            'η' | 'ι' | 'υ' => ("i", 1),
+            'ο' | 'ω' => ("o", 1),
+            // Synizesis: the ι (or ει) is a glide before a vowel (για, γεια, χρόνια)
+            ch if !is_vowel(ch) && glide_len(idx) > 0 => {
+                if let Some(palatal) = palatal(ch) {
+                    out[syllable].push_str(palatal);
+                } else {
+                    out[syllable].push_str(consonant(ch, idx, &letters, voiced_next(idx + 1)));
+                    out[syllable].push('j');
+                }
+                idx += 1 + glide_len(idx);
+                continue;
+            }
+            'μ' if base(idx + 1) == Some('π') => (if idx == 0 { "b" } else { "mb" }, 2),
+            'ν' if base(idx + 1) == Some('τ') => (if idx == 0 { "d" } else { "nd" }, 2),
+            'γ' if base(idx + 1) == Some('κ') => (if idx == 0 { "g" } else { "ŋg" }, 2),
+            'γ' if base(idx + 1) == Some('γ') => {
+                out[syllable].push('ŋ');
+                out[letters[idx + 1].syllable].push('g');
+                idx += 2;
+                continue;
+            }
+            'γ' if matches!(base(idx + 1), Some('χ' | 'ξ')) => ("ŋ", 1),
+            'τ' if base(idx + 1) == Some('σ') => ("ts", 2),
+            'τ' if base(idx + 1) == Some('ζ') => ("dz", 2),
+            // Double consonants are pronounced once
+            ch if !is_vowel(ch) && base(idx + 1) == Some(ch) => ("", 1),
+            ch if is_vowel(ch) || ch.is_alphabetic() && !is_greek_letter(ch) => {
+                out[syllable].push(ch);
+                idx += 1;
+                continue;
+            }
+            ch if is_greek_letter(ch) => (consonant(ch, idx, &letters, voiced_next(idx + 1)), 1),
+            _ => ("", 1),
+        };
+        out[syllable].push_str(phonemes);
+        idx += len;
+    }
+    out
+}
+
+fn consonant(ch: char, idx: usize, letters: &[Letter], voiced_next: bool) -> &'static str {
+    let front = is_front(letters, idx + 1);
+    match ch {
+        'β' => "v",
+        'γ' if front => "ʝ",
+        'γ' => "ɣ",
+        'δ' => "ð",
+        'ζ' => "z",
+        'θ' => "θ",
+        'κ' if front => "c",
+        'κ' => "k",
+        'λ' => "l",
+        'μ' => "m",
+        'ν' => "n",
+        'ξ' => "ks",
+        'π' => "p",
+        'ρ' => "r",
+        'σ' | 'ς' if voiced_next && letters.get(idx + 1).is_some_and(|l| !is_vowel(l.base)) => {
+            "z"
+        }
+        'σ' | 'ς' => "s",
+        'τ' => "t",
+        'φ' => "f",
+        'χ' if front => "ç",
+        'χ' => "x",
+        'ψ' => "ps",
+        _ => "",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transcribe(word: &str) -> String {
+        tts_syllables(word)
+            .iter()
+            .map(|syllable| {
+                let stress = if syllable.stressed { "ˈ" } else { "" };
+                format!("{stress}{}", syllable.phonemes)
+            })
+            .collect::<Vec<_>>()
+            .join(".")
+    }
+
+    #[test]
+    fn test_transcription() {
+        let cases = [
+            ("άνθρωπος", "ˈan.θro.pos"),
+            ("μπαμπάς", "ba.ˈmbas"),
+            ("αυτοκίνητο", "af.to.ˈci.ni.to"),
+            ("αύριο", "ˈav.ri.o"),
+            ("άγγελος", "ˈaŋ.ge.los"),
+            ("παιδιά", "pe.ði.ˈa"),
+            ("χρόνια", "ˈxro.ɲa"),
+            ("χέρι", "ˈçe.ri"),
+            ("θάλασσα", "ˈθa.la.sa"),
+            ("κόσμος", "ˈko.zmos"),
+            ("τσάι", "ˈtsa.i"),
+            ("ευχαριστώ", "ef.xa.ri.ˈsto"),
+            ("γεια", "ˈʝa"),
+            ("το", "to"),
+        ];
+        for (word, expected) in cases {
+            assert_eq!(transcribe(word), expected, "{word}");
+        }
+    }
+
+    #[test]
+    fn test_number_to_words() {
+        let cases = [
+            (7, "επτά"),
+            (13, "δεκατρία"),
+            (100, "εκατό"),
+            (101, "εκατόν ένα"),
+            (1000, "χίλια"),
+            (13_000, "δεκατρείς χιλιάδες"),
+            (21_000, "είκοσι μία χιλιάδες"),
+            (200_000, "διακόσιες χιλιάδες"),
+            (1_000_001, "ένα εκατομμύριο ένα"),
+            (1_200_000_000, "ένα δισεκατομμύριο διακόσια εκατομμύρια"),
+        ];
+        for (n, expected) in cases {
+            assert_eq!(number_to_words(n), expected, "{n}");
+        }
+    }
+
+    #[test]
+    fn test_tts_tokens_numbers() {
+        let texts =
+            |text| -> Vec<String> { tts_tokens(text).into_iter().map(|t| t.text).collect() };
+        assert_eq!(texts("1.500"), ["χίλια", "πεντακόσια"]);
+        assert_eq!(texts("3,05"), ["τρία", "κόμμα", "μηδέν", "πέντε"]);
+        assert_eq!(texts("(1821)"), ["χίλια", "οκτακόσια", "είκοσι", "ένα"]);
+        let tokens = tts_tokens("Τέλος... Ή όχι;");
+        let kinds: Vec<_> = tokens.iter().map(|t| t.kind).collect();
+        assert_eq!(
+            kinds,
+            [
+                TtsTokenKind::Word,
+                TtsTokenKind::SentenceEnd,
+                TtsTokenKind::Word,
+                TtsTokenKind::Word,
+                TtsTokenKind::SentenceEnd,
+            ]
+        );
+        assert_eq!(tokens[1].source, "...");
+    }
+
+    #[test]
+    fn test_tts_tokens_abbreviations() {
+        let tokens = tts_tokens("π.χ. ο κ. Παπαδόπουλος");
+        let texts: Vec<_> = tokens.iter().map(|t| t.text.as_str()).collect();
+        assert_eq!(
+            texts,
+            ["παραδείγματος", "χάρη", "ο", "κύριος", "Παπαδόπουλος"]
+        );
+        assert_eq!(tokens[0].source, "π.χ.");
+        assert_eq!(tokens[0].span, 0..6);
+        assert_eq!(tokens[0].kind, TtsTokenKind::Abbreviation);
+        // Not followed by a dot
+        assert_eq!(tts_tokens("κα")[0].kind, TtsTokenKind::Word);
+    }
+}
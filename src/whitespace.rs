@@ -0,0 +1,171 @@
+//! Normalization of whitespace.
+//!
+//! The conversions of grac keep whitespace as written, so that this is a
+//! separate pass, to be run before or after them.
+
+use std::borrow::Cow;
+
+/// Spaces of a fixed width, or that do not break.
+const SPECIAL_SPACES: [char; 15] = [
+    '\u{00A0}', // NO-BREAK SPACE
+    '\u{2000}', // EN QUAD
+    '\u{2001}', // EM QUAD
+    '\u{2002}', // EN SPACE
+    '\u{2003}', // EM SPACE
+    '\u{2004}', // THREE-PER-EM SPACE
+    '\u{2005}', // FOUR-PER-EM SPACE
+    '\u{2006}', // SIX-PER-EM SPACE
+    '\u{2007}', // FIGURE SPACE
+    '\u{2008}', // PUNCTUATION SPACE
+    '\u{2009}', // THIN SPACE, as before the dash of Greek dialogue
+    '\u{200A}', // HAIR SPACE
+    '\u{202F}', // NARROW NO-BREAK SPACE
+    '\u{205F}', // MEDIUM MATHEMATICAL SPACE
+    '\u{3000}', // IDEOGRAPHIC SPACE
+];
+
+/// Options for [`normalize_whitespace`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct WhitespaceOptions {
+    /// Replace a run of spaces inside a line by its first space.
+    pub collapse_runs: bool,
+    /// Replace no-break, thin and other fixed-width spaces by a plain space.
+    pub plain_spaces: bool,
+}
+
+impl WhitespaceOptions {
+    pub const fn new() -> Self {
+        Self {
+            collapse_runs: true,
+            plain_spaces: true,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_collapse_runs(mut self, collapse_runs: bool) -> Self {
+        self.collapse_runs = collapse_runs;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_plain_spaces(mut self, plain_spaces: bool) -> Self {
+        self.plain_spaces = plain_spaces;
+        self
+    }
+}
+
+impl Default for WhitespaceOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Whitespace that does not end a line.
+fn is_space(ch: char) -> bool {
+    ch.is_whitespace()
+        && !matches!(
+            ch,
+            '\n' | '\u{000B}' | '\u{000C}' | '\r' | '\u{0085}' | '\u{2028}' | '\u{2029}'
+        )
+}
+
+/// Normalize the spaces of a text.
+///
+/// Line breaks are never changed, nor are spaces at the start or end of the
+/// text: runs are only collapsed, not removed.
+///
+/// # Example
+///
+/// ```
+/// use grac::{WhitespaceOptions, normalize_whitespace};
+///
+/// let text = "Είπε:\n\n―\u{2009}Την  είδα\u{00A0}χθες.\r\n";
+/// let options = WhitespaceOptions::new();
+/// assert_eq!(normalize_whitespace(text, &options), "Είπε:\n\n― Την είδα χθες.\r\n");
+///
+/// let options = WhitespaceOptions::new().with_plain_spaces(false);
+/// assert_eq!(normalize_whitespace(text, &options), "Είπε:\n\n―\u{2009}Την είδα\u{00A0}χθες.\r\n");
+/// ```
+pub fn normalize_whitespace(text: &str, options: &WhitespaceOptions) -> String {
+    let mut prev_space = false;
+    text.chars()
+        .filter_map(|ch| normalize_char(ch, &mut prev_space, options))
+        .collect()
+}
+
+/// The char to write instead of `ch`, if any.
+fn normalize_char(ch: char, prev_space: &mut bool, options: &WhitespaceOptions) -> Option<char> {
+    let space = is_space(ch);
+    if space && *prev_space && options.collapse_runs {
+        return None;
+    }
+    *prev_space = space;
+    if options.plain_spaces && SPECIAL_SPACES.contains(&ch) {
+        Some(' ')
+    } else {
+        Some(ch)
+    }
+}
+
+/// Same as [`normalize_whitespace`], borrowing the text when it is unchanged.
+///
+/// # Example
+///
+/// ```
+/// use std::borrow::Cow;
+///
+/// use grac::{WhitespaceOptions, normalize_whitespace_cow};
+///
+/// let options = WhitespaceOptions::new();
+/// assert!(matches!(normalize_whitespace_cow("Ναι, όχι.\n\n", &options), Cow::Borrowed(_)));
+/// assert_eq!(normalize_whitespace_cow("Ναι,  όχι.", &options), "Ναι, όχι.");
+/// ```
+pub fn normalize_whitespace_cow<'a>(text: &'a str, options: &WhitespaceOptions) -> Cow<'a, str> {
+    let mut prev_space = false;
+    let unchanged = text
+        .chars()
+        .all(|ch| normalize_char(ch, &mut prev_space, options) == Some(ch));
+    if unchanged {
+        Cow::Borrowed(text)
+    } else {
+        Cow::Owned(normalize_whitespace(text, options))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_special_spaces() {
+        for ch in SPECIAL_SPACES {
+            assert!(is_space(ch), "{ch:?}");
+        }
+    }
+
+    #[test]
+    fn test_normalize_whitespace() {
+        let options = WhitespaceOptions::new();
+        for (text, expected) in [
+            ("", ""),
+            ("  καὶ \t τὸ  ", " καὶ τὸ "),
+            ("α\n\n\r\nβ", "α\n\n\r\nβ"),
+            ("α \n  β", "α \n β"),
+            ("α\u{00A0}\u{00A0}β", "α β"),
+            ("\u{202F}»", " »"),
+        ] {
+            assert_eq!(normalize_whitespace(text, &options), expected, "{text:?}");
+            assert_eq!(normalize_whitespace_cow(text, &options), expected);
+        }
+
+        let options = WhitespaceOptions::new().with_collapse_runs(false);
+        assert_eq!(normalize_whitespace("α\u{2009} β", &options), "α  β");
+        let options = options.with_plain_spaces(false);
+        let text = "α\u{2009}  β";
+        assert!(matches!(
+            normalize_whitespace_cow(text, &options),
+            Cow::Borrowed(_)
+        ));
+    }
+}
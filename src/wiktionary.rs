@@ -0,0 +1,310 @@
+//! Importer for Greek Wiktionary XML dumps (`elwiktionary-*-pages-articles.xml`).
+//!
+//! The synizesis lookup table follows the syllabification of el.wiktionary:
+//! this makes it reproducible from a dump. Only the Greek section of each page is
+//! read, and only its `{{συλλαβισμός}}` and `{{ΔΦΑ}}` templates.
+//!
+//! Requires the `wiktionary` feature.
+
+use std::io::BufRead;
+
+use quick_xml::Reader;
+use quick_xml::events::Event;
+
+use crate::corpus::Syllabification;
+use crate::formats::FormatError;
+use crate::syllabify::{Merge, syllabify, syllabify_with_merge};
+
+/// Heading of the Greek section of a page.
+const GREEK_SECTION: &str = "{{-el-}}";
+
+/// Templates giving the syllables of a word: `{{συλλαβισμός|χρό|νια}}`.
+const SYLLABLES_TEMPLATES: [&str; 2] = ["συλλαβισμός", "συλλ"];
+
+/// Template giving the pronunciation of a word: `{{ΔΦΑ|ˈxɾo.ɲa|γλ=el}}`.
+const IPA_TEMPLATE: &str = "ΔΦΑ";
+
+/// The Greek data of a Wiktionary page.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WiktionaryEntry {
+    /// The title of the page.
+    pub word: String,
+    /// Syllables of the `{{συλλαβισμός}}` template, if any.
+    pub syllables: Option<Syllabification>,
+    /// Pronunciation of the `{{ΔΦΑ}}` template, if any.
+    pub ipa: Option<String>,
+}
+
+impl WiktionaryEntry {
+    /// Number of syllables of the pronunciation, separated by `.` or stress marks.
+    pub fn ipa_syllable_count(&self) -> Option<usize> {
+        let ipa = self.ipa.as_deref()?;
+        let count = ipa
+            .split(['.', 'ˈ', 'ˌ'])
+            .filter(|syllable| !syllable.trim().is_empty())
+            .count();
+        (count > 0).then_some(count)
+    }
+
+    /// The syllabification of Wiktionary.
+    ///
+    /// When the page has a pronunciation but no syllables, the syllables are
+    /// those of [`syllabify`], with synizesis at the last syllable if the
+    /// pronunciation needs it.
+    pub fn syllabification(&self) -> Option<Syllabification> {
+        if let Some(syllables) = &self.syllables {
+            return (syllables.concat() == self.word).then(|| syllables.clone());
+        }
+        let count = self.ipa_syllable_count()?;
+        [
+            syllabify(&self.word),
+            syllabify_with_merge(&self.word, Merge::from_indices(&[1])),
+        ]
+        .into_iter()
+        .find(|syllables| syllables.len() == count)
+        .map(|syllables| syllables.iter().map(ToString::to_string).collect())
+    }
+}
+
+/// Read the Greek entries of a Wiktionary dump, calling `f` on every page of
+/// the main namespace that has a Greek section.
+///
+/// The dump is read one page at a time, so that it does not need to fit in memory.
+///
+/// # Errors
+///
+/// Returns [`FormatError::Io`] if the dump can not be read, and
+/// [`FormatError::Xml`] if it is not well-formed XML.
+///
+/// # Example
+///
+/// ```
+/// use grac::wiktionary::read_dump;
+///
+/// let dump = "<mediawiki><page><title>χρόνια</title><ns>0</ns><revision>\
+///     <text>=={{-el-}}==\n* {{ΔΦΑ|ˈxɾo.ɲa|γλ=el}}\n* {{συλλαβισμός|χρό|νια}}</text>\
+///     </revision></page></mediawiki>";
+/// let mut entries = Vec::new();
+/// read_dump(dump.as_bytes(), |entry| entries.push(entry)).unwrap();
+/// assert_eq!(entries[0].word, "χρόνια");
+/// assert_eq!(entries[0].syllables.as_ref().unwrap().join("-"), "χρό-νια");
+/// assert_eq!(entries[0].ipa.as_deref(), Some("ˈxɾo.ɲa"));
+/// ```
+pub fn read_dump(
+    input: impl BufRead,
+    mut f: impl FnMut(WiktionaryEntry),
+) -> Result<(), FormatError> {
+    let mut reader = Reader::from_reader(input);
+    let mut buf = Vec::new();
+    // Name of the innermost open element.
+    let mut element = Vec::new();
+    let (mut title, mut ns, mut text) = (String::new(), String::new(), String::new());
+    loop {
+        let event = reader
+            .read_event_into(&mut buf)
+            .map_err(|err| FormatError::Xml {
+                position: reader.error_position(),
+                message: err.to_string(),
+            })?;
+        match event {
+            Event::Eof => break,
+            Event::Start(start) => {
+                element = start.name().as_ref().to_vec();
+                if element == b"page" {
+                    title.clear();
+                    ns.clear();
+                    text.clear();
+                }
+            }
+            Event::Text(content) => {
+                let content = content.unescape().map_err(|err| FormatError::Xml {
+                    position: reader.buffer_position(),
+                    message: err.to_string(),
+                })?;
+                match element.as_slice() {
+                    b"title" => title.push_str(&content),
+                    b"ns" => ns.push_str(&content),
+                    b"text" => text.push_str(&content),
+                    _ => (),
+                }
+            }
+            Event::End(end) => {
+                if end.name().as_ref() == b"page"
+                    && ns.trim() == "0"
+                    && let Some(entry) = parse_page(&title, &text)
+                {
+                    f(entry);
+                }
+                element.clear();
+            }
+            _ => (),
+        }
+        buf.clear();
+    }
+    Ok(())
+}
+
+/// Words whose syllabification in Wiktionary differs from [`syllabify`], sorted.
+///
+/// These are the entries missing from the synizesis lookup table. They can be
+/// written with [`lookup_entry`](crate::corpus::lookup_entry) for the generated
+/// table, or with [`table_entry`](crate::corpus::table_entry) to be loaded at runtime.
+///
+/// # Errors
+///
+/// Returns the errors of [`read_dump`].
+///
+/// # Example
+///
+/// ```
+/// use grac::wiktionary::exceptions_from_dump;
+///
+/// let dump = "<mediawiki>\
+///     <page><title>κάποιος</title><ns>0</ns><revision><text>=={{-el-}}==\n{{ΔΦΑ|ˈka.pços}}</text></revision></page>\
+///     <page><title>δόξα</title><ns>0</ns><revision><text>=={{-el-}}==\n{{ΔΦΑ|ˈðo.ksa}}</text></revision></page>\
+///     </mediawiki>";
+/// let exceptions = exceptions_from_dump(dump.as_bytes()).unwrap();
+/// assert_eq!(exceptions.len(), 1);
+/// assert_eq!(exceptions[0].1.join("-"), "κά-ποιος");
+/// ```
+pub fn exceptions_from_dump(
+    input: impl BufRead,
+) -> Result<Vec<(String, Syllabification)>, FormatError> {
+    let mut exceptions = Vec::new();
+    read_dump(input, |entry| {
+        if let Some(syllables) = entry.syllabification()
+            && *syllables != *syllabify(&entry.word)
+        {
+            exceptions.push((entry.word, syllables));
+        }
+    })?;
+    exceptions.sort();
+    exceptions.dedup();
+    Ok(exceptions)
+}
+
+fn parse_page(title: &str, text: &str) -> Option<WiktionaryEntry> {
+    let section = greek_section(text)?;
+    let syllables = SYLLABLES_TEMPLATES
+        .iter()
+        .find_map(|name| template_params(section, name))
+        .filter(|params| !params.is_empty())
+        .map(|params| params.into_iter().map(str::to_string).collect());
+    let ipa = template_params(section, IPA_TEMPLATE)
+        .and_then(|params| params.first().map(|ipa| ipa.to_string()));
+    Some(WiktionaryEntry {
+        word: title.trim().to_string(),
+        syllables,
+        ipa,
+    })
+}
+
+/// From the Greek heading up to the next language heading.
+fn greek_section(text: &str) -> Option<&str> {
+    let start = text.find(GREEK_SECTION)? + GREEK_SECTION.len();
+    let rest = &text[start..];
+    let end = rest.find("\n=={{-").unwrap_or(rest.len());
+    Some(&rest[..end])
+}
+
+/// Positional parameters of the first use of a template.
+fn template_params<'a>(text: &'a str, name: &str) -> Option<Vec<&'a str>> {
+    let open = format!("{{{{{name}");
+    let mut rest = text;
+    loop {
+        let start = rest.find(&open)? + open.len();
+        rest = &rest[start..];
+        // Not a longer template name.
+        if rest.starts_with(['|', '}']) {
+            break;
+        }
+    }
+    let end = rest.find("}}")?;
+    let params = rest[..end]
+        .split('|')
+        .skip(1)
+        .map(str::trim)
+        .filter(|param| !param.is_empty() && !param.contains('='))
+        .collect();
+    Some(params)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(title: &str, ns: u32, text: &str) -> String {
+        format!(
+            "<page><title>{title}</title><ns>{ns}</ns><revision><text xml:space=\"preserve\">{text}</text></revision></page>"
+        )
+    }
+
+    #[test]
+    fn test_read_dump() {
+        let dump = [
+            "<mediawiki xmlns=\"http://www.mediawiki.org/xml/export-0.11/\">".to_string(),
+            page("Κατηγορία:Ουσιαστικά", 14, "=={{-el-}}=="),
+            page("book", 0, "=={{-en-}}==\n{{ΔΦΑ|bʊk}}"),
+            page(
+                "καλά",
+                0,
+                "=={{-el-}}==\n{{ΔΦΑ|kaˈla|γλ=el}}\n{{συλλαβισμόςx|κ}}\n=={{-grc-}}==\n{{συλλαβισμός|κα|λά}}",
+            ),
+            page("&lt;ό&gt;", 0, "=={{-el-}}==\n{{συλλ|ό|τ=ό}}"),
+            "</mediawiki>".to_string(),
+        ]
+        .concat();
+        let mut entries = Vec::new();
+        read_dump(dump.as_bytes(), |entry| entries.push(entry)).unwrap();
+        assert_eq!(
+            entries,
+            [
+                WiktionaryEntry {
+                    word: "καλά".to_string(),
+                    syllables: None,
+                    ipa: Some("kaˈla".to_string()),
+                },
+                WiktionaryEntry {
+                    word: "<ό>".to_string(),
+                    syllables: Some(vec!["ό".to_string()]),
+                    ipa: None,
+                },
+            ]
+        );
+        assert_eq!(entries[0].ipa_syllable_count(), Some(2));
+        assert_eq!(entries[0].syllabification().unwrap().join("-"), "κα-λά");
+        // Syllables that do not spell the word
+        assert_eq!(entries[1].syllabification(), None);
+    }
+
+    #[test]
+    fn test_exceptions_from_dump() {
+        let dump = [
+            page(
+                "λεβέντικια",
+                0,
+                "=={{-el-}}==\n{{συλλαβισμός|λε|βέ|ντι|κια}}",
+            ),
+            page("φασκόμηλια", 0, "=={{-el-}}==\n{{ΔΦΑ|fa.ˈsko.mi.ʎa}}"),
+            page("ζήλια", 0, "=={{-el-}}==\n{{συλλαβισμός|ζή|λια}}"),
+            page("ζήλια", 0, "=={{-el-}}==\n{{συλλαβισμός|ζή|λια}}"),
+        ]
+        .concat();
+        let exceptions = exceptions_from_dump(dump.as_bytes()).unwrap();
+        let words: Vec<String> = exceptions
+            .iter()
+            .map(|(word, syllables)| format!("{word} {}", syllables.join("-")))
+            .collect();
+        // ζήλια is already in the lookup table
+        assert_eq!(
+            words,
+            ["λεβέντικια λε-βέ-ντι-κια", "φασκόμηλια φα-σκό-μη-λια"]
+        );
+    }
+
+    #[test]
+    fn test_malformed_dump() {
+        let err = exceptions_from_dump("<page><title>α</page>".as_bytes()).unwrap_err();
+        assert!(matches!(err, FormatError::Xml { .. }), "{err:?}");
+    }
+}
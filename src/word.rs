@@ -0,0 +1,302 @@
+//! A word decoded once, for the passes that look at its chars.
+
+use std::ops::Range;
+
+use unicode_normalization::UnicodeNormalization;
+
+use crate::chars::{
+    has_combining_marks, is_combining_mark, is_greek_char, is_greek_char_strict, is_greek_letter,
+};
+use crate::monotonic::{Converter, DiaeresisFixer, DiaeresisPolicy, not_punct};
+
+// Classes of a char, as bit flags.
+/// Can be part of a Greek word (cf. [`is_greek_word`](crate::is_greek_word)).
+const GREEK: u8 = 1;
+/// Same as `GREEK`, with [`is_greek_char_strict`].
+const GREEK_STRICT: u8 = 1 << 1;
+/// Not punctuation (cf. [`split_punctuation`](crate::split_punctuation)).
+const CORE: u8 = 1 << 2;
+/// Left unchanged by the conversion of accents to monotonic: ASCII, and the
+/// Greek and Coptic block, whose only diacritics are the acute and diaeresis.
+const PLAIN: u8 = 1 << 3;
+
+const fn fits_greek_word(ch: char, is_greek: bool) -> bool {
+    is_greek || ch == '\u{02BC}' || is_combining_mark(ch)
+}
+
+// Without the chars that NFD maps to others: ʹ, ; and ·
+const fn is_plain(ch: char) -> bool {
+    ch.is_ascii()
+        || matches!(ch, '\u{0370}'..='\u{03FF}')
+            && !matches!(ch, '\u{0374}' | '\u{037E}' | '\u{0387}')
+}
+
+fn classify(ch: char) -> u8 {
+    let plain = if is_plain(ch) { PLAIN } else { 0 };
+    // Fast paths, without looking up the Unicode tables.
+    if ch.is_ascii() {
+        return if ch.is_ascii_alphabetic() {
+            CORE | plain
+        } else {
+            GREEK | GREEK_STRICT | plain
+        };
+    }
+    if is_greek_letter(ch) {
+        let strict = if is_greek_char_strict(ch) {
+            GREEK_STRICT
+        } else {
+            0
+        };
+        return GREEK | CORE | strict | plain;
+    }
+    let alphabetic = ch.is_alphabetic();
+    let mut class = plain;
+    if fits_greek_word(ch, is_greek_char(ch)) || !alphabetic {
+        class |= GREEK;
+    }
+    if fits_greek_word(ch, is_greek_char_strict(ch)) || !alphabetic {
+        class |= GREEK_STRICT;
+    }
+    if not_punct(ch) {
+        class |= CORE;
+    }
+    class
+}
+
+/// A word in NFC, with its chars decoded and classified once.
+///
+/// [`to_monotonic`](crate::to_monotonic) looks at every word several times:
+/// is it Greek, where is its punctuation, is it in capitals. This buffer
+/// answers all of them from a single decoding, and keeps its allocations
+/// between words, so that it can be reused over a whole text.
+///
+/// # Example
+///
+/// ```
+/// use grac::GreekWordBuf;
+///
+/// let mut buf = GreekWordBuf::new();
+/// buf.set("«ἌΝΘΡΩΠΟΣ»");
+/// assert!(buf.is_greek());
+/// assert!(buf.is_all_caps());
+/// assert_eq!(buf.split_punctuation(), ("«", "ἌΝΘΡΩΠΟΣ", "»"));
+///
+/// // Reuse the buffer for every word
+/// let words: Vec<String> = "ὁ ἄνθρωπος".split(' ').map(|word| buf.to_monotonic(word)).collect();
+/// assert_eq!(words, ["ο", "άνθρωπος"]);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct GreekWordBuf {
+    /// The word in NFC.
+    text: String,
+    /// Byte offset in `text`, char and classes.
+    chars: Vec<(usize, char, u8)>,
+    /// Byte range of the word without punctuation.
+    core: Range<usize>,
+}
+
+impl GreekWordBuf {
+    pub const fn new() -> Self {
+        Self {
+            text: String::new(),
+            chars: Vec::new(),
+            core: 0..0,
+        }
+    }
+
+    /// Decode `word` into the buffer, replacing the previous word.
+    pub fn set(&mut self, word: &str) {
+        self.fill(word, 0);
+    }
+
+    /// Same as [`set`](Self::set), but stop decoding at the first char that
+    /// can not be part of a Greek word (strictly or not), and return whether
+    /// there was none.
+    ///
+    /// Most non Greek words are thus rejected after their first letter. When
+    /// this returns `false`, only [`as_str`](Self::as_str) is meaningful.
+    pub(crate) fn set_if_greek(&mut self, word: &str, strict: bool) -> bool {
+        self.fill(word, if strict { GREEK_STRICT } else { GREEK })
+    }
+
+    fn fill(&mut self, word: &str, required: u8) -> bool {
+        self.text.clear();
+        if has_combining_marks(word) {
+            self.text.extend(word.nfc());
+        } else {
+            self.text.push_str(word);
+        }
+        self.chars.clear();
+        for (idx, ch) in self.text.char_indices() {
+            let class = classify(ch);
+            if class & required != required {
+                self.core = 0..0;
+                return false;
+            }
+            self.chars.push((idx, ch, class));
+        }
+        self.core = self.core_bytes();
+        true
+    }
+
+    /// The word in NFC.
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    pub fn chars(&self) -> impl DoubleEndedIterator<Item = char> + '_ {
+        self.chars.iter().map(|&(_, ch, _)| ch)
+    }
+
+    /// Same as [`is_greek_word`](crate::is_greek_word).
+    pub fn is_greek(&self) -> bool {
+        self.all(GREEK)
+    }
+
+    /// Same as [`is_greek_word_strict`](crate::is_greek_word_strict).
+    pub fn is_greek_strict(&self) -> bool {
+        self.all(GREEK_STRICT)
+    }
+
+    /// Same as [`split_punctuation`](crate::split_punctuation).
+    pub fn split_punctuation(&self) -> (&str, &str, &str) {
+        let Range { start, end } = self.core;
+        (
+            &self.text[..start],
+            &self.text[start..end],
+            &self.text[end..],
+        )
+    }
+
+    /// Check if the word, without punctuation, is written in capitals, with at
+    /// least two letters.
+    ///
+    /// Capitals with iota subscript (ᾼ) are titlecase, so this checks that there
+    /// are no lowercase letters instead.
+    pub fn is_all_caps(&self) -> bool {
+        let mut letters = self
+            .core_chars()
+            .map(|(ch, _)| ch)
+            .filter(|ch| ch.is_alphabetic());
+        letters.clone().nth(1).is_some() && letters.all(|ch| !ch.is_lowercase())
+    }
+
+    /// Check if the word, without punctuation, has no diacritics but the acute
+    /// and diaeresis, so that converting its accents can be skipped.
+    pub(crate) fn is_core_plain(&self) -> bool {
+        self.core_chars().all(|(_, class)| class & PLAIN != 0)
+    }
+
+    /// Same as [`to_monotonic`](crate::to_monotonic) over a single word,
+    /// reusing the buffer.
+    pub fn to_monotonic(&mut self, word: &str) -> String {
+        let diaereses = DiaeresisFixer::shared(&DiaeresisPolicy::default());
+        Converter::default().convert_word(&diaereses, self, word)
+    }
+
+    fn all(&self, flag: u8) -> bool {
+        self.chars.iter().all(|&(_, _, class)| class & flag != 0)
+    }
+
+    fn core_chars(&self) -> impl Iterator<Item = (char, u8)> + Clone + '_ {
+        self.chars
+            .iter()
+            .filter(|&&(idx, _, _)| self.core.contains(&idx))
+            .map(|&(_, ch, class)| (ch, class))
+    }
+
+    fn core_bytes(&self) -> Range<usize> {
+        let mut core = self
+            .chars
+            .iter()
+            .filter(|&&(_, _, class)| class & CORE != 0);
+        match (core.next(), core.next_back()) {
+            (Some(&(start, _, _)), Some(&(end, ch, _))) => start..end + ch.len_utf8(),
+            (Some(&(start, ch, _)), None) => start..start + ch.len_utf8(),
+            // If there are not alphabetic chars, treat the word as left punctuation.
+            _ => self.text.len()..self.text.len(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::monotonic::convert_to_acute;
+    use crate::{is_greek_word, is_greek_word_strict, split_punctuation};
+
+    #[test]
+    fn test_agrees_with_str_functions() {
+        let mut buf = GreekWordBuf::new();
+        for word in [
+            "",
+            ";?...",
+            "«καλημέρα»",
+            "λέ-ξη...",
+            "2ος",
+            "Poète.",
+            "ϣαϫε",
+            "ʼΑ",
+            "ά",
+            "ά\u{301}",
+            "α\u{301}ν",
+        ] {
+            buf.set(word);
+            let nfc: String = word.nfc().collect();
+            assert_eq!(buf.as_str(), nfc);
+            assert!(buf.chars().eq(nfc.chars()), "{word}");
+            assert_eq!(buf.is_greek(), is_greek_word(word), "{word}");
+            assert_eq!(buf.is_greek_strict(), is_greek_word_strict(word), "{word}");
+            assert_eq!(buf.split_punctuation(), split_punctuation(&nfc), "{word}");
+        }
+    }
+
+    #[test]
+    fn test_classify() {
+        for ch in (0..0x3000).filter_map(char::from_u32) {
+            let class = classify(ch);
+            let s = ch.to_string();
+            assert_eq!(class & GREEK != 0, is_greek_word(&s), "U+{:04X}", ch as u32);
+            assert_eq!(
+                class & GREEK_STRICT != 0,
+                is_greek_word_strict(&s),
+                "U+{:04X}",
+                ch as u32
+            );
+            assert_eq!(class & CORE != 0, not_punct(ch), "U+{:04X}", ch as u32);
+        }
+    }
+
+    #[test]
+    fn test_plain_chars() {
+        let plain = (0..0x0400)
+            .filter_map(char::from_u32)
+            .filter(|&ch| classify(ch) & PLAIN != 0);
+        for ch in plain {
+            let s = ch.to_string();
+            assert_eq!(convert_to_acute(&s), s, "U+{:04X}", ch as u32);
+        }
+        let mut buf = GreekWordBuf::new();
+        buf.set("«ἄνθρωπος»");
+        assert!(!buf.is_core_plain());
+        buf.set("«ΐδιος»");
+        assert!(buf.is_core_plain());
+    }
+
+    #[test]
+    fn test_is_all_caps() {
+        let mut buf = GreekWordBuf::new();
+        for (word, expected) in [
+            ("ΚΑῚ", true),
+            ("«ΚΑῚ»", true),
+            ("ᾬΔΕ", true),
+            ("Καὶ", false),
+            ("Ὁ", false),
+            ("12", false),
+        ] {
+            buf.set(word);
+            assert_eq!(buf.is_all_caps(), expected, "{word}");
+        }
+    }
+}
@@ -0,0 +1,297 @@
+//! Line breaking.
+
+use crate::chars::graphemes_el;
+use crate::hyphenate::{
+    HYPHEN_PENALTY, HyphenationOptions, hyphenation_points, scored_hyphenation_points,
+};
+use crate::tokenize::{TokenKind, tokenize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BreakKind {
+    /// Whitespace between words.
+    Space,
+    /// A hyphenation point: a hyphen is added at the end of the line.
+    Hyphen,
+    /// After a hyphen of the text (Ελληνο-τουρκικός): no hyphen is added.
+    ExplicitHyphen,
+    /// A line break of the text, that must be kept.
+    Mandatory,
+}
+
+/// Where a line can be broken, for line breaking algorithms like Knuth-Plass.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BreakPoint {
+    /// Byte offset where the line ends.
+    pub offset: usize,
+    /// Byte offset where the next line starts: breaks at whitespace drop it.
+    pub next_line: usize,
+    pub kind: BreakKind,
+    /// Cost of breaking here: 0 at spaces, and [`FORCED_BREAK`] for mandatory breaks.
+    pub penalty: i32,
+}
+
+/// Penalty of mandatory breaks, as in TeX.
+pub const FORCED_BREAK: i32 = -10_000;
+
+/// Width of a string in columns: combining marks take no space.
+fn width(s: &str) -> usize {
+    graphemes_el(s).count()
+}
+
+/// Wrap text into lines of at most `max_cols` columns, with lines of similar lengths.
+///
+/// Lines are broken at whitespace, that is collapsed to single spaces. Words
+/// longer than a line are hyphenated with `-` at their hyphenation points (cf.
+/// [`hyphenation_points`](crate::hyphenation_points)), so that they are never
+/// split inside a diphthong or with a single letter on a line. A part that still
+/// does not fit is left longer than `max_cols`.
+///
+/// Among the wrappings with the least number of lines, the one with the
+/// shortest longest line is chosen, as is usual for subtitles.
+///
+/// # Example
+///
+/// ```
+/// use grac::wrap_greek;
+///
+/// let text = "Δεν ξέρω τι να πω για όλα αυτά.";
+/// assert_eq!(wrap_greek(text, 42), ["Δεν ξέρω τι να πω για όλα αυτά."]);
+/// assert_eq!(wrap_greek(text, 20), ["Δεν ξέρω τι να", "πω για όλα αυτά."]);
+/// assert_eq!(wrap_greek("ηλεκτροκαρδιογράφημα", 10), ["ηλεκτρο-", "καρδιογρά-", "φημα"]);
+/// ```
+pub fn wrap_greek(text: &str, max_cols: usize) -> Vec<String> {
+    let max_cols = max_cols.max(1);
+    let words: Vec<&str> = text.split_whitespace().collect();
+    let lines = wrap_words(&words, max_cols);
+
+    // Narrow the lines as long as the number of lines does not grow, without
+    // hyphenating more words.
+    let longest_word = words.iter().map(|word| width(word)).max().unwrap_or(0);
+    let (mut lo, mut hi) = (longest_word.min(max_cols), max_cols);
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if wrap_words(&words, mid).len() <= lines.len() {
+            hi = mid;
+        } else {
+            lo = mid + 1;
+        }
+    }
+    wrap_words(&words, hi)
+}
+
+/// Every place where the text can be broken into lines, in order.
+///
+/// Breaks are allowed at whitespace (mandatory if it contains a newline),
+/// after hyphens between letters, and at the hyphenation points of Greek words
+/// (cf. [`hyphenation_points`](crate::hyphenation_points)). Trailing
+/// whitespace is not a break.
+///
+/// # Example
+///
+/// ```
+/// use grac::{BreakKind, break_opportunities};
+///
+/// let text = "Ένα σπίτι\nνέο";
+/// let breaks = break_opportunities(text);
+/// let kinds: Vec<_> = breaks.iter().map(|b| b.kind).collect();
+/// assert_eq!(kinds, [BreakKind::Space, BreakKind::Hyphen, BreakKind::Mandatory]);
+/// assert_eq!(&text[..breaks[1].offset], "Ένα σπί");
+/// assert_eq!(&text[breaks[2].next_line..], "νέο");
+/// assert_eq!(breaks[0].penalty, 0);
+/// ```
+pub fn break_opportunities(text: &str) -> Vec<BreakPoint> {
+    break_opportunities_with_options(text, &HyphenationOptions::new())
+}
+
+/// Same as [`break_opportunities`], with the hyphenation points and penalties of
+/// [`HyphenationOptions`].
+///
+/// Breaks at the hyphens of the text have the default penalty.
+///
+/// # Example
+///
+/// ```
+/// use grac::{HyphenationOptions, break_opportunities_with_options};
+///
+/// // Avoid breaking after the first syllable
+/// let options = HyphenationOptions::new()
+///     .with_penalty_fn(|point| if point.syllables_before == 1 { 1000 } else { 50 });
+/// let breaks = break_opportunities_with_options("θάλασσα", &options);
+/// let penalties: Vec<_> = breaks.iter().map(|b| b.penalty).collect();
+/// assert_eq!(penalties, [1000, 50]);
+/// ```
+pub fn break_opportunities_with_options(
+    text: &str,
+    options: &HyphenationOptions,
+) -> Vec<BreakPoint> {
+    let mut breaks = Vec::new();
+    let mut pending_space: Option<BreakPoint> = None;
+    for token in tokenize(text) {
+        if token.kind == TokenKind::Whitespace {
+            let mandatory = token.text.contains('\n');
+            pending_space = Some(BreakPoint {
+                offset: token.span.start,
+                next_line: token.span.end,
+                kind: if mandatory {
+                    BreakKind::Mandatory
+                } else {
+                    BreakKind::Space
+                },
+                penalty: if mandatory { FORCED_BREAK } else { 0 },
+            });
+            continue;
+        }
+        // Leading whitespace is not a break either.
+        if let Some(space) = pending_space.take()
+            && space.offset > 0
+        {
+            breaks.push(space);
+        }
+        if token.kind != TokenKind::Word {
+            continue;
+        }
+        let start = token.span.start;
+        let mut points: Vec<(usize, BreakKind, i32)> =
+            scored_hyphenation_points(token.text, options)
+                .into_iter()
+                .map(|(point, penalty)| (start + point, BreakKind::Hyphen, penalty))
+                .collect();
+        points.extend(
+            explicit_hyphens(token.text)
+                .map(|point| (start + point, BreakKind::ExplicitHyphen, HYPHEN_PENALTY)),
+        );
+        points.sort_unstable_by_key(|(offset, _, _)| *offset);
+        breaks.extend(
+            points
+                .into_iter()
+                .map(|(offset, kind, penalty)| BreakPoint {
+                    offset,
+                    next_line: offset,
+                    kind,
+                    penalty,
+                }),
+        );
+    }
+    breaks
+}
+
+/// Byte offsets after the hyphens between two letters.
+fn explicit_hyphens(word: &str) -> impl Iterator<Item = usize> + '_ {
+    word.char_indices().filter_map(|(idx, ch)| {
+        let is_between_letters = word[..idx]
+            .chars()
+            .next_back()
+            .is_some_and(char::is_alphabetic)
+            && word[idx + ch.len_utf8()..]
+                .chars()
+                .next()
+                .is_some_and(char::is_alphabetic);
+        (matches!(ch, '-' | '\u{2010}') && is_between_letters).then_some(idx + ch.len_utf8())
+    })
+}
+
+/// Greedy wrapping.
+fn wrap_words(words: &[&str], max_cols: usize) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    let mut line = String::new();
+    for word in words {
+        let line_width = width(&line);
+        if line.is_empty() && width(word) <= max_cols {
+            line.push_str(word);
+        } else if !line.is_empty() && line_width + 1 + width(word) <= max_cols {
+            line.push(' ');
+            line.push_str(word);
+        } else {
+            if !line.is_empty() {
+                lines.push(std::mem::take(&mut line));
+            }
+            let mut parts = hyphenated_parts(word, max_cols);
+            line = parts.pop().unwrap_or_default();
+            lines.extend(parts);
+        }
+    }
+    if !line.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+/// Split a word into lines of at most `max_cols` columns, hyphen included.
+fn hyphenated_parts(word: &str, max_cols: usize) -> Vec<String> {
+    let points = hyphenation_points(word);
+    let mut parts = Vec::new();
+    let mut start = 0;
+    while width(&word[start..]) > max_cols {
+        // The furthest point that fits, or else the nearest one.
+        let candidates = points.iter().filter(|point| **point > start);
+        let fitting = candidates
+            .clone()
+            .rfind(|point| width(&word[start..**point]) < max_cols);
+        let Some(&point) = fitting.or_else(|| candidates.min()) else {
+            break;
+        };
+        parts.push(format!("{}-", &word[start..point]));
+        start = point;
+    }
+    parts.push(word[start..].to_string());
+    parts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wrap_greek() {
+        assert!(wrap_greek("", 10).is_empty());
+        assert!(wrap_greek(" \n ", 10).is_empty());
+        assert_eq!(wrap_greek("α  β\nγ", 10), ["α β γ"]);
+        // Too long, and no hyphenation points
+        assert_eq!(wrap_greek("ευχαριστώ", 1), ["ευ-", "χα-", "ρι-", "στώ"]);
+        assert_eq!(wrap_greek("hello world", 3), ["hello", "world"]);
+    }
+
+    #[test]
+    fn test_wrap_greek_balanced() {
+        let text = "Αυτό είναι ένα μεγάλο κείμενο για υπότιτλους";
+        let lines = wrap_greek(text, 40);
+        assert_eq!(lines, ["Αυτό είναι ένα μεγάλο", "κείμενο για υπότιτλους"]);
+        for max_cols in 1..50 {
+            let lines = wrap_greek(text, max_cols);
+            assert_eq!(lines.join(" ").replace("- ", ""), text);
+            assert!(lines.iter().all(|line| width(line) <= max_cols.max(5)));
+        }
+    }
+
+    #[test]
+    fn test_break_opportunities() {
+        let text = "  Ελληνο-τουρκικός,\n\n«καλημέρα» ";
+        let breaks: Vec<(&str, BreakKind)> = break_opportunities(text)
+            .into_iter()
+            .map(|b| (&text[..b.offset], b.kind))
+            .collect();
+        assert_eq!(
+            breaks,
+            [
+                ("  Ελ", BreakKind::Hyphen),
+                ("  Ελλη", BreakKind::Hyphen),
+                ("  Ελληνο-", BreakKind::ExplicitHyphen),
+                ("  Ελληνο-τουρ", BreakKind::Hyphen),
+                ("  Ελληνο-τουρκι", BreakKind::Hyphen),
+                ("  Ελληνο-τουρκικός,", BreakKind::Mandatory),
+                ("  Ελληνο-τουρκικός,\n\n«κα", BreakKind::Hyphen),
+                ("  Ελληνο-τουρκικός,\n\n«καλη", BreakKind::Hyphen),
+                ("  Ελληνο-τουρκικός,\n\n«καλημέ", BreakKind::Hyphen),
+            ]
+        );
+        assert!(break_opportunities("").is_empty());
+    }
+
+    #[test]
+    fn test_wrap_greek_decomposed() {
+        let text = "ἄνθρωπος ἄνθρωπος";
+        let decomposed: String = unicode_normalization::UnicodeNormalization::nfd(text).collect();
+        assert_eq!(wrap_greek(&decomposed, 8).len(), 2);
+        assert_eq!(wrap_greek(&decomposed, 17).len(), 1);
+    }
+}
@@ -1,7 +1,9 @@
+use grac::Diacritic;
 use grac::Syllables;
 use grac::syllabify;
 use grac::{Merge, syllabify_with_merge};
 use quickcheck::quickcheck;
+use unicode_normalization::UnicodeNormalization;
 
 /// More informative than a simple `assert_eq!` macro.
 macro_rules! assert_eq_dbg {
@@ -625,6 +627,17 @@ impl quickcheck::Arbitrary for GreekWord {
     }
 }
 
+/// A pronounceable word, unlike [`GreekWord`].
+#[derive(Debug, Clone)]
+struct Pseudoword(String);
+
+impl quickcheck::Arbitrary for Pseudoword {
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        let words = grac::generate_pseudowords(1, 1..=6, || u64::arbitrary(g));
+        Self(words[0].clone())
+    }
+}
+
 quickcheck! {
     fn test_fuzz_merge(word: GreekWord) -> bool {
         let _ = grac::syllabify_with_merge(&word.0, Merge::Never);
@@ -632,4 +645,54 @@ quickcheck! {
         let _ = grac::syllabify_with_merge(&word.0, Merge::from_indices(&[1]));
         true
     }
+
+    fn test_normalization_syllabify(word: GreekWord) -> bool {
+        let (nfc, nfd) = normalized(&word.0);
+        let composed = |s: &str| -> Vec<String> { syllabify(s).iter().map(|syl| syl.nfc().collect()).collect() };
+        composed(&nfc) == composed(&nfd)
+    }
+
+    fn test_normalization_diacritics(word: GreekWord) -> bool {
+        let (nfc, nfd) = normalized(&word.0);
+        [Diacritic::ACUTE, Diacritic::GRAVE, Diacritic::CIRCUMFLEX, Diacritic::DIAERESIS]
+            .into_iter()
+            .all(|diacritic| {
+                grac::has_diacritic(nfc.chars(), diacritic) == grac::has_diacritic(nfd.chars(), diacritic)
+                    && grac::diacritic_pos(&nfc, diacritic) == grac::diacritic_pos(&nfd, diacritic)
+            })
+    }
+
+    fn test_normalization_ends_with_diphthong(word: GreekWord) -> bool {
+        let (nfc, nfd) = normalized(&word.0);
+        let nfd_chars: Vec<char> = nfd.chars().collect();
+        let expected = grac::ends_with_diphthong(&nfc);
+        assert_eq!(grac::ends_with_diphthong(&nfd), expected, "{nfc}");
+        assert_eq!(grac::ends_with_diphthong_chars(&nfd_chars), expected, "{nfc}");
+        true
+    }
+
+    fn test_normalization_to_monotonic(word: GreekWord) -> bool {
+        let (nfc, nfd) = normalized(&word.0);
+        if grac::is_greek_word(&nfc) {
+            grac::to_monotonic(&nfc) == grac::to_monotonic(&nfd)
+        } else {
+            // Words that are not Greek (with a ʹ, for instance) keep their
+            // normalization, and only go through the diaeresis pass.
+            let policy = grac::DiaeresisPolicy::default();
+            grac::to_monotonic(&nfc) == grac::normalize_diaereses(&nfc, &policy)
+                && grac::to_monotonic(&nfd) == grac::normalize_diaereses(&nfd, &policy)
+        }
+    }
+
+    fn test_pseudoword_accent(word: Pseudoword) -> bool {
+        let syllables = syllabify(&word.0);
+        syllables.concat() == word.0
+            && syllabify(&grac::remove_diacritics(&word.0, &[Diacritic::ACUTE])).len() == syllables.len()
+            && grac::to_monotonic(&word.0) == word.0
+    }
+}
+
+/// The NFC and NFD forms of a string.
+fn normalized(s: &str) -> (String, String) {
+    (s.nfc().collect(), s.nfd().collect())
 }
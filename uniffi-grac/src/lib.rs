@@ -0,0 +1,225 @@
+//! The core API of grac, for the bindings generated by UniFFI.
+//!
+//! Every type here is a plain copy of its counterpart in grac, so that the
+//! generated bindings only depend on this interface.
+
+use std::collections::HashMap;
+
+use ::grac as _grac;
+
+uniffi::setup_scaffolding!();
+
+#[derive(Debug, Clone, uniffi::Enum)]
+pub enum Merge {
+    Every,
+    Never,
+    Indices { indices: Vec<u32> },
+}
+
+impl From<Merge> for _grac::Merge {
+    fn from(merge: Merge) -> Self {
+        match merge {
+            Merge::Every => Self::Every,
+            Merge::Never => Self::Never,
+            Merge::Indices { indices } => {
+                Self::Indices(indices.into_iter().map(|idx| idx as usize).collect())
+            }
+        }
+    }
+}
+
+/// Cf. `grac::SyllabifyOptions`.
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct SyllabifyOptions {
+    pub merge: Merge,
+    #[uniffi(default = false)]
+    pub digamma_as_consonant: bool,
+    #[uniffi(default = false)]
+    pub morphological: bool,
+}
+
+impl From<SyllabifyOptions> for _grac::SyllabifyOptions {
+    fn from(options: SyllabifyOptions) -> Self {
+        Self::new()
+            .with_merge(options.merge.into())
+            .with_digamma_as_consonant(options.digamma_as_consonant)
+            .with_morphological(options.morphological)
+    }
+}
+
+#[derive(Debug, Clone, Copy, uniffi::Enum)]
+pub enum ProtectedRegions {
+    None,
+    Xml,
+    Markdown,
+    Latex,
+}
+
+/// Cf. `grac::MonotonicOptions`.
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct MonotonicOptions {
+    #[uniffi(default = false)]
+    pub keep_pronoun_accents: bool,
+    #[uniffi(default = false)]
+    pub keep_diaereses: bool,
+    #[uniffi(default = false)]
+    pub strict_greek: bool,
+    #[uniffi(default = false)]
+    pub conservative_names: bool,
+    pub protected: ProtectedRegions,
+    #[uniffi(default = [])]
+    pub protected_words: Vec<String>,
+    pub special_cases: HashMap<String, String>,
+}
+
+impl From<MonotonicOptions> for _grac::MonotonicOptions {
+    fn from(options: MonotonicOptions) -> Self {
+        let protected = match options.protected {
+            ProtectedRegions::None => _grac::ProtectedRegions::new(),
+            ProtectedRegions::Xml => _grac::ProtectedRegions::xml(),
+            ProtectedRegions::Markdown => _grac::ProtectedRegions::markdown(),
+            ProtectedRegions::Latex => _grac::ProtectedRegions::latex(),
+        };
+        let diaeresis = if options.keep_diaereses {
+            _grac::DiaeresisPolicy::Keep
+        } else {
+            _grac::DiaeresisPolicy::RemoveSuperfluous
+        };
+        let mut monotonic = Self::new()
+            .with_keep_pronoun_accents(options.keep_pronoun_accents)
+            .with_protected(protected)
+            .with_diaeresis(diaeresis)
+            .with_strict_greek(options.strict_greek)
+            .with_conservative_names(options.conservative_names);
+        monotonic.special_cases = options.special_cases.into_iter().collect();
+        monotonic.protected_words = options.protected_words.into_iter().collect();
+        monotonic
+    }
+}
+
+/// How span offsets are counted, to index strings of the target language.
+#[derive(Debug, Clone, Copy, uniffi::Enum)]
+pub enum SpanEncoding {
+    /// Rust, Go, C
+    Utf8,
+    /// Kotlin, Java, C#, Swift `NSString`
+    Utf16,
+    /// Python
+    Chars,
+}
+
+impl From<SpanEncoding> for _grac::SpanEncoding {
+    fn from(encoding: SpanEncoding) -> Self {
+        match encoding {
+            SpanEncoding::Utf8 => Self::Utf8,
+            SpanEncoding::Utf16 => Self::Utf16,
+            SpanEncoding::Chars => Self::Chars,
+        }
+    }
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct Syllable {
+    pub text: String,
+    pub start: u32,
+    pub end: u32,
+}
+
+#[derive(Debug, Clone, Copy, uniffi::Enum)]
+pub enum PolytonicErrorKind {
+    MisplacedBreathing,
+    CircumflexOnShortVowel,
+    CircumflexTooEarly,
+    AccentTooEarly,
+    MisplacedIotaSubscript,
+    /// Added to grac after these bindings (cf. `message`).
+    Other,
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct PolytonicError {
+    pub kind: PolytonicErrorKind,
+    pub message: String,
+    /// Index of the character, as in grac.
+    pub idx: u32,
+}
+
+#[uniffi::export]
+pub fn syllabify(word: String) -> Vec<String> {
+    to_strings(&_grac::syllabify(&word))
+}
+
+#[uniffi::export]
+pub fn syllabify_with_options(word: String, options: SyllabifyOptions) -> Vec<String> {
+    to_strings(&_grac::syllabify_with_options(&word, &options.into()))
+}
+
+/// Syllables of a word, with their spans in the given encoding.
+#[uniffi::export]
+pub fn syllable_spans(word: String, encoding: SpanEncoding) -> Vec<Syllable> {
+    let syllables = _grac::syllabify(&word);
+    let mut start = 0;
+    let spans = syllables.iter().map(|syllable| {
+        start += syllable.len();
+        start - syllable.len()..start
+    });
+    let spans = _grac::encode_spans(&word, spans, encoding.into());
+    syllables
+        .iter()
+        .zip(spans)
+        .map(|(syllable, span)| Syllable {
+            text: (*syllable).to_string(),
+            start: span.start as u32,
+            end: span.end as u32,
+        })
+        .collect()
+}
+
+#[uniffi::export]
+pub fn to_monotonic(text: String) -> String {
+    _grac::to_monotonic(&text)
+}
+
+#[uniffi::export]
+pub fn to_monotonic_with_options(text: String, options: MonotonicOptions) -> String {
+    _grac::to_monotonic_with_options(&text, &options.into())
+}
+
+#[uniffi::export]
+pub fn hyphenate(word: String, hyphen: String) -> String {
+    _grac::hyphenate(&word, &hyphen)
+}
+
+#[uniffi::export]
+pub fn validate_polytonic(word: String) -> Vec<PolytonicError> {
+    _grac::validate_polytonic(&word)
+        .into_iter()
+        .map(|error| PolytonicError {
+            kind: match error.kind {
+                _grac::PolytonicErrorKind::MisplacedBreathing => {
+                    PolytonicErrorKind::MisplacedBreathing
+                }
+                _grac::PolytonicErrorKind::CircumflexOnShortVowel => {
+                    PolytonicErrorKind::CircumflexOnShortVowel
+                }
+                _grac::PolytonicErrorKind::CircumflexTooEarly => {
+                    PolytonicErrorKind::CircumflexTooEarly
+                }
+                _grac::PolytonicErrorKind::AccentTooEarly => PolytonicErrorKind::AccentTooEarly,
+                _grac::PolytonicErrorKind::MisplacedIotaSubscript => {
+                    PolytonicErrorKind::MisplacedIotaSubscript
+                }
+                _ => PolytonicErrorKind::Other,
+            },
+            message: error.kind.message().to_string(),
+            idx: error.idx as u32,
+        })
+        .collect()
+}
+
+fn to_strings(syllables: &[&str]) -> Vec<String> {
+    syllables
+        .iter()
+        .map(|syllable| (*syllable).to_string())
+        .collect()
+}
@@ -0,0 +1,87 @@
+/** The functions of an instantiated WASM module. */
+export function bind(wasm) {
+  return {
+    syllabify: (word) => wasm.syllabify(word),
+    toMonotonic: (text) => wasm.toMonotonic(text),
+    romanize: (text) => wasm.romanize(text),
+    hyphenate: (text, hyphen = "\u00AD") => wasm.hyphenate(text, hyphen),
+    hyphenationOffsets: (text) => wasm.hyphenationOffsets(text),
+    hyphenateElementText: (text, maxChunk) =>
+      hyphenateElementText(wasm, text, maxChunk),
+  };
+}
+
+/** The same functions, instantiating the module with `load` on first use. */
+export function bindLazy(load) {
+  return {
+    syllabify: async (word) => (await load()).syllabify(word),
+    toMonotonic: async (text) => (await load()).toMonotonic(text),
+    romanize: async (text) => (await load()).romanize(text),
+    hyphenate: async (text, hyphen) => (await load()).hyphenate(text, hyphen),
+    hyphenationOffsets: async (text) => (await load()).hyphenationOffsets(text),
+    hyphenateElementText: async (text, maxChunk) =>
+      (await load()).hyphenateElementText(text, maxChunk),
+  };
+}
+
+/**
+ * Hyphenation offsets of a long text, computed `maxChunk` UTF-16 units at a
+ * time, yielding to the event loop between chunks.
+ *
+ * Chunks end at whitespace, so that words are never cut, unless a single
+ * word is longer than `maxChunk`.
+ */
+async function hyphenateElementText(wasm, text, maxChunk = 16384) {
+  const parts = [];
+  let length = 0;
+  let start = 0;
+  while (start < text.length) {
+    let end = Math.min(start + Math.max(maxChunk, 1), text.length);
+    if (end < text.length) {
+      const space = lastWhitespace(text, start, end);
+      end = space > start ? space : nextWhitespace(text, end);
+    }
+    const offsets = wasm.hyphenationOffsets(text.slice(start, end));
+    for (let idx = 0; idx < offsets.length; idx++) {
+      offsets[idx] += start;
+    }
+    parts.push(offsets);
+    length += offsets.length;
+    start = end;
+    if (start < text.length) {
+      await new Promise((resolve) => setTimeout(resolve, 0));
+    }
+  }
+  const out = new Uint32Array(length);
+  let idx = 0;
+  for (const part of parts) {
+    out.set(part, idx);
+    idx += part.length;
+  }
+  return out;
+}
+
+function lastWhitespace(text, start, end) {
+  for (let idx = end; idx > start; idx--) {
+    if (/\s/.test(text[idx])) {
+      return idx;
+    }
+  }
+  return start;
+}
+
+function nextWhitespace(text, end) {
+  const match = /\s/.exec(text.slice(end));
+  return match === null ? text.length : end + match.index;
+}
+
+/** Insert `hyphen` at the given UTF-16 offsets, sorted, of the text. */
+export function insertBreaks(text, offsets, hyphen = "\u00AD") {
+  let out = "";
+  let start = 0;
+  for (const offset of offsets) {
+    out += text.slice(start, offset) + hyphen;
+    start = offset;
+  }
+  return out + text.slice(start);
+}
@@ -0,0 +1,33 @@
+export interface Grac {
+  /** Syllables of a word. */
+  syllabify(word: string): string[];
+  /** Convert polytonic or mixed text to monotonic. */
+  toMonotonic(text: string): string;
+  /** Romanize Greek text, following ELOT 743: Καλημέρα → Kalimera. */
+  romanize(text: string): string;
+  /** Insert `hyphen` (a soft hyphen by default) at the hyphenation points of every word. */
+  hyphenate(text: string, hyphen?: string): string;
+  /** UTF-16 offsets of the hyphenation points of every word. */
+  hyphenationOffsets(text: string): Uint32Array;
+  /**
+   * Same as `hyphenationOffsets`, `maxChunk` UTF-16 units (16384 by default)
+   * at a time, yielding to the event loop between chunks.
+   */
+  hyphenateElementText(text: string, maxChunk?: number): Promise<Uint32Array>;
+}
+
+/**
+ * Instantiate the WASM module, once. In browsers, `input` overrides where
+ * the module is fetched from.
+ */
+export function load(input?: string | URL | Response | BufferSource): Promise<Grac>;
+
+export function syllabify(word: string): Promise<string[]>;
+export function toMonotonic(text: string): Promise<string>;
+export function romanize(text: string): Promise<string>;
+export function hyphenate(text: string, hyphen?: string): Promise<string>;
+export function hyphenationOffsets(text: string): Promise<Uint32Array>;
+export function hyphenateElementText(text: string, maxChunk?: number): Promise<Uint32Array>;
+
+/** Insert `hyphen` (a soft hyphen by default) at the given sorted UTF-16 offsets. */
+export function insertBreaks(text: string, offsets: ArrayLike<number>, hyphen?: string): string;
@@ -0,0 +1,27 @@
+// Browsers and bundlers: the module is fetched next to this file.
+import init, * as wasm from "../pkg/grac_wasm.js";
+import { bind, bindLazy } from "./api.js";
+
+export { insertBreaks } from "./api.js";
+
+let ready;
+
+/**
+ * Instantiate the WASM module, once, from `input` (a URL, a Response or
+ * bytes) if given. Resolves to the synchronous API.
+ */
+export function load(input) {
+  ready ??= init(input === undefined ? undefined : { module_or_path: input }).then(() =>
+    bind(wasm),
+  );
+  return ready;
+}
+
+export const {
+  syllabify,
+  toMonotonic,
+  romanize,
+  hyphenate,
+  hyphenationOffsets,
+  hyphenateElementText,
+} = bindLazy(load);
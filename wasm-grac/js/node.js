@@ -0,0 +1,26 @@
+// Node: the module is read from the package, since fetch does not read files.
+import { readFile } from "node:fs/promises";
+
+import init, * as wasm from "../pkg/grac_wasm.js";
+import { bind, bindLazy } from "./api.js";
+
+export { insertBreaks } from "./api.js";
+
+let ready;
+
+/** Instantiate the WASM module, once. Resolves to the synchronous API. */
+export function load() {
+  ready ??= readFile(new URL("../pkg/grac_wasm_bg.wasm", import.meta.url))
+    .then((bytes) => init({ module_or_path: bytes }))
+    .then(() => bind(wasm));
+  return ready;
+}
+
+export const {
+  syllabify,
+  toMonotonic,
+  romanize,
+  hyphenate,
+  hyphenationOffsets,
+  hyphenateElementText,
+} = bindLazy(load);
@@ -0,0 +1,4 @@
+import type { Transform } from "node:stream";
+
+/** A Transform that converts UTF-8 text to monotonic, line by line. */
+export function createMonotonicStream(): Transform;
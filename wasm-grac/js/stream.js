@@ -0,0 +1,38 @@
+import { Transform } from "node:stream";
+import { StringDecoder } from "node:string_decoder";
+
+import { load } from "./node.js";
+
+/**
+ * A Transform that converts UTF-8 text to monotonic, one batch of complete
+ * lines at a time, as `to_monotonic_stream` in Rust.
+ */
+export function createMonotonicStream() {
+  const decoder = new StringDecoder("utf8");
+  let pending = "";
+  return new Transform({
+    transform(chunk, _encoding, callback) {
+      pending += decoder.write(chunk);
+      const end = pending.lastIndexOf("\n") + 1;
+      const lines = pending.slice(0, end);
+      pending = pending.slice(end);
+      convert(lines, callback);
+    },
+    flush(callback) {
+      const rest = pending + decoder.end();
+      pending = "";
+      convert(rest, callback);
+    },
+  });
+}
+
+function convert(text, callback) {
+  if (!text) {
+    callback();
+    return;
+  }
+  load().then(
+    (grac) => callback(null, grac.toMonotonic(text)),
+    (err) => callback(err),
+  );
+}
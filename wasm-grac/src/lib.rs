@@ -0,0 +1,79 @@
+use ::grac as _grac;
+use wasm_bindgen::prelude::*;
+
+/// Syllables of a word.
+#[wasm_bindgen]
+pub fn syllabify(word: &str) -> Vec<String> {
+    _grac::syllabify(word)
+        .iter()
+        .map(|syllable| (*syllable).to_string())
+        .collect()
+}
+
+#[wasm_bindgen(js_name = toMonotonic)]
+pub fn to_monotonic(text: &str) -> String {
+    _grac::to_monotonic(text)
+}
+
+/// Romanize Greek text, following ELOT 743.
+#[wasm_bindgen]
+pub fn romanize(text: &str) -> String {
+    _grac::romanize(text)
+}
+
+/// Insert `hyphen` at the hyphenation points of every word of the text.
+#[wasm_bindgen]
+pub fn hyphenate(text: &str, hyphen: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for token in _grac::tokenize(text) {
+        if token.kind == _grac::TokenKind::Word {
+            out.push_str(&_grac::hyphenate(token.text, hyphen));
+        } else {
+            out.push_str(token.text);
+        }
+    }
+    out
+}
+
+/// UTF-16 offsets of the hyphenation points of every word of the text.
+///
+/// Returned as a `Uint32Array`, whose buffer can be transferred from a worker.
+#[wasm_bindgen(js_name = hyphenationOffsets)]
+pub fn hyphenation_offsets(text: &str) -> Vec<u32> {
+    let points = _grac::tokenize(text)
+        .filter(|token| token.kind == _grac::TokenKind::Word)
+        .flat_map(|token| {
+            _grac::hyphenation_points(token.text)
+                .into_iter()
+                .map(move |point| token.span.start + point)
+        })
+        .map(|point| point..point);
+    _grac::encode_spans(text, points, _grac::SpanEncoding::Utf16)
+        .into_iter()
+        .map(|span| span.start as u32)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hyphenation_offsets() {
+        // 𝔸 is two UTF-16 code units
+        let text = "𝔸 καλημέρα, κόσμε";
+        assert_eq!(hyphenation_offsets(text), [5, 7, 9, 15]);
+        assert_eq!(hyphenate(text, "-"), "𝔸 κα-λη-μέ-ρα, κό-σμε");
+        assert!(hyphenation_offsets("").is_empty());
+    }
+
+    #[test]
+    fn test_romanize() {
+        assert_eq!(romanize("Καλημέρα"), "Kalimera");
+    }
+
+    #[test]
+    fn test_syllabify() {
+        assert_eq!(syllabify("πάντα"), ["πά", "ντα"]);
+    }
+}